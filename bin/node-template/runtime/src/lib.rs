@@ -230,10 +230,13 @@ impl pallet_timestamp::Trait for Runtime {
 parameter_types! {
 	pub const ExistentialDeposit: u128 = 500;
 	pub const MaxLocks: u32 = 50;
+	pub const MaxReserves: u32 = 50;
 }
 
 impl pallet_balances::Trait for Runtime {
 	type MaxLocks = MaxLocks;
+	type MaxReserves = MaxReserves;
+	type ReserveIdentifier = [u8; 8];
 	/// The type for recording an account's balance.
 	type Balance = Balance;
 	/// The ubiquitous event type.
@@ -338,6 +341,17 @@ impl_runtime_apis! {
 		fn metadata() -> OpaqueMetadata {
 			Runtime::metadata().into()
 		}
+
+		fn metadata_at_version(version: u32) -> Option<OpaqueMetadata> {
+			match version {
+				12 => Some(Runtime::metadata().into()),
+				_ => None,
+			}
+		}
+
+		fn metadata_versions() -> Vec<u32> {
+			vec![12]
+		}
 	}
 
 	impl sp_block_builder::BlockBuilder<Block> for Runtime {
@@ -441,6 +455,18 @@ impl_runtime_apis! {
 		) -> pallet_transaction_payment_rpc_runtime_api::RuntimeDispatchInfo<Balance> {
 			TransactionPayment::query_info(uxt, len)
 		}
+		fn query_fee_details(
+			uxt: <Block as BlockT>::Extrinsic,
+			len: u32,
+		) -> pallet_transaction_payment_rpc_runtime_api::FeeDetails<Balance> {
+			TransactionPayment::query_fee_details(uxt, len)
+		}
+		fn query_fee_multiplier_projection(
+			fullness: Perbill,
+			blocks: u32,
+		) -> Vec<sp_runtime::FixedU128> {
+			TransactionPayment::project_fee_multiplier(fullness, blocks)
+		}
 	}
 
 	#[cfg(feature = "runtime-benchmarks")]
@@ -29,15 +29,15 @@ impl OnUnbalanced<NegativeImbalance> for Author {
 
 #[cfg(test)]
 mod multiplier_tests {
-	use sp_runtime::{assert_eq_error_rate, FixedPointNumber, traits::Convert};
-	use pallet_transaction_payment::{Multiplier, TargetedFeeAdjustment};
+	use sp_runtime::{assert_eq_error_rate, FixedPointNumber, Perbill, traits::Convert};
+	use pallet_transaction_payment::{Multiplier, NextFeeMultiplier, TargetedFeeAdjustment};
 
 	use crate::{
 		constants::{currency::*, time::*},
 		TransactionPayment, MaximumBlockWeight, AvailableBlockRatio, Runtime, TargetBlockFullness,
 		AdjustmentVariable, System, MinimumMultiplier,
 	};
-	use frame_support::weights::{Weight, WeightToFeePolynomial};
+	use frame_support::{StorageValue, weights::{DispatchClass, Weight, WeightToFeePolynomial}};
 
 	fn max() -> Weight {
 		AvailableBlockRatio::get() * MaximumBlockWeight::get()
@@ -117,6 +117,43 @@ mod multiplier_tests {
 		})
 	}
 
+	#[test]
+	fn fee_multiplier_projection_follows_runtime_update() {
+		run_with_system_weight(0, || {
+			let fm = Multiplier::saturating_from_rational(3, 2);
+			NextFeeMultiplier::put(fm);
+
+			// Full blocks raise the multiplier, block after block.
+			let projection = TransactionPayment::project_fee_multiplier(Perbill::from_percent(100), 5);
+			assert_eq!(projection.len(), 6);
+			assert_eq!(projection[0], fm);
+			for pair in projection.windows(2) {
+				assert!(pair[1] > pair[0], "{:?} !> {:?}", pair[1], pair[0]);
+				assert_eq_error_rate!(
+					truth_value_update(max(), pair[0]),
+					pair[1],
+					Multiplier::from_inner(100),
+				);
+			}
+
+			// Empty blocks lower it.
+			let projection = TransactionPayment::project_fee_multiplier(Perbill::from_percent(0), 5);
+			assert_eq!(projection[0], fm);
+			for pair in projection.windows(2) {
+				assert!(pair[1] < pair[0], "{:?} !< {:?}", pair[1], pair[0]);
+				assert_eq_error_rate!(
+					truth_value_update(0, pair[0]),
+					pair[1],
+					Multiplier::from_inner(100),
+				);
+			}
+
+			// The storage is left untouched.
+			assert_eq!(NextFeeMultiplier::get(), fm);
+			assert_eq!(System::block_weight().get(DispatchClass::Normal), 0);
+		})
+	}
+
 	#[test]
 	fn multiplier_can_grow_from_zero() {
 		// if the min is too small, then this will not change, and we are doomed forever.
@@ -26,6 +26,7 @@
 use sp_std::prelude::*;
 use frame_support::{
 	construct_runtime, parameter_types, debug, RuntimeDebug,
+	type_info::TypeInfo,
 	weights::{
 		Weight, IdentityFee,
 		constants::{BlockExecutionWeight, ExtrinsicBaseWeight, RocksDbWeight, WEIGHT_PER_SECOND},
@@ -63,7 +64,10 @@ use pallet_grandpa::{AuthorityId as GrandpaId, AuthorityList as GrandpaAuthority
 use pallet_grandpa::fg_primitives;
 use pallet_im_online::sr25519::AuthorityId as ImOnlineId;
 use sp_authority_discovery::AuthorityId as AuthorityDiscoveryId;
-use pallet_transaction_payment_rpc_runtime_api::RuntimeDispatchInfo;
+use pallet_transaction_payment_rpc_runtime_api::{FeeDetails, RuntimeDispatchInfo};
+use pallet_scheduler_rpc_runtime_api::AgendaItem;
+use pallet_identity::{IdentityField, Data as IdentityData, VerifiedField};
+use pallet_referenda::{TrackId, TrackInfo, Curve};
 pub use pallet_transaction_payment::{Multiplier, TargetedFeeAdjustment};
 use pallet_contracts_rpc_runtime_api::ContractExecResult;
 use pallet_session::{historical as pallet_session_historical};
@@ -112,10 +116,10 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
 	// and set impl_version to 0. If only runtime
 	// implementation changes and behavior does not, then leave spec_version as
 	// is and increment impl_version.
-	spec_version: 260,
+	spec_version: 282,
 	impl_version: 0,
 	apis: RUNTIME_API_VERSIONS,
-	transaction_version: 1,
+	transaction_version: 2,
 };
 
 /// Native version.
@@ -161,7 +165,7 @@ parameter_types! {
 const_assert!(AvailableBlockRatio::get().deconstruct() >= AVERAGE_ON_INITIALIZE_WEIGHT.deconstruct());
 
 impl frame_system::Trait for Runtime {
-	type BaseCallFilter = ();
+	type BaseCallFilter = SafeMode;
 	type Origin = Origin;
 	type Call = Call;
 	type Index = Index;
@@ -191,6 +195,7 @@ impl frame_system::Trait for Runtime {
 impl pallet_utility::Trait for Runtime {
 	type Event = Event;
 	type Call = Call;
+	type PalletsOrigin = OriginCaller;
 	type WeightInfo = weights::pallet_utility::WeightInfo<Runtime>;
 }
 
@@ -224,7 +229,7 @@ parameter_types! {
 }
 
 /// The type used to represent the kinds of proxying allowed.
-#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Encode, Decode, RuntimeDebug)]
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Encode, Decode, RuntimeDebug, TypeInfo)]
 pub enum ProxyType {
 	Any,
 	NonTransfer,
@@ -249,7 +254,8 @@ impl InstanceFilter<Call> for ProxyType {
 				Call::Society(..) |
 				Call::TechnicalCommittee(..) |
 				Call::Elections(..) |
-				Call::Treasury(..)
+				Call::Treasury(..) |
+				Call::Bounties(..)
 			),
 			ProxyType::Staking => matches!(c, Call::Staking(..)),
 		}
@@ -280,9 +286,27 @@ impl pallet_proxy::Trait for Runtime {
 	type AnnouncementDepositFactor = AnnouncementDepositFactor;
 }
 
+parameter_types! {
+	pub const PreimageMaxSize: u32 = 4096 * 1024;
+	pub const PreimageBaseDeposit: Balance = 1 * DOLLARS;
+	// One cent: $10,000 / MB
+	pub const PreimageByteDeposit: Balance = 1 * CENTS;
+}
+
+impl pallet_preimage::Trait for Runtime {
+	type Event = Event;
+	type Currency = Balances;
+	type ManagerOrigin = EnsureRoot<AccountId>;
+	type MaxSize = PreimageMaxSize;
+	type BaseDeposit = PreimageBaseDeposit;
+	type ByteDeposit = PreimageByteDeposit;
+	type WeightInfo = weights::pallet_preimage::WeightInfo<Runtime>;
+}
+
 parameter_types! {
 	pub MaximumSchedulerWeight: Weight = Perbill::from_percent(80) * MaximumBlockWeight::get();
 	pub const MaxScheduledPerBlock: u32 = 50;
+	pub const NoPreimagePostponement: Option<BlockNumber> = Some(10);
 }
 
 impl pallet_scheduler::Trait for Runtime {
@@ -293,6 +317,8 @@ impl pallet_scheduler::Trait for Runtime {
 	type MaximumWeight = MaximumSchedulerWeight;
 	type ScheduleOrigin = EnsureRoot<AccountId>;
 	type MaxScheduledPerBlock = MaxScheduledPerBlock;
+	type PreimageProvider = Preimage;
+	type NoPreimagePostponement = NoPreimagePostponement;
 	type WeightInfo = weights::pallet_scheduler::WeightInfo<Runtime>;
 }
 
@@ -341,10 +367,13 @@ parameter_types! {
 	// For weight estimation, we assume that the most locks on an individual account will be 50.
 	// This number may need to be adjusted in the future if this assumption no longer holds true.
 	pub const MaxLocks: u32 = 50;
+	pub const MaxReserves: u32 = 50;
 }
 
 impl pallet_balances::Trait for Runtime {
 	type MaxLocks = MaxLocks;
+	type MaxReserves = MaxReserves;
+	type ReserveIdentifier = [u8; 8];
 	type Balance = Balance;
 	type DustRemoval = ();
 	type Event = Event;
@@ -483,13 +512,13 @@ impl pallet_staking::Trait for Runtime {
 parameter_types! {
 	pub const LaunchPeriod: BlockNumber = 28 * 24 * 60 * MINUTES;
 	pub const VotingPeriod: BlockNumber = 28 * 24 * 60 * MINUTES;
+	pub const RevealPeriod: BlockNumber = 7 * 24 * 60 * MINUTES;
+	pub const CommitmentDeposit: Balance = 1 * DOLLARS;
 	pub const FastTrackVotingPeriod: BlockNumber = 3 * 24 * 60 * MINUTES;
 	pub const InstantAllowed: bool = true;
 	pub const MinimumDeposit: Balance = 100 * DOLLARS;
 	pub const EnactmentPeriod: BlockNumber = 30 * 24 * 60 * MINUTES;
 	pub const CooloffPeriod: BlockNumber = 28 * 24 * 60 * MINUTES;
-	// One cent: $10,000 / MB
-	pub const PreimageByteDeposit: Balance = 1 * CENTS;
 	pub const MaxVotes: u32 = 100;
 	pub const MaxProposals: u32 = 100;
 }
@@ -501,6 +530,8 @@ impl pallet_democracy::Trait for Runtime {
 	type EnactmentPeriod = EnactmentPeriod;
 	type LaunchPeriod = LaunchPeriod;
 	type VotingPeriod = VotingPeriod;
+	type RevealPeriod = RevealPeriod;
+	type CommitmentDeposit = CommitmentDeposit;
 	type MinimumDeposit = MinimumDeposit;
 	/// A straight majority of the council can decide what their next motion is.
 	type ExternalOrigin = pallet_collective::EnsureProportionAtLeast<_1, _2, AccountId, CouncilCollective>;
@@ -525,11 +556,14 @@ impl pallet_democracy::Trait for Runtime {
 		pallet_collective::EnsureProportionAtLeast<_1, _1, AccountId, TechnicalCollective>,
 	>;
 	type BlacklistOrigin = EnsureRoot<AccountId>;
+	/// A straight majority of the council can have a referendum decided by secret ballot.
+	type SecretBallotOrigin = pallet_collective::EnsureProportionAtLeast<_1, _2, AccountId, CouncilCollective>;
 	// Any single technical committee member may veto a coming council proposal, however they can
 	// only do it once and it lasts only for the cooloff period.
 	type VetoOrigin = pallet_collective::EnsureMember<AccountId, TechnicalCollective>;
 	type CooloffPeriod = CooloffPeriod;
 	type PreimageByteDeposit = PreimageByteDeposit;
+	type Preimages = Preimage;
 	type OperationalPreimageOrigin = pallet_collective::EnsureMember<AccountId, CouncilCollective>;
 	type Slash = Treasury;
 	type Scheduler = Scheduler;
@@ -539,10 +573,96 @@ impl pallet_democracy::Trait for Runtime {
 	type MaxProposals = MaxProposals;
 }
 
+parameter_types! {
+	pub const ReferendaSubmissionDeposit: Balance = 1 * DOLLARS;
+	pub const ReferendaMaxQueued: u32 = 100;
+	pub const ReferendaUndecidingTimeout: BlockNumber = 28 * DAYS;
+	pub const ReferendaAlarmInterval: BlockNumber = 1;
+	pub const ReferendaVoteLockingPeriod: BlockNumber = 7 * DAYS;
+}
+
+pub struct ReferendaTracks;
+impl pallet_referenda::TracksInfo<Balance, BlockNumber> for ReferendaTracks {
+	type Origin = OriginCaller;
+
+	fn tracks() -> Vec<(TrackId, TrackInfo<Balance, BlockNumber>)> {
+		vec![
+			(0, TrackInfo {
+				name: "root",
+				max_deciding: 1,
+				decision_deposit: 1_000 * DOLLARS,
+				prepare_period: 1 * DAYS,
+				decision_period: 28 * DAYS,
+				confirm_period: 3 * DAYS,
+				min_enactment_period: 1 * DAYS,
+				min_approval: Curve::LinearDecreasing {
+					length: Perbill::one(),
+					floor: Perbill::from_percent(50),
+					ceil: Perbill::one(),
+				},
+				min_support: Curve::LinearDecreasing {
+					length: Perbill::one(),
+					floor: Perbill::zero(),
+					ceil: Perbill::from_percent(50),
+				},
+			}),
+			(1, TrackInfo {
+				name: "council",
+				max_deciding: 10,
+				decision_deposit: 100 * DOLLARS,
+				prepare_period: 1 * HOURS,
+				decision_period: 7 * DAYS,
+				confirm_period: 1 * DAYS,
+				min_enactment_period: 1 * HOURS,
+				min_approval: Curve::SteppedDecreasing {
+					begin: Perbill::one(),
+					end: Perbill::from_percent(50),
+					step: Perbill::from_percent(10),
+					period: Perbill::from_percent(10),
+				},
+				min_support: Curve::LinearDecreasing {
+					length: Perbill::from_percent(50),
+					floor: Perbill::zero(),
+					ceil: Perbill::from_percent(10),
+				},
+			}),
+		]
+	}
+
+	fn track_for(origin: &OriginCaller) -> Result<TrackId, ()> {
+		match origin {
+			OriginCaller::system(frame_system::RawOrigin::Root) => Ok(0),
+			OriginCaller::pallet_collective_Instance1(_) => Ok(1),
+			_ => Err(()),
+		}
+	}
+}
+
+impl pallet_referenda::Trait for Runtime {
+	type Event = Event;
+	type Proposal = Call;
+	type Scheduler = Scheduler;
+	type PalletsOrigin = OriginCaller;
+	type Currency = Balances;
+	type Preimages = Preimage;
+	type CancelOrigin = EnsureRoot<AccountId>;
+	type KillOrigin = EnsureRoot<AccountId>;
+	type Slash = Treasury;
+	type SubmissionDeposit = ReferendaSubmissionDeposit;
+	type MaxQueued = ReferendaMaxQueued;
+	type UndecidingTimeout = ReferendaUndecidingTimeout;
+	type AlarmInterval = ReferendaAlarmInterval;
+	type VoteLockingPeriod = ReferendaVoteLockingPeriod;
+	type MaxVotes = MaxVotes;
+	type Tracks = ReferendaTracks;
+	type WeightInfo = ();
+}
+
 parameter_types! {
 	pub const CouncilMotionDuration: BlockNumber = 5 * DAYS;
 	pub const CouncilMaxProposals: u32 = 100;
 	pub const CouncilMaxMembers: u32 = 100;
+	pub const CouncilQuorum: Perbill = Perbill::from_percent(50);
 }
 
 type CouncilCollective = pallet_collective::Instance1;
@@ -554,6 +674,9 @@ impl pallet_collective::Trait<CouncilCollective> for Runtime {
 	type MaxProposals = CouncilMaxProposals;
 	type MaxMembers = CouncilMaxMembers;
 	type DefaultVote = pallet_collective::PrimeDefaultVote;
+	/// Council members' votes are weighted by their stake.
+	type VoteWeighting = pallet_collective::BalanceVoteWeight<Balances>;
+	type Quorum = CouncilQuorum;
 	type WeightInfo = weights::pallet_collective::WeightInfo<Runtime>;
 }
 
@@ -604,6 +727,8 @@ impl pallet_collective::Trait<TechnicalCollective> for Runtime {
 	type MaxProposals = TechnicalMaxProposals;
 	type MaxMembers = TechnicalMaxMembers;
 	type DefaultVote = pallet_collective::PrimeDefaultVote;
+	type VoteWeighting = pallet_collective::EqualVoteWeight;
+	type Quorum = ();
 	type WeightInfo = weights::pallet_collective::WeightInfo<Runtime>;
 }
 
@@ -612,6 +737,12 @@ type EnsureRootOrHalfCouncil = EnsureOneOf<
 	EnsureRoot<AccountId>,
 	pallet_collective::EnsureProportionMoreThan<_1, _2, AccountId, CouncilCollective>
 >;
+parameter_types! {
+	pub const TechnicalCandidacyDeposit: Balance = 10 * DOLLARS;
+	pub const TechnicalMaxCandidates: u32 = 10;
+	pub const TechnicalTermDuration: BlockNumber = 28 * DAYS;
+}
+
 impl pallet_membership::Trait<pallet_membership::Instance1> for Runtime {
 	type Event = Event;
 	type AddOrigin = EnsureRootOrHalfCouncil;
@@ -621,6 +752,10 @@ impl pallet_membership::Trait<pallet_membership::Instance1> for Runtime {
 	type PrimeOrigin = EnsureRootOrHalfCouncil;
 	type MembershipInitialized = TechnicalCommittee;
 	type MembershipChanged = TechnicalCommittee;
+	type Currency = Balances;
+	type CandidacyDeposit = TechnicalCandidacyDeposit;
+	type MaxCandidates = TechnicalMaxCandidates;
+	type TermDuration = TechnicalTermDuration;
 }
 
 parameter_types! {
@@ -639,6 +774,8 @@ parameter_types! {
 	pub const MaximumReasonLength: u32 = 16384;
 	pub const BountyCuratorDeposit: Permill = Permill::from_percent(50);
 	pub const BountyValueMinimum: Balance = 5 * DOLLARS;
+	pub const AssetSpendMaximum: Balance = 100_000 * DOLLARS;
+	pub const MaxInstalments: u32 = 24;
 }
 
 impl pallet_treasury::Trait for Runtime {
@@ -665,14 +802,31 @@ impl pallet_treasury::Trait for Runtime {
 	type ProposalBondMinimum = ProposalBondMinimum;
 	type SpendPeriod = SpendPeriod;
 	type Burn = Burn;
+	type MaximumReasonLength = MaximumReasonLength;
+	type BurnDestination = ();
+	type SpendFunds = Bounties;
+	type Assets = Assets;
+	type BalanceConverter = AssetRate;
+	type AssetSpendMaximum = AssetSpendMaximum;
+	type MaxInstalments = MaxInstalments;
+	type WeightInfo = weights::pallet_treasury::WeightInfo<Runtime>;
+}
+
+parameter_types! {
+	pub const MaxActiveChildBountyCount: u32 = 5;
+	pub const ChildBountyValueMinimum: Balance = 1 * DOLLARS;
+}
+
+impl pallet_bounties::Trait for Runtime {
 	type BountyDepositBase = BountyDepositBase;
 	type BountyDepositPayoutDelay = BountyDepositPayoutDelay;
 	type BountyUpdatePeriod = BountyUpdatePeriod;
 	type BountyCuratorDeposit = BountyCuratorDeposit;
 	type BountyValueMinimum = BountyValueMinimum;
-	type MaximumReasonLength = MaximumReasonLength;
-	type BurnDestination = ();
-	type WeightInfo = weights::pallet_treasury::WeightInfo<Runtime>;
+	type MaxActiveChildBountyCount = MaxActiveChildBountyCount;
+	type ChildBountyValueMinimum = ChildBountyValueMinimum;
+	type Event = Event;
+	type WeightInfo = weights::pallet_bounties::WeightInfo<Runtime>;
 }
 
 parameter_types! {
@@ -885,6 +1039,7 @@ impl pallet_society::Trait for Runtime {
 
 parameter_types! {
 	pub const MinVestedTransfer: Balance = 100 * DOLLARS;
+	pub const MaxVestingSchedules: u32 = 28;
 }
 
 impl pallet_vesting::Trait for Runtime {
@@ -892,9 +1047,102 @@ impl pallet_vesting::Trait for Runtime {
 	type Currency = Balances;
 	type BlockNumberToBalance = ConvertInto;
 	type MinVestedTransfer = MinVestedTransfer;
+	type MaxVestingSchedules = MaxVestingSchedules;
 	type WeightInfo = weights::pallet_vesting::WeightInfo<Runtime>;
 }
 
+parameter_types! {
+	pub const ClassDeposit: Balance = 100 * DOLLARS;
+	pub const InstanceDeposit: Balance = 1 * DOLLARS;
+	pub const KeyLimit: u32 = 32;
+	pub const ValueLimit: u32 = 256;
+	pub const StringLimit: u32 = 128;
+	pub const MetadataDepositBase: Balance = deposit(1, 129);
+	pub const AttributeDepositBase: Balance = deposit(1, 0);
+	pub const DepositPerByte: Balance = deposit(0, 1);
+}
+
+impl pallet_uniques::Trait for Runtime {
+	type Event = Event;
+	type ClassId = u32;
+	type InstanceId = u32;
+	type Currency = Balances;
+	type ForceOrigin = EnsureRoot<AccountId>;
+	type ClassDeposit = ClassDeposit;
+	type InstanceDeposit = InstanceDeposit;
+	type MetadataDepositBase = MetadataDepositBase;
+	type AttributeDepositBase = AttributeDepositBase;
+	type DepositPerByte = DepositPerByte;
+	type StringLimit = StringLimit;
+	type KeyLimit = KeyLimit;
+	type ValueLimit = ValueLimit;
+	type WeightInfo = ();
+}
+
+parameter_types! {
+	pub const AssetDeposit: Balance = 100 * DOLLARS;
+	pub const ApprovalDeposit: Balance = 1 * DOLLARS;
+}
+
+impl pallet_assets::Trait for Runtime {
+	type Event = Event;
+	type Balance = Balance;
+	type AssetId = u32;
+	type Currency = Balances;
+	type ForceOrigin = EnsureRoot<AccountId>;
+	type AssetDeposit = AssetDeposit;
+	type MetadataDepositBase = MetadataDepositBase;
+	type MetadataDepositPerByte = DepositPerByte;
+	type ApprovalDeposit = ApprovalDeposit;
+	type StringLimit = StringLimit;
+	type WeightInfo = weights::pallet_assets::WeightInfo<Runtime>;
+}
+
+impl pallet_asset_rate::Trait for Runtime {
+	type Event = Event;
+	type RateOrigin = EnsureRoot<AccountId>;
+	type AssetId = u32;
+	type WeightInfo = weights::pallet_asset_rate::WeightInfo<Runtime>;
+}
+
+parameter_types! {
+	pub const SafeModeDuration: BlockNumber = 1 * DAYS;
+}
+
+/// The calls which are still dispatched while the chain is in safe mode or while their pallet
+/// is paused: the inherents and unsigned reports needed to produce and finalize blocks, and the
+/// calls needed to lift the restrictions again.
+pub struct SafeModeWhitelistedCalls;
+impl frame_support::traits::Filter<Call> for SafeModeWhitelistedCalls {
+	fn filter(call: &Call) -> bool {
+		matches!(
+			call,
+			Call::Timestamp(..) |
+			Call::Authorship(..) |
+			Call::Babe(..) |
+			Call::Grandpa(..) |
+			Call::ImOnline(..) |
+			Call::Sudo(..) |
+			Call::Council(..) |
+			Call::TechnicalCommittee(..)
+		)
+	}
+}
+
+impl pallet_safe_mode::Trait for Runtime {
+	type Event = Event;
+	type Call = Call;
+	type PauseOrigin = EnsureOneOf<
+		AccountId,
+		EnsureRoot<AccountId>,
+		pallet_collective::EnsureProportionAtLeast<_2, _3, AccountId, TechnicalCollective>,
+	>;
+	type UnpauseOrigin = EnsureRootOrHalfCouncil;
+	type WhitelistedCalls = SafeModeWhitelistedCalls;
+	type SafeModeDuration = SafeModeDuration;
+	type WeightInfo = weights::pallet_safe_mode::WeightInfo<Runtime>;
+}
+
 construct_runtime!(
 	pub enum Runtime where
 		Block = Block,
@@ -930,8 +1178,15 @@ construct_runtime!(
 		Recovery: pallet_recovery::{Module, Call, Storage, Event<T>},
 		Vesting: pallet_vesting::{Module, Call, Storage, Event<T>, Config<T>},
 		Scheduler: pallet_scheduler::{Module, Call, Storage, Event<T>},
-		Proxy: pallet_proxy::{Module, Call, Storage, Event<T>},
+		Proxy: pallet_proxy::{Module, Call, Storage, Config, Event<T>},
 		Multisig: pallet_multisig::{Module, Call, Storage, Event<T>},
+		Uniques: pallet_uniques::{Module, Call, Storage, Event<T>},
+		Preimage: pallet_preimage::{Module, Call, Storage, Event<T>},
+		Referenda: pallet_referenda::{Module, Call, Storage, Event<T>},
+		SafeMode: pallet_safe_mode::{Module, Call, Storage, Event<T>},
+		Bounties: pallet_bounties::{Module, Call, Storage, Event<T>},
+		Assets: pallet_assets::{Module, Call, Storage, Event<T>},
+		AssetRate: pallet_asset_rate::{Module, Call, Storage, Event<T>},
 	}
 );
 
@@ -987,6 +1242,18 @@ impl_runtime_apis! {
 		fn metadata() -> OpaqueMetadata {
 			Runtime::metadata().into()
 		}
+
+		fn metadata_at_version(version: u32) -> Option<OpaqueMetadata> {
+			match version {
+				12 => Some(Runtime::metadata().into()),
+				13 => Some(Runtime::metadata_v13().into()),
+				_ => None,
+			}
+		}
+
+		fn metadata_versions() -> Vec<u32> {
+			vec![12, 13]
+		}
 	}
 
 	impl sp_block_builder::BlockBuilder<Block> for Runtime {
@@ -1158,6 +1425,27 @@ impl_runtime_apis! {
 		fn query_info(uxt: <Block as BlockT>::Extrinsic, len: u32) -> RuntimeDispatchInfo<Balance> {
 			TransactionPayment::query_info(uxt, len)
 		}
+		fn query_fee_details(uxt: <Block as BlockT>::Extrinsic, len: u32) -> FeeDetails<Balance> {
+			TransactionPayment::query_fee_details(uxt, len)
+		}
+		fn query_fee_multiplier_projection(fullness: Perbill, blocks: u32) -> Vec<Multiplier> {
+			TransactionPayment::project_fee_multiplier(fullness, blocks)
+		}
+	}
+
+	impl pallet_scheduler_rpc_runtime_api::SchedulerApi<Block, BlockNumber, OriginCaller> for Runtime {
+		fn agenda_by_name(id: Vec<u8>) -> Option<AgendaItem<BlockNumber, OriginCaller>> {
+			Scheduler::agenda_by_name(id)
+		}
+		fn agenda_by_origin(origin: OriginCaller) -> Vec<AgendaItem<BlockNumber, OriginCaller>> {
+			Scheduler::agenda_by_origin(origin)
+		}
+	}
+
+	impl pallet_identity_rpc_runtime_api::IdentityApi<Block, AccountId, IdentityField, IdentityData> for Runtime {
+		fn verified_fields(who: AccountId) -> Vec<VerifiedField<IdentityField, IdentityData>> {
+			Identity::verified_fields(&who)
+		}
 	}
 
 	impl sp_session::SessionKeys<Block> for Runtime {
@@ -1207,8 +1495,11 @@ impl_runtime_apis! {
 			let mut batches = Vec::<BenchmarkBatch>::new();
 			let params = (&config, &whitelist);
 
+			add_benchmark!(params, batches, pallet_assets, Assets);
+			add_benchmark!(params, batches, pallet_asset_rate, AssetRate);
 			add_benchmark!(params, batches, pallet_babe, Babe);
 			add_benchmark!(params, batches, pallet_balances, Balances);
+			add_benchmark!(params, batches, pallet_bounties, Bounties);
 			add_benchmark!(params, batches, pallet_collective, Council);
 			add_benchmark!(params, batches, pallet_contracts, Contracts);
 			add_benchmark!(params, batches, pallet_democracy, Democracy);
@@ -1219,13 +1510,17 @@ impl_runtime_apis! {
 			add_benchmark!(params, batches, pallet_indices, Indices);
 			add_benchmark!(params, batches, pallet_multisig, Multisig);
 			add_benchmark!(params, batches, pallet_offences, OffencesBench::<Runtime>);
+			add_benchmark!(params, batches, pallet_preimage, Preimage);
 			add_benchmark!(params, batches, pallet_proxy, Proxy);
+			add_benchmark!(params, batches, pallet_referenda, Referenda);
+			add_benchmark!(params, batches, pallet_safe_mode, SafeMode);
 			add_benchmark!(params, batches, pallet_scheduler, Scheduler);
 			add_benchmark!(params, batches, pallet_session, SessionBench::<Runtime>);
 			add_benchmark!(params, batches, pallet_staking, Staking);
 			add_benchmark!(params, batches, frame_system, SystemBench::<Runtime>);
 			add_benchmark!(params, batches, pallet_timestamp, Timestamp);
 			add_benchmark!(params, batches, pallet_treasury, Treasury);
+			add_benchmark!(params, batches, pallet_uniques, Uniques);
 			add_benchmark!(params, batches, pallet_utility, Utility);
 			add_benchmark!(params, batches, pallet_vesting, Vesting);
 
@@ -1248,4 +1543,30 @@ mod tests {
 
 		is_submit_signed_transaction::<Runtime>();
 	}
+
+	#[test]
+	fn metadata_v13_describes_every_module() {
+		use frame_support::metadata::{RuntimeMetadata, RuntimeMetadataPrefixed};
+
+		let metadata_encoded = Runtime::metadata_v13().encode();
+		let metadata_prefixed =
+			RuntimeMetadataPrefixed::decode(&mut &metadata_encoded[..]).unwrap();
+		let metadata = match metadata_prefixed.1 {
+			RuntimeMetadata::V13(metadata) => metadata,
+			_ => panic!("metadata has version 13"),
+		};
+
+		assert_eq!(metadata.modules.len(), 38);
+		assert_eq!(
+			(metadata.modules[0].name.as_str(), metadata.modules[37].name.as_str()),
+			("System", "AssetRate"),
+		);
+		for module in &metadata.modules {
+			for call in module.calls.iter().flatten() {
+				for argument in &call.arguments {
+					assert!(metadata.types.resolve(argument.ty).is_some());
+				}
+			}
+		}
+	}
 }
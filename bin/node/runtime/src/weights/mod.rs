@@ -16,7 +16,10 @@
 //! A list of the different weight modules for our runtime.
 
 pub mod frame_system;
+pub mod pallet_asset_rate;
+pub mod pallet_assets;
 pub mod pallet_balances;
+pub mod pallet_bounties;
 pub mod pallet_collective;
 pub mod pallet_contracts;
 pub mod pallet_democracy;
@@ -25,7 +28,9 @@ pub mod pallet_identity;
 pub mod pallet_im_online;
 pub mod pallet_indices;
 pub mod pallet_multisig;
+pub mod pallet_preimage;
 pub mod pallet_proxy;
+pub mod pallet_safe_mode;
 pub mod pallet_scheduler;
 pub mod pallet_session;
 pub mod pallet_staking;
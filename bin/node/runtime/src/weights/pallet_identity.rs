@@ -16,6 +16,10 @@
 // limitations under the License.
 
 //! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 2.0.0-rc6
+//!
+//! The weights after the `Estimates` comment were written by hand and not generated by the
+//! benchmark CLI. They are estimated from the generated weights of similar calls until the
+//! benchmarks are run again.
 
 #![allow(unused_parens)]
 #![allow(unused_imports)]
@@ -53,15 +57,6 @@ impl<T: frame_system::Trait> pallet_identity::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().writes(1 as Weight))
 			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(p as Weight)))
 	}
-	fn clear_identity(r: u32, s: u32, x: u32, ) -> Weight {
-		(91_553_000 as Weight)
-			.saturating_add((284_000 as Weight).saturating_mul(r as Weight))
-			.saturating_add((5_749_000 as Weight).saturating_mul(s as Weight))
-			.saturating_add((1_621_000 as Weight).saturating_mul(x as Weight))
-			.saturating_add(T::DbWeight::get().reads(2 as Weight))
-			.saturating_add(T::DbWeight::get().writes(2 as Weight))
-			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(s as Weight)))
-	}
 	fn request_judgement(r: u32, x: u32, ) -> Weight {
 		(110_856_000 as Weight)
 			.saturating_add((496_000 as Weight).saturating_mul(r as Weight))
@@ -69,13 +64,6 @@ impl<T: frame_system::Trait> pallet_identity::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(2 as Weight))
 			.saturating_add(T::DbWeight::get().writes(1 as Weight))
 	}
-	fn cancel_request(r: u32, x: u32, ) -> Weight {
-		(96_857_000 as Weight)
-			.saturating_add((311_000 as Weight).saturating_mul(r as Weight))
-			.saturating_add((3_204_000 as Weight).saturating_mul(x as Weight))
-			.saturating_add(T::DbWeight::get().reads(1 as Weight))
-			.saturating_add(T::DbWeight::get().writes(1 as Weight))
-	}
 	fn set_fee(r: u32, ) -> Weight {
 		(16_276_000 as Weight)
 			.saturating_add((381_000 as Weight).saturating_mul(r as Weight))
@@ -94,22 +82,6 @@ impl<T: frame_system::Trait> pallet_identity::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(1 as Weight))
 			.saturating_add(T::DbWeight::get().writes(1 as Weight))
 	}
-	fn provide_judgement(r: u32, x: u32, ) -> Weight {
-		(72_869_000 as Weight)
-			.saturating_add((423_000 as Weight).saturating_mul(r as Weight))
-			.saturating_add((3_187_000 as Weight).saturating_mul(x as Weight))
-			.saturating_add(T::DbWeight::get().reads(2 as Weight))
-			.saturating_add(T::DbWeight::get().writes(1 as Weight))
-	}
-	fn kill_identity(r: u32, s: u32, x: u32, ) -> Weight {
-		(123_199_000 as Weight)
-			.saturating_add((71_000 as Weight).saturating_mul(r as Weight))
-			.saturating_add((5_730_000 as Weight).saturating_mul(s as Weight))
-			.saturating_add((2_000 as Weight).saturating_mul(x as Weight))
-			.saturating_add(T::DbWeight::get().reads(3 as Weight))
-			.saturating_add(T::DbWeight::get().writes(3 as Weight))
-			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(s as Weight)))
-	}
 	fn add_sub(s: u32, ) -> Weight {
 		(110_070_000 as Weight)
 			.saturating_add((262_000 as Weight).saturating_mul(s as Weight))
@@ -134,4 +106,51 @@ impl<T: frame_system::Trait> pallet_identity::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(2 as Weight))
 			.saturating_add(T::DbWeight::get().writes(2 as Weight))
 	}
+	// Estimates, not generated by the benchmark CLI.
+	fn clear_identity(r: u32, s: u32, x: u32, ) -> Weight {
+		(91_553_000 as Weight)
+			.saturating_add((284_000 as Weight).saturating_mul(r as Weight))
+			.saturating_add((5_749_000 as Weight).saturating_mul(s as Weight))
+			.saturating_add((1_621_000 as Weight).saturating_mul(x as Weight))
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(s as Weight)))
+	}
+	fn cancel_request(r: u32, x: u32, ) -> Weight {
+		(96_857_000 as Weight)
+			.saturating_add((311_000 as Weight).saturating_mul(r as Weight))
+			.saturating_add((3_204_000 as Weight).saturating_mul(x as Weight))
+			.saturating_add(T::DbWeight::get().reads(5 as Weight))
+			.saturating_add(T::DbWeight::get().writes(5 as Weight))
+	}
+	fn provide_judgement(r: u32, x: u32, ) -> Weight {
+		(72_869_000 as Weight)
+			.saturating_add((423_000 as Weight).saturating_mul(r as Weight))
+			.saturating_add((3_187_000 as Weight).saturating_mul(x as Weight))
+			.saturating_add(T::DbWeight::get().reads(7 as Weight))
+			.saturating_add(T::DbWeight::get().writes(6 as Weight))
+	}
+	fn post_challenge(r: u32, x: u32, ) -> Weight {
+		(58_214_000 as Weight)
+			.saturating_add((402_000 as Weight).saturating_mul(r as Weight))
+			.saturating_add((3_163_000 as Weight).saturating_mul(x as Weight))
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	fn answer_challenge(r: u32, x: u32, ) -> Weight {
+		(47_518_000 as Weight)
+			.saturating_add((297_000 as Weight).saturating_mul(r as Weight))
+			.saturating_add((3_092_000 as Weight).saturating_mul(x as Weight))
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	fn kill_identity(r: u32, s: u32, x: u32, ) -> Weight {
+		(123_199_000 as Weight)
+			.saturating_add((71_000 as Weight).saturating_mul(r as Weight))
+			.saturating_add((5_730_000 as Weight).saturating_mul(s as Weight))
+			.saturating_add((2_000 as Weight).saturating_mul(x as Weight))
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(5 as Weight))
+			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(s as Weight)))
+	}
 }
@@ -14,6 +14,10 @@
 // limitations under the License.
 
 //! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 2.0.0-rc5
+//!
+//! The weights after the `Estimates` comment were written by hand and not generated by the
+//! benchmark CLI. They are estimated from the generated weights of similar calls until the
+//! benchmarks are run again.
 
 use frame_support::{traits::Get, weights::Weight};
 use sp_std::marker::PhantomData;
@@ -83,4 +87,11 @@ impl<T: frame_system::Trait> pallet_proxy::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(1 as Weight))
 			.saturating_add(T::DbWeight::get().writes(1 as Weight))
 	}
+	// Estimates, not generated by the benchmark CLI.
+	fn remove_expired_proxies(p: u32, ) -> Weight {
+		(33412000 as Weight)
+			.saturating_add((231000 as Weight).saturating_mul(p as Weight))
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
 }
@@ -16,6 +16,10 @@
 // limitations under the License.
 
 //! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 2.0.0-rc6
+//!
+//! The weights after the `Estimates` comment were written by hand and not generated by the
+//! benchmark CLI. They are estimated from the generated weights of similar calls until the
+//! benchmarks are run again.
 
 #![allow(unused_parens)]
 #![allow(unused_imports)]
@@ -31,22 +35,53 @@ impl<T: frame_system::Trait> pallet_scheduler::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(1 as Weight))
 			.saturating_add(T::DbWeight::get().writes(1 as Weight))
 	}
-	fn cancel(s: u32, ) -> Weight {
-		(34_707_000 as Weight)
-			.saturating_add((3_125_000 as Weight).saturating_mul(s as Weight))
-			.saturating_add(T::DbWeight::get().reads(1 as Weight))
-			.saturating_add(T::DbWeight::get().writes(2 as Weight))
-	}
 	fn schedule_named(s: u32, ) -> Weight {
 		(48_065_000 as Weight)
 			.saturating_add((110_000 as Weight).saturating_mul(s as Weight))
 			.saturating_add(T::DbWeight::get().reads(2 as Weight))
 			.saturating_add(T::DbWeight::get().writes(2 as Weight))
 	}
+	// Estimates, not generated by the benchmark CLI.
+	fn cancel(s: u32, ) -> Weight {
+		(34_707_000 as Weight)
+			.saturating_add((3_125_000 as Weight).saturating_mul(s as Weight))
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
 	fn cancel_named(s: u32, ) -> Weight {
 		(38_776_000 as Weight)
 			.saturating_add((3_138_000 as Weight).saturating_mul(s as Weight))
 			.saturating_add(T::DbWeight::get().reads(2 as Weight))
-			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	fn reschedule_named(s: u32, ) -> Weight {
+		(41_251_000 as Weight)
+			.saturating_add((3_244_000 as Weight).saturating_mul(s as Weight))
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(4 as Weight))
+	}
+	fn set_retry(s: u32, ) -> Weight {
+		(28_432_000 as Weight)
+			.saturating_add((105_000 as Weight).saturating_mul(s as Weight))
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	fn set_retry_named(s: u32, ) -> Weight {
+		(33_910_000 as Weight)
+			.saturating_add((112_000 as Weight).saturating_mul(s as Weight))
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	fn cancel_retry(s: u32, ) -> Weight {
+		(27_003_000 as Weight)
+			.saturating_add((99_000 as Weight).saturating_mul(s as Weight))
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	fn cancel_retry_named(s: u32, ) -> Weight {
+		(32_487_000 as Weight)
+			.saturating_add((108_000 as Weight).saturating_mul(s as Weight))
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
 	}
 }
@@ -16,6 +16,10 @@
 // limitations under the License.
 
 //! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 2.0.0-rc5
+//!
+//! The weights after the `Estimates` comment were written by hand and not generated by the
+//! benchmark CLI. They are estimated from the generated weights of similar calls until the
+//! benchmarks are run again.
 
 #![allow(unused_parens)]
 #![allow(unused_imports)]
@@ -32,4 +36,12 @@ impl<T: frame_system::Trait> pallet_utility::WeightInfo for WeightInfo<T> {
 	fn as_derivative() -> Weight {
 		(4086000 as Weight)
 	}
+	// Estimates, not generated by the benchmark CLI.
+	fn batch_all(c: u32, ) -> Weight {
+		(17428000 as Weight)
+			.saturating_add((2047000 as Weight).saturating_mul(c as Weight))
+	}
+	fn dispatch_as() -> Weight {
+		(4525000 as Weight)
+	}
 }
@@ -0,0 +1,82 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2020 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// Benchmarks for Asset Rate Pallet
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use frame_benchmarking::benchmarks;
+use frame_support::traits::UnfilteredDispatchable;
+
+const ASSET_ID: u32 = 1;
+
+fn asset_id<T: Trait>() -> T::AssetId where T::AssetId: From<u32> {
+	ASSET_ID.into()
+}
+
+fn rate(n: u32) -> FixedU128 {
+	FixedU128::saturating_from_integer(n)
+}
+
+benchmarks! {
+	where_clause { where T::AssetId: From<u32> }
+
+	_ { }
+
+	create {
+		let origin = T::RateOrigin::successful_origin();
+		let call = Call::<T>::create(asset_id::<T>(), rate(2));
+	}: { call.dispatch_bypass_filter(origin)? }
+	verify {
+		assert!(ConversionRateToNative::<T>::contains_key(asset_id::<T>()));
+	}
+
+	update {
+		ConversionRateToNative::<T>::insert(asset_id::<T>(), rate(2));
+		let origin = T::RateOrigin::successful_origin();
+		let call = Call::<T>::update(asset_id::<T>(), rate(3));
+	}: { call.dispatch_bypass_filter(origin)? }
+	verify {
+		assert_eq!(ConversionRateToNative::<T>::get(asset_id::<T>()), Some(rate(3)));
+	}
+
+	remove {
+		ConversionRateToNative::<T>::insert(asset_id::<T>(), rate(2));
+		let origin = T::RateOrigin::successful_origin();
+		let call = Call::<T>::remove(asset_id::<T>());
+	}: { call.dispatch_bypass_filter(origin)? }
+	verify {
+		assert!(!ConversionRateToNative::<T>::contains_key(asset_id::<T>()));
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::tests::{new_test_ext, Test};
+	use frame_support::assert_ok;
+
+	#[test]
+	fn test_benchmarks() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(test_benchmark_create::<Test>());
+			assert_ok!(test_benchmark_update::<Test>());
+			assert_ok!(test_benchmark_remove::<Test>());
+		});
+	}
+}
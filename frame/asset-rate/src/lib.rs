@@ -0,0 +1,189 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2020 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # Asset Rate Module
+//!
+//! A module which keeps the conversion rates of assets to the native currency, as set by the
+//! `RateOrigin`.
+//!
+//! - [`asset_rate::Trait`](./trait.Trait.html)
+//! - [`Call`](./enum.Call.html)
+//!
+//! ## Overview
+//!
+//! The rate of an asset is the amount of the native currency that one unit of the asset is worth.
+//! The module implements the Treasury module's
+//! [`ConversionFromAssetBalance`](../pallet_treasury/trait.ConversionFromAssetBalance.html), so
+//! that the value of asset spends can be limited in the native currency. Converting an amount of
+//! an asset without a rate fails.
+//!
+//! ## Interface
+//!
+//! ### Dispatchable Functions
+//!
+//! * `create` - Set the rate of an asset which has none yet.
+//! * `update` - Change the rate of an asset.
+//! * `remove` - Remove the rate of an asset.
+//!
+//! [`Call`]: ./enum.Call.html
+//! [`Trait`]: ./trait.Trait.html
+
+// Ensure we're `no_std` when compiling for Wasm.
+#![cfg_attr(not(feature = "std"), no_std)]
+
+mod tests;
+mod benchmarking;
+mod default_weight;
+
+use frame_support::{
+	decl_module, decl_event, decl_storage, decl_error, ensure, Parameter,
+	dispatch::DispatchError,
+	traits::EnsureOrigin,
+	weights::Weight,
+};
+use sp_runtime::{FixedU128, FixedPointNumber, FixedPointOperand};
+use pallet_treasury::ConversionFromAssetBalance;
+
+pub trait WeightInfo {
+	fn create() -> Weight;
+	fn update() -> Weight;
+	fn remove() -> Weight;
+}
+
+/// The module's configuration trait.
+pub trait Trait: frame_system::Trait {
+	/// The overarching event type.
+	type Event: From<Event<Self>> + Into<<Self as frame_system::Trait>::Event>;
+
+	/// The origin which may create, update and remove the rates of assets.
+	type RateOrigin: EnsureOrigin<Self::Origin>;
+
+	/// The identifier of an asset.
+	type AssetId: Parameter + Copy;
+
+	/// Weight information for extrinsics in this pallet.
+	type WeightInfo: WeightInfo;
+}
+
+decl_storage! {
+	trait Store for Module<T: Trait> as AssetRate {
+		/// The amount of the native currency that one unit of an asset is worth.
+		pub ConversionRateToNative get(fn conversion_rate_to_native):
+			map hasher(blake2_128_concat) T::AssetId => Option<FixedU128>;
+	}
+}
+
+decl_event! {
+	pub enum Event<T> where
+		AssetId = <T as Trait>::AssetId,
+	{
+		/// The rate of an asset has been set. \[asset_id, rate\]
+		Created(AssetId, FixedU128),
+		/// The rate of an asset has been changed. \[asset_id, old_rate, new_rate\]
+		Updated(AssetId, FixedU128, FixedU128),
+		/// The rate of an asset has been removed. \[asset_id\]
+		Removed(AssetId),
+	}
+}
+
+decl_error! {
+	pub enum Error for Module<T: Trait> {
+		/// The asset has no rate.
+		UnknownAssetId,
+		/// The asset already has a rate.
+		AlreadyExists,
+	}
+}
+
+decl_module! {
+	pub struct Module<T: Trait> for enum Call where origin: T::Origin {
+		type Error = Error<T>;
+
+		fn deposit_event() = default;
+
+		/// Set the rate of an asset which has none yet.
+		///
+		/// The dispatch origin for this call must be the `RateOrigin`.
+		///
+		/// - `asset_id`: The asset to set the rate of.
+		/// - `rate`: The amount of the native currency that one unit of the asset is worth.
+		///
+		/// Emits `Created` event when successful.
+		///
+		/// Weight: `O(1)`.
+		#[weight = T::WeightInfo::create()]
+		fn create(origin, asset_id: T::AssetId, rate: FixedU128) {
+			T::RateOrigin::ensure_origin(origin)?;
+			ensure!(!ConversionRateToNative::<T>::contains_key(asset_id), Error::<T>::AlreadyExists);
+
+			ConversionRateToNative::<T>::insert(asset_id, rate);
+			Self::deposit_event(RawEvent::Created(asset_id, rate));
+		}
+
+		/// Change the rate of an asset.
+		///
+		/// The dispatch origin for this call must be the `RateOrigin`.
+		///
+		/// - `asset_id`: The asset to change the rate of.
+		/// - `rate`: The amount of the native currency that one unit of the asset is worth.
+		///
+		/// Emits `Updated` event when successful.
+		///
+		/// Weight: `O(1)`.
+		#[weight = T::WeightInfo::update()]
+		fn update(origin, asset_id: T::AssetId, rate: FixedU128) {
+			T::RateOrigin::ensure_origin(origin)?;
+			let old_rate = ConversionRateToNative::<T>::try_mutate(asset_id, |maybe_rate| {
+				let old_rate = maybe_rate.ok_or(Error::<T>::UnknownAssetId)?;
+				*maybe_rate = Some(rate);
+				Ok::<_, Error<T>>(old_rate)
+			})?;
+			Self::deposit_event(RawEvent::Updated(asset_id, old_rate, rate));
+		}
+
+		/// Remove the rate of an asset, so that its amounts can no longer be converted.
+		///
+		/// The dispatch origin for this call must be the `RateOrigin`.
+		///
+		/// - `asset_id`: The asset to remove the rate of.
+		///
+		/// Emits `Removed` event when successful.
+		///
+		/// Weight: `O(1)`.
+		#[weight = T::WeightInfo::remove()]
+		fn remove(origin, asset_id: T::AssetId) {
+			T::RateOrigin::ensure_origin(origin)?;
+			ensure!(ConversionRateToNative::<T>::contains_key(asset_id), Error::<T>::UnknownAssetId);
+
+			ConversionRateToNative::<T>::remove(asset_id);
+			Self::deposit_event(RawEvent::Removed(asset_id));
+		}
+	}
+}
+
+/// Amounts of an asset are converted with the rate of the asset, rounding down. The amounts of
+/// the asset and of the native currency are of the same type.
+impl<T: Trait, Balance: FixedPointOperand> ConversionFromAssetBalance<Balance, T::AssetId, Balance>
+	for Module<T>
+{
+	type Error = DispatchError;
+
+	fn from_asset_balance(balance: Balance, asset_id: T::AssetId) -> Result<Balance, DispatchError> {
+		let rate = ConversionRateToNative::<T>::get(asset_id).ok_or(Error::<T>::UnknownAssetId)?;
+		Ok(rate.saturating_mul_int(balance))
+	}
+}
@@ -0,0 +1,156 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2020 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// Tests for Asset Rate Pallet
+
+#![cfg(test)]
+
+use super::*;
+
+use frame_support::{
+	assert_ok, assert_noop, impl_outer_origin, impl_outer_event, parameter_types,
+};
+use frame_system::EnsureRoot;
+use sp_core::H256;
+use sp_runtime::{Perbill, traits::{BadOrigin, BlakeTwo256, IdentityLookup}, testing::Header};
+use crate as asset_rate;
+
+impl_outer_origin! {
+	pub enum Origin for Test where system = frame_system {}
+}
+impl_outer_event! {
+	pub enum TestEvent for Test {
+		frame_system<T>,
+		asset_rate<T>,
+	}
+}
+
+#[derive(Clone, Eq, PartialEq)]
+pub struct Test;
+parameter_types! {
+	pub const BlockHashCount: u64 = 250;
+	pub const MaximumBlockWeight: Weight = 1024;
+	pub const MaximumBlockLength: u32 = 2 * 1024;
+	pub const AvailableBlockRatio: Perbill = Perbill::one();
+}
+impl frame_system::Trait for Test {
+	type BaseCallFilter = ();
+	type Origin = Origin;
+	type Index = u64;
+	type Call = ();
+	type BlockNumber = u64;
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type AccountId = u64;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type Header = Header;
+	type Event = TestEvent;
+	type BlockHashCount = BlockHashCount;
+	type MaximumBlockWeight = MaximumBlockWeight;
+	type DbWeight = ();
+	type BlockExecutionWeight = ();
+	type ExtrinsicBaseWeight = ();
+	type MaximumExtrinsicWeight = MaximumBlockWeight;
+	type AvailableBlockRatio = AvailableBlockRatio;
+	type MaximumBlockLength = MaximumBlockLength;
+	type Version = ();
+	type PalletInfo = ();
+	type AccountData = ();
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+}
+impl Trait for Test {
+	type Event = TestEvent;
+	type RateOrigin = EnsureRoot<u64>;
+	type AssetId = u32;
+	type WeightInfo = ();
+}
+type System = frame_system::Module<Test>;
+type AssetRate = Module<Test>;
+
+pub(crate) fn new_test_ext() -> sp_io::TestExternalities {
+	let t = frame_system::GenesisConfig::default().build_storage::<Test>().unwrap();
+	let mut ext = sp_io::TestExternalities::new(t);
+	ext.execute_with(|| System::set_block_number(1));
+	ext
+}
+
+fn last_event() -> TestEvent {
+	System::events().pop().expect("Event expected").event
+}
+
+#[test]
+fn create_works() {
+	new_test_ext().execute_with(|| {
+		let rate = |n: u64| FixedU128::saturating_from_integer(n);
+		assert_noop!(AssetRate::create(Origin::signed(1), 0, rate(2)), BadOrigin);
+		assert_ok!(AssetRate::create(Origin::root(), 0, rate(2)));
+		assert_eq!(last_event(), TestEvent::asset_rate(RawEvent::Created(0, rate(2))));
+		assert_eq!(AssetRate::conversion_rate_to_native(0), Some(rate(2)));
+
+		assert_noop!(
+			AssetRate::create(Origin::root(), 0, rate(3)),
+			Error::<Test>::AlreadyExists,
+		);
+	});
+}
+
+#[test]
+fn update_and_remove_work() {
+	new_test_ext().execute_with(|| {
+		let rate = |n: u64| FixedU128::saturating_from_integer(n);
+		assert_noop!(
+			AssetRate::update(Origin::root(), 0, rate(3)),
+			Error::<Test>::UnknownAssetId,
+		);
+		assert_noop!(AssetRate::remove(Origin::root(), 0), Error::<Test>::UnknownAssetId);
+
+		assert_ok!(AssetRate::create(Origin::root(), 0, rate(2)));
+		assert_noop!(AssetRate::update(Origin::signed(1), 0, rate(3)), BadOrigin);
+		assert_ok!(AssetRate::update(Origin::root(), 0, rate(3)));
+		assert_eq!(last_event(), TestEvent::asset_rate(RawEvent::Updated(0, rate(2), rate(3))));
+		assert_eq!(AssetRate::conversion_rate_to_native(0), Some(rate(3)));
+
+		assert_noop!(AssetRate::remove(Origin::signed(1), 0), BadOrigin);
+		assert_ok!(AssetRate::remove(Origin::root(), 0));
+		assert_eq!(last_event(), TestEvent::asset_rate(RawEvent::Removed(0)));
+		assert_eq!(AssetRate::conversion_rate_to_native(0), None);
+	});
+}
+
+#[test]
+fn conversion_uses_the_rate_of_the_asset() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(
+			<AssetRate as ConversionFromAssetBalance<u64, u32, u64>>::from_asset_balance(10, 0),
+			Err(Error::<Test>::UnknownAssetId.into()),
+		);
+
+		assert_ok!(AssetRate::create(Origin::root(), 0, FixedU128::saturating_from_rational(5, 2)));
+		assert_ok!(AssetRate::create(Origin::root(), 1, FixedU128::saturating_from_rational(1, 3)));
+		assert_eq!(
+			<AssetRate as ConversionFromAssetBalance<u64, u32, u64>>::from_asset_balance(10, 0),
+			Ok(25),
+		);
+		// Amounts are rounded down.
+		assert_eq!(
+			<AssetRate as ConversionFromAssetBalance<u64, u32, u64>>::from_asset_balance(10, 1),
+			Ok(3),
+		);
+	});
+}
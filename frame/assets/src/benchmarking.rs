@@ -0,0 +1,391 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2020 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// Benchmarks for Assets Pallet
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use sp_runtime::traits::Bounded;
+use frame_system::{RawOrigin as SystemOrigin, EventRecord};
+use frame_benchmarking::{benchmarks, account, whitelisted_caller, whitelist_account};
+
+use crate::Module as Assets;
+
+const SEED: u32 = 0;
+
+fn create_default_asset<T: Trait>() -> (T::AccountId, <T::Lookup as StaticLookup>::Source) {
+	let caller: T::AccountId = whitelisted_caller();
+	let caller_lookup = T::Lookup::unlookup(caller.clone());
+	let root = SystemOrigin::Root.into();
+	assert!(Assets::<T>::force_create(
+		root,
+		Default::default(),
+		caller_lookup.clone(),
+		1u32.into(),
+	).is_ok());
+	(caller, caller_lookup)
+}
+
+fn create_default_minted_asset<T: Trait>(amount: T::Balance)
+	-> (T::AccountId, <T::Lookup as StaticLookup>::Source)
+{
+	let (caller, caller_lookup) = create_default_asset::<T>();
+	assert!(Assets::<T>::mint(
+		SystemOrigin::Signed(caller.clone()).into(),
+		Default::default(),
+		caller_lookup.clone(),
+		amount,
+	).is_ok());
+	(caller, caller_lookup)
+}
+
+fn add_accounts<T: Trait>(minter: T::AccountId, n: u32) {
+	let origin = SystemOrigin::Signed(minter);
+	for i in 0..n {
+		let target = account("holder", i, SEED);
+		let target_lookup = T::Lookup::unlookup(target);
+		assert!(Assets::<T>::mint(origin.clone().into(), Default::default(), target_lookup, 100u32.into()).is_ok());
+	}
+}
+
+fn add_approvals<T: Trait>(minter: T::AccountId, n: u32) {
+	T::Currency::make_free_balance_be(&minter, DepositBalanceOf::<T>::max_value());
+	let minter_lookup = T::Lookup::unlookup(minter.clone());
+	let origin = SystemOrigin::Signed(minter);
+	assert!(Assets::<T>::mint(origin.clone().into(), Default::default(), minter_lookup, (100 * (n + 1)).into()).is_ok());
+	for i in 0..n {
+		let target = account("approval", i, SEED);
+		let target_lookup = T::Lookup::unlookup(target);
+		assert!(Assets::<T>::approve_transfer(origin.clone().into(), Default::default(), target_lookup, 100u32.into()).is_ok());
+	}
+}
+
+fn assert_last_event<T: Trait>(generic_event: <T as Trait>::Event) {
+	let events = frame_system::Module::<T>::events();
+	let system_event: <T as frame_system::Trait>::Event = generic_event.into();
+	// compare to the last event record
+	let EventRecord { event, .. } = &events[events.len() - 1];
+	assert_eq!(event, &system_event);
+}
+
+benchmarks! {
+	_ { }
+
+	create {
+		let caller: T::AccountId = whitelisted_caller();
+		let caller_lookup = T::Lookup::unlookup(caller.clone());
+		T::Currency::make_free_balance_be(&caller, DepositBalanceOf::<T>::max_value());
+	}: _(SystemOrigin::Signed(caller.clone()), Default::default(), caller_lookup, 1u32.into())
+	verify {
+		assert_last_event::<T>(RawEvent::Created(Default::default(), caller.clone(), caller).into());
+	}
+
+	force_create {
+		let caller: T::AccountId = whitelisted_caller();
+		let caller_lookup = T::Lookup::unlookup(caller.clone());
+	}: _(SystemOrigin::Root, Default::default(), caller_lookup, 1u32.into())
+	verify {
+		assert_last_event::<T>(RawEvent::ForceCreated(Default::default(), caller).into());
+	}
+
+	destroy {
+		let c in 0 .. 5_000;
+		let a in 0 .. 500;
+		let (caller, _) = create_default_asset::<T>();
+		add_accounts::<T>(caller.clone(), c);
+		add_approvals::<T>(caller.clone(), a);
+		let witness = DestroyWitness {
+			accounts: Asset::<T>::get(T::AssetId::default()).unwrap().accounts,
+			approvals: a,
+		};
+	}: _(SystemOrigin::Signed(caller), Default::default(), witness)
+	verify {
+		assert_last_event::<T>(RawEvent::Destroyed(Default::default()).into());
+	}
+
+	mint {
+		let (caller, caller_lookup) = create_default_asset::<T>();
+		let amount = T::Balance::from(100u32);
+	}: _(SystemOrigin::Signed(caller.clone()), Default::default(), caller_lookup, amount)
+	verify {
+		assert_last_event::<T>(RawEvent::Issued(Default::default(), caller, amount).into());
+	}
+
+	burn {
+		let amount = T::Balance::from(100u32);
+		let (caller, caller_lookup) = create_default_minted_asset::<T>(amount);
+	}: _(SystemOrigin::Signed(caller.clone()), Default::default(), caller_lookup, amount)
+	verify {
+		assert_last_event::<T>(RawEvent::Burned(Default::default(), caller, amount).into());
+	}
+
+	transfer {
+		let amount = T::Balance::from(100u32);
+		let (caller, caller_lookup) = create_default_minted_asset::<T>(amount);
+		let target: T::AccountId = account("target", 0, SEED);
+		let target_lookup = T::Lookup::unlookup(target.clone());
+	}: _(SystemOrigin::Signed(caller.clone()), Default::default(), target_lookup, amount)
+	verify {
+		assert_last_event::<T>(RawEvent::Transferred(Default::default(), caller, target, amount).into());
+	}
+
+	force_transfer {
+		let amount = T::Balance::from(100u32);
+		let (caller, caller_lookup) = create_default_minted_asset::<T>(amount);
+		let target: T::AccountId = account("target", 0, SEED);
+		let target_lookup = T::Lookup::unlookup(target.clone());
+	}: _(SystemOrigin::Signed(caller.clone()), Default::default(), caller_lookup, target_lookup, amount)
+	verify {
+		assert_last_event::<T>(
+			RawEvent::ForceTransferred(Default::default(), caller, target, amount).into()
+		);
+	}
+
+	freeze {
+		let (caller, caller_lookup) = create_default_minted_asset::<T>(100u32.into());
+	}: _(SystemOrigin::Signed(caller.clone()), Default::default(), caller_lookup)
+	verify {
+		assert_last_event::<T>(RawEvent::Frozen(Default::default(), caller).into());
+	}
+
+	thaw {
+		let (caller, caller_lookup) = create_default_minted_asset::<T>(100u32.into());
+		assert!(Assets::<T>::freeze(
+			SystemOrigin::Signed(caller.clone()).into(),
+			Default::default(),
+			caller_lookup.clone(),
+		).is_ok());
+	}: _(SystemOrigin::Signed(caller.clone()), Default::default(), caller_lookup)
+	verify {
+		assert_last_event::<T>(RawEvent::Thawed(Default::default(), caller).into());
+	}
+
+	freeze_asset {
+		let (caller, _) = create_default_minted_asset::<T>(100u32.into());
+	}: _(SystemOrigin::Signed(caller.clone()), Default::default())
+	verify {
+		assert_last_event::<T>(RawEvent::AssetFrozen(Default::default()).into());
+	}
+
+	thaw_asset {
+		let (caller, _) = create_default_minted_asset::<T>(100u32.into());
+		assert!(Assets::<T>::freeze_asset(
+			SystemOrigin::Signed(caller.clone()).into(),
+			Default::default(),
+		).is_ok());
+	}: _(SystemOrigin::Signed(caller.clone()), Default::default())
+	verify {
+		assert_last_event::<T>(RawEvent::AssetThawed(Default::default()).into());
+	}
+
+	transfer_ownership {
+		let (caller, _) = create_default_asset::<T>();
+		let target: T::AccountId = account("target", 0, SEED);
+		let target_lookup = T::Lookup::unlookup(target.clone());
+	}: _(SystemOrigin::Signed(caller), Default::default(), target_lookup)
+	verify {
+		assert_last_event::<T>(RawEvent::OwnerChanged(Default::default(), target).into());
+	}
+
+	set_team {
+		let (caller, _) = create_default_asset::<T>();
+		let target0 = T::Lookup::unlookup(account("target", 0, SEED));
+		let target1 = T::Lookup::unlookup(account("target", 1, SEED));
+		let target2 = T::Lookup::unlookup(account("target", 2, SEED));
+	}: _(SystemOrigin::Signed(caller), Default::default(), target0.clone(), target1.clone(), target2.clone())
+	verify {
+		assert_last_event::<T>(RawEvent::TeamChanged(
+			Default::default(),
+			account("target", 0, SEED),
+			account("target", 1, SEED),
+			account("target", 2, SEED),
+		).into());
+	}
+
+	set_metadata {
+		let n in 0 .. T::StringLimit::get();
+		let s in 0 .. T::StringLimit::get();
+
+		let name = vec![0u8; n as usize];
+		let symbol = vec![0u8; s as usize];
+		let decimals = 12;
+
+		let (caller, _) = create_default_asset::<T>();
+		T::Currency::make_free_balance_be(&caller, DepositBalanceOf::<T>::max_value());
+	}: _(SystemOrigin::Signed(caller), Default::default(), name.clone(), symbol.clone(), decimals)
+	verify {
+		assert_last_event::<T>(RawEvent::MetadataSet(Default::default(), name, symbol, decimals).into());
+	}
+
+	clear_metadata {
+		let (caller, _) = create_default_asset::<T>();
+		T::Currency::make_free_balance_be(&caller, DepositBalanceOf::<T>::max_value());
+		let dummy = vec![0u8; T::StringLimit::get() as usize];
+		let origin = SystemOrigin::Signed(caller.clone()).into();
+		Assets::<T>::set_metadata(origin, Default::default(), dummy.clone(), dummy, 12)?;
+	}: _(SystemOrigin::Signed(caller), Default::default())
+	verify {
+		assert_last_event::<T>(RawEvent::MetadataCleared(Default::default()).into());
+	}
+
+	force_set_metadata {
+		let n in 0 .. T::StringLimit::get();
+		let s in 0 .. T::StringLimit::get();
+
+		let name = vec![0u8; n as usize];
+		let symbol = vec![0u8; s as usize];
+		let decimals = 12;
+
+		create_default_asset::<T>();
+	}: _(SystemOrigin::Root, Default::default(), name.clone(), symbol.clone(), decimals)
+	verify {
+		assert_last_event::<T>(RawEvent::MetadataSet(Default::default(), name, symbol, decimals).into());
+	}
+
+	force_clear_metadata {
+		let (caller, _) = create_default_asset::<T>();
+		T::Currency::make_free_balance_be(&caller, DepositBalanceOf::<T>::max_value());
+		let dummy = vec![0u8; T::StringLimit::get() as usize];
+		let origin = SystemOrigin::Signed(caller).into();
+		Assets::<T>::set_metadata(origin, Default::default(), dummy.clone(), dummy, 12)?;
+	}: _(SystemOrigin::Root, Default::default())
+	verify {
+		assert_last_event::<T>(RawEvent::MetadataCleared(Default::default()).into());
+	}
+
+	force_asset_status {
+		let (caller, caller_lookup) = create_default_asset::<T>();
+		T::Currency::make_free_balance_be(&caller, DepositBalanceOf::<T>::max_value());
+		let dummy = vec![0u8; T::StringLimit::get() as usize];
+		let origin = SystemOrigin::Signed(caller.clone()).into();
+		Assets::<T>::set_metadata(origin, Default::default(), dummy.clone(), dummy, 12)?;
+		let target: T::AccountId = account("target", 0, SEED);
+		let target_lookup = T::Lookup::unlookup(target.clone());
+		T::Currency::make_free_balance_be(&target, T::Currency::minimum_balance());
+	}: _(
+		SystemOrigin::Root,
+		Default::default(),
+		target_lookup,
+		caller_lookup.clone(),
+		caller_lookup.clone(),
+		caller_lookup,
+		100u32.into(),
+		true
+	)
+	verify {
+		assert_last_event::<T>(RawEvent::AssetStatusChanged(Default::default()).into());
+	}
+
+	approve_transfer {
+		let (caller, _) = create_default_minted_asset::<T>(100u32.into());
+		T::Currency::make_free_balance_be(&caller, DepositBalanceOf::<T>::max_value());
+
+		let id = Default::default();
+		let delegate: T::AccountId = account("delegate", 0, SEED);
+		let delegate_lookup = T::Lookup::unlookup(delegate.clone());
+		let amount = 100u32.into();
+	}: _(SystemOrigin::Signed(caller.clone()), id, delegate_lookup, amount)
+	verify {
+		assert_last_event::<T>(RawEvent::ApprovedTransfer(id, caller, delegate, amount).into());
+	}
+
+	transfer_approved {
+		let (owner, owner_lookup) = create_default_minted_asset::<T>(100u32.into());
+		T::Currency::make_free_balance_be(&owner, DepositBalanceOf::<T>::max_value());
+
+		let id = Default::default();
+		let delegate: T::AccountId = account("delegate", 0, SEED);
+		whitelist_account!(delegate);
+		let delegate_lookup = T::Lookup::unlookup(delegate.clone());
+		let amount = 100u32.into();
+		let origin = SystemOrigin::Signed(owner.clone()).into();
+		Assets::<T>::approve_transfer(origin, id, delegate_lookup, amount)?;
+
+		let dest: T::AccountId = account("dest", 0, SEED);
+		let dest_lookup = T::Lookup::unlookup(dest.clone());
+	}: _(SystemOrigin::Signed(delegate.clone()), id, owner_lookup, dest_lookup, amount)
+	verify {
+		assert!(T::Currency::reserved_balance(&owner).is_zero());
+		assert_last_event::<T>(RawEvent::TransferredApproved(id, owner, delegate, dest, amount).into());
+	}
+
+	cancel_approval {
+		let (caller, _) = create_default_minted_asset::<T>(100u32.into());
+		T::Currency::make_free_balance_be(&caller, DepositBalanceOf::<T>::max_value());
+
+		let id = Default::default();
+		let delegate: T::AccountId = account("delegate", 0, SEED);
+		let delegate_lookup = T::Lookup::unlookup(delegate.clone());
+		let amount = 100u32.into();
+		let origin = SystemOrigin::Signed(caller.clone()).into();
+		Assets::<T>::approve_transfer(origin, id, delegate_lookup.clone(), amount)?;
+	}: _(SystemOrigin::Signed(caller.clone()), id, delegate_lookup)
+	verify {
+		assert_last_event::<T>(RawEvent::ApprovalCancelled(id, caller, delegate).into());
+	}
+
+	force_cancel_approval {
+		let (caller, caller_lookup) = create_default_minted_asset::<T>(100u32.into());
+		T::Currency::make_free_balance_be(&caller, DepositBalanceOf::<T>::max_value());
+
+		let id = Default::default();
+		let delegate: T::AccountId = account("delegate", 0, SEED);
+		let delegate_lookup = T::Lookup::unlookup(delegate.clone());
+		let amount = 100u32.into();
+		let origin = SystemOrigin::Signed(caller.clone()).into();
+		Assets::<T>::approve_transfer(origin, id, delegate_lookup.clone(), amount)?;
+	}: _(SystemOrigin::Signed(caller.clone()), id, caller_lookup, delegate_lookup)
+	verify {
+		assert_last_event::<T>(RawEvent::ApprovalCancelled(id, caller, delegate).into());
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::tests::{new_test_ext, Test};
+	use frame_support::assert_ok;
+
+	#[test]
+	fn test_benchmarks() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(test_benchmark_create::<Test>());
+			assert_ok!(test_benchmark_force_create::<Test>());
+			assert_ok!(test_benchmark_destroy::<Test>());
+			assert_ok!(test_benchmark_mint::<Test>());
+			assert_ok!(test_benchmark_burn::<Test>());
+			assert_ok!(test_benchmark_transfer::<Test>());
+			assert_ok!(test_benchmark_force_transfer::<Test>());
+			assert_ok!(test_benchmark_freeze::<Test>());
+			assert_ok!(test_benchmark_thaw::<Test>());
+			assert_ok!(test_benchmark_freeze_asset::<Test>());
+			assert_ok!(test_benchmark_thaw_asset::<Test>());
+			assert_ok!(test_benchmark_transfer_ownership::<Test>());
+			assert_ok!(test_benchmark_set_team::<Test>());
+			assert_ok!(test_benchmark_set_metadata::<Test>());
+			assert_ok!(test_benchmark_clear_metadata::<Test>());
+			assert_ok!(test_benchmark_force_set_metadata::<Test>());
+			assert_ok!(test_benchmark_force_clear_metadata::<Test>());
+			assert_ok!(test_benchmark_force_asset_status::<Test>());
+			assert_ok!(test_benchmark_approve_transfer::<Test>());
+			assert_ok!(test_benchmark_transfer_approved::<Test>());
+			assert_ok!(test_benchmark_cancel_approval::<Test>());
+			assert_ok!(test_benchmark_force_cancel_approval::<Test>());
+		});
+	}
+}
@@ -0,0 +1,314 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2020 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Implementations of the `fungibles` traits for the Assets module.
+
+use super::*;
+use frame_support::traits::{fungibles, WithdrawConsequence, DepositConsequence};
+
+impl<T: Trait> fungibles::Inspect<T::AccountId> for Module<T> {
+	type AssetId = T::AssetId;
+	type Balance = T::Balance;
+
+	fn total_issuance(asset: T::AssetId) -> T::Balance {
+		Self::total_supply(asset)
+	}
+
+	fn minimum_balance(asset: T::AssetId) -> T::Balance {
+		Asset::<T>::get(asset).map(|x| x.min_balance).unwrap_or_else(Zero::zero)
+	}
+
+	fn balance(asset: T::AssetId, who: &T::AccountId) -> T::Balance {
+		// The held funds are part of the account balance, but not of the balance in the sense of
+		// `fungibles`, which is the same quantity as the free balance of `pallet-balances`.
+		Account::<T>::get(asset, who).balance.saturating_sub(Holds::<T>::get(asset, who))
+	}
+
+	fn reducible_balance(asset: T::AssetId, who: &T::AccountId, keep_alive: bool) -> T::Balance {
+		let details = match Asset::<T>::get(asset) {
+			Some(details) => details,
+			None => return Zero::zero(),
+		};
+		let account = Account::<T>::get(asset, who);
+		if details.is_frozen || account.is_frozen {
+			Zero::zero()
+		} else {
+			let held = Holds::<T>::get(asset, who);
+			Self::reducible(&account, held, details.min_balance, keep_alive)
+		}
+	}
+
+	fn can_deposit(asset: T::AssetId, who: &T::AccountId, amount: T::Balance)
+		-> DepositConsequence
+	{
+		let details = match Asset::<T>::get(asset) {
+			Some(details) => details,
+			None => return DepositConsequence::UnknownAsset,
+		};
+		if details.supply.checked_add(&amount).is_none() {
+			return DepositConsequence::Overflow
+		}
+		let account = Account::<T>::get(asset, who);
+		match account.balance.checked_add(&amount) {
+			None => DepositConsequence::Overflow,
+			Some(balance) if balance < details.min_balance => DepositConsequence::BelowMinimum,
+			Some(_) => DepositConsequence::Success,
+		}
+	}
+
+	fn can_withdraw(
+		asset: T::AssetId,
+		who: &T::AccountId,
+		amount: T::Balance,
+	) -> WithdrawConsequence<T::Balance> {
+		let details = match Asset::<T>::get(asset) {
+			Some(details) => details,
+			None => return WithdrawConsequence::UnknownAsset,
+		};
+		if details.supply.checked_sub(&amount).is_none() {
+			return WithdrawConsequence::Underflow
+		}
+		let account = Account::<T>::get(asset, who);
+		if details.is_frozen || account.is_frozen {
+			return WithdrawConsequence::Frozen
+		}
+		let held = Holds::<T>::get(asset, who);
+		match account.balance.checked_sub(&amount) {
+			None => WithdrawConsequence::NoFunds,
+			Some(rest) if rest < held => WithdrawConsequence::Frozen,
+			Some(rest) if rest < details.min_balance && !held.is_zero() =>
+				WithdrawConsequence::WouldDie,
+			Some(rest) if rest < details.min_balance => WithdrawConsequence::ReducedToZero(rest),
+			Some(_) => WithdrawConsequence::Success,
+		}
+	}
+}
+
+impl<T: Trait> fungibles::Mutate<T::AccountId> for Module<T> {
+	fn mint_into(asset: T::AssetId, who: &T::AccountId, amount: T::Balance) -> DispatchResult {
+		if amount.is_zero() { return Ok(()) }
+
+		Self::do_mint(asset, who, amount, None)?;
+		Self::deposit_event(RawEvent::Issued(asset, who.clone(), amount));
+		Ok(())
+	}
+
+	fn burn_from(asset: T::AssetId, who: &T::AccountId, amount: T::Balance)
+		-> Result<T::Balance, DispatchError>
+	{
+		if amount.is_zero() { return Ok(amount) }
+
+		<Self as fungibles::Inspect<_>>::can_withdraw(asset, who, amount).into_result()?;
+		let burned = Self::do_burn(asset, who, amount, None)?;
+		Self::deposit_event(RawEvent::Burned(asset, who.clone(), burned));
+		Ok(burned)
+	}
+}
+
+impl<T: Trait> fungibles::Transfer<T::AccountId> for Module<T> {
+	fn transfer(
+		asset: T::AssetId,
+		source: &T::AccountId,
+		dest: &T::AccountId,
+		amount: T::Balance,
+		keep_alive: bool,
+	) -> Result<T::Balance, DispatchError> {
+		if amount.is_zero() { return Ok(amount) }
+
+		if keep_alive {
+			let reducible = <Self as fungibles::Inspect<_>>::reducible_balance(asset, source, true);
+			ensure!(amount <= reducible, Error::<T>::WouldDie);
+		}
+		let transferred = Self::do_transfer(asset, source, dest, amount, None, true)?;
+		Self::deposit_event(RawEvent::Transferred(asset, source.clone(), dest.clone(), transferred));
+		Ok(transferred)
+	}
+}
+
+impl<T: Trait> fungibles::Unbalanced<T::AccountId> for Module<T> {
+	fn set_balance(asset: T::AssetId, who: &T::AccountId, amount: T::Balance) -> DispatchResult {
+		Asset::<T>::try_mutate(asset, |maybe_details| {
+			let details = maybe_details.as_mut().ok_or(Error::<T>::Unknown)?;
+			// `amount` is the balance in the sense of `Inspect::balance`, the held funds stay on
+			// top of it.
+			let total = amount.checked_add(&Holds::<T>::get(asset, who))
+				.ok_or(Error::<T>::Overflow)?;
+			ensure!(total.is_zero() || total >= details.min_balance, Error::<T>::BelowMinimum);
+
+			let mut account = Account::<T>::get(asset, who);
+			if total < account.balance {
+				ensure!(!details.is_frozen && !account.is_frozen, Error::<T>::Frozen);
+			}
+			match (account.balance.is_zero(), total.is_zero()) {
+				(true, false) => details.accounts = details.accounts.saturating_add(1),
+				(false, true) => details.accounts = details.accounts.saturating_sub(1),
+				_ => {},
+			}
+			if total.is_zero() {
+				Account::<T>::remove(asset, who);
+			} else {
+				account.balance = total;
+				Account::<T>::insert(asset, who, &account);
+			}
+			Ok(())
+		})
+	}
+
+	fn set_total_issuance(asset: T::AssetId, amount: T::Balance) {
+		Asset::<T>::mutate(asset, |maybe_details| {
+			if let Some(details) = maybe_details {
+				details.supply = amount;
+			}
+		});
+	}
+}
+
+impl<T: Trait> fungibles::InspectHold<T::AccountId> for Module<T> {
+	fn balance_on_hold(asset: T::AssetId, who: &T::AccountId) -> T::Balance {
+		Holds::<T>::get(asset, who)
+	}
+
+	fn can_hold(asset: T::AssetId, who: &T::AccountId, amount: T::Balance) -> bool {
+		let details = match Asset::<T>::get(asset) {
+			Some(details) => details,
+			None => return false,
+		};
+		let account = Account::<T>::get(asset, who);
+		let held = Holds::<T>::get(asset, who);
+		!details.is_frozen && !account.is_frozen &&
+			account.balance.saturating_sub(held) >= amount
+	}
+}
+
+impl<T: Trait> fungibles::MutateHold<T::AccountId> for Module<T> {
+	fn hold(asset: T::AssetId, who: &T::AccountId, amount: T::Balance) -> DispatchResult {
+		if amount.is_zero() { return Ok(()) }
+
+		let details = Asset::<T>::get(asset).ok_or(Error::<T>::Unknown)?;
+		let account = Account::<T>::get(asset, who);
+		ensure!(!details.is_frozen && !account.is_frozen, Error::<T>::Frozen);
+		Holds::<T>::try_mutate(asset, who, |held| -> DispatchResult {
+			let new_held = held.checked_add(&amount).ok_or(Error::<T>::Overflow)?;
+			ensure!(new_held <= account.balance, Error::<T>::BalanceLow);
+			*held = new_held;
+			Ok(())
+		})
+	}
+
+	fn release(asset: T::AssetId, who: &T::AccountId, amount: T::Balance, best_effort: bool)
+		-> Result<T::Balance, DispatchError>
+	{
+		Holds::<T>::try_mutate_exists(asset, who, |maybe_held| -> Result<T::Balance, DispatchError> {
+			let held = maybe_held.unwrap_or_else(Zero::zero);
+			let released = if best_effort {
+				amount.min(held)
+			} else {
+				ensure!(amount <= held, Error::<T>::BalanceLow);
+				amount
+			};
+			let rest = held - released;
+			*maybe_held = if rest.is_zero() { None } else { Some(rest) };
+			Ok(released)
+		})
+	}
+
+	fn transfer_held(
+		asset: T::AssetId,
+		source: &T::AccountId,
+		dest: &T::AccountId,
+		amount: T::Balance,
+		best_effort: bool,
+		on_hold: bool,
+	) -> Result<T::Balance, DispatchError> {
+		let held = Holds::<T>::get(asset, source);
+		let amount = if best_effort {
+			amount.min(held)
+		} else {
+			ensure!(amount <= held, Error::<T>::BalanceLow);
+			amount
+		};
+		if amount.is_zero() { return Ok(amount) }
+		if source == dest {
+			if !on_hold {
+				<Self as fungibles::MutateHold<_>>::release(asset, source, amount, false)?;
+			}
+			return Ok(amount)
+		}
+
+		Asset::<T>::try_mutate(asset, |maybe_details| {
+			let details = maybe_details.as_mut().ok_or(Error::<T>::Unknown)?;
+			let mut source_account = Account::<T>::get(asset, source);
+			let remainder = source_account.balance.checked_sub(&amount)
+				.ok_or(Error::<T>::BalanceLow)?;
+			ensure!(
+				remainder.is_zero() || remainder >= details.min_balance,
+				Error::<T>::BelowMinimum,
+			);
+
+			if on_hold {
+				ensure!(Account::<T>::contains_key(asset, dest), Error::<T>::BalanceZero);
+				Self::credit(asset, details, dest, amount)?;
+				Holds::<T>::mutate(asset, dest, |held| *held = held.saturating_add(amount));
+			} else {
+				Self::credit(asset, details, dest, amount)?;
+			}
+
+			Holds::<T>::mutate_exists(asset, source, |maybe_held| {
+				let rest = maybe_held.unwrap_or_else(Zero::zero).saturating_sub(amount);
+				*maybe_held = if rest.is_zero() { None } else { Some(rest) };
+			});
+			source_account.balance = remainder;
+			if remainder.is_zero() {
+				details.accounts = details.accounts.saturating_sub(1);
+				Account::<T>::remove(asset, source);
+			} else {
+				Account::<T>::insert(asset, source, &source_account);
+			}
+			Ok(())
+		})?;
+
+		Self::deposit_event(RawEvent::Transferred(asset, source.clone(), dest.clone(), amount));
+		Ok(amount)
+	}
+}
+
+impl<T: Trait> fungibles::InspectFreeze<T::AccountId> for Module<T> {
+	fn is_frozen(asset: T::AssetId, who: &T::AccountId) -> bool {
+		Asset::<T>::get(asset).map_or(false, |details| details.is_frozen) ||
+			Account::<T>::get(asset, who).is_frozen
+	}
+}
+
+impl<T: Trait> fungibles::MutateFreeze<T::AccountId> for Module<T> {
+	fn freeze(asset: T::AssetId, who: &T::AccountId) -> DispatchResult {
+		ensure!(Asset::<T>::contains_key(asset), Error::<T>::Unknown);
+		ensure!(Account::<T>::contains_key(asset, who), Error::<T>::BalanceZero);
+
+		Account::<T>::mutate(asset, who, |a| a.is_frozen = true);
+		Self::deposit_event(RawEvent::Frozen(asset, who.clone()));
+		Ok(())
+	}
+
+	fn thaw(asset: T::AssetId, who: &T::AccountId) -> DispatchResult {
+		ensure!(Asset::<T>::contains_key(asset), Error::<T>::Unknown);
+		ensure!(Account::<T>::contains_key(asset, who), Error::<T>::BalanceZero);
+
+		Account::<T>::mutate(asset, who, |a| a.is_frozen = false);
+		Self::deposit_event(RawEvent::Thawed(asset, who.clone()));
+		Ok(())
+	}
+}
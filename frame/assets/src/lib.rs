@@ -22,10 +22,13 @@
 //! ## Overview
 //!
 //! The Assets module provides functionality for asset management of fungible asset classes
-//! with a fixed supply, including:
+//! with a managed supply, including:
 //!
-//! * Asset Issuance
-//! * Asset Transfer
+//! * Asset Creation
+//! * Asset Issuance (minting) and Burning
+//! * Asset Transfer, directly or through an approved delegate
+//! * Asset and Account Freezing and Thawing
+//! * Asset Metadata
 //! * Asset Destruction
 //!
 //! To use it in your runtime, you need to implement the assets [`Trait`](./trait.Trait.html).
@@ -34,31 +37,77 @@
 //!
 //! ### Terminology
 //!
-//! * **Asset issuance:** The creation of a new asset, whose total supply will belong to the
-//!   account that issues the asset.
-//! * **Asset transfer:** The action of transferring assets from one account to another.
-//! * **Asset destruction:** The process of an account removing its entire holding of an asset.
-//! * **Fungible asset:** An asset whose units are interchangeable.
-//! * **Non-fungible asset:** An asset for which each unit has unique characteristics.
+//! * **Admin:** An account ID uniquely privileged to be able to unfreeze (thaw) an account and its
+//!   assets, as well as forcibly transfer a particular class of assets between arbitrary accounts
+//!   and reduce the balance of a particular class of assets of arbitrary accounts.
+//! * **Asset issuance/minting**: The creation of a new asset, whose total supply will belong to the
+//!   account that issues the asset. This is a privileged operation.
+//! * **Asset transfer**: The reduction of the balance of an asset of one account with the
+//!   corresponding increase in the balance of another.
+//! * **Asset destruction**: The process of reduce the balance of an asset of one account. This is
+//!   a privileged operation.
+//! * **Fungible asset**: An asset whose units are interchangeable.
+//! * **Issuer**: An account ID uniquely privileged to be able to mint a particular class of assets.
+//! * **Freezer**: An account ID uniquely privileged to be able to freeze an account from
+//!   transferring a particular class of assets.
+//! * **Freezing**: Removing the possibility of an unpermissioned transfer of an asset from a
+//!   particular account.
+//! * **Non-fungible asset**: An asset for which each unit has unique characteristics.
+//! * **Owner**: An account ID uniquely privileged to be able to destroy a particular asset class,
+//!   or to set the Issuer, Freezer or Admin of that asset class.
+//! * **Approval**: The act of allowing an account the permission to transfer some balance of
+//!   asset from the approving account into some third-party destination account.
+//! * **Minimum balance**: The minimum balance required for an account to hold a particular asset
+//!   class. An account whose balance would drop below it has its whole balance removed instead.
+//! * **Deposit**: An amount of the native currency reserved from the owner of an asset class on
+//!   its creation and for its metadata, or from an account approving a transfer, and returned when
+//!   the corresponding item is removed.
 //!
 //! ### Goals
 //!
 //! The assets system in Substrate is designed to make the following possible:
 //!
-//! * Issue a unique asset to its creator's account.
-//! * Move assets between accounts.
-//! * Remove an account's balance of an asset when requested by that account's owner and update
-//!   the asset's total supply.
+//! * Create a new asset class, whose total supply is controlled by its issuer.
+//! * Move assets between accounts, directly or through an approved delegate.
+//! * Let the team of an asset class mint, burn, freeze and thaw its units.
+//! * Attach a name, a symbol and a number of decimals to an asset class.
+//! * Let a privileged origin, e.g. governance, create, destroy and reconfigure any asset class.
 //!
 //! ## Interface
 //!
-//! ### Dispatchable Functions
+//! ### Permissionless Functions
 //!
-//! * `issue` - Issues the total supply of a new fungible asset to the account of the caller of the function.
-//! * `transfer` - Transfers an `amount` of units of fungible asset `id` from the balance of
-//! the function caller's account (`origin`) to a `target` account.
-//! * `destroy` - Destroys the entire holding of a fungible asset `id` associated with the account
-//! that called the function.
+//! * `create`: Creates a new asset class, taking the required deposit.
+//! * `transfer`: Transfer sender's assets to another account.
+//! * `approve_transfer`: Create or increase an delegated transfer.
+//! * `cancel_approval`: Rescind a previous approval.
+//! * `transfer_approved`: Transfer third-party's assets to another account.
+//!
+//! ### Permissioned Functions
+//!
+//! * `force_create`: Creates a new asset class without taking any deposit.
+//! * `force_set_metadata`: Set the metadata of an asset class.
+//! * `force_clear_metadata`: Remove the metadata of an asset class.
+//! * `force_asset_status`: Alter an asset class's attributes.
+//! * `force_cancel_approval`: Rescind a previous approval.
+//!
+//! ### Privileged Functions
+//!
+//! * `destroy`: Destroys an entire asset class; called by the asset class's Owner, or by the
+//!   forcing origin.
+//! * `mint`: Increases the asset balance of an account; called by the asset class's Issuer.
+//! * `burn`: Decreases the asset balance of an account; called by the asset class's Admin.
+//! * `force_transfer`: Transfers between arbitrary accounts; called by the asset class's Admin.
+//! * `freeze`: Disallows further `transfer`s from an account; called by the asset class's Freezer.
+//! * `thaw`: Allows further `transfer`s from an account; called by the asset class's Admin.
+//! * `freeze_asset`: Disallows further `transfer`s of an asset class; called by the asset class's
+//!   Freezer.
+//! * `thaw_asset`: Allows further `transfer`s of an asset class; called by the asset class's Admin.
+//! * `transfer_ownership`: Changes an asset class's Owner; called by the asset class's Owner.
+//! * `set_team`: Changes an asset class's Admin, Freezer and Issuer; called by the asset class's
+//!   Owner.
+//! * `set_metadata`: Set the metadata of an asset class; called by the asset class's Owner.
+//! * `clear_metadata`: Remove the metadata of an asset class; called by the asset class's Owner.
 //!
 //! Please refer to the [`Call`](./enum.Call.html) enum and its associated variants for documentation on each function.
 //!
@@ -67,63 +116,25 @@
 //!
 //! * `balance` - Get the asset `id` balance of `who`.
 //! * `total_supply` - Get the total supply of an asset `id`.
+//! * `withdraw` - Remove some units of an asset `id` from the balance of `who`, returning them
+//!   as a [`NegativeImbalance`](./struct.NegativeImbalance.html).
+//! * `resolve` - Credit the units of a `NegativeImbalance` to the balance of `who`.
 //!
 //! Please refer to the [`Module`](./struct.Module.html) struct for details on publicly available functions.
 //!
-//! ## Usage
-//!
-//! The following example shows how to use the Assets module in your runtime by exposing public functions to:
-//!
-//! * Issue a new fungible asset for a token distribution event (airdrop).
-//! * Query the fungible asset holding balance of an account.
-//! * Query the total supply of a fungible asset that has been issued.
-//!
-//! ### Prerequisites
-//!
-//! Import the Assets module and types and derive your runtime's configuration traits from the Assets module trait.
-//!
-//! ### Simple Code Snippet
-//!
-//! ```rust,ignore
-//! use pallet_assets as assets;
-//! use frame_support::{decl_module, dispatch, ensure};
-//! use frame_system::ensure_signed;
-//!
-//! pub trait Trait: assets::Trait { }
-//!
-//! decl_module! {
-//! 	pub struct Module<T: Trait> for enum Call where origin: T::Origin {
-//! 		pub fn issue_token_airdrop(origin) -> dispatch::DispatchResult {
-//! 			let sender = ensure_signed(origin).map_err(|e| e.as_str())?;
-//!
-//! 			const ACCOUNT_ALICE: u64 = 1;
-//! 			const ACCOUNT_BOB: u64 = 2;
-//! 			const COUNT_AIRDROP_RECIPIENTS: u64 = 2;
-//! 			const TOKENS_FIXED_SUPPLY: u64 = 100;
-//!
-//! 			ensure!(!COUNT_AIRDROP_RECIPIENTS.is_zero(), "Divide by zero error.");
-//!
-//! 			let asset_id = Self::next_asset_id();
-//!
-//! 			<NextAssetId<T>>::mutate(|asset_id| *asset_id += 1);
-//! 			<Balances<T>>::insert((asset_id, &ACCOUNT_ALICE), TOKENS_FIXED_SUPPLY / COUNT_AIRDROP_RECIPIENTS);
-//! 			<Balances<T>>::insert((asset_id, &ACCOUNT_BOB), TOKENS_FIXED_SUPPLY / COUNT_AIRDROP_RECIPIENTS);
-//! 			<TotalSupply<T>>::insert(asset_id, TOKENS_FIXED_SUPPLY);
-//!
-//! 			Self::deposit_event(RawEvent::Issued(asset_id, sender, TOKENS_FIXED_SUPPLY));
-//! 			Ok(())
-//! 		}
-//! 	}
-//! }
-//! ```
+//! The module also implements the
+//! [`fungibles`](../frame_support/traits/fungibles/index.html) traits, so that other
+//! modules can deal with any of its assets without coupling to it. Funds put on hold through
+//! `fungibles::MutateHold` remain part of the balance of the account, but can't be transferred
+//! or burned until they are released, and keep the account alive.
 //!
 //! ## Assumptions
 //!
 //! Below are assumptions that must be held when using this module.  If any of
 //! them are violated, the behavior of this module is undefined.
 //!
-//! * The total count of assets should be less than
-//!   `Trait::AssetId::max_value()`.
+//! * The number of accounts and approvals of an asset class must be bounded, since destroying
+//!   the asset class removes all of them in a single call.
 //!
 //! ## Related Modules
 //!
@@ -133,10 +144,54 @@
 // Ensure we're `no_std` when compiling for Wasm.
 #![cfg_attr(not(feature = "std"), no_std)]
 
-use frame_support::{Parameter, decl_module, decl_event, decl_storage, decl_error, ensure};
-use sp_runtime::traits::{Member, AtLeast32Bit, AtLeast32BitUnsigned, Zero, StaticLookup};
+mod tests;
+mod benchmarking;
+mod default_weight;
+mod impl_fungibles;
+
+use sp_std::prelude::*;
+use codec::{Encode, Decode, HasCompact};
+use frame_support::{
+	Parameter, decl_module, decl_event, decl_storage, decl_error, ensure, RuntimeDebug,
+	dispatch::{DispatchError, DispatchResult, DispatchResultWithPostInfo},
+	traits::{Currency, ReservableCurrency, EnsureOrigin, Get, BalanceStatus, TryDrop},
+	weights::Weight,
+	type_info::TypeInfo,
+};
+use sp_runtime::traits::{
+	Member, AtLeast32BitUnsigned, Zero, StaticLookup, Saturating, CheckedAdd, CheckedSub,
+};
 use frame_system::ensure_signed;
-use sp_runtime::traits::One;
+
+pub use imbalances::NegativeImbalance;
+
+type DepositBalanceOf<T> =
+	<<T as Trait>::Currency as Currency<<T as frame_system::Trait>::AccountId>>::Balance;
+
+pub trait WeightInfo {
+	fn create() -> Weight;
+	fn force_create() -> Weight;
+	fn destroy(c: u32, a: u32, ) -> Weight;
+	fn mint() -> Weight;
+	fn burn() -> Weight;
+	fn transfer() -> Weight;
+	fn force_transfer() -> Weight;
+	fn freeze() -> Weight;
+	fn thaw() -> Weight;
+	fn freeze_asset() -> Weight;
+	fn thaw_asset() -> Weight;
+	fn transfer_ownership() -> Weight;
+	fn set_team() -> Weight;
+	fn set_metadata(n: u32, s: u32, ) -> Weight;
+	fn clear_metadata() -> Weight;
+	fn force_set_metadata(n: u32, s: u32, ) -> Weight;
+	fn force_clear_metadata() -> Weight;
+	fn force_asset_status() -> Weight;
+	fn approve_transfer() -> Weight;
+	fn transfer_approved() -> Weight;
+	fn cancel_approval() -> Weight;
+	fn force_cancel_approval() -> Weight;
+}
 
 /// The module configuration trait.
 pub trait Trait: frame_system::Trait {
@@ -147,119 +202,1164 @@ pub trait Trait: frame_system::Trait {
 	type Balance: Member + Parameter + AtLeast32BitUnsigned + Default + Copy;
 
 	/// The arithmetic type of asset identifier.
-	type AssetId: Parameter + AtLeast32Bit + Default + Copy;
+	type AssetId: Member + Parameter + Default + Copy + HasCompact;
+
+	/// The currency mechanism, used for the deposits.
+	type Currency: ReservableCurrency<Self::AccountId>;
+
+	/// The origin which may forcibly create or destroy an asset class or otherwise alter
+	/// privileged attributes.
+	type ForceOrigin: EnsureOrigin<Self::Origin>;
+
+	/// The basic amount of funds that must be reserved for an asset class.
+	type AssetDeposit: Get<DepositBalanceOf<Self>>;
+
+	/// The basic amount of funds that must be reserved when adding metadata to an asset class.
+	type MetadataDepositBase: Get<DepositBalanceOf<Self>>;
+
+	/// The additional funds that must be reserved for the number of bytes stored in the
+	/// metadata of an asset class.
+	type MetadataDepositPerByte: Get<DepositBalanceOf<Self>>;
+
+	/// The amount of funds that must be reserved when creating a new transfer approval.
+	type ApprovalDeposit: Get<DepositBalanceOf<Self>>;
+
+	/// The maximum length of a name or symbol stored on-chain.
+	type StringLimit: Get<u32>;
+
+	/// Weight information for extrinsics in this pallet.
+	type WeightInfo: WeightInfo;
+}
+
+/// The details of an asset class.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct AssetDetails<Balance, AccountId, DepositBalance> {
+	/// Can change `owner`, `issuer`, `freezer` and `admin` accounts.
+	owner: AccountId,
+	/// Can mint tokens.
+	issuer: AccountId,
+	/// Can thaw tokens, force transfers and burn tokens from any account.
+	admin: AccountId,
+	/// Can freeze tokens.
+	freezer: AccountId,
+	/// The total supply across all accounts.
+	supply: Balance,
+	/// The balance deposited for this asset class. This pays for the data stored here.
+	deposit: DepositBalance,
+	/// The minimum balance of an account holding this asset class.
+	min_balance: Balance,
+	/// The total number of accounts holding this asset class.
+	accounts: u32,
+	/// The total number of approvals of this asset class.
+	approvals: u32,
+	/// Whether the asset class is frozen for non-admin transfers.
+	is_frozen: bool,
+}
+
+/// The balance of an account in an asset class.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, Default, RuntimeDebug, TypeInfo)]
+pub struct AssetBalance<Balance> {
+	/// The balance.
+	balance: Balance,
+	/// Whether the account is frozen.
+	is_frozen: bool,
+}
+
+/// The metadata of an asset class.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, Default, RuntimeDebug, TypeInfo)]
+pub struct AssetMetadata<DepositBalance> {
+	/// The balance deposited for this metadata.
+	///
+	/// This pays for the data stored in this struct.
+	deposit: DepositBalance,
+	/// The user friendly name of this asset. Limited in length by `StringLimit`.
+	name: Vec<u8>,
+	/// The ticker symbol for this asset. Limited in length by `StringLimit`.
+	symbol: Vec<u8>,
+	/// The number of decimals this asset uses to represent one unit.
+	decimals: u8,
+}
+
+/// The key of an approval: the account whose balance may be transferred by the delegate.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct ApprovalKey<AccountId> {
+	/// The owner of the funds that are being approved.
+	owner: AccountId,
+	/// The party to whom transfer of the funds is being delegated.
+	delegate: AccountId,
+}
+
+/// Data concerning an approval.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, Default, RuntimeDebug, TypeInfo)]
+pub struct Approval<Balance, DepositBalance> {
+	/// The amount of funds approved for the balance transfer from the owner to some delegated
+	/// target.
+	amount: Balance,
+	/// The amount reserved on the owner's account to hold this item in storage.
+	deposit: DepositBalance,
+}
+
+/// Witness data for the destruction of an asset class, used to bound the weight of `destroy`.
+#[derive(Copy, Clone, Encode, Decode, Eq, PartialEq, Default, RuntimeDebug, TypeInfo)]
+pub struct DestroyWitness {
+	/// The number of accounts holding the asset.
+	#[codec(compact)]
+	pub accounts: u32,
+	/// The number of approvals of the asset.
+	#[codec(compact)]
+	pub approvals: u32,
+}
+
+decl_storage! {
+	trait Store for Module<T: Trait> as Assets {
+		/// Details of an asset class.
+		Asset get(fn asset): map hasher(blake2_128_concat) T::AssetId
+			=> Option<AssetDetails<T::Balance, T::AccountId, DepositBalanceOf<T>>>;
+
+		/// The number of units of assets held by any given account.
+		Account: double_map
+			hasher(blake2_128_concat) T::AssetId,
+			hasher(blake2_128_concat) T::AccountId
+			=> AssetBalance<T::Balance>;
+
+		/// The part of the balance of an account which is on hold, see `fungibles::MutateHold`.
+		/// Funds on hold can't be transferred or burned and keep the account alive.
+		Holds: double_map
+			hasher(blake2_128_concat) T::AssetId,
+			hasher(blake2_128_concat) T::AccountId
+			=> T::Balance;
+
+		/// Approved balance transfers. First balance is the amount approved for transfer. Second
+		/// is the amount of `T::Currency` reserved for storing this.
+		Approvals: double_map
+			hasher(blake2_128_concat) T::AssetId,
+			hasher(blake2_128_concat) ApprovalKey<T::AccountId>
+			=> Option<Approval<T::Balance, DepositBalanceOf<T>>>;
+
+		/// Metadata of an asset class.
+		Metadata get(fn metadata): map hasher(blake2_128_concat) T::AssetId
+			=> AssetMetadata<DepositBalanceOf<T>>;
+	}
+}
+
+decl_event! {
+	pub enum Event<T> where
+		<T as frame_system::Trait>::AccountId,
+		<T as Trait>::Balance,
+		<T as Trait>::AssetId,
+	{
+		/// Some asset class was created. \[asset_id, creator, owner\]
+		Created(AssetId, AccountId, AccountId),
+		/// Some assets were issued. \[asset_id, owner, amount\]
+		Issued(AssetId, AccountId, Balance),
+		/// Some assets were transferred. \[asset_id, from, to, amount\]
+		Transferred(AssetId, AccountId, AccountId, Balance),
+		/// Some assets were destroyed. \[asset_id, owner, amount\]
+		Burned(AssetId, AccountId, Balance),
+		/// The management team changed \[asset_id, issuer, admin, freezer\]
+		TeamChanged(AssetId, AccountId, AccountId, AccountId),
+		/// The owner changed \[asset_id, owner\]
+		OwnerChanged(AssetId, AccountId),
+		/// Some assets was transferred by an admin. \[asset_id, from, to, amount\]
+		ForceTransferred(AssetId, AccountId, AccountId, Balance),
+		/// Some account `who` was frozen. \[asset_id, who\]
+		Frozen(AssetId, AccountId),
+		/// Some account `who` was thawed. \[asset_id, who\]
+		Thawed(AssetId, AccountId),
+		/// Some asset `asset_id` was frozen. \[asset_id\]
+		AssetFrozen(AssetId),
+		/// Some asset `asset_id` was thawed. \[asset_id\]
+		AssetThawed(AssetId),
+		/// An asset class was destroyed. \[asset_id\]
+		Destroyed(AssetId),
+		/// Some asset class was force-created. \[asset_id, owner\]
+		ForceCreated(AssetId, AccountId),
+		/// New metadata has been set for an asset. \[asset_id, name, symbol, decimals\]
+		MetadataSet(AssetId, Vec<u8>, Vec<u8>, u8),
+		/// Metadata has been cleared for an asset. \[asset_id\]
+		MetadataCleared(AssetId),
+		/// (Additional) funds have been approved for transfer to a destination account.
+		/// \[asset_id, source, delegate, amount\]
+		ApprovedTransfer(AssetId, AccountId, AccountId, Balance),
+		/// An approval for account `delegate` was cancelled by `owner`.
+		/// \[asset_id, owner, delegate\]
+		ApprovalCancelled(AssetId, AccountId, AccountId),
+		/// An `amount` was transferred in its entirety from `owner` to `destination` by
+		/// the approved `delegate`.
+		/// \[asset_id, owner, delegate, destination, amount\]
+		TransferredApproved(AssetId, AccountId, AccountId, AccountId, Balance),
+		/// An asset has had its attributes changed by the `Force` origin.
+		/// \[asset_id\]
+		AssetStatusChanged(AssetId),
+	}
+}
+
+decl_error! {
+	pub enum Error for Module<T: Trait> {
+		/// Transfer amount should be non-zero.
+		AmountZero,
+		/// Account balance must be greater than or equal to the transfer amount.
+		BalanceLow,
+		/// Balance should be non-zero.
+		BalanceZero,
+		/// The signing account has no permission to do the operation.
+		NoPermission,
+		/// The given asset ID is unknown.
+		Unknown,
+		/// The origin account is frozen.
+		Frozen,
+		/// The asset ID is already taken.
+		InUse,
+		/// Invalid witness data given.
+		BadWitness,
+		/// Minimum balance should be non-zero.
+		MinBalanceZero,
+		/// The resulting balance of the account would be below the minimum balance of the asset.
+		BelowMinimum,
+		/// The operation would result in funds being burned.
+		WouldDie,
+		/// The supply of the asset would overflow.
+		Overflow,
+		/// Invalid metadata given.
+		BadMetadata,
+		/// No approval exists that would allow the transfer.
+		Unapproved,
+		/// The operation would affect funds on hold.
+		FundsOnHold,
+	}
 }
 
 decl_module! {
 	pub struct Module<T: Trait> for enum Call where origin: T::Origin {
 		type Error = Error<T>;
 
+		/// The basic amount of funds that must be reserved for an asset class.
+		const AssetDeposit: DepositBalanceOf<T> = T::AssetDeposit::get();
+
+		/// The basic amount of funds that must be reserved when adding metadata to an asset
+		/// class.
+		const MetadataDepositBase: DepositBalanceOf<T> = T::MetadataDepositBase::get();
+
+		/// The additional funds that must be reserved for the number of bytes stored in the
+		/// metadata of an asset class.
+		const MetadataDepositPerByte: DepositBalanceOf<T> = T::MetadataDepositPerByte::get();
+
+		/// The amount of funds that must be reserved when creating a new transfer approval.
+		const ApprovalDeposit: DepositBalanceOf<T> = T::ApprovalDeposit::get();
+
+		/// The maximum length of a name or symbol stored on-chain.
+		const StringLimit: u32 = T::StringLimit::get();
+
 		fn deposit_event() = default;
-		/// Issue a new class of fungible assets. There are, and will only ever be, `total`
-		/// such assets and they'll all belong to the `origin` initially. It will have an
-		/// identifier `AssetId` instance: this will be specified in the `Issued` event.
+
+		/// Issue a new class of fungible assets from a public origin.
+		///
+		/// This new asset class has no assets initially and its owner is the origin.
+		///
+		/// The origin must be Signed and the sender must have sufficient funds free.
+		///
+		/// Funds of sender are reserved by `AssetDeposit`.
+		///
+		/// Parameters:
+		/// - `id`: The identifier of the new asset. This must not be currently in use to identify
+		/// an existing asset.
+		/// - `admin`: The admin of this class of assets. The admin is the initial address of each
+		/// member of the asset class's admin team.
+		/// - `min_balance`: The minimum balance of this new asset that any single account must
+		/// have. If an account's balance is reduced below this, then it collapses to zero.
+		///
+		/// Emits `Created` event when successful.
+		///
+		/// # <weight>
+		/// - `O(1)`
+		/// - 1 storage read and write.
+		/// - 1 reserve.
+		/// - 1 event.
+		/// # </weight>
+		#[weight = T::WeightInfo::create()]
+		fn create(origin,
+			#[compact] id: T::AssetId,
+			admin: <T::Lookup as StaticLookup>::Source,
+			#[compact] min_balance: T::Balance,
+		) {
+			let owner = ensure_signed(origin)?;
+			let admin = T::Lookup::lookup(admin)?;
+
+			ensure!(!Asset::<T>::contains_key(id), Error::<T>::InUse);
+			ensure!(!min_balance.is_zero(), Error::<T>::MinBalanceZero);
+
+			let deposit = T::AssetDeposit::get();
+			T::Currency::reserve(&owner, deposit)?;
+
+			Asset::<T>::insert(id, AssetDetails {
+				owner: owner.clone(),
+				issuer: admin.clone(),
+				admin: admin.clone(),
+				freezer: admin.clone(),
+				supply: Zero::zero(),
+				deposit,
+				min_balance,
+				accounts: 0,
+				approvals: 0,
+				is_frozen: false,
+			});
+			Self::deposit_event(RawEvent::Created(id, owner, admin));
+		}
+
+		/// Issue a new class of fungible assets from a privileged origin.
+		///
+		/// This new asset class has no assets initially.
+		///
+		/// The origin must conform to `ForceOrigin`.
+		///
+		/// Unlike `create`, no funds are reserved.
+		///
+		/// - `id`: The identifier of the new asset. This must not be currently in use to identify
+		/// an existing asset.
+		/// - `owner`: The owner of this class of assets. The owner has full superuser permissions
+		/// over this asset, but may later change and configure the permissions using
+		/// `transfer_ownership` and `set_team`.
+		/// - `min_balance`: The minimum balance of this new asset that any single account must
+		/// have. If an account's balance is reduced below this, then it collapses to zero.
+		///
+		/// Emits `ForceCreated` event when successful.
+		///
+		/// # <weight>
+		/// - `O(1)`
+		/// - 1 storage read and write.
+		/// - 1 event.
+		/// # </weight>
+		#[weight = T::WeightInfo::force_create()]
+		fn force_create(origin,
+			#[compact] id: T::AssetId,
+			owner: <T::Lookup as StaticLookup>::Source,
+			#[compact] min_balance: T::Balance,
+		) {
+			T::ForceOrigin::ensure_origin(origin)?;
+			let owner = T::Lookup::lookup(owner)?;
+
+			ensure!(!Asset::<T>::contains_key(id), Error::<T>::InUse);
+			ensure!(!min_balance.is_zero(), Error::<T>::MinBalanceZero);
+
+			Asset::<T>::insert(id, AssetDetails {
+				owner: owner.clone(),
+				issuer: owner.clone(),
+				admin: owner.clone(),
+				freezer: owner.clone(),
+				supply: Zero::zero(),
+				deposit: Zero::zero(),
+				min_balance,
+				accounts: 0,
+				approvals: 0,
+				is_frozen: false,
+			});
+			Self::deposit_event(RawEvent::ForceCreated(id, owner));
+		}
+
+		/// Destroy a class of fungible assets.
+		///
+		/// The origin must conform to `ForceOrigin` or must be Signed and the sender must be the
+		/// owner of the asset `id`.
+		///
+		/// - `id`: The identifier of the asset to be destroyed. This must identify an existing
+		/// asset.
+		/// - `witness`: The number of accounts and approvals of the asset, as an upper bound.
+		///
+		/// All the accounts and approvals of the asset are removed and all the deposits are
+		/// returned.
+		///
+		/// Emits `Destroyed` event when successful.
+		///
+		/// # <weight>
+		/// - `O(c + a)` where `c` is the number of accounts and `a` the number of approvals of
+		///   the asset.
+		/// - `c + a + 3` storage deletions.
+		/// - `a + 1` unreserves.
+		/// - 1 event.
+		/// # </weight>
+		#[weight = T::WeightInfo::destroy(witness.accounts, witness.approvals)]
+		fn destroy(origin,
+			#[compact] id: T::AssetId,
+			witness: DestroyWitness,
+		) -> DispatchResultWithPostInfo {
+			let maybe_check_owner = match T::ForceOrigin::try_origin(origin) {
+				Ok(_) => None,
+				Err(origin) => Some(ensure_signed(origin)?),
+			};
+
+			let details = Asset::<T>::get(id).ok_or(Error::<T>::Unknown)?;
+			if let Some(check_owner) = maybe_check_owner {
+				ensure!(details.owner == check_owner, Error::<T>::NoPermission);
+			}
+			ensure!(details.accounts <= witness.accounts, Error::<T>::BadWitness);
+			ensure!(details.approvals <= witness.approvals, Error::<T>::BadWitness);
+
+			Account::<T>::remove_prefix(&id);
+			Holds::<T>::remove_prefix(&id);
+			for (key, approval) in Approvals::<T>::drain_prefix(&id) {
+				T::Currency::unreserve(&key.owner, approval.deposit);
+			}
+			let metadata = Metadata::<T>::take(&id);
+			T::Currency::unreserve(&details.owner, details.deposit.saturating_add(metadata.deposit));
+			Asset::<T>::remove(&id);
+
+			Self::deposit_event(RawEvent::Destroyed(id));
+			Ok(Some(T::WeightInfo::destroy(details.accounts, details.approvals)).into())
+		}
+
+		/// Mint assets of a particular class.
+		///
+		/// The origin must be Signed and the sender must be the Issuer of the asset `id`.
+		///
+		/// - `id`: The identifier of the asset to have some amount minted.
+		/// - `beneficiary`: The account to be credited with the minted assets.
+		/// - `amount`: The amount of the asset to be minted.
+		///
+		/// Emits `Issued` event when successful.
 		///
 		/// # <weight>
 		/// - `O(1)`
-		/// - 1 storage mutation (codec `O(1)`).
-		/// - 2 storage writes (condec `O(1)`).
+		/// - 2 storage mutations.
 		/// - 1 event.
 		/// # </weight>
-		#[weight = 0]
-		fn issue(origin, #[compact] total: T::Balance) {
+		#[weight = T::WeightInfo::mint()]
+		fn mint(origin,
+			#[compact] id: T::AssetId,
+			beneficiary: <T::Lookup as StaticLookup>::Source,
+			#[compact] amount: T::Balance
+		) {
 			let origin = ensure_signed(origin)?;
+			let beneficiary = T::Lookup::lookup(beneficiary)?;
 
-			let id = Self::next_asset_id();
-			<NextAssetId<T>>::mutate(|id| *id += One::one());
+			Self::do_mint(id, &beneficiary, amount, Some(origin))?;
+			Self::deposit_event(RawEvent::Issued(id, beneficiary, amount));
+		}
 
-			<Balances<T>>::insert((id, &origin), total);
-			<TotalSupply<T>>::insert(id, total);
+		/// Reduce the balance of `who` by as much as possible up to `amount` assets of `id`.
+		///
+		/// Origin must be Signed and the sender should be the Admin of the asset `id`.
+		///
+		/// Bails with `BalanceZero` if the `who` is already dead.
+		///
+		/// - `id`: The identifier of the asset to have some amount burned.
+		/// - `who`: The account to be debited from.
+		/// - `amount`: The maximum amount by which `who`'s balance should be reduced.
+		///
+		/// Emits `Burned` with the actual amount burned. If this takes the balance to below the
+		/// minimum for the asset, then the amount burned is increased to take it to zero.
+		///
+		/// # <weight>
+		/// - `O(1)`
+		/// - 2 storage mutations.
+		/// - 1 event.
+		/// # </weight>
+		#[weight = T::WeightInfo::burn()]
+		fn burn(origin,
+			#[compact] id: T::AssetId,
+			who: <T::Lookup as StaticLookup>::Source,
+			#[compact] amount: T::Balance
+		) {
+			let origin = ensure_signed(origin)?;
+			let who = T::Lookup::lookup(who)?;
 
-			Self::deposit_event(RawEvent::Issued(id, origin, total));
+			let burned = Self::do_burn(id, &who, amount, Some(origin))?;
+			Self::deposit_event(RawEvent::Burned(id, who, burned));
 		}
 
-		/// Move some assets from one holder to another.
+		/// Move some assets from the sender account to another.
+		///
+		/// Origin must be Signed.
+		///
+		/// - `id`: The identifier of the asset to have some amount transferred.
+		/// - `target`: The account to be credited.
+		/// - `amount`: The amount by which the sender's balance of assets should be reduced and
+		/// `target`'s balance increased. The amount actually transferred may be slightly greater in
+		/// the case that the transfer would otherwise take the sender balance above zero but below
+		/// the minimum balance. Must be greater than zero.
+		///
+		/// Emits `Transferred` with the actual amount transferred. If this takes the source balance
+		/// to below the minimum for the asset, then the amount transferred is increased to take it
+		/// to zero.
 		///
 		/// # <weight>
 		/// - `O(1)`
 		/// - 1 static lookup
-		/// - 2 storage mutations (codec `O(1)`).
+		/// - 3 storage mutations.
 		/// - 1 event.
 		/// # </weight>
-		#[weight = 0]
+		#[weight = T::WeightInfo::transfer()]
 		fn transfer(origin,
 			#[compact] id: T::AssetId,
 			target: <T::Lookup as StaticLookup>::Source,
 			#[compact] amount: T::Balance
 		) {
 			let origin = ensure_signed(origin)?;
-			let origin_account = (id, origin.clone());
-			let origin_balance = <Balances<T>>::get(&origin_account);
 			let target = T::Lookup::lookup(target)?;
-			ensure!(!amount.is_zero(), Error::<T>::AmountZero);
-			ensure!(origin_balance >= amount, Error::<T>::BalanceLow);
 
-			Self::deposit_event(RawEvent::Transferred(id, origin, target.clone(), amount));
-			<Balances<T>>::insert(origin_account, origin_balance - amount);
-			<Balances<T>>::mutate((id, target), |balance| *balance += amount);
+			let transferred = Self::do_transfer(id, &origin, &target, amount, None, false)?;
+			Self::deposit_event(RawEvent::Transferred(id, origin, target, transferred));
 		}
 
-		/// Destroy any assets of `id` owned by `origin`.
+		/// Move some assets from one account to another.
+		///
+		/// Origin must be Signed and the sender should be the Admin of the asset `id`.
+		///
+		/// - `id`: The identifier of the asset to have some amount transferred.
+		/// - `source`: The account to be debited.
+		/// - `dest`: The account to be credited.
+		/// - `amount`: The amount by which the `source`'s balance of assets should be reduced and
+		/// `dest`'s balance increased. The amount actually transferred may be slightly greater in
+		/// the case that the transfer would otherwise take the `source` balance above zero but
+		/// below the minimum balance. Must be greater than zero.
+		///
+		/// Emits `ForceTransferred` with the actual amount transferred. If this takes the source
+		/// balance to below the minimum for the asset, then the amount transferred is increased to
+		/// take it to zero.
 		///
 		/// # <weight>
 		/// - `O(1)`
-		/// - 1 storage mutation (codec `O(1)`).
-		/// - 1 storage deletion (codec `O(1)`).
+		/// - 2 static lookups
+		/// - 3 storage mutations.
 		/// - 1 event.
 		/// # </weight>
-		#[weight = 0]
-		fn destroy(origin, #[compact] id: T::AssetId) {
+		#[weight = T::WeightInfo::force_transfer()]
+		fn force_transfer(origin,
+			#[compact] id: T::AssetId,
+			source: <T::Lookup as StaticLookup>::Source,
+			dest: <T::Lookup as StaticLookup>::Source,
+			#[compact] amount: T::Balance,
+		) {
 			let origin = ensure_signed(origin)?;
-			let balance = <Balances<T>>::take((id, &origin));
-			ensure!(!balance.is_zero(), Error::<T>::BalanceZero);
+			let source = T::Lookup::lookup(source)?;
+			let dest = T::Lookup::lookup(dest)?;
 
-			<TotalSupply<T>>::mutate(id, |total_supply| *total_supply -= balance);
-			Self::deposit_event(RawEvent::Destroyed(id, origin, balance));
+			let transferred = Self::do_transfer(id, &source, &dest, amount, Some(origin), false)?;
+			Self::deposit_event(RawEvent::ForceTransferred(id, source, dest, transferred));
 		}
-	}
-}
 
-decl_event! {
-	pub enum Event<T> where
-		<T as frame_system::Trait>::AccountId,
-		<T as Trait>::Balance,
-		<T as Trait>::AssetId,
-	{
-		/// Some assets were issued. \[asset_id, owner, total_supply\]
-		Issued(AssetId, AccountId, Balance),
-		/// Some assets were transferred. \[asset_id, from, to, amount\]
-		Transferred(AssetId, AccountId, AccountId, Balance),
-		/// Some assets were destroyed. \[asset_id, owner, balance\]
-		Destroyed(AssetId, AccountId, Balance),
-	}
-}
+		/// Disallow further unprivileged transfers from an account.
+		///
+		/// Origin must be Signed and the sender should be the Freezer of the asset `id`.
+		///
+		/// - `id`: The identifier of the asset to be frozen.
+		/// - `who`: The account to be frozen.
+		///
+		/// Emits `Frozen`.
+		///
+		/// # <weight>
+		/// - `O(1)`
+		/// - 1 storage mutation.
+		/// - 1 event.
+		/// # </weight>
+		#[weight = T::WeightInfo::freeze()]
+		fn freeze(origin, #[compact] id: T::AssetId, who: <T::Lookup as StaticLookup>::Source) {
+			let origin = ensure_signed(origin)?;
+			let who = T::Lookup::lookup(who)?;
 
-decl_error! {
-	pub enum Error for Module<T: Trait> {
-		/// Transfer amount should be non-zero
-		AmountZero,
-		/// Account balance must be greater than or equal to the transfer amount
-		BalanceLow,
-		/// Balance should be non-zero
-		BalanceZero,
-	}
-}
+			let details = Asset::<T>::get(id).ok_or(Error::<T>::Unknown)?;
+			ensure!(&origin == &details.freezer, Error::<T>::NoPermission);
+			ensure!(Account::<T>::contains_key(id, &who), Error::<T>::BalanceZero);
 
-decl_storage! {
-	trait Store for Module<T: Trait> as Assets {
-		/// The number of units of assets held by any given account.
-		Balances: map hasher(blake2_128_concat) (T::AssetId, T::AccountId) => T::Balance;
-		/// The next asset identifier up for grabs.
-		NextAssetId get(fn next_asset_id): T::AssetId;
-		/// The total unit supply of an asset.
+			Account::<T>::mutate(id, &who, |a| a.is_frozen = true);
+			Self::deposit_event(RawEvent::Frozen(id, who));
+		}
+
+		/// Allow unprivileged transfers from an account again.
+		///
+		/// Origin must be Signed and the sender should be the Admin of the asset `id`.
+		///
+		/// - `id`: The identifier of the asset to be thawed.
+		/// - `who`: The account to be unfrozen.
+		///
+		/// Emits `Thawed`.
 		///
-		/// TWOX-NOTE: `AssetId` is trusted, so this is safe.
-		TotalSupply: map hasher(twox_64_concat) T::AssetId => T::Balance;
+		/// # <weight>
+		/// - `O(1)`
+		/// - 1 storage mutation.
+		/// - 1 event.
+		/// # </weight>
+		#[weight = T::WeightInfo::thaw()]
+		fn thaw(origin, #[compact] id: T::AssetId, who: <T::Lookup as StaticLookup>::Source) {
+			let origin = ensure_signed(origin)?;
+			let who = T::Lookup::lookup(who)?;
+
+			let details = Asset::<T>::get(id).ok_or(Error::<T>::Unknown)?;
+			ensure!(&origin == &details.admin, Error::<T>::NoPermission);
+			ensure!(Account::<T>::contains_key(id, &who), Error::<T>::BalanceZero);
+
+			Account::<T>::mutate(id, &who, |a| a.is_frozen = false);
+			Self::deposit_event(RawEvent::Thawed(id, who));
+		}
+
+		/// Disallow further unprivileged transfers for the asset class.
+		///
+		/// Origin must be Signed and the sender should be the Freezer of the asset `id`.
+		///
+		/// - `id`: The identifier of the asset to be frozen.
+		///
+		/// Emits `AssetFrozen`.
+		///
+		/// # <weight>
+		/// - `O(1)`
+		/// - 1 storage mutation.
+		/// - 1 event.
+		/// # </weight>
+		#[weight = T::WeightInfo::freeze_asset()]
+		fn freeze_asset(origin, #[compact] id: T::AssetId) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+
+			Asset::<T>::try_mutate(id, |maybe_details| {
+				let details = maybe_details.as_mut().ok_or(Error::<T>::Unknown)?;
+				ensure!(&origin == &details.freezer, Error::<T>::NoPermission);
+
+				details.is_frozen = true;
+
+				Self::deposit_event(RawEvent::AssetFrozen(id));
+				Ok(())
+			})
+		}
+
+		/// Allow unprivileged transfers for the asset again.
+		///
+		/// Origin must be Signed and the sender should be the Admin of the asset `id`.
+		///
+		/// - `id`: The identifier of the asset to be thawed.
+		///
+		/// Emits `AssetThawed`.
+		///
+		/// # <weight>
+		/// - `O(1)`
+		/// - 1 storage mutation.
+		/// - 1 event.
+		/// # </weight>
+		#[weight = T::WeightInfo::thaw_asset()]
+		fn thaw_asset(origin, #[compact] id: T::AssetId) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+
+			Asset::<T>::try_mutate(id, |maybe_details| {
+				let details = maybe_details.as_mut().ok_or(Error::<T>::Unknown)?;
+				ensure!(&origin == &details.admin, Error::<T>::NoPermission);
+
+				details.is_frozen = false;
+
+				Self::deposit_event(RawEvent::AssetThawed(id));
+				Ok(())
+			})
+		}
+
+		/// Change the Owner of an asset.
+		///
+		/// Origin must be Signed and the sender should be the Owner of the asset `id`.
+		///
+		/// The deposits of the asset and of its metadata are moved to the new Owner.
+		///
+		/// - `id`: The identifier of the asset.
+		/// - `owner`: The new Owner of this asset.
+		///
+		/// Emits `OwnerChanged`.
+		///
+		/// # <weight>
+		/// - `O(1)`
+		/// - 1 storage mutation.
+		/// - 1 storage read.
+		/// - 1 repatriation of reserved funds.
+		/// - 1 event.
+		/// # </weight>
+		#[weight = T::WeightInfo::transfer_ownership()]
+		fn transfer_ownership(origin,
+			#[compact] id: T::AssetId,
+			owner: <T::Lookup as StaticLookup>::Source,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			let owner = T::Lookup::lookup(owner)?;
+
+			Asset::<T>::try_mutate(id, |maybe_details| {
+				let details = maybe_details.as_mut().ok_or(Error::<T>::Unknown)?;
+				ensure!(&origin == &details.owner, Error::<T>::NoPermission);
+				if details.owner == owner { return Ok(()) }
+
+				let metadata_deposit = Metadata::<T>::get(id).deposit;
+				let deposit = details.deposit.saturating_add(metadata_deposit);
+
+				// Move the deposit to the new owner.
+				T::Currency::repatriate_reserved(&details.owner, &owner, deposit, BalanceStatus::Reserved)?;
+
+				details.owner = owner.clone();
+
+				Self::deposit_event(RawEvent::OwnerChanged(id, owner));
+				Ok(())
+			})
+		}
+
+		/// Change the Issuer, Admin and Freezer of an asset.
+		///
+		/// Origin must be Signed and the sender should be the Owner of the asset `id`.
+		///
+		/// - `id`: The identifier of the asset to be frozen.
+		/// - `issuer`: The new Issuer of this asset.
+		/// - `admin`: The new Admin of this asset.
+		/// - `freezer`: The new Freezer of this asset.
+		///
+		/// Emits `TeamChanged`.
+		///
+		/// # <weight>
+		/// - `O(1)`
+		/// - 1 storage mutation.
+		/// - 1 event.
+		/// # </weight>
+		#[weight = T::WeightInfo::set_team()]
+		fn set_team(origin,
+			#[compact] id: T::AssetId,
+			issuer: <T::Lookup as StaticLookup>::Source,
+			admin: <T::Lookup as StaticLookup>::Source,
+			freezer: <T::Lookup as StaticLookup>::Source,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			let issuer = T::Lookup::lookup(issuer)?;
+			let admin = T::Lookup::lookup(admin)?;
+			let freezer = T::Lookup::lookup(freezer)?;
+
+			Asset::<T>::try_mutate(id, |maybe_details| {
+				let details = maybe_details.as_mut().ok_or(Error::<T>::Unknown)?;
+				ensure!(&origin == &details.owner, Error::<T>::NoPermission);
+
+				details.issuer = issuer.clone();
+				details.admin = admin.clone();
+				details.freezer = freezer.clone();
+
+				Self::deposit_event(RawEvent::TeamChanged(id, issuer, admin, freezer));
+				Ok(())
+			})
+		}
+
+		/// Set the metadata for an asset.
+		///
+		/// Origin must be Signed and the sender should be the Owner of the asset `id`.
+		///
+		/// Funds of sender are reserved according to the formula:
+		/// `MetadataDepositBase + MetadataDepositPerByte * (name.len + symbol.len)` taking into
+		/// account any already reserved funds.
+		///
+		/// - `id`: The identifier of the asset to update.
+		/// - `name`: The user friendly name of this asset. Limited in length by `StringLimit`.
+		/// - `symbol`: The exchange symbol for this asset. Limited in length by `StringLimit`.
+		/// - `decimals`: The number of decimals this asset uses to represent one unit.
+		///
+		/// Emits `MetadataSet`.
+		///
+		/// # <weight>
+		/// - `O(N + S)` where N and S are the length of the name and symbol respectively.
+		/// - 1 storage read.
+		/// - 1 storage mutation.
+		/// - 1 reserve or unreserve.
+		/// - 1 event.
+		/// # </weight>
+		#[weight = T::WeightInfo::set_metadata(name.len() as u32, symbol.len() as u32)]
+		fn set_metadata(origin,
+			#[compact] id: T::AssetId,
+			name: Vec<u8>,
+			symbol: Vec<u8>,
+			decimals: u8,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+
+			ensure!(name.len() <= T::StringLimit::get() as usize, Error::<T>::BadMetadata);
+			ensure!(symbol.len() <= T::StringLimit::get() as usize, Error::<T>::BadMetadata);
+
+			let details = Asset::<T>::get(id).ok_or(Error::<T>::Unknown)?;
+			ensure!(&origin == &details.owner, Error::<T>::NoPermission);
+
+			Metadata::<T>::try_mutate_exists(id, |metadata| {
+				let old_deposit = metadata.take().map_or(Zero::zero(), |m| m.deposit);
+				let new_deposit = T::MetadataDepositPerByte::get()
+					.saturating_mul(((name.len() + symbol.len()) as u32).into())
+					.saturating_add(T::MetadataDepositBase::get());
+
+				if new_deposit > old_deposit {
+					T::Currency::reserve(&origin, new_deposit - old_deposit)?;
+				} else {
+					T::Currency::unreserve(&origin, old_deposit - new_deposit);
+				}
+
+				*metadata = Some(AssetMetadata {
+					deposit: new_deposit,
+					name: name.clone(),
+					symbol: symbol.clone(),
+					decimals,
+				});
+
+				Self::deposit_event(RawEvent::MetadataSet(id, name, symbol, decimals));
+				Ok(())
+			})
+		}
+
+		/// Clear the metadata for an asset.
+		///
+		/// Origin must be Signed and the sender should be the Owner of the asset `id`.
+		///
+		/// Any deposit is freed for the asset owner.
+		///
+		/// - `id`: The identifier of the asset to clear.
+		///
+		/// Emits `MetadataCleared`.
+		///
+		/// # <weight>
+		/// - `O(1)`
+		/// - 1 storage read.
+		/// - 1 storage deletion.
+		/// - 1 unreserve.
+		/// - 1 event.
+		/// # </weight>
+		#[weight = T::WeightInfo::clear_metadata()]
+		fn clear_metadata(origin, #[compact] id: T::AssetId) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+
+			let details = Asset::<T>::get(id).ok_or(Error::<T>::Unknown)?;
+			ensure!(&origin == &details.owner, Error::<T>::NoPermission);
+
+			Metadata::<T>::try_mutate_exists(id, |metadata| {
+				let deposit = metadata.take().ok_or(Error::<T>::Unknown)?.deposit;
+				T::Currency::unreserve(&details.owner, deposit);
+				Self::deposit_event(RawEvent::MetadataCleared(id));
+				Ok(())
+			})
+		}
+
+		/// Force the metadata for an asset to some value.
+		///
+		/// Origin must be ForceOrigin.
+		///
+		/// Any deposit is left alone.
+		///
+		/// - `id`: The identifier of the asset to update.
+		/// - `name`: The user friendly name of this asset. Limited in length by `StringLimit`.
+		/// - `symbol`: The exchange symbol for this asset. Limited in length by `StringLimit`.
+		/// - `decimals`: The number of decimals this asset uses to represent one unit.
+		///
+		/// Emits `MetadataSet`.
+		///
+		/// # <weight>
+		/// - `O(N + S)` where N and S are the length of the name and symbol respectively.
+		/// - 1 storage read.
+		/// - 1 storage mutation.
+		/// - 1 event.
+		/// # </weight>
+		#[weight = T::WeightInfo::force_set_metadata(name.len() as u32, symbol.len() as u32)]
+		fn force_set_metadata(origin,
+			#[compact] id: T::AssetId,
+			name: Vec<u8>,
+			symbol: Vec<u8>,
+			decimals: u8,
+		) {
+			T::ForceOrigin::ensure_origin(origin)?;
+
+			ensure!(name.len() <= T::StringLimit::get() as usize, Error::<T>::BadMetadata);
+			ensure!(symbol.len() <= T::StringLimit::get() as usize, Error::<T>::BadMetadata);
+
+			ensure!(Asset::<T>::contains_key(id), Error::<T>::Unknown);
+			Metadata::<T>::mutate(id, |metadata| {
+				metadata.name = name.clone();
+				metadata.symbol = symbol.clone();
+				metadata.decimals = decimals;
+			});
+
+			Self::deposit_event(RawEvent::MetadataSet(id, name, symbol, decimals));
+		}
+
+		/// Clear the metadata for an asset.
+		///
+		/// Origin must be ForceOrigin.
+		///
+		/// Any deposit is returned.
+		///
+		/// - `id`: The identifier of the asset to clear.
+		///
+		/// Emits `MetadataCleared`.
+		///
+		/// # <weight>
+		/// - `O(1)`
+		/// - 1 storage read.
+		/// - 1 storage deletion.
+		/// - 1 unreserve.
+		/// - 1 event.
+		/// # </weight>
+		#[weight = T::WeightInfo::force_clear_metadata()]
+		fn force_clear_metadata(origin, #[compact] id: T::AssetId) -> DispatchResult {
+			T::ForceOrigin::ensure_origin(origin)?;
+
+			let details = Asset::<T>::get(id).ok_or(Error::<T>::Unknown)?;
+			Metadata::<T>::try_mutate_exists(id, |metadata| {
+				let deposit = metadata.take().ok_or(Error::<T>::Unknown)?.deposit;
+				T::Currency::unreserve(&details.owner, deposit);
+				Self::deposit_event(RawEvent::MetadataCleared(id));
+				Ok(())
+			})
+		}
+
+		/// Alter the attributes of a given asset.
+		///
+		/// Origin must be `ForceOrigin`.
+		///
+		/// - `id`: The identifier of the asset.
+		/// - `owner`: The new Owner of this asset.
+		/// - `issuer`: The new Issuer of this asset.
+		/// - `admin`: The new Admin of this asset.
+		/// - `freezer`: The new Freezer of this asset.
+		/// - `min_balance`: The minimum balance of this new asset that any single account must
+		/// have. If an account's balance is reduced below this, then it collapses to zero.
+		/// - `is_frozen`: Whether this asset class is frozen except for permissioned/admin
+		/// instructions.
+		///
+		/// If the owner changes, the asset deposit and the metadata deposit are moved to the new
+		/// owner.
+		///
+		/// Emits `AssetStatusChanged` with the identity of the asset.
+		///
+		/// # <weight>
+		/// - `O(1)`
+		/// - 1 storage read (metadata).
+		/// - 1 storage mutation.
+		/// - 1 event.
+		/// # </weight>
+		#[weight = T::WeightInfo::force_asset_status()]
+		fn force_asset_status(origin,
+			#[compact] id: T::AssetId,
+			owner: <T::Lookup as StaticLookup>::Source,
+			issuer: <T::Lookup as StaticLookup>::Source,
+			admin: <T::Lookup as StaticLookup>::Source,
+			freezer: <T::Lookup as StaticLookup>::Source,
+			#[compact] min_balance: T::Balance,
+			is_frozen: bool,
+		) -> DispatchResult {
+			T::ForceOrigin::ensure_origin(origin)?;
+			ensure!(!min_balance.is_zero(), Error::<T>::MinBalanceZero);
+			let owner = T::Lookup::lookup(owner)?;
+			let issuer = T::Lookup::lookup(issuer)?;
+			let admin = T::Lookup::lookup(admin)?;
+			let freezer = T::Lookup::lookup(freezer)?;
+
+			Asset::<T>::try_mutate(id, |maybe_details| {
+				let details = maybe_details.as_mut().ok_or(Error::<T>::Unknown)?;
+				if details.owner != owner {
+					let metadata_deposit = Metadata::<T>::get(id).deposit;
+					let deposit = details.deposit.saturating_add(metadata_deposit);
+
+					// Move the deposit to the new owner.
+					T::Currency::repatriate_reserved(
+						&details.owner,
+						&owner,
+						deposit,
+						BalanceStatus::Reserved,
+					)?;
+					details.owner = owner;
+				}
+				details.issuer = issuer;
+				details.admin = admin;
+				details.freezer = freezer;
+				details.min_balance = min_balance;
+				details.is_frozen = is_frozen;
+
+				Self::deposit_event(RawEvent::AssetStatusChanged(id));
+				Ok(())
+			})
+		}
+
+		/// Approve an amount of asset for transfer by a delegated third-party account.
+		///
+		/// Origin must be Signed.
+		///
+		/// Ensures that `ApprovalDeposit` worth of `Currency` is reserved from signing account
+		/// for the purpose of holding the approval. If some non-zero amount of assets is already
+		/// approved from signing account to `delegate`, then it is topped up or unreserved to
+		/// meet the right value.
+		///
+		/// NOTE: The signing account does not need to own `amount` of assets at the point of
+		/// making this call.
+		///
+		/// - `id`: The identifier of the asset.
+		/// - `delegate`: The account to delegate permission to transfer asset.
+		/// - `amount`: The amount of asset that may be transferred by `delegate`. If there is
+		/// already an approval in place, then this acts additively.
+		///
+		/// Emits `ApprovedTransfer` on success.
+		///
+		/// # <weight>
+		/// - `O(1)`
+		/// - 2 storage mutations.
+		/// - 1 reserve.
+		/// - 1 event.
+		/// # </weight>
+		#[weight = T::WeightInfo::approve_transfer()]
+		fn approve_transfer(origin,
+			#[compact] id: T::AssetId,
+			delegate: <T::Lookup as StaticLookup>::Source,
+			#[compact] amount: T::Balance,
+		) {
+			let owner = ensure_signed(origin)?;
+			let delegate = T::Lookup::lookup(delegate)?;
+
+			let key = ApprovalKey { owner, delegate };
+			Asset::<T>::try_mutate(id, |maybe_details| -> DispatchResult {
+				let details = maybe_details.as_mut().ok_or(Error::<T>::Unknown)?;
+				Approvals::<T>::try_mutate(id, &key, |maybe_approved| -> DispatchResult {
+					let mut approved = match maybe_approved.take() {
+						Some(approved) => approved,
+						None => {
+							details.approvals = details.approvals.saturating_add(1);
+							Default::default()
+						},
+					};
+					let deposit_required = T::ApprovalDeposit::get();
+					if approved.deposit < deposit_required {
+						T::Currency::reserve(&key.owner, deposit_required - approved.deposit)?;
+						approved.deposit = deposit_required;
+					}
+					approved.amount = approved.amount.saturating_add(amount);
+					*maybe_approved = Some(approved);
+					Ok(())
+				})
+			})?;
+
+			Self::deposit_event(RawEvent::ApprovedTransfer(id, key.owner, key.delegate, amount));
+		}
+
+		/// Cancel all of some asset approved for delegated transfer by a third-party account.
+		///
+		/// Origin must be Signed and there must be an approval in place between signer and
+		/// `delegate`.
+		///
+		/// Unreserves any deposit previously reserved by `approve_transfer` for the approval.
+		///
+		/// - `id`: The identifier of the asset.
+		/// - `delegate`: The account delegated permission to transfer asset.
+		///
+		/// Emits `ApprovalCancelled` on success.
+		///
+		/// # <weight>
+		/// - `O(1)`
+		/// - 1 storage deletion.
+		/// - 1 storage mutation.
+		/// - 1 unreserve.
+		/// - 1 event.
+		/// # </weight>
+		#[weight = T::WeightInfo::cancel_approval()]
+		fn cancel_approval(origin,
+			#[compact] id: T::AssetId,
+			delegate: <T::Lookup as StaticLookup>::Source,
+		) {
+			let owner = ensure_signed(origin)?;
+			let delegate = T::Lookup::lookup(delegate)?;
+
+			Self::do_cancel_approval(id, ApprovalKey { owner, delegate })?;
+		}
+
+		/// Cancel all of some asset approved for delegated transfer by a third-party account.
+		///
+		/// Origin must be either ForceOrigin or Signed origin with the signer being the Admin
+		/// account of the asset `id`.
+		///
+		/// Unreserves any deposit previously reserved by `approve_transfer` for the approval.
+		///
+		/// - `id`: The identifier of the asset.
+		/// - `owner`: The account which approved the transfer.
+		/// - `delegate`: The account delegated permission to transfer asset.
+		///
+		/// Emits `ApprovalCancelled` on success.
+		///
+		/// # <weight>
+		/// - `O(1)`
+		/// - 1 storage deletion.
+		/// - 1 storage mutation.
+		/// - 1 unreserve.
+		/// - 1 event.
+		/// # </weight>
+		#[weight = T::WeightInfo::force_cancel_approval()]
+		fn force_cancel_approval(origin,
+			#[compact] id: T::AssetId,
+			owner: <T::Lookup as StaticLookup>::Source,
+			delegate: <T::Lookup as StaticLookup>::Source,
+		) {
+			T::ForceOrigin::try_origin(origin)
+				.map(|_| ())
+				.or_else(|origin| -> DispatchResult {
+					let origin = ensure_signed(origin)?;
+					let details = Asset::<T>::get(id).ok_or(Error::<T>::Unknown)?;
+					ensure!(&origin == &details.admin, Error::<T>::NoPermission);
+					Ok(())
+				})?;
+
+			let owner = T::Lookup::lookup(owner)?;
+			let delegate = T::Lookup::lookup(delegate)?;
+
+			Self::do_cancel_approval(id, ApprovalKey { owner, delegate })?;
+		}
+
+		/// Transfer some asset balance from a previously delegated account to some third-party
+		/// account.
+		///
+		/// Origin must be Signed and there must be an approval in place by the `owner` to the
+		/// signer.
+		///
+		/// If the entire amount approved for transfer is transferred, then any deposit previously
+		/// reserved by `approve_transfer` is unreserved.
+		///
+		/// - `id`: The identifier of the asset.
+		/// - `owner`: The account which previously approved for a transfer of at least `amount` and
+		/// from which the asset balance will be withdrawn.
+		/// - `destination`: The account to which the asset balance of `amount` will be transferred.
+		/// - `amount`: The amount of assets to transfer. Exactly this amount is transferred: the
+		/// transfer fails if the remaining balance of `owner` would be non-zero but below the
+		/// minimum balance of the asset.
+		///
+		/// Emits `TransferredApproved` on success.
+		///
+		/// # <weight>
+		/// - `O(1)`
+		/// - 2 static lookups
+		/// - 5 storage mutations.
+		/// - 1 unreserve.
+		/// - 1 event.
+		/// # </weight>
+		#[weight = T::WeightInfo::transfer_approved()]
+		fn transfer_approved(origin,
+			#[compact] id: T::AssetId,
+			owner: <T::Lookup as StaticLookup>::Source,
+			destination: <T::Lookup as StaticLookup>::Source,
+			#[compact] amount: T::Balance,
+		) {
+			let delegate = ensure_signed(origin)?;
+			let owner = T::Lookup::lookup(owner)?;
+			let destination = T::Lookup::lookup(destination)?;
+
+			let key = ApprovalKey { owner, delegate };
+			Approvals::<T>::try_mutate_exists(id, &key, |maybe_approved| -> DispatchResult {
+				let mut approved = maybe_approved.take().ok_or(Error::<T>::Unapproved)?;
+				let remaining = approved.amount.checked_sub(&amount).ok_or(Error::<T>::Unapproved)?;
+
+				Self::do_transfer(id, &key.owner, &destination, amount, None, true)?;
+
+				if remaining.is_zero() {
+					T::Currency::unreserve(&key.owner, approved.deposit);
+					Asset::<T>::mutate(id, |maybe_details| {
+						if let Some(details) = maybe_details {
+							details.approvals = details.approvals.saturating_sub(1);
+						}
+					});
+				} else {
+					approved.amount = remaining;
+					*maybe_approved = Some(approved);
+				}
+				Ok(())
+			})?;
+
+			Self::deposit_event(RawEvent::TransferredApproved(
+				id,
+				key.owner,
+				key.delegate,
+				destination,
+				amount,
+			));
+		}
 	}
 }
 
@@ -267,158 +1367,333 @@ decl_storage! {
 impl<T: Trait> Module<T> {
 	// Public immutables
 
-	/// Get the asset `id` balance of `who`.
+	/// Get the asset `id` balance of `who`, including any funds on hold.
 	pub fn balance(id: T::AssetId, who: T::AccountId) -> T::Balance {
-		<Balances<T>>::get((id, who))
+		Account::<T>::get(id, who).balance
 	}
 
 	/// Get the total supply of an asset `id`.
 	pub fn total_supply(id: T::AssetId) -> T::Balance {
-		<TotalSupply<T>>::get(id)
+		Asset::<T>::get(id).map(|x| x.supply).unwrap_or_else(Zero::zero)
 	}
-}
 
-#[cfg(test)]
-mod tests {
-	use super::*;
+	// Public mutables
 
-	use frame_support::{impl_outer_origin, assert_ok, assert_noop, parameter_types, weights::Weight};
-	use sp_core::H256;
-	use sp_runtime::{Perbill, traits::{BlakeTwo256, IdentityLookup}, testing::Header};
+	/// Remove `amount` units of the asset `id` from the balance of `who`.
+	///
+	/// The asset and the account must not be frozen, and the account is kept alive: the
+	/// withdrawal fails if it would take the balance of `who` below the minimum balance or below
+	/// the amount it has on hold.
+	///
+	/// The units are not removed from the total supply until the returned imbalance is dropped,
+	/// so that they can be handed over to another account with [`resolve`](#method.resolve).
+	pub fn withdraw(
+		id: T::AssetId,
+		who: &T::AccountId,
+		amount: T::Balance,
+	) -> Result<NegativeImbalance<T>, DispatchError> {
+		if amount.is_zero() {
+			return Ok(NegativeImbalance::zero(id));
+		}
 
-	impl_outer_origin! {
-		pub enum Origin for Test where system = frame_system {}
-	}
+		let details = Asset::<T>::get(id).ok_or(Error::<T>::Unknown)?;
+		ensure!(!details.is_frozen, Error::<T>::Frozen);
 
-	#[derive(Clone, Eq, PartialEq)]
-	pub struct Test;
-	parameter_types! {
-		pub const BlockHashCount: u64 = 250;
-		pub const MaximumBlockWeight: Weight = 1024;
-		pub const MaximumBlockLength: u32 = 2 * 1024;
-		pub const AvailableBlockRatio: Perbill = Perbill::one();
+		Account::<T>::try_mutate(id, who, |account| -> Result<_, DispatchError> {
+			ensure!(!account.is_frozen, Error::<T>::Frozen);
+			let remaining = account.balance.checked_sub(&amount).ok_or(Error::<T>::BalanceLow)?;
+			ensure!(remaining >= Holds::<T>::get(id, who), Error::<T>::FundsOnHold);
+			ensure!(remaining >= details.min_balance, Error::<T>::WouldDie);
+			account.balance = remaining;
+			Ok(NegativeImbalance::new(id, amount))
+		})
 	}
-	impl frame_system::Trait for Test {
-		type BaseCallFilter = ();
-		type Origin = Origin;
-		type Index = u64;
-		type Call = ();
-		type BlockNumber = u64;
-		type Hash = H256;
-		type Hashing = BlakeTwo256;
-		type AccountId = u64;
-		type Lookup = IdentityLookup<Self::AccountId>;
-		type Header = Header;
-		type Event = ();
-		type BlockHashCount = BlockHashCount;
-		type MaximumBlockWeight = MaximumBlockWeight;
-		type DbWeight = ();
-		type BlockExecutionWeight = ();
-		type ExtrinsicBaseWeight = ();
-		type MaximumExtrinsicWeight = MaximumBlockWeight;
-		type AvailableBlockRatio = AvailableBlockRatio;
-		type MaximumBlockLength = MaximumBlockLength;
-		type Version = ();
-		type PalletInfo = ();
-		type AccountData = ();
-		type OnNewAccount = ();
-		type OnKilledAccount = ();
-		type SystemWeightInfo = ();
+
+	/// Credit the units of the asset held by `imbalance` to the balance of `who`.
+	///
+	/// Fails, returning the imbalance, if the asset does not exist any more or if the
+	/// resulting balance of `who` would be below the minimum balance of the asset.
+	pub fn resolve(
+		who: &T::AccountId,
+		imbalance: NegativeImbalance<T>,
+	) -> Result<(), NegativeImbalance<T>> {
+		let id = imbalance.asset_id();
+		let amount = imbalance.peek();
+		if amount.is_zero() {
+			return Ok(());
+		}
+
+		let credited = Asset::<T>::try_mutate(id, |maybe_details| -> DispatchResult {
+			let details = maybe_details.as_mut().ok_or(Error::<T>::Unknown)?;
+			Self::credit(id, details, who, amount)
+		});
+		match credited {
+			Ok(()) => {
+				let _ = imbalance.take();
+				Ok(())
+			},
+			Err(_) => Err(imbalance),
+		}
 	}
-	impl Trait for Test {
-		type Event = ();
-		type Balance = u64;
-		type AssetId = u32;
+
+	/// Increase the balance of `who` by `amount` units of the asset `id`, creating new units.
+	///
+	/// If `maybe_check_issuer` is given, it must be the Issuer of the asset.
+	pub(crate) fn do_mint(
+		id: T::AssetId,
+		who: &T::AccountId,
+		amount: T::Balance,
+		maybe_check_issuer: Option<T::AccountId>,
+	) -> DispatchResult {
+		Asset::<T>::try_mutate(id, |maybe_details| {
+			let details = maybe_details.as_mut().ok_or(Error::<T>::Unknown)?;
+			if let Some(check_issuer) = maybe_check_issuer {
+				ensure!(&check_issuer == &details.issuer, Error::<T>::NoPermission);
+			}
+
+			let supply = details.supply.checked_add(&amount).ok_or(Error::<T>::Overflow)?;
+			Self::credit(id, details, who, amount)?;
+			details.supply = supply;
+			Ok(())
+		})
 	}
-	type Assets = Module<Test>;
 
-	fn new_test_ext() -> sp_io::TestExternalities {
-		frame_system::GenesisConfig::default().build_storage::<Test>().unwrap().into()
+	/// Reduce the balance of `who` by as much as possible up to `amount` units of the asset `id`,
+	/// destroying them. The whole balance is burned if the remainder would be below the minimum
+	/// balance.
+	///
+	/// If `maybe_check_admin` is given, it must be the Admin of the asset.
+	///
+	/// Returns the amount actually burned.
+	pub(crate) fn do_burn(
+		id: T::AssetId,
+		who: &T::AccountId,
+		amount: T::Balance,
+		maybe_check_admin: Option<T::AccountId>,
+	) -> Result<T::Balance, DispatchError> {
+		Asset::<T>::try_mutate(id, |maybe_details| {
+			let details = maybe_details.as_mut().ok_or(Error::<T>::Unknown)?;
+			if let Some(check_admin) = maybe_check_admin {
+				ensure!(&check_admin == &details.admin, Error::<T>::NoPermission);
+			}
+
+			let burned = Account::<T>::try_mutate_exists(
+				id,
+				who,
+				|maybe_account| -> Result<T::Balance, DispatchError> {
+					let mut account = maybe_account.take().ok_or(Error::<T>::BalanceZero)?;
+					let held = Holds::<T>::get(id, who);
+					let reducible = Self::reducible(&account, held, details.min_balance, false);
+					let mut burned = amount.min(reducible);
+					account.balance -= burned;
+					*maybe_account = if account.balance < details.min_balance {
+						burned += account.balance;
+						details.accounts = details.accounts.saturating_sub(1);
+						None
+					} else {
+						Some(account)
+					};
+					Ok(burned)
+				},
+			)?;
+
+			details.supply = details.supply.saturating_sub(burned);
+			Ok(burned)
+		})
 	}
 
-	#[test]
-	fn issuing_asset_units_to_issuer_should_work() {
-		new_test_ext().execute_with(|| {
-			assert_ok!(Assets::issue(Origin::signed(1), 100));
-			assert_eq!(Assets::balance(0, 1), 100);
-		});
+	/// Move `amount` units of the asset `id` from `source` to `dest`. The whole balance of
+	/// `source` is moved if the remainder would be below the minimum balance, unless `exact` is
+	/// set, in which case the transfer fails with `BelowMinimum` instead.
+	///
+	/// If `maybe_need_admin` is given, it must be the Admin of the asset and the transfer is
+	/// allowed even if the asset or `source` are frozen.
+	///
+	/// Returns the amount actually transferred.
+	pub(crate) fn do_transfer(
+		id: T::AssetId,
+		source: &T::AccountId,
+		dest: &T::AccountId,
+		amount: T::Balance,
+		maybe_need_admin: Option<T::AccountId>,
+		exact: bool,
+	) -> Result<T::Balance, DispatchError> {
+		let mut source_account = Account::<T>::get(id, source);
+		let held = Holds::<T>::get(id, source);
+		ensure!(!amount.is_zero(), Error::<T>::AmountZero);
+		ensure!(source_account.balance >= amount, Error::<T>::BalanceLow);
+		ensure!(source_account.balance - amount >= held, Error::<T>::FundsOnHold);
+
+		Asset::<T>::try_mutate(id, |maybe_details| {
+			let details = maybe_details.as_mut().ok_or(Error::<T>::Unknown)?;
+			if let Some(need_admin) = maybe_need_admin {
+				ensure!(&need_admin == &details.admin, Error::<T>::NoPermission);
+			} else {
+				ensure!(!details.is_frozen, Error::<T>::Frozen);
+				ensure!(!source_account.is_frozen, Error::<T>::Frozen);
+			}
+
+			let mut amount = amount;
+			let remainder = source_account.balance - amount;
+			if !remainder.is_zero() && remainder < details.min_balance {
+				ensure!(!exact && held.is_zero(), Error::<T>::BelowMinimum);
+				amount = source_account.balance;
+			}
+			if source == dest {
+				return Ok(amount);
+			}
+
+			Self::credit(id, details, dest, amount)?;
+
+			source_account.balance -= amount;
+			if source_account.balance.is_zero() {
+				details.accounts = details.accounts.saturating_sub(1);
+				Account::<T>::remove(id, source);
+			} else {
+				Account::<T>::insert(id, source, &source_account);
+			}
+			Ok(amount)
+		})
 	}
 
-	#[test]
-	fn querying_total_supply_should_work() {
-		new_test_ext().execute_with(|| {
-			assert_ok!(Assets::issue(Origin::signed(1), 100));
-			assert_eq!(Assets::balance(0, 1), 100);
-			assert_ok!(Assets::transfer(Origin::signed(1), 0, 2, 50));
-			assert_eq!(Assets::balance(0, 1), 50);
-			assert_eq!(Assets::balance(0, 2), 50);
-			assert_ok!(Assets::transfer(Origin::signed(2), 0, 3, 31));
-			assert_eq!(Assets::balance(0, 1), 50);
-			assert_eq!(Assets::balance(0, 2), 19);
-			assert_eq!(Assets::balance(0, 3), 31);
-			assert_ok!(Assets::destroy(Origin::signed(3), 0));
-			assert_eq!(Assets::total_supply(0), 69);
-		});
+	/// The part of the balance of `account` which can be withdrawn, given the amount `held` on
+	/// hold and the `min_balance` of the asset. An account with funds on hold is kept alive.
+	fn reducible(
+		account: &AssetBalance<T::Balance>,
+		held: T::Balance,
+		min_balance: T::Balance,
+		keep_alive: bool,
+	) -> T::Balance {
+		let untouchable = if keep_alive || !held.is_zero() {
+			held.max(min_balance)
+		} else {
+			Zero::zero()
+		};
+		account.balance.saturating_sub(untouchable)
 	}
 
-	#[test]
-	fn transferring_amount_above_available_balance_should_work() {
-		new_test_ext().execute_with(|| {
-			assert_ok!(Assets::issue(Origin::signed(1), 100));
-			assert_eq!(Assets::balance(0, 1), 100);
-			assert_ok!(Assets::transfer(Origin::signed(1), 0, 2, 50));
-			assert_eq!(Assets::balance(0, 1), 50);
-			assert_eq!(Assets::balance(0, 2), 50);
-		});
+	/// Add `amount` to the balance of `who` in the asset `id`, whose `details` are given, without
+	/// touching its supply.
+	fn credit(
+		id: T::AssetId,
+		details: &mut AssetDetails<T::Balance, T::AccountId, DepositBalanceOf<T>>,
+		who: &T::AccountId,
+		amount: T::Balance,
+	) -> DispatchResult {
+		Account::<T>::try_mutate(id, who, |account| -> DispatchResult {
+			let new_balance = account.balance.saturating_add(amount);
+			ensure!(new_balance >= details.min_balance, Error::<T>::BelowMinimum);
+			if account.balance.is_zero() {
+				details.accounts = details.accounts.saturating_add(1);
+			}
+			account.balance = new_balance;
+			Ok(())
+		})
 	}
 
-	#[test]
-	fn transferring_amount_more_than_available_balance_should_not_work() {
-		new_test_ext().execute_with(|| {
-			assert_ok!(Assets::issue(Origin::signed(1), 100));
-			assert_eq!(Assets::balance(0, 1), 100);
-			assert_ok!(Assets::transfer(Origin::signed(1), 0, 2, 50));
-			assert_eq!(Assets::balance(0, 1), 50);
-			assert_eq!(Assets::balance(0, 2), 50);
-			assert_ok!(Assets::destroy(Origin::signed(1), 0));
-			assert_eq!(Assets::balance(0, 1), 0);
-			assert_noop!(Assets::transfer(Origin::signed(1), 0, 1, 50), Error::<Test>::BalanceLow);
+	/// Remove the approval of `key`, returning its deposit.
+	fn do_cancel_approval(id: T::AssetId, key: ApprovalKey<T::AccountId>) -> DispatchResult {
+		let approval = Approvals::<T>::take(id, &key).ok_or(Error::<T>::Unknown)?;
+		T::Currency::unreserve(&key.owner, approval.deposit);
+		Asset::<T>::mutate(id, |maybe_details| {
+			if let Some(details) = maybe_details {
+				details.approvals = details.approvals.saturating_sub(1);
+			}
 		});
+
+		Self::deposit_event(RawEvent::ApprovalCancelled(id, key.owner, key.delegate));
+		Ok(())
 	}
+}
 
-	#[test]
-	fn transferring_less_than_one_unit_should_not_work() {
-		new_test_ext().execute_with(|| {
-			assert_ok!(Assets::issue(Origin::signed(1), 100));
-			assert_eq!(Assets::balance(0, 1), 100);
-			assert_noop!(Assets::transfer(Origin::signed(1), 0, 2, 0), Error::<Test>::AmountZero);
-		});
+mod imbalances {
+	use super::{Trait, Asset, TryDrop, Zero};
+	use frame_support::StorageMap;
+	use sp_runtime::traits::Saturating;
+	use sp_std::mem;
+
+	/// Opaque, move-only struct with private fields that serves as a token denoting that units
+	/// of an asset have been removed from an account without any equal and opposite accounting.
+	///
+	/// When dropped, the units are removed from the total supply of the asset.
+	#[must_use]
+	pub struct NegativeImbalance<T: Trait> {
+		asset_id: T::AssetId,
+		amount: T::Balance,
 	}
 
-	#[test]
-	fn transferring_more_units_than_total_supply_should_not_work() {
-		new_test_ext().execute_with(|| {
-			assert_ok!(Assets::issue(Origin::signed(1), 100));
-			assert_eq!(Assets::balance(0, 1), 100);
-			assert_noop!(Assets::transfer(Origin::signed(1), 0, 2, 101), Error::<Test>::BalanceLow);
-		});
+	impl<T: Trait> NegativeImbalance<T> {
+		/// Create a new negative imbalance of `amount` units of the asset `asset_id`.
+		pub(super) fn new(asset_id: T::AssetId, amount: T::Balance) -> Self {
+			NegativeImbalance { asset_id, amount }
+		}
+
+		/// Create an empty negative imbalance of the asset `asset_id`.
+		pub fn zero(asset_id: T::AssetId) -> Self {
+			Self::new(asset_id, Zero::zero())
+		}
+
+		/// The asset of this imbalance.
+		pub fn asset_id(&self) -> T::AssetId {
+			self.asset_id
+		}
+
+		/// The number of units of this imbalance.
+		pub fn peek(&self) -> T::Balance {
+			self.amount
+		}
+
+		/// Split this imbalance into two, the first holding at most `amount` units.
+		pub fn split(self, amount: T::Balance) -> (Self, Self) {
+			let first = self.amount.min(amount);
+			let second = self.amount - first;
+			let asset_id = self.asset_id;
+
+			mem::forget(self);
+			(Self::new(asset_id, first), Self::new(asset_id, second))
+		}
+
+		/// Merge `other` into this imbalance.
+		///
+		/// Fails, returning both imbalances, if they are not of the same asset.
+		pub fn merge(mut self, other: Self) -> Result<Self, (Self, Self)> {
+			if self.asset_id != other.asset_id {
+				return Err((self, other));
+			}
+			self.amount = self.amount.saturating_add(other.amount);
+			mem::forget(other);
+
+			Ok(self)
+		}
+
+		/// Consume this imbalance without affecting the total supply, returning its amount.
+		pub(super) fn take(self) -> T::Balance {
+			let amount = self.amount;
+			mem::forget(self);
+			amount
+		}
 	}
 
-	#[test]
-	fn destroying_asset_balance_with_positive_balance_should_work() {
-		new_test_ext().execute_with(|| {
-			assert_ok!(Assets::issue(Origin::signed(1), 100));
-			assert_eq!(Assets::balance(0, 1), 100);
-			assert_ok!(Assets::destroy(Origin::signed(1), 0));
-		});
+	impl<T: Trait> TryDrop for NegativeImbalance<T> {
+		fn try_drop(self) -> Result<(), Self> {
+			if self.amount.is_zero() {
+				Ok(())
+			} else {
+				Err(self)
+			}
+		}
 	}
 
-	#[test]
-	fn destroying_asset_balance_with_zero_balance_should_not_work() {
-		new_test_ext().execute_with(|| {
-			assert_ok!(Assets::issue(Origin::signed(1), 100));
-			assert_eq!(Assets::balance(0, 2), 0);
-			assert_noop!(Assets::destroy(Origin::signed(2), 0), Error::<Test>::BalanceZero);
-		});
+	impl<T: Trait> Drop for NegativeImbalance<T> {
+		/// Basic drop handler will just square up the total supply.
+		fn drop(&mut self) {
+			if !self.amount.is_zero() {
+				<Asset<T>>::mutate(self.asset_id, |maybe_details| {
+					if let Some(details) = maybe_details {
+						details.supply = details.supply.saturating_sub(self.amount);
+					}
+				});
+			}
+		}
 	}
 }
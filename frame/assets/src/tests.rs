@@ -0,0 +1,1016 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2017-2020 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// Tests for Assets Pallet
+
+#![cfg(test)]
+
+use super::*;
+
+use frame_support::{
+	assert_ok, assert_noop, impl_outer_origin, impl_outer_event, parameter_types, weights::Weight,
+	traits::{fungibles, fungibles::SingleAsset, WithdrawConsequence, DepositConsequence},
+};
+use frame_system::EnsureRoot;
+use sp_core::H256;
+use sp_runtime::{Perbill, traits::{BadOrigin, BlakeTwo256, IdentityLookup}, testing::Header};
+use pallet_balances::Error as BalancesError;
+use crate as assets;
+
+impl_outer_origin! {
+	pub enum Origin for Test where system = frame_system {}
+}
+impl_outer_event! {
+	pub enum TestEvent for Test {
+		frame_system<T>,
+		pallet_balances<T>,
+		assets<T>,
+	}
+}
+
+#[derive(Clone, Eq, PartialEq)]
+pub struct Test;
+parameter_types! {
+	pub const BlockHashCount: u64 = 250;
+	pub const MaximumBlockWeight: Weight = 1024;
+	pub const MaximumBlockLength: u32 = 2 * 1024;
+	pub const AvailableBlockRatio: Perbill = Perbill::one();
+}
+impl frame_system::Trait for Test {
+	type BaseCallFilter = ();
+	type Origin = Origin;
+	type Index = u64;
+	type Call = ();
+	type BlockNumber = u64;
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type AccountId = u64;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type Header = Header;
+	type Event = TestEvent;
+	type BlockHashCount = BlockHashCount;
+	type MaximumBlockWeight = MaximumBlockWeight;
+	type DbWeight = ();
+	type BlockExecutionWeight = ();
+	type ExtrinsicBaseWeight = ();
+	type MaximumExtrinsicWeight = MaximumBlockWeight;
+	type AvailableBlockRatio = AvailableBlockRatio;
+	type MaximumBlockLength = MaximumBlockLength;
+	type Version = ();
+	type PalletInfo = ();
+	type AccountData = pallet_balances::AccountData<u64>;
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+}
+parameter_types! {
+	pub const ExistentialDeposit: u64 = 1;
+}
+impl pallet_balances::Trait for Test {
+	type Balance = u64;
+	type Event = TestEvent;
+	type DustRemoval = ();
+	type ExistentialDeposit = ExistentialDeposit;
+	type AccountStore = System;
+	type MaxLocks = ();
+	type MaxReserves = ();
+	type ReserveIdentifier = [u8; 8];
+	type WeightInfo = ();
+}
+parameter_types! {
+	pub const AssetDeposit: u64 = 1;
+	pub const ApprovalDeposit: u64 = 1;
+	pub const StringLimit: u32 = 50;
+	pub const MetadataDepositBase: u64 = 1;
+	pub const MetadataDepositPerByte: u64 = 1;
+}
+impl Trait for Test {
+	type Event = TestEvent;
+	type Balance = u64;
+	type AssetId = u32;
+	type Currency = Balances;
+	type ForceOrigin = EnsureRoot<u64>;
+	type AssetDeposit = AssetDeposit;
+	type MetadataDepositBase = MetadataDepositBase;
+	type MetadataDepositPerByte = MetadataDepositPerByte;
+	type ApprovalDeposit = ApprovalDeposit;
+	type StringLimit = StringLimit;
+	type WeightInfo = ();
+}
+type System = frame_system::Module<Test>;
+type Balances = pallet_balances::Module<Test>;
+type Assets = Module<Test>;
+
+pub(crate) fn new_test_ext() -> sp_io::TestExternalities {
+	let t = frame_system::GenesisConfig::default().build_storage::<Test>().unwrap();
+	let mut ext = sp_io::TestExternalities::new(t);
+	ext.execute_with(|| System::set_block_number(1));
+	ext
+}
+
+fn last_event() -> TestEvent {
+	System::events().pop().expect("Event expected").event
+}
+
+fn witness(id: u32) -> DestroyWitness {
+	let details = Assets::asset(id).unwrap();
+	DestroyWitness { accounts: details.accounts, approvals: details.approvals }
+}
+
+#[test]
+fn basic_minting_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, 1));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 1, 100));
+		assert_eq!(Assets::balance(0, 1), 100);
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 2, 100));
+		assert_eq!(Assets::balance(0, 2), 100);
+		assert_eq!(Assets::total_supply(0), 200);
+		assert_eq!(last_event(), TestEvent::assets(RawEvent::Issued(0, 2, 100)));
+	});
+}
+
+#[test]
+fn creating_an_asset_should_reserve_the_deposit() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&1, 100);
+		assert_ok!(Assets::create(Origin::signed(1), 0, 2, 1));
+		assert_eq!(Balances::reserved_balance(&1), 1);
+		assert_eq!(last_event(), TestEvent::assets(RawEvent::Created(0, 1, 2)));
+
+		// The admin is the whole team, the creator is the owner.
+		assert_noop!(Assets::mint(Origin::signed(1), 0, 1, 100), Error::<Test>::NoPermission);
+		assert_ok!(Assets::mint(Origin::signed(2), 0, 1, 100));
+
+		assert_noop!(Assets::create(Origin::signed(1), 0, 1, 1), Error::<Test>::InUse);
+		assert_noop!(Assets::create(Origin::signed(1), 1, 1, 0), Error::<Test>::MinBalanceZero);
+		assert_noop!(
+			Assets::create(Origin::signed(3), 1, 1, 1),
+			BalancesError::<Test, _>::InsufficientBalance,
+		);
+		assert_noop!(Assets::force_create(Origin::signed(1), 1, 1, 1), BadOrigin);
+	});
+}
+
+#[test]
+fn destroying_an_asset_should_remove_everything_and_return_deposits() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&1, 100);
+		assert_ok!(Assets::create(Origin::signed(1), 0, 1, 1));
+		assert_ok!(Assets::set_metadata(Origin::signed(1), 0, vec![0; 10], vec![0; 10], 12));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 1, 100));
+		assert_ok!(Assets::transfer(Origin::signed(1), 0, 2, 50));
+		assert_ok!(Assets::approve_transfer(Origin::signed(1), 0, 3, 50));
+		assert_eq!(Balances::reserved_balance(&1), 1 + 21 + 1);
+
+		let w = witness(0);
+		assert_eq!(w, DestroyWitness { accounts: 2, approvals: 1 });
+		assert_noop!(
+			Assets::destroy(Origin::signed(1), 0, DestroyWitness { accounts: 1, approvals: 1 }),
+			Error::<Test>::BadWitness,
+		);
+		assert_noop!(Assets::destroy(Origin::signed(2), 0, w), Error::<Test>::NoPermission);
+
+		assert_ok!(Assets::destroy(Origin::signed(1), 0, w));
+		assert_eq!(Balances::reserved_balance(&1), 0);
+		assert!(Assets::asset(0).is_none());
+		assert_eq!(Assets::balance(0, 1), 0);
+		assert_eq!(Assets::balance(0, 2), 0);
+		assert!(!Metadata::<Test>::contains_key(0));
+		assert!(Approvals::<Test>::iter_prefix(0).next().is_none());
+		assert_eq!(last_event(), TestEvent::assets(RawEvent::Destroyed(0)));
+	});
+}
+
+#[test]
+fn force_destroying_an_asset_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, 1));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 1, 100));
+		assert_ok!(Assets::destroy(Origin::root(), 0, witness(0)));
+		assert_eq!(Assets::total_supply(0), 0);
+		assert_noop!(Assets::destroy(Origin::root(), 0, Default::default()), Error::<Test>::Unknown);
+	});
+}
+
+#[test]
+fn min_balance_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, 10));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 1, 100));
+		assert_eq!(Assets::asset(0).unwrap().accounts, 1);
+
+		// Cannot create a new account with a balance that is below minimum...
+		assert_noop!(Assets::mint(Origin::signed(1), 0, 2, 9), Error::<Test>::BelowMinimum);
+		assert_noop!(Assets::transfer(Origin::signed(1), 0, 2, 9), Error::<Test>::BelowMinimum);
+		assert_noop!(Assets::force_transfer(Origin::signed(1), 0, 1, 2, 9), Error::<Test>::BelowMinimum);
+
+		// When deducting from an account to below minimum, it should be reaped.
+		assert_ok!(Assets::transfer(Origin::signed(1), 0, 2, 91));
+		assert_eq!(Assets::balance(0, 1), 0);
+		assert_eq!(Assets::balance(0, 2), 100);
+		assert_eq!(Assets::asset(0).unwrap().accounts, 1);
+		assert_eq!(last_event(), TestEvent::assets(RawEvent::Transferred(0, 1, 2, 100)));
+
+		assert_ok!(Assets::force_transfer(Origin::signed(1), 0, 2, 1, 91));
+		assert_eq!(Assets::balance(0, 2), 0);
+		assert_eq!(Assets::balance(0, 1), 100);
+		assert_eq!(Assets::asset(0).unwrap().accounts, 1);
+
+		assert_ok!(Assets::burn(Origin::signed(1), 0, 1, 91));
+		assert_eq!(Assets::balance(0, 1), 0);
+		assert_eq!(Assets::asset(0).unwrap().accounts, 0);
+		assert_eq!(Assets::total_supply(0), 0);
+		assert_eq!(last_event(), TestEvent::assets(RawEvent::Burned(0, 1, 100)));
+	});
+}
+
+#[test]
+fn querying_total_supply_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, 1));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 1, 100));
+		assert_eq!(Assets::balance(0, 1), 100);
+		assert_ok!(Assets::transfer(Origin::signed(1), 0, 2, 50));
+		assert_eq!(Assets::balance(0, 1), 50);
+		assert_eq!(Assets::balance(0, 2), 50);
+		assert_ok!(Assets::transfer(Origin::signed(2), 0, 3, 31));
+		assert_eq!(Assets::balance(0, 1), 50);
+		assert_eq!(Assets::balance(0, 2), 19);
+		assert_eq!(Assets::balance(0, 3), 31);
+		assert_ok!(Assets::burn(Origin::signed(1), 0, 3, u64::max_value()));
+		assert_eq!(Assets::total_supply(0), 69);
+	});
+}
+
+#[test]
+fn transferring_amount_below_available_balance_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, 1));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 1, 100));
+		assert_eq!(Assets::balance(0, 1), 100);
+		assert_ok!(Assets::transfer(Origin::signed(1), 0, 2, 50));
+		assert_eq!(Assets::balance(0, 1), 50);
+		assert_eq!(Assets::balance(0, 2), 50);
+	});
+}
+
+#[test]
+fn transferring_amount_more_than_available_balance_should_not_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, 1));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 1, 100));
+		assert_eq!(Assets::balance(0, 1), 100);
+		assert_ok!(Assets::transfer(Origin::signed(1), 0, 2, 50));
+		assert_eq!(Assets::balance(0, 1), 50);
+		assert_eq!(Assets::balance(0, 2), 50);
+		assert_ok!(Assets::burn(Origin::signed(1), 0, 1, u64::max_value()));
+		assert_eq!(Assets::balance(0, 1), 0);
+		assert_noop!(Assets::transfer(Origin::signed(1), 0, 1, 50), Error::<Test>::BalanceLow);
+	});
+}
+
+#[test]
+fn transferring_less_than_one_unit_should_not_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, 1));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 1, 100));
+		assert_eq!(Assets::balance(0, 1), 100);
+		assert_noop!(Assets::transfer(Origin::signed(1), 0, 2, 0), Error::<Test>::AmountZero);
+	});
+}
+
+#[test]
+fn transferring_more_units_than_total_supply_should_not_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, 1));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 1, 100));
+		assert_eq!(Assets::balance(0, 1), 100);
+		assert_noop!(Assets::transfer(Origin::signed(1), 0, 2, 101), Error::<Test>::BalanceLow);
+	});
+}
+
+#[test]
+fn burning_asset_balance_with_positive_balance_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, 1));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 1, 100));
+		assert_eq!(Assets::balance(0, 1), 100);
+		assert_noop!(Assets::burn(Origin::signed(2), 0, 1, 10), Error::<Test>::NoPermission);
+		assert_ok!(Assets::burn(Origin::signed(1), 0, 1, 10));
+		assert_eq!(Assets::balance(0, 1), 90);
+	});
+}
+
+#[test]
+fn burning_asset_balance_with_zero_balance_should_not_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, 1));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 1, 100));
+		assert_eq!(Assets::balance(0, 2), 0);
+		assert_noop!(Assets::burn(Origin::signed(1), 0, 2, u64::max_value()), Error::<Test>::BalanceZero);
+	});
+}
+
+#[test]
+fn freezing_and_thawing_accounts_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, 1));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 1, 100));
+		assert_ok!(Assets::set_team(Origin::signed(1), 0, 1, 1, 3));
+
+		assert_noop!(Assets::freeze(Origin::signed(1), 0, 1), Error::<Test>::NoPermission);
+		assert_noop!(Assets::freeze(Origin::signed(3), 0, 2), Error::<Test>::BalanceZero);
+		assert_ok!(Assets::freeze(Origin::signed(3), 0, 1));
+		assert_noop!(Assets::transfer(Origin::signed(1), 0, 2, 50), Error::<Test>::Frozen);
+		assert_eq!(Assets::withdraw(0, &1, 50).err(), Some(Error::<Test>::Frozen.into()));
+
+		// The admin can still move the funds.
+		assert_ok!(Assets::force_transfer(Origin::signed(1), 0, 1, 2, 10));
+
+		assert_noop!(Assets::thaw(Origin::signed(3), 0, 1), Error::<Test>::NoPermission);
+		assert_ok!(Assets::thaw(Origin::signed(1), 0, 1));
+		assert_ok!(Assets::transfer(Origin::signed(1), 0, 2, 50));
+		assert_eq!(Assets::balance(0, 2), 60);
+	});
+}
+
+#[test]
+fn freezing_and_thawing_assets_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, 1));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 1, 100));
+		assert_ok!(Assets::set_team(Origin::signed(1), 0, 1, 1, 3));
+
+		assert_noop!(Assets::freeze_asset(Origin::signed(1), 0), Error::<Test>::NoPermission);
+		assert_ok!(Assets::freeze_asset(Origin::signed(3), 0));
+		assert_eq!(last_event(), TestEvent::assets(RawEvent::AssetFrozen(0)));
+		assert_noop!(Assets::transfer(Origin::signed(1), 0, 2, 50), Error::<Test>::Frozen);
+
+		assert_noop!(Assets::thaw_asset(Origin::signed(3), 0), Error::<Test>::NoPermission);
+		assert_ok!(Assets::thaw_asset(Origin::signed(1), 0));
+		assert_ok!(Assets::transfer(Origin::signed(1), 0, 2, 50));
+	});
+}
+
+#[test]
+fn origin_guards_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, 1));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 1, 100));
+		assert_noop!(Assets::transfer_ownership(Origin::signed(2), 0, 2), Error::<Test>::NoPermission);
+		assert_noop!(Assets::set_team(Origin::signed(2), 0, 2, 2, 2), Error::<Test>::NoPermission);
+		assert_noop!(Assets::freeze(Origin::signed(2), 0, 1), Error::<Test>::NoPermission);
+		assert_noop!(Assets::thaw(Origin::signed(2), 0, 2), Error::<Test>::NoPermission);
+		assert_noop!(Assets::mint(Origin::signed(2), 0, 2, 100), Error::<Test>::NoPermission);
+		assert_noop!(Assets::burn(Origin::signed(2), 0, 1, 100), Error::<Test>::NoPermission);
+		assert_noop!(Assets::force_transfer(Origin::signed(2), 0, 1, 2, 100), Error::<Test>::NoPermission);
+		assert_noop!(Assets::destroy(Origin::signed(2), 0, witness(0)), Error::<Test>::NoPermission);
+		assert_noop!(Assets::mint(Origin::signed(1), 1, 1, 100), Error::<Test>::Unknown);
+	});
+}
+
+#[test]
+fn transfer_owner_should_work() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&1, 100);
+		Balances::make_free_balance_be(&2, 100);
+		assert_ok!(Assets::create(Origin::signed(1), 0, 1, 1));
+		assert_ok!(Assets::set_metadata(Origin::signed(1), 0, vec![0u8; 10], vec![0u8; 10], 12));
+		assert_eq!(Balances::reserved_balance(&1), 22);
+
+		assert_ok!(Assets::transfer_ownership(Origin::signed(1), 0, 2));
+		assert_eq!(last_event(), TestEvent::assets(RawEvent::OwnerChanged(0, 2)));
+		assert_eq!(Balances::reserved_balance(&2), 22);
+		assert_eq!(Balances::reserved_balance(&1), 0);
+
+		assert_noop!(Assets::transfer_ownership(Origin::signed(1), 0, 1), Error::<Test>::NoPermission);
+
+		assert_ok!(Assets::transfer_ownership(Origin::signed(2), 0, 1));
+		assert_eq!(Balances::reserved_balance(&1), 22);
+		assert_eq!(Balances::reserved_balance(&2), 0);
+	});
+}
+
+#[test]
+fn set_metadata_should_work() {
+	new_test_ext().execute_with(|| {
+		// Cannot add metadata to unknown asset
+		assert_noop!(
+			Assets::set_metadata(Origin::signed(1), 0, vec![0u8; 10], vec![0u8; 10], 12),
+			Error::<Test>::Unknown,
+		);
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, 1));
+		// Cannot add metadata to unowned asset
+		assert_noop!(
+			Assets::set_metadata(Origin::signed(2), 0, vec![0u8; 10], vec![0u8; 10], 12),
+			Error::<Test>::NoPermission,
+		);
+
+		// Cannot add oversized metadata
+		assert_noop!(
+			Assets::set_metadata(Origin::signed(1), 0, vec![0u8; 100], vec![0u8; 10], 12),
+			Error::<Test>::BadMetadata,
+		);
+		assert_noop!(
+			Assets::set_metadata(Origin::signed(1), 0, vec![0u8; 10], vec![0u8; 100], 12),
+			Error::<Test>::BadMetadata,
+		);
+
+		// Successfully add metadata and take deposit
+		Balances::make_free_balance_be(&1, 30);
+		assert_ok!(Assets::set_metadata(Origin::signed(1), 0, vec![0u8; 10], vec![0u8; 10], 12));
+		assert_eq!(Balances::free_balance(&1), 9);
+		assert_eq!(Assets::metadata(0).decimals, 12);
+
+		// Update deposit
+		assert_ok!(Assets::set_metadata(Origin::signed(1), 0, vec![0u8; 10], vec![0u8; 5], 12));
+		assert_eq!(Balances::free_balance(&1), 14);
+		assert_ok!(Assets::set_metadata(Origin::signed(1), 0, vec![0u8; 10], vec![0u8; 15], 12));
+		assert_eq!(Balances::free_balance(&1), 4);
+
+		// Cannot over-reserve
+		assert_noop!(
+			Assets::set_metadata(Origin::signed(1), 0, vec![0u8; 20], vec![0u8; 20], 12),
+			BalancesError::<Test, _>::InsufficientBalance,
+		);
+
+		// Clear Metadata
+		assert!(Metadata::<Test>::contains_key(0));
+		assert_noop!(Assets::clear_metadata(Origin::signed(2), 0), Error::<Test>::NoPermission);
+		assert_noop!(Assets::clear_metadata(Origin::signed(1), 1), Error::<Test>::Unknown);
+		assert_ok!(Assets::clear_metadata(Origin::signed(1), 0));
+		assert!(!Metadata::<Test>::contains_key(0));
+		assert_eq!(Balances::free_balance(&1), 30);
+	});
+}
+
+#[test]
+fn force_metadata_should_work() {
+	new_test_ext().execute_with(|| {
+		// Force set metadata works
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, 1));
+		assert_ok!(Assets::force_set_metadata(Origin::root(), 0, vec![0u8; 10], vec![0u8; 10], 8));
+		assert!(Metadata::<Test>::contains_key(0));
+		assert_eq!(Assets::metadata(0).deposit, 0);
+
+		// Force set metadata with unknown asset or bad origin fails
+		assert_noop!(
+			Assets::force_set_metadata(Origin::root(), 1, vec![0u8; 10], vec![0u8; 10], 8),
+			Error::<Test>::Unknown,
+		);
+		assert_noop!(
+			Assets::force_set_metadata(Origin::signed(1), 0, vec![0u8; 10], vec![0u8; 10], 8),
+			BadOrigin,
+		);
+		assert_noop!(
+			Assets::force_set_metadata(Origin::root(), 0, vec![0u8; 100], vec![0u8; 10], 8),
+			Error::<Test>::BadMetadata,
+		);
+
+		// Owner-set metadata pays a deposit, which is returned by a forced clear.
+		Balances::make_free_balance_be(&1, 30);
+		assert_ok!(Assets::set_metadata(Origin::signed(1), 0, vec![0u8; 10], vec![0u8; 10], 12));
+		assert_eq!(Balances::reserved_balance(&1), 21);
+		assert_noop!(Assets::force_clear_metadata(Origin::signed(1), 0), BadOrigin);
+		assert_ok!(Assets::force_clear_metadata(Origin::root(), 0));
+		assert!(!Metadata::<Test>::contains_key(0));
+		assert_eq!(Balances::reserved_balance(&1), 0);
+		assert_noop!(Assets::force_clear_metadata(Origin::root(), 0), Error::<Test>::Unknown);
+	});
+}
+
+#[test]
+fn force_asset_status_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, 10));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 1, 50));
+
+		assert_noop!(
+			Assets::force_asset_status(Origin::signed(1), 0, 2, 2, 2, 2, 100, true),
+			BadOrigin,
+		);
+		assert_noop!(
+			Assets::force_asset_status(Origin::root(), 1, 2, 2, 2, 2, 100, true),
+			Error::<Test>::Unknown,
+		);
+		assert_ok!(Assets::force_asset_status(Origin::root(), 0, 2, 2, 2, 2, 100, true));
+		assert_eq!(last_event(), TestEvent::assets(RawEvent::AssetStatusChanged(0)));
+
+		// The team changed and the asset is frozen.
+		assert_noop!(Assets::mint(Origin::signed(1), 0, 1, 100), Error::<Test>::NoPermission);
+		assert_noop!(Assets::transfer(Origin::signed(1), 0, 3, 10), Error::<Test>::Frozen);
+
+		// The new minimum balance applies to new accounts.
+		assert_noop!(Assets::mint(Origin::signed(2), 0, 3, 99), Error::<Test>::BelowMinimum);
+		assert_ok!(Assets::mint(Origin::signed(2), 0, 3, 100));
+	});
+}
+
+#[test]
+fn force_asset_status_moves_deposits_to_new_owner() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&1, 100);
+		Balances::make_free_balance_be(&2, 100);
+		assert_ok!(Assets::create(Origin::signed(1), 0, 1, 1));
+		assert_ok!(Assets::set_metadata(Origin::signed(1), 0, vec![0u8; 10], vec![0u8; 10], 12));
+		assert_eq!(Balances::reserved_balance(&1), 22);
+
+		assert_ok!(Assets::force_asset_status(Origin::root(), 0, 2, 1, 1, 1, 1, false));
+		assert_eq!(Balances::total_balance(&1), 78);
+		assert_eq!(Balances::reserved_balance(&1), 0);
+		assert_eq!(Balances::reserved_balance(&2), 22);
+
+		// The new owner gets the deposits back.
+		assert_ok!(Assets::clear_metadata(Origin::signed(2), 0));
+		assert_eq!(Balances::reserved_balance(&2), 1);
+		assert_ok!(Assets::destroy(Origin::signed(2), 0, witness(0)));
+		assert_eq!(Balances::reserved_balance(&2), 0);
+		assert_eq!(Balances::free_balance(&2), 122);
+	});
+}
+
+#[test]
+fn approval_lifecycle_works() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, 1));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 1, 100));
+		Balances::make_free_balance_be(&1, 1);
+		assert_ok!(Assets::approve_transfer(Origin::signed(1), 0, 2, 50));
+		assert_eq!(Balances::reserved_balance(&1), 1);
+		assert_eq!(Assets::asset(0).unwrap().approvals, 1);
+
+		assert_ok!(Assets::transfer_approved(Origin::signed(2), 0, 1, 3, 40));
+		assert_eq!(last_event(), TestEvent::assets(RawEvent::TransferredApproved(0, 1, 2, 3, 40)));
+		assert_eq!(Assets::asset(0).unwrap().approvals, 1);
+		assert_ok!(Assets::transfer_approved(Origin::signed(2), 0, 1, 3, 10));
+		assert_eq!(Assets::balance(0, 1), 50);
+		assert_eq!(Assets::balance(0, 3), 50);
+
+		// The approval is used up.
+		assert_eq!(Balances::reserved_balance(&1), 0);
+		assert_eq!(Assets::asset(0).unwrap().approvals, 0);
+		assert_noop!(
+			Assets::transfer_approved(Origin::signed(2), 0, 1, 3, 1),
+			Error::<Test>::Unapproved,
+		);
+	});
+}
+
+#[test]
+fn approvals_should_add_up() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, 1));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 1, 100));
+		Balances::make_free_balance_be(&1, 1);
+		assert_ok!(Assets::approve_transfer(Origin::signed(1), 0, 2, 30));
+		assert_ok!(Assets::approve_transfer(Origin::signed(1), 0, 2, 20));
+		// The deposit is only taken once.
+		assert_eq!(Balances::reserved_balance(&1), 1);
+		assert_eq!(Assets::asset(0).unwrap().approvals, 1);
+
+		assert_noop!(
+			Assets::transfer_approved(Origin::signed(2), 0, 1, 3, 51),
+			Error::<Test>::Unapproved,
+		);
+		assert_ok!(Assets::transfer_approved(Origin::signed(2), 0, 1, 3, 50));
+		assert_eq!(Assets::balance(0, 3), 50);
+	});
+}
+
+#[test]
+fn transfer_approved_respects_freezing_and_balance() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, 1));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 1, 100));
+		Balances::make_free_balance_be(&1, 1);
+		assert_ok!(Assets::approve_transfer(Origin::signed(1), 0, 2, 200));
+
+		assert_noop!(
+			Assets::transfer_approved(Origin::signed(2), 0, 1, 3, 101),
+			Error::<Test>::BalanceLow,
+		);
+		assert_ok!(Assets::freeze(Origin::signed(1), 0, 1));
+		assert_noop!(
+			Assets::transfer_approved(Origin::signed(2), 0, 1, 3, 50),
+			Error::<Test>::Frozen,
+		);
+	});
+}
+
+#[test]
+fn transfer_approved_moves_exact_amount() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, 10));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 1, 100));
+		Balances::make_free_balance_be(&1, 1);
+		assert_ok!(Assets::approve_transfer(Origin::signed(1), 0, 2, 95));
+
+		// The remainder of 5 would be below the minimum balance.
+		assert_noop!(
+			Assets::transfer_approved(Origin::signed(2), 0, 1, 3, 95),
+			Error::<Test>::BelowMinimum,
+		);
+		assert_ok!(Assets::transfer_approved(Origin::signed(2), 0, 1, 3, 90));
+		assert_eq!(last_event(), TestEvent::assets(RawEvent::TransferredApproved(0, 1, 2, 3, 90)));
+		assert_eq!(Assets::balance(0, 1), 10);
+		assert_eq!(Assets::balance(0, 3), 90);
+		// Only the transferred amount was used from the approval.
+		assert_noop!(
+			Assets::transfer_approved(Origin::signed(2), 0, 1, 3, 6),
+			Error::<Test>::Unapproved,
+		);
+		assert_eq!(Assets::asset(0).unwrap().approvals, 1);
+	});
+}
+
+#[test]
+fn cancel_approval_works() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, 1));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 1, 100));
+		Balances::make_free_balance_be(&1, 1);
+		assert_ok!(Assets::approve_transfer(Origin::signed(1), 0, 2, 50));
+		assert_noop!(Assets::cancel_approval(Origin::signed(1), 1, 2), Error::<Test>::Unknown);
+		assert_noop!(Assets::cancel_approval(Origin::signed(2), 0, 2), Error::<Test>::Unknown);
+		assert_noop!(Assets::cancel_approval(Origin::signed(1), 0, 3), Error::<Test>::Unknown);
+		assert_ok!(Assets::cancel_approval(Origin::signed(1), 0, 2));
+		assert_eq!(last_event(), TestEvent::assets(RawEvent::ApprovalCancelled(0, 1, 2)));
+		assert_eq!(Balances::reserved_balance(&1), 0);
+		assert_eq!(Assets::asset(0).unwrap().approvals, 0);
+		assert_noop!(Assets::cancel_approval(Origin::signed(1), 0, 2), Error::<Test>::Unknown);
+	});
+}
+
+#[test]
+fn force_cancel_approval_works() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, 1));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 1, 100));
+		Balances::make_free_balance_be(&1, 2);
+		assert_ok!(Assets::approve_transfer(Origin::signed(1), 0, 2, 50));
+		assert_ok!(Assets::approve_transfer(Origin::signed(1), 0, 3, 50));
+		assert_eq!(Assets::asset(0).unwrap().approvals, 2);
+
+		assert_noop!(
+			Assets::force_cancel_approval(Origin::signed(2), 0, 1, 2),
+			Error::<Test>::NoPermission,
+		);
+		assert_noop!(Assets::force_cancel_approval(Origin::root(), 1, 1, 2), Error::<Test>::Unknown);
+		assert_noop!(Assets::force_cancel_approval(Origin::root(), 0, 2, 2), Error::<Test>::Unknown);
+
+		// Both the admin and the forcing origin can cancel an approval.
+		assert_ok!(Assets::force_cancel_approval(Origin::signed(1), 0, 1, 2));
+		assert_ok!(Assets::force_cancel_approval(Origin::root(), 0, 1, 3));
+		assert_eq!(Balances::reserved_balance(&1), 0);
+		assert_eq!(Assets::asset(0).unwrap().approvals, 0);
+	});
+}
+
+#[test]
+fn withdrawing_and_resolving_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, 10));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 1, 100));
+		assert_eq!(Assets::withdraw(0, &1, 101).err(), Some(Error::<Test>::BalanceLow.into()));
+		// The account is kept alive.
+		assert_eq!(Assets::withdraw(0, &1, 91).err(), Some(Error::<Test>::WouldDie.into()));
+		assert_eq!(Assets::balance(0, 1), 100);
+
+		let imbalance = Assets::withdraw(0, &1, 30).unwrap();
+		assert_eq!((imbalance.asset_id(), imbalance.peek()), (0, 30));
+		assert_eq!(Assets::balance(0, 1), 70);
+		assert_eq!(Assets::total_supply(0), 100);
+
+		let (to_resolve, to_drop) = imbalance.split(20);
+		assert!(Assets::resolve(&2, to_resolve).is_ok());
+		assert_eq!(Assets::balance(0, 2), 20);
+		assert_eq!(Assets::total_supply(0), 100);
+
+		drop(to_drop);
+		assert_eq!(Assets::total_supply(0), 90);
+	});
+}
+
+#[test]
+fn resolving_below_minimum_balance_should_not_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, 10));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 1, 100));
+
+		let imbalance = Assets::withdraw(0, &1, 5).unwrap();
+		let imbalance = Assets::resolve(&2, imbalance).err().unwrap();
+		assert_eq!(Assets::balance(0, 2), 0);
+		assert_eq!(Assets::asset(0).unwrap().accounts, 1);
+
+		// Existing accounts can receive any amount.
+		assert!(Assets::resolve(&1, imbalance).is_ok());
+		assert_eq!(Assets::balance(0, 1), 100);
+	});
+}
+
+#[test]
+fn merging_imbalances_of_different_assets_should_not_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, 1));
+		assert_ok!(Assets::force_create(Origin::root(), 1, 1, 1));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 1, 100));
+		assert_ok!(Assets::mint(Origin::signed(1), 1, 1, 100));
+
+		let first = Assets::withdraw(0, &1, 10).unwrap();
+		let second = Assets::withdraw(0, &1, 5).unwrap();
+		let merged = first.merge(second).ok().unwrap();
+		assert_eq!(merged.peek(), 15);
+
+		let other = Assets::withdraw(1, &1, 5).unwrap();
+		let (merged, other) = merged.merge(other).err().unwrap();
+		assert!(Assets::resolve(&1, merged).is_ok());
+		assert!(Assets::resolve(&1, other).is_ok());
+		assert_eq!(Assets::balance(0, 1), 100);
+		assert_eq!(Assets::balance(1, 1), 100);
+	});
+}
+
+#[test]
+fn fungibles_inspect_works() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, 10));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 1, 100));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 2, 100));
+
+		assert_eq!(<Assets as fungibles::Inspect<_>>::total_issuance(0), 200);
+		assert_eq!(<Assets as fungibles::Inspect<_>>::minimum_balance(0), 10);
+		assert_eq!(<Assets as fungibles::Inspect<_>>::balance(0, &1), 100);
+		assert_eq!(<Assets as fungibles::Inspect<_>>::reducible_balance(0, &1, true), 90);
+		assert_eq!(<Assets as fungibles::Inspect<_>>::reducible_balance(0, &1, false), 100);
+
+		assert_eq!(
+			<Assets as fungibles::Inspect<_>>::can_withdraw(0, &1, 50),
+			WithdrawConsequence::Success,
+		);
+		assert_eq!(
+			<Assets as fungibles::Inspect<_>>::can_withdraw(0, &1, 95),
+			WithdrawConsequence::ReducedToZero(5),
+		);
+		assert_eq!(
+			<Assets as fungibles::Inspect<_>>::can_withdraw(0, &1, 101),
+			WithdrawConsequence::NoFunds,
+		);
+		assert_eq!(
+			<Assets as fungibles::Inspect<_>>::can_withdraw(1, &1, 1),
+			WithdrawConsequence::UnknownAsset,
+		);
+
+		assert_eq!(
+			<Assets as fungibles::Inspect<_>>::can_deposit(0, &3, 5),
+			DepositConsequence::BelowMinimum,
+		);
+		assert_eq!(<Assets as fungibles::Inspect<_>>::can_deposit(0, &3, 10), DepositConsequence::Success);
+		assert_eq!(
+			<Assets as fungibles::Inspect<_>>::can_deposit(0, &1, u64::max_value()),
+			DepositConsequence::Overflow,
+		);
+		assert_eq!(
+			<Assets as fungibles::Inspect<_>>::can_deposit(1, &3, 10),
+			DepositConsequence::UnknownAsset,
+		);
+
+		assert_ok!(Assets::freeze(Origin::signed(1), 0, 1));
+		assert_eq!(<Assets as fungibles::Inspect<_>>::reducible_balance(0, &1, false), 0);
+		assert_eq!(
+			<Assets as fungibles::Inspect<_>>::can_withdraw(0, &1, 1),
+			WithdrawConsequence::Frozen,
+		);
+	});
+}
+
+#[test]
+fn fungibles_mutate_and_transfer_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, 10));
+
+		assert_noop!(
+			<Assets as fungibles::Mutate<_>>::mint_into(0, &1, 5),
+			Error::<Test>::BelowMinimum,
+		);
+		assert_ok!(<Assets as fungibles::Mutate<_>>::mint_into(0, &1, 100));
+		assert_eq!(Assets::total_supply(0), 100);
+		assert_eq!(last_event(), TestEvent::assets(RawEvent::Issued(0, 1, 100)));
+
+		assert_noop!(
+			<Assets as fungibles::Transfer<_>>::transfer(0, &1, &2, 95, true),
+			Error::<Test>::WouldDie,
+		);
+		assert_eq!(<Assets as fungibles::Transfer<_>>::transfer(0, &1, &2, 90, true), Ok(90));
+		assert_eq!(Assets::balance(0, 1), 10);
+		assert_eq!(Assets::balance(0, 2), 90);
+
+		// Exactly the requested amount is moved, the remainder can't be left below the minimum.
+		assert_noop!(
+			<Assets as fungibles::Transfer<_>>::transfer(0, &1, &2, 5, false),
+			Error::<Test>::BelowMinimum,
+		);
+		assert_eq!(<Assets as fungibles::Transfer<_>>::transfer(0, &1, &2, 10, false), Ok(10));
+		assert_eq!(Assets::balance(0, 1), 0);
+		assert_eq!(Assets::asset(0).unwrap().accounts, 1);
+
+		assert_eq!(<Assets as fungibles::Mutate<_>>::burn_from(0, &2, 95), Ok(100));
+		assert_eq!(Assets::total_supply(0), 0);
+		assert_eq!(Assets::asset(0).unwrap().accounts, 0);
+		assert_noop!(
+			<Assets as fungibles::Mutate<_>>::burn_from(0, &2, 1),
+			DispatchError::Other("Underflow"),
+		);
+	});
+}
+
+#[test]
+fn fungibles_unbalanced_works() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, 10));
+
+		assert_noop!(
+			<Assets as fungibles::Unbalanced<_>>::set_balance(0, &1, 5),
+			Error::<Test>::BelowMinimum,
+		);
+		assert_ok!(<Assets as fungibles::Unbalanced<_>>::set_balance(0, &1, 50));
+		assert_eq!(Assets::balance(0, 1), 50);
+		assert_eq!(Assets::asset(0).unwrap().accounts, 1);
+		assert_eq!(Assets::total_supply(0), 0);
+
+		<Assets as fungibles::Unbalanced<_>>::set_total_issuance(0, 50);
+		assert_eq!(Assets::total_supply(0), 50);
+
+		// Frozen balances can't be reduced, but can still be increased.
+		assert_ok!(Assets::freeze(Origin::signed(1), 0, 1));
+		assert_noop!(
+			<Assets as fungibles::Unbalanced<_>>::set_balance(0, &1, 40),
+			Error::<Test>::Frozen,
+		);
+		assert_ok!(<Assets as fungibles::Unbalanced<_>>::set_balance(0, &1, 60));
+		assert_ok!(Assets::thaw(Origin::signed(1), 0, 1));
+		assert_ok!(<Assets as fungibles::Unbalanced<_>>::set_balance(0, &1, 50));
+
+		assert_eq!(<Assets as fungibles::Unbalanced<_>>::decrease_balance(0, &1, 45), Ok(50));
+		assert_eq!(Assets::balance(0, 1), 0);
+		assert_eq!(Assets::asset(0).unwrap().accounts, 0);
+	});
+}
+
+#[test]
+fn fungibles_unbalanced_keeps_funds_on_hold() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, 10));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 1, 100));
+		assert_ok!(<Assets as fungibles::MutateHold<_>>::hold(0, &1, 30));
+
+		assert_eq!(<Assets as fungibles::Unbalanced<_>>::increase_balance(0, &1, 10), Ok(10));
+		assert_eq!(Assets::balance(0, 1), 110);
+		assert_eq!(<Assets as fungibles::Inspect<_>>::balance(0, &1), 80);
+
+		assert_eq!(<Assets as fungibles::Unbalanced<_>>::decrease_balance(0, &1, 20), Ok(20));
+		assert_eq!(Assets::balance(0, 1), 90);
+		assert_eq!(<Assets as fungibles::Inspect<_>>::balance(0, &1), 60);
+
+		// Only the free balance can be decreased, the remainder of 5 is below the minimum balance
+		// and decreased as well.
+		assert_eq!(
+			<Assets as fungibles::Unbalanced<_>>::decrease_balance(0, &1, 61),
+			Err(DispatchError::Other("NoFunds")),
+		);
+		assert_eq!(<Assets as fungibles::Unbalanced<_>>::decrease_balance(0, &1, 55), Ok(60));
+		assert_eq!(Assets::balance(0, 1), 30);
+		assert_eq!(<Assets as fungibles::Inspect<_>>::balance(0, &1), 0);
+		assert_eq!(<Assets as fungibles::InspectHold<_>>::balance_on_hold(0, &1), 30);
+		assert_eq!(Assets::asset(0).unwrap().accounts, 1);
+	});
+}
+
+#[test]
+fn fungibles_hold_works() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, 10));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 1, 100));
+
+		assert!(<Assets as fungibles::InspectHold<_>>::can_hold(0, &1, 100));
+		assert!(!<Assets as fungibles::InspectHold<_>>::can_hold(0, &1, 101));
+		assert_noop!(<Assets as fungibles::MutateHold<_>>::hold(0, &1, 101), Error::<Test>::BalanceLow);
+		assert_ok!(<Assets as fungibles::MutateHold<_>>::hold(0, &1, 60));
+		assert_eq!(<Assets as fungibles::InspectHold<_>>::balance_on_hold(0, &1), 60);
+		assert!(!<Assets as fungibles::InspectHold<_>>::can_hold(0, &1, 41));
+
+		// Funds on hold remain part of the account balance but can't be moved or burned, and are
+		// not part of the `fungibles` balance.
+		assert_eq!(Assets::balance(0, 1), 100);
+		assert_eq!(<Assets as fungibles::Inspect<_>>::balance(0, &1), 40);
+		assert_eq!(<Assets as fungibles::Inspect<_>>::reducible_balance(0, &1, false), 40);
+		assert_noop!(Assets::transfer(Origin::signed(1), 0, 2, 41), Error::<Test>::FundsOnHold);
+		// The held funds stay on top of the balance that is set.
+		assert_ok!(<Assets as fungibles::Unbalanced<_>>::set_balance(0, &1, 40));
+		assert_eq!(Assets::balance(0, 1), 100);
+		assert_eq!(Assets::withdraw(0, &1, 41).err(), Some(Error::<Test>::FundsOnHold.into()));
+		assert_eq!(Assets::balance(0, 1), 100);
+		assert_ok!(Assets::burn(Origin::signed(1), 0, 1, 100));
+		assert_eq!(Assets::balance(0, 1), 60);
+		assert_eq!(Assets::total_supply(0), 60);
+
+		// Held funds are transferred either on hold or free.
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 2, 10));
+		assert_eq!(<Assets as fungibles::MutateHold<_>>::transfer_held(0, &1, &2, 20, false, true), Ok(20));
+		assert_eq!(<Assets as fungibles::InspectHold<_>>::balance_on_hold(0, &2), 20);
+		assert_eq!(Assets::balance(0, 2), 30);
+		assert_noop!(
+			<Assets as fungibles::MutateHold<_>>::transfer_held(0, &1, &3, 40, false, true),
+			Error::<Test>::BalanceZero,
+		);
+		// The remainder of 5 would be below the minimum balance.
+		assert_noop!(
+			<Assets as fungibles::MutateHold<_>>::transfer_held(0, &1, &3, 35, false, false),
+			Error::<Test>::BelowMinimum,
+		);
+		assert_eq!(<Assets as fungibles::MutateHold<_>>::transfer_held(0, &1, &3, 50, true, false), Ok(40));
+		assert_eq!(Assets::balance(0, 1), 0);
+		assert_eq!(Assets::balance(0, 3), 40);
+		assert_eq!(<Assets as fungibles::InspectHold<_>>::balance_on_hold(0, &1), 0);
+
+		// Released funds are free again.
+		assert_noop!(
+			<Assets as fungibles::MutateHold<_>>::release(0, &2, 21, false),
+			Error::<Test>::BalanceLow,
+		);
+		assert_eq!(<Assets as fungibles::MutateHold<_>>::release(0, &2, 30, true), Ok(20));
+		assert_ok!(Assets::transfer(Origin::signed(2), 0, 3, 30));
+		assert_eq!(Assets::balance(0, 3), 70);
+	});
+}
+
+#[test]
+fn fungibles_freeze_works() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, 1));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 1, 100));
+
+		assert!(!<Assets as fungibles::InspectFreeze<_>>::is_frozen(0, &1));
+		assert_noop!(
+			<Assets as fungibles::MutateFreeze<_>>::freeze(0, &2),
+			Error::<Test>::BalanceZero,
+		);
+		assert_ok!(<Assets as fungibles::MutateFreeze<_>>::freeze(0, &1));
+		assert!(<Assets as fungibles::InspectFreeze<_>>::is_frozen(0, &1));
+		assert_noop!(
+			<Assets as fungibles::Transfer<_>>::transfer(0, &1, &2, 10, false),
+			Error::<Test>::Frozen,
+		);
+
+		assert_ok!(<Assets as fungibles::MutateFreeze<_>>::thaw(0, &1));
+		assert_ok!(<Assets as fungibles::Transfer<_>>::transfer(0, &1, &2, 10, false));
+
+		// Freezing the whole asset class freezes every account.
+		assert_ok!(Assets::freeze_asset(Origin::signed(1), 0));
+		assert!(<Assets as fungibles::InspectFreeze<_>>::is_frozen(0, &2));
+	});
+}
+
+parameter_types! {
+	pub const NativeAssetId: u32 = 100;
+}
+
+#[test]
+fn single_asset_adapter_works() {
+	new_test_ext().execute_with(|| {
+		type Native = SingleAsset<Balances, NativeAssetId, u32>;
+		Balances::make_free_balance_be(&1, 100);
+
+		assert_eq!(<Native as fungibles::Inspect<u64>>::balance(100, &1), 100);
+		assert_eq!(<Native as fungibles::Inspect<u64>>::balance(0, &1), 0);
+		assert_eq!(
+			<Native as fungibles::Inspect<u64>>::can_deposit(0, &2, 10),
+			DepositConsequence::UnknownAsset,
+		);
+
+		assert_eq!(<Native as fungibles::Transfer<u64>>::transfer(100, &1, &2, 50, false), Ok(50));
+		assert_eq!(Balances::free_balance(2), 50);
+		assert_noop!(
+			<Native as fungibles::Transfer<u64>>::transfer(0, &1, &2, 10, false),
+			DispatchError::Other("UnknownAsset"),
+		);
+
+		assert_ok!(<Native as fungibles::MutateHold<u64>>::hold(100, &1, 10));
+		assert_eq!(<Native as fungibles::InspectHold<u64>>::balance_on_hold(100, &1), 10);
+		assert_eq!(<Native as fungibles::InspectHold<u64>>::balance_on_hold(0, &1), 0);
+	});
+}
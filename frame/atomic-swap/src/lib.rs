@@ -29,6 +29,17 @@
 //! is used to allow the target to approve (claim) the swap. If the swap is not
 //! claimed within a specified duration of time, the sender may cancel it.
 //!
+//! The proof is locked by its hash, computed with the [`HashAlgorithm`](./enum.HashAlgorithm.html)
+//! chosen for each swap, so that swaps can be paired with hash-locks on other chains, such as
+//! SHA-256 ones on Bitcoin or Keccak-256 ones on Ethereum. The duration of a swap is measured
+//! either in blocks or in time, as given by [`SwapDuration`](./enum.SwapDuration.html).
+//!
+//! Swaps can move balances of a reservable currency with
+//! [`BalanceSwapAction`](./struct.BalanceSwapAction.html), or balances of a set of fungible
+//! assets, such as those of the Assets module, with
+//! [`AssetSwapAction`](./struct.AssetSwapAction.html). The latter holds the assets of pending
+//! swaps in the account of this module.
+//!
 //! ## Interface
 //!
 //! ### Dispatchable Functions
@@ -43,16 +54,19 @@
 mod tests;
 
 use sp_std::{prelude::*, marker::PhantomData, ops::{Deref, DerefMut}};
-use sp_io::hashing::blake2_256;
+use sp_io::hashing::{blake2_256, sha2_256, keccak_256};
 use frame_support::{
 	Parameter, decl_module, decl_storage, decl_event, decl_error, ensure,
-	traits::{Get, Currency, ReservableCurrency, BalanceStatus},
+	traits::{Get, Currency, ReservableCurrency, BalanceStatus, Time, fungibles},
 	weights::Weight,
 	dispatch::DispatchResult,
 };
 use frame_system::{self as system, ensure_signed};
 use codec::{Encode, Decode};
-use sp_runtime::RuntimeDebug;
+use sp_runtime::{RuntimeDebug, ModuleId, traits::{AccountIdConversion, Saturating, Zero}};
+
+type MomentOf<T> = <<T as Trait>::Time as Time>::Moment;
+type SwapDurationOf<T> = SwapDuration<<T as frame_system::Trait>::BlockNumber, MomentOf<T>>;
 
 /// Pending atomic swap operation.
 #[derive(Clone, Eq, PartialEq, RuntimeDebug, Encode, Decode)]
@@ -61,13 +75,55 @@ pub struct PendingSwap<T: Trait> {
 	pub source: T::AccountId,
 	/// Action of this swap.
 	pub action: T::SwapAction,
-	/// End block of the lock.
-	pub end_block: T::BlockNumber,
+	/// Algorithm used to hash the proof of this swap.
+	pub hash_algorithm: HashAlgorithm,
+	/// End of the lock, after which the swap may be cancelled.
+	pub end: SwapDurationOf<T>,
 }
 
 /// Hashed proof type.
 pub type HashedProof = [u8; 32];
 
+/// Algorithm used to hash the secret proof of a swap.
+#[derive(Clone, Copy, Eq, PartialEq, RuntimeDebug, Encode, Decode)]
+pub enum HashAlgorithm {
+	/// BLAKE2b with a 256-bit output.
+	Blake2_256,
+	/// SHA-256, as used by Bitcoin hash-locks.
+	Sha2_256,
+	/// Keccak-256, as used by Ethereum hash-locks.
+	Keccak256,
+}
+
+impl Default for HashAlgorithm {
+	fn default() -> Self {
+		HashAlgorithm::Blake2_256
+	}
+}
+
+impl HashAlgorithm {
+	/// Hash the given `proof` with this algorithm.
+	pub fn hash(&self, proof: &[u8]) -> HashedProof {
+		match self {
+			HashAlgorithm::Blake2_256 => blake2_256(proof),
+			HashAlgorithm::Sha2_256 => sha2_256(proof),
+			HashAlgorithm::Keccak256 => keccak_256(proof),
+		}
+	}
+}
+
+/// A duration, or a point in time, measured either in blocks or with the timestamp.
+///
+/// When creating a swap, this is the duration of its lock; once the swap is pending, this is the
+/// block number or the moment at which the lock ends.
+#[derive(Clone, Copy, Eq, PartialEq, RuntimeDebug, Encode, Decode)]
+pub enum SwapDuration<BlockNumber, Moment> {
+	/// A number of blocks, or a block number.
+	Blocks(BlockNumber),
+	/// An amount of time, or a moment, as given by the timestamp.
+	Time(Moment),
+}
+
 /// Definition of a pending atomic swap action. It contains the following three phrases:
 ///
 /// - **Reserve**: reserve the resources needed for a swap. This is to make sure that **Claim**
@@ -135,6 +191,88 @@ impl<T: Trait, AccountId, C> SwapAction<AccountId, T> for BalanceSwapAction<Acco
 	}
 }
 
+/// A swap action that transfers balances of a set of fungible assets, such as those of the
+/// Assets module.
+///
+/// Since such assets cannot generally be reserved, the assets of the swap are moved into the
+/// account of this module until the swap is claimed or cancelled. The `value` of the swap must be
+/// at least the minimum balance of the asset, and exactly `value` is moved out of the account of
+/// this module, so that the assets of other pending swaps are never swept along.
+#[derive(Clone, RuntimeDebug, Eq, PartialEq, Encode, Decode)]
+pub struct AssetSwapAction<AccountId, F: fungibles::Inspect<AccountId>> {
+	asset: F::AssetId,
+	value: F::Balance,
+	_marker: PhantomData<F>,
+}
+
+impl<AccountId, F> AssetSwapAction<AccountId, F> where F: fungibles::Inspect<AccountId> {
+	/// Create a new swap action of `value` of the given `asset`.
+	pub fn new(asset: F::AssetId, value: F::Balance) -> Self {
+		Self { asset, value, _marker: PhantomData }
+	}
+
+	/// The asset to be swapped.
+	pub fn asset(&self) -> F::AssetId {
+		self.asset
+	}
+}
+
+impl<AccountId, F> AssetSwapAction<AccountId, F> where F: fungibles::Transfer<AccountId> {
+	/// Move exactly the `value` of the swap out of the account of the module into `dest`.
+	fn release<T: Trait<AccountId = AccountId>>(&self, dest: &AccountId) -> DispatchResult {
+		let escrow = Module::<T>::account_id();
+		let remainder = F::balance(self.asset, &escrow).saturating_sub(self.value);
+		ensure!(
+			remainder.is_zero() || remainder >= F::minimum_balance(self.asset),
+			Error::<T>::ValueTooLow,
+		);
+		let moved = F::transfer(self.asset, &escrow, dest, self.value, false)?;
+		debug_assert!(moved == self.value, "only the value of the swap is ever moved; qed");
+		Ok(())
+	}
+}
+
+impl<AccountId, F> Deref for AssetSwapAction<AccountId, F>
+	where F: fungibles::Inspect<AccountId>
+{
+	type Target = F::Balance;
+
+	fn deref(&self) -> &Self::Target {
+		&self.value
+	}
+}
+
+impl<AccountId, F> DerefMut for AssetSwapAction<AccountId, F>
+	where F: fungibles::Inspect<AccountId>
+{
+	fn deref_mut(&mut self) -> &mut Self::Target {
+		&mut self.value
+	}
+}
+
+impl<T: Trait, F> SwapAction<T::AccountId, T> for AssetSwapAction<T::AccountId, F>
+	where F: fungibles::Transfer<T::AccountId>
+{
+	fn reserve(&self, source: &T::AccountId) -> DispatchResult {
+		ensure!(self.value >= F::minimum_balance(self.asset), Error::<T>::ValueTooLow);
+		// Keep the source alive, so that exactly `value` is moved, without any dust.
+		F::transfer(self.asset, source, &Module::<T>::account_id(), self.value, true).map(|_| ())
+	}
+
+	fn claim(&self, _source: &T::AccountId, target: &T::AccountId) -> bool {
+		self.release::<T>(target).is_ok()
+	}
+
+	fn weight(&self) -> Weight {
+		T::DbWeight::get().reads_writes(3, 3)
+	}
+
+	fn cancel(&self, source: &T::AccountId) {
+		let result = self.release::<T>(source);
+		debug_assert!(result.is_ok(), "the assets of the swap were moved in on creation; qed");
+	}
+}
+
 /// Atomic swap's pallet configuration trait.
 pub trait Trait: frame_system::Trait {
 	/// The overarching event type.
@@ -152,6 +290,11 @@ pub trait Trait: frame_system::Trait {
 	/// to accept the atomic swap request if A generates the proof, and asks that B generates the
 	/// proof instead.
 	type ProofLimit: Get<u32>;
+	/// The source of time, for swaps whose duration is measured with the timestamp.
+	type Time: Time;
+	/// The module's id, used for deriving the account which holds the assets of pending swaps
+	/// that cannot be reserved.
+	type ModuleId: Get<ModuleId>;
 }
 
 decl_storage! {
@@ -180,6 +323,10 @@ decl_error! {
 		ClaimActionMismatch,
 		/// Duration has not yet passed for the swap to be cancelled.
 		DurationNotPassed,
+		/// The proof was hashed with a different algorithm than the one of the swap.
+		HashAlgorithmMismatch,
+		/// The value of the swap is below the minimum balance of its asset.
+		ValueTooLow,
 	}
 }
 
@@ -208,23 +355,29 @@ decl_module! {
 
 		/// Register a new atomic swap, declaring an intention to send funds from origin to target
 		/// on the current blockchain. The target can claim the fund using the revealed proof. If
-		/// the fund is not claimed after `duration` blocks, then the sender can cancel the swap.
+		/// the fund is not claimed after `duration`, then the sender can cancel the swap.
 		///
 		/// The dispatch origin for this call must be _Signed_.
 		///
 		/// - `target`: Receiver of the atomic swap.
-		/// - `hashed_proof`: The blake2_256 hash of the secret proof.
-		/// - `balance`: Funds to be sent from origin.
-		/// - `duration`: Locked duration of the atomic swap. For safety reasons, it is recommended
-		///   that the revealer uses a shorter duration than the counterparty, to prevent the
-		///   situation where the revealer reveals the proof too late around the end block.
-		#[weight = T::DbWeight::get().reads_writes(1, 1).saturating_add(40_000_000)]
+		/// - `hashed_proof`: The hash of the secret proof, computed with `hash_algorithm`.
+		/// - `action`: Funds to be sent from origin.
+		/// - `duration`: Locked duration of the atomic swap, either in blocks or in time. For
+		///   safety reasons, it is recommended that the revealer uses a shorter duration than the
+		///   counterparty, to prevent the situation where the revealer reveals the proof too late
+		///   around the end of the lock.
+		/// - `hash_algorithm`: The algorithm with which the secret proof is hashed.
+		#[weight = T::DbWeight::get().reads_writes(1, 1)
+			.saturating_add(40_000_000)
+			.saturating_add(action.weight())
+		]
 		fn create_swap(
 			origin,
 			target: T::AccountId,
 			hashed_proof: HashedProof,
 			action: T::SwapAction,
-			duration: T::BlockNumber,
+			duration: SwapDurationOf<T>,
+			hash_algorithm: HashAlgorithm,
 		) {
 			let source = ensure_signed(origin)?;
 			ensure!(
@@ -234,10 +387,17 @@ decl_module! {
 
 			action.reserve(&source)?;
 
+			let end = match duration {
+				SwapDuration::Blocks(blocks) => SwapDuration::Blocks(
+					frame_system::Module::<T>::block_number().saturating_add(blocks)
+				),
+				SwapDuration::Time(time) => SwapDuration::Time(T::Time::now().saturating_add(time)),
+			};
 			let swap = PendingSwap {
 				source,
 				action,
-				end_block: frame_system::Module::<T>::block_number() + duration,
+				hash_algorithm,
+				end,
 			};
 			PendingSwaps::<T>::insert(target.clone(), hashed_proof.clone(), swap.clone());
 
@@ -253,6 +413,8 @@ decl_module! {
 		/// - `proof`: Revealed proof of the claim.
 		/// - `action`: Action defined in the swap, it must match the entry in blockchain. Otherwise
 		///   the operation fails. This is used for weight calculation.
+		/// - `hash_algorithm`: The algorithm with which the proof was hashed when the swap was
+		///   created.
 		#[weight = T::DbWeight::get().reads_writes(1, 1)
 		  .saturating_add(40_000_000)
 		  .saturating_add((proof.len() as Weight).saturating_mul(100))
@@ -262,6 +424,7 @@ decl_module! {
 			origin,
 			proof: Vec<u8>,
 			action: T::SwapAction,
+			hash_algorithm: HashAlgorithm,
 		) -> DispatchResult {
 			ensure!(
 				proof.len() <= T::ProofLimit::get() as usize,
@@ -269,10 +432,11 @@ decl_module! {
 			);
 
 			let target = ensure_signed(origin)?;
-			let hashed_proof = blake2_256(&proof);
+			let hashed_proof = hash_algorithm.hash(&proof);
 
 			let swap = PendingSwaps::<T>::get(&target, hashed_proof)
 				.ok_or(Error::<T>::InvalidProof)?;
+			ensure!(swap.hash_algorithm == hash_algorithm, Error::<T>::HashAlgorithmMismatch);
 			ensure!(swap.action == action, Error::<T>::ClaimActionMismatch);
 
 			let succeeded = swap.action.claim(&swap.source, &target);
@@ -292,7 +456,7 @@ decl_module! {
 		///
 		/// - `target`: Target of the original atomic swap.
 		/// - `hashed_proof`: Hashed proof of the original atomic swap.
-		#[weight = T::DbWeight::get().reads_writes(1, 1).saturating_add(40_000_000)]
+		#[weight = T::DbWeight::get().reads_writes(2, 1).saturating_add(40_000_000)]
 		fn cancel_swap(
 			origin,
 			target: T::AccountId,
@@ -306,10 +470,7 @@ decl_module! {
 				swap.source == source,
 				Error::<T>::SourceMismatch,
 			);
-			ensure!(
-				frame_system::Module::<T>::block_number() >= swap.end_block,
-				Error::<T>::DurationNotPassed,
-			);
+			ensure!(Self::has_ended(&swap.end), Error::<T>::DurationNotPassed);
 
 			swap.action.cancel(&swap.source);
 			PendingSwaps::<T>::remove(&target, hashed_proof.clone());
@@ -320,3 +481,22 @@ decl_module! {
 		}
 	}
 }
+
+impl<T: Trait> Module<T> {
+	/// The account ID of the module, which holds the assets of pending swaps that cannot be
+	/// reserved.
+	///
+	/// This actually does computation. If you need to keep using it, then make sure you cache the
+	/// value and only call this once.
+	pub fn account_id() -> T::AccountId {
+		T::ModuleId::get().into_account()
+	}
+
+	/// Whether the lock of a swap ending at `end` is over.
+	fn has_ended(end: &SwapDurationOf<T>) -> bool {
+		match end {
+			SwapDuration::Blocks(block) => frame_system::Module::<T>::block_number() >= *block,
+			SwapDuration::Time(moment) => T::Time::now() >= *moment,
+		}
+	}
+}
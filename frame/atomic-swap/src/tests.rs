@@ -3,7 +3,7 @@
 use super::*;
 
 use frame_support::{
-	impl_outer_origin, parameter_types, weights::Weight,
+	assert_noop, assert_ok, impl_outer_origin, parameter_types, weights::Weight,
 };
 use sp_core::H256;
 use sp_runtime::{
@@ -56,6 +56,8 @@ parameter_types! {
 }
 impl pallet_balances::Trait for Test {
 	type MaxLocks = ();
+	type MaxReserves = ();
+	type ReserveIdentifier = [u8; 8];
 	type Balance = u64;
 	type DustRemoval = ();
 	type Event = ();
@@ -63,17 +65,30 @@ impl pallet_balances::Trait for Test {
 	type AccountStore = System;
 	type WeightInfo = ();
 }
+parameter_types! {
+	pub const MinimumPeriod: u64 = 1;
+}
+impl pallet_timestamp::Trait for Test {
+	type Moment = u64;
+	type OnTimestampSet = ();
+	type MinimumPeriod = MinimumPeriod;
+	type WeightInfo = ();
+}
 parameter_types! {
 	pub const ProofLimit: u32 = 1024;
 	pub const ExpireDuration: u64 = 100;
+	pub const AtomicSwapModuleId: ModuleId = ModuleId(*b"py/atswp");
 }
 impl Trait for Test {
 	type Event = ();
 	type SwapAction = BalanceSwapAction<u64, Balances>;
 	type ProofLimit = ProofLimit;
+	type Time = Timestamp;
+	type ModuleId = AtomicSwapModuleId;
 }
 type System = frame_system::Module<Test>;
 type Balances = pallet_balances::Module<Test>;
+type Timestamp = pallet_timestamp::Module<Test>;
 type AtomicSwap = Module<Test>;
 
 const A: u64 = 1;
@@ -108,7 +123,8 @@ fn two_party_successful_swap() {
 			B,
 			hashed_proof.clone(),
 			BalanceSwapAction::new(50),
-			1000,
+			SwapDuration::Blocks(1000),
+			HashAlgorithm::Blake2_256,
 		).unwrap();
 
 		assert_eq!(Balances::free_balance(A), 100 - 50);
@@ -122,7 +138,8 @@ fn two_party_successful_swap() {
 			A,
 			hashed_proof.clone(),
 			BalanceSwapAction::new(75),
-			1000,
+			SwapDuration::Blocks(1000),
+			HashAlgorithm::Blake2_256,
 		).unwrap();
 
 		assert_eq!(Balances::free_balance(A), 100);
@@ -135,6 +152,7 @@ fn two_party_successful_swap() {
 			Origin::signed(A),
 			proof.to_vec(),
 			BalanceSwapAction::new(75),
+			HashAlgorithm::Blake2_256,
 		).unwrap();
 
 		assert_eq!(Balances::free_balance(A), 100 + 75);
@@ -147,9 +165,387 @@ fn two_party_successful_swap() {
 			Origin::signed(B),
 			proof.to_vec(),
 			BalanceSwapAction::new(50),
+			HashAlgorithm::Blake2_256,
 		).unwrap();
 
 		assert_eq!(Balances::free_balance(A), 100 - 50);
 		assert_eq!(Balances::free_balance(B), 200 + 50);
 	});
 }
+
+#[test]
+fn swaps_can_be_locked_with_any_hash_algorithm() {
+	new_test_ext().execute_with(|| {
+		let proof: [u8; 2] = [4, 2];
+
+		for (i, algorithm) in [
+			HashAlgorithm::Blake2_256,
+			HashAlgorithm::Sha2_256,
+			HashAlgorithm::Keccak256,
+		].iter().enumerate() {
+			let hashed_proof = match algorithm {
+				HashAlgorithm::Blake2_256 => blake2_256(&proof),
+				HashAlgorithm::Sha2_256 => sha2_256(&proof),
+				HashAlgorithm::Keccak256 => keccak_256(&proof),
+			};
+			assert_ok!(AtomicSwap::create_swap(
+				Origin::signed(A),
+				B,
+				hashed_proof,
+				BalanceSwapAction::new(10),
+				SwapDuration::Blocks(1000),
+				*algorithm,
+			));
+			assert_eq!(Balances::free_balance(B), 200 + 10 * i as u64);
+
+			assert_ok!(AtomicSwap::claim_swap(
+				Origin::signed(B),
+				proof.to_vec(),
+				BalanceSwapAction::new(10),
+				*algorithm,
+			));
+			assert_eq!(Balances::free_balance(B), 200 + 10 * (i as u64 + 1));
+		}
+		assert_eq!(Balances::free_balance(A), 100 - 30);
+	});
+}
+
+#[test]
+fn claiming_with_another_hash_algorithm_fails() {
+	new_test_ext().execute_with(|| {
+		let proof: [u8; 2] = [4, 2];
+		assert_ok!(AtomicSwap::create_swap(
+			Origin::signed(A),
+			B,
+			sha2_256(&proof),
+			BalanceSwapAction::new(50),
+			SwapDuration::Blocks(1000),
+			HashAlgorithm::Sha2_256,
+		));
+
+		assert_noop!(
+			AtomicSwap::claim_swap(
+				Origin::signed(B),
+				proof.to_vec(),
+				BalanceSwapAction::new(50),
+				HashAlgorithm::Keccak256,
+			),
+			Error::<Test>::InvalidProof,
+		);
+
+		// A swap whose hashed proof happens to match under another algorithm is still rejected.
+		assert_ok!(AtomicSwap::create_swap(
+			Origin::signed(A),
+			B,
+			blake2_256(&proof),
+			BalanceSwapAction::new(10),
+			SwapDuration::Blocks(1000),
+			HashAlgorithm::Keccak256,
+		));
+		assert_noop!(
+			AtomicSwap::claim_swap(
+				Origin::signed(B),
+				proof.to_vec(),
+				BalanceSwapAction::new(10),
+				HashAlgorithm::Blake2_256,
+			),
+			Error::<Test>::HashAlgorithmMismatch,
+		);
+	});
+}
+
+#[test]
+fn swap_can_be_cancelled_after_block_duration() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let hashed_proof = blake2_256(&[4, 2]);
+		assert_ok!(AtomicSwap::create_swap(
+			Origin::signed(A),
+			B,
+			hashed_proof,
+			BalanceSwapAction::new(50),
+			SwapDuration::Blocks(10),
+			HashAlgorithm::Blake2_256,
+		));
+		assert_eq!(Balances::free_balance(A), 50);
+
+		System::set_block_number(10);
+		assert_noop!(
+			AtomicSwap::cancel_swap(Origin::signed(A), B, hashed_proof),
+			Error::<Test>::DurationNotPassed,
+		);
+		assert_noop!(
+			AtomicSwap::cancel_swap(Origin::signed(B), B, hashed_proof),
+			Error::<Test>::SourceMismatch,
+		);
+
+		System::set_block_number(11);
+		assert_ok!(AtomicSwap::cancel_swap(Origin::signed(A), B, hashed_proof));
+		assert_eq!(Balances::free_balance(A), 100);
+		assert!(!PendingSwaps::<Test>::contains_key(B, hashed_proof));
+	});
+}
+
+#[test]
+fn swap_can_be_cancelled_after_time_duration() {
+	new_test_ext().execute_with(|| {
+		Timestamp::set_timestamp(1_000);
+		let hashed_proof = blake2_256(&[4, 2]);
+		assert_ok!(AtomicSwap::create_swap(
+			Origin::signed(A),
+			B,
+			hashed_proof,
+			BalanceSwapAction::new(50),
+			SwapDuration::Time(500),
+			HashAlgorithm::Blake2_256,
+		));
+		assert_eq!(PendingSwaps::<Test>::get(B, hashed_proof).unwrap().end, SwapDuration::Time(1_500));
+
+		// Blocks passing do not matter for a swap locked in time.
+		System::set_block_number(1_000);
+		Timestamp::set_timestamp(1_499);
+		assert_noop!(
+			AtomicSwap::cancel_swap(Origin::signed(A), B, hashed_proof),
+			Error::<Test>::DurationNotPassed,
+		);
+
+		Timestamp::set_timestamp(1_500);
+		assert_ok!(AtomicSwap::cancel_swap(Origin::signed(A), B, hashed_proof));
+		assert_eq!(Balances::free_balance(A), 100);
+	});
+}
+
+mod asset_swaps {
+	use super::*;
+	use frame_system::EnsureRoot;
+
+	impl_outer_origin! {
+		pub enum Origin for AssetTest where system = frame_system {}
+	}
+
+	#[derive(Clone, Eq, Debug, PartialEq)]
+	pub struct AssetTest;
+	impl frame_system::Trait for AssetTest {
+		type BaseCallFilter = ();
+		type Origin = Origin;
+		type Index = u64;
+		type BlockNumber = u64;
+		type Hash = H256;
+		type Call = ();
+		type Hashing = BlakeTwo256;
+		type AccountId = u64;
+		type Lookup = IdentityLookup<Self::AccountId>;
+		type Header = Header;
+		type Event = ();
+		type BlockHashCount = BlockHashCount;
+		type MaximumBlockWeight = MaximumBlockWeight;
+		type DbWeight = ();
+		type BlockExecutionWeight = ();
+		type ExtrinsicBaseWeight = ();
+		type MaximumExtrinsicWeight = MaximumBlockWeight;
+		type MaximumBlockLength = MaximumBlockLength;
+		type AvailableBlockRatio = AvailableBlockRatio;
+		type Version = ();
+		type PalletInfo = ();
+		type AccountData = pallet_balances::AccountData<u64>;
+		type OnNewAccount = ();
+		type OnKilledAccount = ();
+		type SystemWeightInfo = ();
+	}
+	impl pallet_balances::Trait for AssetTest {
+		type MaxLocks = ();
+		type MaxReserves = ();
+		type ReserveIdentifier = [u8; 8];
+		type Balance = u64;
+		type DustRemoval = ();
+		type Event = ();
+		type ExistentialDeposit = ExistentialDeposit;
+		type AccountStore = System;
+		type WeightInfo = ();
+	}
+	impl pallet_timestamp::Trait for AssetTest {
+		type Moment = u64;
+		type OnTimestampSet = ();
+		type MinimumPeriod = MinimumPeriod;
+		type WeightInfo = ();
+	}
+	parameter_types! {
+		pub const AssetDeposit: u64 = 1;
+		pub const ApprovalDeposit: u64 = 1;
+		pub const StringLimit: u32 = 50;
+		pub const MetadataDepositBase: u64 = 1;
+		pub const MetadataDepositPerByte: u64 = 1;
+	}
+	impl pallet_assets::Trait for AssetTest {
+		type Event = ();
+		type Balance = u64;
+		type AssetId = u32;
+		type Currency = Balances;
+		type ForceOrigin = EnsureRoot<u64>;
+		type AssetDeposit = AssetDeposit;
+		type MetadataDepositBase = MetadataDepositBase;
+		type MetadataDepositPerByte = MetadataDepositPerByte;
+		type ApprovalDeposit = ApprovalDeposit;
+		type StringLimit = StringLimit;
+		type WeightInfo = ();
+	}
+	impl Trait for AssetTest {
+		type Event = ();
+		type SwapAction = AssetSwapAction<u64, Assets>;
+		type ProofLimit = ProofLimit;
+		type Time = Timestamp;
+		type ModuleId = AtomicSwapModuleId;
+	}
+	type System = frame_system::Module<AssetTest>;
+	type Balances = pallet_balances::Module<AssetTest>;
+	type Timestamp = pallet_timestamp::Module<AssetTest>;
+	type Assets = pallet_assets::Module<AssetTest>;
+	type AtomicSwap = Module<AssetTest>;
+
+	const ASSET: u32 = 0;
+
+	fn new_test_ext() -> sp_io::TestExternalities {
+		let t = frame_system::GenesisConfig::default().build_storage::<AssetTest>().unwrap();
+		let mut ext = sp_io::TestExternalities::new(t);
+		ext.execute_with(|| {
+			System::set_block_number(1);
+			assert_ok!(Assets::force_create(Origin::root(), ASSET, A, 1));
+			assert_ok!(Assets::mint(Origin::signed(A), ASSET, A, 100));
+		});
+		ext
+	}
+
+	#[test]
+	fn asset_swap_can_be_claimed() {
+		new_test_ext().execute_with(|| {
+			let proof: [u8; 2] = [4, 2];
+			assert_ok!(AtomicSwap::create_swap(
+				Origin::signed(A),
+				B,
+				keccak_256(&proof),
+				AssetSwapAction::new(ASSET, 50),
+				SwapDuration::Blocks(1000),
+				HashAlgorithm::Keccak256,
+			));
+			assert_eq!(Assets::balance(ASSET, A), 50);
+			assert_eq!(Assets::balance(ASSET, AtomicSwap::account_id()), 50);
+
+			assert_noop!(
+				AtomicSwap::claim_swap(
+					Origin::signed(B),
+					proof.to_vec(),
+					AssetSwapAction::new(ASSET, 40),
+					HashAlgorithm::Keccak256,
+				),
+				Error::<AssetTest>::ClaimActionMismatch,
+			);
+			assert_ok!(AtomicSwap::claim_swap(
+				Origin::signed(B),
+				proof.to_vec(),
+				AssetSwapAction::new(ASSET, 50),
+				HashAlgorithm::Keccak256,
+			));
+			assert_eq!(Assets::balance(ASSET, B), 50);
+			assert_eq!(Assets::balance(ASSET, AtomicSwap::account_id()), 0);
+		});
+	}
+
+	#[test]
+	fn asset_swap_can_be_cancelled() {
+		new_test_ext().execute_with(|| {
+			let hashed_proof = sha2_256(&[4, 2]);
+			assert_ok!(AtomicSwap::create_swap(
+				Origin::signed(A),
+				B,
+				hashed_proof,
+				AssetSwapAction::new(ASSET, 30),
+				SwapDuration::Time(100),
+				HashAlgorithm::Sha2_256,
+			));
+			assert_eq!(Assets::balance(ASSET, A), 70);
+
+			Timestamp::set_timestamp(100);
+			assert_ok!(AtomicSwap::cancel_swap(Origin::signed(A), B, hashed_proof));
+			assert_eq!(Assets::balance(ASSET, A), 100);
+			assert_eq!(Assets::balance(ASSET, AtomicSwap::account_id()), 0);
+		});
+	}
+
+	#[test]
+	fn asset_swap_needs_enough_assets() {
+		new_test_ext().execute_with(|| {
+			assert_noop!(
+				AtomicSwap::create_swap(
+					Origin::signed(B),
+					A,
+					blake2_256(&[4, 2]),
+					AssetSwapAction::new(ASSET, 10),
+					SwapDuration::Blocks(1000),
+					HashAlgorithm::Blake2_256,
+				),
+				pallet_assets::Error::<AssetTest>::BalanceLow,
+			);
+		});
+	}
+
+	#[test]
+	fn concurrent_asset_swaps_only_move_their_own_value() {
+		new_test_ext().execute_with(|| {
+			const OTHER: u32 = 1;
+			assert_ok!(Assets::force_create(Origin::root(), OTHER, A, 10));
+			assert_ok!(Assets::mint(Origin::signed(A), OTHER, A, 100));
+			assert_ok!(Assets::mint(Origin::signed(A), OTHER, B, 100));
+
+			// A swap below the minimum balance could leave dust in the account of the module.
+			assert_noop!(
+				AtomicSwap::create_swap(
+					Origin::signed(A),
+					B,
+					blake2_256(&[1]),
+					AssetSwapAction::new(OTHER, 5),
+					SwapDuration::Blocks(1000),
+					HashAlgorithm::Blake2_256,
+				),
+				Error::<AssetTest>::ValueTooLow,
+			);
+
+			let (proof_a, proof_b) = ([4u8, 2], [2u8, 4]);
+			assert_ok!(AtomicSwap::create_swap(
+				Origin::signed(A),
+				B,
+				blake2_256(&proof_a),
+				AssetSwapAction::new(OTHER, 50),
+				SwapDuration::Blocks(1000),
+				HashAlgorithm::Blake2_256,
+			));
+			assert_ok!(AtomicSwap::create_swap(
+				Origin::signed(B),
+				A,
+				blake2_256(&proof_b),
+				AssetSwapAction::new(OTHER, 55),
+				SwapDuration::Blocks(1000),
+				HashAlgorithm::Blake2_256,
+			));
+			assert_eq!(Assets::balance(OTHER, AtomicSwap::account_id()), 105);
+
+			// Claiming one swap leaves the escrow of the other one untouched.
+			assert_ok!(AtomicSwap::claim_swap(
+				Origin::signed(B),
+				proof_a.to_vec(),
+				AssetSwapAction::new(OTHER, 50),
+				HashAlgorithm::Blake2_256,
+			));
+			assert_eq!(Assets::balance(OTHER, B), 95);
+			assert_eq!(Assets::balance(OTHER, AtomicSwap::account_id()), 55);
+
+			assert_ok!(AtomicSwap::claim_swap(
+				Origin::signed(A),
+				proof_b.to_vec(),
+				AssetSwapAction::new(OTHER, 55),
+				HashAlgorithm::Blake2_256,
+			));
+			assert_eq!(Assets::balance(OTHER, A), 105);
+			assert_eq!(Assets::balance(OTHER, AtomicSwap::account_id()), 0);
+		});
+	}
+}
@@ -153,6 +153,8 @@ parameter_types! {
 
 impl pallet_balances::Trait for Test {
 	type MaxLocks = ();
+	type MaxReserves = ();
+	type ReserveIdentifier = [u8; 8];
 	type Balance = u128;
 	type DustRemoval = ();
 	type Event = ();
@@ -35,6 +35,7 @@
 //! - Account creation and removal.
 //! - Managing total issuance.
 //! - Setting and managing locks.
+//! - Reserving balance under named identifiers.
 //!
 //! ### Terminology
 //!
@@ -65,6 +66,10 @@
 //! - **Lock:** A freeze on a specified amount of an account's free balance until a specified block number. Multiple
 //! locks always operate over the same funds, so they "overlay" rather than "stack".
 //!
+//! - **Named Reserve:** A part of the reserved balance that is attributed to a specific identifier, so that it
+//! can only be released, slashed or repatriated by whoever uses that identifier. Named reserves "stack": the
+//! reserved balance is always at least the sum of all named reserves of an account.
+//!
 //! ### Implementations
 //!
 //! The Balances module provides implementations for the following traits. If these traits provide the functionality
@@ -74,6 +79,8 @@
 //! fungible assets system.
 //! - [`ReservableCurrency`](../frame_support/traits/trait.ReservableCurrency.html):
 //! Functions for dealing with assets that can be reserved from an account.
+//! - [`NamedReservableCurrency`](../frame_support/traits/trait.NamedReservableCurrency.html):
+//! Functions for dealing with assets that can be reserved from an account under a named identifier.
 //! - [`LockableCurrency`](../frame_support/traits/trait.LockableCurrency.html): Functions for
 //! dealing with accounts that allow liquidity restrictions.
 //! - [`Imbalance`](../frame_support/traits/trait.Imbalance.html): Functions for handling
@@ -81,6 +88,9 @@
 //! creates new funds (e.g. a reward) or destroys some funds (e.g. a system fee).
 //! - [`IsDeadAccount`](../frame_system/trait.IsDeadAccount.html): Determiner to say whether a
 //! given account is unused.
+//! - [`fungible`](../frame_support/traits/fungible/index.html): Imbalance-free functions for
+//! inspecting, minting, burning, transferring, holding and freezing the balances of a single
+//! fungible asset.
 //!
 //! ## Interface
 //!
@@ -167,7 +177,9 @@ use frame_support::{
 		WithdrawReason, WithdrawReasons, LockIdentifier, LockableCurrency, ExistenceRequirement,
 		Imbalance, SignedImbalance, ReservableCurrency, Get, ExistenceRequirement::KeepAlive,
 		ExistenceRequirement::AllowDeath, IsDeadAccount, BalanceStatus as Status,
-	}
+		WithdrawConsequence, DepositConsequence, NamedReservableCurrency, fungible,
+	},
+	type_info::TypeInfo,
 };
 use sp_runtime::{
 	RuntimeDebug, DispatchResult, DispatchError,
@@ -204,6 +216,12 @@ pub trait Subtrait<I: Instance = DefaultInstance>: frame_system::Trait {
 	/// The maximum number of locks that should exist on an account.
 	/// Not strictly enforced, but used for weight estimation.
 	type MaxLocks: Get<u32>;
+
+	/// The maximum number of named reserves that can exist on an account.
+	type MaxReserves: Get<u32>;
+
+	/// The id type for named reserves.
+	type ReserveIdentifier: Parameter + Member + Ord + Copy;
 }
 
 pub trait Trait<I: Instance = DefaultInstance>: frame_system::Trait {
@@ -229,6 +247,12 @@ pub trait Trait<I: Instance = DefaultInstance>: frame_system::Trait {
 	/// The maximum number of locks that should exist on an account.
 	/// Not strictly enforced, but used for weight estimation.
 	type MaxLocks: Get<u32>;
+
+	/// The maximum number of named reserves that can exist on an account.
+	type MaxReserves: Get<u32>;
+
+	/// The id type for named reserves.
+	type ReserveIdentifier: Parameter + Member + Ord + Copy;
 }
 
 impl<T: Trait<I>, I: Instance> Subtrait<I> for T {
@@ -237,6 +261,8 @@ impl<T: Trait<I>, I: Instance> Subtrait<I> for T {
 	type AccountStore = T::AccountStore;
 	type WeightInfo = <T as Trait<I>>::WeightInfo;
 	type MaxLocks = T::MaxLocks;
+	type MaxReserves = T::MaxReserves;
+	type ReserveIdentifier = T::ReserveIdentifier;
 }
 
 decl_event!(
@@ -284,11 +310,13 @@ decl_error! {
 		ExistingVestingSchedule,
 		/// Beneficiary account must pre-exist
 		DeadAccount,
+		/// Number of named reserves exceed MaxReserves
+		TooManyReserves,
 	}
 }
 
 /// Simplified reasons for withdrawing balance.
-#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug)]
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo)]
 pub enum Reasons {
 	/// Paying system transaction fees.
 	Fee = 0,
@@ -320,7 +348,7 @@ impl BitOr for Reasons {
 
 /// A single lock on a balance. There can be many of these on an account and they "overlap", so the
 /// same balance is frozen by multiple locks.
-#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug)]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
 pub struct BalanceLock<Balance> {
 	/// An identifier for this lock. Only one lock may be in existence for each identifier.
 	pub id: LockIdentifier,
@@ -330,8 +358,17 @@ pub struct BalanceLock<Balance> {
 	pub reasons: Reasons,
 }
 
+/// Store named reserved balance.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct ReserveData<ReserveIdentifier, Balance> {
+	/// The identifier for the named reserve.
+	pub id: ReserveIdentifier,
+	/// The amount of the named reserve.
+	pub amount: Balance,
+}
+
 /// All balance information for an account.
-#[derive(Encode, Decode, Clone, PartialEq, Eq, Default, RuntimeDebug)]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Default, RuntimeDebug, TypeInfo)]
 pub struct AccountData<Balance> {
 	/// Non-reserved part of the balance. There may still be restrictions on this, but it is the
 	/// total pool what may in principle be transferred, reserved and used for tipping.
@@ -377,7 +414,7 @@ impl<Balance: Saturating + Copy + Ord> AccountData<Balance> {
 // A value placed in storage that represents the current version of the Balances storage.
 // This value is used by the `on_runtime_upgrade` logic to determine whether we run
 // storage migration logic. This should match directly with the semantic versions of the Rust crate.
-#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug)]
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo)]
 enum Releases {
 	V1_0_0,
 	V2_0_0,
@@ -405,6 +442,10 @@ decl_storage! {
 		/// NOTE: Should only be accessed when setting, changing and freeing a lock.
 		pub Locks get(fn locks): map hasher(blake2_128_concat) T::AccountId => Vec<BalanceLock<T::Balance>>;
 
+		/// Named reserves on some account balances, sorted by identifier.
+		pub Reserves get(fn reserves):
+			map hasher(blake2_128_concat) T::AccountId => Vec<ReserveData<T::ReserveIdentifier, T::Balance>>;
+
 		/// Storage version of the pallet.
 		///
 		/// This is set to v2.0.0 for new networks.
@@ -660,6 +701,10 @@ impl<T: Trait<I>, I: Instance> Module<T, I> {
 			f(&mut account, is_new).map(move |result| {
 				let maybe_endowed = if is_new { Some(account.free) } else { None };
 				*maybe_account = Self::post_mutation(who, account);
+				if !is_new && maybe_account.is_none() {
+					// the named reserves go along with the reserved balance of a reaped account.
+					Reserves::<T, I>::remove(who);
+				}
 				(maybe_endowed, result)
 			})
 		}).map(|(maybe_endowed, result)| {
@@ -706,6 +751,121 @@ impl<T: Trait<I>, I: Instance> Module<T, I> {
 			}
 		}
 	}
+
+	/// Update the named reserves of `who`, removing the entry altogether once none are left.
+	///
+	/// The named reserves are dropped as well if `who` has no reserved balance left, e.g. because
+	/// the account was reaped by the operation which updated them.
+	fn update_reserves(who: &T::AccountId, reserves: &[ReserveData<T::ReserveIdentifier, T::Balance>]) {
+		if reserves.is_empty() || Self::account(who).reserved.is_zero() {
+			Reserves::<T, I>::remove(who);
+		} else {
+			Reserves::<T, I>::insert(who, reserves);
+		}
+	}
+
+	/// The part of the reserved balance of `who` which isn't held in any named reserve.
+	fn unnamed_reserved_balance(who: &T::AccountId) -> T::Balance {
+		let named = Self::reserves(who).iter()
+			.fold(Zero::zero(), |acc: T::Balance, data| acc.saturating_add(data.amount));
+		Self::account(who).reserved.saturating_sub(named)
+	}
+
+	/// Unreserve up to `value` of the reserved balance of `who`, named or not, returning any
+	/// amount that was unable to be unreserved.
+	fn do_unreserve(who: &T::AccountId, value: T::Balance) -> T::Balance {
+		if value.is_zero() { return Zero::zero() }
+
+		let actual = Self::mutate_account(who, |account| {
+			let actual = cmp::min(account.reserved, value);
+			account.reserved -= actual;
+			// defensive only: this can never fail since total issuance which is at least free+reserved
+			// fits into the same data type.
+			account.free = account.free.saturating_add(actual);
+			actual
+		});
+
+		Self::deposit_event(RawEvent::Unreserved(who.clone(), actual.clone()));
+		value - actual
+	}
+
+	/// Slash up to `value` of the reserved balance of `who`, named or not, returning the negative
+	/// imbalance created and any amount that was unable to be slashed.
+	fn do_slash_reserved(who: &T::AccountId, value: T::Balance) -> (NegativeImbalance<T, I>, T::Balance) {
+		if value.is_zero() { return (NegativeImbalance::zero(), Zero::zero()) }
+
+		Self::mutate_account(who, |account| {
+			// underflow should never happen, but it if does, there's nothing to be done here.
+			let actual = cmp::min(account.reserved, value);
+			account.reserved -= actual;
+			(NegativeImbalance::new(actual), value - actual)
+		})
+	}
+
+	/// Move up to `value` of the reserved balance of `slashed`, named or not, into the balance of
+	/// `beneficiary` according to `status`, returning any amount that was unable to be moved.
+	///
+	/// `slashed` and `beneficiary` must be different accounts.
+	fn do_repatriate_reserved(
+		slashed: &T::AccountId,
+		beneficiary: &T::AccountId,
+		value: T::Balance,
+		status: Status,
+	) -> Result<T::Balance, DispatchError> {
+		if value.is_zero() { return Ok(Zero::zero()) }
+
+		let actual = Self::try_mutate_account(beneficiary, |to_account, is_new|-> Result<T::Balance, DispatchError> {
+			ensure!(!is_new, Error::<T, I>::DeadAccount);
+			Self::try_mutate_account(slashed, |from_account, _| -> Result<T::Balance, DispatchError> {
+				let actual = cmp::min(from_account.reserved, value);
+				match status {
+					Status::Free => to_account.free = to_account.free.checked_add(&actual).ok_or(Error::<T, I>::Overflow)?,
+					Status::Reserved => to_account.reserved = to_account.reserved.checked_add(&actual).ok_or(Error::<T, I>::Overflow)?,
+				}
+				from_account.reserved -= actual;
+				Ok(actual)
+			})
+		})?;
+
+		Self::deposit_event(RawEvent::ReserveRepatriated(slashed.clone(), beneficiary.clone(), actual, status));
+		Ok(value - actual)
+	}
+
+	/// Add `value` to the named reserve `id` within `reserves`, keeping them sorted.
+	///
+	/// Fails if a new named reserve would exceed `MaxReserves`.
+	fn add_named_reserve(
+		reserves: &mut Vec<ReserveData<T::ReserveIdentifier, T::Balance>>,
+		id: &T::ReserveIdentifier,
+		value: T::Balance,
+	) -> DispatchResult {
+		match reserves.binary_search_by_key(id, |data| data.id) {
+			Ok(index) => {
+				// this add can't overflow but just to be defensive.
+				reserves[index].amount = reserves[index].amount.saturating_add(value);
+			},
+			Err(index) => {
+				ensure!(
+					(reserves.len() as u32) < T::MaxReserves::get(),
+					Error::<T, I>::TooManyReserves
+				);
+				reserves.insert(index, ReserveData { id: *id, amount: value });
+			},
+		}
+		Ok(())
+	}
+
+	/// Remove `value` from the named reserve at `index` within `reserves`, dropping it once empty.
+	fn sub_named_reserve(
+		reserves: &mut Vec<ReserveData<T::ReserveIdentifier, T::Balance>>,
+		index: usize,
+		value: T::Balance,
+	) {
+		reserves[index].amount = reserves[index].amount.saturating_sub(value);
+		if reserves[index].amount.is_zero() {
+			reserves.remove(index);
+		}
+	}
 }
 
 // wrapping these imbalances in a private module is necessary to ensure absolute privacy
@@ -916,6 +1076,8 @@ impl<T: Subtrait<I>, I: Instance> Trait<I> for ElevatedTrait<T, I> {
 	type AccountStore = T::AccountStore;
 	type WeightInfo = <T as Subtrait<I>>::WeightInfo;
 	type MaxLocks = T::MaxLocks;
+	type MaxReserves = T::MaxReserves;
+	type ReserveIdentifier = T::ReserveIdentifier;
 }
 
 impl<T: Trait<I>, I: Instance> Currency<T::AccountId> for Module<T, I> where
@@ -1202,27 +1364,21 @@ impl<T: Trait<I>, I: Instance> ReservableCurrency<T::AccountId> for Module<T, I>
 		Ok(())
 	}
 
-	/// Unreserve some funds, returning any amount that was unable to be unreserved.
+	/// Unreserve some funds, returning any amount that was unable to be unreserved. Funds held
+	/// in a named reserve are never unreserved.
 	///
 	/// Is a no-op if the value to be unreserved is zero.
 	fn unreserve(who: &T::AccountId, value: Self::Balance) -> Self::Balance {
 		if value.is_zero() { return Zero::zero() }
 
-		let actual = Self::mutate_account(who, |account| {
-			let actual = cmp::min(account.reserved, value);
-			account.reserved -= actual;
-			// defensive only: this can never fail since total issuance which is at least free+reserved
-			// fits into the same data type.
-			account.free = account.free.saturating_add(actual);
-			actual
-		});
-
-		Self::deposit_event(RawEvent::Unreserved(who.clone(), actual.clone()));
-		value - actual
+		let to_change = cmp::min(Self::unnamed_reserved_balance(who), value);
+		let remain = Self::do_unreserve(who, to_change);
+		value - to_change.saturating_sub(remain)
 	}
 
 	/// Slash from reserved balance, returning the negative imbalance created,
-	/// and any amount that was unable to be slashed.
+	/// and any amount that was unable to be slashed. Funds held in a named reserve are never
+	/// slashed.
 	///
 	/// Is a no-op if the value to be slashed is zero.
 	fn slash_reserved(
@@ -1231,15 +1387,13 @@ impl<T: Trait<I>, I: Instance> ReservableCurrency<T::AccountId> for Module<T, I>
 	) -> (Self::NegativeImbalance, Self::Balance) {
 		if value.is_zero() { return (NegativeImbalance::zero(), Zero::zero()) }
 
-		Self::mutate_account(who, |account| {
-			// underflow should never happen, but it if does, there's nothing to be done here.
-			let actual = cmp::min(account.reserved, value);
-			account.reserved -= actual;
-			(NegativeImbalance::new(actual), value - actual)
-		})
+		let to_change = cmp::min(Self::unnamed_reserved_balance(who), value);
+		let (imbalance, remain) = Self::do_slash_reserved(who, to_change);
+		(imbalance, value - to_change.saturating_sub(remain))
 	}
 
 	/// Move the reserved balance of one account into the balance of another, according to `status`.
+	/// Funds held in a named reserve are never moved.
 	///
 	/// Is a no-op if:
 	/// - the value to be moved is zero; or
@@ -1255,26 +1409,162 @@ impl<T: Trait<I>, I: Instance> ReservableCurrency<T::AccountId> for Module<T, I>
 		if slashed == beneficiary {
 			return match status {
 				Status::Free => Ok(Self::unreserve(slashed, value)),
-				Status::Reserved => Ok(value.saturating_sub(Self::reserved_balance(slashed))),
+				Status::Reserved => Ok(value.saturating_sub(Self::unnamed_reserved_balance(slashed))),
 			};
 		}
 
-		let actual = Self::try_mutate_account(beneficiary, |to_account, is_new|-> Result<Self::Balance, DispatchError> {
-			ensure!(!is_new, Error::<T, I>::DeadAccount);
-			Self::try_mutate_account(slashed, |from_account, _| -> Result<Self::Balance, DispatchError> {
-				let actual = cmp::min(from_account.reserved, value);
-				match status {
-					Status::Free => to_account.free = to_account.free.checked_add(&actual).ok_or(Error::<T, I>::Overflow)?,
-					Status::Reserved => to_account.reserved = to_account.reserved.checked_add(&actual).ok_or(Error::<T, I>::Overflow)?,
+		let to_change = cmp::min(Self::unnamed_reserved_balance(slashed), value);
+		let remain = Self::do_repatriate_reserved(slashed, beneficiary, to_change, status)?;
+		Ok(value - to_change.saturating_sub(remain))
+	}
+}
+
+impl<T: Trait<I>, I: Instance> NamedReservableCurrency<T::AccountId> for Module<T, I> where
+	T::Balance: MaybeSerializeDeserialize + Debug
+{
+	type ReserveIdentifier = T::ReserveIdentifier;
+
+	fn reserved_balance_named(id: &Self::ReserveIdentifier, who: &T::AccountId) -> Self::Balance {
+		let reserves = Self::reserves(who);
+		reserves.binary_search_by_key(id, |data| data.id)
+			.map(|index| reserves[index].amount)
+			.unwrap_or_default()
+	}
+
+	/// Move `value` from the free balance from `who` to a named reserve balance.
+	///
+	/// Is a no-op if value to be reserved is zero.
+	fn reserve_named(id: &Self::ReserveIdentifier, who: &T::AccountId, value: Self::Balance) -> DispatchResult {
+		if value.is_zero() { return Ok(()) }
+
+		let mut reserves = Self::reserves(who);
+		Self::add_named_reserve(&mut reserves, id, value)?;
+		<Self as ReservableCurrency<_>>::reserve(who, value)?;
+		Self::update_reserves(who, &reserves);
+		Ok(())
+	}
+
+	/// Unreserve some funds held under `id`, returning any amount that was unable to be
+	/// unreserved.
+	///
+	/// Is a no-op if the value to be unreserved is zero.
+	fn unreserve_named(id: &Self::ReserveIdentifier, who: &T::AccountId, value: Self::Balance) -> Self::Balance {
+		if value.is_zero() { return Zero::zero() }
+
+		let mut reserves = Self::reserves(who);
+		match reserves.binary_search_by_key(id, |data| data.id) {
+			Ok(index) => {
+				let to_change = cmp::min(reserves[index].amount, value);
+				let remain = Self::do_unreserve(who, to_change);
+				// remain should always be zero but just to be defensive here.
+				let actual = to_change.saturating_sub(remain);
+				Self::sub_named_reserve(&mut reserves, index, actual);
+				Self::update_reserves(who, &reserves);
+				value - actual
+			},
+			Err(_) => value,
+		}
+	}
+
+	/// Slash from the reserved balance held under `id`, returning the negative imbalance created,
+	/// and any amount that was unable to be slashed.
+	///
+	/// Is a no-op if the value to be slashed is zero.
+	fn slash_reserved_named(
+		id: &Self::ReserveIdentifier,
+		who: &T::AccountId,
+		value: Self::Balance
+	) -> (Self::NegativeImbalance, Self::Balance) {
+		if value.is_zero() { return (NegativeImbalance::zero(), Zero::zero()) }
+
+		let mut reserves = Self::reserves(who);
+		match reserves.binary_search_by_key(id, |data| data.id) {
+			Ok(index) => {
+				let to_change = cmp::min(reserves[index].amount, value);
+				let (imb, remain) = Self::do_slash_reserved(who, to_change);
+				// remain should always be zero but just to be defensive here.
+				let actual = to_change.saturating_sub(remain);
+				Self::sub_named_reserve(&mut reserves, index, actual);
+				Self::update_reserves(who, &reserves);
+				(imb, value - actual)
+			},
+			Err(_) => (NegativeImbalance::zero(), value),
+		}
+	}
+
+	/// Move the reserved balance held under `id` of one account into the balance of another,
+	/// according to `status`. If `status` is `Reserved`, the balance is reserved under the same
+	/// `id` on `beneficiary`.
+	///
+	/// Is a no-op if:
+	/// - the value to be moved is zero; or
+	/// - the `slashed` id equal to `beneficiary` and the `status` is `Reserved`.
+	fn repatriate_reserved_named(
+		id: &Self::ReserveIdentifier,
+		slashed: &T::AccountId,
+		beneficiary: &T::AccountId,
+		value: Self::Balance,
+		status: Status,
+	) -> Result<Self::Balance, DispatchError> {
+		if value.is_zero() { return Ok(Zero::zero()) }
+
+		if slashed == beneficiary {
+			return match status {
+				Status::Free => Ok(Self::unreserve_named(id, slashed, value)),
+				Status::Reserved => Ok(value.saturating_sub(Self::reserved_balance_named(id, slashed))),
+			};
+		}
+
+		let mut reserves = Self::reserves(slashed);
+		let index = match reserves.binary_search_by_key(id, |data| data.id) {
+			Ok(index) => index,
+			Err(_) => return Ok(value),
+		};
+		let to_change = cmp::min(reserves[index].amount, value);
+
+		let actual = match status {
+			Status::Free => {
+				let remain = Self::do_repatriate_reserved(slashed, beneficiary, to_change, status)?;
+				// remain should always be zero but just to be defensive here.
+				to_change.saturating_sub(remain)
+			},
+			Status::Reserved => {
+				let mut beneficiary_reserves = Self::reserves(beneficiary);
+				// check the beneficiary can hold the named reserve before moving any funds.
+				ensure!(
+					beneficiary_reserves.binary_search_by_key(id, |data| data.id).is_ok() ||
+						(beneficiary_reserves.len() as u32) < T::MaxReserves::get(),
+					Error::<T, I>::TooManyReserves
+				);
+				let remain = Self::do_repatriate_reserved(slashed, beneficiary, to_change, status)?;
+				// remain should always be zero but just to be defensive here.
+				let actual = to_change.saturating_sub(remain);
+				if !actual.is_zero() {
+					Self::add_named_reserve(&mut beneficiary_reserves, id, actual)?;
+					Self::update_reserves(beneficiary, &beneficiary_reserves);
 				}
-				from_account.reserved -= actual;
-				Ok(actual)
-			})
-		})?;
+				actual
+			},
+		};
 
-		Self::deposit_event(RawEvent::ReserveRepatriated(slashed.clone(), beneficiary.clone(), actual, status));
+		Self::sub_named_reserve(&mut reserves, index, actual);
+		Self::update_reserves(slashed, &reserves);
 		Ok(value - actual)
 	}
+
+	/// Attribute `value` of the unnamed reserved balance of `who` to `id`, without moving funds.
+	///
+	/// Is a no-op if the value to be named is zero.
+	fn name_reserved(id: &Self::ReserveIdentifier, who: &T::AccountId, value: Self::Balance) -> DispatchResult {
+		if value.is_zero() { return Ok(()) }
+
+		ensure!(Self::unnamed_reserved_balance(who) >= value, Error::<T, I>::InsufficientBalance);
+		let mut reserves = Self::reserves(who);
+
+		Self::add_named_reserve(&mut reserves, id, value)?;
+		Self::update_reserves(who, &reserves);
+		Ok(())
+	}
 }
 
 /// Implement `OnKilledAccount` to remove the local account, if using local account storage.
@@ -1292,6 +1582,7 @@ impl<T: Trait<I>, I: Instance> OnKilledAccount<T::AccountId> for Module<T, I> {
 			}
 			*account = None;
 		});
+		Reserves::<T, I>::remove(who);
 	}
 }
 
@@ -1360,6 +1651,223 @@ where
 	}
 }
 
+impl<T: Trait<I>, I: Instance> fungible::Inspect<T::AccountId> for Module<T, I> {
+	type Balance = T::Balance;
+
+	fn total_issuance() -> Self::Balance {
+		TotalIssuance::<T, I>::get()
+	}
+
+	fn minimum_balance() -> Self::Balance {
+		T::ExistentialDeposit::get()
+	}
+
+	fn balance(who: &T::AccountId) -> Self::Balance {
+		Self::account(who).free
+	}
+
+	fn reducible_balance(who: &T::AccountId, keep_alive: bool) -> Self::Balance {
+		let a = Self::account(who);
+		// Liquid balance is what is neither reserved nor locked/frozen.
+		let liquid = a.free.saturating_sub(a.fee_frozen.max(a.misc_frozen));
+		if system::Module::<T>::allow_death(who) && !keep_alive {
+			liquid
+		} else {
+			// `must_remain_to_exist` is the part of liquid balance which must remain to keep total
+			// over ED.
+			let must_remain_to_exist = T::ExistentialDeposit::get()
+				.saturating_sub(a.total().saturating_sub(liquid));
+			liquid.saturating_sub(must_remain_to_exist)
+		}
+	}
+
+	fn can_deposit(who: &T::AccountId, amount: Self::Balance) -> DepositConsequence {
+		if amount.is_zero() { return DepositConsequence::Success }
+
+		if TotalIssuance::<T, I>::get().checked_add(&amount).is_none() {
+			return DepositConsequence::Overflow
+		}
+
+		let account = Self::account(who);
+		match account.total().checked_add(&amount) {
+			None => DepositConsequence::Overflow,
+			Some(total) if total < T::ExistentialDeposit::get() => DepositConsequence::BelowMinimum,
+			Some(_) => DepositConsequence::Success,
+		}
+	}
+
+	fn can_withdraw(
+		who: &T::AccountId,
+		amount: Self::Balance,
+	) -> WithdrawConsequence<Self::Balance> {
+		if amount.is_zero() { return WithdrawConsequence::Success }
+
+		if TotalIssuance::<T, I>::get().checked_sub(&amount).is_none() {
+			return WithdrawConsequence::Underflow
+		}
+
+		let account = Self::account(who);
+		let new_free_balance = match account.free.checked_sub(&amount) {
+			Some(x) => x,
+			None => return WithdrawConsequence::NoFunds,
+		};
+
+		// Eventual free funds must be no less than the frozen balance.
+		if new_free_balance < account.frozen(Reasons::All) {
+			return WithdrawConsequence::Frozen
+		}
+
+		// The account is reaped if its total balance falls below ED, which is only allowed if
+		// nothing else depends on it.
+		if new_free_balance.saturating_add(account.reserved) < T::ExistentialDeposit::get() {
+			if system::Module::<T>::allow_death(who) {
+				WithdrawConsequence::ReducedToZero(new_free_balance)
+			} else {
+				WithdrawConsequence::WouldDie
+			}
+		} else {
+			WithdrawConsequence::Success
+		}
+	}
+}
+
+impl<T: Trait<I>, I: Instance> fungible::Mutate<T::AccountId> for Module<T, I> {
+	fn mint_into(who: &T::AccountId, amount: Self::Balance) -> DispatchResult {
+		if amount.is_zero() { return Ok(()) }
+
+		<Self as fungible::Inspect<_>>::can_deposit(who, amount).into_result()?;
+		Self::try_mutate_account(who, |account, _| -> DispatchResult {
+			account.free = account.free.checked_add(&amount).ok_or(Error::<T, I>::Overflow)?;
+			Ok(())
+		})?;
+		TotalIssuance::<T, I>::mutate(|t| *t = t.saturating_add(amount));
+		Ok(())
+	}
+
+	fn burn_from(who: &T::AccountId, amount: Self::Balance) -> Result<Self::Balance, DispatchError> {
+		if amount.is_zero() { return Ok(Zero::zero()) }
+
+		let extra = <Self as fungible::Inspect<_>>::can_withdraw(who, amount).into_result()?;
+		let actual = amount.saturating_add(extra);
+		Self::try_mutate_account(who, |account, _| -> DispatchResult {
+			account.free = account.free.checked_sub(&actual)
+				.ok_or(Error::<T, I>::InsufficientBalance)?;
+			Ok(())
+		})?;
+		TotalIssuance::<T, I>::mutate(|t| *t = t.saturating_sub(actual));
+		Ok(actual)
+	}
+}
+
+impl<T: Trait<I>, I: Instance> fungible::Transfer<T::AccountId> for Module<T, I> {
+	fn transfer(
+		source: &T::AccountId,
+		dest: &T::AccountId,
+		amount: Self::Balance,
+		keep_alive: bool,
+	) -> Result<Self::Balance, DispatchError> {
+		let existence_requirement = if keep_alive { KeepAlive } else { AllowDeath };
+		<Self as Currency<_>>::transfer(source, dest, amount, existence_requirement)?;
+		Ok(amount)
+	}
+}
+
+impl<T: Trait<I>, I: Instance> fungible::Unbalanced<T::AccountId> for Module<T, I> {
+	fn set_balance(who: &T::AccountId, amount: Self::Balance) -> DispatchResult {
+		Self::try_mutate_account(who, |account, _| -> DispatchResult {
+			// Setting a balance that would be dusted is a mistake, rather than a way to reap
+			// the account.
+			ensure!(
+				amount.is_zero() ||
+					amount.saturating_add(account.reserved) >= T::ExistentialDeposit::get(),
+				Error::<T, I>::ExistentialDeposit,
+			);
+			account.free = amount;
+			Ok(())
+		})
+	}
+
+	fn set_total_issuance(amount: Self::Balance) {
+		TotalIssuance::<T, I>::put(amount);
+	}
+}
+
+impl<T: Trait<I>, I: Instance> fungible::InspectHold<T::AccountId> for Module<T, I> {
+	fn balance_on_hold(who: &T::AccountId) -> Self::Balance {
+		Self::account(who).reserved
+	}
+
+	fn can_hold(who: &T::AccountId, amount: Self::Balance) -> bool {
+		<Self as ReservableCurrency<_>>::can_reserve(who, amount)
+	}
+}
+
+impl<T: Trait<I>, I: Instance> fungible::MutateHold<T::AccountId> for Module<T, I> {
+	fn hold(who: &T::AccountId, amount: Self::Balance) -> DispatchResult {
+		<Self as ReservableCurrency<_>>::reserve(who, amount)
+	}
+
+	fn release(who: &T::AccountId, amount: Self::Balance, best_effort: bool)
+		-> Result<Self::Balance, DispatchError>
+	{
+		if amount.is_zero() { return Ok(amount) }
+
+		let actual = amount.min(Self::unnamed_reserved_balance(who));
+		ensure!(best_effort || actual == amount, Error::<T, I>::InsufficientBalance);
+		let remaining = <Self as ReservableCurrency<_>>::unreserve(who, actual);
+		Ok(actual - remaining)
+	}
+
+	fn transfer_held(
+		source: &T::AccountId,
+		dest: &T::AccountId,
+		amount: Self::Balance,
+		best_effort: bool,
+		on_hold: bool,
+	) -> Result<Self::Balance, DispatchError> {
+		if amount.is_zero() { return Ok(amount) }
+
+		let actual = amount.min(Self::unnamed_reserved_balance(source));
+		ensure!(best_effort || actual == amount, Error::<T, I>::InsufficientBalance);
+		let status = if on_hold { Status::Reserved } else { Status::Free };
+		let remaining = <Self as ReservableCurrency<_>>::repatriate_reserved(
+			source,
+			dest,
+			actual,
+			status,
+		)?;
+		Ok(actual - remaining)
+	}
+}
+
+/// The identifier of the lock with which accounts are frozen through
+/// [`fungible::MutateFreeze`](../frame_support/traits/fungible/trait.MutateFreeze.html).
+pub const FREEZE_ID: LockIdentifier = *b"frozen  ";
+
+impl<T: Trait<I>, I: Instance> fungible::InspectFreeze<T::AccountId> for Module<T, I> {
+	fn is_frozen(who: &T::AccountId) -> bool {
+		Self::locks(who).iter().any(|l| l.id == FREEZE_ID)
+	}
+}
+
+impl<T: Trait<I>, I: Instance> fungible::MutateFreeze<T::AccountId> for Module<T, I> {
+	fn freeze(who: &T::AccountId) -> DispatchResult {
+		ensure!(!Self::account(who).total().is_zero(), Error::<T, I>::DeadAccount);
+		<Self as LockableCurrency<_>>::set_lock(
+			FREEZE_ID,
+			who,
+			Bounded::max_value(),
+			WithdrawReasons::all(),
+		);
+		Ok(())
+	}
+
+	fn thaw(who: &T::AccountId) -> DispatchResult {
+		<Self as LockableCurrency<_>>::remove_lock(FREEZE_ID, who);
+		Ok(())
+	}
+}
+
 impl<T: Trait<I>, I: Instance> IsDeadAccount<T::AccountId> for Module<T, I> where
 	T::Balance: MaybeSerializeDeserialize + Debug
 {
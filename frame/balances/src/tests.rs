@@ -38,12 +38,13 @@ macro_rules! decl_tests {
 	($test:ty, $ext_builder:ty, $existential_deposit:expr) => {
 
 		use crate::*;
-		use sp_runtime::{FixedPointNumber, traits::{SignedExtension, BadOrigin}};
+		use sp_runtime::{FixedPointNumber, DispatchError, traits::{SignedExtension, BadOrigin}};
 		use frame_support::{
 			assert_noop, assert_ok, assert_err,
 			traits::{
 				LockableCurrency, LockIdentifier, WithdrawReason, WithdrawReasons,
-				Currency, ReservableCurrency, ExistenceRequirement::AllowDeath, StoredMap
+				Currency, ReservableCurrency, ExistenceRequirement::AllowDeath, StoredMap,
+				WithdrawConsequence, DepositConsequence, NamedReservableCurrency, fungible,
 			}
 		};
 		use pallet_transaction_payment::{ChargeTransactionPayment, Multiplier};
@@ -787,5 +788,346 @@ macro_rules! decl_tests {
 					);
 				});
 		}
+
+		#[test]
+		fn fungible_inspect_works() {
+			<$ext_builder>::default().existential_deposit(2).monied(true).build().execute_with(|| {
+				type F = Balances;
+				assert_eq!(<F as fungible::Inspect<_>>::balance(&1), 20);
+				assert_eq!(<F as fungible::Inspect<_>>::minimum_balance(), 2);
+				assert_eq!(
+					<F as fungible::Inspect<_>>::total_issuance(),
+					<Balances as Currency<_>>::total_issuance(),
+				);
+				assert_eq!(<F as fungible::Inspect<_>>::reducible_balance(&1, false), 20);
+				assert_eq!(<F as fungible::Inspect<_>>::reducible_balance(&1, true), 18);
+				assert_eq!(
+					<F as fungible::Inspect<_>>::can_withdraw(&1, 19),
+					WithdrawConsequence::ReducedToZero(1),
+				);
+
+				// reserved funds keep the account alive, but are not part of the balance.
+				assert_ok!(Balances::reserve(&1, 5));
+				assert_eq!(<F as fungible::Inspect<_>>::balance(&1), 15);
+				assert_eq!(<F as fungible::Inspect<_>>::reducible_balance(&1, true), 15);
+				assert_eq!(<F as fungible::Inspect<_>>::can_withdraw(&1, 15), WithdrawConsequence::Success);
+				assert_eq!(<F as fungible::Inspect<_>>::can_withdraw(&1, 16), WithdrawConsequence::NoFunds);
+
+				Balances::set_lock(ID_1, &1, 10, WithdrawReasons::all());
+				assert_eq!(<F as fungible::Inspect<_>>::reducible_balance(&1, false), 5);
+				assert_eq!(<F as fungible::Inspect<_>>::can_withdraw(&1, 6), WithdrawConsequence::Frozen);
+
+				assert_eq!(<F as fungible::Inspect<_>>::can_deposit(&5, 1), DepositConsequence::BelowMinimum);
+				assert_eq!(<F as fungible::Inspect<_>>::can_deposit(&5, 2), DepositConsequence::Success);
+				assert_eq!(
+					<F as fungible::Inspect<_>>::can_deposit(&1, u64::max_value()),
+					DepositConsequence::Overflow,
+				);
+			});
+		}
+
+		#[test]
+		fn fungible_mutate_and_transfer_work() {
+			<$ext_builder>::default().existential_deposit(2).monied(true).build().execute_with(|| {
+				type F = Balances;
+				let issuance = Balances::total_issuance();
+
+				assert_noop!(
+					<F as fungible::Mutate<_>>::mint_into(&5, 1),
+					DispatchError::Other("BelowMinimum"),
+				);
+				assert_ok!(<F as fungible::Mutate<_>>::mint_into(&5, 10));
+				assert_eq!(Balances::free_balance(5), 10);
+				assert_eq!(Balances::total_issuance(), issuance + 10);
+
+				// the remainder would be dust, so it is burned as well.
+				assert_eq!(<F as fungible::Mutate<_>>::burn_from(&5, 9), Ok(10));
+				assert_eq!(Balances::free_balance(5), 0);
+				assert_eq!(Balances::total_issuance(), issuance);
+				assert_noop!(<F as fungible::Mutate<_>>::burn_from(&5, 1), DispatchError::Other("NoFunds"));
+
+				assert_noop!(
+					<F as fungible::Transfer<_>>::transfer(&1, &2, 19, true),
+					Error::<$test, _>::KeepAlive,
+				);
+				assert_eq!(<F as fungible::Transfer<_>>::transfer(&1, &2, 18, true), Ok(18));
+				assert_eq!(Balances::free_balance(1), 2);
+				assert_eq!(Balances::free_balance(2), 58);
+			});
+		}
+
+		#[test]
+		fn fungible_unbalanced_works() {
+			<$ext_builder>::default().existential_deposit(2).monied(true).build().execute_with(|| {
+				type F = Balances;
+				let issuance = Balances::total_issuance();
+
+				assert_noop!(
+					<F as fungible::Unbalanced<_>>::set_balance(&1, 1),
+					Error::<$test, _>::ExistentialDeposit,
+				);
+				assert_ok!(<F as fungible::Unbalanced<_>>::set_balance(&1, 30));
+				assert_eq!(Balances::free_balance(1), 30);
+				assert_eq!(Balances::total_issuance(), issuance);
+
+				assert_eq!(<F as fungible::Unbalanced<_>>::decrease_balance(&1, 29), Ok(30));
+				assert_eq!(Balances::free_balance(1), 0);
+				assert_eq!(<F as fungible::Unbalanced<_>>::increase_balance_at_most(&5, 1), 0);
+				assert_eq!(<F as fungible::Unbalanced<_>>::increase_balance(&5, 5), Ok(5));
+				assert_eq!(Balances::free_balance(5), 5);
+
+				<F as fungible::Unbalanced<_>>::set_total_issuance(issuance - 25);
+				assert_eq!(Balances::total_issuance(), issuance - 25);
+			});
+		}
+
+		#[test]
+		fn fungible_hold_works() {
+			<$ext_builder>::default().existential_deposit(2).monied(true).build().execute_with(|| {
+				type F = Balances;
+				assert!(!<F as fungible::InspectHold<_>>::can_hold(&1, 21));
+				assert_ok!(<F as fungible::MutateHold<_>>::hold(&1, 5));
+				assert_eq!(<F as fungible::InspectHold<_>>::balance_on_hold(&1), 5);
+				assert_eq!(<F as fungible::Inspect<_>>::balance(&1), 15);
+
+				assert_noop!(
+					<F as fungible::MutateHold<_>>::release(&1, 6, false),
+					Error::<$test, _>::InsufficientBalance,
+				);
+				assert_eq!(<F as fungible::MutateHold<_>>::release(&1, 2, false), Ok(2));
+				assert_eq!(<F as fungible::InspectHold<_>>::balance_on_hold(&1), 3);
+
+				assert_noop!(
+					<F as fungible::MutateHold<_>>::transfer_held(&1, &2, 5, false, true),
+					Error::<$test, _>::InsufficientBalance,
+				);
+				assert_eq!(<F as fungible::MutateHold<_>>::transfer_held(&1, &2, 5, true, true), Ok(3));
+				assert_eq!(<F as fungible::InspectHold<_>>::balance_on_hold(&1), 0);
+				assert_eq!(<F as fungible::InspectHold<_>>::balance_on_hold(&2), 3);
+			});
+		}
+
+		#[test]
+		fn fungible_freeze_works() {
+			<$ext_builder>::default().existential_deposit(2).monied(true).build().execute_with(|| {
+				type F = Balances;
+				assert!(!<F as fungible::InspectFreeze<_>>::is_frozen(&1));
+				assert_noop!(<F as fungible::MutateFreeze<_>>::freeze(&5), Error::<$test, _>::DeadAccount);
+
+				assert_ok!(<F as fungible::MutateFreeze<_>>::freeze(&1));
+				assert!(<F as fungible::InspectFreeze<_>>::is_frozen(&1));
+				assert_eq!(<F as fungible::Inspect<_>>::reducible_balance(&1, false), 0);
+				assert_noop!(
+					<F as fungible::Transfer<_>>::transfer(&1, &2, 1, false),
+					Error::<$test, _>::LiquidityRestrictions,
+				);
+
+				assert_ok!(<F as fungible::MutateFreeze<_>>::thaw(&1));
+				assert!(!<F as fungible::InspectFreeze<_>>::is_frozen(&1));
+				assert_ok!(<F as fungible::Transfer<_>>::transfer(&1, &2, 1, false));
+			});
+		}
+
+		#[test]
+		fn named_reserve_should_work() {
+			<$ext_builder>::default().build().execute_with(|| {
+				let _ = Balances::deposit_creating(&1, 111);
+
+				let id_1 = [1u8; 8];
+				let id_2 = [2u8; 8];
+				let id_3 = [3u8; 8];
+
+				// reserve
+				assert_noop!(Balances::reserve_named(&id_1, &1, 112), Error::<$test, _>::InsufficientBalance);
+				assert_ok!(Balances::reserve_named(&id_1, &1, 12));
+				assert_eq!(Balances::reserved_balance(1), 12);
+				assert_eq!(Balances::reserved_balance_named(&id_1, &1), 12);
+				assert_eq!(Balances::reserved_balance_named(&id_2, &1), 0);
+
+				assert_ok!(Balances::reserve_named(&id_1, &1, 2));
+				assert_ok!(Balances::reserve_named(&id_2, &1, 23));
+				assert_eq!(Balances::reserved_balance_named(&id_1, &1), 14);
+				assert_eq!(Balances::reserved_balance_named(&id_2, &1), 23);
+
+				// anonymous reserves stack on top of named ones
+				assert_ok!(Balances::reserve(&1, 34));
+				assert_eq!(Balances::reserved_balance(1), 71);
+				assert_eq!(Balances::free_balance(1), 40);
+
+				// only `MaxReserves` distinct named reserves per account
+				assert_noop!(Balances::reserve_named(&id_3, &1, 1), Error::<$test, _>::TooManyReserves);
+
+				// unreserve only touches the given reserve
+				assert_eq!(Balances::unreserve_named(&id_1, &1, 10), 0);
+				assert_eq!(Balances::reserved_balance_named(&id_1, &1), 4);
+				assert_eq!(Balances::reserved_balance_named(&id_2, &1), 23);
+				assert_eq!(Balances::unreserve_named(&id_1, &1, 5), 1);
+				assert_eq!(Balances::reserved_balance_named(&id_1, &1), 0);
+				assert_eq!(Balances::reserved_balance(1), 57);
+				assert_eq!(Balances::free_balance(1), 54);
+
+				// a fully released reserve frees up its slot
+				assert_ok!(Balances::reserve_named(&id_3, &1, 3));
+				assert_eq!(Balances::reserves(1).len(), 2);
+
+				// slash only touches the given reserve
+				let (imbalance, remain) = Balances::slash_reserved_named(&id_2, &1, 25);
+				assert_eq!(remain, 2);
+				drop(imbalance);
+				assert_eq!(Balances::reserved_balance_named(&id_2, &1), 0);
+				assert_eq!(Balances::reserved_balance_named(&id_3, &1), 3);
+				assert_eq!(Balances::reserved_balance(1), 37);
+				assert_eq!(Balances::total_issuance(), 88);
+
+				// ensure
+				assert_ok!(Balances::ensure_reserved_named(&id_3, &1, 10));
+				assert_eq!(Balances::reserved_balance_named(&id_3, &1), 10);
+				assert_ok!(Balances::ensure_reserved_named(&id_3, &1, 4));
+				assert_eq!(Balances::reserved_balance_named(&id_3, &1), 4);
+
+				assert_eq!(Balances::unreserve_all_named(&id_3, &1), 4);
+				assert_eq!(Balances::reserved_balance_named(&id_3, &1), 0);
+				assert!(Balances::reserves(1).is_empty());
+				assert_eq!(Balances::reserved_balance(1), 34);
+			});
+		}
+
+		#[test]
+		fn repatriate_reserved_named_should_work() {
+			<$ext_builder>::default().build().execute_with(|| {
+				let _ = Balances::deposit_creating(&1, 110);
+				let _ = Balances::deposit_creating(&2, 10);
+				let _ = Balances::deposit_creating(&3, 10);
+
+				let id = [1u8; 8];
+
+				assert_ok!(Balances::reserve_named(&id, &1, 50));
+				assert_ok!(Balances::reserve(&1, 20));
+
+				assert_ok!(Balances::repatriate_reserved_named(&id, &1, &2, 10, Status::Free), 0);
+				assert_eq!(Balances::reserved_balance_named(&id, &1), 40);
+				assert_eq!(Balances::free_balance(2), 20);
+
+				assert_ok!(Balances::repatriate_reserved_named(&id, &1, &2, 10, Status::Reserved), 0);
+				assert_eq!(Balances::reserved_balance_named(&id, &1), 30);
+				assert_eq!(Balances::reserved_balance_named(&id, &2), 10);
+				assert_eq!(Balances::reserved_balance(2), 10);
+
+				// the anonymous reserve is never repatriated
+				assert_ok!(Balances::repatriate_reserved_named(&id, &1, &3, 50, Status::Free), 20);
+				assert_eq!(Balances::reserved_balance_named(&id, &1), 0);
+				assert_eq!(Balances::reserved_balance(1), 20);
+				assert_eq!(Balances::free_balance(3), 40);
+
+				assert_noop!(
+					Balances::repatriate_reserved_named(&id, &2, &4, 5, Status::Free),
+					Error::<$test, _>::DeadAccount,
+				);
+
+				assert_ok!(Balances::repatriate_all_reserved_named(&id, &2, &3, Status::Reserved));
+				assert_eq!(Balances::reserved_balance_named(&id, &2), 0);
+				assert_eq!(Balances::reserved_balance_named(&id, &3), 10);
+			});
+		}
+
+		#[test]
+		fn name_reserved_should_work() {
+			<$ext_builder>::default().build().execute_with(|| {
+				let _ = Balances::deposit_creating(&1, 100);
+
+				let id_1 = [1u8; 8];
+				let id_2 = [2u8; 8];
+
+				assert_ok!(Balances::reserve(&1, 30));
+				assert_ok!(Balances::reserve_named(&id_1, &1, 10));
+
+				// only the unnamed part of the reserved balance can be named
+				assert_noop!(Balances::name_reserved(&id_2, &1, 31), Error::<$test, _>::InsufficientBalance);
+				assert_ok!(Balances::name_reserved(&id_2, &1, 20));
+				assert_eq!(Balances::reserved_balance_named(&id_2, &1), 20);
+				assert_eq!(Balances::reserved_balance(1), 40);
+				assert_eq!(Balances::free_balance(1), 60);
+
+				assert_ok!(Balances::name_reserved(&id_1, &1, 10));
+				assert_eq!(Balances::reserved_balance_named(&id_1, &1), 20);
+				assert_noop!(Balances::name_reserved(&id_1, &1, 1), Error::<$test, _>::InsufficientBalance);
+
+				assert_eq!(Balances::unreserve_all_named(&id_2, &1), 20);
+				assert_eq!(Balances::reserved_balance(1), 20);
+				assert_eq!(Balances::free_balance(1), 80);
+			});
+		}
+
+		#[test]
+		fn unnamed_reserve_operations_leave_named_reserves_alone() {
+			<$ext_builder>::default().build().execute_with(|| {
+				let _ = Balances::deposit_creating(&1, 100);
+				let _ = Balances::deposit_creating(&2, 10);
+
+				let id = [1u8; 8];
+
+				assert_ok!(Balances::reserve_named(&id, &1, 30));
+				assert_ok!(Balances::reserve(&1, 20));
+
+				// only the unnamed part of the reserved balance is unreserved
+				assert_eq!(Balances::unreserve(&1, 25), 5);
+				assert_eq!(Balances::reserved_balance(1), 30);
+				assert_eq!(Balances::reserved_balance_named(&id, &1), 30);
+				assert_eq!(Balances::free_balance(1), 70);
+
+				// ... slashed
+				assert_ok!(Balances::reserve(&1, 20));
+				let (imbalance, remain) = Balances::slash_reserved(&1, 25);
+				assert_eq!(remain, 5);
+				drop(imbalance);
+				assert_eq!(Balances::reserved_balance(1), 30);
+				assert_eq!(Balances::reserved_balance_named(&id, &1), 30);
+
+				// ... or repatriated
+				assert_ok!(Balances::reserve(&1, 20));
+				assert_ok!(Balances::repatriate_reserved(&1, &2, 25, Status::Free), 5);
+				assert_eq!(Balances::free_balance(2), 30);
+				assert_eq!(Balances::reserved_balance(1), 30);
+				assert_eq!(Balances::reserved_balance_named(&id, &1), 30);
+
+				assert_eq!(Balances::unreserve_named(&id, &1, 30), 0);
+				assert_eq!(Balances::reserved_balance(1), 0);
+				assert_eq!(Balances::free_balance(1), 60);
+			});
+		}
+
+		#[test]
+		fn named_reserves_are_removed_when_account_is_reaped() {
+			<$ext_builder>::default().build().execute_with(|| {
+				let _ = Balances::deposit_creating(&1, 100);
+				let _ = Balances::deposit_creating(&2, 100);
+
+				let id_1 = [1u8; 8];
+				let id_2 = [2u8; 8];
+
+				// reaped by an operation which doesn't know about named reserves
+				assert_ok!(Balances::reserve_named(&id_1, &1, 30));
+				assert_ok!(Balances::reserve_named(&id_2, &1, 20));
+				let (imbalance, _) = Balances::slash(&1, 100);
+				drop(imbalance);
+				assert_eq!(Balances::total_balance(&1), 0);
+				assert!(Balances::reserves(1).is_empty());
+				assert_eq!(Balances::reserved_balance_named(&id_1, &1), 0);
+
+				// reaped by slashing a named reserve
+				assert_ok!(Balances::reserve_named(&id_1, &2, 100));
+				let (imbalance, remain) = Balances::slash_reserved_named(&id_1, &2, 100);
+				assert_eq!(remain, 0);
+				drop(imbalance);
+				assert_eq!(Balances::total_balance(&2), 0);
+				assert!(Balances::reserves(2).is_empty());
+
+				// a new account starts without named reserves
+				let _ = Balances::deposit_creating(&1, 100);
+				assert_eq!(Balances::reserved_balance_named(&id_2, &1), 0);
+				assert_eq!(Balances::unreserve_named(&id_2, &1, 20), 20);
+				assert_eq!(Balances::free_balance(1), 100);
+			});
+		}
 	}
 }
@@ -104,6 +104,9 @@ impl pallet_transaction_payment::Trait for Test {
 	type FeeMultiplierUpdate = ();
 }
 
+parameter_types! {
+	pub const MaxReserves: u32 = 2;
+}
 impl Trait for Test {
 	type Balance = u64;
 	type DustRemoval = ();
@@ -111,6 +114,8 @@ impl Trait for Test {
 	type ExistentialDeposit = ExistentialDeposit;
 	type AccountStore = system::Module<Test>;
 	type MaxLocks = ();
+	type MaxReserves = MaxReserves;
+	type ReserveIdentifier = [u8; 8];
 	type WeightInfo = ();
 }
 
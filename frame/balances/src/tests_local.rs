@@ -105,6 +105,7 @@ impl pallet_transaction_payment::Trait for Test {
 }
 parameter_types! {
 	pub const MaxLocks: u32 = 50;
+	pub const MaxReserves: u32 = 2;
 }
 impl Trait for Test {
 	type Balance = u64;
@@ -118,6 +119,8 @@ impl Trait for Test {
 		u64, super::AccountData<u64>
 	>;
 	type MaxLocks = MaxLocks;
+	type MaxReserves = MaxReserves;
+	type ReserveIdentifier = [u8; 8];
 	type WeightInfo = ();
 }
 
@@ -0,0 +1,321 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2020 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Bounties pallet benchmarking.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+
+use frame_system::RawOrigin;
+use frame_benchmarking::{benchmarks, account, whitelisted_caller};
+use frame_support::traits::OnInitialize;
+use sp_runtime::traits::Bounded;
+
+use crate::Module as Bounties;
+use pallet_treasury::Module as Treasury;
+
+const SEED: u32 = 0;
+
+// Create bounties that are approved for use in `on_initialize`.
+fn create_approved_bounties<T: Trait>(n: u32) -> Result<(), &'static str> {
+	for i in 0 .. n {
+		let (caller, _curator, _fee, value, reason) = setup_bounty::<T>(i, MAX_BYTES);
+		Bounties::<T>::propose_bounty(RawOrigin::Signed(caller).into(), value, reason)?;
+		let bounty_id = BountyCount::get() - 1;
+		Bounties::<T>::approve_bounty(RawOrigin::Root.into(), bounty_id)?;
+	}
+	ensure!(BountyApprovals::get().len() == n as usize, "Not all bounty approved");
+	Ok(())
+}
+
+// Create the pre-requisite information needed to create a `propose_bounty`.
+fn setup_bounty<T: Trait>(u: u32, d: u32) -> (
+	T::AccountId,
+	T::AccountId,
+	BalanceOf<T>,
+	BalanceOf<T>,
+	Vec<u8>,
+) {
+	let caller = account("caller", u, SEED);
+	let value: BalanceOf<T> = T::BountyValueMinimum::get().saturating_mul(100.into());
+	let fee = value / 2.into();
+	let deposit = T::BountyDepositBase::get() + T::DataDepositPerByte::get() * MAX_BYTES.into();
+	let _ = T::Currency::make_free_balance_be(&caller, deposit);
+	let curator = account("curator", u, SEED);
+	let _ = T::Currency::make_free_balance_be(&curator, fee / 2.into());
+	let reason = vec![0; d as usize];
+	(caller, curator, fee, value, reason)
+}
+
+fn create_bounty<T: Trait>() -> Result<(
+	<T::Lookup as StaticLookup>::Source,
+	BountyIndex,
+), &'static str> {
+	let (caller, curator, fee, value, reason) = setup_bounty::<T>(0, MAX_BYTES);
+	let curator_lookup = T::Lookup::unlookup(curator.clone());
+	Bounties::<T>::propose_bounty(RawOrigin::Signed(caller).into(), value, reason)?;
+	let bounty_id = BountyCount::get() - 1;
+	Bounties::<T>::approve_bounty(RawOrigin::Root.into(), bounty_id)?;
+	Treasury::<T>::on_initialize(T::BlockNumber::zero());
+	Bounties::<T>::propose_curator(RawOrigin::Root.into(), bounty_id, curator_lookup.clone(), fee)?;
+	Bounties::<T>::accept_curator(RawOrigin::Signed(curator).into(), bounty_id)?;
+	Ok((curator_lookup, bounty_id))
+}
+
+// Add a child bounty to an active bounty and propose a curator for it, returning the parent
+// curator, the child curator and the child bounty index.
+fn create_child_bounty<T: Trait>() -> Result<(
+	T::AccountId,
+	T::AccountId,
+	BountyIndex,
+	BountyIndex,
+), &'static str> {
+	let (curator_lookup, bounty_id) = create_bounty::<T>()?;
+	let curator = T::Lookup::lookup(curator_lookup)?;
+	let value = T::ChildBountyValueMinimum::get().saturating_mul(10.into());
+	let fee = value / 2.into();
+	Bounties::<T>::add_child_bounty(RawOrigin::Signed(curator.clone()).into(), bounty_id, value, vec![0; MAX_BYTES as usize])?;
+	let child_bounty_id = ChildBountyCount::get() - 1;
+	let child_curator: T::AccountId = account("child_curator", 0, SEED);
+	let _ = T::Currency::make_free_balance_be(&child_curator, fee);
+	Bounties::<T>::propose_child_curator(
+		RawOrigin::Signed(curator.clone()).into(),
+		bounty_id,
+		child_bounty_id,
+		T::Lookup::unlookup(child_curator.clone()),
+		fee,
+	)?;
+	Ok((curator, child_curator, bounty_id, child_bounty_id))
+}
+
+fn setup_pod_account<T: Trait>() {
+	let pot_account = Treasury::<T>::account_id();
+	let value = T::Currency::minimum_balance().saturating_mul(1_000_000_000.into());
+	let _ = T::Currency::make_free_balance_be(&pot_account, value);
+}
+
+const MAX_BYTES: u32 = 16384;
+
+benchmarks! {
+	_ { }
+
+	propose_bounty {
+		let d in 0 .. MAX_BYTES;
+
+		let (caller, curator, fee, value, description) = setup_bounty::<T>(0, d);
+	}: _(RawOrigin::Signed(caller), value, description)
+
+	approve_bounty {
+		let (caller, curator, fee, value, reason) = setup_bounty::<T>(0, MAX_BYTES);
+		Bounties::<T>::propose_bounty(RawOrigin::Signed(caller).into(), value, reason)?;
+		let bounty_id = BountyCount::get() - 1;
+	}: _(RawOrigin::Root, bounty_id)
+
+	propose_curator {
+		setup_pod_account::<T>();
+		let (caller, curator, fee, value, reason) = setup_bounty::<T>(0, MAX_BYTES);
+		let curator_lookup = T::Lookup::unlookup(curator.clone());
+		Bounties::<T>::propose_bounty(RawOrigin::Signed(caller).into(), value, reason)?;
+		let bounty_id = BountyCount::get() - 1;
+		Bounties::<T>::approve_bounty(RawOrigin::Root.into(), bounty_id)?;
+		Treasury::<T>::on_initialize(T::BlockNumber::zero());
+	}: _(RawOrigin::Root, bounty_id, curator_lookup, fee)
+
+	// Worst case when curator is inactive and any sender unassigns the curator.
+	unassign_curator {
+		setup_pod_account::<T>();
+		let (curator_lookup, bounty_id) = create_bounty::<T>()?;
+		Treasury::<T>::on_initialize(T::BlockNumber::zero());
+		let bounty_id = BountyCount::get() - 1;
+		frame_system::Module::<T>::set_block_number(T::BountyUpdatePeriod::get() + 1.into());
+		let caller = whitelisted_caller();
+	}: _(RawOrigin::Signed(caller), bounty_id)
+
+	accept_curator {
+		setup_pod_account::<T>();
+		let (caller, curator, fee, value, reason) = setup_bounty::<T>(0, MAX_BYTES);
+		let curator_lookup = T::Lookup::unlookup(curator.clone());
+		Bounties::<T>::propose_bounty(RawOrigin::Signed(caller).into(), value, reason)?;
+		let bounty_id = BountyCount::get() - 1;
+		Bounties::<T>::approve_bounty(RawOrigin::Root.into(), bounty_id)?;
+		Treasury::<T>::on_initialize(T::BlockNumber::zero());
+		Bounties::<T>::propose_curator(RawOrigin::Root.into(), bounty_id, curator_lookup, fee)?;
+	}: _(RawOrigin::Signed(curator), bounty_id)
+
+	award_bounty {
+		setup_pod_account::<T>();
+		let (curator_lookup, bounty_id) = create_bounty::<T>()?;
+		Treasury::<T>::on_initialize(T::BlockNumber::zero());
+
+		let bounty_id = BountyCount::get() - 1;
+		let curator = T::Lookup::lookup(curator_lookup)?;
+		let beneficiary = T::Lookup::unlookup(account("beneficiary", 0, SEED));
+	}: _(RawOrigin::Signed(curator), bounty_id, beneficiary)
+
+	claim_bounty {
+		setup_pod_account::<T>();
+		let (curator_lookup, bounty_id) = create_bounty::<T>()?;
+		Treasury::<T>::on_initialize(T::BlockNumber::zero());
+
+		let bounty_id = BountyCount::get() - 1;
+		let curator = T::Lookup::lookup(curator_lookup)?;
+
+		let beneficiary = T::Lookup::unlookup(account("beneficiary", 0, SEED));
+		Bounties::<T>::award_bounty(RawOrigin::Signed(curator.clone()).into(), bounty_id, beneficiary)?;
+
+		frame_system::Module::<T>::set_block_number(T::BountyDepositPayoutDelay::get());
+
+	}: _(RawOrigin::Signed(curator), bounty_id)
+
+	close_bounty_proposed {
+		setup_pod_account::<T>();
+		let (caller, curator, fee, value, reason) = setup_bounty::<T>(0, 0);
+		Bounties::<T>::propose_bounty(RawOrigin::Signed(caller).into(), value, reason)?;
+		let bounty_id = BountyCount::get() - 1;
+	}: close_bounty(RawOrigin::Root, bounty_id)
+
+	close_bounty_active {
+		setup_pod_account::<T>();
+		let (curator_lookup, bounty_id) = create_bounty::<T>()?;
+		Treasury::<T>::on_initialize(T::BlockNumber::zero());
+		let bounty_id = BountyCount::get() - 1;
+	}: close_bounty(RawOrigin::Root, bounty_id)
+
+	extend_bounty_expiry {
+		setup_pod_account::<T>();
+		let (curator_lookup, bounty_id) = create_bounty::<T>()?;
+		Treasury::<T>::on_initialize(T::BlockNumber::zero());
+
+		let bounty_id = BountyCount::get() - 1;
+		let curator = T::Lookup::lookup(curator_lookup)?;
+	}: _(RawOrigin::Signed(curator), bounty_id, Vec::new())
+
+	spend_funds {
+		let b in 1 .. 100;
+		setup_pod_account::<T>();
+		create_approved_bounties::<T>(b)?;
+
+		let mut budget_remaining = BalanceOf::<T>::max_value();
+		let mut imbalance = PositiveImbalanceOf::<T>::zero();
+		let mut total_weight = Weight::zero();
+		let mut missed_any = false;
+	}: {
+		<Bounties<T> as pallet_treasury::SpendFunds<T>>::spend_funds(
+			&mut budget_remaining,
+			&mut imbalance,
+			&mut total_weight,
+			&mut missed_any,
+		);
+	}
+	verify {
+		ensure!(missed_any == false, "Missed some");
+		ensure!(BountyApprovals::get().is_empty(), "Not all bounties funded");
+	}
+
+	add_child_bounty {
+		let d in 0 .. MAX_BYTES;
+		setup_pod_account::<T>();
+		let (curator_lookup, bounty_id) = create_bounty::<T>()?;
+		let curator = T::Lookup::lookup(curator_lookup)?;
+		let value = T::ChildBountyValueMinimum::get().saturating_mul(10.into());
+		let description = vec![0; d as usize];
+	}: _(RawOrigin::Signed(curator), bounty_id, value, description)
+
+	propose_child_curator {
+		setup_pod_account::<T>();
+		let (curator_lookup, bounty_id) = create_bounty::<T>()?;
+		let curator = T::Lookup::lookup(curator_lookup)?;
+		let value = T::ChildBountyValueMinimum::get().saturating_mul(10.into());
+		Bounties::<T>::add_child_bounty(RawOrigin::Signed(curator.clone()).into(), bounty_id, value, Vec::new())?;
+		let child_bounty_id = ChildBountyCount::get() - 1;
+		let child_curator = T::Lookup::unlookup(account("child_curator", 0, SEED));
+	}: _(RawOrigin::Signed(curator), bounty_id, child_bounty_id, child_curator, value / 2.into())
+
+	accept_child_curator {
+		setup_pod_account::<T>();
+		let (_, child_curator, bounty_id, child_bounty_id) = create_child_bounty::<T>()?;
+	}: _(RawOrigin::Signed(child_curator), bounty_id, child_bounty_id)
+
+	// Worst case when the parent curator unassigns an active child curator, slashing them.
+	unassign_child_curator {
+		setup_pod_account::<T>();
+		let (curator, child_curator, bounty_id, child_bounty_id) = create_child_bounty::<T>()?;
+		Bounties::<T>::accept_child_curator(RawOrigin::Signed(child_curator).into(), bounty_id, child_bounty_id)?;
+	}: _(RawOrigin::Signed(curator), bounty_id, child_bounty_id)
+
+	award_child_bounty {
+		setup_pod_account::<T>();
+		let (_, child_curator, bounty_id, child_bounty_id) = create_child_bounty::<T>()?;
+		Bounties::<T>::accept_child_curator(RawOrigin::Signed(child_curator.clone()).into(), bounty_id, child_bounty_id)?;
+		let beneficiary = T::Lookup::unlookup(account("beneficiary", 0, SEED));
+	}: _(RawOrigin::Signed(child_curator), bounty_id, child_bounty_id, beneficiary)
+
+	claim_child_bounty {
+		setup_pod_account::<T>();
+		let (_, child_curator, bounty_id, child_bounty_id) = create_child_bounty::<T>()?;
+		Bounties::<T>::accept_child_curator(RawOrigin::Signed(child_curator.clone()).into(), bounty_id, child_bounty_id)?;
+		let beneficiary = T::Lookup::unlookup(account("beneficiary", 0, SEED));
+		Bounties::<T>::award_child_bounty(
+			RawOrigin::Signed(child_curator.clone()).into(),
+			bounty_id,
+			child_bounty_id,
+			beneficiary,
+		)?;
+
+		frame_system::Module::<T>::set_block_number(T::BountyDepositPayoutDelay::get());
+
+	}: _(RawOrigin::Signed(child_curator), bounty_id, child_bounty_id)
+
+	// Worst case when the child curator is active and their deposit has to be returned.
+	close_child_bounty {
+		setup_pod_account::<T>();
+		let (_, child_curator, bounty_id, child_bounty_id) = create_child_bounty::<T>()?;
+		Bounties::<T>::accept_child_curator(RawOrigin::Signed(child_curator).into(), bounty_id, child_bounty_id)?;
+	}: _(RawOrigin::Root, bounty_id, child_bounty_id)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::tests::{new_test_ext, Test};
+	use frame_support::assert_ok;
+
+	#[test]
+	fn test_benchmarks() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(test_benchmark_propose_bounty::<Test>());
+			assert_ok!(test_benchmark_approve_bounty::<Test>());
+			assert_ok!(test_benchmark_propose_curator::<Test>());
+			assert_ok!(test_benchmark_unassign_curator::<Test>());
+			assert_ok!(test_benchmark_accept_curator::<Test>());
+			assert_ok!(test_benchmark_award_bounty::<Test>());
+			assert_ok!(test_benchmark_claim_bounty::<Test>());
+			assert_ok!(test_benchmark_close_bounty_proposed::<Test>());
+			assert_ok!(test_benchmark_close_bounty_active::<Test>());
+			assert_ok!(test_benchmark_extend_bounty_expiry::<Test>());
+			assert_ok!(test_benchmark_spend_funds::<Test>());
+			assert_ok!(test_benchmark_add_child_bounty::<Test>());
+			assert_ok!(test_benchmark_propose_child_curator::<Test>());
+			assert_ok!(test_benchmark_accept_child_curator::<Test>());
+			assert_ok!(test_benchmark_unassign_child_curator::<Test>());
+			assert_ok!(test_benchmark_award_child_bounty::<Test>());
+			assert_ok!(test_benchmark_claim_child_bounty::<Test>());
+			assert_ok!(test_benchmark_close_child_bounty::<Test>());
+		});
+	}
+}
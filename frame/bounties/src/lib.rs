@@ -0,0 +1,1203 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2020 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # Bounties Module
+//!
+//! - [`bounties::Trait`](./trait.Trait.html)
+//! - [`Call`](./enum.Call.html)
+//!
+//! ## Overview
+//!
+//! A Bounty Spending is a reward for a specified body of work - or specified set of objectives - that
+//! needs to be executed for a predefined Treasury amount to be paid out. A curator is assigned after
+//! the bounty is approved and funded by Council, to be delegated
+//! with the responsibility of assigning a payout address once the specified set of objectives is completed.
+//!
+//! After the Council has activated a bounty, it delegates the work that requires expertise to a curator
+//! in exchange of a deposit. Once the curator accepts the bounty, they
+//! get to close the Active bounty. Closing the Active bounty enacts a delayed payout to the payout
+//! address, the curator fee and the return of the curator deposit. The
+//! delay allows for intervention through regular democracy. The Council gets to unassign the curator,
+//! resulting in a new curator election. The Council also gets to cancel
+//! the bounty if deemed necessary before assigning a curator or once the bounty is active or payout
+//! is pending, resulting in the slash of the curator's deposit.
+//!
+//! Approved bounties are funded from the treasury pot at the end of each spend period, through
+//! the treasury's [`SpendFunds`](../pallet_treasury/trait.SpendFunds.html) hook.
+//!
+//! ### Child Bounties
+//!
+//! The curator of an active bounty may split its work up into child bounties, each funded from
+//! the parent bounty's account and managed by a child curator of their own. The parent curator
+//! proposes the child curators and their fees, which are paid out of the parent curator's own
+//! fee. A child bounty is awarded and claimed in the same way as its parent. A bounty can only be
+//! awarded once all of its child bounties are claimed or closed, and closing a bounty closes its
+//! child bounties as well.
+//!
+//! ### Terminology
+//!
+//! - **Bounty spending proposal:** A proposal to reward a predefined body of work upon completion by
+//! the Treasury.
+//! - **Proposer:** An account proposing a bounty spending.
+//! - **Curator:** An account managing the bounty and assigning a payout address receiving the reward
+//! for the completion of work.
+//! - **Deposit:** The amount held on deposit for placing a bounty proposal plus the amount held on
+//! deposit per byte within the bounty description.
+//! - **Curator deposit:** The payment from a candidate willing to curate an approved bounty. The deposit
+//! is returned when/if the bounty is completed.
+//! - **Bounty value:** The total amount that should be paid to the Payout Address if the bounty is
+//! rewarded.
+//! - **Payout address:** The account to which the total or part of the bounty is assigned to.
+//! - **Payout Delay:** The delay period for which a bounty beneficiary needs to wait before claiming.
+//! - **Curator fee:** The reserved upfront payment for a curator for work related to the bounty.
+//! - **Child bounty:** A part of an active bounty's work, carved out by its curator along with
+//! part of its value.
+//!
+//! ## Interface
+//!
+//! ### Dispatchable Functions
+//!
+//! Bounty protocol:
+//! - `propose_bounty` - Propose a specific treasury amount to be earmarked for a predefined set of
+//! tasks and stake the required deposit.
+//! - `approve_bounty` - Accept a specific treasury amount to be earmarked for a predefined body of work.
+//! - `propose_curator` - Assign an account to a bounty as candidate curator.
+//! - `accept_curator` - Accept a bounty assignment from the Council, setting a curator deposit.
+//! - `extend_bounty_expiry` - Extend the expiry block number of the bounty and stay active.
+//! - `award_bounty` - Close and pay out the specified amount for the completed work.
+//! - `claim_bounty` - Claim a specific bounty amount from the Payout Address.
+//! - `unassign_curator` - Unassign an accepted curator from a specific earmark.
+//! - `close_bounty` - Cancel the earmark for a specific treasury amount and close the bounty.
+//!
+//! Child bounty protocol:
+//! - `add_child_bounty` - Carve a child bounty out of an active bounty.
+//! - `propose_child_curator` - Assign an account to a child bounty as candidate curator.
+//! - `accept_child_curator` - Accept a child bounty assignment, setting a curator deposit.
+//! - `unassign_child_curator` - Unassign the curator of a child bounty.
+//! - `award_child_bounty` - Close and pay out a child bounty for the completed work.
+//! - `claim_child_bounty` - Claim the payout of a child bounty.
+//! - `close_child_bounty` - Cancel a child bounty, returning its funds to the parent bounty.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+mod tests;
+mod benchmarking;
+mod default_weights;
+
+use sp_std::prelude::*;
+use frame_support::{decl_module, decl_storage, decl_event, ensure, decl_error};
+use frame_support::traits::{
+	Currency, Get, Imbalance, OnUnbalanced, ExistenceRequirement::{AllowDeath, KeepAlive},
+	ReservableCurrency, EnsureOrigin,
+};
+use sp_runtime::{Permill, RuntimeDebug, DispatchResult, DispatchError, traits::{
+	Zero, StaticLookup, AccountIdConversion, Saturating, BadOrigin
+}};
+use frame_support::dispatch::DispatchResultWithPostInfo;
+use frame_support::weights::Weight;
+use frame_support::type_info::TypeInfo;
+use codec::{Encode, Decode};
+use frame_system::{self as system, ensure_signed};
+
+pub use pallet_treasury::{BalanceOf, PositiveImbalanceOf};
+
+pub trait WeightInfo {
+	fn propose_bounty(d: u32, ) -> Weight;
+	fn approve_bounty() -> Weight;
+	fn propose_curator() -> Weight;
+	fn unassign_curator() -> Weight;
+	fn accept_curator() -> Weight;
+	fn award_bounty() -> Weight;
+	fn claim_bounty() -> Weight;
+	fn close_bounty_proposed() -> Weight;
+	fn close_bounty_active() -> Weight;
+	fn extend_bounty_expiry() -> Weight;
+	fn spend_funds(b: u32, ) -> Weight;
+	fn add_child_bounty(d: u32, ) -> Weight;
+	fn propose_child_curator() -> Weight;
+	fn accept_child_curator() -> Weight;
+	fn unassign_child_curator() -> Weight;
+	fn award_child_bounty() -> Weight;
+	fn claim_child_bounty() -> Weight;
+	fn close_child_bounty() -> Weight;
+}
+
+pub trait Trait: frame_system::Trait + pallet_treasury::Trait {
+	/// The amount held on deposit for placing a bounty proposal.
+	type BountyDepositBase: Get<BalanceOf<Self>>;
+
+	/// The delay period for which a bounty beneficiary need to wait before claim the payout.
+	type BountyDepositPayoutDelay: Get<Self::BlockNumber>;
+
+	/// Bounty duration in blocks.
+	type BountyUpdatePeriod: Get<Self::BlockNumber>;
+
+	/// Percentage of the curator fee that will be reserved upfront as deposit for bounty curator.
+	type BountyCuratorDeposit: Get<Permill>;
+
+	/// Minimum value for a bounty.
+	type BountyValueMinimum: Get<BalanceOf<Self>>;
+
+	/// Maximum number of child bounties that a bounty may have open at once.
+	type MaxActiveChildBountyCount: Get<u32>;
+
+	/// Minimum value for a child bounty.
+	type ChildBountyValueMinimum: Get<BalanceOf<Self>>;
+
+	/// The overarching event type.
+	type Event: From<Event<Self>> + Into<<Self as frame_system::Trait>::Event>;
+
+	/// Weight information for extrinsics in this pallet.
+	type WeightInfo: WeightInfo;
+}
+
+/// An index of a bounty. Just a `u32`.
+pub type BountyIndex = u32;
+
+/// A bounty proposal.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct Bounty<AccountId, Balance, BlockNumber> {
+	/// The account proposing it.
+	proposer: AccountId,
+	/// The (total) amount that should be paid if the bounty is rewarded.
+	value: Balance,
+	/// The curator fee. Included in value.
+	fee: Balance,
+	/// The deposit of curator.
+	curator_deposit: Balance,
+	/// The amount held on deposit (reserved) for making this proposal.
+	bond: Balance,
+	/// The status of this bounty.
+	status: BountyStatus<AccountId, BlockNumber>,
+}
+
+/// The status of a bounty proposal.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub enum BountyStatus<AccountId, BlockNumber> {
+	/// The bounty is proposed and waiting for approval.
+	Proposed,
+	/// The bounty is approved and waiting to become active at next spend period.
+	Approved,
+	/// The bounty is funded and waiting for curator assignment.
+	Funded,
+	/// A curator has been proposed by the `ApproveOrigin`. Waiting for acceptance from the curator.
+	CuratorProposed {
+		/// The assigned curator of this bounty.
+		curator: AccountId,
+	},
+	/// The bounty is active and waiting to be awarded.
+	Active {
+		/// The curator of this bounty.
+		curator: AccountId,
+		/// An update from the curator is due by this block, else they are considered inactive.
+		update_due: BlockNumber,
+	},
+	/// The bounty is awarded and waiting to released after a delay.
+	PendingPayout {
+		/// The curator of this bounty.
+		curator: AccountId,
+		/// The beneficiary of the bounty.
+		beneficiary: AccountId,
+		/// When the bounty can be claimed.
+		unlock_at: BlockNumber,
+	},
+}
+
+/// A child bounty, funded from the account of its parent bounty.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct ChildBounty<AccountId, Balance, BlockNumber> {
+	/// The index of the parent bounty.
+	parent_bounty: BountyIndex,
+	/// The (total) amount that should be paid if the child bounty is rewarded.
+	value: Balance,
+	/// The child curator fee. Included in value, and paid out of the parent curator's fee.
+	fee: Balance,
+	/// The deposit of the child curator.
+	curator_deposit: Balance,
+	/// The status of this child bounty.
+	status: ChildBountyStatus<AccountId, BlockNumber>,
+}
+
+/// The status of a child bounty.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub enum ChildBountyStatus<AccountId, BlockNumber> {
+	/// The child bounty is funded and waiting for curator assignment.
+	Added,
+	/// A curator has been proposed by the parent curator. Waiting for acceptance from the curator.
+	CuratorProposed {
+		/// The assigned curator of this child bounty.
+		curator: AccountId,
+	},
+	/// The child bounty is active and waiting to be awarded.
+	Active {
+		/// The curator of this child bounty.
+		curator: AccountId,
+	},
+	/// The child bounty is awarded and waiting to released after a delay.
+	PendingPayout {
+		/// The curator of this child bounty.
+		curator: AccountId,
+		/// The beneficiary of the child bounty.
+		beneficiary: AccountId,
+		/// When the child bounty can be claimed.
+		unlock_at: BlockNumber,
+	},
+}
+
+decl_storage! {
+	// Bounties used to be part of the treasury module; its storage prefix is kept so that existing
+	// bounties carry over.
+	trait Store for Module<T: Trait> as Treasury {
+		/// Number of bounty proposals that have been made.
+		pub BountyCount get(fn bounty_count): BountyIndex;
+
+		/// Bounties that have been made.
+		pub Bounties get(fn bounties):
+			map hasher(twox_64_concat) BountyIndex
+			=> Option<Bounty<T::AccountId, BalanceOf<T>, T::BlockNumber>>;
+
+		/// The description of each bounty.
+		pub BountyDescriptions get(fn bounty_descriptions): map hasher(twox_64_concat) BountyIndex => Option<Vec<u8>>;
+
+		/// Bounty indices that have been approved but not yet funded.
+		pub BountyApprovals get(fn bounty_approvals): Vec<BountyIndex>;
+
+		/// Number of child bounties that have been added.
+		pub ChildBountyCount get(fn child_bounty_count): BountyIndex;
+
+		/// Number of open child bounties of each bounty.
+		pub ParentChildBounties get(fn parent_child_bounties): map hasher(twox_64_concat) BountyIndex => u32;
+
+		/// Child bounties that have been added, keyed by their parent bounty.
+		pub ChildBounties get(fn child_bounties):
+			double_map hasher(twox_64_concat) BountyIndex, hasher(twox_64_concat) BountyIndex
+			=> Option<ChildBounty<T::AccountId, BalanceOf<T>, T::BlockNumber>>;
+
+		/// The description of each child bounty.
+		pub ChildBountyDescriptions get(fn child_bounty_descriptions):
+			map hasher(twox_64_concat) BountyIndex => Option<Vec<u8>>;
+
+		/// The sum of the curator fees of the child bounties of each bounty, which are deducted
+		/// from the fee of its curator.
+		pub ChildrenCuratorFees get(fn children_curator_fees):
+			map hasher(twox_64_concat) BountyIndex => BalanceOf<T>;
+	}
+}
+
+decl_event!(
+	pub enum Event<T>
+	where
+		Balance = BalanceOf<T>,
+		<T as frame_system::Trait>::AccountId,
+	{
+		/// New bounty proposal. \[index\]
+		BountyProposed(BountyIndex),
+		/// A bounty proposal was rejected; funds were slashed. \[index, bond\]
+		BountyRejected(BountyIndex, Balance),
+		/// A bounty proposal is funded and became active. \[index\]
+		BountyBecameActive(BountyIndex),
+		/// A bounty is awarded to a beneficiary. \[index, beneficiary\]
+		BountyAwarded(BountyIndex, AccountId),
+		/// A bounty is claimed by beneficiary. \[index, payout, beneficiary\]
+		BountyClaimed(BountyIndex, Balance, AccountId),
+		/// A bounty is cancelled. \[index\]
+		BountyCanceled(BountyIndex),
+		/// A bounty expiry is extended. \[index\]
+		BountyExtended(BountyIndex),
+		/// A child bounty is added. \[index, child_index\]
+		ChildBountyAdded(BountyIndex, BountyIndex),
+		/// A child bounty is awarded to a beneficiary. \[index, child_index, beneficiary\]
+		ChildBountyAwarded(BountyIndex, BountyIndex, AccountId),
+		/// A child bounty is claimed by beneficiary. \[index, child_index, payout, beneficiary\]
+		ChildBountyClaimed(BountyIndex, BountyIndex, Balance, AccountId),
+		/// A child bounty is cancelled. \[index, child_index\]
+		ChildBountyCanceled(BountyIndex, BountyIndex),
+	}
+);
+
+decl_error! {
+	/// Error for the bounties module.
+	pub enum Error for Module<T: Trait> {
+		/// Proposer's balance is too low.
+		InsufficientProposersBalance,
+		/// No proposal or bounty at that index.
+		InvalidIndex,
+		/// The reason given is just too big.
+		ReasonTooBig,
+		/// The bounty status is unexpected.
+		UnexpectedStatus,
+		/// Require bounty curator.
+		RequireCurator,
+		/// Invalid bounty value.
+		InvalidValue,
+		/// Invalid bounty fee.
+		InvalidFee,
+		/// A bounty payout is pending.
+		/// To cancel the bounty, you must unassign and slash the curator.
+		PendingPayout,
+		/// The bounties cannot be claimed/closed because it's still in the countdown period.
+		Premature,
+		/// The bounty cannot be awarded while it has open child bounties.
+		HasActiveChildBounty,
+		/// The bounty already has the maximum number of open child bounties.
+		TooManyChildBounties,
+		/// The bounty's account cannot fund the child bounty.
+		InsufficientBountyBalance,
+	}
+}
+
+decl_module! {
+	pub struct Module<T: Trait>
+		for enum Call
+		where origin: T::Origin
+	{
+		/// The amount held on deposit for placing a bounty proposal.
+		const BountyDepositBase: BalanceOf<T> = T::BountyDepositBase::get();
+
+		/// The delay period for which a bounty beneficiary need to wait before claim the payout.
+		const BountyDepositPayoutDelay: T::BlockNumber = T::BountyDepositPayoutDelay::get();
+
+		/// Bounty duration in blocks.
+		const BountyUpdatePeriod: T::BlockNumber = T::BountyUpdatePeriod::get();
+
+		/// Percentage of the curator fee that will be reserved upfront as deposit for bounty curator.
+		const BountyCuratorDeposit: Permill = T::BountyCuratorDeposit::get();
+
+		/// Minimum value for a bounty.
+		const BountyValueMinimum: BalanceOf<T> = T::BountyValueMinimum::get();
+
+		/// Maximum number of child bounties that a bounty may have open at once.
+		const MaxActiveChildBountyCount: u32 = T::MaxActiveChildBountyCount::get();
+
+		/// Minimum value for a child bounty.
+		const ChildBountyValueMinimum: BalanceOf<T> = T::ChildBountyValueMinimum::get();
+
+		type Error = Error<T>;
+
+		fn deposit_event() = default;
+
+		/// Propose a new bounty.
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		///
+		/// Payment: `BountyDepositBase` will be reserved from the origin account, as well as
+		/// `DataDepositPerByte` for each byte in `description`. It will be unreserved upon
+		/// approval, or slashed when rejected.
+		///
+		/// - `value`: The total payment amount of this bounty, curator fee included.
+		/// - `description`: The description of this bounty.
+		#[weight = <T as Trait>::WeightInfo::propose_bounty(description.len() as u32)]
+		fn propose_bounty(
+			origin,
+			#[compact] value: BalanceOf<T>,
+			description: Vec<u8>,
+		) {
+			let proposer = ensure_signed(origin)?;
+			Self::create_bounty(proposer, description, value)?;
+		}
+
+		/// Approve a bounty proposal. At a later time, the bounty will be funded and become active
+		/// and the original deposit will be returned.
+		///
+		/// May only be called from `T::ApproveOrigin`.
+		///
+		/// # <weight>
+		/// - O(1).
+		/// - Limited storage reads.
+		/// - One DB change.
+		/// # </weight>
+		#[weight = <T as Trait>::WeightInfo::approve_bounty()]
+		fn approve_bounty(origin, #[compact] bounty_id: BountyIndex) {
+			T::ApproveOrigin::ensure_origin(origin)?;
+
+			Bounties::<T>::try_mutate_exists(bounty_id, |maybe_bounty| -> DispatchResult {
+				let mut bounty = maybe_bounty.as_mut().ok_or(Error::<T>::InvalidIndex)?;
+				ensure!(bounty.status == BountyStatus::Proposed, Error::<T>::UnexpectedStatus);
+
+				bounty.status = BountyStatus::Approved;
+
+				BountyApprovals::append(bounty_id);
+
+				Ok(())
+			})?;
+		}
+
+		/// Assign a curator to a funded bounty.
+		///
+		/// May only be called from `T::ApproveOrigin`.
+		///
+		/// # <weight>
+		/// - O(1).
+		/// - Limited storage reads.
+		/// - One DB change.
+		/// # </weight>
+		#[weight = <T as Trait>::WeightInfo::propose_curator()]
+		fn propose_curator(
+			origin,
+			#[compact] bounty_id: BountyIndex,
+			curator: <T::Lookup as StaticLookup>::Source,
+			#[compact] fee: BalanceOf<T>,
+		) {
+			T::ApproveOrigin::ensure_origin(origin)?;
+
+			let curator = T::Lookup::lookup(curator)?;
+			Bounties::<T>::try_mutate_exists(bounty_id, |maybe_bounty| -> DispatchResult {
+				let mut bounty = maybe_bounty.as_mut().ok_or(Error::<T>::InvalidIndex)?;
+				match bounty.status {
+					BountyStatus::Funded | BountyStatus::CuratorProposed { .. } => {},
+					_ => return Err(Error::<T>::UnexpectedStatus.into()),
+				};
+
+				ensure!(fee < bounty.value, Error::<T>::InvalidFee);
+				// The fees already promised to child curators must still be covered.
+				ensure!(fee >= Self::children_curator_fees(bounty_id), Error::<T>::InvalidFee);
+
+				bounty.status = BountyStatus::CuratorProposed { curator };
+				bounty.fee = fee;
+
+				Ok(())
+			})?;
+		}
+
+		/// Unassign curator from a bounty.
+		///
+		/// This function can only be called by the `RejectOrigin` a signed origin.
+		///
+		/// If this function is called by the `RejectOrigin`, we assume that the curator is malicious
+		/// or inactive. As a result, we will slash the curator when possible.
+		///
+		/// If the origin is the curator, we take this as a sign they are unable to do their job and
+		/// they willingly give up. We could slash them, but for now we allow them to recover their
+		/// deposit and exit without issue. (We may want to change this if it is abused.)
+		///
+		/// Finally, the origin can be anyone if and only if the curator is "inactive". This allows
+		/// anyone in the community to call out that a curator is not doing their due diligence, and
+		/// we should pick a new curator. In this case the curator should also be slashed.
+		///
+		/// # <weight>
+		/// - O(1).
+		/// - Limited storage reads.
+		/// - One DB change.
+		/// # </weight>
+		#[weight = <T as Trait>::WeightInfo::unassign_curator()]
+		fn unassign_curator(
+			origin,
+			#[compact] bounty_id: BountyIndex,
+		) {
+			let maybe_sender = ensure_signed(origin.clone())
+				.map(Some)
+				.or_else(|_| T::RejectOrigin::ensure_origin(origin).map(|_| None))?;
+
+			Bounties::<T>::try_mutate_exists(bounty_id, |maybe_bounty| -> DispatchResult {
+				let mut bounty = maybe_bounty.as_mut().ok_or(Error::<T>::InvalidIndex)?;
+
+				let slash_curator = |curator: &T::AccountId, curator_deposit: &mut BalanceOf<T>| {
+					let imbalance = T::Currency::slash_reserved(curator, *curator_deposit).0;
+					T::OnSlash::on_unbalanced(imbalance);
+					*curator_deposit = Zero::zero();
+				};
+
+				match bounty.status {
+					BountyStatus::Proposed | BountyStatus::Approved | BountyStatus::Funded => {
+						// No curator to unassign at this point.
+						return Err(Error::<T>::UnexpectedStatus.into())
+					}
+					BountyStatus::CuratorProposed { ref curator } => {
+						// A curator has been proposed, but not accepted yet.
+						// Either `RejectOrigin` or the proposed curator can unassign the curator.
+						ensure!(maybe_sender.map_or(true, |sender| sender == *curator), BadOrigin);
+					},
+					BountyStatus::Active { ref curator, ref update_due } => {
+						// The bounty is active.
+						match maybe_sender {
+							// If the `RejectOrigin` is calling this function, slash the curator.
+							None => {
+								slash_curator(curator, &mut bounty.curator_deposit);
+								// Continue to change bounty status below...
+							},
+							Some(sender) => {
+								// If the sender is not the curator, and the curator is inactive,
+								// slash the curator.
+								if sender != *curator {
+									let block_number = system::Module::<T>::block_number();
+									if *update_due < block_number {
+										slash_curator(curator, &mut bounty.curator_deposit);
+										// Continue to change bounty status below...
+									} else {
+										// Curator has more time to give an update.
+										return Err(Error::<T>::Premature.into())
+									}
+								} else {
+									// Else this is the curator, willingly giving up their role.
+									// Give back their deposit.
+									let _ = T::Currency::unreserve(&curator, bounty.curator_deposit);
+									// Continue to change bounty status below...
+								}
+							},
+						}
+					},
+					BountyStatus::PendingPayout { ref curator, .. } => {
+						// The bounty is pending payout, so only council can unassign a curator.
+						// By doing so, they are claiming the curator is acting maliciously, so
+						// we slash the curator.
+						ensure!(maybe_sender.is_none(), BadOrigin);
+						slash_curator(curator, &mut bounty.curator_deposit);
+						// Continue to change bounty status below...
+					}
+				};
+
+				bounty.status = BountyStatus::Funded;
+				Ok(())
+			})?;
+		}
+
+		/// Accept the curator role for a bounty.
+		/// A deposit will be reserved from curator and refund upon successful payout.
+		///
+		/// May only be called from the curator.
+		///
+		/// # <weight>
+		/// - O(1).
+		/// - Limited storage reads.
+		/// - One DB change.
+		/// # </weight>
+		#[weight = <T as Trait>::WeightInfo::accept_curator()]
+		fn accept_curator(origin, #[compact] bounty_id: BountyIndex) {
+			let signer = ensure_signed(origin)?;
+
+			Bounties::<T>::try_mutate_exists(bounty_id, |maybe_bounty| -> DispatchResult {
+				let mut bounty = maybe_bounty.as_mut().ok_or(Error::<T>::InvalidIndex)?;
+
+				match bounty.status {
+					BountyStatus::CuratorProposed { ref curator } => {
+						ensure!(signer == *curator, Error::<T>::RequireCurator);
+
+						let deposit = T::BountyCuratorDeposit::get() * bounty.fee;
+						T::Currency::reserve(curator, deposit)?;
+						bounty.curator_deposit = deposit;
+
+						let update_due = system::Module::<T>::block_number() + T::BountyUpdatePeriod::get();
+						bounty.status = BountyStatus::Active { curator: curator.clone(), update_due };
+
+						Ok(())
+					},
+					_ => Err(Error::<T>::UnexpectedStatus.into()),
+				}
+			})?;
+		}
+
+		/// Award bounty to a beneficiary account. The beneficiary will be able to claim the funds after a delay.
+		///
+		/// The dispatch origin for this call must be the curator of this bounty, and the bounty must
+		/// not have any open child bounties.
+		///
+		/// - `bounty_id`: Bounty ID to award.
+		/// - `beneficiary`: The beneficiary account whom will receive the payout.
+		#[weight = <T as Trait>::WeightInfo::award_bounty()]
+		fn award_bounty(origin, #[compact] bounty_id: BountyIndex, beneficiary: <T::Lookup as StaticLookup>::Source) {
+			let signer = ensure_signed(origin)?;
+			let beneficiary = T::Lookup::lookup(beneficiary)?;
+
+			Bounties::<T>::try_mutate_exists(bounty_id, |maybe_bounty| -> DispatchResult {
+				let mut bounty = maybe_bounty.as_mut().ok_or(Error::<T>::InvalidIndex)?;
+				match &bounty.status {
+					BountyStatus::Active {
+						curator,
+						..
+					} => {
+						ensure!(signer == *curator, Error::<T>::RequireCurator);
+					},
+					_ => return Err(Error::<T>::UnexpectedStatus.into()),
+				}
+				ensure!(Self::parent_child_bounties(bounty_id) == 0, Error::<T>::HasActiveChildBounty);
+				bounty.status = BountyStatus::PendingPayout {
+					curator: signer,
+					beneficiary: beneficiary.clone(),
+					unlock_at: system::Module::<T>::block_number() + T::BountyDepositPayoutDelay::get(),
+				};
+
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::<T>::BountyAwarded(bounty_id, beneficiary));
+		}
+
+		/// Claim the payout from an awarded bounty after payout delay.
+		///
+		/// The curator receives their fee minus the fees of any child curators, which were paid
+		/// out of the child bounties.
+		///
+		/// The dispatch origin for this call must be the beneficiary of this bounty.
+		///
+		/// - `bounty_id`: Bounty ID to claim.
+		#[weight = <T as Trait>::WeightInfo::claim_bounty()]
+		fn claim_bounty(origin, #[compact] bounty_id: BountyIndex) {
+			let _ = ensure_signed(origin)?; // anyone can trigger claim
+
+			Bounties::<T>::try_mutate_exists(bounty_id, |maybe_bounty| -> DispatchResult {
+				let bounty = maybe_bounty.take().ok_or(Error::<T>::InvalidIndex)?;
+				if let BountyStatus::PendingPayout { curator, beneficiary, unlock_at } = bounty.status {
+					ensure!(system::Module::<T>::block_number() >= unlock_at, Error::<T>::Premature);
+					let bounty_account = Self::bounty_account_id(bounty_id);
+					let balance = T::Currency::free_balance(&bounty_account);
+					let fee = bounty.fee.min(balance); // just to be safe
+					let children_fee = ChildrenCuratorFees::<T>::take(bounty_id);
+					let curator_fee = fee.saturating_sub(children_fee);
+					let payout = balance.saturating_sub(curator_fee);
+					let _ = T::Currency::unreserve(&curator, bounty.curator_deposit);
+					let _ = T::Currency::transfer(&bounty_account, &curator, curator_fee, AllowDeath); // should not fail
+					let _ = T::Currency::transfer(&bounty_account, &beneficiary, payout, AllowDeath); // should not fail
+					*maybe_bounty = None;
+
+					BountyDescriptions::remove(bounty_id);
+
+					Self::deposit_event(Event::<T>::BountyClaimed(bounty_id, payout, beneficiary));
+					Ok(())
+				} else {
+					Err(Error::<T>::UnexpectedStatus.into())
+				}
+			})?;
+		}
+
+		/// Cancel a proposed or active bounty. All the funds will be sent to treasury and
+		/// the curator deposit will be unreserved if possible.
+		///
+		/// Any open child bounties are closed along with the bounty, returning their funds and
+		/// the deposits of their curators.
+		///
+		/// Only `T::RejectOrigin` is able to cancel a bounty.
+		///
+		/// - `bounty_id`: Bounty ID to cancel.
+		#[weight = <T as Trait>::WeightInfo::close_bounty_proposed()
+			.max(<T as Trait>::WeightInfo::close_bounty_active().saturating_add(
+				<T as Trait>::WeightInfo::close_child_bounty()
+					.saturating_mul(T::MaxActiveChildBountyCount::get().into())
+			))]
+		fn close_bounty(origin, #[compact] bounty_id: BountyIndex) -> DispatchResultWithPostInfo {
+			T::RejectOrigin::ensure_origin(origin)?;
+
+			Bounties::<T>::try_mutate_exists(bounty_id, |maybe_bounty| -> DispatchResultWithPostInfo {
+				let bounty = maybe_bounty.as_ref().ok_or(Error::<T>::InvalidIndex)?;
+
+				match &bounty.status {
+					BountyStatus::Proposed => {
+						// The reject origin would like to cancel a proposed bounty.
+						BountyDescriptions::remove(bounty_id);
+						let value = bounty.bond;
+						let imbalance = T::Currency::slash_reserved(&bounty.proposer, value).0;
+						T::OnSlash::on_unbalanced(imbalance);
+						*maybe_bounty = None;
+
+						Self::deposit_event(Event::<T>::BountyRejected(bounty_id, value));
+						// Return early, nothing else to do.
+						return Ok(Some(<T as Trait>::WeightInfo::close_bounty_proposed()).into())
+					},
+					BountyStatus::Approved => {
+						// For weight reasons, we don't allow a council to cancel in this phase.
+						// We ask for them to wait until it is funded before they can cancel.
+						return Err(Error::<T>::UnexpectedStatus.into())
+					},
+					BountyStatus::Funded |
+					BountyStatus::CuratorProposed { .. } => {
+						// Nothing extra to do besides the removal of the bounty below.
+					},
+					BountyStatus::Active { curator, .. } => {
+						// Cancelled by council, refund deposit of the working curator.
+						let _ = T::Currency::unreserve(&curator, bounty.curator_deposit);
+						// Then execute removal of the bounty below.
+					},
+					BountyStatus::PendingPayout { .. } => {
+						// Bounty is already pending payout. If council wants to cancel
+						// this bounty, it should mean the curator was acting maliciously.
+						// So the council should first unassign the curator, slashing their
+						// deposit.
+						return Err(Error::<T>::PendingPayout.into())
+					}
+				}
+
+				// Child bounties pending payout must be dealt with as above, by unassigning
+				// their curator first.
+				let children = ChildBounties::<T>::iter_prefix(bounty_id).collect::<Vec<_>>();
+				ensure!(
+					children.iter().all(|(_, child_bounty)| match child_bounty.status {
+						ChildBountyStatus::PendingPayout { .. } => false,
+						_ => true,
+					}),
+					Error::<T>::PendingPayout,
+				);
+				let children_len = children.len() as Weight;
+				for (child_bounty_id, child_bounty) in children {
+					Self::remove_child_bounty(bounty_id, child_bounty_id, child_bounty);
+				}
+				ParentChildBounties::remove(bounty_id);
+				ChildrenCuratorFees::<T>::remove(bounty_id);
+
+				let bounty_account = Self::bounty_account_id(bounty_id);
+
+				BountyDescriptions::remove(bounty_id);
+
+				let balance = T::Currency::free_balance(&bounty_account);
+				let _ = T::Currency::transfer(
+					&bounty_account,
+					&pallet_treasury::Module::<T>::account_id(),
+					balance,
+					AllowDeath,
+				); // should not fail
+				*maybe_bounty = None;
+
+				Self::deposit_event(Event::<T>::BountyCanceled(bounty_id));
+				Ok(Some(<T as Trait>::WeightInfo::close_bounty_active().saturating_add(
+					<T as Trait>::WeightInfo::close_child_bounty().saturating_mul(children_len)
+				)).into())
+			})
+		}
+
+		/// Extend the expiry time of an active bounty.
+		///
+		/// The dispatch origin for this call must be the curator of this bounty.
+		///
+		/// - `bounty_id`: Bounty ID to extend.
+		/// - `remark`: additional information.
+		#[weight = <T as Trait>::WeightInfo::extend_bounty_expiry()]
+		fn extend_bounty_expiry(origin, #[compact] bounty_id: BountyIndex, _remark: Vec<u8>) {
+			let signer = ensure_signed(origin)?;
+
+			Bounties::<T>::try_mutate_exists(bounty_id, |maybe_bounty| -> DispatchResult {
+				let bounty = maybe_bounty.as_mut().ok_or(Error::<T>::InvalidIndex)?;
+
+				match bounty.status {
+					BountyStatus::Active { ref curator, ref mut update_due } => {
+						ensure!(*curator == signer, Error::<T>::RequireCurator);
+						*update_due = (system::Module::<T>::block_number() + T::BountyUpdatePeriod::get()).max(*update_due);
+					},
+					_ => return Err(Error::<T>::UnexpectedStatus.into()),
+				}
+
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::<T>::BountyExtended(bounty_id));
+		}
+
+		/// Add a child bounty to an active bounty, transferring `value` from the bounty's account
+		/// to the child bounty's account.
+		///
+		/// The dispatch origin for this call must be the curator of the parent bounty.
+		///
+		/// - `parent_bounty_id`: The bounty to carve the child bounty out of.
+		/// - `value`: The total payment amount of this child bounty, curator fee included.
+		/// - `description`: The description of this child bounty.
+		#[weight = <T as Trait>::WeightInfo::add_child_bounty(description.len() as u32)]
+		fn add_child_bounty(
+			origin,
+			#[compact] parent_bounty_id: BountyIndex,
+			#[compact] value: BalanceOf<T>,
+			description: Vec<u8>,
+		) {
+			let signer = ensure_signed(origin)?;
+			ensure!(description.len() <= T::MaximumReasonLength::get() as usize, Error::<T>::ReasonTooBig);
+			ensure!(value >= T::ChildBountyValueMinimum::get(), Error::<T>::InvalidValue);
+			ensure!(
+				Self::parent_child_bounties(parent_bounty_id) < T::MaxActiveChildBountyCount::get(),
+				Error::<T>::TooManyChildBounties,
+			);
+			Self::ensure_bounty_curator(parent_bounty_id, &signer)?;
+
+			let child_bounty_id = Self::child_bounty_count();
+			T::Currency::transfer(
+				&Self::bounty_account_id(parent_bounty_id),
+				&Self::child_bounty_account_id(child_bounty_id),
+				value,
+				KeepAlive,
+			).map_err(|_| Error::<T>::InsufficientBountyBalance)?;
+
+			ChildBountyCount::put(child_bounty_id + 1);
+			ParentChildBounties::mutate(parent_bounty_id, |count| *count += 1);
+
+			let child_bounty = ChildBounty {
+				parent_bounty: parent_bounty_id,
+				value,
+				fee: Zero::zero(),
+				curator_deposit: Zero::zero(),
+				status: ChildBountyStatus::Added,
+			};
+			ChildBounties::<T>::insert(parent_bounty_id, child_bounty_id, &child_bounty);
+			ChildBountyDescriptions::insert(child_bounty_id, description);
+
+			Self::deposit_event(Event::<T>::ChildBountyAdded(parent_bounty_id, child_bounty_id));
+		}
+
+		/// Propose a curator for a child bounty. The child curator fee is paid out of the fee of
+		/// the parent curator, so the fees of all child curators of a bounty may not exceed it.
+		///
+		/// The dispatch origin for this call must be the curator of the parent bounty.
+		#[weight = <T as Trait>::WeightInfo::propose_child_curator()]
+		fn propose_child_curator(
+			origin,
+			#[compact] parent_bounty_id: BountyIndex,
+			#[compact] child_bounty_id: BountyIndex,
+			curator: <T::Lookup as StaticLookup>::Source,
+			#[compact] fee: BalanceOf<T>,
+		) {
+			let signer = ensure_signed(origin)?;
+			let parent_bounty = Self::ensure_bounty_curator(parent_bounty_id, &signer)?;
+			let curator = T::Lookup::lookup(curator)?;
+
+			ChildBounties::<T>::try_mutate_exists(parent_bounty_id, child_bounty_id, |maybe_child_bounty| -> DispatchResult {
+				let child_bounty = maybe_child_bounty.as_mut().ok_or(Error::<T>::InvalidIndex)?;
+				ensure!(child_bounty.status == ChildBountyStatus::Added, Error::<T>::UnexpectedStatus);
+				ensure!(fee < child_bounty.value, Error::<T>::InvalidFee);
+
+				let children_fees = Self::children_curator_fees(parent_bounty_id).saturating_add(fee);
+				ensure!(children_fees <= parent_bounty.fee, Error::<T>::InvalidFee);
+				ChildrenCuratorFees::<T>::insert(parent_bounty_id, children_fees);
+
+				child_bounty.fee = fee;
+				child_bounty.status = ChildBountyStatus::CuratorProposed { curator };
+
+				Ok(())
+			})?;
+		}
+
+		/// Accept the curator role for a child bounty.
+		/// A deposit will be reserved from curator and refund upon successful payout.
+		///
+		/// May only be called from the proposed child curator.
+		#[weight = <T as Trait>::WeightInfo::accept_child_curator()]
+		fn accept_child_curator(
+			origin,
+			#[compact] parent_bounty_id: BountyIndex,
+			#[compact] child_bounty_id: BountyIndex,
+		) {
+			let signer = ensure_signed(origin)?;
+
+			ChildBounties::<T>::try_mutate_exists(parent_bounty_id, child_bounty_id, |maybe_child_bounty| -> DispatchResult {
+				let child_bounty = maybe_child_bounty.as_mut().ok_or(Error::<T>::InvalidIndex)?;
+
+				match child_bounty.status {
+					ChildBountyStatus::CuratorProposed { ref curator } => {
+						ensure!(signer == *curator, Error::<T>::RequireCurator);
+
+						let deposit = T::BountyCuratorDeposit::get() * child_bounty.fee;
+						T::Currency::reserve(curator, deposit)?;
+						child_bounty.curator_deposit = deposit;
+						child_bounty.status = ChildBountyStatus::Active { curator: curator.clone() };
+
+						Ok(())
+					},
+					_ => Err(Error::<T>::UnexpectedStatus.into()),
+				}
+			})?;
+		}
+
+		/// Unassign the curator of a child bounty.
+		///
+		/// The `RejectOrigin` and the curator of the parent bounty may unassign the child curator
+		/// at any point, slashing their deposit if they had accepted the role. The child curator
+		/// may also give up their role, recovering their deposit, unless the child bounty is
+		/// pending payout.
+		#[weight = <T as Trait>::WeightInfo::unassign_child_curator()]
+		fn unassign_child_curator(
+			origin,
+			#[compact] parent_bounty_id: BountyIndex,
+			#[compact] child_bounty_id: BountyIndex,
+		) {
+			let maybe_sender = ensure_signed(origin.clone())
+				.map(Some)
+				.or_else(|_| T::RejectOrigin::ensure_origin(origin).map(|_| None))?;
+			let parent_curator = Self::bounty_curator(parent_bounty_id);
+			let is_authority = maybe_sender.as_ref().map_or(true, |sender| Some(sender) == parent_curator.as_ref());
+
+			ChildBounties::<T>::try_mutate_exists(parent_bounty_id, child_bounty_id, |maybe_child_bounty| -> DispatchResult {
+				let child_bounty = maybe_child_bounty.as_mut().ok_or(Error::<T>::InvalidIndex)?;
+
+				let slash_curator = |curator: &T::AccountId, curator_deposit: &mut BalanceOf<T>| {
+					let imbalance = T::Currency::slash_reserved(curator, *curator_deposit).0;
+					T::OnSlash::on_unbalanced(imbalance);
+					*curator_deposit = Zero::zero();
+				};
+
+				match child_bounty.status {
+					ChildBountyStatus::Added => {
+						// No curator to unassign at this point.
+						return Err(Error::<T>::UnexpectedStatus.into())
+					},
+					ChildBountyStatus::CuratorProposed { ref curator } => {
+						ensure!(is_authority || maybe_sender.as_ref() == Some(curator), BadOrigin);
+					},
+					ChildBountyStatus::Active { ref curator } => {
+						if is_authority {
+							slash_curator(curator, &mut child_bounty.curator_deposit);
+						} else if maybe_sender.as_ref() == Some(curator) {
+							// The curator willingly gives up their role.
+							let _ = T::Currency::unreserve(curator, child_bounty.curator_deposit);
+							child_bounty.curator_deposit = Zero::zero();
+						} else {
+							return Err(BadOrigin.into())
+						}
+					},
+					ChildBountyStatus::PendingPayout { ref curator, .. } => {
+						ensure!(is_authority, BadOrigin);
+						slash_curator(curator, &mut child_bounty.curator_deposit);
+					},
+				}
+
+				ChildrenCuratorFees::<T>::mutate(parent_bounty_id, |fees| *fees = fees.saturating_sub(child_bounty.fee));
+				child_bounty.fee = Zero::zero();
+				child_bounty.status = ChildBountyStatus::Added;
+				Ok(())
+			})?;
+		}
+
+		/// Award a child bounty to a beneficiary account. The beneficiary will be able to claim the
+		/// funds after a delay.
+		///
+		/// The dispatch origin for this call must be the curator of this child bounty.
+		#[weight = <T as Trait>::WeightInfo::award_child_bounty()]
+		fn award_child_bounty(
+			origin,
+			#[compact] parent_bounty_id: BountyIndex,
+			#[compact] child_bounty_id: BountyIndex,
+			beneficiary: <T::Lookup as StaticLookup>::Source,
+		) {
+			let signer = ensure_signed(origin)?;
+			let beneficiary = T::Lookup::lookup(beneficiary)?;
+
+			ChildBounties::<T>::try_mutate_exists(parent_bounty_id, child_bounty_id, |maybe_child_bounty| -> DispatchResult {
+				let child_bounty = maybe_child_bounty.as_mut().ok_or(Error::<T>::InvalidIndex)?;
+				match &child_bounty.status {
+					ChildBountyStatus::Active { curator } => {
+						ensure!(signer == *curator, Error::<T>::RequireCurator);
+					},
+					_ => return Err(Error::<T>::UnexpectedStatus.into()),
+				}
+				child_bounty.status = ChildBountyStatus::PendingPayout {
+					curator: signer,
+					beneficiary: beneficiary.clone(),
+					unlock_at: system::Module::<T>::block_number() + T::BountyDepositPayoutDelay::get(),
+				};
+
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::<T>::ChildBountyAwarded(parent_bounty_id, child_bounty_id, beneficiary));
+		}
+
+		/// Claim the payout from an awarded child bounty after payout delay.
+		///
+		/// The dispatch origin for this call may be any signed origin.
+		#[weight = <T as Trait>::WeightInfo::claim_child_bounty()]
+		fn claim_child_bounty(
+			origin,
+			#[compact] parent_bounty_id: BountyIndex,
+			#[compact] child_bounty_id: BountyIndex,
+		) {
+			let _ = ensure_signed(origin)?; // anyone can trigger claim
+
+			ChildBounties::<T>::try_mutate_exists(parent_bounty_id, child_bounty_id, |maybe_child_bounty| -> DispatchResult {
+				let child_bounty = maybe_child_bounty.take().ok_or(Error::<T>::InvalidIndex)?;
+				if let ChildBountyStatus::PendingPayout { curator, beneficiary, unlock_at } = child_bounty.status {
+					ensure!(system::Module::<T>::block_number() >= unlock_at, Error::<T>::Premature);
+					let child_bounty_account = Self::child_bounty_account_id(child_bounty_id);
+					let balance = T::Currency::free_balance(&child_bounty_account);
+					let fee = child_bounty.fee.min(balance); // just to be safe
+					let payout = balance.saturating_sub(fee);
+					let _ = T::Currency::unreserve(&curator, child_bounty.curator_deposit);
+					let _ = T::Currency::transfer(&child_bounty_account, &curator, fee, AllowDeath); // should not fail
+					let _ = T::Currency::transfer(&child_bounty_account, &beneficiary, payout, AllowDeath); // should not fail
+					*maybe_child_bounty = None;
+
+					ChildBountyDescriptions::remove(child_bounty_id);
+					ParentChildBounties::mutate(parent_bounty_id, |count| *count = count.saturating_sub(1));
+
+					Self::deposit_event(Event::<T>::ChildBountyClaimed(parent_bounty_id, child_bounty_id, payout, beneficiary));
+					Ok(())
+				} else {
+					Err(Error::<T>::UnexpectedStatus.into())
+				}
+			})?;
+		}
+
+		/// Cancel a child bounty. Its funds are returned to the parent bounty and the child
+		/// curator deposit will be unreserved if possible.
+		///
+		/// May only be called from `T::RejectOrigin` or the curator of the parent bounty. A child
+		/// bounty which is pending payout can't be closed; unassign its curator first.
+		#[weight = <T as Trait>::WeightInfo::close_child_bounty()]
+		fn close_child_bounty(
+			origin,
+			#[compact] parent_bounty_id: BountyIndex,
+			#[compact] child_bounty_id: BountyIndex,
+		) {
+			let maybe_sender = ensure_signed(origin.clone())
+				.map(Some)
+				.or_else(|_| T::RejectOrigin::ensure_origin(origin).map(|_| None))?;
+			if let Some(sender) = maybe_sender {
+				ensure!(Some(sender) == Self::bounty_curator(parent_bounty_id), BadOrigin);
+			}
+
+			let child_bounty = Self::child_bounties(parent_bounty_id, child_bounty_id)
+				.ok_or(Error::<T>::InvalidIndex)?;
+			if let ChildBountyStatus::PendingPayout { .. } = child_bounty.status {
+				return Err(Error::<T>::PendingPayout.into())
+			}
+
+			Self::remove_child_bounty(parent_bounty_id, child_bounty_id, child_bounty);
+			ParentChildBounties::mutate(parent_bounty_id, |count| *count = count.saturating_sub(1));
+		}
+	}
+}
+
+impl<T: Trait> Module<T> {
+	// Add public immutables and private mutables.
+
+	/// The account ID of a bounty account
+	pub fn bounty_account_id(id: BountyIndex) -> T::AccountId {
+		// only use two byte prefix to support 16 byte account id (used by test)
+		// "modl" ++ "py/trsry" ++ "bt" is 14 bytes, and two bytes remaining for bounty index
+		T::ModuleId::get().into_sub_account(("bt", id))
+	}
+
+	/// The account ID of a child bounty account.
+	pub fn child_bounty_account_id(id: BountyIndex) -> T::AccountId {
+		// same as above, child bounty indices are unique across all parent bounties.
+		T::ModuleId::get().into_sub_account(("cb", id))
+	}
+
+	/// The curator of the bounty `bounty_id`, if it is active.
+	fn bounty_curator(bounty_id: BountyIndex) -> Option<T::AccountId> {
+		match Self::bounties(bounty_id)?.status {
+			BountyStatus::Active { curator, .. } => Some(curator),
+			_ => None,
+		}
+	}
+
+	/// Ensure that `who` is the curator of the active bounty `bounty_id`, returning the bounty.
+	fn ensure_bounty_curator(
+		bounty_id: BountyIndex,
+		who: &T::AccountId,
+	) -> Result<Bounty<T::AccountId, BalanceOf<T>, T::BlockNumber>, DispatchError> {
+		let bounty = Self::bounties(bounty_id).ok_or(Error::<T>::InvalidIndex)?;
+		match &bounty.status {
+			BountyStatus::Active { curator, .. } => {
+				ensure!(curator == who, Error::<T>::RequireCurator);
+			},
+			_ => return Err(Error::<T>::UnexpectedStatus.into()),
+		}
+		Ok(bounty)
+	}
+
+	fn create_bounty(
+		proposer: T::AccountId,
+		description: Vec<u8>,
+		value: BalanceOf<T>,
+	) -> DispatchResult {
+		ensure!(description.len() <= T::MaximumReasonLength::get() as usize, Error::<T>::ReasonTooBig);
+		ensure!(value >= T::BountyValueMinimum::get(), Error::<T>::InvalidValue);
+
+		let index = Self::bounty_count();
+
+		// reserve deposit for new bounty
+		let bond = T::BountyDepositBase::get()
+			+ T::DataDepositPerByte::get() * (description.len() as u32).into();
+		T::Currency::reserve(&proposer, bond)
+			.map_err(|_| Error::<T>::InsufficientProposersBalance)?;
+
+		BountyCount::put(index + 1);
+
+		let bounty = Bounty {
+			proposer, value, fee: 0.into(), curator_deposit: 0.into(), bond, status: BountyStatus::Proposed,
+		};
+
+		Bounties::<T>::insert(index, &bounty);
+		BountyDescriptions::insert(index, description);
+
+		Self::deposit_event(RawEvent::BountyProposed(index));
+
+		Ok(())
+	}
+
+	/// Remove a child bounty which is not pending payout, returning its funds to the parent
+	/// bounty and the deposit of its curator. The count of open child bounties of the parent is
+	/// left to the caller.
+	fn remove_child_bounty(
+		parent_bounty_id: BountyIndex,
+		child_bounty_id: BountyIndex,
+		child_bounty: ChildBounty<T::AccountId, BalanceOf<T>, T::BlockNumber>,
+	) {
+		if let ChildBountyStatus::Active { curator } = &child_bounty.status {
+			let _ = T::Currency::unreserve(curator, child_bounty.curator_deposit);
+		}
+		ChildrenCuratorFees::<T>::mutate(parent_bounty_id, |fees| *fees = fees.saturating_sub(child_bounty.fee));
+
+		let child_bounty_account = Self::child_bounty_account_id(child_bounty_id);
+		let balance = T::Currency::free_balance(&child_bounty_account);
+		let _ = T::Currency::transfer(
+			&child_bounty_account,
+			&Self::bounty_account_id(parent_bounty_id),
+			balance,
+			AllowDeath,
+		); // should not fail
+
+		ChildBounties::<T>::remove(parent_bounty_id, child_bounty_id);
+		ChildBountyDescriptions::remove(child_bounty_id);
+
+		Self::deposit_event(Event::<T>::ChildBountyCanceled(parent_bounty_id, child_bounty_id));
+	}
+}
+
+impl<T: Trait> pallet_treasury::SpendFunds<T> for Module<T> {
+	fn spend_funds(
+		budget_remaining: &mut BalanceOf<T>,
+		imbalance: &mut PositiveImbalanceOf<T>,
+		total_weight: &mut Weight,
+		missed_any: &mut bool,
+	) {
+		let bounties_len = BountyApprovals::mutate(|v| {
+			let bounties_approval_len = v.len() as u32;
+			v.retain(|&index| {
+				Bounties::<T>::mutate(index, |bounty| {
+					// Should always be true, but shouldn't panic if false or we're screwed.
+					if let Some(bounty) = bounty {
+						if bounty.value <= *budget_remaining {
+							*budget_remaining -= bounty.value;
+
+							bounty.status = BountyStatus::Funded;
+
+							// return their deposit.
+							let _ = T::Currency::unreserve(&bounty.proposer, bounty.bond);
+
+							// fund the bounty account
+							imbalance.subsume(T::Currency::deposit_creating(&Self::bounty_account_id(index), bounty.value));
+
+							Self::deposit_event(RawEvent::BountyBecameActive(index));
+							false
+						} else {
+							*missed_any = true;
+							true
+						}
+					} else {
+						false
+					}
+				})
+			});
+			bounties_approval_len
+		});
+
+		*total_weight += <T as Trait>::WeightInfo::spend_funds(bounties_len);
+	}
+}
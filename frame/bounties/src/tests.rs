@@ -0,0 +1,935 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2020 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Bounties pallet tests.
+
+#![cfg(test)]
+
+use super::*;
+use std::cell::RefCell;
+use frame_support::{
+	assert_noop, assert_ok, impl_outer_origin, impl_outer_event, parameter_types, weights::Weight,
+	traits::{Contains, ContainsLengthBound, OnInitialize, fungibles::SingleAsset}
+};
+use sp_core::H256;
+use sp_runtime::{
+	Perbill, Percent, ModuleId,
+	testing::Header,
+	traits::{BlakeTwo256, IdentityLookup, BadOrigin},
+};
+
+impl_outer_origin! {
+	pub enum Origin for Test where system = frame_system {}
+}
+
+mod bounties {
+	// Re-export needed for `impl_outer_event!`.
+	pub use super::super::*;
+}
+
+impl_outer_event! {
+	pub enum Event for Test {
+		system<T>,
+		pallet_balances<T>,
+		pallet_treasury<T>,
+		bounties<T>,
+	}
+}
+
+#[derive(Clone, Eq, PartialEq)]
+pub struct Test;
+parameter_types! {
+	pub const BlockHashCount: u64 = 250;
+	pub const MaximumBlockWeight: Weight = 1024;
+	pub const MaximumBlockLength: u32 = 2 * 1024;
+	pub const AvailableBlockRatio: Perbill = Perbill::one();
+}
+impl frame_system::Trait for Test {
+	type BaseCallFilter = ();
+	type Origin = Origin;
+	type Index = u64;
+	type BlockNumber = u64;
+	type Call = ();
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type AccountId = u128; // u64 is not enough to hold bytes used to generate bounty account
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type Header = Header;
+	type Event = Event;
+	type BlockHashCount = BlockHashCount;
+	type MaximumBlockWeight = MaximumBlockWeight;
+	type DbWeight = ();
+	type BlockExecutionWeight = ();
+	type ExtrinsicBaseWeight = ();
+	type MaximumExtrinsicWeight = MaximumBlockWeight;
+	type AvailableBlockRatio = AvailableBlockRatio;
+	type MaximumBlockLength = MaximumBlockLength;
+	type Version = ();
+	type PalletInfo = ();
+	type AccountData = pallet_balances::AccountData<u64>;
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+}
+parameter_types! {
+	pub const ExistentialDeposit: u64 = 1;
+}
+impl pallet_balances::Trait for Test {
+	type MaxLocks = ();
+	type MaxReserves = ();
+	type ReserveIdentifier = [u8; 8];
+	type Balance = u64;
+	type Event = Event;
+	type DustRemoval = ();
+	type ExistentialDeposit = ExistentialDeposit;
+	type AccountStore = System;
+	type WeightInfo = ();
+}
+thread_local! {
+	static TEN_TO_FOURTEEN: RefCell<Vec<u128>> = RefCell::new(vec![10,11,12,13,14]);
+}
+pub struct TenToFourteen;
+impl Contains<u128> for TenToFourteen {
+	fn sorted_members() -> Vec<u128> {
+		TEN_TO_FOURTEEN.with(|v| {
+			v.borrow().clone()
+		})
+	}
+	#[cfg(feature = "runtime-benchmarks")]
+	fn add(new: &u128) {
+		TEN_TO_FOURTEEN.with(|v| {
+			let mut members = v.borrow_mut();
+			members.push(*new);
+			members.sort();
+		})
+	}
+}
+impl ContainsLengthBound for TenToFourteen {
+	fn max_len() -> usize {
+		TEN_TO_FOURTEEN.with(|v| v.borrow().len())
+	}
+	fn min_len() -> usize { 0 }
+}
+parameter_types! {
+	pub const ProposalBond: Permill = Permill::from_percent(5);
+	pub const ProposalBondMinimum: u64 = 1;
+	pub const SpendPeriod: u64 = 2;
+	pub const Burn: Permill = Permill::from_percent(50);
+	pub const TipCountdown: u64 = 1;
+	pub const TipFindersFee: Percent = Percent::from_percent(20);
+	pub const TipReportDepositBase: u64 = 1;
+	pub const DataDepositPerByte: u64 = 1;
+	pub const TreasuryModuleId: ModuleId = ModuleId(*b"py/trsry");
+	pub const MaximumReasonLength: u32 = 16384;
+	pub const NativeAssetId: u32 = 0;
+	pub const AssetSpendMaximum: u64 = 1000;
+	pub const MaxInstalments: u32 = 4;
+}
+impl pallet_treasury::Trait for Test {
+	type ModuleId = TreasuryModuleId;
+	type Currency = pallet_balances::Module<Test>;
+	type ApproveOrigin = frame_system::EnsureRoot<u128>;
+	type RejectOrigin = frame_system::EnsureRoot<u128>;
+	type Tippers = TenToFourteen;
+	type TipCountdown = TipCountdown;
+	type TipFindersFee = TipFindersFee;
+	type TipReportDepositBase = TipReportDepositBase;
+	type DataDepositPerByte = DataDepositPerByte;
+	type Event = Event;
+	type OnSlash = ();
+	type ProposalBond = ProposalBond;
+	type ProposalBondMinimum = ProposalBondMinimum;
+	type SpendPeriod = SpendPeriod;
+	type Burn = Burn;
+	type MaximumReasonLength = MaximumReasonLength;
+	type BurnDestination = ();  // Just gets burned.
+	type SpendFunds = Bounties;
+	type Assets = SingleAsset<Balances, NativeAssetId, u32>;
+	type BalanceConverter = pallet_treasury::UnityAssetBalanceConversion;
+	type AssetSpendMaximum = AssetSpendMaximum;
+	type MaxInstalments = MaxInstalments;
+	type WeightInfo = ();
+}
+parameter_types! {
+	pub const BountyDepositBase: u64 = 80;
+	pub const BountyDepositPayoutDelay: u64 = 3;
+	pub const BountyUpdatePeriod: u64 = 20;
+	pub const BountyCuratorDeposit: Permill = Permill::from_percent(50);
+	pub const BountyValueMinimum: u64 = 1;
+	pub const MaxActiveChildBountyCount: u32 = 2;
+	pub const ChildBountyValueMinimum: u64 = 1;
+}
+impl Trait for Test {
+	type BountyDepositBase = BountyDepositBase;
+	type BountyDepositPayoutDelay = BountyDepositPayoutDelay;
+	type BountyUpdatePeriod = BountyUpdatePeriod;
+	type BountyCuratorDeposit = BountyCuratorDeposit;
+	type BountyValueMinimum = BountyValueMinimum;
+	type MaxActiveChildBountyCount = MaxActiveChildBountyCount;
+	type ChildBountyValueMinimum = ChildBountyValueMinimum;
+	type Event = Event;
+	type WeightInfo = ();
+}
+type System = frame_system::Module<Test>;
+type Balances = pallet_balances::Module<Test>;
+type Treasury = pallet_treasury::Module<Test>;
+type Bounties = Module<Test>;
+
+pub fn new_test_ext() -> sp_io::TestExternalities {
+	let mut t = frame_system::GenesisConfig::default().build_storage::<Test>().unwrap();
+	pallet_balances::GenesisConfig::<Test>{
+		// Total issuance will be 200 with treasury account initialized at ED.
+		balances: vec![(0, 100), (1, 98), (2, 1)],
+	}.assimilate_storage(&mut t).unwrap();
+	pallet_treasury::GenesisConfig::default().assimilate_storage::<Test, _>(&mut t).unwrap();
+	t.into()
+}
+
+fn last_event() -> RawEvent<u64, u128> {
+	System::events().into_iter().map(|r| r.event)
+		.filter_map(|e| {
+			if let Event::bounties(inner) = e { Some(inner) } else { None }
+		})
+		.last()
+		.unwrap()
+}
+
+#[test]
+fn genesis_config_works() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(Bounties::bounty_count(), 0);
+		assert_eq!(Bounties::child_bounty_count(), 0);
+	});
+}
+
+#[test]
+fn propose_bounty_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		Balances::make_free_balance_be(&Treasury::account_id(), 101);
+		assert_eq!(Treasury::pot(), 100);
+
+		assert_ok!(Bounties::propose_bounty(Origin::signed(0), 10, b"1234567890".to_vec()));
+
+		assert_eq!(last_event(), RawEvent::BountyProposed(0));
+
+		let deposit: u64 = 85 + 5;
+		assert_eq!(Balances::reserved_balance(0), deposit);
+		assert_eq!(Balances::free_balance(0), 100 - deposit);
+
+		assert_eq!(Bounties::bounties(0).unwrap(), Bounty {
+			proposer: 0,
+			fee: 0,
+			curator_deposit: 0,
+			value: 10,
+			bond: deposit,
+			status: BountyStatus::Proposed,
+		});
+
+		assert_eq!(Bounties::bounty_descriptions(0).unwrap(), b"1234567890".to_vec());
+
+		assert_eq!(Bounties::bounty_count(), 1);
+	});
+}
+
+#[test]
+fn propose_bounty_validation_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		Balances::make_free_balance_be(&Treasury::account_id(), 101);
+		assert_eq!(Treasury::pot(), 100);
+
+		assert_noop!(
+			Bounties::propose_bounty(Origin::signed(1), 0, [0; 17_000].to_vec()),
+			Error::<Test>::ReasonTooBig
+		);
+
+		assert_noop!(
+			Bounties::propose_bounty(Origin::signed(1), 10, b"12345678901234567890".to_vec()),
+			Error::<Test>::InsufficientProposersBalance
+		);
+
+		assert_noop!(
+			Bounties::propose_bounty(Origin::signed(1), 0, b"12345678901234567890".to_vec()),
+			Error::<Test>::InvalidValue
+		);
+	});
+}
+
+#[test]
+fn close_bounty_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		Balances::make_free_balance_be(&Treasury::account_id(), 101);
+		assert_noop!(Bounties::close_bounty(Origin::root(), 0), Error::<Test>::InvalidIndex);
+
+		assert_ok!(Bounties::propose_bounty(Origin::signed(0), 10, b"12345".to_vec()));
+
+		assert_ok!(Bounties::close_bounty(Origin::root(), 0));
+
+		let deposit: u64 = 80 + 5;
+
+		assert_eq!(last_event(), RawEvent::BountyRejected(0, deposit));
+
+		assert_eq!(Balances::reserved_balance(0), 0);
+		assert_eq!(Balances::free_balance(0), 100 - deposit);
+
+		assert_eq!(Bounties::bounties(0), None);
+		assert!(!crate::Bounties::<Test>::contains_key(0));
+		assert_eq!(Bounties::bounty_descriptions(0), None);
+	});
+}
+
+#[test]
+fn approve_bounty_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		Balances::make_free_balance_be(&Treasury::account_id(), 101);
+		assert_noop!(Bounties::approve_bounty(Origin::root(), 0), Error::<Test>::InvalidIndex);
+
+		assert_ok!(Bounties::propose_bounty(Origin::signed(0), 50, b"12345".to_vec()));
+
+		assert_ok!(Bounties::approve_bounty(Origin::root(), 0));
+
+		let deposit: u64 = 80 + 5;
+
+		assert_eq!(Bounties::bounties(0).unwrap(), Bounty {
+			proposer: 0,
+			fee: 0,
+			value: 50,
+			curator_deposit: 0,
+			bond: deposit,
+			status: BountyStatus::Approved,
+		});
+		assert_eq!(Bounties::bounty_approvals(), vec![0]);
+
+		assert_noop!(Bounties::close_bounty(Origin::root(), 0), Error::<Test>::UnexpectedStatus);
+
+		// deposit not returned yet
+		assert_eq!(Balances::reserved_balance(0), deposit);
+		assert_eq!(Balances::free_balance(0), 100 - deposit);
+
+		<Treasury as OnInitialize<u64>>::on_initialize(2);
+
+		// return deposit
+		assert_eq!(Balances::reserved_balance(0), 0);
+		assert_eq!(Balances::free_balance(0), 100);
+
+		assert_eq!(Bounties::bounties(0).unwrap(), Bounty {
+			proposer: 0,
+			fee: 0,
+			curator_deposit: 0,
+			value: 50,
+			bond: deposit,
+			status: BountyStatus::Funded,
+		});
+		assert_eq!(Treasury::pot(), 100 - 50 - 25); // burn 25
+		assert_eq!(Balances::free_balance(Bounties::bounty_account_id(0)), 50);
+	});
+}
+
+#[test]
+fn assign_curator_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		Balances::make_free_balance_be(&Treasury::account_id(), 101);
+
+		assert_noop!(Bounties::propose_curator(Origin::root(), 0, 4, 4), Error::<Test>::InvalidIndex);
+
+		assert_ok!(Bounties::propose_bounty(Origin::signed(0), 50, b"12345".to_vec()));
+
+		assert_ok!(Bounties::approve_bounty(Origin::root(), 0));
+
+		System::set_block_number(2);
+		<Treasury as OnInitialize<u64>>::on_initialize(2);
+
+		assert_noop!(Bounties::propose_curator(Origin::root(), 0, 4, 50), Error::<Test>::InvalidFee);
+
+		assert_ok!(Bounties::propose_curator(Origin::root(), 0, 4, 4));
+
+		assert_eq!(Bounties::bounties(0).unwrap(), Bounty {
+			proposer: 0,
+			fee: 4,
+			curator_deposit: 0,
+			value: 50,
+			bond: 85,
+			status: BountyStatus::CuratorProposed {
+				curator: 4,
+			},
+		});
+
+		assert_noop!(Bounties::accept_curator(Origin::signed(1), 0), Error::<Test>::RequireCurator);
+		assert_noop!(Bounties::accept_curator(Origin::signed(4), 0), pallet_balances::Error::<Test, _>::InsufficientBalance);
+
+		Balances::make_free_balance_be(&4, 10);
+
+		assert_ok!(Bounties::accept_curator(Origin::signed(4), 0));
+
+		assert_eq!(Bounties::bounties(0).unwrap(), Bounty {
+			proposer: 0,
+			fee: 4,
+			curator_deposit: 2,
+			value: 50,
+			bond: 85,
+			status: BountyStatus::Active {
+				curator: 4,
+				update_due: 22,
+			},
+		});
+
+		assert_eq!(Balances::free_balance(&4), 8);
+		assert_eq!(Balances::reserved_balance(&4), 2);
+	});
+}
+
+#[test]
+fn unassign_curator_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		Balances::make_free_balance_be(&Treasury::account_id(), 101);
+		assert_ok!(Bounties::propose_bounty(Origin::signed(0), 50, b"12345".to_vec()));
+
+		assert_ok!(Bounties::approve_bounty(Origin::root(), 0));
+
+		System::set_block_number(2);
+		<Treasury as OnInitialize<u64>>::on_initialize(2);
+
+		assert_ok!(Bounties::propose_curator(Origin::root(), 0, 4, 4));
+
+		assert_noop!(Bounties::unassign_curator(Origin::signed(1), 0), BadOrigin);
+
+		assert_ok!(Bounties::unassign_curator(Origin::signed(4), 0));
+
+		assert_eq!(Bounties::bounties(0).unwrap(), Bounty {
+			proposer: 0,
+			fee: 4,
+			curator_deposit: 0,
+			value: 50,
+			bond: 85,
+			status: BountyStatus::Funded,
+		});
+
+		assert_ok!(Bounties::propose_curator(Origin::root(), 0, 4, 4));
+
+		Balances::make_free_balance_be(&4, 10);
+
+		assert_ok!(Bounties::accept_curator(Origin::signed(4), 0));
+
+		assert_ok!(Bounties::unassign_curator(Origin::root(), 0));
+
+		assert_eq!(Bounties::bounties(0).unwrap(), Bounty {
+			proposer: 0,
+			fee: 4,
+			curator_deposit: 0,
+			value: 50,
+			bond: 85,
+			status: BountyStatus::Funded,
+		});
+
+		assert_eq!(Balances::free_balance(&4), 8);
+		assert_eq!(Balances::reserved_balance(&4), 0); // slashed 2
+	});
+}
+
+#[test]
+fn award_and_claim_bounty_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		Balances::make_free_balance_be(&Treasury::account_id(), 101);
+		Balances::make_free_balance_be(&4, 10);
+		assert_ok!(Bounties::propose_bounty(Origin::signed(0), 50, b"12345".to_vec()));
+
+		assert_ok!(Bounties::approve_bounty(Origin::root(), 0));
+
+		System::set_block_number(2);
+		<Treasury as OnInitialize<u64>>::on_initialize(2);
+
+		assert_ok!(Bounties::propose_curator(Origin::root(), 0, 4, 4));
+		assert_ok!(Bounties::accept_curator(Origin::signed(4), 0));
+
+		assert_eq!(Balances::free_balance(4), 8); // inital 10 - 2 deposit
+
+		assert_noop!(Bounties::award_bounty(Origin::signed(1), 0, 3), Error::<Test>::RequireCurator);
+
+		assert_ok!(Bounties::award_bounty(Origin::signed(4), 0, 3));
+
+		assert_eq!(Bounties::bounties(0).unwrap(), Bounty {
+			proposer: 0,
+			fee: 4,
+			curator_deposit: 2,
+			value: 50,
+			bond: 85,
+			status: BountyStatus::PendingPayout {
+				curator: 4,
+				beneficiary: 3,
+				unlock_at: 5
+			},
+		});
+
+		assert_noop!(Bounties::claim_bounty(Origin::signed(1), 0), Error::<Test>::Premature);
+
+		System::set_block_number(5);
+		<Treasury as OnInitialize<u64>>::on_initialize(5);
+
+		assert_ok!(Balances::transfer(Origin::signed(0), Bounties::bounty_account_id(0), 10));
+
+		assert_ok!(Bounties::claim_bounty(Origin::signed(1), 0));
+
+		assert_eq!(last_event(), RawEvent::BountyClaimed(0, 56, 3));
+
+		assert_eq!(Balances::free_balance(4), 14); // initial 10 + fee 4
+		assert_eq!(Balances::free_balance(3), 56);
+		assert_eq!(Balances::free_balance(Bounties::bounty_account_id(0)), 0);
+
+		assert_eq!(Bounties::bounties(0), None);
+		assert_eq!(Bounties::bounty_descriptions(0), None);
+	});
+}
+
+#[test]
+fn claim_handles_high_fee() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		Balances::make_free_balance_be(&Treasury::account_id(), 101);
+		Balances::make_free_balance_be(&4, 30);
+		assert_ok!(Bounties::propose_bounty(Origin::signed(0), 50, b"12345".to_vec()));
+
+		assert_ok!(Bounties::approve_bounty(Origin::root(), 0));
+
+		System::set_block_number(2);
+		<Treasury as OnInitialize<u64>>::on_initialize(2);
+
+		assert_ok!(Bounties::propose_curator(Origin::root(), 0, 4, 49));
+		assert_ok!(Bounties::accept_curator(Origin::signed(4), 0));
+
+		assert_ok!(Bounties::award_bounty(Origin::signed(4), 0, 3));
+
+		System::set_block_number(5);
+		<Treasury as OnInitialize<u64>>::on_initialize(5);
+
+		// make fee > balance
+		let _ = Balances::slash(&Bounties::bounty_account_id(0), 10);
+
+		assert_ok!(Bounties::claim_bounty(Origin::signed(1), 0));
+
+		assert_eq!(last_event(), RawEvent::BountyClaimed(0, 0, 3));
+
+		assert_eq!(Balances::free_balance(4), 70); // 30 + 50 - 10
+		assert_eq!(Balances::free_balance(3), 0);
+		assert_eq!(Balances::free_balance(Bounties::bounty_account_id(0)), 0);
+
+		assert_eq!(Bounties::bounties(0), None);
+		assert_eq!(Bounties::bounty_descriptions(0), None);
+	});
+}
+
+#[test]
+fn cancel_and_refund() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		Balances::make_free_balance_be(&Treasury::account_id(), 101);
+		assert_ok!(Bounties::propose_bounty(Origin::signed(0), 50, b"12345".to_vec()));
+
+		assert_ok!(Bounties::approve_bounty(Origin::root(), 0));
+
+		System::set_block_number(2);
+		<Treasury as OnInitialize<u64>>::on_initialize(2);
+
+		assert_ok!(Balances::transfer(Origin::signed(0), Bounties::bounty_account_id(0), 10));
+
+		assert_eq!(Bounties::bounties(0).unwrap(), Bounty {
+			proposer: 0,
+			fee: 0,
+			curator_deposit: 0,
+			value: 50,
+			bond: 85,
+			status: BountyStatus::Funded,
+		});
+
+		assert_eq!(Balances::free_balance(Bounties::bounty_account_id(0)), 60);
+
+		assert_noop!(Bounties::close_bounty(Origin::signed(0), 0), BadOrigin);
+
+		assert_ok!(Bounties::close_bounty(Origin::root(), 0));
+
+		assert_eq!(Treasury::pot(), 85); // - 25 + 10
+	});
+}
+
+#[test]
+fn award_and_cancel() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		Balances::make_free_balance_be(&Treasury::account_id(), 101);
+		assert_ok!(Bounties::propose_bounty(Origin::signed(0), 50, b"12345".to_vec()));
+
+		assert_ok!(Bounties::approve_bounty(Origin::root(), 0));
+
+		System::set_block_number(2);
+		<Treasury as OnInitialize<u64>>::on_initialize(2);
+
+		assert_ok!(Bounties::propose_curator(Origin::root(), 0, 0, 10));
+		assert_ok!(Bounties::accept_curator(Origin::signed(0), 0));
+
+		assert_eq!(Balances::free_balance(0), 95);
+		assert_eq!(Balances::reserved_balance(0), 5);
+
+		assert_ok!(Bounties::award_bounty(Origin::signed(0), 0, 3));
+
+		// Cannot close bounty directly when payout is happening...
+		assert_noop!(Bounties::close_bounty(Origin::root(), 0), Error::<Test>::PendingPayout);
+
+		// Instead unassign the curator to slash them and then close.
+		assert_ok!(Bounties::unassign_curator(Origin::root(), 0));
+		assert_ok!(Bounties::close_bounty(Origin::root(), 0));
+
+		assert_eq!(last_event(), RawEvent::BountyCanceled(0));
+
+		assert_eq!(Balances::free_balance(Bounties::bounty_account_id(0)), 0);
+		// Slashed.
+		assert_eq!(Balances::free_balance(0), 95);
+		assert_eq!(Balances::reserved_balance(0), 0);
+
+		assert_eq!(Bounties::bounties(0), None);
+		assert_eq!(Bounties::bounty_descriptions(0), None);
+	});
+}
+
+#[test]
+fn expire_and_unassign() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		Balances::make_free_balance_be(&Treasury::account_id(), 101);
+		assert_ok!(Bounties::propose_bounty(Origin::signed(0), 50, b"12345".to_vec()));
+
+		assert_ok!(Bounties::approve_bounty(Origin::root(), 0));
+
+		System::set_block_number(2);
+		<Treasury as OnInitialize<u64>>::on_initialize(2);
+
+		assert_ok!(Bounties::propose_curator(Origin::root(), 0, 1, 10));
+		assert_ok!(Bounties::accept_curator(Origin::signed(1), 0));
+
+		assert_eq!(Balances::free_balance(1), 93);
+		assert_eq!(Balances::reserved_balance(1), 5);
+
+		System::set_block_number(22);
+		<Treasury as OnInitialize<u64>>::on_initialize(22);
+
+		assert_noop!(Bounties::unassign_curator(Origin::signed(0), 0), Error::<Test>::Premature);
+
+		System::set_block_number(23);
+		<Treasury as OnInitialize<u64>>::on_initialize(23);
+
+		assert_ok!(Bounties::unassign_curator(Origin::signed(0), 0));
+
+		assert_eq!(Bounties::bounties(0).unwrap(), Bounty {
+			proposer: 0,
+			fee: 10,
+			curator_deposit: 0,
+			value: 50,
+			bond: 85,
+			status: BountyStatus::Funded,
+		});
+
+		assert_eq!(Balances::free_balance(1), 93);
+		assert_eq!(Balances::reserved_balance(1), 0); // slashed
+
+	});
+}
+
+#[test]
+fn extend_expiry() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		Balances::make_free_balance_be(&Treasury::account_id(), 101);
+		Balances::make_free_balance_be(&4, 10);
+		assert_ok!(Bounties::propose_bounty(Origin::signed(0), 50, b"12345".to_vec()));
+
+		assert_ok!(Bounties::approve_bounty(Origin::root(), 0));
+
+		assert_noop!(Bounties::extend_bounty_expiry(Origin::signed(1), 0, Vec::new()), Error::<Test>::UnexpectedStatus);
+
+		System::set_block_number(2);
+		<Treasury as OnInitialize<u64>>::on_initialize(2);
+
+		assert_ok!(Bounties::propose_curator(Origin::root(), 0, 4, 10));
+		assert_ok!(Bounties::accept_curator(Origin::signed(4), 0));
+
+		assert_eq!(Balances::free_balance(4), 5);
+		assert_eq!(Balances::reserved_balance(4), 5);
+
+		System::set_block_number(10);
+		<Treasury as OnInitialize<u64>>::on_initialize(10);
+
+		assert_noop!(Bounties::extend_bounty_expiry(Origin::signed(0), 0, Vec::new()), Error::<Test>::RequireCurator);
+		assert_ok!(Bounties::extend_bounty_expiry(Origin::signed(4), 0, Vec::new()));
+
+		assert_eq!(Bounties::bounties(0).unwrap(), Bounty {
+			proposer: 0,
+			fee: 10,
+			curator_deposit: 5,
+			value: 50,
+			bond: 85,
+			status: BountyStatus::Active { curator: 4, update_due: 30 },
+		});
+
+		assert_ok!(Bounties::extend_bounty_expiry(Origin::signed(4), 0, Vec::new()));
+
+		assert_eq!(Bounties::bounties(0).unwrap(), Bounty {
+			proposer: 0,
+			fee: 10,
+			curator_deposit: 5,
+			value: 50,
+			bond: 85,
+			status: BountyStatus::Active { curator: 4, update_due: 30 }, // still the same
+		});
+
+		System::set_block_number(25);
+		<Treasury as OnInitialize<u64>>::on_initialize(25);
+
+		assert_noop!(Bounties::unassign_curator(Origin::signed(0), 0), Error::<Test>::Premature);
+		assert_ok!(Bounties::unassign_curator(Origin::signed(4), 0));
+
+		assert_eq!(Balances::free_balance(4), 10); // not slashed
+		assert_eq!(Balances::reserved_balance(4), 0);
+	});
+}
+
+/// Propose, fund and activate bounty 0 of value 50 with curator 4 and a fee of 10.
+fn setup_active_bounty() {
+	System::set_block_number(1);
+	Balances::make_free_balance_be(&Treasury::account_id(), 101);
+	Balances::make_free_balance_be(&4, 10);
+	assert_ok!(Bounties::propose_bounty(Origin::signed(0), 50, b"12345".to_vec()));
+	assert_ok!(Bounties::approve_bounty(Origin::root(), 0));
+
+	System::set_block_number(2);
+	<Treasury as OnInitialize<u64>>::on_initialize(2);
+
+	assert_ok!(Bounties::propose_curator(Origin::root(), 0, 4, 10));
+	assert_ok!(Bounties::accept_curator(Origin::signed(4), 0));
+}
+
+#[test]
+fn add_child_bounty_works() {
+	new_test_ext().execute_with(|| {
+		setup_active_bounty();
+
+		assert_noop!(
+			Bounties::add_child_bounty(Origin::signed(1), 0, 10, b"child".to_vec()),
+			Error::<Test>::RequireCurator
+		);
+		assert_noop!(
+			Bounties::add_child_bounty(Origin::signed(4), 0, 0, b"child".to_vec()),
+			Error::<Test>::InvalidValue
+		);
+		// The bounty account must be kept alive.
+		assert_noop!(
+			Bounties::add_child_bounty(Origin::signed(4), 0, 50, b"child".to_vec()),
+			Error::<Test>::InsufficientBountyBalance
+		);
+
+		assert_ok!(Bounties::add_child_bounty(Origin::signed(4), 0, 10, b"child".to_vec()));
+
+		assert_eq!(last_event(), RawEvent::ChildBountyAdded(0, 0));
+		assert_eq!(Bounties::child_bounties(0, 0).unwrap(), ChildBounty {
+			parent_bounty: 0,
+			value: 10,
+			fee: 0,
+			curator_deposit: 0,
+			status: ChildBountyStatus::Added,
+		});
+		assert_eq!(Bounties::child_bounty_descriptions(0).unwrap(), b"child".to_vec());
+		assert_eq!(Bounties::child_bounty_count(), 1);
+		assert_eq!(Bounties::parent_child_bounties(0), 1);
+		assert_eq!(Balances::free_balance(Bounties::bounty_account_id(0)), 40);
+		assert_eq!(Balances::free_balance(Bounties::child_bounty_account_id(0)), 10);
+
+		assert_ok!(Bounties::add_child_bounty(Origin::signed(4), 0, 10, b"child".to_vec()));
+		assert_noop!(
+			Bounties::add_child_bounty(Origin::signed(4), 0, 10, b"child".to_vec()),
+			Error::<Test>::TooManyChildBounties
+		);
+
+		// The bounty can't be awarded while it has open child bounties.
+		assert_noop!(Bounties::award_bounty(Origin::signed(4), 0, 3), Error::<Test>::HasActiveChildBounty);
+	});
+}
+
+#[test]
+fn child_bounty_curator_and_claim_works() {
+	new_test_ext().execute_with(|| {
+		setup_active_bounty();
+		Balances::make_free_balance_be(&8, 10);
+
+		assert_ok!(Bounties::add_child_bounty(Origin::signed(4), 0, 10, b"child".to_vec()));
+		assert_ok!(Bounties::add_child_bounty(Origin::signed(4), 0, 10, b"child".to_vec()));
+
+		assert_noop!(
+			Bounties::propose_child_curator(Origin::signed(8), 0, 0, 8, 6),
+			Error::<Test>::RequireCurator
+		);
+		assert_noop!(
+			Bounties::propose_child_curator(Origin::signed(4), 0, 0, 8, 10),
+			Error::<Test>::InvalidFee
+		);
+		assert_ok!(Bounties::propose_child_curator(Origin::signed(4), 0, 0, 8, 6));
+		// Child curator fees may not exceed the fee of the parent curator.
+		assert_noop!(
+			Bounties::propose_child_curator(Origin::signed(4), 0, 1, 8, 5),
+			Error::<Test>::InvalidFee
+		);
+		assert_eq!(Bounties::children_curator_fees(0), 6);
+
+		assert_noop!(Bounties::accept_child_curator(Origin::signed(1), 0, 0), Error::<Test>::RequireCurator);
+		assert_ok!(Bounties::accept_child_curator(Origin::signed(8), 0, 0));
+
+		assert_eq!(Bounties::child_bounties(0, 0).unwrap(), ChildBounty {
+			parent_bounty: 0,
+			value: 10,
+			fee: 6,
+			curator_deposit: 3,
+			status: ChildBountyStatus::Active { curator: 8 },
+		});
+		assert_eq!(Balances::reserved_balance(8), 3);
+
+		assert_noop!(Bounties::award_child_bounty(Origin::signed(4), 0, 0, 9), Error::<Test>::RequireCurator);
+		assert_ok!(Bounties::award_child_bounty(Origin::signed(8), 0, 0, 9));
+		assert_eq!(last_event(), RawEvent::ChildBountyAwarded(0, 0, 9));
+
+		// Awarded child bounties can't be closed.
+		assert_noop!(Bounties::close_child_bounty(Origin::root(), 0, 0), Error::<Test>::PendingPayout);
+		assert_noop!(Bounties::claim_child_bounty(Origin::signed(1), 0, 0), Error::<Test>::Premature);
+
+		System::set_block_number(5);
+
+		assert_ok!(Bounties::claim_child_bounty(Origin::signed(1), 0, 0));
+
+		assert_eq!(last_event(), RawEvent::ChildBountyClaimed(0, 0, 4, 9));
+		assert_eq!(Balances::free_balance(8), 16); // initial 10 + fee 6
+		assert_eq!(Balances::reserved_balance(8), 0);
+		assert_eq!(Balances::free_balance(9), 4);
+		assert_eq!(Balances::free_balance(Bounties::child_bounty_account_id(0)), 0);
+		assert_eq!(Bounties::child_bounties(0, 0), None);
+		assert_eq!(Bounties::child_bounty_descriptions(0), None);
+		assert_eq!(Bounties::parent_child_bounties(0), 1);
+
+		// Close the remaining child bounty, returning its funds to the parent.
+		assert_noop!(Bounties::close_child_bounty(Origin::signed(8), 0, 1), BadOrigin);
+		assert_ok!(Bounties::close_child_bounty(Origin::signed(4), 0, 1));
+		assert_eq!(last_event(), RawEvent::ChildBountyCanceled(0, 1));
+		assert_eq!(Bounties::parent_child_bounties(0), 0);
+		assert_eq!(Balances::free_balance(Bounties::bounty_account_id(0)), 40);
+
+		// The parent curator is paid their fee minus the fees of the child curators.
+		assert_ok!(Bounties::award_bounty(Origin::signed(4), 0, 3));
+		System::set_block_number(8);
+		assert_ok!(Bounties::claim_bounty(Origin::signed(1), 0));
+
+		assert_eq!(last_event(), RawEvent::BountyClaimed(0, 36, 3));
+		assert_eq!(Balances::free_balance(4), 14); // initial 10 + fee 10 - child fee 6
+		assert_eq!(Balances::free_balance(3), 36);
+		assert_eq!(Bounties::children_curator_fees(0), 0);
+	});
+}
+
+#[test]
+fn unassign_child_curator_works() {
+	new_test_ext().execute_with(|| {
+		setup_active_bounty();
+		Balances::make_free_balance_be(&8, 10);
+
+		assert_ok!(Bounties::add_child_bounty(Origin::signed(4), 0, 10, b"child".to_vec()));
+		assert_noop!(Bounties::unassign_child_curator(Origin::root(), 0, 0), Error::<Test>::UnexpectedStatus);
+
+		assert_ok!(Bounties::propose_child_curator(Origin::signed(4), 0, 0, 8, 6));
+		assert_noop!(Bounties::unassign_child_curator(Origin::signed(1), 0, 0), BadOrigin);
+		assert_ok!(Bounties::unassign_child_curator(Origin::signed(8), 0, 0));
+
+		assert_eq!(Bounties::child_bounties(0, 0).unwrap(), ChildBounty {
+			parent_bounty: 0,
+			value: 10,
+			fee: 0,
+			curator_deposit: 0,
+			status: ChildBountyStatus::Added,
+		});
+		assert_eq!(Bounties::children_curator_fees(0), 0);
+
+		// The child curator may give up their role and recover their deposit.
+		assert_ok!(Bounties::propose_child_curator(Origin::signed(4), 0, 0, 8, 6));
+		assert_ok!(Bounties::accept_child_curator(Origin::signed(8), 0, 0));
+		assert_noop!(Bounties::unassign_child_curator(Origin::signed(1), 0, 0), BadOrigin);
+		assert_ok!(Bounties::unassign_child_curator(Origin::signed(8), 0, 0));
+		assert_eq!(Balances::free_balance(8), 10);
+		assert_eq!(Balances::reserved_balance(8), 0);
+
+		// The parent curator unassigning the child curator slashes them.
+		assert_ok!(Bounties::propose_child_curator(Origin::signed(4), 0, 0, 8, 6));
+		assert_ok!(Bounties::accept_child_curator(Origin::signed(8), 0, 0));
+		assert_ok!(Bounties::unassign_child_curator(Origin::signed(4), 0, 0));
+		assert_eq!(Balances::free_balance(8), 7);
+		assert_eq!(Balances::reserved_balance(8), 0);
+
+		// Once awarded, only the authorities may unassign the child curator.
+		assert_ok!(Bounties::propose_child_curator(Origin::signed(4), 0, 0, 8, 6));
+		assert_ok!(Bounties::accept_child_curator(Origin::signed(8), 0, 0));
+		assert_ok!(Bounties::award_child_bounty(Origin::signed(8), 0, 0, 9));
+		assert_noop!(Bounties::unassign_child_curator(Origin::signed(8), 0, 0), BadOrigin);
+		assert_ok!(Bounties::unassign_child_curator(Origin::root(), 0, 0));
+		assert_eq!(Balances::free_balance(8), 4);
+		assert_eq!(Balances::reserved_balance(8), 0);
+		assert_eq!(Bounties::child_bounties(0, 0).unwrap().status, ChildBountyStatus::Added);
+		assert_eq!(Bounties::children_curator_fees(0), 0);
+	});
+}
+
+#[test]
+fn close_bounty_closes_child_bounties() {
+	new_test_ext().execute_with(|| {
+		setup_active_bounty();
+		Balances::make_free_balance_be(&8, 10);
+
+		assert_ok!(Bounties::add_child_bounty(Origin::signed(4), 0, 10, b"child".to_vec()));
+		assert_ok!(Bounties::add_child_bounty(Origin::signed(4), 0, 10, b"child".to_vec()));
+		assert_ok!(Bounties::propose_child_curator(Origin::signed(4), 0, 0, 8, 6));
+		assert_ok!(Bounties::accept_child_curator(Origin::signed(8), 0, 0));
+		assert_ok!(Bounties::award_child_bounty(Origin::signed(8), 0, 0, 9));
+
+		// Child bounties pending payout must have their curator unassigned first.
+		assert_noop!(Bounties::close_bounty(Origin::root(), 0), Error::<Test>::PendingPayout);
+		assert_ok!(Bounties::unassign_child_curator(Origin::root(), 0, 0));
+
+		assert_ok!(Bounties::close_bounty(Origin::root(), 0));
+
+		assert_eq!(last_event(), RawEvent::BountyCanceled(0));
+		assert_eq!(Bounties::child_bounties(0, 0), None);
+		assert_eq!(Bounties::child_bounties(0, 1), None);
+		assert_eq!(Bounties::child_bounty_descriptions(0), None);
+		assert_eq!(Bounties::child_bounty_descriptions(1), None);
+		assert_eq!(Bounties::parent_child_bounties(0), 0);
+		assert_eq!(Balances::free_balance(Bounties::child_bounty_account_id(0)), 0);
+		assert_eq!(Balances::free_balance(Bounties::child_bounty_account_id(1)), 0);
+		assert_eq!(Balances::free_balance(Bounties::bounty_account_id(0)), 0);
+		// All of the bounty value is back in the pot.
+		assert_eq!(Treasury::pot(), 100 - 25);
+		// The parent curator deposit is refunded.
+		assert_eq!(Balances::free_balance(4), 10);
+	});
+}
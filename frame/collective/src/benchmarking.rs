@@ -289,6 +289,80 @@ benchmarks_instance! {
 		assert_eq!(voting.nays.len(), 1);
 	}
 
+	abstain {
+		let m in 5 .. T::MaxMembers::get();
+
+		let p = T::MaxProposals::get();
+		let b = MAX_BYTES;
+		let bytes_in_storage = b + size_of::<u32>() as u32;
+
+		// Construct `members`.
+		let mut members = vec![];
+		let proposer: T::AccountId = account("proposer", 0, SEED);
+		members.push(proposer.clone());
+		for i in 1 .. m - 1 {
+			let member = account("member", i, SEED);
+			members.push(member);
+		}
+		let voter: T::AccountId = account("voter", 0, SEED);
+		members.push(voter.clone());
+		Collective::<T, _>::set_members(SystemOrigin::Root.into(), members.clone(), None, T::MaxMembers::get())?;
+
+		// Threshold is 1 less than the number of members so that one person can abstain
+		let threshold = m - 1;
+
+		// Add previous proposals
+		let mut last_hash = T::Hash::default();
+		for i in 0 .. p {
+			// Proposals should be different so that different proposal hashes are generated
+			let proposal: T::Proposal = SystemCall::<T>::remark(vec![i as u8; b as usize]).into();
+			Collective::<T, _>::propose(
+				SystemOrigin::Signed(proposer.clone()).into(),
+				threshold,
+				Box::new(proposal.clone()),
+				bytes_in_storage,
+			)?;
+			last_hash = T::Hashing::hash_of(&proposal);
+		}
+
+		let index = p - 1;
+		// Have almost everyone vote aye on last proposal, while keeping it from passing.
+		// Proposer already voted aye so we start at 1.
+		for j in 1 .. m - 3 {
+			let voter = &members[j as usize];
+			let approve = true;
+			Collective::<T, _>::vote(
+				SystemOrigin::Signed(voter.clone()).into(),
+				last_hash.clone(),
+				index,
+				approve,
+			)?;
+		}
+		// Voter votes aye without resolving the vote.
+		let approve = true;
+		Collective::<T, _>::vote(
+			SystemOrigin::Signed(voter.clone()).into(),
+			last_hash.clone(),
+			index,
+			approve,
+		)?;
+
+		assert_eq!(Collective::<T, _>::proposals().len(), p as usize);
+
+		// Voter switches vote to an abstention, removing the aye.
+
+		// Whitelist voter account from further DB operations.
+		let voter_key = frame_system::Account::<T>::hashed_key_for(&voter);
+		frame_benchmarking::benchmarking::add_to_whitelist(voter_key.into());
+	}: _(SystemOrigin::Signed(voter), last_hash.clone(), index)
+	verify {
+		// All proposals exist and the last proposal has just been updated.
+		assert_eq!(Collective::<T, _>::proposals().len(), p as usize);
+		let voting = Collective::<T, _>::voting(&last_hash).ok_or(Error::<T, I>::ProposalMissing)?;
+		assert_eq!(voting.ayes.len(), (m - 3) as usize);
+		assert_eq!(voting.abstentions.len(), 1);
+	}
+
 	close_early_disapproved {
 		// We choose 4 as a minimum so we always trigger a vote in the voting loop (`for j in ...`)
 		let m in 4 .. T::MaxMembers::get();
@@ -676,6 +750,13 @@ mod tests {
 		});
 	}
 
+	#[test]
+	fn abstain() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(test_benchmark_abstain::<Test>());
+		});
+	}
+
 	#[test]
 	fn close_early_disapproved() {
 		new_test_ext().execute_with(|| {
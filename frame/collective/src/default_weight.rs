@@ -15,6 +15,10 @@
 
 //! Default weights for the Collective Pallet
 //! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 2.0.0-rc6
+//!
+//! The weights after the `Estimates` comment were written by hand and not generated by the
+//! benchmark CLI. They are estimated from the generated weights of similar calls until the
+//! benchmarks are run again.
 
 #![allow(unused_parens)]
 #![allow(unused_imports)]
@@ -38,24 +42,31 @@ impl crate::WeightInfo for () {
 			.saturating_add((123000 as Weight).saturating_mul(m as Weight))
 			.saturating_add(DbWeight::get().reads(1 as Weight))
 	}
-	fn propose_execute(b: u32, m: u32, ) -> Weight {
-		(54134000 as Weight)
-			.saturating_add((4000 as Weight).saturating_mul(b as Weight))
-			.saturating_add((239000 as Weight).saturating_mul(m as Weight))
-			.saturating_add(DbWeight::get().reads(2 as Weight))
+	fn disapprove_proposal(p: u32, ) -> Weight {
+		(50500000 as Weight)
+			.saturating_add((966000 as Weight).saturating_mul(p as Weight))
+			.saturating_add(DbWeight::get().reads(1 as Weight))
+			.saturating_add(DbWeight::get().writes(3 as Weight))
 	}
+	// Estimates, not generated by the benchmark CLI.
 	fn propose_proposed(b: u32, m: u32, p: u32, ) -> Weight {
 		(90650000 as Weight)
 			.saturating_add((5000 as Weight).saturating_mul(b as Weight))
 			.saturating_add((152000 as Weight).saturating_mul(m as Weight))
 			.saturating_add((970000 as Weight).saturating_mul(p as Weight))
-			.saturating_add(DbWeight::get().reads(4 as Weight))
+			.saturating_add(DbWeight::get().reads(5 as Weight))
 			.saturating_add(DbWeight::get().writes(4 as Weight))
 	}
 	fn vote(m: u32, ) -> Weight {
 		(74460000 as Weight)
 			.saturating_add((290000 as Weight).saturating_mul(m as Weight))
-			.saturating_add(DbWeight::get().reads(2 as Weight))
+			.saturating_add(DbWeight::get().reads(3 as Weight))
+			.saturating_add(DbWeight::get().writes(1 as Weight))
+	}
+	fn abstain(m: u32, ) -> Weight {
+		(74460000 as Weight)
+			.saturating_add((290000 as Weight).saturating_mul(m as Weight))
+			.saturating_add(DbWeight::get().reads(3 as Weight))
 			.saturating_add(DbWeight::get().writes(1 as Weight))
 	}
 	fn close_early_disapproved(m: u32, p: u32, ) -> Weight {
@@ -63,6 +74,7 @@ impl crate::WeightInfo for () {
 			.saturating_add((232000 as Weight).saturating_mul(m as Weight))
 			.saturating_add((954000 as Weight).saturating_mul(p as Weight))
 			.saturating_add(DbWeight::get().reads(3 as Weight))
+			.saturating_add(DbWeight::get().reads((1 as Weight).saturating_mul(m as Weight)))
 			.saturating_add(DbWeight::get().writes(3 as Weight))
 	}
 	fn close_early_approved(b: u32, m: u32, p: u32, ) -> Weight {
@@ -71,6 +83,7 @@ impl crate::WeightInfo for () {
 			.saturating_add((287000 as Weight).saturating_mul(m as Weight))
 			.saturating_add((920000 as Weight).saturating_mul(p as Weight))
 			.saturating_add(DbWeight::get().reads(4 as Weight))
+			.saturating_add(DbWeight::get().reads((1 as Weight).saturating_mul(m as Weight)))
 			.saturating_add(DbWeight::get().writes(3 as Weight))
 	}
 	fn close_disapproved(m: u32, p: u32, ) -> Weight {
@@ -78,6 +91,7 @@ impl crate::WeightInfo for () {
 			.saturating_add((236000 as Weight).saturating_mul(m as Weight))
 			.saturating_add((965000 as Weight).saturating_mul(p as Weight))
 			.saturating_add(DbWeight::get().reads(4 as Weight))
+			.saturating_add(DbWeight::get().reads((1 as Weight).saturating_mul(m as Weight)))
 			.saturating_add(DbWeight::get().writes(3 as Weight))
 	}
 	fn close_approved(b: u32, m: u32, p: u32, ) -> Weight {
@@ -86,12 +100,14 @@ impl crate::WeightInfo for () {
 			.saturating_add((218000 as Weight).saturating_mul(m as Weight))
 			.saturating_add((951000 as Weight).saturating_mul(p as Weight))
 			.saturating_add(DbWeight::get().reads(5 as Weight))
+			.saturating_add(DbWeight::get().reads((1 as Weight).saturating_mul(m as Weight)))
 			.saturating_add(DbWeight::get().writes(3 as Weight))
 	}
-	fn disapprove_proposal(p: u32, ) -> Weight {
-		(50500000 as Weight)
-			.saturating_add((966000 as Weight).saturating_mul(p as Weight))
-			.saturating_add(DbWeight::get().reads(1 as Weight))
-			.saturating_add(DbWeight::get().writes(3 as Weight))
+	fn propose_execute(b: u32, m: u32, ) -> Weight {
+		(54134000 as Weight)
+			.saturating_add((4000 as Weight).saturating_mul(b as Weight))
+			.saturating_add((239000 as Weight).saturating_mul(m as Weight))
+			.saturating_add(DbWeight::get().reads(2 as Weight))
+			.saturating_add(DbWeight::get().reads((1 as Weight).saturating_mul(m as Weight)))
 	}
 }
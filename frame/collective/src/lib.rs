@@ -38,14 +38,29 @@
 //! abstentions and the proposal is executed if there are enough approvals counting the new votes.
 //!
 //! If there are not, or if no prime is set, then the motion is dropped without being executed.
+//!
+//! Members may also explicitly abstain. Abstentions count towards turnout, but are never replaced
+//! by the default vote.
+//!
+//! By default every member carries the same weight. A collective may instead weigh its members'
+//! votes through `VoteWeighting`, e.g. by stake with `BalanceVoteWeight` or from a configured map
+//! with `MapVoteWeight`. Approval then requires the approving weight to make up at least the
+//! `threshold` proportion of the collective's total weight. Independently of the threshold, the
+//! explicit turnout (ayes, nays and abstentions) must reach the `Quorum` proportion of the total
+//! weight for a motion to be approved.
+//!
+//! When a motion is closed, every vote is weighed by the weight of its voter at that time, the same
+//! as the total weight of the collective. Weight moved between members while the motion is open,
+//! e.g. by a balance transfer with `BalanceVoteWeight`, is thus only counted once. The weights
+//! recorded when votes are cast only serve the running tally reported in the voting events.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 #![recursion_limit="128"]
 
-use sp_std::{prelude::*, result};
+use sp_std::{prelude::*, result, cell::Cell};
 use sp_core::u32_trait::Value as U32;
 use sp_io::storage;
-use sp_runtime::{RuntimeDebug, traits::Hash};
+use sp_runtime::{RuntimeDebug, Perbill, traits::{Hash, Zero, UniqueSaturatedInto}};
 
 use frame_support::{
 	codec::{Decode, Encode},
@@ -55,8 +70,9 @@ use frame_support::{
 		PostDispatchInfo,
 	},
 	ensure,
-	traits::{ChangeMembers, EnsureOrigin, Get, InitializeMembers},
+	traits::{ChangeMembers, Currency, EnsureOrigin, Get, InitializeMembers},
 	weights::{DispatchClass, GetDispatchInfo, Weight},
+	type_info::TypeInfo,
 };
 use frame_system::{self as system, ensure_signed, ensure_root};
 
@@ -74,6 +90,47 @@ pub type ProposalIndex = u32;
 /// vote exactly once, therefore also the number of votes for any given motion.
 pub type MemberCount = u32;
 
+/// The weight of a member's vote, or of a number of votes taken together.
+pub type VoteWeight = u128;
+
+/// Source of the weight carried by each member's vote.
+pub trait VoteWeightProvider<AccountId> {
+	/// The weight of the vote of `who`.
+	fn vote_weight(who: &AccountId) -> VoteWeight;
+}
+
+/// Every member carries the same weight; this is the classic one-member-one-vote collective.
+pub struct EqualVoteWeight;
+
+impl<AccountId> VoteWeightProvider<AccountId> for EqualVoteWeight {
+	fn vote_weight(_who: &AccountId) -> VoteWeight {
+		1
+	}
+}
+
+/// Members are weighted by their total balance in `C`.
+pub struct BalanceVoteWeight<C>(sp_std::marker::PhantomData<C>);
+
+impl<AccountId, C: Currency<AccountId>> VoteWeightProvider<AccountId> for BalanceVoteWeight<C> {
+	fn vote_weight(who: &AccountId) -> VoteWeight {
+		C::total_balance(who).unique_saturated_into()
+	}
+}
+
+/// Members are weighted according to the map returned by `W`. Members which are not in the map
+/// carry no weight.
+pub struct MapVoteWeight<W>(sp_std::marker::PhantomData<W>);
+
+impl<AccountId: PartialEq, W: Get<Vec<(AccountId, VoteWeight)>>> VoteWeightProvider<AccountId>
+	for MapVoteWeight<W>
+{
+	fn vote_weight(who: &AccountId) -> VoteWeight {
+		W::get().into_iter()
+			.find(|(a, _)| a == who)
+			.map_or(0, |(_, w)| w)
+	}
+}
+
 /// Default voting strategy when a member is inactive.
 pub trait DefaultVote {
 	/// Get the default voting strategy, given:
@@ -126,6 +183,7 @@ pub trait WeightInfo {
 	fn propose_execute(b: u32, m: u32, ) -> Weight;
 	fn propose_proposed(b: u32, m: u32, p: u32, ) -> Weight;
 	fn vote(m: u32, ) -> Weight;
+	fn abstain(m: u32, ) -> Weight;
 	fn close_early_disapproved(m: u32, p: u32, ) -> Weight;
 	fn close_early_approved(b: u32, m: u32, p: u32, ) -> Weight;
 	fn close_disapproved(m: u32, p: u32, ) -> Weight;
@@ -162,12 +220,20 @@ pub trait Trait<I: Instance=DefaultInstance>: frame_system::Trait {
 	/// Default vote strategy of this collective.
 	type DefaultVote: DefaultVote;
 
+	/// The weight carried by each member's vote.
+	type VoteWeighting: VoteWeightProvider<Self::AccountId>;
+
+	/// The proportion of the collective's total vote weight which must have voted or abstained
+	/// for a motion to be approved.
+	type Quorum: Get<Perbill>;
+
 	/// Weight information for extrinsics in this pallet.
 	type WeightInfo: WeightInfo;
 }
 
 /// Origin for the collective module.
-#[derive(PartialEq, Eq, Clone, RuntimeDebug, Encode, Decode)]
+#[derive(PartialEq, Eq, Clone, RuntimeDebug, Encode, Decode, TypeInfo)]
+#[scale_info(skip_type_params(I))]
 pub enum RawOrigin<AccountId, I> {
 	/// It has been condoned by a given number of members of the collective from a given total.
 	Members(MemberCount, MemberCount),
@@ -180,21 +246,49 @@ pub enum RawOrigin<AccountId, I> {
 /// Origin for the collective module.
 pub type Origin<T, I=DefaultInstance> = RawOrigin<<T as frame_system::Trait>::AccountId, I>;
 
-#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug)]
+#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, TypeInfo)]
 /// Info for keeping track of a motion being voted on.
 pub struct Votes<AccountId, BlockNumber> {
 	/// The proposal's unique index.
 	index: ProposalIndex,
 	/// The number of approval votes that are needed to pass the motion.
 	threshold: MemberCount,
-	/// The current set of voters that approved it.
+	/// The current set of voters that approved it, with the weight of their vote at the time.
+	ayes: Vec<(AccountId, VoteWeight)>,
+	/// The current set of voters that rejected it, with the weight of their vote at the time.
+	nays: Vec<(AccountId, VoteWeight)>,
+	/// The current set of voters that abstained, with the weight of their vote at the time.
+	abstentions: Vec<(AccountId, VoteWeight)>,
+	/// The hard end time of this vote.
+	end: BlockNumber,
+}
+
+/// Info for keeping track of a motion being voted on, before abstentions and weighted votes were
+/// introduced.
+#[derive(Decode)]
+struct VotesV0<AccountId, BlockNumber> {
+	index: ProposalIndex,
+	threshold: MemberCount,
 	ayes: Vec<AccountId>,
-	/// The current set of voters that rejected it.
 	nays: Vec<AccountId>,
-	/// The hard end time of this vote.
 	end: BlockNumber,
 }
 
+// A value placed in storage that represents the current version of the Collective storage.
+// This value is used by the `on_runtime_upgrade` logic to determine whether we run
+// storage migration logic.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+enum Releases {
+	V0,
+	V1,
+}
+
+impl Default for Releases {
+	fn default() -> Self {
+		Releases::V0
+	}
+}
+
 decl_storage! {
 	trait Store for Module<T: Trait<I>, I: Instance=DefaultInstance> as Collective {
 		/// The hashes of the active proposals.
@@ -211,6 +305,10 @@ decl_storage! {
 		pub Members get(fn members): Vec<T::AccountId>;
 		/// The prime member that helps determine the default vote behavior in case of absentations.
 		pub Prime get(fn prime): Option<T::AccountId>;
+		/// Storage version of the pallet.
+		///
+		/// New networks start with last version.
+		StorageVersion build(|_| Releases::V1): Releases;
 	}
 	add_extra_genesis {
 		config(phantom): sp_std::marker::PhantomData<I>;
@@ -229,9 +327,13 @@ decl_event! {
 		/// \[account, proposal_index, proposal_hash, threshold\]
 		Proposed(AccountId, ProposalIndex, Hash, MemberCount),
 		/// A motion (given hash) has been voted on by given account, leaving
-		/// a tally (yes votes and no votes given respectively as `MemberCount`).
+		/// a tally (yes votes and no votes given respectively as `VoteWeight`).
 		/// \[account, proposal_hash, voted, yes, no\]
-		Voted(AccountId, Hash, bool, MemberCount, MemberCount),
+		Voted(AccountId, Hash, bool, VoteWeight, VoteWeight),
+		/// A given account has abstained on a motion (given hash), leaving a tally (yes votes,
+		/// no votes and abstentions given respectively as `VoteWeight`).
+		/// \[account, proposal_hash, yes, no, abstain\]
+		Abstained(AccountId, Hash, VoteWeight, VoteWeight, VoteWeight),
 		/// A motion was approved by the required threshold.
 		/// \[proposal_hash\]
 		Approved(Hash),
@@ -245,8 +347,9 @@ decl_event! {
 		/// \[proposal_hash, result\]
 		MemberExecuted(Hash, DispatchResult),
 		/// A proposal was closed because its threshold was reached or after its duration was up.
+		/// The tally (given as `VoteWeight`) includes any default votes.
 		/// \[proposal_hash, yes, no\]
-		Closed(Hash, MemberCount, MemberCount),
+		Closed(Hash, VoteWeight, VoteWeight),
 	}
 }
 
@@ -272,6 +375,9 @@ decl_error! {
 		WrongProposalWeight,
 		/// The given length bound for the proposal was too low.
 		WrongProposalLength,
+		/// The proposer's vote alone doesn't meet the threshold or the quorum, so the proposal
+		/// can't be executed directly.
+		InsufficientVoteWeight,
 	}
 }
 
@@ -293,6 +399,10 @@ decl_module! {
 
 		fn deposit_event() = default;
 
+		fn on_runtime_upgrade() -> Weight {
+			Self::migrate_v0_to_v1()
+		}
+
 		/// Set the collective's membership.
 		///
 		/// - `new_members`: The new member list. Be nice to the chain and provide it sorted.
@@ -407,6 +517,10 @@ decl_module! {
 		/// `threshold` determines whether `proposal` is executed directly (`threshold < 2`)
 		/// or put up for voting.
 		///
+		/// A proposal is only executed directly if the proposer's vote, weighed by `VoteWeighting`,
+		/// meets both `threshold` and the `Quorum` on its own, as it would have to when closing a
+		/// motion.
+		///
 		/// # <weight>
 		/// ## Weight
 		/// - `O(B + M + P1)` or `O(B + M + P2)` where:
@@ -419,7 +533,8 @@ decl_module! {
 		///   - 1 storage read `is_member` (codec `O(M)`)
 		///   - 1 storage read `ProposalOf::contains_key` (codec `O(1)`)
 		///   - DB accesses influenced by `threshold`:
-		///     - EITHER storage accesses done by `proposal` (`threshold < 2`)
+		///     - EITHER `M` storage reads for the vote weights of the members and the storage
+		///       accesses done by `proposal` (`threshold < 2`)
 		///     - OR proposal insertion (`threshold <= 2`)
 		///       - 1 storage mutation `Proposals` (codec `O(P2)`)
 		///       - 1 storage mutation `ProposalCount` (codec `O(1)`)
@@ -457,7 +572,16 @@ decl_module! {
 			ensure!(!<ProposalOf<T, I>>::contains_key(proposal_hash), Error::<T, I>::DuplicateProposal);
 
 			if threshold < 2 {
-				let seats = Self::members().len() as MemberCount;
+				let seats = members.len() as MemberCount;
+				let weights = Self::weigh(&members);
+				let total = weights.iter().fold(0, |acc: VoteWeight, (_, w)| acc.saturating_add(*w));
+				let yes_votes = weights.iter().find(|(a, _)| a == &who).map_or(0, |(_, w)| *w);
+				ensure!(
+					yes_votes >= T::Quorum::get() * total &&
+						Self::meets_threshold(yes_votes, total, threshold, seats),
+					Error::<T, I>::InsufficientVoteWeight
+				);
+
 				let result = proposal.dispatch(RawOrigin::Members(1, seats).into());
 				Self::deposit_event(
 					RawEvent::Executed(proposal_hash, result.map(|_| ()).map_err(|e| e.error))
@@ -483,7 +607,14 @@ decl_module! {
 				<ProposalCount<I>>::mutate(|i| *i += 1);
 				<ProposalOf<T, I>>::insert(proposal_hash, *proposal);
 				let end = system::Module::<T>::block_number() + T::MotionDuration::get();
-				let votes = Votes { index, threshold, ayes: vec![who.clone()], nays: vec![], end };
+				let votes = Votes {
+					index,
+					threshold,
+					ayes: vec![(who.clone(), T::VoteWeighting::vote_weight(&who))],
+					nays: vec![],
+					abstentions: vec![],
+					end,
+				};
 				<Voting<T, I>>::insert(proposal_hash, votes);
 
 				Self::deposit_event(RawEvent::Proposed(who, index, proposal_hash, threshold));
@@ -505,6 +636,7 @@ decl_module! {
 		/// - `O(M)` where `M` is members-count (code- and governance-bounded)
 		/// - DB:
 		///   - 1 storage read `Members` (codec `O(M)`)
+		///   - 1 storage read for the vote weight of the sender
 		///   - 1 storage mutation `Voting` (codec `O(M)`)
 		/// - 1 event
 		/// # </weight>
@@ -524,12 +656,15 @@ decl_module! {
 			let mut voting = Self::voting(&proposal).ok_or(Error::<T, I>::ProposalMissing)?;
 			ensure!(voting.index == index, Error::<T, I>::WrongIndex);
 
-			let position_yes = voting.ayes.iter().position(|a| a == &who);
-			let position_no = voting.nays.iter().position(|a| a == &who);
+			let position_yes = voting.ayes.iter().position(|(a, _)| a == &who);
+			let position_no = voting.nays.iter().position(|(a, _)| a == &who);
+			let position_abstain = voting.abstentions.iter().position(|(a, _)| a == &who);
+			// The weight of the vote is fixed when it is cast.
+			let weight = T::VoteWeighting::vote_weight(&who);
 
 			if approve {
 				if position_yes.is_none() {
-					voting.ayes.push(who.clone());
+					voting.ayes.push((who.clone(), weight));
 				} else {
 					Err(Error::<T, I>::DuplicateVote)?
 				}
@@ -538,7 +673,7 @@ decl_module! {
 				}
 			} else {
 				if position_no.is_none() {
-					voting.nays.push(who.clone());
+					voting.nays.push((who.clone(), weight));
 				} else {
 					Err(Error::<T, I>::DuplicateVote)?
 				}
@@ -546,9 +681,12 @@ decl_module! {
 					voting.ayes.swap_remove(pos);
 				}
 			}
+			if let Some(pos) = position_abstain {
+				voting.abstentions.swap_remove(pos);
+			}
 
-			let yes_votes = voting.ayes.len() as MemberCount;
-			let no_votes = voting.nays.len() as MemberCount;
+			let yes_votes = Self::tally(&voting.ayes);
+			let no_votes = Self::tally(&voting.nays);
 			Self::deposit_event(RawEvent::Voted(who, proposal, approve, yes_votes, no_votes));
 
 			Voting::<T, I>::insert(&proposal, voting);
@@ -556,6 +694,55 @@ decl_module! {
 			Ok(Some(T::WeightInfo::vote(members.len() as u32)).into())
 		}
 
+		/// Abstain on the given proposal, replacing any aye or nay vote of the sender.
+		///
+		/// Abstentions count towards the quorum, but are never replaced by the default vote when
+		/// the motion is closed.
+		///
+		/// Requires the sender to be a member.
+		///
+		/// # <weight>
+		/// ## Weight
+		/// - `O(M)` where `M` is members-count (code- and governance-bounded)
+		/// - DB:
+		///   - 1 storage read `Members` (codec `O(M)`)
+		///   - 1 storage read for the vote weight of the sender
+		///   - 1 storage mutation `Voting` (codec `O(M)`)
+		/// - 1 event
+		/// # </weight>
+		#[weight = (
+			T::WeightInfo::abstain(T::MaxMembers::get()),
+			DispatchClass::Operational
+		)]
+		fn abstain(origin,
+			proposal: T::Hash,
+			#[compact] index: ProposalIndex,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			let members = Self::members();
+			ensure!(members.contains(&who), Error::<T, I>::NotMember);
+
+			let mut voting = Self::voting(&proposal).ok_or(Error::<T, I>::ProposalMissing)?;
+			ensure!(voting.index == index, Error::<T, I>::WrongIndex);
+			ensure!(
+				!voting.abstentions.iter().any(|(a, _)| a == &who),
+				Error::<T, I>::DuplicateVote,
+			);
+
+			voting.ayes.retain(|(a, _)| a != &who);
+			voting.nays.retain(|(a, _)| a != &who);
+			voting.abstentions.push((who.clone(), T::VoteWeighting::vote_weight(&who)));
+
+			let yes_votes = Self::tally(&voting.ayes);
+			let no_votes = Self::tally(&voting.nays);
+			let abstentions = Self::tally(&voting.abstentions);
+			Self::deposit_event(RawEvent::Abstained(who, proposal, yes_votes, no_votes, abstentions));
+
+			Voting::<T, I>::insert(&proposal, voting);
+
+			Ok(Some(T::WeightInfo::abstain(members.len() as u32)).into())
+		}
+
 		/// Close a vote that is either approved, disapproved or whose voting period has ended.
 		///
 		/// May be called by any signed account in order to finish voting and close the proposal.
@@ -563,8 +750,13 @@ decl_module! {
 		/// If called before the end of the voting period it will only close the vote if it is
 		/// has enough votes to be approved or disapproved.
 		///
-		/// If called after the end of the voting period abstentions are counted as rejections
-		/// unless there is a prime member set and the prime member cast an approval.
+		/// If called after the end of the voting period, members who did not vote are counted as
+		/// rejections unless there is a prime member set and the prime member cast an approval.
+		/// Explicit abstentions are not counted either way.
+		///
+		/// Votes, as well as the total weight of the collective, are weighed by `VoteWeighting` at
+		/// the time of closing. The motion is only approved if the explicit turnout reaches the
+		/// `Quorum`.
 		///
 		/// + `proposal_weight_bound`: The maximum amount of weight consumed by executing the closed proposal.
 		/// + `length_bound`: The upper bound for the length of the proposal in storage. Checked via
@@ -579,6 +771,7 @@ decl_module! {
 		///   - `P2` is proposal-count (code-bounded)
 		/// - DB:
 		///  - 2 storage reads (`Members`: codec `O(M)`, `Prime`: codec `O(1)`)
+		///  - `M` storage reads for the vote weights of the members
 		///  - 3 mutations (`Voting`: codec `O(M)`, `ProposalOf`: codec `O(B)`, `Proposals`: codec `O(P2)`)
 		///  - any mutations done while executing `proposal` (`P1`)
 		/// - up to 3 events
@@ -608,11 +801,21 @@ decl_module! {
 			let voting = Self::voting(&proposal_hash).ok_or(Error::<T, I>::ProposalMissing)?;
 			ensure!(voting.index == index, Error::<T, I>::WrongIndex);
 
-			let mut no_votes = voting.nays.len() as MemberCount;
-			let mut yes_votes = voting.ayes.len() as MemberCount;
-			let seats = Self::members().len() as MemberCount;
-			let approved = yes_votes >= voting.threshold;
-			let disapproved = seats.saturating_sub(no_votes) < voting.threshold;
+			let members = Self::members();
+			let seats = members.len() as MemberCount;
+			// Votes are weighed anew so that weight moved between members since they were cast,
+			// e.g. by a balance transfer, can't be counted twice.
+			let weights = Self::weigh(&members);
+			let total = weights.iter().fold(0, |acc: VoteWeight, (_, w)| acc.saturating_add(*w));
+			let mut no_votes = Self::reweigh(&voting.nays, &weights);
+			let mut yes_votes = Self::reweigh(&voting.ayes, &weights);
+			let abstentions = Self::reweigh(&voting.abstentions, &weights);
+			let turnout = yes_votes.saturating_add(no_votes).saturating_add(abstentions);
+			let quorum = turnout >= T::Quorum::get() * total;
+			let threshold = voting.threshold;
+			let approved = quorum && Self::meets_threshold(yes_votes, total, threshold, seats);
+			let undecided = total.saturating_sub(no_votes).saturating_sub(abstentions);
+			let disapproved = !Self::meets_threshold(undecided, total, threshold, seats);
 			// Allow (dis-)approving the proposal as soon as there are enough votes.
 			if approved {
 				let (proposal, len) = Self::validate_and_get_proposal(
@@ -638,17 +841,23 @@ decl_module! {
 			// Only allow actual closing of the proposal after the voting period has ended.
 			ensure!(system::Module::<T>::block_number() >= voting.end, Error::<T, I>::TooEarly);
 
-			let prime_vote = Self::prime().map(|who| voting.ayes.iter().any(|a| a == &who));
+			let prime_vote = Self::prime().map(|who| voting.ayes.iter().any(|(a, _)| a == &who));
 
-			// default voting strategy.
-			let default = T::DefaultVote::default_vote(prime_vote, yes_votes, no_votes, seats);
+			// default voting strategy, decided by head count.
+			let default = T::DefaultVote::default_vote(
+				prime_vote,
+				voting.ayes.len() as MemberCount,
+				voting.nays.len() as MemberCount,
+				seats,
+			);
 
-			let abstentions = seats - (yes_votes + no_votes);
+			// members who did not vote at all follow the default vote.
+			let silent = total.saturating_sub(turnout);
 			match default {
-				true => yes_votes += abstentions,
-				false => no_votes += abstentions,
+				true => yes_votes = yes_votes.saturating_add(silent),
+				false => no_votes = no_votes.saturating_add(silent),
 			}
-			let approved = yes_votes >= voting.threshold;
+			let approved = quorum && Self::meets_threshold(yes_votes, total, threshold, seats);
 
 			if approved {
 				let (proposal, len) = Self::validate_and_get_proposal(
@@ -702,6 +911,73 @@ impl<T: Trait<I>, I: Instance> Module<T, I> {
 		Self::members().contains(who)
 	}
 
+	/// The current vote weight of each of `who`.
+	fn weigh(who: &[T::AccountId]) -> Vec<(T::AccountId, VoteWeight)> {
+		who.iter().map(|a| (a.clone(), T::VoteWeighting::vote_weight(a))).collect()
+	}
+
+	/// The total weight of the recorded `votes`, as recorded when they were cast.
+	fn tally(votes: &[(T::AccountId, VoteWeight)]) -> VoteWeight {
+		votes.iter().fold(0, |acc: VoteWeight, (_, w)| acc.saturating_add(*w))
+	}
+
+	/// The total weight of the recorded `votes` according to the voters' `weights`, ignoring
+	/// the weights recorded when they were cast. Voters without a weight don't count.
+	fn reweigh(
+		votes: &[(T::AccountId, VoteWeight)],
+		weights: &[(T::AccountId, VoteWeight)],
+	) -> VoteWeight {
+		votes.iter().fold(0, |acc: VoteWeight, (who, _)| {
+			let weight = weights.iter().find(|(a, _)| a == who).map_or(0, |(_, w)| *w);
+			acc.saturating_add(weight)
+		})
+	}
+
+	/// Whether `weight` out of a `total` vote weight is at least `threshold` out of `seats`.
+	///
+	/// Nothing can meet the threshold of a collective without any vote weight.
+	fn meets_threshold(
+		weight: VoteWeight,
+		total: VoteWeight,
+		threshold: MemberCount,
+		seats: MemberCount,
+	) -> bool {
+		!total.is_zero() &&
+			weight.saturating_mul(VoteWeight::from(seats)) >=
+				VoteWeight::from(threshold).saturating_mul(total)
+	}
+
+	/// Migrate motions to the format including abstentions and the weight of each vote (V1).
+	/// The votes cast so far are given their current weight. Return the weight consumed.
+	pub fn migrate_v0_to_v1() -> Weight {
+		if StorageVersion::<I>::get() == Releases::V0 {
+			StorageVersion::<I>::put(Releases::V1);
+
+			let translated = Cell::new(0u64);
+			let with_weight = |voters: Vec<T::AccountId>| voters.into_iter()
+				.map(|who| {
+					let weight = T::VoteWeighting::vote_weight(&who);
+					(who, weight)
+				})
+				.collect::<Vec<_>>();
+			Voting::<T, I>::translate::<VotesV0<T::AccountId, T::BlockNumber>, _>(|_, old| {
+				translated.set(translated.get() + 1);
+				Some(Votes {
+					index: old.index,
+					threshold: old.threshold,
+					ayes: with_weight(old.ayes),
+					nays: with_weight(old.nays),
+					abstentions: vec![],
+					end: old.end,
+				})
+			});
+
+			T::DbWeight::get().reads_writes(translated.get() + 1, translated.get() + 1)
+		} else {
+			T::DbWeight::get().reads(1)
+		}
+	}
+
 	/// Ensure that the right proposal bounds were passed and get the proposal from storage.
 	///
 	/// Checks the length in storage via `storage::read` which adds an extra `size_of::<u32>() == 4`
@@ -813,10 +1089,13 @@ impl<T: Trait<I>, I: Instance> ChangeMembers<T::AccountId> for Module<T, I> {
 			<Voting<T, I>>::mutate(h, |v|
 				if let Some(mut votes) = v.take() {
 					votes.ayes = votes.ayes.into_iter()
-						.filter(|i| outgoing.binary_search(i).is_err())
+						.filter(|(i, _)| outgoing.binary_search(i).is_err())
 						.collect();
 					votes.nays = votes.nays.into_iter()
-						.filter(|i| outgoing.binary_search(i).is_err())
+						.filter(|(i, _)| outgoing.binary_search(i).is_err())
+						.collect();
+					votes.abstentions = votes.abstentions.into_iter()
+						.filter(|(i, _)| outgoing.binary_search(i).is_err())
 						.collect();
 					*v = Some(votes);
 				}
@@ -963,6 +1242,8 @@ mod tests {
 		pub const MotionDuration: u64 = 3;
 		pub const MaxProposals: u32 = 100;
 		pub const MaxMembers: u32 = 100;
+		pub const Quorum: Perbill = Perbill::from_percent(50);
+		pub storage MemberWeights: Vec<(u64, VoteWeight)> = vec![(1, 50), (2, 30), (3, 15), (4, 5)];
 	}
 	impl frame_system::Trait for Test {
 		type BaseCallFilter = ();
@@ -999,6 +1280,8 @@ mod tests {
 		type MaxProposals = MaxProposals;
 		type MaxMembers = MaxMembers;
 		type DefaultVote = PrimeDefaultVote;
+		type VoteWeighting = EqualVoteWeight;
+		type Quorum = ();
 		type WeightInfo = ();
 	}
 	impl Trait<Instance2> for Test {
@@ -1009,6 +1292,20 @@ mod tests {
 		type MaxProposals = MaxProposals;
 		type MaxMembers = MaxMembers;
 		type DefaultVote = MoreThanMajorityThenPrimeDefaultVote;
+		type VoteWeighting = EqualVoteWeight;
+		type Quorum = ();
+		type WeightInfo = ();
+	}
+	impl Trait<Instance3> for Test {
+		type Origin = Origin;
+		type Proposal = Call;
+		type Event = Event;
+		type MotionDuration = MotionDuration;
+		type MaxProposals = MaxProposals;
+		type MaxMembers = MaxMembers;
+		type DefaultVote = PrimeDefaultVote;
+		type VoteWeighting = MapVoteWeight<MemberWeights>;
+		type Quorum = Quorum;
 		type WeightInfo = ();
 	}
 	impl Trait for Test {
@@ -1019,6 +1316,8 @@ mod tests {
 		type MaxProposals = MaxProposals;
 		type MaxMembers = MaxMembers;
 		type DefaultVote = PrimeDefaultVote;
+		type VoteWeighting = EqualVoteWeight;
+		type Quorum = ();
 		type WeightInfo = ();
 	}
 
@@ -1034,6 +1333,7 @@ mod tests {
 			System: system::{Module, Call, Event<T>},
 			Collective: collective::<Instance1>::{Module, Call, Event<T>, Origin<T>, Config<T>},
 			CollectiveMajority: collective::<Instance2>::{Module, Call, Event<T>, Origin<T>, Config<T>},
+			StakeCollective: collective::<Instance3>::{Module, Call, Event<T>, Origin<T>, Config<T>},
 			DefaultCollective: collective::{Module, Call, Event<T>, Origin<T>, Config<T>},
 		}
 	);
@@ -1048,6 +1348,10 @@ mod tests {
 				members: vec![1, 2, 3, 4, 5],
 				phantom: Default::default(),
 			}),
+			collective_Instance3: Some(collective::GenesisConfig {
+				members: vec![1, 2, 3, 4],
+				phantom: Default::default(),
+			}),
 			collective: None,
 		}.build_storage().unwrap().into();
 		ext.execute_with(|| System::set_block_number(1));
@@ -1223,12 +1527,12 @@ mod tests {
 			assert_ok!(Collective::vote(Origin::signed(2), hash.clone(), 0, true));
 			assert_eq!(
 				Collective::voting(&hash),
-				Some(Votes { index: 0, threshold: 3, ayes: vec![1, 2], nays: vec![], end })
+				Some(Votes { index: 0, threshold: 3, ayes: vec![(1, 1), (2, 1)], nays: vec![], abstentions: vec![], end })
 			);
 			Collective::change_members_sorted(&[4], &[1], &[2, 3, 4]);
 			assert_eq!(
 				Collective::voting(&hash),
-				Some(Votes { index: 0, threshold: 3, ayes: vec![2], nays: vec![], end })
+				Some(Votes { index: 0, threshold: 3, ayes: vec![(2, 1)], nays: vec![], abstentions: vec![], end })
 			);
 
 			let proposal = make_proposal(69);
@@ -1238,12 +1542,12 @@ mod tests {
 			assert_ok!(Collective::vote(Origin::signed(3), hash.clone(), 1, false));
 			assert_eq!(
 				Collective::voting(&hash),
-				Some(Votes { index: 1, threshold: 2, ayes: vec![2], nays: vec![3], end })
+				Some(Votes { index: 1, threshold: 2, ayes: vec![(2, 1)], nays: vec![(3, 1)], abstentions: vec![], end })
 			);
 			Collective::change_members_sorted(&[], &[3], &[2, 4]);
 			assert_eq!(
 				Collective::voting(&hash),
-				Some(Votes { index: 1, threshold: 2, ayes: vec![2], nays: vec![], end })
+				Some(Votes { index: 1, threshold: 2, ayes: vec![(2, 1)], nays: vec![], abstentions: vec![], end })
 			);
 		});
 	}
@@ -1259,12 +1563,12 @@ mod tests {
 			assert_ok!(Collective::vote(Origin::signed(2), hash.clone(), 0, true));
 			assert_eq!(
 				Collective::voting(&hash),
-				Some(Votes { index: 0, threshold: 3, ayes: vec![1, 2], nays: vec![], end })
+				Some(Votes { index: 0, threshold: 3, ayes: vec![(1, 1), (2, 1)], nays: vec![], abstentions: vec![], end })
 			);
 			assert_ok!(Collective::set_members(Origin::root(), vec![2, 3, 4], None, MaxMembers::get()));
 			assert_eq!(
 				Collective::voting(&hash),
-				Some(Votes { index: 0, threshold: 3, ayes: vec![2], nays: vec![], end })
+				Some(Votes { index: 0, threshold: 3, ayes: vec![(2, 1)], nays: vec![], abstentions: vec![], end })
 			);
 
 			let proposal = make_proposal(69);
@@ -1274,12 +1578,12 @@ mod tests {
 			assert_ok!(Collective::vote(Origin::signed(3), hash.clone(), 1, false));
 			assert_eq!(
 				Collective::voting(&hash),
-				Some(Votes { index: 1, threshold: 2, ayes: vec![2], nays: vec![3], end })
+				Some(Votes { index: 1, threshold: 2, ayes: vec![(2, 1)], nays: vec![(3, 1)], abstentions: vec![], end })
 			);
 			assert_ok!(Collective::set_members(Origin::root(), vec![2, 4], None, MaxMembers::get()));
 			assert_eq!(
 				Collective::voting(&hash),
-				Some(Votes { index: 1, threshold: 2, ayes: vec![2], nays: vec![], end })
+				Some(Votes { index: 1, threshold: 2, ayes: vec![(2, 1)], nays: vec![], abstentions: vec![], end })
 			);
 		});
 	}
@@ -1296,7 +1600,7 @@ mod tests {
 			assert_eq!(Collective::proposal_of(&hash), Some(proposal));
 			assert_eq!(
 				Collective::voting(&hash),
-				Some(Votes { index: 0, threshold: 3, ayes: vec![1], nays: vec![], end })
+				Some(Votes { index: 0, threshold: 3, ayes: vec![(1, 1)], nays: vec![], abstentions: vec![], end })
 			);
 
 			assert_eq!(System::events(), vec![
@@ -1411,7 +1715,7 @@ mod tests {
 			assert_ok!(Collective::propose(Origin::signed(1), 2, Box::new(proposal.clone()), proposal_len));
 			assert_eq!(
 				Collective::voting(&hash),
-				Some(Votes { index: 0, threshold: 2, ayes: vec![1], nays: vec![], end })
+				Some(Votes { index: 0, threshold: 2, ayes: vec![(1, 1)], nays: vec![], abstentions: vec![], end })
 			);
 			assert_noop!(
 				Collective::vote(Origin::signed(1), hash.clone(), 0, true),
@@ -1420,7 +1724,7 @@ mod tests {
 			assert_ok!(Collective::vote(Origin::signed(1), hash.clone(), 0, false));
 			assert_eq!(
 				Collective::voting(&hash),
-				Some(Votes { index: 0, threshold: 2, ayes: vec![], nays: vec![1], end })
+				Some(Votes { index: 0, threshold: 2, ayes: vec![], nays: vec![(1, 1)], abstentions: vec![], end })
 			);
 			assert_noop!(
 				Collective::vote(Origin::signed(1), hash.clone(), 0, false),
@@ -1628,4 +1932,268 @@ mod tests {
 			]);
 		})
 	}
+
+	#[test]
+	fn abstain_works() {
+		new_test_ext().execute_with(|| {
+			let proposal = make_proposal(42);
+			let proposal_len: u32 = proposal.using_encoded(|p| p.len() as u32);
+			let hash: H256 = proposal.blake2_256().into();
+			let end = 4;
+			assert_ok!(Collective::propose(Origin::signed(1), 3, Box::new(proposal.clone()), proposal_len));
+			assert_ok!(Collective::abstain(Origin::signed(2), hash.clone(), 0));
+			assert_noop!(
+				Collective::abstain(Origin::signed(2), hash.clone(), 0),
+				Error::<Test, Instance1>::DuplicateVote,
+			);
+			assert_noop!(
+				Collective::abstain(Origin::signed(42), hash.clone(), 0),
+				Error::<Test, Instance1>::NotMember,
+			);
+			assert_ok!(Collective::abstain(Origin::signed(1), hash.clone(), 0));
+			assert_eq!(
+				Collective::voting(&hash),
+				Some(Votes { index: 0, threshold: 3, ayes: vec![], nays: vec![], abstentions: vec![(2, 1), (1, 1)], end })
+			);
+			assert_ok!(Collective::vote(Origin::signed(2), hash.clone(), 0, true));
+			assert_eq!(
+				Collective::voting(&hash),
+				Some(Votes { index: 0, threshold: 3, ayes: vec![(2, 1)], nays: vec![], abstentions: vec![(1, 1)], end })
+			);
+
+			let record = |event| EventRecord { phase: Phase::Initialization, event, topics: vec![] };
+			assert_eq!(System::events(), vec![
+				record(Event::collective_Instance1(RawEvent::Proposed(1, 0, hash.clone(), 3))),
+				record(Event::collective_Instance1(RawEvent::Abstained(2, hash.clone(), 1, 0, 1))),
+				record(Event::collective_Instance1(RawEvent::Abstained(1, hash.clone(), 0, 0, 2))),
+				record(Event::collective_Instance1(RawEvent::Voted(2, hash.clone(), true, 1, 0))),
+			]);
+		});
+	}
+
+	#[test]
+	fn abstentions_are_not_replaced_by_default_vote() {
+		new_test_ext().execute_with(|| {
+			let proposal = make_proposal(42);
+			let proposal_len: u32 = proposal.using_encoded(|p| p.len() as u32);
+			let proposal_weight = proposal.get_dispatch_info().weight;
+			let hash: H256 = proposal.blake2_256().into();
+			assert_ok!(Collective::set_members(Origin::root(), vec![1, 2, 3], Some(1), MaxMembers::get()));
+			assert_ok!(Collective::propose(Origin::signed(1), 2, Box::new(proposal.clone()), proposal_len));
+			assert_ok!(Collective::abstain(Origin::signed(2), hash.clone(), 0));
+
+			System::set_block_number(4);
+			assert_ok!(Collective::close(Origin::signed(4), hash.clone(), 0, proposal_weight, proposal_len));
+
+			// Only 3 follows the prime's aye; 2 abstained.
+			let record = |event| EventRecord { phase: Phase::Initialization, event, topics: vec![] };
+			assert_eq!(System::events(), vec![
+				record(Event::collective_Instance1(RawEvent::Proposed(1, 0, hash.clone(), 2))),
+				record(Event::collective_Instance1(RawEvent::Abstained(2, hash.clone(), 1, 0, 1))),
+				record(Event::collective_Instance1(RawEvent::Closed(hash.clone(), 2, 0))),
+				record(Event::collective_Instance1(RawEvent::Approved(hash.clone()))),
+				record(Event::collective_Instance1(RawEvent::Executed(hash.clone(), Err(DispatchError::BadOrigin))))
+			]);
+		});
+	}
+
+	#[test]
+	fn abstentions_can_disapprove_early() {
+		new_test_ext().execute_with(|| {
+			let proposal = make_proposal(42);
+			let proposal_len: u32 = proposal.using_encoded(|p| p.len() as u32);
+			let proposal_weight = proposal.get_dispatch_info().weight;
+			let hash: H256 = proposal.blake2_256().into();
+			assert_ok!(Collective::propose(Origin::signed(1), 3, Box::new(proposal.clone()), proposal_len));
+			assert_noop!(
+				Collective::close(Origin::signed(4), hash.clone(), 0, proposal_weight, proposal_len),
+				Error::<Test, Instance1>::TooEarly
+			);
+			assert_ok!(Collective::abstain(Origin::signed(2), hash.clone(), 0));
+			assert_ok!(Collective::close(Origin::signed(4), hash.clone(), 0, proposal_weight, proposal_len));
+			assert_eq!(Collective::proposals(), vec![]);
+		});
+	}
+
+	#[test]
+	fn stake_weighted_voting_works() {
+		new_test_ext().execute_with(|| {
+			let proposal = make_proposal(42);
+			let proposal_len: u32 = proposal.using_encoded(|p| p.len() as u32);
+			let proposal_weight = proposal.get_dispatch_info().weight;
+			let hash: H256 = proposal.blake2_256().into();
+			// Three out of four seats means 75% of the total weight of 100.
+			assert_ok!(StakeCollective::propose(Origin::signed(2), 3, Box::new(proposal.clone()), proposal_len));
+			assert_noop!(
+				StakeCollective::close(Origin::signed(4), hash.clone(), 0, proposal_weight, proposal_len),
+				Error::<Test, Instance3>::TooEarly
+			);
+			// Two members, but 80% of the weight.
+			assert_ok!(StakeCollective::vote(Origin::signed(1), hash.clone(), 0, true));
+			assert_ok!(StakeCollective::close(Origin::signed(4), hash.clone(), 0, proposal_weight, proposal_len));
+
+			let record = |event| EventRecord { phase: Phase::Initialization, event, topics: vec![] };
+			assert_eq!(System::events(), vec![
+				record(Event::collective_Instance3(RawEvent::Proposed(2, 0, hash.clone(), 3))),
+				record(Event::collective_Instance3(RawEvent::Voted(1, hash.clone(), true, 80, 0))),
+				record(Event::collective_Instance3(RawEvent::Closed(hash.clone(), 80, 0))),
+				record(Event::collective_Instance3(RawEvent::Approved(hash.clone()))),
+				record(Event::collective_Instance3(RawEvent::Executed(hash.clone(), Err(DispatchError::BadOrigin))))
+			]);
+		});
+	}
+
+	#[test]
+	fn stake_weighted_early_disapproval_works() {
+		new_test_ext().execute_with(|| {
+			let proposal = make_proposal(42);
+			let proposal_len: u32 = proposal.using_encoded(|p| p.len() as u32);
+			let proposal_weight = proposal.get_dispatch_info().weight;
+			let hash: H256 = proposal.blake2_256().into();
+			assert_ok!(StakeCollective::propose(Origin::signed(4), 2, Box::new(proposal.clone()), proposal_len));
+			// Half of the weight can still approve.
+			assert_ok!(StakeCollective::vote(Origin::signed(1), hash.clone(), 0, false));
+			assert_noop!(
+				StakeCollective::close(Origin::signed(4), hash.clone(), 0, proposal_weight, proposal_len),
+				Error::<Test, Instance3>::TooEarly
+			);
+			assert_ok!(StakeCollective::abstain(Origin::signed(3), hash.clone(), 0));
+			assert_ok!(StakeCollective::close(Origin::signed(4), hash.clone(), 0, proposal_weight, proposal_len));
+
+			let record = |event| EventRecord { phase: Phase::Initialization, event, topics: vec![] };
+			assert_eq!(System::events(), vec![
+				record(Event::collective_Instance3(RawEvent::Proposed(4, 0, hash.clone(), 2))),
+				record(Event::collective_Instance3(RawEvent::Voted(1, hash.clone(), false, 5, 50))),
+				record(Event::collective_Instance3(RawEvent::Abstained(3, hash.clone(), 5, 50, 15))),
+				record(Event::collective_Instance3(RawEvent::Closed(hash.clone(), 5, 50))),
+				record(Event::collective_Instance3(RawEvent::Disapproved(hash.clone()))),
+			]);
+		});
+	}
+
+	#[test]
+	fn quorum_is_required_for_approval() {
+		new_test_ext().execute_with(|| {
+			let proposal = make_proposal(42);
+			let proposal_len: u32 = proposal.using_encoded(|p| p.len() as u32);
+			let proposal_weight = proposal.get_dispatch_info().weight;
+			let hash: H256 = proposal.blake2_256().into();
+			Prime::<Test, Instance3>::set(Some(3));
+			assert_ok!(StakeCollective::propose(Origin::signed(4), 2, Box::new(proposal.clone()), proposal_len));
+			assert_ok!(StakeCollective::vote(Origin::signed(3), hash.clone(), 0, true));
+
+			// The prime's default would carry the motion, but only 20% of the weight turned out.
+			System::set_block_number(4);
+			assert_ok!(StakeCollective::close(Origin::signed(4), hash.clone(), 0, proposal_weight, proposal_len));
+
+			let record = |event| EventRecord { phase: Phase::Initialization, event, topics: vec![] };
+			assert_eq!(System::events(), vec![
+				record(Event::collective_Instance3(RawEvent::Proposed(4, 0, hash.clone(), 2))),
+				record(Event::collective_Instance3(RawEvent::Voted(3, hash.clone(), true, 20, 0))),
+				record(Event::collective_Instance3(RawEvent::Closed(hash.clone(), 100, 0))),
+				record(Event::collective_Instance3(RawEvent::Disapproved(hash.clone()))),
+			]);
+		});
+	}
+
+	#[test]
+	fn abstentions_count_towards_quorum() {
+		new_test_ext().execute_with(|| {
+			let proposal = make_proposal(42);
+			let proposal_len: u32 = proposal.using_encoded(|p| p.len() as u32);
+			let proposal_weight = proposal.get_dispatch_info().weight;
+			let hash: H256 = proposal.blake2_256().into();
+			Prime::<Test, Instance3>::set(Some(3));
+			assert_ok!(StakeCollective::propose(Origin::signed(4), 2, Box::new(proposal.clone()), proposal_len));
+			assert_ok!(StakeCollective::vote(Origin::signed(3), hash.clone(), 0, true));
+			assert_ok!(StakeCollective::abstain(Origin::signed(1), hash.clone(), 0));
+
+			// 70% turned out; 2 follows the prime's aye, which makes exactly half of the weight.
+			System::set_block_number(4);
+			assert_ok!(StakeCollective::close(Origin::signed(4), hash.clone(), 0, proposal_weight, proposal_len));
+
+			let record = |event| EventRecord { phase: Phase::Initialization, event, topics: vec![] };
+			assert_eq!(System::events(), vec![
+				record(Event::collective_Instance3(RawEvent::Proposed(4, 0, hash.clone(), 2))),
+				record(Event::collective_Instance3(RawEvent::Voted(3, hash.clone(), true, 20, 0))),
+				record(Event::collective_Instance3(RawEvent::Abstained(1, hash.clone(), 20, 0, 50))),
+				record(Event::collective_Instance3(RawEvent::Closed(hash.clone(), 50, 0))),
+				record(Event::collective_Instance3(RawEvent::Approved(hash.clone()))),
+				record(Event::collective_Instance3(RawEvent::Executed(hash.clone(), Err(DispatchError::BadOrigin))))
+			]);
+		});
+	}
+
+	#[test]
+	fn direct_execution_requires_weight_and_quorum() {
+		new_test_ext().execute_with(|| {
+			let proposal = make_proposal(42);
+			let proposal_len: u32 = proposal.using_encoded(|p| p.len() as u32);
+			let hash: H256 = proposal.blake2_256().into();
+			// 30% of the weight meets a threshold of one seat, but not the quorum.
+			assert_noop!(
+				StakeCollective::propose(Origin::signed(2), 1, Box::new(proposal.clone()), proposal_len),
+				Error::<Test, Instance3>::InsufficientVoteWeight
+			);
+			assert_ok!(StakeCollective::propose(Origin::signed(1), 1, Box::new(proposal.clone()), proposal_len));
+
+			let record = |event| EventRecord { phase: Phase::Initialization, event, topics: vec![] };
+			assert_eq!(System::events(), vec![
+				record(Event::collective_Instance3(RawEvent::Executed(hash.clone(), Err(DispatchError::BadOrigin)))),
+			]);
+		});
+	}
+
+	#[test]
+	fn votes_are_weighed_when_closed() {
+		new_test_ext().execute_with(|| {
+			let proposal = make_proposal(42);
+			let proposal_len: u32 = proposal.using_encoded(|p| p.len() as u32);
+			let proposal_weight = proposal.get_dispatch_info().weight;
+			let hash: H256 = proposal.blake2_256().into();
+			assert_ok!(StakeCollective::propose(Origin::signed(4), 3, Box::new(proposal.clone()), proposal_len));
+			assert_ok!(StakeCollective::vote(Origin::signed(1), hash.clone(), 0, true));
+
+			// Member 1 moves some of its weight to member 3, who votes with it again.
+			MemberWeights::set(&vec![(1, 35), (2, 30), (3, 30), (4, 5)]);
+			assert_ok!(StakeCollective::vote(Origin::signed(3), hash.clone(), 0, true));
+			assert_eq!(StakeCollective::voting(&hash).map(|v| v.ayes), Some(vec![(4, 5), (1, 50), (3, 30)]));
+
+			// The moved weight is only counted once: 70 out of 100 doesn't meet the threshold.
+			assert_noop!(
+				StakeCollective::close(Origin::signed(4), hash.clone(), 0, proposal_weight, proposal_len),
+				Error::<Test, Instance3>::TooEarly
+			);
+
+			System::set_block_number(4);
+			System::reset_events();
+			assert_ok!(StakeCollective::close(Origin::signed(4), hash.clone(), 0, proposal_weight, proposal_len));
+			assert_eq!(System::events(), vec![
+				record(Event::collective_Instance3(RawEvent::Closed(hash.clone(), 70, 30))),
+				record(Event::collective_Instance3(RawEvent::Disapproved(hash.clone()))),
+			]);
+		});
+	}
+
+	#[test]
+	fn migrate_v0_to_v1_works() {
+		new_test_ext().execute_with(|| {
+			let hash = BlakeTwo256::hash_of(&make_proposal(42));
+			StorageVersion::<Instance1>::put(Releases::V0);
+			frame_support::migration::put_storage_value(
+				b"Instance1Collective",
+				b"Voting",
+				&hash.encode(),
+				(0 as ProposalIndex, 2 as MemberCount, vec![1u64], vec![2u64], 4u64),
+			);
+
+			Collective::migrate_v0_to_v1();
+
+			assert_eq!(StorageVersion::<Instance1>::get(), Releases::V1);
+			assert_eq!(
+				Collective::voting(&hash),
+				Some(Votes { index: 0, threshold: 2, ayes: vec![(1, 1)], nays: vec![(2, 1)], abstentions: vec![], end: 4 })
+			);
+		});
+	}
 }
@@ -22,6 +22,7 @@ use crate::{Trait, WeightInfo};
 #[cfg(feature = "std")]
 use serde::{Serialize, Deserialize};
 use frame_support::weights::Weight;
+use frame_support::type_info::TypeInfo;
 use sp_std::{marker::PhantomData, fmt};
 use codec::{Encode, Decode};
 
@@ -32,7 +33,8 @@ pub const API_BENCHMARK_BATCH_SIZE: u32 = 100;
 
 /// Definition of the cost schedule and other parameterizations for wasm vm.
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
-#[derive(Clone, Encode, Decode, PartialEq, Eq)]
+#[derive(Clone, Encode, Decode, PartialEq, Eq, TypeInfo)]
+#[scale_info(skip_type_params(T))]
 pub struct Schedule<T: Trait> {
 	/// Version of the schedule.
 	pub version: u32,
@@ -75,7 +77,7 @@ pub struct Schedule<T: Trait> {
 
 /// Describes the weight for all categories of supported wasm instructions.
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
-#[derive(Clone, Encode, Decode, PartialEq, Eq)]
+#[derive(Clone, Encode, Decode, PartialEq, Eq, TypeInfo)]
 pub struct InstructionWeights {
 	/// Weight of a growing memory by single page.
 	pub grow_mem: Weight,
@@ -86,7 +88,7 @@ pub struct InstructionWeights {
 
 /// Describes the weight for each imported function that a contract is allowed to call.
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
-#[derive(Clone, Encode, Decode, PartialEq, Eq)]
+#[derive(Clone, Encode, Decode, PartialEq, Eq, TypeInfo)]
 pub struct HostFnWeights {
 	/// Weight of calling `seal_caller`.
 	pub caller: Weight,
@@ -24,6 +24,7 @@ use crate::gas::GasMeter;
 
 use sp_std::prelude::*;
 use codec::{Encode, Decode};
+use frame_support::type_info::TypeInfo;
 use sp_sandbox;
 
 #[macro_use]
@@ -41,7 +42,7 @@ pub use self::code_cache::save_raw as save_code_raw;
 pub use self::runtime::ReturnCode;
 
 /// A prepared wasm module ready for execution.
-#[derive(Clone, Encode, Decode)]
+#[derive(Clone, Encode, Decode, TypeInfo)]
 pub struct PrefabWasmModule {
 	/// Version of the schedule with which the code was instrumented.
 	#[codec(compact)]
@@ -84,6 +84,18 @@ fn add_referendum<T: Trait>(n: u32) -> Result<ReferendumIndex, &'static str> {
 	Ok(referendum_index)
 }
 
+fn add_secret_referendum<T: Trait>(n: u32) -> Result<ReferendumIndex, &'static str> {
+	let proposal_hash: T::Hash = T::Hashing::hash_of(&n);
+	let referendum_index = Democracy::<T>::internal_start_referendum(
+		proposal_hash,
+		VoteThreshold::SimpleMajority,
+		0.into(),
+	);
+	let origin = T::SecretBallotOrigin::successful_origin();
+	Call::<T>::enable_secret_ballot(referendum_index).dispatch_bypass_filter(origin)?;
+	Ok(referendum_index)
+}
+
 fn account_vote<T: Trait>(b: BalanceOf<T>) -> AccountVote<BalanceOf<T>> {
 	let v = Vote {
 		aye: true,
@@ -576,6 +588,7 @@ benchmarks! {
 		let proposal_hash = T::Hashing::hash(&encoded_proposal[..]);
 		let block_number = T::BlockNumber::one();
 		Preimages::<T>::insert(&proposal_hash, PreimageStatus::Missing(block_number));
+		T::Preimages::request_preimage(&proposal_hash);
 
 		let caller = funded_account::<T>("caller", 0);
 		let encoded_proposal = vec![1; b as usize];
@@ -611,6 +624,7 @@ benchmarks! {
 	verify {
 		let proposal_hash = T::Hashing::hash(&encoded_proposal[..]);
 		assert!(!Preimages::<T>::contains_key(proposal_hash));
+		assert!(!T::Preimages::have_preimage(&proposal_hash));
 	}
 
 	// Test when unlock will remove locks
@@ -738,6 +752,105 @@ benchmarks! {
 		assert_eq!(votes.len(), (r - 1) as usize, "Vote was not removed");
 	}
 
+	enable_secret_ballot {
+		let origin = T::SecretBallotOrigin::successful_origin();
+		let proposal_hash: T::Hash = T::Hashing::hash_of(&0);
+		let referendum_index = Democracy::<T>::internal_start_referendum(
+			proposal_hash,
+			VoteThreshold::SimpleMajority,
+			0.into(),
+		);
+		let call = Call::<T>::enable_secret_ballot(referendum_index);
+	}: { call.dispatch_bypass_filter(origin)? }
+	verify {
+		assert!(Democracy::<T>::secret_ballot(referendum_index), "Secret ballot not enabled");
+	}
+
+	commit_vote {
+		let r in 1 .. MAX_REFERENDUMS;
+
+		let caller = funded_account::<T>("caller", 0);
+		let account_vote = account_vote::<T>(100.into());
+
+		// We need to create existing direct votes
+		for i in 0 .. r {
+			let ref_idx = add_referendum::<T>(i)?;
+			Democracy::<T>::vote(RawOrigin::Signed(caller.clone()).into(), ref_idx, account_vote.clone())?;
+		}
+
+		let referendum_index = add_secret_referendum::<T>(r)?;
+		let commitment = T::Hashing::hash_of(&(account_vote, [0u8; 32]));
+		whitelist_account!(caller);
+	}: _(RawOrigin::Signed(caller.clone()), referendum_index, commitment, 100.into())
+	verify {
+		assert!(
+			Democracy::<T>::vote_commitment(referendum_index, &caller).is_some(),
+			"Commitment was not recorded",
+		);
+	}
+
+	reveal_vote {
+		let r in 1 .. MAX_REFERENDUMS;
+
+		let caller = funded_account::<T>("caller", 0);
+		let account_vote = account_vote::<T>(100.into());
+
+		// We need to create existing direct votes
+		for i in 0 .. r {
+			let ref_idx = add_referendum::<T>(i)?;
+			Democracy::<T>::vote(RawOrigin::Signed(caller.clone()).into(), ref_idx, account_vote.clone())?;
+		}
+
+		let referendum_index = add_secret_referendum::<T>(r)?;
+		let salt = [0u8; 32];
+		let commitment = T::Hashing::hash_of(&(account_vote, salt));
+		Democracy::<T>::commit_vote(
+			RawOrigin::Signed(caller.clone()).into(),
+			referendum_index,
+			commitment,
+			100.into(),
+		)?;
+
+		// Move into the reveal period.
+		let status = Democracy::<T>::referendum_status(referendum_index)?;
+		System::<T>::set_block_number(Democracy::<T>::reveal_start(&status));
+		whitelist_account!(caller);
+	}: _(RawOrigin::Signed(caller.clone()), referendum_index, account_vote, salt)
+	verify {
+		let votes = match VotingOf::<T>::get(&caller) {
+			Voting::Direct { votes, .. } => votes,
+			_ => return Err("Votes are not direct"),
+		};
+		assert_eq!(votes.len(), (r + 1) as usize, "Vote was not revealed");
+	}
+
+	reap_commitment {
+		let caller = funded_account::<T>("caller", 0);
+		let account_vote = account_vote::<T>(100.into());
+		let referendum_index = add_secret_referendum::<T>(0)?;
+		let commitment = T::Hashing::hash_of(&(account_vote, [0u8; 32]));
+		Democracy::<T>::commit_vote(
+			RawOrigin::Signed(caller.clone()).into(),
+			referendum_index,
+			commitment,
+			100.into(),
+		)?;
+
+		// The referendum finishes without the vote being revealed, so the deposit is slashed.
+		ReferendumInfoOf::<T>::insert(
+			referendum_index,
+			ReferendumInfo::Finished { end: 0.into(), approved: false },
+		);
+		let reaper = funded_account::<T>("reaper", 0);
+		whitelist_account!(reaper);
+	}: _(RawOrigin::Signed(reaper), referendum_index, caller.clone())
+	verify {
+		assert!(
+			Democracy::<T>::vote_commitment(referendum_index, &caller).is_none(),
+			"Commitment was not removed",
+		);
+	}
+
 	#[extra]
 	enact_proposal_execute {
 		// Num of bytes in encoded proposal
@@ -817,6 +930,10 @@ mod tests {
 			assert_ok!(test_benchmark_unlock_set::<Test>());
 			assert_ok!(test_benchmark_remove_vote::<Test>());
 			assert_ok!(test_benchmark_remove_other_vote::<Test>());
+			assert_ok!(test_benchmark_enable_secret_ballot::<Test>());
+			assert_ok!(test_benchmark_commit_vote::<Test>());
+			assert_ok!(test_benchmark_reveal_vote::<Test>());
+			assert_ok!(test_benchmark_reap_commitment::<Test>());
 			assert_ok!(test_benchmark_enact_proposal_execute::<Test>());
 			assert_ok!(test_benchmark_enact_proposal_slash::<Test>());
 			assert_ok!(test_benchmark_blacklist::<Test>());
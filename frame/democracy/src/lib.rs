@@ -83,6 +83,10 @@
 //!   requires a matching deposit to the original.
 //! - `vote` - Votes in a referendum, either the vote is "Aye" to enact the proposal or "Nay" to
 //!   keep the status quo.
+//! - `commit_vote` - Commits to a hidden vote in a secret-ballot referendum, locking the
+//!   committed balance and reserving a deposit.
+//! - `reveal_vote` - Reveals a previously committed vote during the reveal period, counting it
+//!   towards the tally and returning the deposit.
 //! - `unvote` - Cancel a previous vote, this must be done by the voter before the vote ends.
 //! - `delegate` - Delegates the voting power (tokens * conviction) to another account.
 //! - `undelegate` - Stops the delegation of voting power to another account.
@@ -90,6 +94,7 @@
 //! Administration actions that can be done to any account:
 //! - `reap_vote` - Remove some account's expired votes.
 //! - `unlock` - Redetermine the account's balance lock, potentially making tokens available.
+//! - `reap_commitment` - Remove an unrevealed vote commitment once its referendum is over.
 //!
 //! Preimage actions:
 //! - `note_preimage` - Registers the preimage for an upcoming proposal, requires
@@ -102,6 +107,10 @@
 //!   work under the condition that it's the same account that noted it and
 //!   after the voting period, OR it's a different account after the enactment period.
 //!
+//! The bytes of preimages are stored through `T::Preimages`, usually the Preimage module, which
+//! is asked to keep each preimage that this module knows of until it is enacted or reaped. This
+//! module only keeps track of who provided a preimage and the deposit they placed for it.
+//!
 //! #### Cancellation Origin
 //!
 //! This call can only be made by the `CancellationOrigin`.
@@ -109,6 +118,13 @@
 //! - `emergency_cancel` - Schedules an emergency cancellation of a referendum.
 //!   Can only happen once to a specific referendum.
 //!
+//! #### Secret Ballot Origin
+//!
+//! This call can only be made by the `SecretBallotOrigin`.
+//!
+//! - `enable_secret_ballot` - Switches a referendum which has not yet received any votes into
+//!   commit-reveal mode.
+//!
 //! #### ExternalOrigin
 //!
 //! This call can only be made by the `ExternalOrigin`.
@@ -157,15 +173,17 @@ use sp_runtime::{
 	DispatchResult, DispatchError, RuntimeDebug,
 	traits::{Zero, Hash, Dispatchable, Saturating, Bounded},
 };
-use codec::{Encode, Decode, Input};
+use codec::{Encode, Decode};
 use frame_support::{
 	decl_module, decl_storage, decl_event, decl_error, ensure, Parameter,
 	weights::{Weight, DispatchClass, Pays},
 	traits::{
 		Currency, ReservableCurrency, LockableCurrency, WithdrawReason, LockIdentifier, Get,
-		OnUnbalanced, BalanceStatus, schedule::{Named as ScheduleNamed, DispatchTime}, EnsureOrigin
+		OnUnbalanced, BalanceStatus, schedule::{Named as ScheduleNamed, DispatchTime}, EnsureOrigin,
+		PreimageRecipient,
 	},
 	dispatch::DispatchResultWithPostInfo,
+	type_info::TypeInfo,
 };
 use frame_system::{self as system, ensure_signed, ensure_root};
 
@@ -175,7 +193,7 @@ mod conviction;
 mod types;
 mod default_weight;
 pub use vote_threshold::{Approved, VoteThreshold};
-pub use vote::{Vote, AccountVote, Voting};
+pub use vote::{Vote, AccountVote, Voting, PriorLock};
 pub use conviction::Conviction;
 pub use types::{ReferendumInfo, ReferendumStatus, Tally, UnvoteScope, Delegations};
 
@@ -228,6 +246,10 @@ pub trait WeightInfo {
 	fn unlock_set(r: u32, ) -> Weight;
 	fn remove_vote(r: u32, ) -> Weight;
 	fn remove_other_vote(r: u32, ) -> Weight;
+	fn enable_secret_ballot() -> Weight;
+	fn commit_vote(r: u32, ) -> Weight;
+	fn reveal_vote(r: u32, ) -> Weight;
+	fn reap_commitment() -> Weight;
 }
 
 pub trait Trait: frame_system::Trait + Sized {
@@ -251,6 +273,14 @@ pub trait Trait: frame_system::Trait + Sized {
 	/// How often (in blocks) to check for new votes.
 	type VotingPeriod: Get<Self::BlockNumber>;
 
+	/// The number of blocks at the end of a secret-ballot referendum's voting period during which
+	/// committed votes may be revealed. Commitments are only accepted before this window opens.
+	type RevealPeriod: Get<Self::BlockNumber>;
+
+	/// The deposit reserved for a vote commitment in a secret-ballot referendum, returned when the
+	/// vote is revealed.
+	type CommitmentDeposit: Get<BalanceOf<Self>>;
+
 	/// The minimum amount to be used as a deposit for a public referendum proposal.
 	type MinimumDeposit: Get<BalanceOf<Self>>;
 
@@ -290,6 +320,9 @@ pub trait Trait: frame_system::Trait + Sized {
 	/// Origin from which proposals may be blacklisted.
 	type BlacklistOrigin: EnsureOrigin<Self::Origin>;
 
+	/// Origin from which a referendum may be switched to a commit-reveal secret ballot.
+	type SecretBallotOrigin: EnsureOrigin<Self::Origin>;
+
 	/// Origin from which a proposal may be cancelled and its backers slashed.
 	type CancelProposalOrigin: EnsureOrigin<Self::Origin>;
 
@@ -307,6 +340,9 @@ pub trait Trait: frame_system::Trait + Sized {
 	/// The amount of balance that must be deposited per byte of preimage stored.
 	type PreimageByteDeposit: Get<BalanceOf<Self>>;
 
+	/// The preimage provider, where the bytes of proposal preimages are stored.
+	type Preimages: PreimageRecipient<Self::Hash>;
+
 	/// An origin that can provide a preimage using operational extrinsics.
 	type OperationalPreimageOrigin: EnsureOrigin<Self::Origin, Success=Self::AccountId>;
 
@@ -332,13 +368,12 @@ pub trait Trait: frame_system::Trait + Sized {
 	type MaxProposals: Get<u32>;
 }
 
-#[derive(Clone, Encode, Decode, RuntimeDebug)]
+#[derive(Clone, Encode, Decode, RuntimeDebug, TypeInfo)]
 pub enum PreimageStatus<AccountId, Balance, BlockNumber> {
 	/// The preimage is imminently needed at the argument.
 	Missing(BlockNumber),
-	/// The preimage is available.
+	/// The preimage is available through `Trait::Preimages`.
 	Available {
-		data: Vec<u8>,
 		provider: AccountId,
 		deposit: Balance,
 		since: BlockNumber,
@@ -356,12 +391,27 @@ impl<AccountId, Balance, BlockNumber> PreimageStatus<AccountId, Balance, BlockNu
 	}
 }
 
+// The format of `Preimages` values before the bytes of preimages were moved to
+// `Trait::Preimages`.
+#[derive(Clone, Encode, Decode, RuntimeDebug)]
+enum PreimageStatusV1<AccountId, Balance, BlockNumber> {
+	Missing(BlockNumber),
+	Available {
+		data: Vec<u8>,
+		provider: AccountId,
+		deposit: Balance,
+		since: BlockNumber,
+		expiry: Option<BlockNumber>,
+	},
+}
+
 // A value placed in storage that represents the current version of the Democracy storage.
 // This value is used by the `on_runtime_upgrade` logic to determine whether we run
 // storage migration logic.
-#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug)]
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo)]
 enum Releases {
 	V1,
+	V2,
 }
 
 decl_storage! {
@@ -378,10 +428,9 @@ decl_storage! {
 		pub DepositOf get(fn deposit_of):
 			map hasher(twox_64_concat) PropIndex => Option<(Vec<T::AccountId>, BalanceOf<T>)>;
 
-		/// Map of hashes to the proposal preimage, along with who registered it and their deposit.
-		/// The block number is the block at which it was deposited.
-		// TODO: Refactor Preimages into its own pallet.
-		// https://github.com/paritytech/substrate/issues/5322
+		/// Map of hashes to the status of the proposal preimage, along with who registered it and
+		/// their deposit. The block number is the block at which it was deposited. The preimage
+		/// of every hash in this map is requested from `T::Preimages`.
 		pub Preimages:
 			map hasher(identity) T::Hash
 			=> Option<PreimageStatus<T::AccountId, BalanceOf<T>, T::BlockNumber>>;
@@ -430,10 +479,25 @@ decl_storage! {
 		/// Record of all proposals that have been subject to emergency cancellation.
 		pub Cancellations: map hasher(identity) T::Hash => bool;
 
+		/// Referenda which are being voted on by secret ballot.
+		///
+		/// TWOX-NOTE: SAFE as indexes are not under an attacker’s control.
+		pub SecretBallots get(fn secret_ballot): map hasher(twox_64_concat) ReferendumIndex => bool;
+
+		/// The unrevealed vote commitments for secret-ballot referenda, along with the balance
+		/// locked by each commitment and its deposit. Commitments which are never revealed are
+		/// removed with `reap_commitment` once their referendum is over.
+		///
+		/// TWOX-NOTE: SAFE as indexes are not under an attacker’s control and `AccountId`s are
+		/// crypto hashes anyway.
+		pub VoteCommitments get(fn vote_commitment):
+			double_map hasher(twox_64_concat) ReferendumIndex, hasher(twox_64_concat) T::AccountId
+			=> Option<(T::Hash, BalanceOf<T>, BalanceOf<T>)>;
+
 		/// Storage version of the pallet.
 		///
 		/// New networks start with last version.
-		StorageVersion build(|_| Some(Releases::V1)): Option<Releases>;
+		StorageVersion build(|_| Some(Releases::V2)): Option<Releases>;
 	}
 }
 
@@ -484,6 +548,14 @@ decl_event! {
 		Unlocked(AccountId),
 		/// A proposal \[hash\] has been blacklisted permanently.
 		Blacklisted(Hash),
+		/// A referendum will be decided by secret ballot. \[ref_index\]
+		SecretBallotEnabled(ReferendumIndex),
+		/// An account has committed to a hidden vote. \[who, ref_index\]
+		VoteCommitted(AccountId, ReferendumIndex),
+		/// An account has revealed its committed vote. \[who, ref_index\]
+		VoteRevealed(AccountId, ReferendumIndex),
+		/// An unrevealed vote commitment has been removed. \[who, ref_index\]
+		CommitmentReaped(AccountId, ReferendumIndex),
 	}
 }
 
@@ -560,6 +632,26 @@ decl_error! {
 		InvalidWitness,
 		/// Maximum number of proposals reached.
 		TooManyProposals,
+		/// The referendum is decided by secret ballot; votes must be committed and revealed.
+		SecretBallot,
+		/// The referendum is not decided by secret ballot.
+		NotSecretBallot,
+		/// The referendum is already decided by secret ballot.
+		AlreadySecret,
+		/// The referendum has already received votes.
+		AlreadyVoted,
+		/// The period for committing votes is over.
+		CommitPeriodOver,
+		/// The referendum is not in its reveal period.
+		NotRevealPeriod,
+		/// The account has no vote commitment for the referendum.
+		NoCommitment,
+		/// The revealed vote and salt do not match the commitment.
+		BadReveal,
+		/// The revealed vote uses more balance than was committed.
+		ExceedsCommitment,
+		/// The referendum is still ongoing.
+		ReferendumOngoing,
 	}
 }
 
@@ -580,6 +672,13 @@ decl_module! {
 		/// How often (in blocks) to check for new votes.
 		const VotingPeriod: T::BlockNumber = T::VotingPeriod::get();
 
+		/// The number of blocks at the end of a secret-ballot referendum during which votes are
+		/// revealed.
+		const RevealPeriod: T::BlockNumber = T::RevealPeriod::get();
+
+		/// The deposit reserved for a vote commitment in a secret-ballot referendum.
+		const CommitmentDeposit: BalanceOf<T> = T::CommitmentDeposit::get();
+
 		/// The minimum amount to be used as a deposit for a public referendum proposal.
 		const MinimumDeposit: BalanceOf<T> = T::MinimumDeposit::get();
 
@@ -597,6 +696,15 @@ decl_module! {
 
 		fn deposit_event() = default;
 
+		fn on_runtime_upgrade() -> Weight {
+			if StorageVersion::get() == Some(Releases::V1) {
+				StorageVersion::put(Releases::V2);
+				Self::migrate_preimages()
+			} else {
+				0
+			}
+		}
+
 		/// Propose a sensitive action to be taken.
 		///
 		/// The dispatch origin of this call must be _Signed_ and the sender must
@@ -677,6 +785,7 @@ decl_module! {
 			vote: AccountVote<BalanceOf<T>>,
 		) -> DispatchResult {
 			let who = ensure_signed(origin)?;
+			ensure!(!Self::secret_ballot(ref_index), Error::<T>::SecretBallot);
 			Self::try_vote(&who, ref_index, vote)
 		}
 
@@ -1019,18 +1128,16 @@ decl_module! {
 		fn reap_preimage(origin, proposal_hash: T::Hash, #[compact] proposal_len_upper_bound: u32) {
 			let who = ensure_signed(origin)?;
 
-			ensure!(
-				Self::pre_image_data_len(proposal_hash)? <= proposal_len_upper_bound,
-				Error::<T>::WrongUpperBound,
-			);
-
 			let (provider, deposit, since, expiry) = <Preimages<T>>::get(&proposal_hash)
 				.and_then(|m| match m {
-					PreimageStatus::Available { provider, deposit, since, expiry, .. }
+					PreimageStatus::Available { provider, deposit, since, expiry }
 						=> Some((provider, deposit, since, expiry)),
 					_ => None,
 				}).ok_or(Error::<T>::PreimageMissing)?;
 
+			let len = T::Preimages::get_preimage(&proposal_hash).map_or(0, |data| data.len());
+			ensure!(len <= proposal_len_upper_bound as usize, Error::<T>::WrongUpperBound);
+
 			let now = <frame_system::Module<T>>::block_number();
 			let (voting, enactment) = (T::VotingPeriod::get(), T::EnactmentPeriod::get());
 			let additional = if who == provider { Zero::zero() } else { enactment };
@@ -1039,6 +1146,7 @@ decl_module! {
 
 			let _ = T::Currency::repatriate_reserved(&provider, &who, deposit, BalanceStatus::Free);
 			<Preimages<T>>::remove(&proposal_hash);
+			T::Preimages::unrequest_preimage(&proposal_hash);
 			Self::deposit_event(RawEvent::PreimageReaped(proposal_hash, provider, deposit, who));
 		}
 
@@ -1192,6 +1300,161 @@ decl_module! {
 				}
 			}
 		}
+
+		/// Switch a referendum to be decided by secret ballot. Voters must then `commit_vote`
+		/// before the reveal period and `reveal_vote` during it; committed votes count as
+		/// abstentions until they are revealed.
+		///
+		/// The dispatch origin of this call must be `SecretBallotOrigin`.
+		///
+		/// - `ref_index`: The index of the referendum. It must not have received any votes yet.
+		///
+		/// Weight: `O(1)`.
+		#[weight = T::WeightInfo::enable_secret_ballot()]
+		fn enable_secret_ballot(origin, #[compact] ref_index: ReferendumIndex) {
+			T::SecretBallotOrigin::ensure_origin(origin)?;
+
+			let status = Self::referendum_status(ref_index)?;
+			ensure!(!Self::secret_ballot(ref_index), Error::<T>::AlreadySecret);
+			ensure!(status.tally == Default::default(), Error::<T>::AlreadyVoted);
+			let now = system::Module::<T>::block_number();
+			ensure!(now < Self::reveal_start(&status), Error::<T>::CommitPeriodOver);
+
+			SecretBallots::insert(ref_index, true);
+			Self::deposit_event(RawEvent::SecretBallotEnabled(ref_index));
+		}
+
+		/// Commit to a hidden vote in a secret-ballot referendum.
+		///
+		/// `balance` is locked until the end of the referendum, as it would be for a vote that is
+		/// never revealed, and counts towards the turnout as an abstention until the vote is
+		/// revealed. Committing again before the reveal period replaces the commitment.
+		///
+		/// A deposit of `CommitmentDeposit` is reserved from the sender for the first commitment.
+		/// It is returned when the vote is revealed, and slashed if it never is.
+		///
+		/// The dispatch origin of this call must be _Signed_.
+		///
+		/// - `ref_index`: The index of the referendum to vote for.
+		/// - `commitment`: The hash of the encoded `(vote, salt)` pair which will be revealed.
+		/// - `balance`: The balance to lock; it bounds the balance of the revealed vote.
+		///
+		/// Weight: `O(R)` where R is the number of referendums the voter has voted on.
+		#[weight = T::WeightInfo::commit_vote(T::MaxVotes::get())]
+		fn commit_vote(origin,
+			#[compact] ref_index: ReferendumIndex,
+			commitment: T::Hash,
+			#[compact] balance: BalanceOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let mut status = Self::referendum_status(ref_index)?;
+			ensure!(Self::secret_ballot(ref_index), Error::<T>::NotSecretBallot);
+			let now = system::Module::<T>::block_number();
+			ensure!(now < Self::reveal_start(&status), Error::<T>::CommitPeriodOver);
+
+			let mut voting = VotingOf::<T>::get(&who);
+			let prior = match voting {
+				Voting::Direct { ref mut prior, .. } => prior,
+				Voting::Delegating { .. } => Err(Error::<T>::AlreadyDelegating)?,
+			};
+			let previous = Self::vote_commitment(ref_index, &who);
+			// The deposit is only taken for the first commitment, and the committed balance must
+			// remain free besides it so that the vote can be revealed.
+			let new_deposit = match previous {
+				Some(_) => Zero::zero(),
+				None => T::CommitmentDeposit::get(),
+			};
+			ensure!(
+				balance.saturating_add(new_deposit) <= T::Currency::free_balance(&who),
+				Error::<T>::InsufficientFunds,
+			);
+			// The committed balance abstains until the vote is revealed.
+			status.tally.turnout = status.tally.turnout
+				.saturating_sub(previous.map_or_else(Zero::zero, |(_, committed, _)| committed))
+				.checked_add(&balance)
+				.ok_or(Error::<T>::Overflow)?;
+			T::Currency::reserve(&who, new_deposit)?;
+			let deposit = previous.map_or(new_deposit, |(_, _, deposit)| deposit);
+
+			prior.accumulate(status.end, balance);
+			VotingOf::<T>::insert(&who, voting);
+			ReferendumInfoOf::<T>::insert(ref_index, ReferendumInfo::Ongoing(status));
+			T::Currency::extend_lock(DEMOCRACY_ID, &who, balance, WithdrawReason::Transfer.into());
+			VoteCommitments::<T>::insert(ref_index, &who, (commitment, balance, deposit));
+
+			Self::deposit_event(RawEvent::VoteCommitted(who, ref_index));
+			Ok(())
+		}
+
+		/// Reveal a vote previously committed with `commit_vote`, counting it towards the tally
+		/// in place of the committed abstention.
+		///
+		/// The dispatch origin of this call must be _Signed_ and must have a commitment for the
+		/// referendum. The deposit of the commitment is returned.
+		///
+		/// - `ref_index`: The index of the referendum.
+		/// - `vote`: The committed vote. Its balance may not exceed the committed balance.
+		/// - `salt`: The salt used to compute the commitment.
+		///
+		/// Weight: `O(R)` where R is the number of referendums the voter has voted on.
+		#[weight = T::WeightInfo::reveal_vote(T::MaxVotes::get())]
+		fn reveal_vote(origin,
+			#[compact] ref_index: ReferendumIndex,
+			vote: AccountVote<BalanceOf<T>>,
+			salt: [u8; 32],
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let status = Self::referendum_status(ref_index)?;
+			let now = system::Module::<T>::block_number();
+			ensure!(now >= Self::reveal_start(&status), Error::<T>::NotRevealPeriod);
+			let (commitment, committed, deposit) = Self::vote_commitment(ref_index, &who)
+				.ok_or(Error::<T>::NoCommitment)?;
+			ensure!(T::Hashing::hash_of(&(vote, salt)) == commitment, Error::<T>::BadReveal);
+			ensure!(vote.balance() <= committed, Error::<T>::ExceedsCommitment);
+
+			Self::try_vote(&who, ref_index, vote)?;
+			VoteCommitments::<T>::remove(ref_index, &who);
+			// The revealed vote is counted now, so the committed balance no longer abstains.
+			ReferendumInfoOf::<T>::mutate(ref_index, |info| {
+				if let Some(ReferendumInfo::Ongoing(status)) = info {
+					status.tally.turnout = status.tally.turnout.saturating_sub(committed);
+				}
+			});
+			T::Currency::unreserve(&who, deposit);
+
+			Self::deposit_event(RawEvent::VoteRevealed(who, ref_index));
+			Ok(())
+		}
+
+		/// Remove a vote commitment for a referendum which is no longer ongoing.
+		///
+		/// The dispatch origin of this call must be _Signed_. It may be any account.
+		///
+		/// - `ref_index`: The index of the referendum.
+		/// - `who`: The account which committed to a vote.
+		///
+		/// The deposit of the commitment is slashed if the referendum finished without the vote
+		/// being revealed, and returned if the referendum was cancelled.
+		///
+		/// Weight: `O(1)`.
+		#[weight = T::WeightInfo::reap_commitment()]
+		fn reap_commitment(origin, #[compact] ref_index: ReferendumIndex, who: T::AccountId) {
+			ensure_signed(origin)?;
+			let info = ReferendumInfoOf::<T>::get(ref_index);
+			ensure!(
+				!matches!(info, Some(ReferendumInfo::Ongoing(_))),
+				Error::<T>::ReferendumOngoing,
+			);
+			let (_, _, deposit) = VoteCommitments::<T>::take(ref_index, &who)
+				.ok_or(Error::<T>::NoCommitment)?;
+			match info {
+				Some(ReferendumInfo::Finished { .. }) =>
+					T::Slash::on_unbalanced(T::Currency::slash_reserved(&who, deposit).0),
+				_ => { T::Currency::unreserve(&who, deposit); },
+			}
+
+			Self::deposit_event(RawEvent::CommitmentReaped(who, ref_index));
+		}
 	}
 }
 
@@ -1247,10 +1510,24 @@ impl<T: Trait> Module<T> {
 	pub fn internal_cancel_referendum(ref_index: ReferendumIndex) {
 		Self::deposit_event(RawEvent::Cancelled(ref_index));
 		ReferendumInfoOf::<T>::remove(ref_index);
+		Self::clear_secret_ballot(ref_index);
 	}
 
 	// private.
 
+	/// The first block of the reveal period of a secret-ballot referendum.
+	fn reveal_start(status: &ReferendumStatus<T::BlockNumber, T::Hash, BalanceOf<T>>) -> T::BlockNumber {
+		status.end.saturating_sub(T::RevealPeriod::get())
+	}
+
+	/// Remove the secret-ballot flag of a referendum which is no longer ongoing.
+	///
+	/// Unrevealed commitments are left for `reap_commitment`, so that clearing them doesn't have
+	/// to be bounded here: they counted as abstentions and their locks expire with the referendum.
+	fn clear_secret_ballot(ref_index: ReferendumIndex) {
+		SecretBallots::remove(ref_index);
+	}
+
 	/// Ok if the given referendum is active, Err otherwise
 	fn ensure_ongoing(r: ReferendumInfo<T::BlockNumber, T::Hash, BalanceOf<T>>)
 		-> Result<ReferendumStatus<T::BlockNumber, T::Hash, BalanceOf<T>>, DispatchError>
@@ -1565,24 +1842,40 @@ impl<T: Trait> Module<T> {
 	}
 
 	fn do_enact_proposal(proposal_hash: T::Hash, index: ReferendumIndex) -> DispatchResult {
-		let preimage = <Preimages<T>>::take(&proposal_hash);
-		if let Some(PreimageStatus::Available { data, provider, deposit, .. }) = preimage {
-			if let Ok(proposal) = T::Proposal::decode(&mut &data[..]) {
+		let status = <Preimages<T>>::take(&proposal_hash);
+		let data = T::Preimages::get_preimage(&proposal_hash);
+		if status.is_some() {
+			T::Preimages::unrequest_preimage(&proposal_hash);
+		}
+		let provider = match status {
+			Some(PreimageStatus::Available { provider, deposit, .. }) => Some((provider, deposit)),
+			_ => None,
+		};
+
+		let data = match data {
+			Some(data) => data,
+			None => {
+				Self::deposit_event(RawEvent::PreimageMissing(proposal_hash, index));
+				return Err(Error::<T>::PreimageMissing.into())
+			}
+		};
+
+		if let Ok(proposal) = T::Proposal::decode(&mut &data[..]) {
+			if let Some((provider, deposit)) = provider {
 				let _ = T::Currency::unreserve(&provider, deposit);
 				Self::deposit_event(RawEvent::PreimageUsed(proposal_hash, provider, deposit));
+			}
 
-				let ok = proposal.dispatch(frame_system::RawOrigin::Root.into()).is_ok();
-				Self::deposit_event(RawEvent::Executed(index, ok));
+			let ok = proposal.dispatch(frame_system::RawOrigin::Root.into()).is_ok();
+			Self::deposit_event(RawEvent::Executed(index, ok));
 
-				Ok(())
-			} else {
+			Ok(())
+		} else {
+			if let Some((provider, deposit)) = provider {
 				T::Slash::on_unbalanced(T::Currency::slash_reserved(&provider, deposit).0);
-				Self::deposit_event(RawEvent::PreimageInvalid(proposal_hash, index));
-				Err(Error::<T>::PreimageInvalid.into())
 			}
-		} else {
-			Self::deposit_event(RawEvent::PreimageMissing(proposal_hash, index));
-			Err(Error::<T>::PreimageMissing.into())
+			Self::deposit_event(RawEvent::PreimageInvalid(proposal_hash, index));
+			Err(Error::<T>::PreimageInvalid.into())
 		}
 	}
 
@@ -1603,7 +1896,11 @@ impl<T: Trait> Module<T> {
 				// Note that we need the preimage now.
 				Preimages::<T>::mutate_exists(&status.proposal_hash, |maybe_pre| match *maybe_pre {
 					Some(PreimageStatus::Available { ref mut expiry, .. }) => *expiry = Some(when),
-					ref mut a => *a = Some(PreimageStatus::Missing(when)),
+					Some(PreimageStatus::Missing(ref mut expiry)) => *expiry = when,
+					ref mut a => {
+						T::Preimages::request_preimage(&status.proposal_hash);
+						*a = Some(PreimageStatus::Missing(when));
+					},
 				});
 
 				if T::Scheduler::schedule_named(
@@ -1654,6 +1951,7 @@ impl<T: Trait> Module<T> {
 		for (index, info) in Self::maturing_referenda_at_inner(now, next..last).into_iter() {
 			let approved = Self::bake_referendum(now, index, info)?;
 			ReferendumInfoOf::<T>::insert(index, ReferendumInfo::Finished { end: now, approved });
+			Self::clear_secret_ballot(index);
 			weight = T::MaximumBlockWeight::get();
 		}
 
@@ -1669,70 +1967,6 @@ impl<T: Trait> Module<T> {
 		decode_compact_u32_at(&<DepositOf<T>>::hashed_key_for(proposal))
 	}
 
-	/// Check that pre image exists and its value is variant `PreimageStatus::Missing`.
-	///
-	/// This check is done without getting the complete value in the runtime to avoid copying a big
-	/// value in the runtime.
-	fn check_pre_image_is_missing(proposal_hash: T::Hash) -> DispatchResult {
-		// To decode the enum variant we only need the first byte.
-		let mut buf = [0u8; 1];
-		let key = <Preimages<T>>::hashed_key_for(proposal_hash);
-		let bytes = match sp_io::storage::read(&key, &mut buf, 0) {
-			Some(bytes) => bytes,
-			None => return Err(Error::<T>::NotImminent.into()),
-		};
-		// The value may be smaller that 1 byte.
-		let mut input = &buf[0..buf.len().min(bytes as usize)];
-
-		match input.read_byte() {
-			Ok(0) => Ok(()), // PreimageStatus::Missing is variant 0
-			Ok(1) => Err(Error::<T>::DuplicatePreimage.into()),
-			_ => {
-				sp_runtime::print("Failed to decode `PreimageStatus` variant");
-				Err(Error::<T>::NotImminent.into())
-			}
-		}
-	}
-
-	/// Check that pre image exists, its value is variant `PreimageStatus::Available` and decode
-	/// the length of `data: Vec<u8>` fields.
-	///
-	/// This check is done without getting the complete value in the runtime to avoid copying a big
-	/// value in the runtime.
-	///
-	/// If the pre image is missing variant or doesn't exist then the error `PreimageMissing` is
-	/// returned.
-	fn pre_image_data_len(proposal_hash: T::Hash) -> Result<u32, DispatchError> {
-		// To decode the `data` field of Available variant we need:
-		// * one byte for the variant
-		// * at most 5 bytes to decode a `Compact<u32>`
-		let mut buf = [0u8; 6];
-		let key = <Preimages<T>>::hashed_key_for(proposal_hash);
-		let bytes = match sp_io::storage::read(&key, &mut buf, 0) {
-			Some(bytes) => bytes,
-			None => return Err(Error::<T>::PreimageMissing.into()),
-		};
-		// The value may be smaller that 6 bytes.
-		let mut input = &buf[0..buf.len().min(bytes as usize)];
-
-		match input.read_byte() {
-			Ok(1) => (), // Check that input exists and is second variant.
-			Ok(0) => return Err(Error::<T>::PreimageMissing.into()),
-			_ => {
-				sp_runtime::print("Failed to decode `PreimageStatus` variant");
-				return Err(Error::<T>::PreimageMissing.into());
-			}
-		}
-
-		// Decode the length of the vector.
-		let len = codec::Compact::<u32>::decode(&mut input).map_err(|_| {
-			sp_runtime::print("Failed to decode `PreimageStatus` variant");
-			DispatchError::from(Error::<T>::PreimageMissing)
-		})?.0;
-
-		Ok(len)
-	}
-
 	// See `note_preimage`
 	fn note_preimage_inner(who: T::AccountId, encoded_proposal: Vec<u8>) -> DispatchResult {
 		let proposal_hash = T::Hashing::hash(&encoded_proposal[..]);
@@ -1742,9 +1976,16 @@ impl<T: Trait> Module<T> {
 			.saturating_mul(T::PreimageByteDeposit::get());
 		T::Currency::reserve(&who, deposit)?;
 
+		if !T::Preimages::have_preimage(&proposal_hash) {
+			if let Err(e) = T::Preimages::note_preimage(encoded_proposal) {
+				T::Currency::unreserve(&who, deposit);
+				return Err(e)
+			}
+		}
+		T::Preimages::request_preimage(&proposal_hash);
+
 		let now = <frame_system::Module<T>>::block_number();
 		let a = PreimageStatus::Available {
-			data: encoded_proposal,
 			provider: who.clone(),
 			deposit,
 			since: now,
@@ -1760,14 +2001,16 @@ impl<T: Trait> Module<T> {
 	// See `note_imminent_preimage`
 	fn note_imminent_preimage_inner(who: T::AccountId, encoded_proposal: Vec<u8>) -> DispatchResult {
 		let proposal_hash = T::Hashing::hash(&encoded_proposal[..]);
-		Self::check_pre_image_is_missing(proposal_hash)?;
 		let status = Preimages::<T>::get(&proposal_hash).ok_or(Error::<T>::NotImminent)?;
 		let expiry = status.to_missing_expiry().ok_or(Error::<T>::DuplicatePreimage)?;
+		ensure!(!T::Preimages::have_preimage(&proposal_hash), Error::<T>::DuplicatePreimage);
+
+		// The preimage is already requested, so it is noted without a deposit.
+		T::Preimages::note_preimage(encoded_proposal)?;
 
 		let now = <frame_system::Module<T>>::block_number();
 		let free = <BalanceOf<T>>::zero();
 		let a = PreimageStatus::Available {
-			data: encoded_proposal,
 			provider: who.clone(),
 			deposit: Zero::zero(),
 			since: now,
@@ -1779,6 +2022,32 @@ impl<T: Trait> Module<T> {
 
 		Ok(())
 	}
+
+	/// Migration code for moving the bytes of preimages to `T::Preimages`.
+	///
+	/// Before this migration, `Preimages` held the bytes of every available preimage. This
+	/// function notes them in `T::Preimages` instead and requests the preimage of every hash
+	/// which is known to this module. A preimage which cannot be noted, such as one which is
+	/// larger than `T::Preimages` allows, is dropped and its deposit returned.
+	pub fn migrate_preimages() -> Weight {
+		Preimages::<T>::translate::<PreimageStatusV1<T::AccountId, BalanceOf<T>, T::BlockNumber>, _>(
+			|hash, status| match status {
+				PreimageStatusV1::Missing(expiry) => {
+					T::Preimages::request_preimage(&hash);
+					Some(PreimageStatus::Missing(expiry))
+				},
+				PreimageStatusV1::Available { data, provider, deposit, since, expiry } => {
+					if !T::Preimages::have_preimage(&hash) && T::Preimages::note_preimage(data).is_err() {
+						T::Currency::unreserve(&provider, deposit);
+						return None
+					}
+					T::Preimages::request_preimage(&hash);
+					Some(PreimageStatus::Available { provider, deposit, since, expiry })
+				},
+			}
+		);
+		T::MaximumBlockWeight::get()
+	}
 }
 
 /// Decode `Compact<u32>` from the trie at given key.
@@ -22,7 +22,7 @@ use std::cell::RefCell;
 use codec::Encode;
 use frame_support::{
 	impl_outer_origin, impl_outer_dispatch, assert_noop, assert_ok, parameter_types,
-	impl_outer_event, ord_parameter_types, traits::{Contains, OnInitialize, Filter},
+	impl_outer_event, ord_parameter_types, traits::{Contains, OnInitialize, Filter, PreimageProvider},
 	weights::Weight,
 };
 use sp_core::H256;
@@ -41,6 +41,7 @@ mod lock_voting;
 mod preimage;
 mod public_proposals;
 mod scheduling;
+mod secret_ballot;
 mod voting;
 mod decoders;
 
@@ -72,6 +73,7 @@ impl_outer_event! {
 		system<T>,
 		pallet_balances<T>,
 		pallet_scheduler<T>,
+		pallet_preimage<T>,
 		democracy<T>,
 	}
 }
@@ -131,6 +133,8 @@ impl pallet_scheduler::Trait for Test {
 	type MaximumWeight = MaximumSchedulerWeight;
 	type ScheduleOrigin = EnsureRoot<u64>;
 	type MaxScheduledPerBlock = ();
+	type PreimageProvider = Preimage;
+	type NoPreimagePostponement = ();
 	type WeightInfo = ();
 }
 parameter_types! {
@@ -138,6 +142,8 @@ parameter_types! {
 }
 impl pallet_balances::Trait for Test {
 	type MaxLocks = ();
+	type MaxReserves = ();
+	type ReserveIdentifier = [u8; 8];
 	type Balance = u64;
 	type Event = Event;
 	type DustRemoval = ();
@@ -145,9 +151,23 @@ impl pallet_balances::Trait for Test {
 	type AccountStore = System;
 	type WeightInfo = ();
 }
+parameter_types! {
+	pub const PreimageMaxSize: u32 = 4096 * 1024;
+}
+impl pallet_preimage::Trait for Test {
+	type Event = Event;
+	type Currency = Balances;
+	type ManagerOrigin = EnsureRoot<u64>;
+	type MaxSize = PreimageMaxSize;
+	type BaseDeposit = ();
+	type ByteDeposit = ();
+	type WeightInfo = ();
+}
 parameter_types! {
 	pub const LaunchPeriod: u64 = 2;
 	pub const VotingPeriod: u64 = 2;
+	pub const RevealPeriod: u64 = 1;
+	pub const CommitmentDeposit: u64 = 1;
 	pub const FastTrackVotingPeriod: u64 = 2;
 	pub const MinimumDeposit: u64 = 1;
 	pub const EnactmentPeriod: u64 = 2;
@@ -190,6 +210,8 @@ impl super::Trait for Test {
 	type EnactmentPeriod = EnactmentPeriod;
 	type LaunchPeriod = LaunchPeriod;
 	type VotingPeriod = VotingPeriod;
+	type RevealPeriod = RevealPeriod;
+	type CommitmentDeposit = CommitmentDeposit;
 	type FastTrackVotingPeriod = FastTrackVotingPeriod;
 	type MinimumDeposit = MinimumDeposit;
 	type ExternalOrigin = EnsureSignedBy<Two, u64>;
@@ -198,10 +220,12 @@ impl super::Trait for Test {
 	type FastTrackOrigin = EnsureSignedBy<Five, u64>;
 	type CancellationOrigin = EnsureSignedBy<Four, u64>;
 	type BlacklistOrigin = EnsureRoot<u64>;
+	type SecretBallotOrigin = EnsureRoot<u64>;
 	type CancelProposalOrigin = EnsureRoot<u64>;
 	type VetoOrigin = EnsureSignedBy<OneToFive, u64>;
 	type CooloffPeriod = CooloffPeriod;
 	type PreimageByteDeposit = PreimageByteDeposit;
+	type Preimages = Preimage;
 	type Slash = ();
 	type InstantOrigin = EnsureSignedBy<Six, u64>;
 	type InstantAllowed = InstantAllowed;
@@ -233,6 +257,7 @@ pub fn new_test_ext_execute_with_cond(execute: impl FnOnce(bool) -> () + Clone)
 type System = frame_system::Module<Test>;
 type Balances = pallet_balances::Module<Test>;
 type Scheduler = pallet_scheduler::Module<Test>;
+type Preimage = pallet_preimage::Module<Test>;
 type Democracy = Module<Test>;
 
 #[test]
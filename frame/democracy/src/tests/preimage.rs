@@ -195,3 +195,92 @@ fn note_imminent_preimage_can_only_be_successful_once() {
 		);
 	});
 }
+
+#[test]
+fn preimage_is_kept_by_preimage_provider_until_enacted() {
+	new_test_ext().execute_with(|| {
+		let h = set_balance_proposal_hash_and_note(2);
+		assert_eq!(Preimage::get_preimage(&h), Some(set_balance_proposal(2)));
+		assert!(Preimage::preimage_requested(&h));
+
+		let r = Democracy::inject_referendum(2, h, VoteThreshold::SuperMajorityApprove, 1);
+		assert_ok!(Democracy::vote(Origin::signed(1), r, aye(1)));
+		next_block();
+		next_block();
+
+		assert_eq!(Balances::free_balance(42), 2);
+		assert!(!Preimages::<Test>::contains_key(h));
+		assert!(!Preimage::have_preimage(&h));
+		assert!(!Preimage::preimage_requested(&h));
+	});
+}
+
+#[test]
+fn preimage_noted_in_preimage_provider_can_be_enacted() {
+	new_test_ext().execute_with(|| {
+		let h = set_balance_proposal_hash(2);
+		let r = Democracy::inject_referendum(2, h, VoteThreshold::SuperMajorityApprove, 1);
+		assert_ok!(Democracy::vote(Origin::signed(1), r, aye(1)));
+		next_block();
+		assert!(matches!(Preimages::<Test>::get(h), Some(PreimageStatus::Missing(3))));
+		assert!(Preimage::preimage_requested(&h));
+
+		// Noting the requested preimage directly is just as good.
+		assert_ok!(Preimage::note_preimage(Origin::signed(6), set_balance_proposal(2)));
+		assert_noop!(
+			Democracy::note_imminent_preimage(Origin::signed(6), set_balance_proposal(2)),
+			Error::<Test>::DuplicatePreimage
+		);
+		next_block();
+
+		assert_eq!(Balances::free_balance(42), 2);
+		assert!(!Preimage::have_preimage(&h));
+	});
+}
+
+#[test]
+fn migration_moves_preimages_to_preimage_provider() {
+	new_test_ext().execute_with(|| {
+		use frame_support::traits::OnRuntimeUpgrade;
+
+		let available = set_balance_proposal(2);
+		let available_hash = set_balance_proposal_hash(2);
+		let missing_hash = set_balance_proposal_hash(3);
+		let too_large = vec![0u8; PreimageMaxSize::get() as usize + 1];
+		let too_large_hash = BlakeTwo256::hash(&too_large[..]);
+		assert_ok!(Balances::reserve(&6, 12));
+		assert_ok!(Balances::reserve(&5, 20));
+
+		let old: Vec<(H256, PreimageStatusV1<u64, u64, u64>)> = vec![
+			(available_hash, PreimageStatusV1::Available {
+				data: available.clone(), provider: 6, deposit: 12, since: 1, expiry: None,
+			}),
+			(missing_hash, PreimageStatusV1::Missing(5)),
+			(too_large_hash, PreimageStatusV1::Available {
+				data: too_large, provider: 5, deposit: 20, since: 1, expiry: Some(5),
+			}),
+		];
+		for (hash, status) in old {
+			frame_support::migration::put_storage_value(b"Democracy", b"Preimages", &hash.encode(), status);
+		}
+		StorageVersion::put(Releases::V1);
+
+		Democracy::on_runtime_upgrade();
+		assert_eq!(StorageVersion::get(), Some(Releases::V2));
+
+		assert!(matches!(
+			Preimages::<Test>::get(available_hash),
+			Some(PreimageStatus::Available { provider: 6, deposit: 12, since: 1, expiry: None })
+		));
+		assert_eq!(Preimage::get_preimage(&available_hash), Some(available));
+		assert!(Preimage::preimage_requested(&available_hash));
+		assert_eq!(Balances::reserved_balance(6), 12);
+
+		assert!(matches!(Preimages::<Test>::get(missing_hash), Some(PreimageStatus::Missing(5))));
+		assert!(Preimage::preimage_requested(&missing_hash));
+
+		assert!(Preimages::<Test>::get(too_large_hash).is_none());
+		assert!(!Preimage::preimage_requested(&too_large_hash));
+		assert_eq!(Balances::reserved_balance(5), 0);
+	});
+}
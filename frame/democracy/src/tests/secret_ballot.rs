@@ -0,0 +1,254 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2017-2020 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The tests for commit-reveal secret ballots.
+
+use super::*;
+
+const SALT: [u8; 32] = [7; 32];
+
+fn commitment(vote: AccountVote<u64>, salt: [u8; 32]) -> H256 {
+	BlakeTwo256::hash_of(&(vote, salt))
+}
+
+fn standard(aye: bool, balance: u64) -> AccountVote<u64> {
+	AccountVote::Standard { vote: if aye { BIG_AYE } else { BIG_NAY }, balance }
+}
+
+fn begin_secret_referendum() -> ReferendumIndex {
+	let r = begin_referendum();
+	assert_ok!(Democracy::enable_secret_ballot(Origin::root(), r));
+	r
+}
+
+fn the_lock(amount: u64) -> BalanceLock<u64> {
+	BalanceLock {
+		id: DEMOCRACY_ID,
+		amount,
+		reasons: pallet_balances::Reasons::Misc,
+	}
+}
+
+#[test]
+fn enable_secret_ballot_requires_origin_and_no_votes() {
+	new_test_ext().execute_with(|| {
+		let r = begin_referendum();
+		assert_noop!(Democracy::enable_secret_ballot(Origin::signed(1), r), BadOrigin);
+		assert_ok!(Democracy::vote(Origin::signed(1), r, aye(1)));
+		assert_noop!(
+			Democracy::enable_secret_ballot(Origin::root(), r),
+			Error::<Test>::AlreadyVoted,
+		);
+		assert_noop!(
+			Democracy::enable_secret_ballot(Origin::root(), r + 1),
+			Error::<Test>::ReferendumInvalid,
+		);
+	});
+}
+
+#[test]
+fn enable_secret_ballot_twice_or_late_should_fail() {
+	new_test_ext().execute_with(|| {
+		let r = begin_secret_referendum();
+		assert!(Democracy::secret_ballot(r));
+		assert_noop!(
+			Democracy::enable_secret_ballot(Origin::root(), r),
+			Error::<Test>::AlreadySecret,
+		);
+
+		assert_ok!(propose_set_balance_and_note(1, 3, 1));
+		// Referendum 1 runs from block 4 until block 6; block 5 opens its reveal period.
+		fast_forward_to(5);
+		assert_noop!(
+			Democracy::enable_secret_ballot(Origin::root(), 1),
+			Error::<Test>::CommitPeriodOver,
+		);
+	});
+}
+
+#[test]
+fn public_vote_on_secret_ballot_should_fail() {
+	new_test_ext().execute_with(|| {
+		let r = begin_secret_referendum();
+		assert_noop!(Democracy::vote(Origin::signed(1), r, aye(1)), Error::<Test>::SecretBallot);
+	});
+}
+
+#[test]
+fn commit_on_public_referendum_should_fail() {
+	new_test_ext().execute_with(|| {
+		let r = begin_referendum();
+		assert_noop!(
+			Democracy::commit_vote(Origin::signed(1), r, commitment(aye(1), SALT), 10),
+			Error::<Test>::NotSecretBallot,
+		);
+	});
+}
+
+#[test]
+fn commit_and_reveal_should_work() {
+	new_test_ext().execute_with(|| {
+		let r = begin_secret_referendum();
+		// The committed balance must stay free besides the deposit.
+		assert_noop!(
+			Democracy::commit_vote(Origin::signed(1), r, commitment(standard(true, 10), SALT), 10),
+			Error::<Test>::InsufficientFunds,
+		);
+		assert_ok!(Democracy::commit_vote(Origin::signed(1), r, commitment(standard(true, 9), SALT), 9));
+		assert_ok!(Democracy::commit_vote(Origin::signed(2), r, commitment(standard(false, 19), SALT), 19));
+
+		// Committed votes are locked and count as abstentions.
+		assert_eq!(Balances::locks(1), vec![the_lock(9)]);
+		assert_eq!(Balances::locks(2), vec![the_lock(19)]);
+		assert_eq!(Balances::reserved_balance(1), 1);
+		assert_eq!(tally(r), Tally { ayes: 0, nays: 0, turnout: 28 });
+
+		// Nothing can be revealed until the reveal period.
+		assert_noop!(
+			Democracy::reveal_vote(Origin::signed(1), r, standard(true, 9), SALT),
+			Error::<Test>::NotRevealPeriod,
+		);
+
+		next_block();
+		assert_noop!(
+			Democracy::commit_vote(Origin::signed(3), r, commitment(aye(3), SALT), 20),
+			Error::<Test>::CommitPeriodOver,
+		);
+		assert_noop!(
+			Democracy::reveal_vote(Origin::signed(3), r, aye(3), SALT),
+			Error::<Test>::NoCommitment,
+		);
+		assert_noop!(
+			Democracy::reveal_vote(Origin::signed(2), r, standard(true, 19), SALT),
+			Error::<Test>::BadReveal,
+		);
+		assert_noop!(
+			Democracy::reveal_vote(Origin::signed(2), r, standard(false, 19), [0; 32]),
+			Error::<Test>::BadReveal,
+		);
+
+		assert_ok!(Democracy::reveal_vote(Origin::signed(1), r, standard(true, 9), SALT));
+		assert_eq!(tally(r), Tally { ayes: 9, nays: 0, turnout: 28 });
+		assert_ok!(Democracy::reveal_vote(Origin::signed(2), r, standard(false, 19), SALT));
+		assert_eq!(tally(r), Tally { ayes: 9, nays: 19, turnout: 28 });
+		assert_eq!(Democracy::vote_commitment(r, 1), None);
+		// The deposit is returned on reveal.
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert_noop!(
+			Democracy::reveal_vote(Origin::signed(1), r, standard(true, 9), SALT),
+			Error::<Test>::NoCommitment,
+		);
+
+		next_block();
+		assert_eq!(
+			Democracy::referendum_info(r),
+			Some(ReferendumInfo::Finished { end: 4, approved: false }),
+		);
+	});
+}
+
+#[test]
+fn recommitting_replaces_commitment() {
+	new_test_ext().execute_with(|| {
+		let r = begin_secret_referendum();
+		assert_ok!(Democracy::commit_vote(Origin::signed(1), r, commitment(standard(false, 9), SALT), 9));
+		assert_ok!(Democracy::commit_vote(Origin::signed(1), r, commitment(standard(true, 5), SALT), 5));
+		// The deposit is only taken once, and only the latest commitment abstains.
+		assert_eq!(Balances::reserved_balance(1), 1);
+		assert_eq!(tally(r), Tally { ayes: 0, nays: 0, turnout: 5 });
+
+		next_block();
+		assert_noop!(
+			Democracy::reveal_vote(Origin::signed(1), r, standard(false, 9), SALT),
+			Error::<Test>::BadReveal,
+		);
+		assert_ok!(Democracy::reveal_vote(Origin::signed(1), r, standard(true, 5), SALT));
+		assert_eq!(tally(r), Tally { ayes: 5, nays: 0, turnout: 5 });
+		assert_eq!(Balances::reserved_balance(1), 0);
+	});
+}
+
+#[test]
+fn revealed_vote_cannot_exceed_commitment() {
+	new_test_ext().execute_with(|| {
+		let r = begin_secret_referendum();
+		assert_ok!(Democracy::commit_vote(Origin::signed(1), r, commitment(standard(true, 9), SALT), 5));
+		assert_eq!(Balances::locks(1), vec![the_lock(5)]);
+
+		next_block();
+		assert_noop!(
+			Democracy::reveal_vote(Origin::signed(1), r, standard(true, 9), SALT),
+			Error::<Test>::ExceedsCommitment,
+		);
+	});
+}
+
+#[test]
+fn unrevealed_vote_is_abstention_and_deposit_is_slashed() {
+	new_test_ext().execute_with(|| {
+		let r = begin_secret_referendum();
+		assert_ok!(Democracy::commit_vote(Origin::signed(1), r, commitment(standard(false, 9), SALT), 9));
+		assert_ok!(Democracy::commit_vote(Origin::signed(2), r, commitment(standard(true, 19), SALT), 19));
+
+		next_block();
+		assert_ok!(Democracy::reveal_vote(Origin::signed(2), r, standard(true, 19), SALT));
+		assert_eq!(tally(r), Tally { ayes: 19, nays: 0, turnout: 28 });
+
+		// The lock stays in place for as long as the referendum is running.
+		assert_ok!(Democracy::unlock(Origin::signed(1), 1));
+		assert_eq!(Balances::locks(1), vec![the_lock(9)]);
+		assert_noop!(
+			Democracy::reap_commitment(Origin::signed(3), r, 1),
+			Error::<Test>::ReferendumOngoing,
+		);
+
+		next_block();
+		assert_eq!(
+			Democracy::referendum_info(r),
+			Some(ReferendumInfo::Finished { end: 4, approved: true }),
+		);
+		assert!(!Democracy::secret_ballot(r));
+
+		// The unrevealed commitment is left to be reaped, losing its deposit.
+		assert_noop!(
+			Democracy::reap_commitment(Origin::signed(3), r, 2),
+			Error::<Test>::NoCommitment,
+		);
+		assert_ok!(Democracy::reap_commitment(Origin::signed(3), r, 1));
+		assert_eq!(Democracy::vote_commitment(r, 1), None);
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert_eq!(Balances::free_balance(1), 9);
+
+		assert_ok!(Democracy::unlock(Origin::signed(2), 1));
+		assert_eq!(Balances::locks(1), vec![]);
+	});
+}
+
+#[test]
+fn cancelling_returns_commitment_deposit() {
+	new_test_ext().execute_with(|| {
+		let r = begin_secret_referendum();
+		assert_ok!(Democracy::commit_vote(Origin::signed(1), r, commitment(standard(true, 9), SALT), 9));
+		assert_ok!(Democracy::cancel_referendum(Origin::root(), r.into()));
+		assert!(!Democracy::secret_ballot(r));
+
+		assert_ok!(Democracy::reap_commitment(Origin::signed(3), r, 1));
+		assert_eq!(Democracy::vote_commitment(r, 1), None);
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert_eq!(Balances::free_balance(1), 10);
+	});
+}
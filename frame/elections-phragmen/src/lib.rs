@@ -95,6 +95,7 @@ use frame_support::{
 		WithdrawReason, WithdrawReasons,
 	},
 	weights::Weight,
+	type_info::TypeInfo,
 };
 use frame_system::{ensure_root, ensure_signed};
 use sp_npos_elections::{ElectionResult, ExtendedBalance};
@@ -116,7 +117,7 @@ type NegativeImbalanceOf<T> =
 	<<T as Trait>::Currency as Currency<<T as frame_system::Trait>::AccountId>>::NegativeImbalance;
 
 /// An indication that the renouncing account currently has which of the below roles.
-#[derive(Encode, Decode, Clone, PartialEq, RuntimeDebug)]
+#[derive(Encode, Decode, Clone, PartialEq, RuntimeDebug, TypeInfo)]
 pub enum Renouncing {
 	/// A member is renouncing.
 	Member,
@@ -127,7 +128,7 @@ pub enum Renouncing {
 }
 
 /// Information needed to prove the defunct-ness of a voter.
-#[derive(Encode, Decode, Clone, PartialEq, RuntimeDebug)]
+#[derive(Encode, Decode, Clone, PartialEq, RuntimeDebug, TypeInfo)]
 pub struct DefunctVoter<AccountId> {
 	/// the voter's who's being challenged for being defunct
 	pub who: AccountId,
@@ -1105,6 +1106,8 @@ mod tests {
 		type ExistentialDeposit = ExistentialDeposit;
 		type AccountStore = frame_system::Module<Test>;
 		type MaxLocks = ();
+		type MaxReserves = ();
+		type ReserveIdentifier = [u8; 8];
 		type WeightInfo = ();
 	}
 
@@ -71,6 +71,8 @@ parameter_types! {
 }
 impl pallet_balances::Trait for Test {
 	type MaxLocks = ();
+	type MaxReserves = ();
+	type ReserveIdentifier = [u8; 8];
 	type Balance = u64;
 	type DustRemoval = ();
 	type Event = Event;
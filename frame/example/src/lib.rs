@@ -775,6 +775,8 @@ mod tests {
 	}
 	impl pallet_balances::Trait for Test {
 		type MaxLocks = ();
+		type MaxReserves = ();
+		type ReserveIdentifier = [u8; 8];
 		type Balance = u64;
 		type DustRemoval = ();
 		type Event = ();
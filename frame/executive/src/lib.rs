@@ -118,7 +118,7 @@
 use sp_std::{prelude::*, marker::PhantomData};
 use frame_support::{
 	storage::StorageValue, weights::{GetDispatchInfo, DispatchInfo, DispatchClass},
-	traits::{OnInitialize, OnFinalize, OnRuntimeUpgrade, OffchainWorker},
+	traits::{OnInitialize, OnIdle, OnFinalize, OnRuntimeUpgrade, OffchainWorker},
 	dispatch::PostDispatchInfo,
 };
 use sp_runtime::{
@@ -150,6 +150,7 @@ pub type OriginOf<E, C> = <CallOf<E, C> as Dispatchable>::Origin;
 /// - `Context`: The context that is used when checking an extrinsic.
 /// - `UnsignedValidator`: The unsigned transaction validator of the runtime.
 /// - `AllModules`: Tuple that contains all modules. Will be used to call e.g. `on_initialize`.
+///                 `on_idle` is called on the modules in a rotating order, see [`OnIdle`].
 /// - `OnRuntimeUpgrade`: Custom logic that should be called after a runtime upgrade. Modules are
 ///                       already called by `AllModules`. It will be called before all modules will
 ///                       be called.
@@ -165,6 +166,7 @@ impl<
 	AllModules:
 		OnRuntimeUpgrade +
 		OnInitialize<System::BlockNumber> +
+		OnIdle<System::BlockNumber> +
 		OnFinalize<System::BlockNumber> +
 		OffchainWorker<System::BlockNumber>,
 	COnRuntimeUpgrade: OnRuntimeUpgrade,
@@ -192,6 +194,7 @@ impl<
 	AllModules:
 		OnRuntimeUpgrade +
 		OnInitialize<System::BlockNumber> +
+		OnIdle<System::BlockNumber> +
 		OnFinalize<System::BlockNumber> +
 		OffchainWorker<System::BlockNumber>,
 	COnRuntimeUpgrade: OnRuntimeUpgrade,
@@ -321,8 +324,7 @@ where
 
 		// post-extrinsics book-keeping
 		<frame_system::Module<System>>::note_finished_extrinsics();
-		<frame_system::Module<System> as OnFinalize<System::BlockNumber>>::on_finalize(block_number);
-		<AllModules as OnFinalize<System::BlockNumber>>::on_finalize(block_number);
+		Self::idle_and_finalize_hook(block_number);
 	}
 
 	/// Finalize the block - it is up the caller to ensure that all header fields are valid
@@ -332,14 +334,39 @@ where
 		sp_tracing::enter_span!( sp_tracing::Level::TRACE, "finalize_block" );
 		<frame_system::Module<System>>::note_finished_extrinsics();
 		let block_number = <frame_system::Module<System>>::block_number();
-		<frame_system::Module<System> as OnFinalize<System::BlockNumber>>::on_finalize(block_number);
-		<AllModules as OnFinalize<System::BlockNumber>>::on_finalize(block_number);
+		Self::idle_and_finalize_hook(block_number);
 
 		// set up extrinsics
 		<frame_system::Module<System>>::derive_extrinsics();
 		<frame_system::Module<System>>::finalize()
 	}
 
+	/// Give the weight left unused in the block to the `on_idle` hooks, then call the
+	/// `on_finalize` hooks.
+	fn idle_and_finalize_hook(block_number: NumberFor<Block>) {
+		let weight = <frame_system::Module<System>>::block_weight();
+		let max_weight = <System::MaximumBlockWeight as frame_support::traits::Get<_>>::get();
+		let mut remaining_weight = max_weight.saturating_sub(weight.total());
+
+		if remaining_weight > 0 {
+			// System is not part of `AllModules`, so we need to call this manually.
+			let mut used_weight = <frame_system::Module<System> as OnIdle<System::BlockNumber>>::on_idle(
+				block_number,
+				remaining_weight,
+			);
+			remaining_weight = remaining_weight.saturating_sub(used_weight);
+			used_weight = <AllModules as OnIdle<System::BlockNumber>>::on_idle(block_number, remaining_weight)
+				.saturating_add(used_weight);
+			<frame_system::Module<System>>::register_extra_weight_unchecked(
+				used_weight,
+				DispatchClass::Mandatory,
+			);
+		}
+
+		<frame_system::Module<System> as OnFinalize<System::BlockNumber>>::on_finalize(block_number);
+		<AllModules as OnFinalize<System::BlockNumber>>::on_finalize(block_number);
+	}
+
 	/// Apply extrinsic outside of the block execution function.
 	///
 	/// This doesn't attempt to validate anything regarding the block, but it builds a list of uxt
@@ -536,6 +563,11 @@ mod tests {
 					175
 				}
 
+				fn on_idle(n: T::BlockNumber, remaining_weight: Weight) -> Weight {
+					println!("on_idle({}, {})", n, remaining_weight);
+					super::ON_IDLE_WEIGHT.with(|w| *w.borrow()).min(remaining_weight)
+				}
+
 				fn on_finalize() {
 					println!("on_finalize(?)");
 				}
@@ -625,6 +657,8 @@ mod tests {
 		type ExistentialDeposit = ExistentialDeposit;
 		type AccountStore = System;
 		type MaxLocks = ();
+		type MaxReserves = ();
+		type ReserveIdentifier = [u8; 8];
 		type WeightInfo = ();
 	}
 
@@ -650,6 +684,8 @@ mod tests {
 	thread_local! {
 		pub static RUNTIME_VERSION: std::cell::RefCell<sp_version::RuntimeVersion> =
 			Default::default();
+		// The weight consumed by the `on_idle` hook of the custom test module.
+		pub static ON_IDLE_WEIGHT: std::cell::RefCell<Weight> = Default::default();
 	}
 
 	type SignedExtra = (
@@ -980,6 +1016,32 @@ mod tests {
 		})
 	}
 
+	#[test]
+	fn on_idle_weight_is_stored() {
+		new_test_ext(1).execute_with(|| {
+			ON_IDLE_WEIGHT.with(|w| *w.borrow_mut() = 300);
+			Executive::initialize_block(&Header::new_from_number(1));
+			Executive::idle_and_finalize_hook(1);
+			// Only the custom test module consumes weight in `on_idle`.
+			assert_eq!(<frame_system::Module<Runtime>>::block_weight().total(), 175 + 10 + 300);
+		})
+	}
+
+	#[test]
+	fn on_idle_is_limited_to_remaining_weight() {
+		new_test_ext(1).execute_with(|| {
+			ON_IDLE_WEIGHT.with(|w| *w.borrow_mut() = 300);
+			Executive::initialize_block(&Header::new_from_number(1));
+			<frame_system::Module<Runtime>>::register_extra_weight_unchecked(
+				1024 - (175 + 10) - 100,
+				DispatchClass::Normal,
+			);
+			Executive::idle_and_finalize_hook(1);
+			// The hook can't consume more than the weight left in the block.
+			assert_eq!(<frame_system::Module<Runtime>>::block_weight().total(), 1024);
+		})
+	}
+
 	#[test]
 	fn runtime_upgraded_should_work() {
 		new_test_ext(1).execute_with(|| {
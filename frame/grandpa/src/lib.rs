@@ -42,6 +42,7 @@ use fg_primitives::{
 use frame_support::{
 	decl_error, decl_event, decl_module, decl_storage, dispatch::DispatchResultWithPostInfo,
 	storage, traits::KeyOwnerProofSystem, weights::{Pays, Weight}, Parameter,
+	type_info::TypeInfo,
 };
 use frame_system::{ensure_none, ensure_root, ensure_signed};
 use sp_runtime::{
@@ -121,7 +122,7 @@ pub struct OldStoredPendingChange<N> {
 }
 
 /// A stored pending change.
-#[derive(Encode)]
+#[derive(Encode, TypeInfo)]
 pub struct StoredPendingChange<N> {
 	/// The block number this was scheduled at.
 	pub scheduled_at: N,
@@ -151,7 +152,7 @@ impl<N: Decode> Decode for StoredPendingChange<N> {
 /// Current state of the GRANDPA authority set. State transitions must happen in
 /// the same order of states defined below, e.g. `Paused` implies a prior
 /// `PendingPause`.
-#[derive(Decode, Encode)]
+#[derive(Decode, Encode, TypeInfo)]
 #[cfg_attr(test, derive(Debug, PartialEq))]
 pub enum StoredState<N> {
 	/// The current authority set is live, and GRANDPA is enabled.
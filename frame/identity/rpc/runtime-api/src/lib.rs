@@ -0,0 +1,47 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2020 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Runtime API definition for the identity module.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use sp_std::prelude::*;
+use codec::{Encode, Codec, Decode};
+use sp_runtime::RuntimeDebug;
+
+/// A field of an identity which a registrar has verified through a challenge.
+#[derive(Eq, PartialEq, Encode, Decode, Clone, RuntimeDebug)]
+pub struct VerifiedField<IdentityField, Data> {
+	/// The index of the registrar which verified the field.
+	pub registrar: u32,
+	/// The verified field.
+	pub field: IdentityField,
+	/// The content of the field, unchanged since it was verified.
+	pub content: Data,
+}
+
+sp_api::decl_runtime_apis! {
+	pub trait IdentityApi<AccountId, IdentityField, Data> where
+		AccountId: Codec,
+		IdentityField: Codec,
+		Data: Codec,
+	{
+		/// The fields of the identity of `who` which registrars have verified, and whose content
+		/// has not changed since, ordered by registrar.
+		fn verified_fields(who: AccountId) -> Vec<VerifiedField<IdentityField, Data>>;
+	}
+}
@@ -106,6 +106,28 @@ fn create_identity_info<T: Trait>(num_fields: u32) -> IdentityInfo {
 	return info
 }
 
+// Posts a challenge from the registrar `caller` of index `r` for each field of the identity of
+// `user` which can be verified through one, and answers it if `answer`.
+fn post_challenges<T: Trait>(caller: &T::AccountId, r: u32, user: &T::AccountId, answer: bool)
+	-> Result<(), &'static str>
+{
+	let user_lookup = <T::Lookup as StaticLookup>::unlookup(user.clone());
+	let challenge = T::Hashing::hash(b"secret");
+	for field in CHALLENGE_FIELDS.iter() {
+		Identity::<T>::post_challenge(
+			RawOrigin::Signed(caller.clone()).into(),
+			r,
+			user_lookup.clone(),
+			*field,
+			challenge,
+		)?;
+		if answer {
+			Identity::<T>::answer_challenge(RawOrigin::Signed(user.clone()).into(), r, *field, b"secret".to_vec())?;
+		}
+	}
+	Ok(())
+}
+
 benchmarks! {
 	// These are the common parameters along with their instancing.
 	_ {
@@ -315,10 +337,67 @@ benchmarks! {
 		};
 
 		Identity::<T>::add_registrar(RawOrigin::Root.into(), caller.clone())?;
+		Identity::<T>::set_fields(RawOrigin::Signed(caller.clone()).into(), r, IdentityFields(BitFlags::all()))?;
 		Identity::<T>::request_judgement(user_origin.clone(), r, 10.into())?;
+		// Every field is verified along with the judgement.
+		post_challenges::<T>(&caller, r, &user, true)?;
 	}: _(RawOrigin::Signed(caller), r, user_lookup, Judgement::Reasonable)
 	verify {
-		assert_last_event::<T>(Event::<T>::JudgementGiven(user, r).into())
+		assert_last_event::<T>(Event::<T>::JudgementGiven(user.clone(), r).into());
+		ensure!(
+			VerifiedFields::<T>::get(&user, r).len() == CHALLENGE_FIELDS.len(),
+			"Fields not verified."
+		);
+	}
+
+	post_challenge {
+		// The user
+		let user: T::AccountId = account("user", r, SEED);
+		let user_origin = <T as frame_system::Trait>::Origin::from(RawOrigin::Signed(user.clone()));
+		let user_lookup = <T::Lookup as StaticLookup>::unlookup(user.clone());
+		let _ = T::Currency::make_free_balance_be(&user, BalanceOf::<T>::max_value());
+
+		let caller: T::AccountId = whitelisted_caller();
+		let _ = T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value());
+
+		let r in 1 .. T::MaxRegistrars::get() - 1 => add_registrars::<T>(r)?;
+		// For this x, it's the user identity that gets the fields, not the caller.
+		let x in _ .. _ => {
+			let info = create_identity_info::<T>(x);
+			Identity::<T>::set_identity(user_origin.clone(), info)?;
+		};
+
+		Identity::<T>::add_registrar(RawOrigin::Root.into(), caller.clone())?;
+		Identity::<T>::set_fields(RawOrigin::Signed(caller.clone()).into(), r, IdentityFields(BitFlags::all()))?;
+		Identity::<T>::request_judgement(user_origin.clone(), r, 10.into())?;
+	}: _(RawOrigin::Signed(caller), r, user_lookup, IdentityField::Email, T::Hashing::hash(b"secret"))
+	verify {
+		assert_last_event::<T>(Event::<T>::ChallengePosted(user, r, IdentityField::Email).into())
+	}
+
+	answer_challenge {
+		// The user
+		let user: T::AccountId = account("user", r, SEED);
+		let user_origin = <T as frame_system::Trait>::Origin::from(RawOrigin::Signed(user.clone()));
+		let _ = T::Currency::make_free_balance_be(&user, BalanceOf::<T>::max_value());
+
+		let caller: T::AccountId = whitelisted_caller();
+		let _ = T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value());
+
+		let r in 1 .. T::MaxRegistrars::get() - 1 => add_registrars::<T>(r)?;
+		// For this x, it's the user identity that gets the fields, not the caller.
+		let x in _ .. _ => {
+			let info = create_identity_info::<T>(x);
+			Identity::<T>::set_identity(user_origin.clone(), info)?;
+		};
+
+		Identity::<T>::add_registrar(RawOrigin::Root.into(), caller.clone())?;
+		Identity::<T>::set_fields(RawOrigin::Signed(caller.clone()).into(), r, IdentityFields(BitFlags::all()))?;
+		Identity::<T>::request_judgement(user_origin.clone(), r, 10.into())?;
+		post_challenges::<T>(&caller, r, &user, false)?;
+	}: _(RawOrigin::Signed(user.clone()), r, IdentityField::Email, b"secret".to_vec())
+	verify {
+		assert_last_event::<T>(Event::<T>::ChallengeAnswered(user, r, IdentityField::Email).into())
 	}
 
 	kill_identity {
@@ -424,6 +503,8 @@ mod tests {
 			assert_ok!(test_benchmark_set_account_id::<Test>());
 			assert_ok!(test_benchmark_set_fields::<Test>());
 			assert_ok!(test_benchmark_provide_judgement::<Test>());
+			assert_ok!(test_benchmark_post_challenge::<Test>());
+			assert_ok!(test_benchmark_answer_challenge::<Test>());
 			assert_ok!(test_benchmark_kill_identity::<Test>());
 			assert_ok!(test_benchmark_add_sub::<Test>());
 			assert_ok!(test_benchmark_rename_sub::<Test>());
@@ -40,6 +40,19 @@
 //! The number of registrars should be limited, and the deposit made sufficiently large, to ensure
 //! no state-bloat attack is viable.
 //!
+//! ### Verification challenges
+//!
+//! Rather than just asserting a judgement, a registrar may verify that an account controls the
+//! handles given in the email, web, Twitter and Riot fields of its identity. Once the account has
+//! requested a judgement, the registrar sends it a secret through each field's channel and posts
+//! the hash of the secret on chain as a challenge. The account answers the challenge on chain with
+//! the secret. When the registrar then gives a positive judgement, the fields whose challenges were
+//! answered are recorded as verified, attached to the hash of their content. A field stays verified
+//! for as long as its content is unchanged, even though `set_identity` wipes the judgements.
+//!
+//! The fields of an account which are currently verified can be queried through the
+//! `IdentityApi` runtime API, see `pallet-identity-rpc-runtime-api`.
+//!
 //! ## Interface
 //!
 //! ### Dispatchable Functions
@@ -50,6 +63,7 @@
 //! * `clear_identity` - Remove an account's associated identity; the deposit is returned.
 //! * `request_judgement` - Request a judgement from a registrar, paying a fee.
 //! * `cancel_request` - Cancel the previous request for a judgement.
+//! * `answer_challenge` - Answer a registrar's verification challenge for a field.
 //!
 //! #### For general users with sub-identities
 //! * `set_subs` - Set the sub-accounts of an identity.
@@ -61,6 +75,7 @@
 //! #### For registrars
 //! * `set_fee` - Set the fee required to be paid for a judgement to be given by the registrar.
 //! * `set_fields` - Set the fields that a registrar cares about in their judgements.
+//! * `post_challenge` - Post a verification challenge for a field of an identity.
 //! * `provide_judgement` - Provide a judgement to an identity.
 //!
 //! #### For super-users
@@ -77,14 +92,16 @@ use sp_std::{fmt::Debug, ops::Add, iter::once};
 use enumflags2::BitFlags;
 use codec::{Encode, Decode};
 use sp_runtime::{DispatchError, RuntimeDebug, DispatchResult};
-use sp_runtime::traits::{StaticLookup, Zero, AppendZerosInput, Saturating};
+use sp_runtime::traits::{StaticLookup, Zero, AppendZerosInput, Saturating, Hash};
 use frame_support::{
 	decl_module, decl_event, decl_storage, ensure, decl_error,
 	dispatch::DispatchResultWithPostInfo,
 	traits::{Currency, ReservableCurrency, OnUnbalanced, Get, BalanceStatus, EnsureOrigin},
 	weights::Weight,
+	type_info::{Field, Path, Type, TypeDef, TypeInfo, Variant},
 };
 use frame_system::ensure_signed;
+pub use pallet_identity_rpc_runtime_api::VerifiedField;
 
 #[cfg(test)]
 mod tests;
@@ -110,6 +127,8 @@ pub trait WeightInfo {
 	fn set_account_id(r: u32, ) -> Weight;
 	fn set_fields(r: u32, ) -> Weight;
 	fn provide_judgement(r: u32, x: u32, ) -> Weight;
+	fn post_challenge(r: u32, x: u32, ) -> Weight;
+	fn answer_challenge(r: u32, x: u32, ) -> Weight;
 	fn kill_identity(r: u32, s: u32, x: u32, ) -> Weight;
 }
 
@@ -217,6 +236,54 @@ impl Encode for Data {
 }
 impl codec::EncodeLike for Data {}
 
+/// Describes `Raw` data of each length as its own variant, as the length is part of the leading
+/// byte.
+impl TypeInfo for Data {
+	type Identity = Self;
+
+	fn type_info() -> Type {
+		macro_rules! raw_variants {
+			( $( $len:literal ),* ) => {
+				vec![ $(
+					Variant::new(
+						concat!("Raw", $len),
+						vec![Field::new::<[u8; $len]>(
+							None,
+							Some(concat!("[u8; ", $len, "]")),
+							Vec::new(),
+						)],
+						$len + 1,
+						Vec::new(),
+					)
+				),* ]
+			}
+		}
+		let hash = |name, index| Variant::new(
+			name,
+			vec![Field::new::<[u8; 32]>(None, Some("[u8; 32]"), Vec::new())],
+			index,
+			Vec::new(),
+		);
+
+		let mut variants = vec![Variant::new("None", Vec::new(), 0, Vec::new())];
+		variants.extend(raw_variants!(
+			0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16,
+			17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32
+		));
+		variants.push(hash("BlakeTwo256", 34));
+		variants.push(hash("Sha256", 35));
+		variants.push(hash("Keccak256", 36));
+		variants.push(hash("ShaThree256", 37));
+
+		Type::new(
+			Path::new("Data", module_path!()),
+			Vec::new(),
+			TypeDef::Variant(variants),
+			Vec::new(),
+		)
+	}
+}
+
 impl Default for Data {
 	fn default() -> Self {
 		Self::None
@@ -230,7 +297,7 @@ pub type RegistrarIndex = u32;
 ///
 /// NOTE: Registrars may pay little attention to some fields. Registrars may want to make clear
 /// which fields their attestation is relevant for by off-chain means.
-#[derive(Copy, Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug)]
+#[derive(Copy, Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
 pub enum Judgement<
 	Balance: Encode + Decode + Copy + Clone + Debug + Eq + PartialEq
 > {
@@ -281,7 +348,7 @@ impl<
 /// The fields that we use to identify the owner of an account with. Each corresponds to a field
 /// in the `IdentityInfo` struct.
 #[repr(u64)]
-#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, BitFlags, RuntimeDebug)]
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, BitFlags, RuntimeDebug, TypeInfo)]
 pub enum IdentityField {
 	Display        = 0b0000000000000000000000000000000000000000000000000000000000000001,
 	Legal          = 0b0000000000000000000000000000000000000000000000000000000000000010,
@@ -309,12 +376,26 @@ impl Decode for IdentityFields {
 		Ok(Self(<BitFlags<IdentityField>>::from_bits(field as u64).map_err(|_| "invalid value")?))
 	}
 }
+impl TypeInfo for IdentityFields {
+	type Identity = Self;
+
+	fn type_info() -> Type {
+		Type::new(
+			Path::new("IdentityFields", module_path!()),
+			Vec::new(),
+			TypeDef::Composite(vec![
+				Field::new::<u64>(None, Some("BitFlags<IdentityField>"), Vec::new()),
+			]),
+			Vec::new(),
+		)
+	}
+}
 
 /// Information concerning the identity of the controller of an account.
 ///
 /// NOTE: This should be stored at the end of the storage item to facilitate the addition of extra
 /// fields in a backwards compatible way through a specialized `Decode` impl.
-#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug)]
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
 #[cfg_attr(test, derive(Default))]
 pub struct IdentityInfo {
 	/// Additional fields of the identity that are not catered for with the struct's explicit
@@ -366,7 +447,7 @@ pub struct IdentityInfo {
 ///
 /// NOTE: This is stored separately primarily to facilitate the addition of extra fields in a
 /// backwards compatible way through a specialized `Decode` impl.
-#[derive(Clone, Encode, Eq, PartialEq, RuntimeDebug)]
+#[derive(Clone, Encode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
 pub struct Registration<
 	Balance: Encode + Decode + Copy + Clone + Debug + Eq + PartialEq
 > {
@@ -400,8 +481,27 @@ impl<
 	}
 }
 
+/// The fields of an identity which a registrar can verify through a challenge, as they name a
+/// channel through which the registrar can send a secret to the controller of the account.
+const CHALLENGE_FIELDS: [IdentityField; 4] =
+	[IdentityField::Web, IdentityField::Riot, IdentityField::Email, IdentityField::Twitter];
+
+/// A challenge posted by a registrar to verify that an account controls the handle given in one
+/// of the fields of its identity.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct Challenge<Hash> {
+	/// The hash of the secret which the registrar sent through the channel named by the field.
+	pub challenge: Hash,
+
+	/// The hash of the content of the field when the challenge was posted.
+	pub content: Hash,
+
+	/// Whether the account has answered the challenge with the secret.
+	pub answered: bool,
+}
+
 /// Information concerning a registrar.
-#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug)]
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
 pub struct RegistrarInfo<
 	Balance: Encode + Decode + Clone + Debug + Eq + PartialEq,
 	AccountId: Encode + Decode + Clone + Debug + Eq + PartialEq
@@ -443,6 +543,22 @@ decl_storage! {
 		///
 		/// The index into this can be cast to `RegistrarIndex` to get a valid value.
 		pub Registrars get(fn registrars): Vec<Option<RegistrarInfo<BalanceOf<T>, T::AccountId>>>;
+
+		/// The verification challenges posted for the fields of an account's identity, keyed by
+		/// the registrar which posted them and the field.
+		///
+		/// TWOX-NOTE: OK ― `AccountId` is a secure hash.
+		pub Challenges get(fn challenges):
+			double_map hasher(twox_64_concat) T::AccountId,
+				hasher(twox_64_concat) (RegistrarIndex, IdentityField) => Option<Challenge<T::Hash>>;
+
+		/// The fields of an account's identity which a registrar has verified through challenges,
+		/// along with the hash of their content when they were verified.
+		///
+		/// TWOX-NOTE: OK ― `AccountId` is a secure hash.
+		pub VerifiedFields get(fn verified_fields_of):
+			double_map hasher(twox_64_concat) T::AccountId,
+				hasher(twox_64_concat) RegistrarIndex => Vec<(IdentityField, T::Hash)>;
 	}
 }
 
@@ -470,6 +586,11 @@ decl_event!(
 		/// A sub-identity was cleared, and the given deposit repatriated from the
 		/// main identity account to the sub-identity account. \[sub, main, deposit\]
 		SubIdentityRevoked(AccountId, AccountId, Balance),
+		/// A registrar posted a verification challenge for a field.
+		/// \[target, registrar_index, field\]
+		ChallengePosted(AccountId, RegistrarIndex, IdentityField),
+		/// A verification challenge for a field was answered. \[who, registrar_index, field\]
+		ChallengeAnswered(AccountId, RegistrarIndex, IdentityField),
 	}
 );
 
@@ -507,7 +628,19 @@ decl_error! {
 		/// Sender is not a sub-account.
 		NotSub,
 		/// Sub-account isn't owned by sender.
-		NotOwned
+		NotOwned,
+		/// No judgement has been requested from the registrar.
+		NotRequested,
+		/// The field cannot be verified through a challenge, or is not set.
+		UnverifiableField,
+		/// The field is not one the registrar judges.
+		FieldNotJudged,
+		/// No challenge has been posted for the field.
+		NoChallenge,
+		/// The response does not match the challenge.
+		InvalidResponse,
+		/// The content of the field has changed since the challenge was posted.
+		FieldChanged,
 	}
 }
 
@@ -715,6 +848,7 @@ decl_module! {
 		///   - where `X` additional-field-count (deposit-bounded and code-bounded).
 		/// - One balance-unreserve operation.
 		/// - `2` storage reads and `S + 2` storage deletions.
+		/// - `2` storage prefix deletions (challenges and verified fields).
 		/// - One event.
 		/// # </weight>
 		#[weight = T::WeightInfo::clear_identity(
@@ -731,6 +865,8 @@ decl_module! {
 			for sub in sub_ids.iter() {
 				<SuperOf<T>>::remove(sub);
 			}
+			<Challenges<T>>::remove_prefix(&sender);
+			<VerifiedFields<T>>::remove_prefix(&sender);
 
 			let _ = T::Currency::unreserve(&sender, deposit.clone());
 
@@ -809,6 +945,8 @@ decl_module! {
 		///
 		/// Payment: A previously reserved deposit is returned on success.
 		///
+		/// Any verification challenges the registrar posted for the request are removed.
+		///
 		/// The dispatch origin for this call must be _Signed_ and the sender must have a
 		/// registered identity.
 		///
@@ -820,6 +958,7 @@ decl_module! {
 		/// - `O(R + X)`.
 		/// - One balance-reserve operation.
 		/// - One storage mutation `O(R + X)`.
+		/// - Up to four storage deletions (challenges).
 		/// - One event
 		/// # </weight>
 		#[weight = T::WeightInfo::cancel_request(
@@ -842,6 +981,7 @@ decl_module! {
 			let judgements = id.judgements.len();
 			let extra_fields = id.info.additional.len();
 			<IdentityOf<T>>::insert(&sender, id);
+			Self::take_challenges(&sender, reg_index);
 
 			Self::deposit_event(RawEvent::JudgementUnrequested(sender, reg_index));
 
@@ -953,6 +1093,11 @@ decl_module! {
 		///   with a registered identity.
 		/// - `judgement`: the judgement of the registrar of index `reg_index` about `target`.
 		///
+		/// The challenges the registrar posted for `target` are consumed. If the judgement is
+		/// `Reasonable` or `KnownGood`, the fields whose challenges were answered, and whose content
+		/// has not changed since, are recorded as verified by the registrar. Any other judgement
+		/// removes the fields the registrar verified before.
+		///
 		/// Emits `JudgementGiven` if successful.
 		///
 		/// # <weight>
@@ -960,6 +1105,7 @@ decl_module! {
 		/// - One balance-transfer operation.
 		/// - Up to one account-lookup operation.
 		/// - Storage: 1 read `O(R)`, 1 mutate `O(R + X)`.
+		/// - Up to four storage deletions (challenges) and 1 mutate `O(1)` (verified fields).
 		/// - One event.
 		/// # </weight>
 		#[weight = T::WeightInfo::provide_judgement(
@@ -992,6 +1138,22 @@ decl_module! {
 				Err(position) => id.judgements.insert(position, item),
 			}
 
+			// Attach the judgement to the content of the fields whose challenges were answered.
+			let answered = Self::take_challenges(&target, reg_index)
+				.into_iter()
+				.filter(|(field, c)| c.answered && Self::content_hash(&id.info, *field) == Some(c.content))
+				.map(|(field, c)| (field, c.content))
+				.collect::<Vec<_>>();
+			match judgement {
+				Judgement::Reasonable | Judgement::KnownGood => if !answered.is_empty() {
+					<VerifiedFields<T>>::mutate(&target, reg_index, |verified| {
+						verified.retain(|(field, _)| answered.iter().all(|(f, _)| f != field));
+						verified.extend(answered);
+					});
+				},
+				_ => <VerifiedFields<T>>::remove(&target, reg_index),
+			}
+
 			let judgements = id.judgements.len();
 			let extra_fields = id.info.additional.len();
 			<IdentityOf<T>>::insert(&target, id);
@@ -1020,6 +1182,7 @@ decl_module! {
 		/// - `O(R + S + X)`.
 		/// - One balance-reserve operation.
 		/// - `S + 2` storage mutations.
+		/// - `2` storage prefix deletions (challenges and verified fields).
 		/// - One event.
 		/// # </weight>
 		#[weight = T::WeightInfo::kill_identity(
@@ -1039,6 +1202,8 @@ decl_module! {
 			for sub in sub_ids.iter() {
 				<SuperOf<T>>::remove(sub);
 			}
+			<Challenges<T>>::remove_prefix(&target);
+			<VerifiedFields<T>>::remove_prefix(&target);
 			// Slash their deposit from them.
 			T::Slashed::on_unbalanced(T::Currency::slash_reserved(&target, deposit).0);
 
@@ -1141,10 +1306,166 @@ decl_module! {
 				Self::deposit_event(RawEvent::SubIdentityRevoked(sender, sup.clone(), deposit));
 			});
 		}
+
+		/// Post a challenge to verify that an account controls the handle given in a field of its
+		/// identity. Any previous challenge of the registrar for the field is replaced.
+		///
+		/// The registrar is expected to send a secret to the account through the channel named by
+		/// the field, such as an email to the given address, and to post the hash of the secret.
+		///
+		/// The dispatch origin for this call must be _Signed_ and the sender must be the account
+		/// of the registrar whose index is `reg_index`.
+		///
+		/// - `reg_index`: the index of the registrar posting the challenge.
+		/// - `target`: the account whose identity is being verified. This must be an account with
+		///   a registered identity which has requested a judgement from the registrar.
+		/// - `field`: the field to verify; one of `Email`, `Web`, `Twitter` or `Riot`. It must be
+		///   set, and one of the fields the registrar judges.
+		/// - `challenge`: the hash of the secret sent to the account.
+		///
+		/// Emits `ChallengePosted` if successful.
+		///
+		/// # <weight>
+		/// - `O(R + X)`.
+		/// - Storage: 2 reads `O(R + X)`, 1 insert `O(1)`.
+		/// - One event.
+		/// # </weight>
+		#[weight = T::WeightInfo::post_challenge(
+			T::MaxRegistrars::get().into(), // R
+			T::MaxAdditionalFields::get().into(), // X
+		)]
+		fn post_challenge(origin,
+			#[compact] reg_index: RegistrarIndex,
+			target: <T::Lookup as StaticLookup>::Source,
+			field: IdentityField,
+			challenge: T::Hash,
+		) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+			let target = T::Lookup::lookup(target)?;
+			let fields = <Registrars<T>>::get()
+				.get(reg_index as usize)
+				.and_then(Option::as_ref)
+				.and_then(|r| if r.account == sender { Some(r.fields) } else { None })
+				.ok_or(Error::<T>::InvalidIndex)?;
+			ensure!(fields.0.contains(field), Error::<T>::FieldNotJudged);
+			let id = <IdentityOf<T>>::get(&target).ok_or(Error::<T>::InvalidTarget)?;
+			match id.judgements.binary_search_by_key(&reg_index, |x| x.0) {
+				Ok(i) if id.judgements[i].1.has_deposit() => (),
+				_ => Err(Error::<T>::NotRequested)?,
+			}
+			let content = Self::content_hash(&id.info, field).ok_or(Error::<T>::UnverifiableField)?;
+
+			<Challenges<T>>::insert(&target, (reg_index, field), Challenge {
+				challenge,
+				content,
+				answered: false,
+			});
+			Self::deposit_event(RawEvent::ChallengePosted(target, reg_index, field));
+
+			Ok(Some(T::WeightInfo::post_challenge(
+				id.judgements.len() as u32,
+				id.info.additional.len() as u32,
+			)).into())
+		}
+
+		/// Answer a verification challenge which a registrar posted for a field of the sender's
+		/// identity, with the secret the registrar sent through the channel named by the field.
+		///
+		/// The dispatch origin for this call must be _Signed_ and the sender must have a
+		/// registered identity whose `field` has not changed since the challenge was posted.
+		///
+		/// - `reg_index`: The index of the registrar which posted the challenge.
+		/// - `field`: The field the challenge was posted for.
+		/// - `response`: The secret whose hash is the challenge.
+		///
+		/// Emits `ChallengeAnswered` if successful.
+		///
+		/// # <weight>
+		/// - `O(R + X)`.
+		/// - Storage: 1 read `O(R + X)`, 1 mutate `O(1)`.
+		/// - One event.
+		/// # </weight>
+		#[weight = T::WeightInfo::answer_challenge(
+			T::MaxRegistrars::get().into(), // R
+			T::MaxAdditionalFields::get().into(), // X
+		)]
+		fn answer_challenge(origin,
+			#[compact] reg_index: RegistrarIndex,
+			field: IdentityField,
+			response: Vec<u8>,
+		) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+			let id = <IdentityOf<T>>::get(&sender).ok_or(Error::<T>::NoIdentity)?;
+
+			<Challenges<T>>::try_mutate(&sender, (reg_index, field), |maybe_challenge| -> DispatchResult {
+				let challenge = maybe_challenge.as_mut().ok_or(Error::<T>::NoChallenge)?;
+				ensure!(T::Hashing::hash(&response) == challenge.challenge, Error::<T>::InvalidResponse);
+				ensure!(
+					Self::content_hash(&id.info, field) == Some(challenge.content),
+					Error::<T>::FieldChanged,
+				);
+				challenge.answered = true;
+				Ok(())
+			})?;
+
+			Self::deposit_event(RawEvent::ChallengeAnswered(sender, reg_index, field));
+
+			Ok(Some(T::WeightInfo::answer_challenge(
+				id.judgements.len() as u32,
+				id.info.additional.len() as u32,
+			)).into())
+		}
 	}
 }
 
 impl<T: Trait> Module<T> {
+	/// Get the fields of the identity of `who` which registrars have verified through challenges
+	/// and whose content has not changed since, ordered by registrar.
+	pub fn verified_fields(who: &T::AccountId) -> Vec<VerifiedField<IdentityField, Data>> {
+		let info = match <IdentityOf<T>>::get(who) {
+			Some(id) => id.info,
+			None => return Vec::new(),
+		};
+		let mut verified = <VerifiedFields<T>>::iter_prefix(who)
+			.flat_map(|(registrar, fields)| fields.into_iter().map(move |f| (registrar, f)))
+			.filter_map(|(registrar, (field, content_hash))| {
+				let content = Self::challenge_content(&info, field)?;
+				if T::Hashing::hash_of(content) == content_hash {
+					Some(VerifiedField { registrar, field, content: content.clone() })
+				} else {
+					None
+				}
+			})
+			.collect::<Vec<_>>();
+		verified.sort_by_key(|f| f.registrar);
+		verified
+	}
+
+	/// The content of `field` in `info`, if it is set and can be verified through a challenge.
+	fn challenge_content(info: &IdentityInfo, field: IdentityField) -> Option<&Data> {
+		let content = match field {
+			IdentityField::Web => &info.web,
+			IdentityField::Riot => &info.riot,
+			IdentityField::Email => &info.email,
+			IdentityField::Twitter => &info.twitter,
+			_ => return None,
+		};
+		if *content == Data::None { None } else { Some(content) }
+	}
+
+	/// The hash of the content of `field` in `info`, if it is set and can be verified through a
+	/// challenge.
+	fn content_hash(info: &IdentityInfo, field: IdentityField) -> Option<T::Hash> {
+		Self::challenge_content(info, field).map(|content| T::Hashing::hash_of(content))
+	}
+
+	/// Remove the challenges posted by registrar `reg_index` for `who`, returning them.
+	fn take_challenges(who: &T::AccountId, reg_index: RegistrarIndex) -> Vec<(IdentityField, Challenge<T::Hash>)> {
+		CHALLENGE_FIELDS.iter()
+			.filter_map(|field| <Challenges<T>>::take(who, (reg_index, *field)).map(|c| (*field, c)))
+			.collect()
+	}
+
 	/// Get the subs of an account.
 	pub fn subs(who: &T::AccountId) -> Vec<(T::AccountId, Data)> {
 		SubsOf::<T>::get(who).1
@@ -79,6 +79,8 @@ impl pallet_balances::Trait for Test {
 	type ExistentialDeposit = ExistentialDeposit;
 	type AccountStore = System;
 	type MaxLocks = ();
+	type MaxReserves = ();
+	type ReserveIdentifier = [u8; 8];
 	type WeightInfo = ();
 }
 parameter_types! {
@@ -470,3 +472,157 @@ fn setting_account_id_should_work() {
 		assert_ok!(Identity::set_account_id(Origin::signed(4), 0, 3));
 	});
 }
+
+fn verifiable() -> IdentityInfo {
+	IdentityInfo {
+		display: Data::Raw(b"ten".to_vec()),
+		email: Data::Raw(b"ten@example.com".to_vec()),
+		twitter: Data::Raw(b"@ten".to_vec()),
+		.. Default::default()
+	}
+}
+
+fn secret_hash() -> H256 {
+	BlakeTwo256::hash(b"secret")
+}
+
+fn add_challenging_registrar() {
+	assert_ok!(Identity::add_registrar(Origin::signed(1), 3));
+	assert_ok!(Identity::set_fee(Origin::signed(3), 0, 10));
+	let fields = IdentityFields(IdentityField::Display | IdentityField::Email | IdentityField::Twitter);
+	assert_ok!(Identity::set_fields(Origin::signed(3), 0, fields));
+}
+
+#[test]
+fn posting_challenge_should_work() {
+	new_test_ext().execute_with(|| {
+		add_challenging_registrar();
+		assert_noop!(
+			Identity::post_challenge(Origin::signed(3), 0, 10, IdentityField::Email, secret_hash()),
+			Error::<Test>::InvalidTarget
+		);
+		assert_ok!(Identity::set_identity(Origin::signed(10), verifiable()));
+		assert_noop!(
+			Identity::post_challenge(Origin::signed(3), 0, 10, IdentityField::Email, secret_hash()),
+			Error::<Test>::NotRequested
+		);
+
+		assert_ok!(Identity::request_judgement(Origin::signed(10), 0, 10));
+		assert_noop!(
+			Identity::post_challenge(Origin::signed(4), 0, 10, IdentityField::Email, secret_hash()),
+			Error::<Test>::InvalidIndex
+		);
+		assert_noop!(
+			Identity::post_challenge(Origin::signed(3), 0, 10, IdentityField::Web, secret_hash()),
+			Error::<Test>::FieldNotJudged
+		);
+		assert_noop!(
+			Identity::post_challenge(Origin::signed(3), 0, 10, IdentityField::Display, secret_hash()),
+			Error::<Test>::UnverifiableField
+		);
+
+		assert_ok!(Identity::post_challenge(Origin::signed(3), 0, 10, IdentityField::Email, secret_hash()));
+		assert_eq!(Identity::challenges(10, (0, IdentityField::Email)), Some(Challenge {
+			challenge: secret_hash(),
+			content: BlakeTwo256::hash_of(&verifiable().email),
+			answered: false,
+		}));
+	});
+}
+
+#[test]
+fn answering_challenge_should_work() {
+	new_test_ext().execute_with(|| {
+		add_challenging_registrar();
+		assert_ok!(Identity::set_identity(Origin::signed(10), verifiable()));
+		assert_ok!(Identity::request_judgement(Origin::signed(10), 0, 10));
+		assert_ok!(Identity::post_challenge(Origin::signed(3), 0, 10, IdentityField::Email, secret_hash()));
+
+		assert_noop!(
+			Identity::answer_challenge(Origin::signed(20), 0, IdentityField::Email, b"secret".to_vec()),
+			Error::<Test>::NoIdentity
+		);
+		assert_noop!(
+			Identity::answer_challenge(Origin::signed(10), 0, IdentityField::Twitter, b"secret".to_vec()),
+			Error::<Test>::NoChallenge
+		);
+		assert_noop!(
+			Identity::answer_challenge(Origin::signed(10), 0, IdentityField::Email, b"guess".to_vec()),
+			Error::<Test>::InvalidResponse
+		);
+		assert_ok!(Identity::answer_challenge(Origin::signed(10), 0, IdentityField::Email, b"secret".to_vec()));
+		assert!(Identity::challenges(10, (0, IdentityField::Email)).unwrap().answered);
+
+		// A challenge cannot be answered once the field has changed.
+		assert_ok!(Identity::post_challenge(Origin::signed(3), 0, 10, IdentityField::Twitter, secret_hash()));
+		let mut info = verifiable();
+		info.twitter = Data::Raw(b"@eleven".to_vec());
+		assert_ok!(Identity::set_identity(Origin::signed(10), info));
+		assert_noop!(
+			Identity::answer_challenge(Origin::signed(10), 0, IdentityField::Twitter, b"secret".to_vec()),
+			Error::<Test>::FieldChanged
+		);
+
+		// Cancelling the request removes its challenges.
+		assert_ok!(Identity::cancel_request(Origin::signed(10), 0));
+		assert_eq!(Identity::challenges(10, (0, IdentityField::Email)), None);
+		assert_eq!(Identity::challenges(10, (0, IdentityField::Twitter)), None);
+	});
+}
+
+#[test]
+fn judgement_attaches_to_verified_field_content() {
+	new_test_ext().execute_with(|| {
+		add_challenging_registrar();
+		assert_ok!(Identity::set_identity(Origin::signed(10), verifiable()));
+		assert_ok!(Identity::request_judgement(Origin::signed(10), 0, 10));
+		assert_ok!(Identity::post_challenge(Origin::signed(3), 0, 10, IdentityField::Email, secret_hash()));
+		assert_ok!(Identity::post_challenge(Origin::signed(3), 0, 10, IdentityField::Twitter, secret_hash()));
+		assert_ok!(Identity::answer_challenge(Origin::signed(10), 0, IdentityField::Email, b"secret".to_vec()));
+
+		// Only the answered challenge verifies its field, and the challenges are consumed.
+		assert_ok!(Identity::provide_judgement(Origin::signed(3), 0, 10, Judgement::Reasonable));
+		let email = VerifiedField { registrar: 0, field: IdentityField::Email, content: verifiable().email };
+		assert_eq!(Identity::verified_fields(&10), vec![email.clone()]);
+		assert_eq!(Identity::challenges(10, (0, IdentityField::Twitter)), None);
+
+		// Changing other fields wipes the judgement, but the field stays verified.
+		let mut info = verifiable();
+		info.display = Data::Raw(b"eleven".to_vec());
+		assert_ok!(Identity::set_identity(Origin::signed(10), info.clone()));
+		assert!(Identity::identity(10).unwrap().judgements.is_empty());
+		assert_eq!(Identity::verified_fields(&10), vec![email]);
+
+		// Changing the field itself does not keep it verified.
+		info.email = Data::Raw(b"eleven@example.com".to_vec());
+		assert_ok!(Identity::set_identity(Origin::signed(10), info));
+		assert!(Identity::verified_fields(&10).is_empty());
+	});
+}
+
+#[test]
+fn verified_fields_are_removed_by_negative_judgement_or_clearing() {
+	new_test_ext().execute_with(|| {
+		add_challenging_registrar();
+		assert_ok!(Identity::set_identity(Origin::signed(10), verifiable()));
+		assert_ok!(Identity::request_judgement(Origin::signed(10), 0, 10));
+		assert_ok!(Identity::post_challenge(Origin::signed(3), 0, 10, IdentityField::Email, secret_hash()));
+		assert_ok!(Identity::answer_challenge(Origin::signed(10), 0, IdentityField::Email, b"secret".to_vec()));
+		assert_ok!(Identity::provide_judgement(Origin::signed(3), 0, 10, Judgement::KnownGood));
+		assert_eq!(Identity::verified_fields(&10).len(), 1);
+
+		assert_ok!(Identity::provide_judgement(Origin::signed(3), 0, 10, Judgement::LowQuality));
+		assert!(Identity::verified_fields(&10).is_empty());
+		assert!(Identity::verified_fields_of(10, 0).is_empty());
+
+		assert_ok!(Identity::request_judgement(Origin::signed(10), 0, 10));
+		assert_ok!(Identity::post_challenge(Origin::signed(3), 0, 10, IdentityField::Email, secret_hash()));
+		assert_ok!(Identity::answer_challenge(Origin::signed(10), 0, IdentityField::Email, b"secret".to_vec()));
+		assert_ok!(Identity::provide_judgement(Origin::signed(3), 0, 10, Judgement::Reasonable));
+		assert_eq!(Identity::verified_fields(&10).len(), 1);
+
+		assert_ok!(Identity::clear_identity(Origin::signed(10)));
+		assert!(Identity::verified_fields_of(10, 0).is_empty());
+		assert!(Identity::verified_fields(&10).is_empty());
+	});
+}
@@ -19,9 +19,12 @@
 
 #[cfg(feature = "std")]
 use std::fmt;
-use sp_std::convert::TryInto;
+use sp_std::{convert::TryInto, prelude::*};
 use crate::Member;
 use codec::{Encode, Decode, Input, Output, Error};
+use frame_support::type_info::{
+	Field, MetaType, Path, Type, TypeDef, TypeInfo, TypeParameter, Variant,
+};
 
 /// An indices-aware address, which can be either a direct `AccountId` or
 /// an index.
@@ -121,6 +124,43 @@ impl<AccountId, AccountIndex> codec::EncodeLike for Address<AccountId, AccountIn
 	AccountIndex: Member + Encode + PartialOrd<AccountIndex> + Ord + Copy + From<u32> + TryInto<u32>,
 {}
 
+/// Describes the variants with their longest encoding, `0xff` followed by the account id and
+/// `0xfe` followed by the index. The shorter encodings of small indices can't be described.
+impl<AccountId, AccountIndex> TypeInfo for Address<AccountId, AccountIndex> where
+	AccountId: Member + TypeInfo + 'static,
+	AccountIndex: Member + TypeInfo + 'static,
+{
+	type Identity = Self;
+
+	fn type_info() -> Type {
+		Type::new(
+			Path::new("Address", module_path!()),
+			vec![
+				TypeParameter::new("AccountId", Some(MetaType::new::<AccountId>())),
+				TypeParameter::new("AccountIndex", Some(MetaType::new::<AccountIndex>())),
+			],
+			TypeDef::Variant(vec![
+				Variant::new(
+					"Id",
+					vec![Field::new::<AccountId>(None, Some("AccountId"), vec![])],
+					255,
+					vec![" It's an account ID (pubkey)."],
+				),
+				Variant::new(
+					"Index",
+					vec![Field::new::<AccountIndex>(None, Some("AccountIndex"), vec![])],
+					254,
+					vec![" It's an account index."],
+				),
+			]),
+			vec![
+				" An indices-aware address, which can be either a direct `AccountId` or",
+				" an index.",
+			],
+		)
+	}
+}
+
 impl<AccountId, AccountIndex> Default for Address<AccountId, AccountIndex> where
 	AccountId: Member + Default,
 	AccountIndex: Member,
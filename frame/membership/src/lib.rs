@@ -19,17 +19,31 @@
 //!
 //! Allows control of membership of a set of `AccountId`s, useful for managing membership of of a
 //! collective. A prime member may be set.
+//!
+//! If `TermDuration` is non-zero, each member's seat expires `TermDuration` blocks after they
+//! joined. Accounts may queue up for a seat by placing a `CandidacyDeposit`; when a term expires,
+//! the seat goes to the first candidate in the queue, whose deposit is returned. If the queue is
+//! empty, the sitting member starts a new term. The membership may also be driven by another
+//! module, e.g. an election, through this module's `ChangeMembers` implementation, in which case
+//! the incoming members start a new term.
 
 // Ensure we're `no_std` when compiling for Wasm.
 #![cfg_attr(not(feature = "std"), no_std)]
 
 use sp_std::prelude::*;
+use codec::{Encode, Decode};
+use sp_runtime::{RuntimeDebug, traits::{Zero, Saturating}};
 use frame_support::{
-	decl_module, decl_storage, decl_event, decl_error,
-	traits::{ChangeMembers, InitializeMembers, EnsureOrigin, Contains},
+	decl_module, decl_storage, decl_event, decl_error, ensure,
+	weights::Weight,
+	traits::{ChangeMembers, InitializeMembers, EnsureOrigin, Contains, Currency, ReservableCurrency, Get},
+	type_info::TypeInfo,
 };
 use frame_system::ensure_signed;
 
+type BalanceOf<T, I> =
+	<<T as Trait<I>>::Currency as Currency<<T as frame_system::Trait>::AccountId>>::Balance;
+
 pub trait Trait<I=DefaultInstance>: frame_system::Trait {
 	/// The overarching event type.
 	type Event: From<Event<Self, I>> + Into<<Self as frame_system::Trait>::Event>;
@@ -56,6 +70,33 @@ pub trait Trait<I=DefaultInstance>: frame_system::Trait {
 
 	/// The receiver of the signal for when the membership has changed.
 	type MembershipChanged: ChangeMembers<Self::AccountId>;
+
+	/// The currency used for candidacy deposits.
+	type Currency: ReservableCurrency<Self::AccountId>;
+
+	/// The amount reserved from an account while it waits in the candidacy queue.
+	type CandidacyDeposit: Get<BalanceOf<Self, I>>;
+
+	/// The maximum number of accounts which may wait in the candidacy queue.
+	type MaxCandidates: Get<u32>;
+
+	/// The number of blocks a member keeps their seat for. Zero means that seats never expire.
+	type TermDuration: Get<Self::BlockNumber>;
+}
+
+// A value placed in storage that represents the current version of the Membership storage.
+// This value is used by the `on_runtime_upgrade` logic to determine whether we run
+// storage migration logic.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+enum Releases {
+	V0,
+	V1,
+}
+
+impl Default for Releases {
+	fn default() -> Self {
+		Releases::V0
+	}
 }
 
 decl_storage! {
@@ -65,6 +106,22 @@ decl_storage! {
 
 		/// The current prime member, if one exists.
 		Prime get(fn prime): Option<T::AccountId>;
+
+		/// The block at which the term of each member ends.
+		TermEnd get(fn term_end): map hasher(twox_64_concat) T::AccountId => Option<T::BlockNumber>;
+
+		/// The members whose term ends at a given block. Entries are not cleaned up when a member
+		/// leaves early, so they must be checked against `TermEnd`.
+		Rotations: map hasher(twox_64_concat) T::BlockNumber => Vec<T::AccountId>;
+
+		/// The queue of accounts waiting for a seat, along with their deposit. First come, first
+		/// served.
+		Candidates get(fn candidates): Vec<(T::AccountId, BalanceOf<T, I>)>;
+
+		/// Storage version of the pallet.
+		///
+		/// New networks start with the last version.
+		StorageVersion build(|_| Releases::V1): Releases;
 	}
 	add_extra_genesis {
 		config(members): Vec<T::AccountId>;
@@ -73,6 +130,7 @@ decl_storage! {
 			let mut members = config.members.clone();
 			members.sort();
 			T::MembershipInitialized::initialize_members(&members);
+			members.iter().for_each(Module::<T, I>::start_term);
 			<Members<T, I>>::put(members);
 		})
 	}
@@ -93,6 +151,15 @@ decl_event!(
 		MembersReset,
 		/// One of the members' keys changed.
 		KeyChanged,
+		/// An account joined the candidacy queue. \[who\]
+		CandidateSubmitted(AccountId),
+		/// An account left the candidacy queue. \[who\]
+		CandidateWithdrawn(AccountId),
+		/// Members whose term ended were replaced by candidates. \[incoming, outgoing\]
+		MembersRotated(Vec<AccountId>, Vec<AccountId>),
+		/// A member's term ended without a candidate to replace them, so they start a new term.
+		/// \[who\]
+		TermRenewed(AccountId),
 		/// Phantom member, never used.
 		Dummy(sp_std::marker::PhantomData<(AccountId, Event)>),
 	}
@@ -105,6 +172,12 @@ decl_error! {
 		AlreadyMember,
 		/// Not a member.
 		NotMember,
+		/// Already in the candidacy queue.
+		AlreadyCandidate,
+		/// Not in the candidacy queue.
+		NotCandidate,
+		/// The candidacy queue is full.
+		TooManyCandidates,
 	}
 }
 
@@ -113,8 +186,27 @@ decl_module! {
 		for enum Call
 		where origin: T::Origin
 	{
+		type Error = Error<T, I>;
+
+		/// The amount reserved from an account while it waits in the candidacy queue.
+		const CandidacyDeposit: BalanceOf<T, I> = T::CandidacyDeposit::get();
+
+		/// The maximum number of accounts which may wait in the candidacy queue.
+		const MaxCandidates: u32 = T::MaxCandidates::get();
+
+		/// The number of blocks a member keeps their seat for. Zero means that seats never expire.
+		const TermDuration: T::BlockNumber = T::TermDuration::get();
+
 		fn deposit_event() = default;
 
+		fn on_runtime_upgrade() -> Weight {
+			Self::migrate_v0_to_v1()
+		}
+
+		fn on_initialize(now: T::BlockNumber) -> Weight {
+			Self::rotate(now)
+		}
+
 		/// Add a member `who` to the set.
 		///
 		/// May only be called from `T::AddOrigin`.
@@ -126,6 +218,7 @@ decl_module! {
 			let location = members.binary_search(&who).err().ok_or(Error::<T, I>::AlreadyMember)?;
 			members.insert(location, who.clone());
 			<Members<T, I>>::put(&members);
+			Self::start_term(&who);
 
 			T::MembershipChanged::change_members_sorted(&[who], &[], &members[..]);
 
@@ -143,6 +236,7 @@ decl_module! {
 			let location = members.binary_search(&who).ok().ok_or(Error::<T, I>::NotMember)?;
 			members.remove(location);
 			<Members<T, I>>::put(&members);
+			<TermEnd<T, I>>::remove(&who);
 
 			T::MembershipChanged::change_members_sorted(&[], &[who], &members[..]);
 			Self::rejig_prime(&members);
@@ -167,6 +261,8 @@ decl_module! {
 			members[location] = add.clone();
 			members.sort();
 			<Members<T, I>>::put(&members);
+			<TermEnd<T, I>>::remove(&remove);
+			Self::start_term(&add);
 
 			T::MembershipChanged::change_members_sorted(
 				&[add],
@@ -189,7 +285,12 @@ decl_module! {
 			let mut members = members;
 			members.sort();
 			<Members<T, I>>::mutate(|m| {
-				T::MembershipChanged::set_members_sorted(&members[..], m);
+				// Members who keep their seat also keep their term.
+				let (incoming, outgoing) =
+					<() as ChangeMembers<T::AccountId>>::compute_members_diff(&members[..], m);
+				outgoing.iter().for_each(|who| <TermEnd<T, I>>::remove(who));
+				incoming.iter().for_each(Self::start_term);
+				T::MembershipChanged::change_members_sorted(&incoming[..], &outgoing[..], &members[..]);
 				Self::rejig_prime(&members);
 				*m = members;
 			});
@@ -214,6 +315,11 @@ decl_module! {
 				members[location] = new.clone();
 				members.sort();
 				<Members<T, I>>::put(&members);
+				// The new key takes over the seat, including its term.
+				if let Some(end) = <TermEnd<T, I>>::take(&remove) {
+					<TermEnd<T, I>>::insert(&new, end);
+					<Rotations<T, I>>::append(end, &new);
+				}
 
 				T::MembershipChanged::change_members_sorted(
 					&[new.clone()],
@@ -250,10 +356,140 @@ decl_module! {
 			Prime::<T, I>::kill();
 			T::MembershipChanged::set_prime(None);
 		}
+
+		/// Join the candidacy queue, reserving `CandidacyDeposit` until the sender either gets a
+		/// seat or withdraws.
+		///
+		/// May only be called from a `Signed` origin which is neither a member nor a candidate.
+		#[weight = 50_000_000]
+		pub fn submit_candidacy(origin) {
+			let who = ensure_signed(origin)?;
+			ensure!(Self::members().binary_search(&who).is_err(), Error::<T, I>::AlreadyMember);
+
+			let mut candidates = <Candidates<T, I>>::get();
+			ensure!(candidates.iter().all(|(c, _)| c != &who), Error::<T, I>::AlreadyCandidate);
+			ensure!(
+				(candidates.len() as u32) < T::MaxCandidates::get(),
+				Error::<T, I>::TooManyCandidates,
+			);
+
+			let deposit = T::CandidacyDeposit::get();
+			T::Currency::reserve(&who, deposit)?;
+			candidates.push((who.clone(), deposit));
+			<Candidates<T, I>>::put(candidates);
+
+			Self::deposit_event(RawEvent::CandidateSubmitted(who));
+		}
+
+		/// Leave the candidacy queue, returning the deposit.
+		///
+		/// May only be called from a `Signed` origin of a current candidate.
+		#[weight = 50_000_000]
+		pub fn withdraw_candidacy(origin) {
+			let who = ensure_signed(origin)?;
+
+			let mut candidates = <Candidates<T, I>>::get();
+			let position = candidates.iter().position(|(c, _)| c == &who)
+				.ok_or(Error::<T, I>::NotCandidate)?;
+			let (_, deposit) = candidates.remove(position);
+			T::Currency::unreserve(&who, deposit);
+			<Candidates<T, I>>::put(candidates);
+
+			Self::deposit_event(RawEvent::CandidateWithdrawn(who));
+		}
 	}
 }
 
 impl<T: Trait<I>, I: Instance> Module<T, I> {
+	/// Migrate to limited terms (V1) by starting a term for every existing member. Return the
+	/// weight consumed.
+	pub fn migrate_v0_to_v1() -> Weight {
+		if StorageVersion::<I>::get() == Releases::V0 {
+			StorageVersion::<I>::put(Releases::V1);
+
+			let members = <Members<T, I>>::get();
+			members.iter().for_each(Self::start_term);
+
+			let started = members.len() as Weight;
+			T::DbWeight::get().reads_writes(started + 2, 2 * started + 1)
+		} else {
+			T::DbWeight::get().reads(1)
+		}
+	}
+
+	/// Start a new term for the member `who`, if terms are limited.
+	fn start_term(who: &T::AccountId) {
+		let duration = T::TermDuration::get();
+		if duration.is_zero() {
+			return
+		}
+		let end = frame_system::Module::<T>::block_number().saturating_add(duration);
+		<TermEnd<T, I>>::insert(who, end);
+		<Rotations<T, I>>::append(end, who);
+	}
+
+	/// Replace the members whose term ends at `now` by candidates from the queue, renewing the
+	/// term of those for whom there is no candidate.
+	fn rotate(now: T::BlockNumber) -> Weight {
+		let expiring = <Rotations<T, I>>::take(now);
+		if expiring.is_empty() {
+			return T::DbWeight::get().reads_writes(1, 1)
+		}
+
+		let mut members = <Members<T, I>>::get();
+		let mut candidates = <Candidates<T, I>>::get();
+		let mut incoming = Vec::new();
+		let mut outgoing = Vec::new();
+		for who in expiring.iter() {
+			// Members may have left or rejoined since this entry was made.
+			if <TermEnd<T, I>>::get(who) != Some(now) {
+				continue
+			}
+
+			// Candidates which were given a seat by other means are skipped.
+			let mut next = None;
+			while next.is_none() && !candidates.is_empty() {
+				let (candidate, deposit) = candidates.remove(0);
+				T::Currency::unreserve(&candidate, deposit);
+				if members.binary_search(&candidate).is_err() {
+					next = Some(candidate);
+				}
+			}
+
+			match next {
+				Some(candidate) => {
+					if let Ok(location) = members.binary_search(who) {
+						members.remove(location);
+					}
+					<TermEnd<T, I>>::remove(who);
+					if let Err(location) = members.binary_search(&candidate) {
+						members.insert(location, candidate.clone());
+					}
+					Self::start_term(&candidate);
+					incoming.push(candidate);
+					outgoing.push(who.clone());
+				}
+				None => {
+					Self::start_term(who);
+					Self::deposit_event(RawEvent::TermRenewed(who.clone()));
+				}
+			}
+		}
+		<Candidates<T, I>>::put(candidates);
+
+		if !incoming.is_empty() {
+			<Members<T, I>>::put(&members);
+			incoming.sort();
+			outgoing.sort();
+			T::MembershipChanged::change_members_sorted(&incoming[..], &outgoing[..], &members[..]);
+			Self::rejig_prime(&members);
+			Self::deposit_event(RawEvent::MembersRotated(incoming, outgoing));
+		}
+
+		let n = expiring.len() as Weight;
+		T::DbWeight::get().reads_writes(3 + 2 * n, 3 + 3 * n)
+	}
+
 	fn rejig_prime(members: &[T::AccountId]) {
 		if let Some(prime) = Prime::<T, I>::get() {
 			match members.binary_search(&prime) {
@@ -264,6 +500,27 @@ impl<T: Trait<I>, I: Instance> Module<T, I> {
 	}
 }
 
+impl<T: Trait<I>, I: Instance> ChangeMembers<T::AccountId> for Module<T, I> {
+	/// Update the members, e.g. following an election. Incoming members start a new term.
+	fn change_members_sorted(
+		incoming: &[T::AccountId],
+		outgoing: &[T::AccountId],
+		new: &[T::AccountId],
+	) {
+		outgoing.iter().for_each(|who| <TermEnd<T, I>>::remove(who));
+		incoming.iter().for_each(Self::start_term);
+		<Members<T, I>>::put(new);
+
+		T::MembershipChanged::change_members_sorted(incoming, outgoing, new);
+		Self::rejig_prime(new);
+	}
+
+	fn set_prime(prime: Option<T::AccountId>) {
+		Prime::<T, I>::set(prime.clone());
+		T::MembershipChanged::set_prime(prime);
+	}
+}
+
 impl<T: Trait<I>, I: Instance> Contains<T::AccountId> for Module<T, I> {
 	fn sorted_members() -> Vec<T::AccountId> {
 		Self::members()
@@ -281,7 +538,7 @@ mod tests {
 	use std::cell::RefCell;
 	use frame_support::{
 		assert_ok, assert_noop, impl_outer_origin, parameter_types, weights::Weight,
-		ord_parameter_types
+		ord_parameter_types, traits::OnInitialize,
 	};
 	use sp_core::H256;
 	use sp_runtime::{Perbill, traits::{BlakeTwo256, IdentityLookup, BadOrigin}, testing::Header};
@@ -321,11 +578,25 @@ mod tests {
 		type AvailableBlockRatio = AvailableBlockRatio;
 		type Version = ();
 		type PalletInfo = ();
-		type AccountData = ();
+		type AccountData = pallet_balances::AccountData<u64>;
 		type OnNewAccount = ();
 		type OnKilledAccount = ();
 		type SystemWeightInfo = ();
 	}
+	parameter_types! {
+		pub const ExistentialDeposit: u64 = 1;
+	}
+	impl pallet_balances::Trait for Test {
+		type MaxLocks = ();
+		type MaxReserves = ();
+		type ReserveIdentifier = [u8; 8];
+		type Balance = u64;
+		type Event = ();
+		type DustRemoval = ();
+		type ExistentialDeposit = ExistentialDeposit;
+		type AccountStore = System;
+		type WeightInfo = ();
+	}
 	ord_parameter_types! {
 		pub const One: u64 = 1;
 		pub const Two: u64 = 2;
@@ -372,12 +643,26 @@ mod tests {
 		type PrimeOrigin = EnsureSignedBy<Five, u64>;
 		type MembershipInitialized = TestChangeMembers;
 		type MembershipChanged = TestChangeMembers;
+		type Currency = Balances;
+		type CandidacyDeposit = CandidacyDeposit;
+		type MaxCandidates = MaxCandidates;
+		type TermDuration = TermDuration;
+	}
+	parameter_types! {
+		pub const CandidacyDeposit: u64 = 10;
+		pub const MaxCandidates: u32 = 2;
+		pub const TermDuration: u64 = 5;
 	}
 
+	type System = frame_system::Module<Test>;
+	type Balances = pallet_balances::Module<Test>;
 	type Membership = Module<Test>;
 
 	fn new_test_ext() -> sp_io::TestExternalities {
 		let mut t = frame_system::GenesisConfig::default().build_storage::<Test>().unwrap();
+		pallet_balances::GenesisConfig::<Test> {
+			balances: vec![(1, 100), (2, 100), (3, 100), (4, 5), (10, 100)],
+		}.assimilate_storage(&mut t).unwrap();
 		// We use default for brevity, but you can configure as desired if needed.
 		GenesisConfig::<Test>{
 			members: vec![10, 20, 30],
@@ -507,4 +792,171 @@ mod tests {
 			assert_eq!(PRIME.with(|m| *m.borrow()), Membership::prime());
 		});
 	}
+
+	fn run_to_block(n: u64) {
+		while System::block_number() < n {
+			System::set_block_number(System::block_number() + 1);
+			Membership::on_initialize(System::block_number());
+		}
+	}
+
+	#[test]
+	fn genesis_members_start_a_term() {
+		new_test_ext().execute_with(|| {
+			assert_eq!(Membership::term_end(10), Some(5));
+			assert_eq!(Membership::term_end(20), Some(5));
+			assert_eq!(Membership::term_end(30), Some(5));
+		});
+	}
+
+	#[test]
+	fn candidacy_works() {
+		new_test_ext().execute_with(|| {
+			assert_noop!(Membership::submit_candidacy(Origin::signed(10)), Error::<Test, _>::AlreadyMember);
+			assert_noop!(
+				Membership::submit_candidacy(Origin::signed(4)),
+				pallet_balances::Error::<Test, _>::InsufficientBalance,
+			);
+
+			assert_ok!(Membership::submit_candidacy(Origin::signed(1)));
+			assert_eq!(Balances::reserved_balance(1), 10);
+			assert_noop!(Membership::submit_candidacy(Origin::signed(1)), Error::<Test, _>::AlreadyCandidate);
+			assert_ok!(Membership::submit_candidacy(Origin::signed(2)));
+			assert_noop!(Membership::submit_candidacy(Origin::signed(3)), Error::<Test, _>::TooManyCandidates);
+			assert_eq!(Membership::candidates(), vec![(1, 10), (2, 10)]);
+
+			assert_noop!(Membership::withdraw_candidacy(Origin::signed(3)), Error::<Test, _>::NotCandidate);
+			assert_ok!(Membership::withdraw_candidacy(Origin::signed(1)));
+			assert_eq!(Balances::reserved_balance(1), 0);
+			assert_eq!(Membership::candidates(), vec![(2, 10)]);
+		});
+	}
+
+	#[test]
+	fn expired_members_are_replaced_by_candidates() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(Membership::set_prime(Origin::signed(5), 30));
+			assert_ok!(Membership::submit_candidacy(Origin::signed(1)));
+			assert_ok!(Membership::submit_candidacy(Origin::signed(2)));
+
+			run_to_block(4);
+			assert_eq!(Membership::members(), vec![10, 20, 30]);
+
+			// Two candidates for three expiring seats: the last member is renewed.
+			run_to_block(5);
+			assert_eq!(Membership::members(), vec![1, 2, 30]);
+			assert_eq!(MEMBERS.with(|m| m.borrow().clone()), Membership::members());
+			assert_eq!(Membership::candidates(), vec![]);
+			assert_eq!(Balances::reserved_balance(1), 0);
+			assert_eq!(Balances::reserved_balance(2), 0);
+			assert_eq!(Membership::term_end(1), Some(10));
+			assert_eq!(Membership::term_end(30), Some(10));
+			assert_eq!(Membership::term_end(10), None);
+			assert_eq!(Membership::prime(), Some(30));
+			assert_eq!(PRIME.with(|m| *m.borrow()), Membership::prime());
+		});
+	}
+
+	#[test]
+	fn terms_are_renewed_without_candidates() {
+		new_test_ext().execute_with(|| {
+			run_to_block(5);
+			assert_eq!(Membership::members(), vec![10, 20, 30]);
+			assert_eq!(Membership::term_end(10), Some(10));
+
+			assert_ok!(Membership::submit_candidacy(Origin::signed(1)));
+			run_to_block(10);
+			assert_eq!(Membership::members(), vec![1, 20, 30]);
+			assert_eq!(Membership::term_end(1), Some(15));
+		});
+	}
+
+	#[test]
+	fn rotation_skips_stale_terms() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(Membership::submit_candidacy(Origin::signed(1)));
+			assert_ok!(Membership::remove_member(Origin::signed(2), 10));
+			assert_eq!(Membership::term_end(10), None);
+
+			// 10 rejoins later, so its old term ending at block 5 no longer applies.
+			run_to_block(2);
+			assert_ok!(Membership::add_member(Origin::signed(1), 10));
+			run_to_block(5);
+			assert_eq!(Membership::members(), vec![1, 10, 30]);
+			assert_eq!(Membership::term_end(10), Some(7));
+
+			run_to_block(7);
+			assert_eq!(Membership::members(), vec![1, 10, 30]);
+			assert_eq!(Membership::term_end(10), Some(12));
+		});
+	}
+
+	#[test]
+	fn change_key_carries_the_term() {
+		new_test_ext().execute_with(|| {
+			run_to_block(2);
+			assert_ok!(Membership::change_key(Origin::signed(10), 40));
+			assert_eq!(Membership::term_end(10), None);
+			assert_eq!(Membership::term_end(40), Some(5));
+
+			assert_ok!(Membership::submit_candidacy(Origin::signed(1)));
+			run_to_block(5);
+			assert_eq!(Membership::members(), vec![1, 30, 40]);
+		});
+	}
+
+	#[test]
+	fn candidates_that_became_members_are_skipped() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(Membership::submit_candidacy(Origin::signed(1)));
+			assert_ok!(Membership::submit_candidacy(Origin::signed(2)));
+			run_to_block(3);
+			assert_ok!(Membership::add_member(Origin::signed(1), 1));
+
+			run_to_block(5);
+			assert_eq!(Membership::members(), vec![1, 2, 20, 30]);
+			assert_eq!(Balances::reserved_balance(1), 0);
+			assert_eq!(Membership::term_end(1), Some(8));
+		});
+	}
+
+	#[test]
+	fn migrate_v0_to_v1_starts_terms() {
+		new_test_ext().execute_with(|| {
+			// Members from before terms were introduced have none.
+			StorageVersion::<DefaultInstance>::put(Releases::V0);
+			for who in &[10, 20, 30] {
+				TermEnd::<Test, DefaultInstance>::remove(who);
+			}
+			Rotations::<Test, DefaultInstance>::remove(5);
+
+			run_to_block(2);
+			Membership::migrate_v0_to_v1();
+			assert_eq!(StorageVersion::<DefaultInstance>::get(), Releases::V1);
+			assert_eq!(Membership::term_end(10), Some(7));
+			assert_eq!(Membership::term_end(30), Some(7));
+
+			// The migration only runs once.
+			run_to_block(3);
+			Membership::migrate_v0_to_v1();
+			assert_eq!(Membership::term_end(10), Some(7));
+
+			assert_ok!(Membership::submit_candidacy(Origin::signed(1)));
+			run_to_block(7);
+			assert_eq!(Membership::members(), vec![1, 20, 30]);
+		});
+	}
+
+	#[test]
+	fn change_members_starts_terms() {
+		new_test_ext().execute_with(|| {
+			run_to_block(3);
+			<Membership as ChangeMembers<u64>>::set_members_sorted(&[20, 30, 40], &[10, 20, 30]);
+			assert_eq!(Membership::members(), vec![20, 30, 40]);
+			assert_eq!(MEMBERS.with(|m| m.borrow().clone()), Membership::members());
+			assert_eq!(Membership::term_end(10), None);
+			assert_eq!(Membership::term_end(20), Some(5));
+			assert_eq!(Membership::term_end(40), Some(8));
+		});
+	}
 }
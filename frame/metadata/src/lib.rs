@@ -30,6 +30,7 @@ use codec::{Decode, Input, Error};
 use codec::{Encode, Output};
 use sp_std::vec::Vec;
 use sp_core::RuntimeDebug;
+use sp_type_info::{PortableRegistry, TypeRef};
 
 #[cfg(feature = "std")]
 type StringBuf = String;
@@ -366,6 +367,10 @@ pub enum RuntimeMetadata {
 	V11(RuntimeMetadataDeprecated),
 	/// Version 12 for runtime metadata.
 	V12(RuntimeMetadataV12),
+	/// Version 13 for runtime metadata, describing all types through a type registry.
+	///
+	/// Served next to version 12 through `Metadata_metadata_at_version`.
+	V13(RuntimeMetadataV13),
 }
 
 /// Enum that should fail.
@@ -428,3 +433,112 @@ impl Into<RuntimeMetadataPrefixed> for RuntimeMetadataLastVersion {
 		RuntimeMetadataPrefixed(META_RESERVED, RuntimeMetadata::V12(self))
 	}
 }
+
+/// The metadata of a runtime, where all types are references into the type registry `types`.
+#[derive(Eq, Encode, PartialEq, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(Decode, Serialize))]
+pub struct RuntimeMetadataV13 {
+	/// The definitions of all the types referenced by the metadata.
+	pub types: PortableRegistry,
+	/// Metadata of all the modules.
+	pub modules: Vec<ModuleMetadataV13>,
+	/// Metadata of the extrinsic.
+	pub extrinsic: ExtrinsicMetadata,
+}
+
+impl Into<RuntimeMetadataPrefixed> for RuntimeMetadataV13 {
+	fn into(self) -> RuntimeMetadataPrefixed {
+		RuntimeMetadataPrefixed(META_RESERVED, RuntimeMetadata::V13(self))
+	}
+}
+
+/// All metadata about a runtime module, with types referring to the registry of the runtime.
+#[derive(Clone, PartialEq, Eq, Encode, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(Decode, Serialize))]
+pub struct ModuleMetadataV13 {
+	pub name: StringBuf,
+	pub storage: Option<StorageMetadataV13>,
+	pub calls: Option<Vec<FunctionMetadataV13>>,
+	pub event: Option<Vec<EventMetadataV13>>,
+	pub constants: Vec<ModuleConstantMetadataV13>,
+	/// The error type of the module, `None` if the module doesn't declare an error enum.
+	pub error: Option<TypeRef>,
+	/// Define the index of the module, this index will be used for the encoding of module event,
+	/// call and origin variants.
+	pub index: u8,
+}
+
+/// All the metadata about a function.
+#[derive(Clone, PartialEq, Eq, Encode, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(Decode, Serialize))]
+pub struct FunctionMetadataV13 {
+	pub name: StringBuf,
+	pub arguments: Vec<FunctionArgumentMetadataV13>,
+	pub documentation: Vec<StringBuf>,
+}
+
+/// All the metadata about a function argument.
+#[derive(Clone, PartialEq, Eq, Encode, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(Decode, Serialize))]
+pub struct FunctionArgumentMetadataV13 {
+	pub name: StringBuf,
+	pub ty: TypeRef,
+}
+
+/// All the metadata about an event.
+#[derive(Clone, PartialEq, Eq, Encode, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(Decode, Serialize))]
+pub struct EventMetadataV13 {
+	pub name: StringBuf,
+	pub arguments: Vec<TypeRef>,
+	pub documentation: Vec<StringBuf>,
+}
+
+/// All metadata of the storage.
+#[derive(Clone, PartialEq, Eq, Encode, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(Decode, Serialize))]
+pub struct StorageMetadataV13 {
+	/// The common prefix used by all storage entries.
+	pub prefix: StringBuf,
+	pub entries: Vec<StorageEntryMetadataV13>,
+}
+
+/// All the metadata about one storage entry.
+#[derive(Clone, PartialEq, Eq, Encode, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(Decode, Serialize))]
+pub struct StorageEntryMetadataV13 {
+	pub name: StringBuf,
+	pub modifier: StorageEntryModifier,
+	pub ty: StorageEntryTypeV13,
+	pub default: Vec<u8>,
+	pub documentation: Vec<StringBuf>,
+}
+
+/// A storage entry type.
+#[derive(Clone, PartialEq, Eq, Encode, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(Decode, Serialize))]
+pub enum StorageEntryTypeV13 {
+	Plain(TypeRef),
+	Map {
+		hasher: StorageHasher,
+		key: TypeRef,
+		value: TypeRef,
+	},
+	DoubleMap {
+		hasher: StorageHasher,
+		key1: TypeRef,
+		key2: TypeRef,
+		value: TypeRef,
+		key2_hasher: StorageHasher,
+	},
+}
+
+/// All the metadata about one module constant.
+#[derive(Clone, PartialEq, Eq, Encode, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(Decode, Serialize))]
+pub struct ModuleConstantMetadataV13 {
+	pub name: StringBuf,
+	pub ty: TypeRef,
+	pub value: Vec<u8>,
+	pub documentation: Vec<StringBuf>,
+}
@@ -91,6 +91,8 @@ parameter_types! {
 }
 impl pallet_balances::Trait for Test {
 	type MaxLocks = ();
+	type MaxReserves = ();
+	type ReserveIdentifier = [u8; 8];
 	type Balance = u64;
 	type Event = TestEvent;
 	type DustRemoval = ();
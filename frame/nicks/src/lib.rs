@@ -294,6 +294,8 @@ mod tests {
 	}
 	impl pallet_balances::Trait for Test {
 		type MaxLocks = ();
+		type MaxReserves = ();
+		type ReserveIdentifier = [u8; 8];
 		type Balance = u64;
 		type Event = ();
 		type DustRemoval = ();
@@ -0,0 +1,407 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2020 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # Preimage Module
+//!
+//! A module for storing preimages of hashes on chain, so that other modules may refer to large
+//! pieces of data, such as calls, by their hash alone.
+//!
+//! - [`preimage::Trait`](./trait.Trait.html)
+//! - [`Call`](./enum.Call.html)
+//!
+//! ## Overview
+//!
+//! A preimage may be noted in one of two ways:
+//!
+//! * **Deposit-based:** Any signed account may note a preimage by placing a deposit of
+//!   `BaseDeposit` plus `ByteDeposit` for each byte of the preimage. The account may later unnote
+//!   it to get the deposit back, unless it has since been requested.
+//! * **Requested:** The `ManagerOrigin`, or any other module through the `PreimageProvider` trait,
+//!   may request the preimage of a hash. Noting a requested preimage is free, and any deposit
+//!   previously placed for it is returned. Requests are reference counted, so a preimage which is
+//!   requested by several modules is only removed once all of them have unrequested it.
+//!
+//! The `ManagerOrigin` may also note and unnote preimages without any deposit.
+//!
+//! Other modules interact with this module through the
+//! [`PreimageProvider`](../frame_support/traits/trait.PreimageProvider.html) and
+//! [`PreimageRecipient`](../frame_support/traits/trait.PreimageRecipient.html) traits.
+//!
+//! The Referenda and Democracy modules keep the preimages of their proposals in this module, and
+//! the Scheduler module looks up the calls of tasks which are scheduled by hash here.
+//!
+//! ## Interface
+//!
+//! ### Dispatchable Functions
+//!
+//! * `note_preimage` - Note a preimage, placing a deposit unless requested or made by the
+//!   `ManagerOrigin`.
+//! * `unnote_preimage` - Clear an unrequested preimage, returning any deposit.
+//! * `request_preimage` - Request a preimage to be uploaded and kept on chain.
+//! * `unrequest_preimage` - Clear a previous preimage request.
+//!
+//! [`Call`]: ./enum.Call.html
+//! [`Trait`]: ./trait.Trait.html
+
+// Ensure we're `no_std` when compiling for Wasm.
+#![cfg_attr(not(feature = "std"), no_std)]
+
+mod tests;
+mod benchmarking;
+mod default_weight;
+
+use sp_std::prelude::*;
+use codec::{Encode, Decode};
+use frame_support::{
+	decl_module, decl_event, decl_storage, decl_error, ensure, RuntimeDebug,
+	dispatch::{DispatchError, DispatchResult, DispatchResultWithPostInfo},
+	traits::{
+		Currency, ReservableCurrency, EnsureOrigin, Get, PreimageProvider, PreimageRecipient,
+	},
+	weights::{Weight, Pays},
+	type_info::TypeInfo,
+};
+use sp_runtime::traits::{Hash, Saturating, BadOrigin};
+use frame_system::ensure_signed;
+
+type BalanceOf<T> =
+	<<T as Trait>::Currency as Currency<<T as frame_system::Trait>::AccountId>>::Balance;
+
+pub trait WeightInfo {
+	fn note_preimage(s: u32, ) -> Weight;
+	fn unnote_preimage() -> Weight;
+	fn request_preimage() -> Weight;
+	fn unrequest_preimage() -> Weight;
+}
+
+/// The module's configuration trait.
+pub trait Trait: frame_system::Trait {
+	/// The overarching event type.
+	type Event: From<Event<Self>> + Into<<Self as frame_system::Trait>::Event>;
+
+	/// Currency type for this module.
+	type Currency: ReservableCurrency<Self::AccountId>;
+
+	/// An origin that can request a preimage be placed on-chain without a deposit or fee, or
+	/// manage existing preimages.
+	type ManagerOrigin: EnsureOrigin<Self::Origin>;
+
+	/// Max size allowed for a preimage.
+	type MaxSize: Get<u32>;
+
+	/// The base deposit for placing a preimage on chain.
+	type BaseDeposit: Get<BalanceOf<Self>>;
+
+	/// The per-byte deposit for placing a preimage on chain.
+	type ByteDeposit: Get<BalanceOf<Self>>;
+
+	/// Weight information for extrinsics in this pallet.
+	type WeightInfo: WeightInfo;
+}
+
+/// A type to note whether a preimage is owned by a user or the system.
+#[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub enum RequestStatus<AccountId, Balance> {
+	/// The associated preimage has not yet been requested by the system. The given deposit (if
+	/// some) is being held until either it becomes requested or the user retracts the preimage.
+	Unrequested(Option<(AccountId, Balance)>),
+	/// There are a non-zero number of outstanding requests for this hash by this chain. If there
+	/// is a preimage registered, then it may be removed iff this counter becomes zero.
+	Requested(u32),
+}
+
+decl_storage! {
+	trait Store for Module<T: Trait> as Preimage {
+		/// The request status of a given hash.
+		pub StatusFor get(fn status_for):
+			map hasher(identity) T::Hash => Option<RequestStatus<T::AccountId, BalanceOf<T>>>;
+
+		/// The preimages stored by this module.
+		pub PreimageFor get(fn preimage_for): map hasher(identity) T::Hash => Option<Vec<u8>>;
+	}
+}
+
+decl_event! {
+	pub enum Event<T> where
+		Hash = <T as frame_system::Trait>::Hash,
+	{
+		/// A preimage has been noted. \[hash\]
+		Noted(Hash),
+		/// A preimage has been requested. \[hash\]
+		Requested(Hash),
+		/// A preimage has been cleared. \[hash\]
+		Cleared(Hash),
+	}
+}
+
+decl_error! {
+	pub enum Error for Module<T: Trait> {
+		/// Preimage is too large to store on-chain.
+		TooLarge,
+		/// Preimage has already been noted on-chain.
+		AlreadyNoted,
+		/// The user is not authorized to perform this action.
+		NotAuthorized,
+		/// The preimage cannot be removed since it has not yet been noted.
+		NotNoted,
+		/// A preimage may not be removed when there are outstanding requests.
+		Requested,
+		/// The preimage request cannot be removed since no outstanding requests exist.
+		NotRequested,
+	}
+}
+
+decl_module! {
+	pub struct Module<T: Trait> for enum Call where origin: T::Origin {
+		type Error = Error<T>;
+
+		/// Max size allowed for a preimage.
+		const MaxSize: u32 = T::MaxSize::get();
+
+		/// The base deposit for placing a preimage on chain.
+		const BaseDeposit: BalanceOf<T> = T::BaseDeposit::get();
+
+		/// The per-byte deposit for placing a preimage on chain.
+		const ByteDeposit: BalanceOf<T> = T::ByteDeposit::get();
+
+		fn deposit_event() = default;
+
+		/// Register a preimage on-chain.
+		///
+		/// If the preimage was previously requested, no fees or deposits are taken for providing
+		/// the preimage. Otherwise, a deposit is taken proportional to the size of the preimage,
+		/// unless the origin is the `ManagerOrigin`.
+		///
+		/// The dispatch origin for this call must be _Signed_ or the `ManagerOrigin`.
+		///
+		/// - `bytes`: The preimage to be noted.
+		///
+		/// Emits `Noted` event when successful.
+		///
+		/// Weight: `O(S)` where S is the size of the preimage.
+		#[weight = T::WeightInfo::note_preimage(bytes.len() as u32)]
+		fn note_preimage(origin, bytes: Vec<u8>) -> DispatchResultWithPostInfo {
+			// We accept a signed origin which will pay a deposit, or a root origin where a deposit
+			// is not taken.
+			let maybe_sender = Self::ensure_signed_or_manager(origin)?;
+			let was_requested = Self::note_bytes(bytes, maybe_sender.as_ref())?;
+			if was_requested {
+				Ok(Pays::No.into())
+			} else {
+				Ok(().into())
+			}
+		}
+
+		/// Clear an unrequested preimage from the runtime storage, returning any deposit.
+		///
+		/// The dispatch origin for this call must be _Signed_ by the account which noted the
+		/// preimage, or the `ManagerOrigin`.
+		///
+		/// - `hash`: The hash of the preimage to be removed from the store.
+		///
+		/// Emits `Cleared` event when successful.
+		///
+		/// Weight: `O(1)`.
+		#[weight = T::WeightInfo::unnote_preimage()]
+		fn unnote_preimage(origin, hash: T::Hash) {
+			let maybe_sender = Self::ensure_signed_or_manager(origin)?;
+			Self::do_unnote_preimage(&hash, maybe_sender)?;
+		}
+
+		/// Request a preimage be uploaded to the chain without paying any fees or deposits.
+		///
+		/// If the preimage requests has already been provided on-chain, we unreserve any deposit
+		/// a user may have paid, and take the control of the preimage out of their hands.
+		///
+		/// The dispatch origin for this call must be the `ManagerOrigin`.
+		///
+		/// - `hash`: The hash of the preimage to be requested.
+		///
+		/// Emits `Requested` event if this is the first request for the preimage.
+		///
+		/// Weight: `O(1)`.
+		#[weight = T::WeightInfo::request_preimage()]
+		fn request_preimage(origin, hash: T::Hash) {
+			T::ManagerOrigin::ensure_origin(origin)?;
+			Self::do_request_preimage(&hash);
+		}
+
+		/// Clear a previously made request for a preimage.
+		///
+		/// The preimage, if any, is removed once all requests for it have been cleared.
+		///
+		/// The dispatch origin for this call must be the `ManagerOrigin`.
+		///
+		/// - `hash`: The hash of the preimage whose request should be cleared.
+		///
+		/// Emits `Cleared` event if this was the last request and the preimage is removed.
+		///
+		/// Weight: `O(1)`.
+		#[weight = T::WeightInfo::unrequest_preimage()]
+		fn unrequest_preimage(origin, hash: T::Hash) {
+			T::ManagerOrigin::ensure_origin(origin)?;
+			Self::do_unrequest_preimage(&hash)?;
+		}
+	}
+}
+
+impl<T: Trait> Module<T> {
+	/// Ensure that the origin is either the `ManagerOrigin` or a signed origin.
+	fn ensure_signed_or_manager(origin: T::Origin) -> Result<Option<T::AccountId>, BadOrigin> {
+		if T::ManagerOrigin::ensure_origin(origin.clone()).is_ok() {
+			return Ok(None)
+		}
+		let who = ensure_signed(origin)?;
+		Ok(Some(who))
+	}
+
+	/// Store some preimage on chain.
+	///
+	/// We verify that the preimage is within the bounds of what the pallet supports.
+	///
+	/// If the preimage was requested to be uploaded, then the user pays no deposits or tx fees.
+	fn note_bytes(
+		preimage: Vec<u8>,
+		maybe_depositor: Option<&T::AccountId>,
+	) -> Result<bool, DispatchError> {
+		ensure!(preimage.len() <= T::MaxSize::get() as usize, Error::<T>::TooLarge);
+		let hash = T::Hashing::hash(&preimage);
+		ensure!(!PreimageFor::<T>::contains_key(hash), Error::<T>::AlreadyNoted);
+
+		// We take a deposit only if there is a provided depositor, and the preimage was not
+		// previously requested. This also allows the tx to pay no fee.
+		let was_requested = match (StatusFor::<T>::get(hash), maybe_depositor) {
+			(Some(RequestStatus::Requested(..)), _) => true,
+			(Some(RequestStatus::Unrequested(..)), _) => Err(Error::<T>::AlreadyNoted)?,
+			(None, None) => {
+				StatusFor::<T>::insert(hash, RequestStatus::Unrequested(None));
+				false
+			},
+			(None, Some(depositor)) => {
+				let length = preimage.len() as u32;
+				let deposit = T::BaseDeposit::get()
+					.saturating_add(T::ByteDeposit::get().saturating_mul(length.into()));
+				T::Currency::reserve(depositor, deposit)?;
+				let status = RequestStatus::Unrequested(Some((depositor.clone(), deposit)));
+				StatusFor::<T>::insert(hash, status);
+				false
+			},
+		};
+
+		PreimageFor::<T>::insert(hash, preimage);
+		Self::deposit_event(RawEvent::Noted(hash));
+
+		Ok(was_requested)
+	}
+
+	/// This function will add a hash to the list of requested preimages.
+	///
+	/// If the preimage already exists before the request is made, the deposit for the preimage is
+	/// returned to the user, and removed from their management.
+	fn do_request_preimage(hash: &T::Hash) {
+		let count = StatusFor::<T>::get(hash).map_or(1, |x| match x {
+			RequestStatus::Requested(count) => count.saturating_add(1),
+			RequestStatus::Unrequested(None) => 1,
+			RequestStatus::Unrequested(Some((owner, deposit))) => {
+				// Return the deposit - the preimage now has outstanding requests.
+				T::Currency::unreserve(&owner, deposit);
+				1
+			},
+		});
+		StatusFor::<T>::insert(hash, RequestStatus::Requested(count));
+		if count == 1 {
+			Self::deposit_event(RawEvent::Requested(*hash));
+		}
+	}
+
+	/// Clear a preimage from the storage of the chain, returning any deposit that may be reserved.
+	///
+	/// If `maybe_check_owner` is provided, we verify that it is the correct owner before
+	/// clearing the data.
+	fn do_unnote_preimage(
+		hash: &T::Hash,
+		maybe_check_owner: Option<T::AccountId>,
+	) -> DispatchResult {
+		match StatusFor::<T>::get(hash).ok_or(Error::<T>::NotNoted)? {
+			RequestStatus::Unrequested(Some((owner, deposit))) => {
+				ensure!(maybe_check_owner.map_or(true, |c| c == owner), Error::<T>::NotAuthorized);
+				T::Currency::unreserve(&owner, deposit);
+			},
+			RequestStatus::Unrequested(None) => {
+				ensure!(maybe_check_owner.is_none(), Error::<T>::NotAuthorized);
+			},
+			RequestStatus::Requested(_) => Err(Error::<T>::Requested)?,
+		}
+		StatusFor::<T>::remove(hash);
+		PreimageFor::<T>::remove(hash);
+		Self::deposit_event(RawEvent::Cleared(*hash));
+		Ok(())
+	}
+
+	/// Clear a preimage request.
+	fn do_unrequest_preimage(hash: &T::Hash) -> DispatchResult {
+		match StatusFor::<T>::get(hash).ok_or(Error::<T>::NotRequested)? {
+			RequestStatus::Requested(count) if count > 1 => {
+				StatusFor::<T>::insert(hash, RequestStatus::Requested(count - 1));
+			},
+			RequestStatus::Requested(count) => {
+				debug_assert!(count == 1, "preimage request counter at zero?");
+				PreimageFor::<T>::remove(hash);
+				StatusFor::<T>::remove(hash);
+				Self::deposit_event(RawEvent::Cleared(*hash));
+			},
+			RequestStatus::Unrequested(_) => Err(Error::<T>::NotRequested)?,
+		}
+		Ok(())
+	}
+}
+
+impl<T: Trait> PreimageProvider<T::Hash> for Module<T> {
+	fn have_preimage(hash: &T::Hash) -> bool {
+		PreimageFor::<T>::contains_key(hash)
+	}
+
+	fn preimage_requested(hash: &T::Hash) -> bool {
+		matches!(StatusFor::<T>::get(hash), Some(RequestStatus::Requested(..)))
+	}
+
+	fn get_preimage(hash: &T::Hash) -> Option<Vec<u8>> {
+		PreimageFor::<T>::get(hash)
+	}
+
+	fn request_preimage(hash: &T::Hash) {
+		Self::do_request_preimage(hash)
+	}
+
+	fn unrequest_preimage(hash: &T::Hash) {
+		let res = Self::do_unrequest_preimage(hash);
+		debug_assert!(res.is_ok(), "do_unrequest_preimage failed - counter underflow?");
+	}
+}
+
+impl<T: Trait> PreimageRecipient<T::Hash> for Module<T> {
+	type MaxSize = T::MaxSize;
+
+	fn note_preimage(bytes: Vec<u8>) -> DispatchResult {
+		Self::note_bytes(bytes, None).map(|_| ())
+	}
+
+	fn unnote_preimage(hash: &T::Hash) {
+		// Requested or unknown preimages are left alone; this is only a hint.
+		let _ = Self::do_unnote_preimage(hash, None);
+	}
+}
@@ -213,6 +213,25 @@ benchmarks! {
 		assert_eq!(proxies.len() as u32, 0);
 	}
 
+	remove_expired_proxies {
+		let p in ...;
+		let caller: T::AccountId = whitelisted_caller();
+		let expiry = system::Module::<T>::block_number() + 1u32.into();
+		Proxy::<T>::add_limited_proxy(
+			RawOrigin::Signed(caller.clone()).into(),
+			account("target", T::MaxProxies::get().into(), SEED),
+			T::ProxyType::default(),
+			T::BlockNumber::zero(),
+			Some(expiry),
+			None,
+		)?;
+		system::Module::<T>::set_block_number(expiry);
+	}: _(RawOrigin::Signed(account("anyone", 0, SEED)), caller.clone())
+	verify {
+		let (proxies, _) = Proxies::<T>::get(caller);
+		assert_eq!(proxies.len() as u32, p);
+	}
+
 	anonymous {
 		let p in ...;
 		let caller: T::AccountId = whitelisted_caller();
@@ -272,6 +291,7 @@ mod tests {
 			assert_ok!(test_benchmark_add_proxy::<Test>());
 			assert_ok!(test_benchmark_remove_proxy::<Test>());
 			assert_ok!(test_benchmark_remove_proxies::<Test>());
+			assert_ok!(test_benchmark_remove_expired_proxies::<Test>());
 			assert_ok!(test_benchmark_anonymous::<Test>());
 			assert_ok!(test_benchmark_kill_anonymous::<Test>());
 		});
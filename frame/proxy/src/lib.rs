@@ -23,6 +23,13 @@
 //! wish to execute some duration prior to execution happens. In this case, the target account may
 //! reject the announcement and in doing so, veto the execution.
 //!
+//! A proxy may additionally be limited in time and in spending power. A proxy with an expiry
+//! block may no longer be used from that block onwards, and anyone may clean it up with
+//! `remove_expired_proxies`, returning the deposit to the delegator. A proxy with a spending
+//! limit may only reduce the free balance of the delegating account by that amount over its
+//! lifetime, so funds reserved by a proxied call count as spent; a proxied call which would
+//! exceed it is reverted.
+//!
 //! - [`proxy::Trait`](./trait.Trait.html)
 //! - [`Call`](./enum.Call.html)
 //!
@@ -41,11 +48,15 @@
 use sp_std::prelude::*;
 use codec::{Encode, Decode};
 use sp_io::hashing::blake2_256;
-use sp_runtime::{DispatchResult, traits::{Dispatchable, Zero, Hash, Member, Saturating}};
+use sp_runtime::{
+	DispatchResult, TransactionOutcome, traits::{Dispatchable, Zero, Hash, Member, Saturating},
+};
 use frame_support::{
 	decl_module, decl_event, decl_error, decl_storage, Parameter, ensure, RuntimeDebug, traits::{
 		Get, ReservableCurrency, Currency, InstanceFilter, OriginTrait, IsType, IsSubType,
-	}, weights::{Weight, GetDispatchInfo}, dispatch::PostDispatchInfo, storage::IterableStorageMap,
+	}, weights::{Weight, GetDispatchInfo}, dispatch::PostDispatchInfo,
+	storage::{IterableStorageMap, with_transaction},
+	type_info::TypeInfo,
 };
 use frame_system::{self as system, ensure_signed};
 use frame_support::dispatch::DispatchError;
@@ -67,6 +78,7 @@ pub trait WeightInfo {
 	fn remove_proxies(p: u32, ) -> Weight;
 	fn anonymous(p: u32, ) -> Weight;
 	fn kill_anonymous(p: u32, ) -> Weight;
+	fn remove_expired_proxies(p: u32, ) -> Weight;
 }
 
 /// Configuration trait.
@@ -125,10 +137,19 @@ pub trait Trait: frame_system::Trait {
 	type AnnouncementDepositFactor: Get<BalanceOf<Self>>;
 }
 
+/// The amount of funds which a proxy may move out of the account it acts for.
+#[derive(Encode, Decode, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, RuntimeDebug, TypeInfo)]
+pub struct SpendingLimit<Balance> {
+	/// The total amount which may be spent over the lifetime of the proxy.
+	pub limit: Balance,
+	/// The amount spent so far.
+	pub spent: Balance,
+}
+
 /// The parameters under which a particular account has a proxy relationship with some other
 /// account.
-#[derive(Encode, Decode, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, RuntimeDebug)]
-pub struct ProxyDefinition<AccountId, ProxyType, BlockNumber> {
+#[derive(Encode, Decode, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, RuntimeDebug, TypeInfo)]
+pub struct ProxyDefinition<AccountId, ProxyType, BlockNumber, Balance> {
 	/// The account which may act on behalf of another.
 	delegate: AccountId,
 	/// A value defining the subset of calls that it is allowed to make.
@@ -136,10 +157,35 @@ pub struct ProxyDefinition<AccountId, ProxyType, BlockNumber> {
 	/// The number of blocks that an announcement must be in place for before the corresponding call
 	/// may be dispatched. If zero, then no announcement is needed.
 	delay: BlockNumber,
+	/// The block from which the proxy may no longer be used, if any.
+	expiry: Option<BlockNumber>,
+	/// The limit on the funds that the proxy may spend on behalf of the delegator, if any.
+	spending_limit: Option<SpendingLimit<Balance>>,
+}
+
+impl<AccountId, ProxyType, BlockNumber: PartialOrd, Balance>
+	ProxyDefinition<AccountId, ProxyType, BlockNumber, Balance>
+{
+	/// The parameters which identify this proxy within the delegator's set of proxies.
+	fn key(&self) -> (&AccountId, &ProxyType, &BlockNumber) {
+		(&self.delegate, &self.proxy_type, &self.delay)
+	}
+
+	/// Whether the proxy has expired as of block `now`.
+	fn is_expired(&self, now: &BlockNumber) -> bool {
+		self.expiry.as_ref().map_or(false, |expiry| now >= expiry)
+	}
 }
 
+type ProxyDefinitionOf<T> = ProxyDefinition<
+	<T as frame_system::Trait>::AccountId,
+	<T as Trait>::ProxyType,
+	<T as frame_system::Trait>::BlockNumber,
+	BalanceOf<T>,
+>;
+
 /// Details surrounding a specific instance of an announcement to make a call.
-#[derive(Encode, Decode, Clone, Copy, Eq, PartialEq, RuntimeDebug)]
+#[derive(Encode, Decode, Clone, Copy, Eq, PartialEq, RuntimeDebug, TypeInfo)]
 pub struct Announcement<AccountId, Hash, BlockNumber> {
 	/// The account which made the announcement.
 	real: AccountId,
@@ -151,16 +197,38 @@ pub struct Announcement<AccountId, Hash, BlockNumber> {
 
 type CallHashOf<T> = <<T as Trait>::CallHasher as Hash>::Output;
 
+// A value placed in storage that represents the current version of the Proxy storage.
+// This value is used by the `on_runtime_upgrade` logic to determine whether we run
+// storage migration logic.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+enum Releases {
+	/// Proxies with an announcement delay.
+	V1,
+	/// Proxies with an optional expiry and spending limit.
+	V2,
+}
+
+impl Default for Releases {
+	fn default() -> Self {
+		Releases::V1
+	}
+}
+
 decl_storage! {
 	trait Store for Module<T: Trait> as Proxy {
 		/// The set of account proxies. Maps the account which has delegated to the accounts
 		/// which are being delegated to, together with the amount held on deposit.
 		pub Proxies get(fn proxies): map hasher(twox_64_concat) T::AccountId
-			=> (Vec<ProxyDefinition<T::AccountId, T::ProxyType, T::BlockNumber>>, BalanceOf<T>);
+			=> (Vec<ProxyDefinitionOf<T>>, BalanceOf<T>);
 
 		/// The announcements made by the proxy (key).
 		pub Announcements get(fn announcements): map hasher(twox_64_concat) T::AccountId
 			=> (Vec<Announcement<T::AccountId, CallHashOf<T>, T::BlockNumber>>, BalanceOf<T>);
+
+		/// Storage version of the pallet.
+		///
+		/// New networks start with the last version.
+		StorageVersion build(|_| Releases::V2): Releases;
 	}
 }
 
@@ -180,6 +248,10 @@ decl_error! {
 		NoPermission,
 		/// Announcement, if made at all, was made too recently.
 		Unannounced,
+		/// The proxy has expired, or the expiry given is not in the future.
+		Expired,
+		/// The call would take the proxy beyond its spending limit.
+		SpendingLimitExceeded,
 	}
 }
 
@@ -225,6 +297,15 @@ decl_module! {
 		/// `AnnouncementDepositFactor` metadata shadow.
 		const AnnouncementDepositFactor: BalanceOf<T> = T::AnnouncementDepositFactor::get();
 
+		fn on_runtime_upgrade() -> Weight {
+			if StorageVersion::get() == Releases::V1 {
+				StorageVersion::put(Releases::V2);
+				migration::migrate_to_limited_proxies::<T>()
+			} else {
+				0
+			}
+		}
+
 		/// Dispatch the given `call` from an account that the sender is authorised for through
 		/// `add_proxy`.
 		///
@@ -245,7 +326,9 @@ decl_module! {
 			(T::WeightInfo::proxy(T::MaxProxies::get().into())
 				.saturating_add(di.weight)
 				 // AccountData for inner call origin accountdata.
-				.saturating_add(T::DbWeight::get().reads_writes(1, 1)),
+				.saturating_add(T::DbWeight::get().reads_writes(1, 1))
+				// Spending limit bookkeeping.
+				.saturating_add(T::DbWeight::get().writes(1)),
 			di.class)
 		}]
 		fn proxy(origin,
@@ -356,6 +439,8 @@ decl_module! {
 				delegate: who.clone(),
 				proxy_type: proxy_type.clone(),
 				delay,
+				expiry: None,
+				spending_limit: None,
 			};
 			Proxies::<T>::insert(&anonymous, (vec![proxy_def], deposit));
 			Self::deposit_event(RawEvent::AnonymousCreated(anonymous, who, proxy_type, index));
@@ -423,9 +508,7 @@ decl_module! {
 		#[weight = T::WeightInfo::announce(T::MaxPending::get(), T::MaxProxies::get().into())]
 		fn announce(origin, real: T::AccountId, call_hash: CallHashOf<T>) {
 			let who = ensure_signed(origin)?;
-			Proxies::<T>::get(&real).0.into_iter()
-				.find(|x| &x.delegate == &who)
-				.ok_or(Error::<T>::NotProxy)?;
+			Self::find_proxy(&real, &who, None)?;
 
 			let announcement = Announcement {
 				real: real.clone(),
@@ -514,7 +597,9 @@ decl_module! {
 			(T::WeightInfo::proxy_announced(T::MaxPending::get(), T::MaxProxies::get().into())
 				.saturating_add(di.weight)
 				 // AccountData for inner call origin accountdata.
-				.saturating_add(T::DbWeight::get().reads_writes(1, 1)),
+				.saturating_add(T::DbWeight::get().reads_writes(1, 1))
+				// Spending limit bookkeeping.
+				.saturating_add(T::DbWeight::get().writes(1)),
 			di.class)
 		}]
 		fn proxy_announced(origin,
@@ -534,6 +619,74 @@ decl_module! {
 
 			Self::do_proxy(def, real, *call);
 		}
+
+		/// Register a proxy account for the sender which may only be used until a given block
+		/// and/or may only spend up to a given amount on the sender's behalf.
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		///
+		/// Parameters:
+		/// - `delegate`: The account that the `caller` would like to make a proxy.
+		/// - `proxy_type`: The permissions allowed for this proxy account.
+		/// - `delay`: The announcement period required of the initial proxy. Will generally be
+		/// zero.
+		/// - `expiry`: The block from which the proxy may no longer be used. Must be in the future.
+		/// - `spending_limit`: The maximum amount by which calls made through the proxy may reduce
+		/// the free balance of the sender, over the lifetime of the proxy. Funds reserved by those
+		/// calls are counted as spent.
+		///
+		/// # <weight>
+		/// Weight is a function of the number of proxies the user has (P).
+		/// # </weight>
+		#[weight = T::WeightInfo::add_proxy(T::MaxProxies::get().into())]
+		fn add_limited_proxy(origin,
+			delegate: T::AccountId,
+			proxy_type: T::ProxyType,
+			delay: T::BlockNumber,
+			expiry: Option<T::BlockNumber>,
+			spending_limit: Option<BalanceOf<T>>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			if let Some(ref expiry) = expiry {
+				ensure!(*expiry > system::Module::<T>::block_number(), Error::<T>::Expired);
+			}
+			let proxy_def = ProxyDefinition {
+				delegate,
+				proxy_type,
+				delay,
+				expiry,
+				spending_limit: spending_limit.map(|limit| SpendingLimit { limit, spent: Zero::zero() }),
+			};
+			Self::do_add_proxy(&who, proxy_def)
+		}
+
+		/// Remove all expired proxies of an account and return the corresponding deposit to it.
+		///
+		/// The dispatch origin for this call must be _Signed_. It may be any account.
+		///
+		/// Parameters:
+		/// - `delegator`: The account whose expired proxies should be removed.
+		///
+		/// Fails with `NotFound` if the account has no expired proxies.
+		///
+		/// # <weight>
+		/// Weight is a function of the number of proxies the user has (P).
+		/// # </weight>
+		#[weight = T::WeightInfo::remove_expired_proxies(T::MaxProxies::get().into())]
+		fn remove_expired_proxies(origin, delegator: T::AccountId) {
+			ensure_signed(origin)?;
+			Proxies::<T>::try_mutate_exists(&delegator, |x| -> DispatchResult {
+				let (mut proxies, old_deposit) = x.take().ok_or(Error::<T>::NotFound)?;
+				let removed = Self::prune_expired(&mut proxies);
+				ensure!(removed > 0, Error::<T>::NotFound);
+				let new_deposit = Self::deposit(proxies.len() as u32);
+				T::Currency::unreserve(&delegator, old_deposit.saturating_sub(new_deposit));
+				if !proxies.is_empty() {
+					*x = Some((proxies, new_deposit))
+				}
+				Ok(())
+			})?;
+		}
 	}
 }
 
@@ -579,10 +732,24 @@ impl<T: Trait> Module<T> {
 		proxy_type: T::ProxyType,
 		delay: T::BlockNumber,
 	) -> DispatchResult {
+		let proxy_def = ProxyDefinition {
+			delegate: delegatee,
+			proxy_type,
+			delay,
+			expiry: None,
+			spending_limit: None,
+		};
+		Self::do_add_proxy(delegator, proxy_def)
+	}
+
+	/// Register a proxy definition for the delegator, pruning any of its expired proxies first.
+	fn do_add_proxy(delegator: &T::AccountId, proxy_def: ProxyDefinitionOf<T>) -> DispatchResult {
 		Proxies::<T>::try_mutate(delegator, |(ref mut proxies, ref mut deposit)| {
+			Self::prune_expired(proxies);
 			ensure!(proxies.len() < T::MaxProxies::get() as usize, Error::<T>::TooMany);
-			let proxy_def = ProxyDefinition { delegate: delegatee, proxy_type, delay };
-			let i = proxies.binary_search(&proxy_def).err().ok_or(Error::<T>::Duplicate)?;
+			let i = proxies.binary_search_by(|p| p.key().cmp(&proxy_def.key()))
+				.err()
+				.ok_or(Error::<T>::Duplicate)?;
 			proxies.insert(i, proxy_def);
 			let new_deposit = Self::deposit(proxies.len() as u32);
 			if new_deposit > *deposit {
@@ -611,9 +778,10 @@ impl<T: Trait> Module<T> {
 	) -> DispatchResult {
 		Proxies::<T>::try_mutate_exists(delegator, |x| {
 			let (mut proxies, old_deposit) = x.take().ok_or(Error::<T>::NotFound)?;
-			let proxy_def = ProxyDefinition { delegate: delegatee, proxy_type, delay };
-			let i = proxies.binary_search(&proxy_def).ok().ok_or(Error::<T>::NotFound)?;
+			let key = (&delegatee, &proxy_type, &delay);
+			let i = proxies.binary_search_by(|p| p.key().cmp(&key)).ok().ok_or(Error::<T>::NotFound)?;
 			proxies.remove(i);
+			Self::prune_expired(&mut proxies);
 			let new_deposit = Self::deposit(proxies.len() as u32);
 			if new_deposit > old_deposit {
 				T::Currency::reserve(delegator, new_deposit - old_deposit)?;
@@ -627,6 +795,14 @@ impl<T: Trait> Module<T> {
 		})
 	}
 
+	/// Remove all proxies in `proxies` which have expired, returning how many were removed.
+	fn prune_expired(proxies: &mut Vec<ProxyDefinitionOf<T>>) -> usize {
+		let now = system::Module::<T>::block_number();
+		let orig_len = proxies.len();
+		proxies.retain(|p| !p.is_expired(&now));
+		orig_len - proxies.len()
+	}
+
 	pub fn deposit(num_proxies: u32) -> BalanceOf<T> {
 		if num_proxies == 0 {
 			Zero::zero()
@@ -682,35 +858,75 @@ impl<T: Trait> Module<T> {
 		real: &T::AccountId,
 		delegate: &T::AccountId,
 		force_proxy_type: Option<T::ProxyType>,
-	) -> Result<ProxyDefinition<T::AccountId, T::ProxyType, T::BlockNumber>, DispatchError> {
-		let f = |x: &ProxyDefinition<T::AccountId, T::ProxyType, T::BlockNumber>| -> bool {
+	) -> Result<ProxyDefinitionOf<T>, DispatchError> {
+		let f = |x: &ProxyDefinitionOf<T>| -> bool {
 			&x.delegate == delegate && force_proxy_type.as_ref().map_or(true, |y| &x.proxy_type == y)
 		};
-		Ok(Proxies::<T>::get(real).0.into_iter().find(f).ok_or(Error::<T>::NotProxy)?)
+		let now = system::Module::<T>::block_number();
+		let mut candidates = Proxies::<T>::get(real).0.into_iter().filter(f).peekable();
+		ensure!(candidates.peek().is_some(), Error::<T>::NotProxy);
+		Ok(candidates.find(|x| !x.is_expired(&now)).ok_or(Error::<T>::Expired)?)
 	}
 
 	fn do_proxy(
-		def: ProxyDefinition<T::AccountId, T::ProxyType, T::BlockNumber>,
+		def: ProxyDefinitionOf<T>,
 		real: T::AccountId,
 		call: <T as Trait>::Call,
 	) {
+		let proxy_type = def.proxy_type.clone();
+		let is_limited = def.spending_limit.is_some();
 		// This is a freshly authenticated new account, the origin restrictions doesn't apply.
-		let mut origin: T::Origin = frame_system::RawOrigin::Signed(real).into();
+		let mut origin: T::Origin = frame_system::RawOrigin::Signed(real.clone()).into();
 		origin.add_filter(move |c: &<T as frame_system::Trait>::Call| {
 			let c = <T as Trait>::Call::from_ref(c);
 			// We make sure the proxy call does access this pallet to change modify proxies.
 			match c.is_sub_type() {
+				// Proxy call with a spending limit cannot add another proxy to escape it.
+				Some(Call::add_proxy(..)) | Some(Call::add_limited_proxy(..)) if is_limited => false,
 				// Proxy call cannot add or remove a proxy with more permissions than it already has.
 				Some(Call::add_proxy(_, ref pt, _)) | Some(Call::remove_proxy(_, ref pt, _))
-					if !def.proxy_type.is_superset(&pt) => false,
+				| Some(Call::add_limited_proxy(_, ref pt, ..))
+					if !proxy_type.is_superset(&pt) => false,
 				// Proxy call cannot remove all proxies or kill anonymous proxies unless it has full permissions.
 				Some(Call::remove_proxies(..)) | Some(Call::kill_anonymous(..))
-					if def.proxy_type != T::ProxyType::default() => false,
-				_ => def.proxy_type.filter(c)
+					if proxy_type != T::ProxyType::default() => false,
+				_ => proxy_type.filter(c)
+			}
+		});
+
+		let e = match def.spending_limit {
+			None => call.dispatch(origin).map(|_| ()).map_err(|e| e.error),
+			// The whole call is reverted if it takes the proxy beyond its spending limit.
+			Some(limit) => with_transaction(|| {
+				// Reserved funds are out of reach of the sender as much as transferred ones, so the
+				// free balance is measured.
+				let before = T::Currency::free_balance(&real);
+				if let Err(e) = call.dispatch(origin) {
+					return TransactionOutcome::Rollback(Err(e.error));
+				}
+				let spent = before.saturating_sub(T::Currency::free_balance(&real));
+				let total_spent = limit.spent.saturating_add(spent);
+				if total_spent > limit.limit {
+					return TransactionOutcome::Rollback(Err(Error::<T>::SpendingLimitExceeded.into()));
+				}
+				Self::note_spent(&real, &def, total_spent);
+				TransactionOutcome::Commit(Ok(()))
+			}),
+		};
+		Self::deposit_event(RawEvent::ProxyExecuted(e));
+	}
+
+	/// Record the total amount spent by the proxy `def` of `real`, if it is still registered.
+	fn note_spent(real: &T::AccountId, def: &ProxyDefinitionOf<T>, total_spent: BalanceOf<T>) {
+		Proxies::<T>::mutate_exists(real, |maybe_proxies| {
+			if let Some((proxies, _)) = maybe_proxies {
+				if let Ok(i) = proxies.binary_search_by(|p| p.key().cmp(&def.key())) {
+					if let Some(ref mut limit) = proxies[i].spending_limit {
+						limit.spent = total_spent;
+					}
+				}
 			}
 		});
-		let e = call.dispatch(origin);
-		Self::deposit_event(RawEvent::ProxyExecuted(e.map(|_| ()).map_err(|e| e.error)));
 	}
 }
 
@@ -734,6 +950,32 @@ pub mod migration {
 						delegate: a,
 						proxy_type: t,
 						delay: Zero::zero(),
+						expiry: None,
+						spending_limit: None,
+					})
+					.collect::<Vec<_>>(),
+				deposit,
+			))
+		);
+		T::MaximumBlockWeight::get()
+	}
+
+	/// Migration code for the introduction of expiring and spending-limited proxies.
+	///
+	/// Before this migration, `ProxyDefinition` held only a delegate, a proxy type and an
+	/// announcement delay. It now also holds an optional expiry block and an optional spending
+	/// limit. This function takes any existing proxies using the old format and migrates them to
+	/// the new one without an expiry or a spending limit, keeping their behaviour unchanged.
+	pub fn migrate_to_limited_proxies<T: Trait>() -> Weight {
+		Proxies::<T>::translate::<(Vec<(T::AccountId, T::ProxyType, T::BlockNumber)>, BalanceOf<T>), _>(
+			|_, (targets, deposit)| Some((
+				targets.into_iter()
+					.map(|(delegate, proxy_type, delay)| ProxyDefinition {
+						delegate,
+						proxy_type,
+						delay,
+						expiry: None,
+						spending_limit: None,
 					})
 					.collect::<Vec<_>>(),
 				deposit,
@@ -93,6 +93,8 @@ parameter_types! {
 }
 impl pallet_balances::Trait for Test {
 	type MaxLocks = ();
+	type MaxReserves = ();
+	type ReserveIdentifier = [u8; 8];
 	type Balance = u64;
 	type Event = TestEvent;
 	type DustRemoval = ();
@@ -103,6 +105,7 @@ impl pallet_balances::Trait for Test {
 impl pallet_utility::Trait for Test {
 	type Event = TestEvent;
 	type Call = Call;
+	type PalletsOrigin = OriginCaller;
 	type WeightInfo = ();
 }
 parameter_types! {
@@ -484,3 +487,154 @@ fn anonymous_works() {
 		assert_noop!(Proxy::proxy(Origin::signed(1), anon, None, call.clone()), Error::<Test>::NotProxy);
 	});
 }
+
+#[test]
+fn expiring_proxies_work() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			Proxy::add_limited_proxy(Origin::signed(1), 2, ProxyType::Any, 0, Some(1), None),
+			Error::<Test>::Expired
+		);
+		assert_ok!(Proxy::add_limited_proxy(Origin::signed(1), 2, ProxyType::Any, 0, Some(3), None));
+		assert_ok!(Proxy::add_proxy(Origin::signed(1), 3, ProxyType::Any, 0));
+		assert_eq!(Balances::reserved_balance(1), 3);
+
+		let call = Box::new(Call::Balances(BalancesCall::transfer(6, 1)));
+		System::set_block_number(2);
+		assert_ok!(Proxy::proxy(Origin::signed(2), 1, None, call.clone()));
+		expect_event(RawEvent::ProxyExecuted(Ok(())));
+		assert_eq!(Balances::free_balance(6), 1);
+
+		System::set_block_number(3);
+		assert_noop!(Proxy::proxy(Origin::signed(2), 1, None, call.clone()), Error::<Test>::Expired);
+		assert_noop!(Proxy::announce(Origin::signed(2), 1, [1; 32].into()), Error::<Test>::Expired);
+		assert_noop!(Proxy::remove_expired_proxies(Origin::signed(4), 3), Error::<Test>::NotFound);
+
+		// anyone may clean up an expired proxy, returning the deposit to the delegator.
+		assert_ok!(Proxy::remove_expired_proxies(Origin::signed(4), 1));
+		assert_eq!(Balances::reserved_balance(1), 2);
+		assert_eq!(Proxy::proxies(1).0.len(), 1);
+		assert_noop!(Proxy::proxy(Origin::signed(2), 1, None, call.clone()), Error::<Test>::NotProxy);
+		assert_noop!(Proxy::remove_expired_proxies(Origin::signed(4), 1), Error::<Test>::NotFound);
+	});
+}
+
+#[test]
+fn expired_proxies_are_pruned_lazily() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Proxy::add_limited_proxy(Origin::signed(1), 2, ProxyType::Any, 0, Some(2), None));
+		assert_ok!(Proxy::add_limited_proxy(Origin::signed(1), 3, ProxyType::Any, 0, Some(2), None));
+		assert_noop!(Proxy::add_proxy(Origin::signed(1), 2, ProxyType::Any, 0), Error::<Test>::Duplicate);
+		assert_ok!(Proxy::add_limited_proxy(Origin::signed(1), 4, ProxyType::Any, 0, Some(2), None));
+		assert_ok!(Proxy::add_proxy(Origin::signed(1), 5, ProxyType::Any, 0));
+		assert_eq!(Balances::reserved_balance(1), 5);
+
+		System::set_block_number(2);
+		// adding a proxy prunes the expired ones and refunds their deposit.
+		assert_ok!(Proxy::add_proxy(Origin::signed(1), 2, ProxyType::Any, 0));
+		assert_eq!(Proxy::proxies(1).0.len(), 2);
+		assert_eq!(Balances::reserved_balance(1), 3);
+
+		assert_ok!(Proxy::add_limited_proxy(Origin::signed(1), 3, ProxyType::Any, 0, Some(3), None));
+		assert_eq!(Balances::reserved_balance(1), 4);
+		System::set_block_number(3);
+		// as does removing one.
+		assert_ok!(Proxy::remove_proxy(Origin::signed(1), 5, ProxyType::Any, 0));
+		assert_eq!(Proxy::proxies(1).0.len(), 1);
+		assert_eq!(Balances::reserved_balance(1), 2);
+	});
+}
+
+#[test]
+fn spending_limited_proxies_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Proxy::add_limited_proxy(Origin::signed(1), 2, ProxyType::Any, 0, None, Some(3)));
+		assert_eq!(Balances::free_balance(1), 8);
+
+		let call = Box::new(Call::Balances(BalancesCall::transfer(6, 2)));
+		assert_ok!(Proxy::proxy(Origin::signed(2), 1, None, call.clone()));
+		expect_event(RawEvent::ProxyExecuted(Ok(())));
+		assert_eq!(Proxy::proxies(1).0[0].spending_limit, Some(SpendingLimit { limit: 3, spent: 2 }));
+
+		// the whole call is reverted if it would exceed the limit.
+		assert_ok!(Proxy::proxy(Origin::signed(2), 1, None, call.clone()));
+		let de = DispatchError::from(Error::<Test>::SpendingLimitExceeded).stripped();
+		expect_event(RawEvent::ProxyExecuted(Err(de)));
+		assert_eq!(Balances::free_balance(6), 2);
+		assert_eq!(Balances::free_balance(1), 6);
+
+		let call = Box::new(Call::Balances(BalancesCall::transfer(6, 1)));
+		assert_ok!(Proxy::proxy(Origin::signed(2), 1, None, call.clone()));
+		expect_event(RawEvent::ProxyExecuted(Ok(())));
+		assert_eq!(Proxy::proxies(1).0[0].spending_limit, Some(SpendingLimit { limit: 3, spent: 3 }));
+		assert_ok!(Proxy::proxy(Origin::signed(2), 1, None, call.clone()));
+		expect_event(RawEvent::ProxyExecuted(Err(de)));
+		assert_eq!(Balances::free_balance(6), 3);
+	});
+}
+
+#[test]
+fn spending_limited_proxies_count_reserved_funds() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Proxy::add_limited_proxy(Origin::signed(1), 2, ProxyType::Any, 0, None, Some(3)));
+		assert_eq!(Balances::free_balance(1), 8);
+
+		// `anonymous` reserves a deposit of 2 from the real account.
+		let call = Box::new(Call::Proxy(ProxyCall::anonymous(ProxyType::Any, 0, 0)));
+		assert_ok!(Proxy::proxy(Origin::signed(2), 1, None, call));
+		expect_event(RawEvent::ProxyExecuted(Ok(())));
+		assert_eq!(Balances::reserved_balance(1), 4);
+		assert_eq!(Proxy::proxies(1).0[0].spending_limit, Some(SpendingLimit { limit: 3, spent: 2 }));
+
+		let call = Box::new(Call::Proxy(ProxyCall::anonymous(ProxyType::Any, 0, 1)));
+		assert_ok!(Proxy::proxy(Origin::signed(2), 1, None, call));
+		let de = DispatchError::from(Error::<Test>::SpendingLimitExceeded).stripped();
+		expect_event(RawEvent::ProxyExecuted(Err(de)));
+		assert_eq!(Balances::reserved_balance(1), 4);
+		assert_eq!(Balances::free_balance(1), 6);
+	});
+}
+
+#[test]
+fn spending_limited_proxy_cannot_add_proxies() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Proxy::add_limited_proxy(Origin::signed(1), 2, ProxyType::Any, 0, None, Some(3)));
+		let call = Box::new(Call::Proxy(ProxyCall::add_proxy(3, ProxyType::Any, 0)));
+		assert_ok!(Proxy::proxy(Origin::signed(2), 1, None, call));
+		expect_event(RawEvent::ProxyExecuted(Err(DispatchError::BadOrigin)));
+		let call = Box::new(Call::Proxy(ProxyCall::add_limited_proxy(3, ProxyType::Any, 0, None, Some(10))));
+		assert_ok!(Proxy::proxy(Origin::signed(2), 1, None, call));
+		expect_event(RawEvent::ProxyExecuted(Err(DispatchError::BadOrigin)));
+		assert_eq!(Proxy::proxies(1).0.len(), 1);
+	});
+}
+
+#[test]
+fn migration_to_limited_proxies_works() {
+	use frame_support::{Twox64Concat, StorageHasher, traits::OnRuntimeUpgrade};
+
+	new_test_ext().execute_with(|| {
+		let old_proxies: (Vec<(u64, ProxyType, u64)>, u64) = (vec![(2, ProxyType::Any, 5)], 2);
+		frame_support::migration::put_storage_value(
+			b"Proxy",
+			b"Proxies",
+			&Twox64Concat::hash(&1u64.encode()),
+			old_proxies,
+		);
+		assert_eq!(StorageVersion::get(), Releases::V1);
+		Proxy::on_runtime_upgrade();
+		let expected = ProxyDefinition {
+			delegate: 2,
+			proxy_type: ProxyType::Any,
+			delay: 5,
+			expiry: None,
+			spending_limit: None,
+		};
+		assert_eq!(Proxy::proxies(1), (vec![expected.clone()], 2));
+		assert_eq!(StorageVersion::get(), Releases::V2);
+
+		// The migration only runs once.
+		assert_eq!(Proxy::on_runtime_upgrade(), 0);
+		assert_eq!(Proxy::proxies(1), (vec![expected], 2));
+	});
+}
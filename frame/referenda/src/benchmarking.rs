@@ -0,0 +1,217 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2020 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// Benchmarks for Referenda Pallet
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use sp_runtime::traits::{Bounded, Hash};
+use frame_system::RawOrigin;
+use frame_benchmarking::{benchmarks, account, whitelisted_caller};
+use frame_support::traits::{OnInitialize, UnfilteredDispatchable};
+use pallet_democracy::{Vote, Conviction};
+
+use crate::Module as Referenda;
+
+const SEED: u32 = 0;
+
+fn funded_account<T: Trait>(name: &'static str, index: u32) -> T::AccountId {
+	let caller: T::AccountId = account(name, index, SEED);
+	T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value());
+	caller
+}
+
+fn note_proposal<T: Trait>() -> T::Hash {
+	let proposal: T::Proposal = frame_system::Call::<T>::remark(vec![]).into();
+	let encoded = proposal.encode();
+	let hash = T::Hashing::hash(&encoded[..]);
+	// The proposal is shared between referenda, so it may already be noted.
+	let _ = T::Preimages::note_preimage(encoded);
+	hash
+}
+
+fn submit_referendum<T: Trait>(who: &T::AccountId) -> Result<ReferendumIndex, &'static str> {
+	let index = ReferendumCount::get();
+	Referenda::<T>::submit(
+		RawOrigin::Signed(who.clone()).into(),
+		Box::new(RawOrigin::Root.into()),
+		note_proposal::<T>(),
+		DispatchTime::After(0u32.into()),
+	)?;
+	Ok(index)
+}
+
+fn place_deposit<T: Trait>(who: &T::AccountId, index: ReferendumIndex) -> Result<(), &'static str> {
+	Referenda::<T>::place_decision_deposit(RawOrigin::Signed(who.clone()).into(), index)?;
+	Ok(())
+}
+
+fn aye<T: Trait>() -> AccountVote<BalanceOf<T>> {
+	let vote = Vote { aye: true, conviction: Conviction::Locked1x };
+	AccountVote::Standard { vote, balance: 100u32.into() }
+}
+
+/// Vote on `r` new referenda, returning the track of the last one.
+fn vote_on<T: Trait>(
+	who: &T::AccountId,
+	r: u32,
+) -> Result<(ReferendumIndex, TrackId), &'static str> {
+	let mut last = (0, 0);
+	for _ in 0 .. r {
+		let index = submit_referendum::<T>(who)?;
+		Referenda::<T>::vote(RawOrigin::Signed(who.clone()).into(), index, aye::<T>())?;
+		let track = Referenda::<T>::referendum_status(index).ok_or("referendum missing")?.track;
+		last = (index, track);
+	}
+	Ok(last)
+}
+
+fn votes_of<T: Trait>(who: &T::AccountId, track: TrackId) -> usize {
+	match VotingFor::<T>::get(who, track) {
+		Voting::Direct { votes, .. } => votes.len(),
+		Voting::Delegating { .. } => 0,
+	}
+}
+
+benchmarks! {
+	_ { }
+
+	submit {
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value());
+		let hash = note_proposal::<T>();
+		let origin: Box<T::PalletsOrigin> = Box::new(RawOrigin::Root.into());
+		let index = ReferendumCount::get();
+	}: _(RawOrigin::Signed(caller), origin, hash, DispatchTime::After(0u32.into()))
+	verify {
+		assert!(Referenda::<T>::referendum_status(index).is_some());
+	}
+
+	place_decision_deposit {
+		let caller = funded_account::<T>("caller", 0);
+		let index = submit_referendum::<T>(&caller)?;
+	}: _(RawOrigin::Signed(caller), index)
+	verify {
+		let status = Referenda::<T>::referendum_status(index).ok_or("referendum missing")?;
+		assert!(status.decision_deposit.is_some());
+	}
+
+	refund_deposits {
+		let caller = funded_account::<T>("caller", 0);
+		let index = submit_referendum::<T>(&caller)?;
+		place_deposit::<T>(&caller, index)?;
+		Referenda::<T>::cancel(T::CancelOrigin::successful_origin(), index)?;
+	}: _(RawOrigin::Signed(caller), index)
+	verify {
+		assert!(matches!(
+			Referenda::<T>::referendum_info(index),
+			Some(ReferendumInfo::Cancelled(_, None, None))
+		));
+	}
+
+	cancel {
+		let caller = funded_account::<T>("caller", 0);
+		let index = submit_referendum::<T>(&caller)?;
+		place_deposit::<T>(&caller, index)?;
+		let origin = T::CancelOrigin::successful_origin();
+		let call = Call::<T>::cancel(index);
+	}: { call.dispatch_bypass_filter(origin)? }
+	verify {
+		assert!(matches!(
+			Referenda::<T>::referendum_info(index),
+			Some(ReferendumInfo::Cancelled(..))
+		));
+	}
+
+	kill {
+		let caller = funded_account::<T>("caller", 0);
+		let index = submit_referendum::<T>(&caller)?;
+		place_deposit::<T>(&caller, index)?;
+		let origin = T::KillOrigin::successful_origin();
+		let call = Call::<T>::kill(index);
+	}: { call.dispatch_bypass_filter(origin)? }
+	verify {
+		assert!(matches!(Referenda::<T>::referendum_info(index), Some(ReferendumInfo::Killed(_))));
+	}
+
+	vote {
+		let r in 1 .. T::MaxVotes::get();
+		let caller = funded_account::<T>("caller", 0);
+		vote_on::<T>(&caller, r - 1)?;
+		let index = submit_referendum::<T>(&caller)?;
+		let track = Referenda::<T>::referendum_status(index).ok_or("referendum missing")?.track;
+	}: _(RawOrigin::Signed(caller.clone()), index, aye::<T>())
+	verify {
+		assert_eq!(votes_of::<T>(&caller, track), r as usize);
+	}
+
+	remove_vote {
+		let r in 1 .. T::MaxVotes::get();
+		let caller = funded_account::<T>("caller", 0);
+		let (index, track) = vote_on::<T>(&caller, r)?;
+	}: _(RawOrigin::Signed(caller.clone()), track, index)
+	verify {
+		assert_eq!(votes_of::<T>(&caller, track), r as usize - 1);
+	}
+
+	unlock {
+		let r in 1 .. T::MaxVotes::get();
+		let caller = funded_account::<T>("caller", 0);
+		let (_, track) = vote_on::<T>(&caller, r)?;
+	}: _(RawOrigin::Signed(caller.clone()), track, caller.clone())
+	verify {
+		assert_eq!(votes_of::<T>(&caller, track), r as usize);
+	}
+
+	// Servicing a referendum which begins deciding at the end of its prepare period.
+	service_referendum {
+		let caller = funded_account::<T>("caller", 0);
+		let index = submit_referendum::<T>(&caller)?;
+		place_deposit::<T>(&caller, index)?;
+		let when = Referenda::<T>::referendum_status(index)
+			.and_then(|s| s.alarm)
+			.ok_or("alarm missing")?;
+		frame_system::Module::<T>::set_block_number(when);
+	}: { Referenda::<T>::on_initialize(when) }
+	verify {
+		let status = Referenda::<T>::referendum_status(index).ok_or("referendum missing")?;
+		assert!(status.deciding.is_some() || status.in_queue);
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::tests::{new_test_ext, Test};
+	use frame_support::assert_ok;
+
+	#[test]
+	fn test_benchmarks() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(test_benchmark_submit::<Test>());
+			assert_ok!(test_benchmark_place_decision_deposit::<Test>());
+			assert_ok!(test_benchmark_refund_deposits::<Test>());
+			assert_ok!(test_benchmark_cancel::<Test>());
+			assert_ok!(test_benchmark_kill::<Test>());
+			assert_ok!(test_benchmark_vote::<Test>());
+			assert_ok!(test_benchmark_remove_vote::<Test>());
+			assert_ok!(test_benchmark_unlock::<Test>());
+			assert_ok!(test_benchmark_service_referendum::<Test>());
+		});
+	}
+}
@@ -0,0 +1,905 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2020 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # Referenda Module
+//!
+//! A module for executing referenda on a number of tracks, each with its own origin and
+//! parameters.
+//!
+//! - [`referenda::Trait`](./trait.Trait.html)
+//! - [`Call`](./enum.Call.html)
+//!
+//! ## Overview
+//!
+//! Unlike the democracy module, which runs a single queue of public referenda with fixed launch
+//! and voting periods, this module decides each referendum on a *track* determined by the origin
+//! with which its proposal is to be dispatched. Tracks are configured by the runtime through the
+//! [`TracksInfo`](./trait.TracksInfo.html) trait, and each has its own decision deposit, maximum
+//! number of concurrently deciding referenda, preparation, decision, confirmation and minimum
+//! enactment periods, and curves for the minimum approval and support needed over time.
+//!
+//! ### Lifecycle
+//!
+//! A referendum is submitted with a submission deposit by any signed account, for a proposal whose
+//! preimage is available through `T::Preimages`. Once its track's preparation period has passed
+//! and someone has placed the track's decision deposit, it begins its decision period if the
+//! track has a free decision slot, or otherwise waits in the track's queue, ordered by aye votes.
+//! A referendum that is neither deciding nor queued within `UndecidingTimeout` of its submission
+//! times out, and its submission deposit is slashed.
+//!
+//! During its decision period a referendum is *passing* when its approval (the proportion of
+//! conviction-weighted votes which are aye) and its support (the proportion of the total issuance
+//! voting aye, without conviction) are at least the values given by the track's curves at that
+//! point in the period. A referendum which stays passing for the track's confirmation period is
+//! approved, and its proposal scheduled for dispatch with the track's origin. Should the proposal
+//! no longer be available or fail to be scheduled, the referendum concludes as `EnactmentFailed`
+//! instead. A referendum which is not confirming by the end of its decision period is rejected.
+//!
+//! Votes don't advance a referendum themselves; a referendum whose tally has changed is serviced
+//! at the start of the next block.
+//!
+//! Voting reuses the `Conviction` and `Voting` types of the democracy module, with votes and
+//! locks kept separately for each track. Delegation is not supported.
+//!
+//! ## Interface
+//!
+//! ### Dispatchable Functions
+//!
+//! * `submit` - Submit a referendum on the track of the given origin.
+//! * `place_decision_deposit` - Place the decision deposit of a referendum.
+//! * `refund_deposits` - Refund the deposits of a concluded referendum.
+//! * `cancel` - Cancel an ongoing referendum, refunding its deposits.
+//! * `kill` - Cancel an ongoing referendum, slashing its deposits.
+//! * `vote` - Vote in an ongoing referendum.
+//! * `remove_vote` - Remove a vote, accumulating any lock it is due.
+//! * `unlock` - Unlock any balance no longer needed for an account's votes.
+//!
+//! [`Call`]: ./enum.Call.html
+//! [`Trait`]: ./trait.Trait.html
+
+// Ensure we're `no_std` when compiling for Wasm.
+#![cfg_attr(not(feature = "std"), no_std)]
+
+mod types;
+mod tests;
+mod benchmarking;
+mod default_weight;
+
+use sp_std::prelude::*;
+use codec::{Encode, Decode};
+use frame_support::{
+	decl_module, decl_event, decl_storage, decl_error, ensure, Parameter,
+	dispatch::{DispatchError, DispatchResult},
+	traits::{
+		Currency, ReservableCurrency, LockableCurrency, WithdrawReason, LockIdentifier, Get,
+		OnUnbalanced, EnsureOrigin, PreimageRecipient,
+		schedule::{Named as ScheduleNamed, DispatchTime},
+	},
+	weights::Weight,
+};
+use sp_runtime::{Perbill, traits::{Dispatchable, Saturating, Zero, One}};
+use frame_system::{self as system, ensure_signed};
+use pallet_democracy::{AccountVote, Voting};
+
+pub use types::{
+	TrackId, ReferendumIndex, Curve, TrackInfo, TracksInfo, Tally, Deposit, DecidingStatus,
+	ReferendumStatus, ReferendumInfo,
+};
+
+const REFERENDA_ID: LockIdentifier = *b"referend";
+
+type BalanceOf<T> =
+	<<T as Trait>::Currency as Currency<<T as frame_system::Trait>::AccountId>>::Balance;
+type NegativeImbalanceOf<T> =
+	<<T as Trait>::Currency as Currency<<T as frame_system::Trait>::AccountId>>::NegativeImbalance;
+type ReferendumStatusOf<T> = ReferendumStatus<
+	<T as Trait>::PalletsOrigin,
+	<T as frame_system::Trait>::BlockNumber,
+	<T as frame_system::Trait>::Hash,
+	BalanceOf<T>,
+	<T as frame_system::Trait>::AccountId,
+>;
+type ReferendumInfoOf<T> = ReferendumInfo<
+	<T as Trait>::PalletsOrigin,
+	<T as frame_system::Trait>::BlockNumber,
+	<T as frame_system::Trait>::Hash,
+	BalanceOf<T>,
+	<T as frame_system::Trait>::AccountId,
+>;
+type VotingOf<T> = Voting<
+	BalanceOf<T>,
+	<T as frame_system::Trait>::AccountId,
+	<T as frame_system::Trait>::BlockNumber,
+>;
+type TrackInfoOf<T> = TrackInfo<BalanceOf<T>, <T as frame_system::Trait>::BlockNumber>;
+
+pub trait WeightInfo {
+	fn submit() -> Weight;
+	fn place_decision_deposit() -> Weight;
+	fn refund_deposits() -> Weight;
+	fn cancel() -> Weight;
+	fn kill() -> Weight;
+	fn vote(r: u32, ) -> Weight;
+	fn remove_vote(r: u32, ) -> Weight;
+	fn unlock(r: u32, ) -> Weight;
+	fn service_referendum() -> Weight;
+}
+
+/// The module's configuration trait.
+pub trait Trait: frame_system::Trait {
+	/// The overarching event type.
+	type Event: From<Event<Self>> + Into<<Self as frame_system::Trait>::Event>;
+
+	/// The type of the proposals which are voted on.
+	type Proposal: Parameter + Dispatchable<Origin=Self::Origin> + From<frame_system::Call<Self>>;
+
+	/// The scheduler with which approved proposals are enacted.
+	type Scheduler: ScheduleNamed<Self::BlockNumber, Self::Proposal, Self::PalletsOrigin>;
+
+	/// Overarching type of all pallets origins.
+	type PalletsOrigin: Parameter + From<system::RawOrigin<Self::AccountId>>;
+
+	/// Currency type for this module.
+	type Currency: ReservableCurrency<Self::AccountId>
+		+ LockableCurrency<Self::AccountId, Moment=Self::BlockNumber>;
+
+	/// The store of the preimages of proposals.
+	type Preimages: PreimageRecipient<Self::Hash>;
+
+	/// Origin from which any ongoing referendum may be cancelled, refunding its deposits.
+	type CancelOrigin: EnsureOrigin<Self::Origin>;
+
+	/// Origin from which any ongoing referendum may be killed, slashing its deposits.
+	type KillOrigin: EnsureOrigin<Self::Origin>;
+
+	/// Handler for the unbalanced reduction when slashing a deposit.
+	type Slash: OnUnbalanced<NegativeImbalanceOf<Self>>;
+
+	/// The deposit placed on submitting a referendum.
+	type SubmissionDeposit: Get<BalanceOf<Self>>;
+
+	/// The maximum number of referenda which may wait in the queue of a track.
+	type MaxQueued: Get<u32>;
+
+	/// The time after its submission within which a referendum must begin deciding or be queued,
+	/// or else time out.
+	type UndecidingTimeout: Get<Self::BlockNumber>;
+
+	/// How often a referendum which is ready to be decided, but for which there is no room, is
+	/// serviced again.
+	type AlarmInterval: Get<Self::BlockNumber>;
+
+	/// The period for which a winning vote is locked for each conviction multiplier after its
+	/// referendum concludes.
+	type VoteLockingPeriod: Get<Self::BlockNumber>;
+
+	/// The maximum number of votes of an account on a single track.
+	type MaxVotes: Get<u32>;
+
+	/// The tracks on which referenda are decided.
+	type Tracks: TracksInfo<BalanceOf<Self>, Self::BlockNumber, Origin=Self::PalletsOrigin>;
+
+	/// Weight information for extrinsics in this pallet.
+	type WeightInfo: WeightInfo;
+}
+
+decl_storage! {
+	trait Store for Module<T: Trait> as Referenda {
+		/// The next free referendum index, aka the number of referenda submitted so far.
+		pub ReferendumCount get(fn referendum_count): ReferendumIndex;
+
+		/// Information concerning any given referendum.
+		pub ReferendumInfoFor get(fn referendum_info):
+			map hasher(twox_64_concat) ReferendumIndex => Option<ReferendumInfoOf<T>>;
+
+		/// The referenda waiting for a decision slot on each track, with their aye votes, ordered
+		/// from the fewest to the most ayes.
+		pub TrackQueue get(fn track_queue):
+			map hasher(twox_64_concat) TrackId => Vec<(ReferendumIndex, BalanceOf<T>)>;
+
+		/// The number of referenda being decided on each track.
+		pub DecidingCount get(fn deciding_count): map hasher(twox_64_concat) TrackId => u32;
+
+		/// The votes of an account on each track, and the locks due to its past votes.
+		pub VotingFor get(fn voting_for):
+			double_map hasher(twox_64_concat) T::AccountId, hasher(twox_64_concat) TrackId
+			=> VotingOf<T>;
+
+		/// The referenda to be serviced at a given block.
+		pub Alarms: map hasher(twox_64_concat) T::BlockNumber => Vec<ReferendumIndex>;
+	}
+}
+
+decl_event! {
+	pub enum Event<T> where
+		AccountId = <T as frame_system::Trait>::AccountId,
+		Balance = BalanceOf<T>,
+		Hash = <T as frame_system::Trait>::Hash,
+	{
+		/// A referendum has been submitted. \[index, track, proposal_hash\]
+		Submitted(ReferendumIndex, TrackId, Hash),
+		/// The decision deposit of a referendum has been placed. \[index, who, amount\]
+		DecisionDepositPlaced(ReferendumIndex, AccountId, Balance),
+		/// A deposit of a concluded referendum has been refunded. \[index, who, amount\]
+		DepositRefunded(ReferendumIndex, AccountId, Balance),
+		/// A referendum has begun its decision period. \[index, track, proposal_hash\]
+		DecisionStarted(ReferendumIndex, TrackId, Hash),
+		/// A referendum has begun its confirmation period. \[index\]
+		ConfirmStarted(ReferendumIndex),
+		/// A referendum has stopped passing during its confirmation period. \[index\]
+		ConfirmAborted(ReferendumIndex),
+		/// A referendum has been approved and its proposal scheduled. \[index\]
+		Approved(ReferendumIndex),
+		/// A referendum has been approved, but its proposal could not be scheduled.
+		/// \[index, error\]
+		EnactmentFailed(ReferendumIndex, DispatchError),
+		/// A referendum has been rejected. \[index\]
+		Rejected(ReferendumIndex),
+		/// A referendum was not decided in time and has timed out. \[index\]
+		TimedOut(ReferendumIndex),
+		/// A referendum has been cancelled. \[index\]
+		Cancelled(ReferendumIndex),
+		/// A referendum has been killed. \[index\]
+		Killed(ReferendumIndex),
+	}
+}
+
+decl_error! {
+	pub enum Error for Module<T: Trait> {
+		/// There is no track for the given origin.
+		NoTrack,
+		/// The track does not exist.
+		BadTrack,
+		/// The referendum does not exist.
+		BadReferendum,
+		/// The referendum is not ongoing.
+		NotOngoing,
+		/// The referendum is still ongoing.
+		Unfinished,
+		/// The decision deposit has already been placed.
+		HasDeposit,
+		/// There are no deposits to refund.
+		NoDeposit,
+		/// The preimage of the proposal does not exist.
+		PreimageNotExist,
+		/// The preimage of the proposal does not decode.
+		PreimageInvalid,
+		/// Too high a balance was provided that the account cannot afford.
+		InsufficientFunds,
+		/// The account is not voting in the referendum.
+		NotVoter,
+		/// The account may not remove its vote in a concluded referendum until its lock expires.
+		NoPermission,
+		/// Maximum number of votes on the track reached.
+		MaxVotesReached,
+		/// Unexpected integer overflow.
+		Overflow,
+		/// Unexpected integer underflow.
+		Underflow,
+		/// The proposal of an approved referendum could not be scheduled.
+		ScheduleFailed,
+	}
+}
+
+decl_module! {
+	pub struct Module<T: Trait> for enum Call where origin: T::Origin {
+		type Error = Error<T>;
+
+		/// The deposit placed on submitting a referendum.
+		const SubmissionDeposit: BalanceOf<T> = T::SubmissionDeposit::get();
+
+		/// The maximum number of referenda which may wait in the queue of a track.
+		const MaxQueued: u32 = T::MaxQueued::get();
+
+		/// The time within which a referendum must begin deciding or be queued.
+		const UndecidingTimeout: T::BlockNumber = T::UndecidingTimeout::get();
+
+		/// How often a referendum which is ready to be decided is serviced again.
+		const AlarmInterval: T::BlockNumber = T::AlarmInterval::get();
+
+		/// The period for which a winning vote is locked for each conviction multiplier.
+		const VoteLockingPeriod: T::BlockNumber = T::VoteLockingPeriod::get();
+
+		/// The maximum number of votes of an account on a single track.
+		const MaxVotes: u32 = T::MaxVotes::get();
+
+		fn deposit_event() = default;
+
+		/// Service the referenda whose alarms are due.
+		fn on_initialize(n: T::BlockNumber) -> Weight {
+			let due = Alarms::<T>::take(n);
+			let serviced = due.len() as Weight;
+			for index in due {
+				if let Some(ReferendumInfo::Ongoing(mut status)) = ReferendumInfoFor::<T>::get(index) {
+					// Stale alarms of referenda which have since been serviced are ignored.
+					if status.alarm == Some(n) {
+						status.alarm = None;
+						let info = Self::service_referendum(n, index, status);
+						ReferendumInfoFor::<T>::insert(index, info);
+					}
+				}
+			}
+			T::DbWeight::get().reads_writes(1, 1)
+				.saturating_add(T::WeightInfo::service_referendum().saturating_mul(serviced))
+		}
+
+		/// Submit a referendum on the track of `proposal_origin`.
+		///
+		/// The dispatch origin of this call must be _Signed_ and the sender must have funds to
+		/// cover the submission deposit. The preimage of the proposal must be available, and is
+		/// requested until the referendum concludes.
+		///
+		/// - `proposal_origin`: The origin with which the proposal is dispatched if approved.
+		/// - `proposal_hash`: The hash of the proposal preimage.
+		/// - `enactment_moment`: When the proposal is dispatched once approved. It is never
+		///   dispatched earlier than the track's minimum enactment period after its approval.
+		///
+		/// Emits `Submitted`.
+		#[weight = T::WeightInfo::submit()]
+		fn submit(origin,
+			proposal_origin: Box<T::PalletsOrigin>,
+			proposal_hash: T::Hash,
+			enactment_moment: DispatchTime<T::BlockNumber>,
+		) {
+			let who = ensure_signed(origin)?;
+			let track = T::Tracks::track_for(&proposal_origin).map_err(|_| Error::<T>::NoTrack)?;
+			ensure!(T::Tracks::info(track).is_some(), Error::<T>::BadTrack);
+			let encoded = T::Preimages::get_preimage(&proposal_hash)
+				.ok_or(Error::<T>::PreimageNotExist)?;
+			T::Proposal::decode(&mut &encoded[..]).map_err(|_| Error::<T>::PreimageInvalid)?;
+
+			let amount = T::SubmissionDeposit::get();
+			T::Currency::reserve(&who, amount)?;
+			T::Preimages::request_preimage(&proposal_hash);
+
+			let index = ReferendumCount::mutate(|c| { let index = *c; *c += 1; index });
+			let now = system::Module::<T>::block_number();
+			let status = ReferendumStatus {
+				track,
+				origin: *proposal_origin,
+				proposal_hash,
+				enactment: enactment_moment,
+				submitted: now,
+				submission_deposit: Deposit { who, amount },
+				decision_deposit: None,
+				deciding: None,
+				tally: Default::default(),
+				in_queue: false,
+				alarm: None,
+			};
+			Self::deposit_event(RawEvent::Submitted(index, track, proposal_hash));
+			let info = Self::service_referendum(now, index, status);
+			ReferendumInfoFor::<T>::insert(index, info);
+		}
+
+		/// Place the decision deposit of the track of an ongoing referendum.
+		///
+		/// The dispatch origin of this call must be _Signed_ and the sender must have funds to
+		/// cover the deposit.
+		///
+		/// - `index`: The index of the referendum.
+		///
+		/// Emits `DecisionDepositPlaced`.
+		#[weight = T::WeightInfo::place_decision_deposit()]
+		fn place_decision_deposit(origin, #[compact] index: ReferendumIndex) {
+			let who = ensure_signed(origin)?;
+			let mut status = Self::ensure_ongoing(index)?;
+			ensure!(status.decision_deposit.is_none(), Error::<T>::HasDeposit);
+			let track = T::Tracks::info(status.track).ok_or(Error::<T>::BadTrack)?;
+
+			let amount = track.decision_deposit;
+			T::Currency::reserve(&who, amount)?;
+			status.decision_deposit = Some(Deposit { who: who.clone(), amount });
+
+			Self::deposit_event(RawEvent::DecisionDepositPlaced(index, who, amount));
+			let now = system::Module::<T>::block_number();
+			let info = Self::service_referendum(now, index, status);
+			ReferendumInfoFor::<T>::insert(index, info);
+		}
+
+		/// Refund the deposits of a concluded referendum which were not slashed.
+		///
+		/// The dispatch origin of this call must be _Signed_.
+		///
+		/// - `index`: The index of the referendum.
+		///
+		/// Emits `DepositRefunded` for each deposit refunded.
+		#[weight = T::WeightInfo::refund_deposits()]
+		fn refund_deposits(origin, #[compact] index: ReferendumIndex) {
+			ensure_signed(origin)?;
+			let mut info = ReferendumInfoFor::<T>::get(index).ok_or(Error::<T>::BadReferendum)?;
+			let deposits = match info {
+				ReferendumInfo::Ongoing(_) => return Err(Error::<T>::Unfinished.into()),
+				ReferendumInfo::Approved(_, ref mut s, ref mut d) |
+				ReferendumInfo::Rejected(_, ref mut s, ref mut d) |
+				ReferendumInfo::Cancelled(_, ref mut s, ref mut d) |
+				ReferendumInfo::EnactmentFailed(_, ref mut s, ref mut d) => vec![s.take(), d.take()],
+				ReferendumInfo::TimedOut(_, ref mut d) => vec![d.take()],
+				ReferendumInfo::Killed(_) => vec![],
+			};
+			let deposits = deposits.into_iter().flatten().collect::<Vec<_>>();
+			ensure!(!deposits.is_empty(), Error::<T>::NoDeposit);
+
+			for Deposit { who, amount } in deposits {
+				T::Currency::unreserve(&who, amount);
+				Self::deposit_event(RawEvent::DepositRefunded(index, who, amount));
+			}
+			ReferendumInfoFor::<T>::insert(index, info);
+		}
+
+		/// Cancel an ongoing referendum. Its deposits may then be refunded.
+		///
+		/// The dispatch origin of this call must be `CancelOrigin`.
+		///
+		/// - `index`: The index of the referendum.
+		///
+		/// Emits `Cancelled`.
+		#[weight = T::WeightInfo::cancel()]
+		fn cancel(origin, #[compact] index: ReferendumIndex) {
+			T::CancelOrigin::ensure_origin(origin)?;
+			let status = Self::ensure_ongoing(index)?;
+			let now = system::Module::<T>::block_number();
+			Self::conclude(now, index, &status);
+
+			Self::deposit_event(RawEvent::Cancelled(index));
+			let info = ReferendumInfo::Cancelled(
+				now,
+				Some(status.submission_deposit),
+				status.decision_deposit,
+			);
+			ReferendumInfoFor::<T>::insert(index, info);
+		}
+
+		/// Cancel an ongoing referendum, slashing its deposits.
+		///
+		/// The dispatch origin of this call must be `KillOrigin`.
+		///
+		/// - `index`: The index of the referendum.
+		///
+		/// Emits `Killed`.
+		#[weight = T::WeightInfo::kill()]
+		fn kill(origin, #[compact] index: ReferendumIndex) {
+			T::KillOrigin::ensure_origin(origin)?;
+			let status = Self::ensure_ongoing(index)?;
+			let now = system::Module::<T>::block_number();
+			Self::conclude(now, index, &status);
+
+			Self::slash_deposit(Some(status.submission_deposit));
+			Self::slash_deposit(status.decision_deposit);
+			Self::deposit_event(RawEvent::Killed(index));
+			ReferendumInfoFor::<T>::insert(index, ReferendumInfo::Killed(now));
+		}
+
+		/// Vote in an ongoing referendum, or change an existing vote.
+		///
+		/// The dispatch origin of this call must be _Signed_, and the voted balance is locked
+		/// until the vote is removed and, if it won, its conviction's lock period is over.
+		///
+		/// - `index`: The index of the referendum.
+		/// - `vote`: The vote configuration.
+		///
+		/// Weight: `O(R)` where R is the number of votes of the voter on the referendum's track.
+		#[weight = T::WeightInfo::vote(T::MaxVotes::get())]
+		fn vote(origin, #[compact] index: ReferendumIndex, vote: AccountVote<BalanceOf<T>>) {
+			let who = ensure_signed(origin)?;
+			Self::try_vote(&who, index, vote)?;
+		}
+
+		/// Remove a vote for a referendum.
+		///
+		/// The dispatch origin of this call must be _Signed_. Votes may be removed from ongoing
+		/// referenda at any time, and from concluded referenda once they no longer need to be
+		/// locked. In the latter case, or where the referendum was approved or rejected, any lock
+		/// the vote is still due is kept until `unlock` is called after it expires.
+		///
+		/// - `track`: The track of the referendum.
+		/// - `index`: The index of the referendum.
+		///
+		/// Weight: `O(R)` where R is the number of votes of the voter on the track.
+		#[weight = T::WeightInfo::remove_vote(T::MaxVotes::get())]
+		fn remove_vote(origin, track: TrackId, #[compact] index: ReferendumIndex) {
+			let who = ensure_signed(origin)?;
+			Self::try_remove_vote(&who, track, index)?;
+		}
+
+		/// Unlock any balance of `target` which is no longer needed for its votes.
+		///
+		/// The dispatch origin of this call must be _Signed_.
+		///
+		/// - `track`: The track whose expired locks are cleared.
+		/// - `target`: The account to unlock.
+		///
+		/// Weight: `O(R)` where R is the number of votes of the target on the track.
+		#[weight = T::WeightInfo::unlock(T::MaxVotes::get())]
+		fn unlock(origin, track: TrackId, target: T::AccountId) {
+			ensure_signed(origin)?;
+			Self::update_lock(&target, track);
+		}
+	}
+}
+
+impl<T: Trait> Module<T> {
+	// exposed immutables.
+
+	/// Get the status of an ongoing referendum, if any.
+	pub fn referendum_status(index: ReferendumIndex) -> Option<ReferendumStatusOf<T>> {
+		match ReferendumInfoFor::<T>::get(index) {
+			Some(ReferendumInfo::Ongoing(status)) => Some(status),
+			_ => None,
+		}
+	}
+
+	/// Whether an ongoing referendum would currently pass its track's curves, at the point
+	/// `elapsed` of its decision period.
+	pub fn is_passing(
+		tally: &Tally<BalanceOf<T>>,
+		elapsed: T::BlockNumber,
+		track: &TrackInfoOf<T>,
+	) -> bool {
+		let x = Self::progress(elapsed, track.decision_period);
+		let (approval, support) = Self::approval_and_support(tally);
+		track.min_approval.passing(x, approval) && track.min_support.passing(x, support)
+	}
+
+	// private.
+
+	fn ensure_ongoing(index: ReferendumIndex) -> Result<ReferendumStatusOf<T>, DispatchError> {
+		match ReferendumInfoFor::<T>::get(index) {
+			Some(ReferendumInfo::Ongoing(status)) => Ok(status),
+			Some(_) => Err(Error::<T>::NotOngoing.into()),
+			None => Err(Error::<T>::BadReferendum.into()),
+		}
+	}
+
+	/// The proportion of `period` represented by `elapsed`.
+	fn progress(elapsed: T::BlockNumber, period: T::BlockNumber) -> Perbill {
+		if period.is_zero() {
+			Perbill::one()
+		} else {
+			Perbill::from_rational_approximation(elapsed.min(period), period)
+		}
+	}
+
+	/// The approval and support of a tally.
+	fn approval_and_support(tally: &Tally<BalanceOf<T>>) -> (Perbill, Perbill) {
+		let turnout = tally.ayes.saturating_add(tally.nays);
+		let approval = if turnout.is_zero() {
+			Perbill::zero()
+		} else {
+			Perbill::from_rational_approximation(tally.ayes, turnout)
+		};
+		let issuance = T::Currency::total_issuance();
+		let support = if issuance.is_zero() {
+			Perbill::zero()
+		} else {
+			Perbill::from_rational_approximation(tally.support.min(issuance), issuance)
+		};
+		(approval, support)
+	}
+
+	/// Advance an ongoing referendum as far as it can go at block `now`, returning its new
+	/// information. The referendum's alarm is set for the next time it needs to be serviced.
+	fn service_referendum(
+		now: T::BlockNumber,
+		index: ReferendumIndex,
+		mut status: ReferendumStatusOf<T>,
+	) -> ReferendumInfoOf<T> {
+		let track = match T::Tracks::info(status.track) {
+			Some(track) => track,
+			None => {
+				frame_support::print("LOGIC ERROR: service_referendum/track missing");
+				return ReferendumInfo::Ongoing(status)
+			}
+		};
+		let timeout = status.submitted.saturating_add(T::UndecidingTimeout::get());
+		let mut alarm = None;
+
+		if status.in_queue {
+			// The tally may have changed, so keep the queue ordered.
+			TrackQueue::<T>::mutate(status.track, |q| {
+				q.retain(|&(i, _)| i != index);
+				Self::insert_sorted(q, index, status.tally.ayes);
+			});
+		} else if status.deciding.is_none() {
+			let prepared = now >= status.submitted.saturating_add(track.prepare_period);
+			if status.decision_deposit.is_some() && prepared {
+				let queue_len = TrackQueue::<T>::decode_len(status.track).unwrap_or(0) as u32;
+				if DecidingCount::get(status.track) < track.max_deciding {
+					DecidingCount::mutate(status.track, |c| *c = c.saturating_add(1));
+					Self::begin_deciding(now, index, &mut status);
+				} else if queue_len < T::MaxQueued::get() {
+					TrackQueue::<T>::mutate(status.track, |q| {
+						Self::insert_sorted(q, index, status.tally.ayes)
+					});
+					status.in_queue = true;
+				} else if now < timeout {
+					alarm = Some(now.saturating_add(T::AlarmInterval::get()).min(timeout));
+				}
+			} else if now < timeout {
+				alarm = Some(if status.decision_deposit.is_some() {
+					status.submitted.saturating_add(track.prepare_period).min(timeout)
+				} else {
+					timeout
+				});
+			}
+
+			if status.deciding.is_none() && !status.in_queue && alarm.is_none() {
+				T::Preimages::unrequest_preimage(&status.proposal_hash);
+				Self::slash_deposit(Some(status.submission_deposit));
+				Self::deposit_event(RawEvent::TimedOut(index));
+				return ReferendumInfo::TimedOut(now, status.decision_deposit)
+			}
+		}
+
+		if let Some(mut deciding) = status.deciding.clone() {
+			let elapsed = now.saturating_sub(deciding.since);
+			let end = deciding.since.saturating_add(track.decision_period);
+			if Self::is_passing(&status.tally, elapsed, &track) {
+				match deciding.confirming {
+					Some(confirmed) if now >= confirmed => {
+						// The scheduler keeps the decoded call itself, so the preimage is no
+						// longer needed once enacted, and `conclude` unrequests it.
+						let enacted = Self::enact(now, index, &status, &track);
+						Self::conclude(now, index, &status);
+						let (submission, decision) = (Some(status.submission_deposit), status.decision_deposit);
+						return match enacted {
+							Ok(()) => {
+								Self::deposit_event(RawEvent::Approved(index));
+								ReferendumInfo::Approved(now, submission, decision)
+							}
+							Err(e) => {
+								Self::deposit_event(RawEvent::EnactmentFailed(index, e));
+								ReferendumInfo::EnactmentFailed(now, submission, decision)
+							}
+						}
+					}
+					Some(confirmed) => alarm = Some(confirmed),
+					None => {
+						let confirmed = now.saturating_add(track.confirm_period);
+						deciding.confirming = Some(confirmed);
+						Self::deposit_event(RawEvent::ConfirmStarted(index));
+						alarm = Some(confirmed);
+					}
+				}
+			} else if now >= end {
+				Self::conclude(now, index, &status);
+				Self::deposit_event(RawEvent::Rejected(index));
+				return ReferendumInfo::Rejected(
+					now,
+					Some(status.submission_deposit),
+					status.decision_deposit,
+				)
+			} else {
+				if deciding.confirming.take().is_some() {
+					Self::deposit_event(RawEvent::ConfirmAborted(index));
+				}
+				// Wake up when both curves have fallen to the current tally, if that happens
+				// before the end of the decision period.
+				let (approval, support) = Self::approval_and_support(&status.tally);
+				let delay = track.min_approval.delay(approval).max(track.min_support.delay(support));
+				let passing_at = deciding.since.saturating_add(delay * track.decision_period);
+				alarm = Some(passing_at.min(end));
+			}
+			status.deciding = Some(deciding);
+		}
+
+		match alarm {
+			Some(when) => Self::set_alarm(index, &mut status, when.max(now + One::one())),
+			None => status.alarm = None,
+		}
+		ReferendumInfo::Ongoing(status)
+	}
+
+	/// Begin the decision period of a referendum. Its track's deciding count must already
+	/// account for it.
+	fn begin_deciding(
+		now: T::BlockNumber,
+		index: ReferendumIndex,
+		status: &mut ReferendumStatusOf<T>,
+	) {
+		status.in_queue = false;
+		status.deciding = Some(DecidingStatus { since: now, confirming: None });
+		Self::deposit_event(RawEvent::DecisionStarted(index, status.track, status.proposal_hash));
+	}
+
+	/// Set the alarm of a referendum, unless it is already set for `when`.
+	fn set_alarm(index: ReferendumIndex, status: &mut ReferendumStatusOf<T>, when: T::BlockNumber) {
+		if status.alarm != Some(when) {
+			Alarms::<T>::append(when, index);
+			status.alarm = Some(when);
+		}
+	}
+
+	/// Insert a referendum into a track queue ordered by ascending aye votes.
+	fn insert_sorted(
+		queue: &mut Vec<(ReferendumIndex, BalanceOf<T>)>,
+		index: ReferendumIndex,
+		ayes: BalanceOf<T>,
+	) {
+		let i = queue.binary_search_by_key(&ayes, |&(_, a)| a).unwrap_or_else(|i| i);
+		queue.insert(i, (index, ayes));
+	}
+
+	/// Release everything an ongoing referendum holds on its conclusion: its preimage request,
+	/// and its place in the queue or decision slot of its track. A freed decision slot is given
+	/// to the queued referendum with the most aye votes, which is serviced on the next block.
+	fn conclude(now: T::BlockNumber, index: ReferendumIndex, status: &ReferendumStatusOf<T>) {
+		T::Preimages::unrequest_preimage(&status.proposal_hash);
+		if status.in_queue {
+			TrackQueue::<T>::mutate(status.track, |q| q.retain(|&(i, _)| i != index));
+		}
+		if status.deciding.is_none() {
+			return
+		}
+		match TrackQueue::<T>::mutate(status.track, |q| q.pop()) {
+			Some((next, _)) => {
+				if let Some(ReferendumInfo::Ongoing(mut s)) = ReferendumInfoFor::<T>::get(next) {
+					Self::begin_deciding(now, next, &mut s);
+					Self::set_alarm(next, &mut s, now + One::one());
+					ReferendumInfoFor::<T>::insert(next, ReferendumInfo::Ongoing(s));
+				}
+			}
+			None => DecidingCount::mutate(status.track, |c| *c = c.saturating_sub(1)),
+		}
+	}
+
+	/// Schedule the proposal of an approved referendum for dispatch with its track's origin.
+	fn enact(
+		now: T::BlockNumber,
+		index: ReferendumIndex,
+		status: &ReferendumStatusOf<T>,
+		track: &TrackInfoOf<T>,
+	) -> DispatchResult {
+		let encoded = T::Preimages::get_preimage(&status.proposal_hash)
+			.ok_or(Error::<T>::PreimageNotExist)?;
+		let call = T::Proposal::decode(&mut &encoded[..]).map_err(|_| Error::<T>::PreimageInvalid)?;
+		let earliest = now.saturating_add(track.min_enactment_period);
+		let when = match status.enactment {
+			DispatchTime::At(when) => when,
+			DispatchTime::After(delay) => now.saturating_add(delay),
+		}.max(earliest).max(now + One::one());
+		T::Scheduler::schedule_named(
+			(REFERENDA_ID, index).encode(),
+			DispatchTime::At(when),
+			None,
+			63,
+			status.origin.clone(),
+			call,
+		).map_err(|_| Error::<T>::ScheduleFailed)?;
+		Ok(())
+	}
+
+	/// Slash a deposit, if any.
+	fn slash_deposit(deposit: Option<Deposit<T::AccountId, BalanceOf<T>>>) {
+		if let Some(Deposit { who, amount }) = deposit {
+			T::Slash::on_unbalanced(T::Currency::slash_reserved(&who, amount).0);
+		}
+	}
+
+	/// Actually enact a vote, if legit.
+	fn try_vote(
+		who: &T::AccountId,
+		index: ReferendumIndex,
+		vote: AccountVote<BalanceOf<T>>,
+	) -> DispatchResult {
+		let mut status = Self::ensure_ongoing(index)?;
+		ensure!(vote.balance() <= T::Currency::free_balance(who), Error::<T>::InsufficientFunds);
+		VotingFor::<T>::try_mutate(who, status.track, |voting| -> DispatchResult {
+			match voting {
+				Voting::Direct { ref mut votes, .. } => {
+					match votes.binary_search_by_key(&index, |i| i.0) {
+						Ok(i) => {
+							// Shouldn't be possible to fail, but we handle it gracefully.
+							status.tally.remove(votes[i].1).ok_or(Error::<T>::Underflow)?;
+							votes[i].1 = vote;
+						}
+						Err(i) => {
+							ensure!(
+								(votes.len() as u32) < T::MaxVotes::get(),
+								Error::<T>::MaxVotesReached
+							);
+							votes.insert(i, (index, vote));
+						}
+					}
+					// Shouldn't be possible to fail, but we handle it gracefully.
+					status.tally.add(vote).ok_or(Error::<T>::Overflow)?;
+					Ok(())
+				}
+				// Delegation is not supported, so accounts only ever vote directly.
+				Voting::Delegating { .. } => Err(Error::<T>::NoPermission.into()),
+			}
+		})?;
+		// Extend the lock to `balance` (rather than setting it) since we don't know what other
+		// votes are in place.
+		T::Currency::extend_lock(REFERENDA_ID, who, vote.balance(), WithdrawReason::Transfer.into());
+
+		// The referendum is serviced with the new tally on the next block.
+		let now = system::Module::<T>::block_number();
+		Self::set_alarm(index, &mut status, now + One::one());
+		ReferendumInfoFor::<T>::insert(index, ReferendumInfo::Ongoing(status));
+		Ok(())
+	}
+
+	/// Remove the account's vote for the given referendum if possible. This is possible when:
+	/// - The referendum has not finished.
+	/// - The referendum has finished and the voter lost their direction.
+	/// - The referendum has finished and the voter's lock period is up.
+	///
+	/// Where the vote is still due a lock, it is kept as a prior lock of the track.
+	fn try_remove_vote(who: &T::AccountId, track: TrackId, index: ReferendumIndex) -> DispatchResult {
+		let info = ReferendumInfoFor::<T>::get(index);
+		let now = system::Module::<T>::block_number();
+		let ongoing = VotingFor::<T>::try_mutate(who, track, |voting| -> Result<_, DispatchError> {
+			match voting {
+				Voting::Direct { ref mut votes, ref mut prior, .. } => {
+					let i = votes.binary_search_by_key(&index, |i| i.0)
+						.map_err(|_| Error::<T>::NotVoter)?;
+					let vote = votes.remove(i).1;
+					let mut ongoing = None;
+					let mut concluded = None;
+					match info {
+						Some(ReferendumInfo::Ongoing(mut status)) => {
+							// Shouldn't be possible to fail, but we handle it gracefully.
+							status.tally.remove(vote).ok_or(Error::<T>::Underflow)?;
+							ongoing = Some(status);
+						}
+						Some(ReferendumInfo::Approved(end, ..)) => concluded = Some((end, true)),
+						Some(ReferendumInfo::Rejected(end, ..)) => concluded = Some((end, false)),
+						// The referendum was cancelled, killed or timed out, or its proposal was
+						// never enacted, so no lock is due.
+						_ => {}
+					}
+					if let Some((end, approved)) = concluded {
+						if let Some((lock_periods, balance)) = vote.locked_if(approved) {
+							let unlock_at = end.saturating_add(
+								T::VoteLockingPeriod::get().saturating_mul(lock_periods.into())
+							);
+							if now < unlock_at {
+								prior.accumulate(unlock_at, balance);
+							}
+						}
+					}
+					Ok(ongoing)
+				}
+				Voting::Delegating { .. } => Err(DispatchError::from(Error::<T>::NotVoter)),
+			}
+		})?;
+		if let Some(mut status) = ongoing {
+			// The referendum is serviced with the new tally on the next block.
+			Self::set_alarm(index, &mut status, now + One::one());
+			ReferendumInfoFor::<T>::insert(index, ReferendumInfo::Ongoing(status));
+		}
+		Ok(())
+	}
+
+	/// Rejig the locks of an account on a track, and set its lock to the greatest needed on any
+	/// track. It will never get more stringent (since that would indicate a security hole) but
+	/// may be reduced from what it currently is.
+	fn update_lock(who: &T::AccountId, track: TrackId) {
+		let now = system::Module::<T>::block_number();
+		VotingFor::<T>::mutate(who, track, |voting| voting.rejig(now));
+		let lock_needed = VotingFor::<T>::iter_prefix_values(who)
+			.map(|voting| voting.locked_balance())
+			.max()
+			.unwrap_or_else(Zero::zero);
+		if lock_needed.is_zero() {
+			T::Currency::remove_lock(REFERENDA_ID, who);
+		} else {
+			T::Currency::set_lock(REFERENDA_ID, who, lock_needed, WithdrawReason::Transfer.into());
+		}
+	}
+}
@@ -0,0 +1,634 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2020 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// Tests for Referenda Pallet
+
+#![cfg(test)]
+
+use super::*;
+
+use frame_support::{
+	assert_ok, assert_noop, impl_outer_origin, impl_outer_dispatch, impl_outer_event,
+	parameter_types, ord_parameter_types, traits::{OnInitialize, PreimageProvider},
+	weights::Weight,
+};
+use frame_system::{EnsureRoot, EnsureSignedBy, RawOrigin};
+use sp_core::H256;
+use sp_runtime::{Perbill, traits::{BlakeTwo256, IdentityLookup, BadOrigin, Hash}, testing::Header};
+use pallet_balances::BalanceLock;
+use pallet_democracy::{Vote, Conviction};
+use crate as referenda;
+
+impl_outer_origin! {
+	pub enum Origin for Test where system = frame_system {}
+}
+
+impl_outer_dispatch! {
+	pub enum Call for Test where origin: Origin {
+		frame_system::System,
+		pallet_balances::Balances,
+	}
+}
+
+impl_outer_event! {
+	pub enum TestEvent for Test {
+		frame_system<T>,
+		pallet_balances<T>,
+		pallet_scheduler<T>,
+		pallet_preimage<T>,
+		referenda<T>,
+	}
+}
+
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct Test;
+parameter_types! {
+	pub const BlockHashCount: u64 = 250;
+	pub const MaximumBlockWeight: Weight = 1_000_000;
+	pub const MaximumBlockLength: u32 = 2 * 1024;
+	pub const AvailableBlockRatio: Perbill = Perbill::one();
+}
+impl frame_system::Trait for Test {
+	type BaseCallFilter = ();
+	type Origin = Origin;
+	type Index = u64;
+	type Call = Call;
+	type BlockNumber = u64;
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type AccountId = u64;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type Header = Header;
+	type Event = TestEvent;
+	type BlockHashCount = BlockHashCount;
+	type MaximumBlockWeight = MaximumBlockWeight;
+	type DbWeight = ();
+	type BlockExecutionWeight = ();
+	type ExtrinsicBaseWeight = ();
+	type MaximumExtrinsicWeight = MaximumBlockWeight;
+	type AvailableBlockRatio = AvailableBlockRatio;
+	type MaximumBlockLength = MaximumBlockLength;
+	type Version = ();
+	type PalletInfo = ();
+	type AccountData = pallet_balances::AccountData<u64>;
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+}
+parameter_types! {
+	pub const ExistentialDeposit: u64 = 1;
+}
+impl pallet_balances::Trait for Test {
+	type Balance = u64;
+	type Event = TestEvent;
+	type DustRemoval = ();
+	type ExistentialDeposit = ExistentialDeposit;
+	type AccountStore = System;
+	type MaxLocks = ();
+	type MaxReserves = ();
+	type ReserveIdentifier = [u8; 8];
+	type WeightInfo = ();
+}
+parameter_types! {
+	pub MaximumSchedulerWeight: Weight = Perbill::from_percent(80) * MaximumBlockWeight::get();
+}
+impl pallet_scheduler::Trait for Test {
+	type Event = TestEvent;
+	type Origin = Origin;
+	type PalletsOrigin = OriginCaller;
+	type Call = Call;
+	type MaximumWeight = MaximumSchedulerWeight;
+	type ScheduleOrigin = EnsureRoot<u64>;
+	type MaxScheduledPerBlock = ();
+	type PreimageProvider = Preimage;
+	type NoPreimagePostponement = ();
+	type WeightInfo = ();
+}
+parameter_types! {
+	pub const MaxSize: u32 = 1024;
+	pub const BaseDeposit: u64 = 0;
+	pub const ByteDeposit: u64 = 0;
+}
+impl pallet_preimage::Trait for Test {
+	type Event = TestEvent;
+	type Currency = Balances;
+	type ManagerOrigin = EnsureRoot<u64>;
+	type MaxSize = MaxSize;
+	type BaseDeposit = BaseDeposit;
+	type ByteDeposit = ByteDeposit;
+	type WeightInfo = ();
+}
+parameter_types! {
+	pub const SubmissionDeposit: u64 = 2;
+	pub const MaxQueued: u32 = 2;
+	pub const UndecidingTimeout: u64 = 20;
+	pub const AlarmInterval: u64 = 3;
+	pub const VoteLockingPeriod: u64 = 10;
+	pub const MaxVotes: u32 = 3;
+}
+ord_parameter_types! {
+	pub const Four: u64 = 4;
+	pub const Five: u64 = 5;
+}
+pub struct TestTracks;
+impl TracksInfo<u64, u64> for TestTracks {
+	type Origin = OriginCaller;
+	fn tracks() -> Vec<(TrackId, TrackInfo<u64, u64>)> {
+		vec![
+			(0, TrackInfo {
+				name: "root",
+				max_deciding: 1,
+				decision_deposit: 10,
+				prepare_period: 4,
+				decision_period: 4,
+				confirm_period: 2,
+				min_enactment_period: 4,
+				min_approval: Curve::LinearDecreasing {
+					length: Perbill::one(),
+					floor: Perbill::from_percent(50),
+					ceil: Perbill::one(),
+				},
+				min_support: Curve::LinearDecreasing {
+					length: Perbill::one(),
+					floor: Perbill::zero(),
+					ceil: Perbill::from_percent(25),
+				},
+			}),
+			(1, TrackInfo {
+				name: "signed",
+				max_deciding: 2,
+				decision_deposit: 1,
+				prepare_period: 1,
+				decision_period: 2,
+				confirm_period: 1,
+				min_enactment_period: 1,
+				min_approval: Curve::SteppedDecreasing {
+					begin: Perbill::one(),
+					end: Perbill::from_percent(50),
+					step: Perbill::from_percent(25),
+					period: Perbill::from_percent(50),
+				},
+				min_support: Curve::LinearDecreasing {
+					length: Perbill::one(),
+					floor: Perbill::zero(),
+					ceil: Perbill::zero(),
+				},
+			}),
+		]
+	}
+	fn track_for(origin: &OriginCaller) -> Result<TrackId, ()> {
+		match origin {
+			OriginCaller::system(RawOrigin::Root) => Ok(0),
+			OriginCaller::system(RawOrigin::Signed(_)) => Ok(1),
+			_ => Err(()),
+		}
+	}
+}
+impl Trait for Test {
+	type Event = TestEvent;
+	type Proposal = Call;
+	type Scheduler = Scheduler;
+	type PalletsOrigin = OriginCaller;
+	type Currency = Balances;
+	type Preimages = Preimage;
+	type CancelOrigin = EnsureSignedBy<Four, u64>;
+	type KillOrigin = EnsureSignedBy<Five, u64>;
+	type Slash = ();
+	type SubmissionDeposit = SubmissionDeposit;
+	type MaxQueued = MaxQueued;
+	type UndecidingTimeout = UndecidingTimeout;
+	type AlarmInterval = AlarmInterval;
+	type VoteLockingPeriod = VoteLockingPeriod;
+	type MaxVotes = MaxVotes;
+	type Tracks = TestTracks;
+	type WeightInfo = ();
+}
+type System = frame_system::Module<Test>;
+type Balances = pallet_balances::Module<Test>;
+type Scheduler = pallet_scheduler::Module<Test>;
+type Preimage = pallet_preimage::Module<Test>;
+type Referenda = Module<Test>;
+
+pub(crate) fn new_test_ext() -> sp_io::TestExternalities {
+	let mut t = frame_system::GenesisConfig::default().build_storage::<Test>().unwrap();
+	pallet_balances::GenesisConfig::<Test> {
+		balances: vec![(1, 10), (2, 20), (3, 30), (4, 40), (5, 50), (6, 60)],
+	}.assimilate_storage(&mut t).unwrap();
+	let mut ext = sp_io::TestExternalities::new(t);
+	ext.execute_with(|| System::set_block_number(1));
+	ext
+}
+
+const AYE: Vote = Vote { aye: true, conviction: Conviction::Locked1x };
+const NAY: Vote = Vote { aye: false, conviction: Conviction::Locked1x };
+
+fn aye(balance: u64) -> AccountVote<u64> {
+	AccountVote::Standard { vote: AYE, balance }
+}
+
+fn nay(balance: u64) -> AccountVote<u64> {
+	AccountVote::Standard { vote: NAY, balance }
+}
+
+fn events() -> Vec<RawEvent<u64, u64, H256>> {
+	System::events().into_iter().filter_map(|r| match r.event {
+		TestEvent::referenda(e) => Some(e),
+		_ => None,
+	}).collect()
+}
+
+fn run_to(n: u64) {
+	while System::block_number() < n {
+		System::set_block_number(System::block_number() + 1);
+		Scheduler::on_initialize(System::block_number());
+		Referenda::on_initialize(System::block_number());
+	}
+}
+
+fn set_balance_proposal(value: u64) -> Vec<u8> {
+	Call::Balances(pallet_balances::Call::set_balance(42, value, 0)).encode()
+}
+
+fn note_proposal(value: u64) -> H256 {
+	let encoded = set_balance_proposal(value);
+	let hash = BlakeTwo256::hash(&encoded[..]);
+	assert_ok!(Preimage::note_preimage(Origin::root(), encoded));
+	hash
+}
+
+fn submit(who: u64, origin: RawOrigin<u64>, value: u64) -> ReferendumIndex {
+	let index = Referenda::referendum_count();
+	assert_ok!(Referenda::submit(
+		Origin::signed(who),
+		Box::new(origin.into()),
+		note_proposal(value),
+		DispatchTime::After(0),
+	));
+	index
+}
+
+fn deciding(index: ReferendumIndex) -> Option<DecidingStatus<u64>> {
+	Referenda::referendum_status(index).and_then(|s| s.deciding)
+}
+
+#[test]
+fn curves_work() {
+	let linear = Curve::LinearDecreasing {
+		length: Perbill::from_percent(50),
+		floor: Perbill::from_percent(20),
+		ceil: Perbill::from_percent(80),
+	};
+	assert_eq!(linear.threshold(Perbill::zero()), Perbill::from_percent(80));
+	assert_eq!(linear.threshold(Perbill::from_percent(25)), Perbill::from_percent(50));
+	assert_eq!(linear.threshold(Perbill::from_percent(75)), Perbill::from_percent(20));
+	assert_eq!(linear.delay(Perbill::from_percent(90)), Perbill::zero());
+	assert_eq!(linear.delay(Perbill::from_percent(50)), Perbill::from_percent(25));
+	assert_eq!(linear.delay(Perbill::from_percent(10)), Perbill::one());
+	assert!(linear.passing(Perbill::from_percent(25), Perbill::from_percent(50)));
+	assert!(!linear.passing(Perbill::from_percent(24), Perbill::from_percent(50)));
+
+	let stepped = Curve::SteppedDecreasing {
+		begin: Perbill::from_percent(80),
+		end: Perbill::from_percent(30),
+		step: Perbill::from_percent(20),
+		period: Perbill::from_percent(10),
+	};
+	assert_eq!(stepped.threshold(Perbill::from_percent(9)), Perbill::from_percent(80));
+	assert_eq!(stepped.threshold(Perbill::from_percent(10)), Perbill::from_percent(60));
+	assert_eq!(stepped.threshold(Perbill::from_percent(90)), Perbill::from_percent(30));
+	assert_eq!(stepped.delay(Perbill::from_percent(50)), Perbill::from_percent(20));
+	assert_eq!(stepped.delay(Perbill::from_percent(20)), Perbill::one());
+}
+
+#[test]
+fn submit_works() {
+	new_test_ext().execute_with(|| {
+		let h = note_proposal(1);
+		let none: Box<OriginCaller> = Box::new(RawOrigin::None.into());
+		let root: Box<OriginCaller> = Box::new(RawOrigin::Root.into());
+		assert_noop!(
+			Referenda::submit(Origin::signed(1), none, h, DispatchTime::At(10)),
+			Error::<Test>::NoTrack,
+		);
+		assert_noop!(
+			Referenda::submit(Origin::signed(1), root.clone(), H256::zero(), DispatchTime::At(10)),
+			Error::<Test>::PreimageNotExist,
+		);
+		assert_ok!(Preimage::note_preimage(Origin::root(), vec![0xff]));
+		let bad = BlakeTwo256::hash(&[0xff][..]);
+		assert_noop!(
+			Referenda::submit(Origin::signed(1), root, bad, DispatchTime::At(10)),
+			Error::<Test>::PreimageInvalid,
+		);
+
+		assert_ok!(Referenda::submit(
+			Origin::signed(1),
+			Box::new(RawOrigin::Signed(1).into()),
+			h,
+			DispatchTime::At(10),
+		));
+		assert_eq!(events(), vec![RawEvent::Submitted(0, 1, h)]);
+		assert_eq!(Balances::reserved_balance(1), 2);
+		assert!(Preimage::preimage_requested(&h));
+		let status = Referenda::referendum_status(0).unwrap();
+		assert_eq!(status.track, 1);
+		assert_eq!(status.alarm, Some(21));
+		assert_eq!(Alarms::<Test>::get(21), vec![0]);
+	});
+}
+
+#[test]
+fn approval_works() {
+	new_test_ext().execute_with(|| {
+		let r = submit(1, RawOrigin::Root, 2);
+		assert_ok!(Referenda::place_decision_deposit(Origin::signed(2), r));
+		assert_noop!(
+			Referenda::place_decision_deposit(Origin::signed(3), r),
+			Error::<Test>::HasDeposit,
+		);
+		assert_eq!(Balances::reserved_balance(2), 10);
+		assert_eq!(Referenda::referendum_status(r).unwrap().alarm, Some(5));
+		assert_ok!(Referenda::vote(Origin::signed(6), r, aye(60)));
+
+		run_to(4);
+		assert_eq!(deciding(r), None);
+		// The referendum begins deciding at the end of the prepare period, and is passing.
+		run_to(5);
+		assert_eq!(deciding(r), Some(DecidingStatus { since: 5, confirming: Some(7) }));
+		assert_eq!(Referenda::deciding_count(0), 1);
+
+		run_to(7);
+		assert_eq!(
+			Referenda::referendum_info(r),
+			Some(ReferendumInfo::Approved(
+				7,
+				Some(Deposit { who: 1, amount: 2 }),
+				Some(Deposit { who: 2, amount: 10 }),
+			)),
+		);
+		assert_eq!(events()[2..].to_vec(), vec![
+			RawEvent::DecisionStarted(r, 0, BlakeTwo256::hash(&set_balance_proposal(2)[..])),
+			RawEvent::ConfirmStarted(r),
+			RawEvent::Approved(r),
+		]);
+		assert_eq!(Referenda::deciding_count(0), 0);
+
+		// The proposal is enacted after the track's minimum enactment period.
+		run_to(10);
+		assert_eq!(Balances::free_balance(42), 0);
+		run_to(11);
+		assert_eq!(Balances::free_balance(42), 2);
+
+		assert_ok!(Referenda::refund_deposits(Origin::signed(3), r));
+		assert_noop!(Referenda::refund_deposits(Origin::signed(3), r), Error::<Test>::NoDeposit);
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert_eq!(Balances::reserved_balance(2), 0);
+	});
+}
+
+#[test]
+fn failed_enactment_is_reported() {
+	new_test_ext().execute_with(|| {
+		let r = submit(1, RawOrigin::Root, 2);
+		assert_ok!(Referenda::place_decision_deposit(Origin::signed(2), r));
+		assert_ok!(Referenda::vote(Origin::signed(6), r, aye(60)));
+		// Take the name under which the proposal would be scheduled.
+		let call = Call::System(frame_system::Call::remark(vec![]));
+		assert!(<Scheduler as ScheduleNamed<_, _, _>>::schedule_named(
+			(REFERENDA_ID, r).encode(),
+			DispatchTime::At(100),
+			None,
+			63,
+			RawOrigin::Root.into(),
+			call,
+		).is_ok());
+
+		run_to(7);
+		assert_eq!(
+			Referenda::referendum_info(r),
+			Some(ReferendumInfo::EnactmentFailed(
+				7,
+				Some(Deposit { who: 1, amount: 2 }),
+				Some(Deposit { who: 2, amount: 10 }),
+			)),
+		);
+		assert_eq!(
+			events().last(),
+			Some(&RawEvent::EnactmentFailed(r, Error::<Test>::ScheduleFailed.into())),
+		);
+		assert_eq!(Referenda::deciding_count(0), 0);
+
+		// No lock is due for a vote on a proposal which was never enacted.
+		assert_ok!(Referenda::remove_vote(Origin::signed(6), 0, r));
+		assert_ok!(Referenda::unlock(Origin::signed(1), 0, 6));
+		assert_eq!(Balances::locks(6), vec![]);
+		assert_ok!(Referenda::refund_deposits(Origin::signed(3), r));
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert_eq!(Balances::reserved_balance(2), 0);
+	});
+}
+
+#[test]
+fn rejection_works() {
+	new_test_ext().execute_with(|| {
+		let r = submit(1, RawOrigin::Root, 2);
+		assert_ok!(Referenda::place_decision_deposit(Origin::signed(2), r));
+		assert_ok!(Referenda::vote(Origin::signed(6), r, nay(60)));
+		assert_noop!(Referenda::refund_deposits(Origin::signed(3), r), Error::<Test>::Unfinished);
+
+		run_to(8);
+		assert_eq!(deciding(r), Some(DecidingStatus { since: 5, confirming: None }));
+		run_to(9);
+		assert!(matches!(Referenda::referendum_info(r), Some(ReferendumInfo::Rejected(9, ..))));
+		assert_eq!(events().last(), Some(&RawEvent::Rejected(r)));
+
+		assert_ok!(Referenda::refund_deposits(Origin::signed(3), r));
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert_eq!(Balances::reserved_balance(2), 0);
+	});
+}
+
+#[test]
+fn confirmation_can_be_aborted() {
+	new_test_ext().execute_with(|| {
+		let r = submit(1, RawOrigin::Root, 2);
+		assert_ok!(Referenda::place_decision_deposit(Origin::signed(2), r));
+		assert_ok!(Referenda::vote(Origin::signed(6), r, aye(60)));
+		run_to(5);
+		assert_eq!(deciding(r), Some(DecidingStatus { since: 5, confirming: Some(7) }));
+
+		run_to(6);
+		assert_ok!(Referenda::vote(Origin::signed(5), r, nay(50)));
+		// The referendum is only serviced with the new tally on the next block.
+		assert_eq!(deciding(r), Some(DecidingStatus { since: 5, confirming: Some(7) }));
+		assert_eq!(Referenda::referendum_status(r).unwrap().alarm, Some(7));
+		run_to(7);
+		assert_eq!(deciding(r), Some(DecidingStatus { since: 5, confirming: None }));
+		assert_eq!(events().last(), Some(&RawEvent::ConfirmAborted(r)));
+
+		// Without the nay vote it passes again and restarts confirmation.
+		assert_ok!(Referenda::remove_vote(Origin::signed(5), 0, r));
+		assert_eq!(Referenda::referendum_status(r).unwrap().alarm, Some(8));
+		run_to(8);
+		assert_eq!(deciding(r), Some(DecidingStatus { since: 5, confirming: Some(10) }));
+		run_to(10);
+		assert!(matches!(Referenda::referendum_info(r), Some(ReferendumInfo::Approved(10, ..))));
+	});
+}
+
+#[test]
+fn timeout_works() {
+	new_test_ext().execute_with(|| {
+		let r = submit(1, RawOrigin::Root, 2);
+		run_to(20);
+		assert!(Referenda::referendum_status(r).is_some());
+		run_to(21);
+		assert_eq!(Referenda::referendum_info(r), Some(ReferendumInfo::TimedOut(21, None)));
+		assert_eq!(events().last(), Some(&RawEvent::TimedOut(r)));
+		// The submission deposit is slashed.
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert_eq!(Balances::free_balance(1), 8);
+		assert_noop!(Referenda::refund_deposits(Origin::signed(1), r), Error::<Test>::NoDeposit);
+	});
+}
+
+#[test]
+fn queueing_works() {
+	new_test_ext().execute_with(|| {
+		let r0 = submit(1, RawOrigin::Root, 1);
+		let r1 = submit(1, RawOrigin::Root, 2);
+		let r2 = submit(1, RawOrigin::Root, 3);
+		let r3 = submit(1, RawOrigin::Root, 4);
+		for r in vec![r0, r1, r2, r3] {
+			assert_ok!(Referenda::place_decision_deposit(Origin::signed(6), r));
+		}
+		assert_ok!(Referenda::vote(Origin::signed(2), r1, aye(20)));
+		assert_ok!(Referenda::vote(Origin::signed(3), r2, aye(30)));
+
+		// Only one referendum may be decided on the root track, and only two queued.
+		run_to(5);
+		assert!(deciding(r0).is_some());
+		assert_eq!(Referenda::track_queue(0), vec![(r1, 20), (r2, 30)]);
+		assert_eq!(Referenda::referendum_status(r3).unwrap().alarm, Some(8));
+
+		// Votes keep the queue ordered once the referendum is serviced.
+		assert_ok!(Referenda::vote(Origin::signed(4), r1, aye(40)));
+		assert_eq!(Referenda::track_queue(0), vec![(r1, 20), (r2, 30)]);
+		run_to(6);
+		assert_eq!(Referenda::track_queue(0), vec![(r2, 30), (r1, 60)]);
+
+		// A freed slot goes to the queued referendum with the most ayes.
+		assert_ok!(Referenda::cancel(Origin::signed(4), r0));
+		assert_eq!(Referenda::deciding_count(0), 1);
+		assert_eq!(Referenda::track_queue(0), vec![(r2, 30)]);
+		assert_eq!(deciding(r1), Some(DecidingStatus { since: 6, confirming: None }));
+		assert!(Referenda::referendum_status(r1).unwrap().alarm.is_some());
+
+		// The referendum waiting for room is queued once there is some, and the slot freed by
+		// the approval of the deciding referendum goes to the top of the queue.
+		run_to(9);
+		assert!(matches!(Referenda::referendum_info(r1), Some(ReferendumInfo::Approved(9, ..))));
+		assert_eq!(deciding(r2), Some(DecidingStatus { since: 9, confirming: None }));
+		assert_eq!(Referenda::track_queue(0), vec![(r3, 0)]);
+		assert!(Referenda::referendum_status(r3).unwrap().in_queue);
+	});
+}
+
+#[test]
+fn cancel_and_kill_work() {
+	new_test_ext().execute_with(|| {
+		let r0 = submit(1, RawOrigin::Root, 1);
+		let r1 = submit(1, RawOrigin::Root, 2);
+		assert_ok!(Referenda::place_decision_deposit(Origin::signed(2), r1));
+		let h = BlakeTwo256::hash(&set_balance_proposal(1)[..]);
+
+		assert_noop!(Referenda::cancel(Origin::signed(5), r0), BadOrigin);
+		assert_ok!(Referenda::cancel(Origin::signed(4), r0));
+		assert_eq!(events().last(), Some(&RawEvent::Cancelled(r0)));
+		assert!(!Preimage::preimage_requested(&h));
+		assert_noop!(Referenda::cancel(Origin::signed(4), r0), Error::<Test>::NotOngoing);
+		assert_ok!(Referenda::refund_deposits(Origin::signed(3), r0));
+		assert_eq!(Balances::reserved_balance(1), 2);
+
+		assert_noop!(Referenda::kill(Origin::signed(4), r1), BadOrigin);
+		assert_ok!(Referenda::kill(Origin::signed(5), r1));
+		assert_eq!(Referenda::referendum_info(r1), Some(ReferendumInfo::Killed(1)));
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert_eq!(Balances::free_balance(1), 8);
+		assert_eq!(Balances::reserved_balance(2), 0);
+		assert_eq!(Balances::free_balance(2), 10);
+	});
+}
+
+#[test]
+fn voting_and_locks_work() {
+	new_test_ext().execute_with(|| {
+		let r = submit(1, RawOrigin::Root, 2);
+		let s = submit(1, RawOrigin::Signed(1), 3);
+		assert_noop!(
+			Referenda::vote(Origin::signed(6), r, aye(61)),
+			Error::<Test>::InsufficientFunds,
+		);
+		assert_ok!(Referenda::vote(Origin::signed(6), r, aye(10)));
+		let tally = |r| Referenda::referendum_status(r).unwrap().tally;
+		assert_eq!(tally(r), Tally { ayes: 10, nays: 0, support: 10 });
+		assert_ok!(Referenda::vote(Origin::signed(6), r, aye(60)));
+		assert_eq!(tally(r), Tally { ayes: 60, nays: 0, support: 60 });
+		assert_ok!(Referenda::vote(Origin::signed(6), s, AccountVote::Split { aye: 5, nay: 5 }));
+		assert_eq!(tally(s), Tally { ayes: 0, nays: 0, support: 5 });
+		assert_eq!(Balances::locks(6), vec![BalanceLock {
+			id: REFERENDA_ID,
+			amount: 60,
+			reasons: pallet_balances::Reasons::Misc,
+		}]);
+
+		// Votes are kept per track.
+		assert_noop!(Referenda::remove_vote(Origin::signed(6), 1, r), Error::<Test>::NotVoter);
+		assert_ok!(Referenda::remove_vote(Origin::signed(6), 1, s));
+		assert_eq!(tally(s), Tally::default());
+
+		assert_ok!(Referenda::place_decision_deposit(Origin::signed(2), r));
+		run_to(7);
+		assert!(matches!(Referenda::referendum_info(r), Some(ReferendumInfo::Approved(7, ..))));
+
+		// The winning vote stays locked for the conviction's lock period after removal.
+		assert_ok!(Referenda::remove_vote(Origin::signed(6), 0, r));
+		assert_ok!(Referenda::unlock(Origin::signed(1), 0, 6));
+		assert_eq!(Balances::locks(6).len(), 1);
+		run_to(17);
+		assert_ok!(Referenda::unlock(Origin::signed(1), 0, 6));
+		assert_eq!(Balances::locks(6), vec![]);
+	});
+}
+
+#[test]
+fn max_votes_works() {
+	new_test_ext().execute_with(|| {
+		for i in 0..3 {
+			let r = submit(2, RawOrigin::Root, i);
+			assert_ok!(Referenda::vote(Origin::signed(6), r, aye(1)));
+		}
+		let r = submit(2, RawOrigin::Root, 3);
+		assert_noop!(
+			Referenda::vote(Origin::signed(6), r, aye(1)),
+			Error::<Test>::MaxVotesReached,
+		);
+		// Votes on other tracks are counted separately.
+		let s = submit(2, RawOrigin::Signed(2), 4);
+		assert_ok!(Referenda::vote(Origin::signed(6), s, aye(1)));
+	});
+}
@@ -0,0 +1,269 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2020 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Miscellaneous additional datatypes.
+
+use sp_std::prelude::*;
+use codec::{Encode, Decode};
+use sp_runtime::{
+	Perbill, RuntimeDebug,
+	traits::{Zero, Bounded, Saturating, CheckedAdd, CheckedSub, CheckedMul, CheckedDiv},
+};
+use frame_support::traits::schedule::DispatchTime;
+use frame_support::type_info::TypeInfo;
+use pallet_democracy::{AccountVote, Conviction, Delegations};
+
+/// The identifier of a track.
+pub type TrackId = u16;
+
+/// A referendum index.
+pub type ReferendumIndex = u32;
+
+/// A curve giving the minimum level of approval or support that a referendum needs, as a function
+/// of how far it is through its decision period.
+#[derive(Encode, Decode, Copy, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub enum Curve {
+	/// Starts at `ceil` and decreases linearly to `floor`, which it reaches at `length` of the
+	/// decision period, after which it stays flat.
+	LinearDecreasing { length: Perbill, floor: Perbill, ceil: Perbill },
+	/// Starts at `begin` and decreases by `step` every `period` of the decision period, until it
+	/// reaches `end`.
+	SteppedDecreasing { begin: Perbill, end: Perbill, step: Perbill, period: Perbill },
+}
+
+impl Curve {
+	/// The threshold at the point `x` of the decision period.
+	pub fn threshold(&self, x: Perbill) -> Perbill {
+		match *self {
+			Curve::LinearDecreasing { length, floor, ceil } => {
+				let progress = if length.is_zero() { Perbill::one() } else { x.min(length) / length };
+				ceil.saturating_sub(progress.saturating_mul(ceil.saturating_sub(floor))).max(floor)
+			},
+			Curve::SteppedDecreasing { begin, end, step, period } => {
+				let steps = if period.is_zero() { 0 } else { x.deconstruct() / period.deconstruct() };
+				let decrease = step.deconstruct().saturating_mul(steps);
+				Perbill::from_parts(begin.deconstruct().saturating_sub(decrease)).max(end)
+			},
+		}
+	}
+
+	/// Whether `y` meets the threshold at the point `x` of the decision period.
+	pub fn passing(&self, x: Perbill, y: Perbill) -> bool {
+		y >= self.threshold(x)
+	}
+
+	/// The earliest point of the decision period at which `y` meets the threshold. This is the
+	/// end of the period if `y` never does.
+	pub fn delay(&self, y: Perbill) -> Perbill {
+		match *self {
+			Curve::LinearDecreasing { length, floor, ceil } => {
+				if y >= ceil {
+					Perbill::zero()
+				} else if y < floor {
+					Perbill::one()
+				} else {
+					let span = ceil.saturating_sub(floor);
+					length.saturating_mul(ceil.saturating_sub(y) / span)
+				}
+			},
+			Curve::SteppedDecreasing { begin, end, step, period } => {
+				if y >= begin {
+					Perbill::zero()
+				} else if y < end || step.is_zero() {
+					Perbill::one()
+				} else {
+					let needed = begin.deconstruct() - y.deconstruct();
+					let steps = (needed + step.deconstruct() - 1) / step.deconstruct();
+					Perbill::from_parts(period.deconstruct().saturating_mul(steps))
+				}
+			},
+		}
+	}
+}
+
+/// The configuration of a track.
+#[derive(Clone, PartialEq, Eq, RuntimeDebug)]
+pub struct TrackInfo<Balance, BlockNumber> {
+	/// A human readable name for the track.
+	pub name: &'static str,
+	/// The maximum number of referenda which may be decided at once on this track.
+	pub max_deciding: u32,
+	/// The amount which must be placed for a referendum to enter its decision period.
+	pub decision_deposit: Balance,
+	/// The minimum time between the submission of a referendum and the start of its decision
+	/// period.
+	pub prepare_period: BlockNumber,
+	/// The maximum length of the decision period.
+	pub decision_period: BlockNumber,
+	/// The time for which a referendum must be passing before it is approved.
+	pub confirm_period: BlockNumber,
+	/// The minimum time between the approval of a referendum and its enactment.
+	pub min_enactment_period: BlockNumber,
+	/// The minimum proportion of aye votes, by conviction, for a referendum to pass.
+	pub min_approval: Curve,
+	/// The minimum proportion of the total issuance voting aye, without conviction, for a
+	/// referendum to pass.
+	pub min_support: Curve,
+}
+
+/// Information on the tracks of a runtime.
+pub trait TracksInfo<Balance, BlockNumber> {
+	/// The origin type from which a track is derived.
+	type Origin;
+
+	/// All the tracks, ordered by their identifier.
+	fn tracks() -> Vec<(TrackId, TrackInfo<Balance, BlockNumber>)>;
+
+	/// The track on which referenda dispatching with `origin` are decided.
+	fn track_for(origin: &Self::Origin) -> Result<TrackId, ()>;
+
+	/// The configuration of track `id`.
+	fn info(id: TrackId) -> Option<TrackInfo<Balance, BlockNumber>> {
+		Self::tracks().into_iter().find(|(i, _)| *i == id).map(|(_, info)| info)
+	}
+}
+
+/// The votes cast on a referendum.
+#[derive(Encode, Decode, Default, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct Tally<Balance> {
+	/// The number of aye votes, expressed in terms of post-conviction lock-vote.
+	pub ayes: Balance,
+	/// The number of nay votes, expressed in terms of post-conviction lock-vote.
+	pub nays: Balance,
+	/// The amount of funds voting aye. Pre-conviction.
+	pub support: Balance,
+}
+
+impl<
+	Balance: From<u8> + Zero + Copy + CheckedAdd + CheckedSub + CheckedMul + CheckedDiv + Bounded
+> Tally<Balance> {
+	/// Add an account's vote into the tally.
+	pub fn add(&mut self, vote: AccountVote<Balance>) -> Option<()> {
+		match vote {
+			AccountVote::Standard { vote, balance } => {
+				let Delegations { votes, capital } = vote.conviction.votes(balance);
+				match vote.aye {
+					true => {
+						self.support = self.support.checked_add(&capital)?;
+						self.ayes = self.ayes.checked_add(&votes)?;
+					},
+					false => self.nays = self.nays.checked_add(&votes)?,
+				}
+			}
+			AccountVote::Split { aye, nay } => {
+				let aye = Conviction::None.votes(aye);
+				let nay = Conviction::None.votes(nay);
+				self.support = self.support.checked_add(&aye.capital)?;
+				self.ayes = self.ayes.checked_add(&aye.votes)?;
+				self.nays = self.nays.checked_add(&nay.votes)?;
+			}
+		}
+		Some(())
+	}
+
+	/// Remove an account's vote from the tally.
+	pub fn remove(&mut self, vote: AccountVote<Balance>) -> Option<()> {
+		match vote {
+			AccountVote::Standard { vote, balance } => {
+				let Delegations { votes, capital } = vote.conviction.votes(balance);
+				match vote.aye {
+					true => {
+						self.support = self.support.checked_sub(&capital)?;
+						self.ayes = self.ayes.checked_sub(&votes)?;
+					},
+					false => self.nays = self.nays.checked_sub(&votes)?,
+				}
+			}
+			AccountVote::Split { aye, nay } => {
+				let aye = Conviction::None.votes(aye);
+				let nay = Conviction::None.votes(nay);
+				self.support = self.support.checked_sub(&aye.capital)?;
+				self.ayes = self.ayes.checked_sub(&aye.votes)?;
+				self.nays = self.nays.checked_sub(&nay.votes)?;
+			}
+		}
+		Some(())
+	}
+}
+
+/// A deposit placed by an account.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct Deposit<AccountId, Balance> {
+	/// The account which placed the deposit.
+	pub who: AccountId,
+	/// The amount reserved.
+	pub amount: Balance,
+}
+
+/// The state of a referendum in its decision period.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct DecidingStatus<BlockNumber> {
+	/// When the decision period began.
+	pub since: BlockNumber,
+	/// If `Some`, the referendum is passing and is approved at that block, unless it stops
+	/// passing in the meantime.
+	pub confirming: Option<BlockNumber>,
+}
+
+/// Info regarding an ongoing referendum.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct ReferendumStatus<Origin, BlockNumber, Hash, Balance, AccountId> {
+	/// The track of the referendum.
+	pub track: TrackId,
+	/// The origin with which the proposal is dispatched.
+	pub origin: Origin,
+	/// The hash of the proposal being voted on.
+	pub proposal_hash: Hash,
+	/// When the proposal should be enacted once approved.
+	pub enactment: DispatchTime<BlockNumber>,
+	/// When the referendum was submitted.
+	pub submitted: BlockNumber,
+	/// The deposit placed on submission.
+	pub submission_deposit: Deposit<AccountId, Balance>,
+	/// The deposit placed for the referendum to be decided, if any.
+	pub decision_deposit: Option<Deposit<AccountId, Balance>>,
+	/// The decision status, if the referendum is being decided.
+	pub deciding: Option<DecidingStatus<BlockNumber>>,
+	/// The current tally of votes in this referendum.
+	pub tally: Tally<Balance>,
+	/// Whether the referendum is queued for a decision slot on its track.
+	pub in_queue: bool,
+	/// The next block at which the referendum is serviced, if any.
+	pub alarm: Option<BlockNumber>,
+}
+
+/// Info regarding a referendum, present or past. Concluded referenda keep any deposit not yet
+/// refunded.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub enum ReferendumInfo<Origin, BlockNumber, Hash, Balance, AccountId> {
+	/// The referendum is happening.
+	Ongoing(ReferendumStatus<Origin, BlockNumber, Hash, Balance, AccountId>),
+	/// The referendum was approved at the given block.
+	Approved(BlockNumber, Option<Deposit<AccountId, Balance>>, Option<Deposit<AccountId, Balance>>),
+	/// The referendum was rejected at the given block.
+	Rejected(BlockNumber, Option<Deposit<AccountId, Balance>>, Option<Deposit<AccountId, Balance>>),
+	/// The referendum was cancelled at the given block.
+	Cancelled(BlockNumber, Option<Deposit<AccountId, Balance>>, Option<Deposit<AccountId, Balance>>),
+	/// The referendum was never decided and timed out at the given block. Its submission deposit
+	/// was slashed.
+	TimedOut(BlockNumber, Option<Deposit<AccountId, Balance>>),
+	/// The referendum was killed at the given block and all its deposits slashed.
+	Killed(BlockNumber),
+	/// The referendum was approved at the given block, but its proposal could not be scheduled
+	/// for enactment.
+	EnactmentFailed(BlockNumber, Option<Deposit<AccountId, Balance>>, Option<Deposit<AccountId, Balance>>),
+}
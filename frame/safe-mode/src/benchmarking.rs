@@ -0,0 +1,126 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2020 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// Benchmarks for Safe Mode Pallet
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use frame_benchmarking::benchmarks;
+use frame_support::traits::UnfilteredDispatchable;
+use sp_runtime::traits::Bounded;
+
+use crate::Module as SafeMode;
+
+/// The pallet and call name of the call of the runtime which takes the longest to look up.
+///
+/// Calls are looked up by scanning the names of the pallets and then the names of the calls of
+/// the pallet, so the worst case is the last call of the last pallet.
+fn worst_case_call<T: Trait>() -> (Vec<u8>, Vec<u8>) {
+	let pallet_name = *<T as Trait>::Call::get_module_names().last().expect("the runtime has calls");
+	let call_name = *<T as Trait>::Call::get_call_names(pallet_name)
+		.last()
+		.expect("a pallet with calls has at least one call");
+	(pallet_name.as_bytes().to_vec(), call_name.as_bytes().to_vec())
+}
+
+/// The name of the pallet of the runtime which takes the longest to look up.
+fn worst_case_pallet<T: Trait>() -> Vec<u8> {
+	<T as Trait>::Call::get_module_names()
+		.last()
+		.expect("the runtime has calls")
+		.as_bytes()
+		.to_vec()
+}
+
+benchmarks! {
+	_ { }
+
+	pause {
+		let (pallet_name, call_name) = worst_case_call::<T>();
+		let origin = T::PauseOrigin::successful_origin();
+		let call = Call::<T>::pause(pallet_name.clone(), call_name.clone());
+	}: { call.dispatch_bypass_filter(origin)? }
+	verify {
+		assert!(SafeMode::<T>::is_paused(&pallet_name, &call_name));
+	}
+
+	unpause {
+		let (pallet_name, call_name) = worst_case_call::<T>();
+		PausedCalls::insert((pallet_name.clone(), call_name.clone()), ());
+		let origin = T::UnpauseOrigin::successful_origin();
+		let call = Call::<T>::unpause(pallet_name.clone(), call_name.clone());
+	}: { call.dispatch_bypass_filter(origin)? }
+	verify {
+		assert!(!SafeMode::<T>::is_paused(&pallet_name, &call_name));
+	}
+
+	enter_safe_mode {
+		let origin = T::PauseOrigin::successful_origin();
+		let call = Call::<T>::enter_safe_mode();
+	}: { call.dispatch_bypass_filter(origin)? }
+	verify {
+		assert!(SafeMode::<T>::is_safe_mode_active());
+	}
+
+	exit_safe_mode {
+		SafeModeUntil::<T>::put(T::BlockNumber::max_value());
+		let origin = T::UnpauseOrigin::successful_origin();
+		let call = Call::<T>::exit_safe_mode();
+	}: { call.dispatch_bypass_filter(origin)? }
+	verify {
+		assert!(!SafeMode::<T>::is_safe_mode_active());
+	}
+
+	pause_pallet {
+		let pallet_name = worst_case_pallet::<T>();
+		let origin = T::PauseOrigin::successful_origin();
+		let call = Call::<T>::pause_pallet(pallet_name.clone());
+	}: { call.dispatch_bypass_filter(origin)? }
+	verify {
+		assert!(SafeMode::<T>::is_pallet_paused(&pallet_name));
+	}
+
+	unpause_pallet {
+		let pallet_name = worst_case_pallet::<T>();
+		PausedPallets::insert(&pallet_name, ());
+		let origin = T::UnpauseOrigin::successful_origin();
+		let call = Call::<T>::unpause_pallet(pallet_name.clone());
+	}: { call.dispatch_bypass_filter(origin)? }
+	verify {
+		assert!(!SafeMode::<T>::is_pallet_paused(&pallet_name));
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::tests::{new_test_ext, Test};
+	use frame_support::assert_ok;
+
+	#[test]
+	fn test_benchmarks() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(test_benchmark_pause::<Test>());
+			assert_ok!(test_benchmark_unpause::<Test>());
+			assert_ok!(test_benchmark_enter_safe_mode::<Test>());
+			assert_ok!(test_benchmark_exit_safe_mode::<Test>());
+			assert_ok!(test_benchmark_pause_pallet::<Test>());
+			assert_ok!(test_benchmark_unpause_pallet::<Test>());
+		});
+	}
+}
@@ -0,0 +1,361 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2020 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # Safe Mode Module
+//!
+//! - [`safe_mode::Trait`](./trait.Trait.html)
+//! - [`Call`](./enum.Call.html)
+//!
+//! ## Overview
+//!
+//! The Safe Mode module allows to stop the dispatch of calls during an incident, without requiring
+//! a runtime upgrade. It keeps an on-chain set of paused calls, each identified by the name of its
+//! pallet and its own name as returned by [`GetCallMetadata`], as well as a set of paused pallets
+//! whose calls are all paused. It can also put the whole chain into a safe mode in which only
+//! whitelisted calls are dispatched. The safe mode ends automatically after
+//! `SafeModeDuration` blocks.
+//!
+//! The module implements [`Filter`] for the runtime call and is meant to be used as (or as part
+//! of) the `BaseCallFilter` of `frame_system`. The calls of this module and the calls accepted by
+//! `WhitelistedCalls` are never filtered, so that the restrictions can always be lifted.
+//!
+//! Note that the `Root` origin bypasses the `BaseCallFilter` and is therefore not affected by the
+//! restrictions.
+//!
+//! ## Interface
+//!
+//! ### Dispatchable Functions
+//!
+//! * `pause` - Pause a call, identified by the name of its pallet and its own name.
+//! * `unpause` - Unpause a previously paused call.
+//! * `enter_safe_mode` - Only allow whitelisted calls for `SafeModeDuration` blocks.
+//! * `exit_safe_mode` - End the safe mode before it expires.
+//! * `pause_pallet` - Pause all the calls of a pallet, identified by its name.
+//! * `unpause_pallet` - Unpause a previously paused pallet.
+//!
+//! [`Call`]: ./enum.Call.html
+//! [`Trait`]: ./trait.Trait.html
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+mod tests;
+mod benchmarking;
+mod default_weights;
+
+use sp_std::prelude::*;
+use frame_support::{
+	decl_module, decl_event, decl_storage, decl_error, ensure,
+	traits::{EnsureOrigin, Filter, Get, GetCallMetadata, IsSubType},
+	weights::Weight,
+};
+use sp_runtime::traits::Saturating;
+use frame_system::Module as System;
+
+pub trait WeightInfo {
+	fn pause() -> Weight;
+	fn unpause() -> Weight;
+	fn enter_safe_mode() -> Weight;
+	fn exit_safe_mode() -> Weight;
+	fn pause_pallet() -> Weight;
+	fn unpause_pallet() -> Weight;
+}
+
+pub trait Trait: frame_system::Trait {
+	/// The overarching event type.
+	type Event: From<Event<Self>> + Into<<Self as frame_system::Trait>::Event>;
+
+	/// The overarching call type, which is filtered by this module.
+	type Call: GetCallMetadata + IsSubType<Call<Self>>;
+
+	/// The origin which may pause calls and enter the safe mode.
+	type PauseOrigin: EnsureOrigin<Self::Origin>;
+
+	/// The origin which may unpause calls and exit the safe mode.
+	type UnpauseOrigin: EnsureOrigin<Self::Origin>;
+
+	/// The calls which are never filtered, neither when paused nor in safe mode.
+	type WhitelistedCalls: Filter<<Self as Trait>::Call>;
+
+	/// The number of blocks after which the safe mode ends.
+	type SafeModeDuration: Get<Self::BlockNumber>;
+
+	/// Weight information for extrinsics in this pallet.
+	type WeightInfo: WeightInfo;
+}
+
+decl_storage! {
+	trait Store for Module<T: Trait> as SafeMode {
+		/// The paused calls, keyed by the name of their pallet and their own name.
+		pub PausedCalls get(fn paused_calls):
+			map hasher(blake2_128_concat) (Vec<u8>, Vec<u8>) => Option<()>;
+
+		/// The paused pallets, keyed by their name. All the calls of a paused pallet are paused.
+		pub PausedPallets get(fn paused_pallets): map hasher(blake2_128_concat) Vec<u8> => Option<()>;
+
+		/// The block at which the safe mode ends, if it is active.
+		pub SafeModeUntil get(fn safe_mode_until): Option<T::BlockNumber>;
+	}
+}
+
+decl_event!(
+	pub enum Event<T> where BlockNumber = <T as frame_system::Trait>::BlockNumber {
+		/// A call was paused. \[pallet_name, call_name\]
+		CallPaused(Vec<u8>, Vec<u8>),
+		/// A call was unpaused. \[pallet_name, call_name\]
+		CallUnpaused(Vec<u8>, Vec<u8>),
+		/// The safe mode was entered, until the given block. \[until\]
+		SafeModeEntered(BlockNumber),
+		/// The safe mode was exited, either explicitly or because it expired.
+		SafeModeExited,
+		/// All the calls of a pallet were paused. \[pallet_name\]
+		PalletPaused(Vec<u8>),
+		/// The calls of a pallet were unpaused. \[pallet_name\]
+		PalletUnpaused(Vec<u8>),
+	}
+);
+
+decl_error! {
+	/// Error for the safe mode module.
+	pub enum Error for Module<T: Trait> {
+		/// There is no call with the given pallet and call name.
+		UnknownCall,
+		/// The call is already paused.
+		AlreadyPaused,
+		/// The call is not paused.
+		NotPaused,
+		/// The safe mode is already active.
+		SafeModeActive,
+		/// The safe mode is not active.
+		SafeModeInactive,
+		/// There is no pallet with the given name.
+		UnknownPallet,
+		/// The pallet is already paused.
+		PalletAlreadyPaused,
+		/// The pallet is not paused.
+		PalletNotPaused,
+	}
+}
+
+decl_module! {
+	/// Safe mode module declaration.
+	pub struct Module<T: Trait> for enum Call where origin: T::Origin {
+		type Error = Error<T>;
+
+		/// The number of blocks after which the safe mode ends.
+		const SafeModeDuration: T::BlockNumber = T::SafeModeDuration::get();
+
+		fn deposit_event() = default;
+
+		fn on_initialize(n: T::BlockNumber) -> Weight {
+			match SafeModeUntil::<T>::get() {
+				Some(until) if until <= n => {
+					SafeModeUntil::<T>::kill();
+					Self::deposit_event(RawEvent::SafeModeExited);
+					T::DbWeight::get().reads_writes(1, 2)
+				},
+				_ => T::DbWeight::get().reads(1),
+			}
+		}
+
+		/// Pause the call `call_name` of the pallet `pallet_name`.
+		///
+		/// The names are the ones returned by `GetCallMetadata`, i.e. the name of the pallet in
+		/// the runtime and the name of the dispatchable function. Pausing a call of this module or
+		/// a whitelisted call has no effect.
+		///
+		/// The dispatch origin for this call must match `T::PauseOrigin`.
+		///
+		/// # <weight>
+		/// - O(P + C) where P is the number of pallets and C the number of calls of the pallet.
+		/// - One storage read/write.
+		/// - One event.
+		/// # </weight>
+		#[weight = T::WeightInfo::pause()]
+		fn pause(origin, pallet_name: Vec<u8>, call_name: Vec<u8>) {
+			T::PauseOrigin::ensure_origin(origin)?;
+
+			ensure!(Self::is_known_call(&pallet_name, &call_name), Error::<T>::UnknownCall);
+			let key = (pallet_name, call_name);
+			ensure!(!PausedCalls::contains_key(&key), Error::<T>::AlreadyPaused);
+
+			PausedCalls::insert(&key, ());
+			let (pallet_name, call_name) = key;
+			Self::deposit_event(RawEvent::CallPaused(pallet_name, call_name));
+		}
+
+		/// Unpause the call `call_name` of the pallet `pallet_name`.
+		///
+		/// The dispatch origin for this call must match `T::UnpauseOrigin`.
+		///
+		/// # <weight>
+		/// - O(1).
+		/// - One storage read/write.
+		/// - One event.
+		/// # </weight>
+		#[weight = T::WeightInfo::unpause()]
+		fn unpause(origin, pallet_name: Vec<u8>, call_name: Vec<u8>) {
+			T::UnpauseOrigin::ensure_origin(origin)?;
+
+			let key = (pallet_name, call_name);
+			ensure!(PausedCalls::contains_key(&key), Error::<T>::NotPaused);
+
+			PausedCalls::remove(&key);
+			let (pallet_name, call_name) = key;
+			Self::deposit_event(RawEvent::CallUnpaused(pallet_name, call_name));
+		}
+
+		/// Enter the safe mode for `SafeModeDuration` blocks.
+		///
+		/// While the safe mode is active, only the calls of this module and the calls accepted by
+		/// `T::WhitelistedCalls` are dispatched.
+		///
+		/// The dispatch origin for this call must match `T::PauseOrigin`.
+		///
+		/// # <weight>
+		/// - O(1).
+		/// - One storage read/write.
+		/// - One event.
+		/// # </weight>
+		#[weight = T::WeightInfo::enter_safe_mode()]
+		fn enter_safe_mode(origin) {
+			T::PauseOrigin::ensure_origin(origin)?;
+			ensure!(!Self::is_safe_mode_active(), Error::<T>::SafeModeActive);
+
+			let until = System::<T>::block_number().saturating_add(T::SafeModeDuration::get());
+			SafeModeUntil::<T>::put(until);
+			Self::deposit_event(RawEvent::SafeModeEntered(until));
+		}
+
+		/// Exit the safe mode before it expires.
+		///
+		/// The dispatch origin for this call must match `T::UnpauseOrigin`.
+		///
+		/// # <weight>
+		/// - O(1).
+		/// - One storage read/write.
+		/// - One event.
+		/// # </weight>
+		#[weight = T::WeightInfo::exit_safe_mode()]
+		fn exit_safe_mode(origin) {
+			T::UnpauseOrigin::ensure_origin(origin)?;
+			ensure!(Self::is_safe_mode_active(), Error::<T>::SafeModeInactive);
+
+			SafeModeUntil::<T>::kill();
+			Self::deposit_event(RawEvent::SafeModeExited);
+		}
+
+		/// Pause all the calls of the pallet `pallet_name`.
+		///
+		/// The name is the one returned by `GetCallMetadata`, i.e. the name of the pallet in the
+		/// runtime. Pausing this module has no effect, and whitelisted calls of a paused pallet
+		/// are still dispatched.
+		///
+		/// The dispatch origin for this call must match `T::PauseOrigin`.
+		///
+		/// # <weight>
+		/// - O(P) where P is the number of pallets.
+		/// - One storage read/write.
+		/// - One event.
+		/// # </weight>
+		#[weight = T::WeightInfo::pause_pallet()]
+		fn pause_pallet(origin, pallet_name: Vec<u8>) {
+			T::PauseOrigin::ensure_origin(origin)?;
+
+			ensure!(Self::is_known_pallet(&pallet_name), Error::<T>::UnknownPallet);
+			ensure!(!PausedPallets::contains_key(&pallet_name), Error::<T>::PalletAlreadyPaused);
+
+			PausedPallets::insert(&pallet_name, ());
+			Self::deposit_event(RawEvent::PalletPaused(pallet_name));
+		}
+
+		/// Unpause the pallet `pallet_name`.
+		///
+		/// The calls of the pallet which were paused individually remain paused.
+		///
+		/// The dispatch origin for this call must match `T::UnpauseOrigin`.
+		///
+		/// # <weight>
+		/// - O(1).
+		/// - One storage read/write.
+		/// - One event.
+		/// # </weight>
+		#[weight = T::WeightInfo::unpause_pallet()]
+		fn unpause_pallet(origin, pallet_name: Vec<u8>) {
+			T::UnpauseOrigin::ensure_origin(origin)?;
+
+			ensure!(PausedPallets::contains_key(&pallet_name), Error::<T>::PalletNotPaused);
+
+			PausedPallets::remove(&pallet_name);
+			Self::deposit_event(RawEvent::PalletUnpaused(pallet_name));
+		}
+	}
+}
+
+impl<T: Trait> Module<T> {
+	/// Whether the safe mode is currently active.
+	pub fn is_safe_mode_active() -> bool {
+		SafeModeUntil::<T>::get().map_or(false, |until| System::<T>::block_number() < until)
+	}
+
+	/// Whether the given call is paused.
+	pub fn is_paused(pallet_name: &[u8], call_name: &[u8]) -> bool {
+		PausedCalls::contains_key((pallet_name.to_vec(), call_name.to_vec()))
+	}
+
+	/// Whether all the calls of the given pallet are paused.
+	pub fn is_pallet_paused(pallet_name: &[u8]) -> bool {
+		PausedPallets::contains_key(pallet_name)
+	}
+
+	/// Whether the runtime has a pallet `pallet_name` with calls.
+	fn is_known_pallet(pallet_name: &[u8]) -> bool {
+		<T as Trait>::Call::get_module_names()
+			.iter()
+			.any(|name| name.as_bytes() == pallet_name)
+	}
+
+	/// Whether the runtime has a call `call_name` in the pallet `pallet_name`.
+	fn is_known_call(pallet_name: &[u8], call_name: &[u8]) -> bool {
+		<T as Trait>::Call::get_module_names()
+			.iter()
+			.find(|name| name.as_bytes() == pallet_name)
+			.map_or(false, |name| {
+				<T as Trait>::Call::get_call_names(name)
+					.iter()
+					.any(|name| name.as_bytes() == call_name)
+			})
+	}
+}
+
+impl<T: Trait> Filter<<T as Trait>::Call> for Module<T> {
+	fn filter(call: &<T as Trait>::Call) -> bool {
+		// The calls of this module must always be available to lift the restrictions.
+		let is_own_call = <<T as Trait>::Call as IsSubType<Call<T>>>::is_sub_type(call).is_some();
+		if is_own_call || T::WhitelistedCalls::filter(call) {
+			return true;
+		}
+
+		if Self::is_safe_mode_active() {
+			return false;
+		}
+
+		let metadata = call.get_call_metadata();
+		let pallet_name = metadata.pallet_name.as_bytes();
+		!Self::is_pallet_paused(pallet_name) &&
+			!Self::is_paused(pallet_name, metadata.function_name.as_bytes())
+	}
+}
@@ -0,0 +1,329 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2020 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// Tests for Safe Mode Pallet
+
+#![cfg(test)]
+
+use super::*;
+
+use frame_support::{
+	assert_ok, assert_noop, impl_outer_origin, impl_outer_event, impl_outer_dispatch,
+	parameter_types, ord_parameter_types, traits::OnInitialize,
+	dispatch::{DispatchError, Dispatchable},
+};
+use frame_system::EnsureSignedBy;
+use sp_core::H256;
+use sp_runtime::{Perbill, traits::{BlakeTwo256, IdentityLookup, BadOrigin}, testing::Header};
+use crate as safe_mode;
+
+impl_outer_origin! {
+	pub enum Origin for Test where system = frame_system {}
+}
+impl_outer_event! {
+	pub enum TestEvent for Test {
+		frame_system<T>,
+		safe_mode<T>,
+	}
+}
+impl_outer_dispatch! {
+	pub enum Call for Test where origin: Origin {
+		frame_system::System,
+		safe_mode::SafeMode,
+	}
+}
+
+#[derive(Clone, Eq, PartialEq)]
+pub struct Test;
+parameter_types! {
+	pub const BlockHashCount: u64 = 250;
+	pub const MaximumBlockWeight: Weight = 1024;
+	pub const MaximumBlockLength: u32 = 2 * 1024;
+	pub const AvailableBlockRatio: Perbill = Perbill::one();
+}
+impl frame_system::Trait for Test {
+	type BaseCallFilter = SafeMode;
+	type Origin = Origin;
+	type Index = u64;
+	type BlockNumber = u64;
+	type Hash = H256;
+	type Call = Call;
+	type Hashing = BlakeTwo256;
+	type AccountId = u64;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type Header = Header;
+	type Event = TestEvent;
+	type BlockHashCount = BlockHashCount;
+	type MaximumBlockWeight = MaximumBlockWeight;
+	type DbWeight = ();
+	type BlockExecutionWeight = ();
+	type ExtrinsicBaseWeight = ();
+	type MaximumExtrinsicWeight = MaximumBlockWeight;
+	type MaximumBlockLength = MaximumBlockLength;
+	type AvailableBlockRatio = AvailableBlockRatio;
+	type Version = ();
+	type PalletInfo = ();
+	type AccountData = ();
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+}
+parameter_types! {
+	pub const SafeModeDuration: u64 = 10;
+}
+ord_parameter_types! {
+	pub const One: u64 = 1;
+	pub const Two: u64 = 2;
+}
+pub struct WhitelistedCalls;
+impl Filter<Call> for WhitelistedCalls {
+	fn filter(call: &Call) -> bool {
+		match call {
+			Call::System(SystemCall::set_heap_pages(..)) => true,
+			_ => false,
+		}
+	}
+}
+impl Trait for Test {
+	type Event = TestEvent;
+	type Call = Call;
+	type PauseOrigin = EnsureSignedBy<One, u64>;
+	type UnpauseOrigin = EnsureSignedBy<Two, u64>;
+	type WhitelistedCalls = WhitelistedCalls;
+	type SafeModeDuration = SafeModeDuration;
+	type WeightInfo = ();
+}
+type System = frame_system::Module<Test>;
+type SafeMode = Module<Test>;
+
+use frame_system::Call as SystemCall;
+
+pub fn new_test_ext() -> sp_io::TestExternalities {
+	let t = frame_system::GenesisConfig::default().build_storage::<Test>().unwrap();
+	let mut ext = sp_io::TestExternalities::new(t);
+	ext.execute_with(|| System::set_block_number(1));
+	ext
+}
+
+fn last_event() -> TestEvent {
+	System::events().pop().map(|e| e.event).expect("Event expected")
+}
+
+fn remark() -> Call {
+	Call::System(SystemCall::remark(vec![]))
+}
+
+fn run_to_block(n: u64) {
+	while System::block_number() < n {
+		System::set_block_number(System::block_number() + 1);
+		SafeMode::on_initialize(System::block_number());
+	}
+}
+
+#[test]
+fn pause_and_unpause_work() {
+	new_test_ext().execute_with(|| {
+		assert!(SafeMode::filter(&remark()));
+
+		assert_ok!(SafeMode::pause(Origin::signed(1), b"System".to_vec(), b"remark".to_vec()));
+		assert_eq!(
+			last_event(),
+			TestEvent::safe_mode(RawEvent::CallPaused(b"System".to_vec(), b"remark".to_vec())),
+		);
+		assert!(SafeMode::is_paused(b"System", b"remark"));
+		assert!(!SafeMode::filter(&remark()));
+		assert_eq!(
+			remark().dispatch(Origin::signed(3)).map_err(|e| e.error),
+			Err(DispatchError::BadOrigin),
+		);
+		// Other calls of the pallet are not affected.
+		assert!(SafeMode::filter(&Call::System(SystemCall::kill_storage(vec![]))));
+
+		assert_ok!(SafeMode::unpause(Origin::signed(2), b"System".to_vec(), b"remark".to_vec()));
+		assert_eq!(
+			last_event(),
+			TestEvent::safe_mode(RawEvent::CallUnpaused(b"System".to_vec(), b"remark".to_vec())),
+		);
+		assert!(SafeMode::filter(&remark()));
+		assert_ok!(remark().dispatch(Origin::signed(3)));
+	});
+}
+
+#[test]
+fn pause_and_unpause_check_origins() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			SafeMode::pause(Origin::signed(2), b"System".to_vec(), b"remark".to_vec()),
+			BadOrigin,
+		);
+		assert_ok!(SafeMode::pause(Origin::signed(1), b"System".to_vec(), b"remark".to_vec()));
+		assert_noop!(
+			SafeMode::unpause(Origin::signed(1), b"System".to_vec(), b"remark".to_vec()),
+			BadOrigin,
+		);
+	});
+}
+
+#[test]
+fn pause_fails_for_unknown_or_paused_calls() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			SafeMode::pause(Origin::signed(1), b"Unknown".to_vec(), b"remark".to_vec()),
+			Error::<Test>::UnknownCall,
+		);
+		assert_noop!(
+			SafeMode::pause(Origin::signed(1), b"System".to_vec(), b"unknown".to_vec()),
+			Error::<Test>::UnknownCall,
+		);
+		assert_ok!(SafeMode::pause(Origin::signed(1), b"System".to_vec(), b"remark".to_vec()));
+		assert_noop!(
+			SafeMode::pause(Origin::signed(1), b"System".to_vec(), b"remark".to_vec()),
+			Error::<Test>::AlreadyPaused,
+		);
+		assert_noop!(
+			SafeMode::unpause(Origin::signed(2), b"System".to_vec(), b"set_heap_pages".to_vec()),
+			Error::<Test>::NotPaused,
+		);
+	});
+}
+
+#[test]
+fn pausing_own_or_whitelisted_calls_has_no_effect() {
+	new_test_ext().execute_with(|| {
+		let set_heap_pages = Call::System(SystemCall::set_heap_pages(1));
+		let exit_safe_mode = Call::SafeMode(safe_mode::Call::exit_safe_mode());
+
+		assert_ok!(SafeMode::pause(
+			Origin::signed(1),
+			b"System".to_vec(),
+			b"set_heap_pages".to_vec(),
+		));
+		assert_ok!(SafeMode::pause(
+			Origin::signed(1),
+			b"SafeMode".to_vec(),
+			b"exit_safe_mode".to_vec(),
+		));
+
+		assert!(SafeMode::filter(&set_heap_pages));
+		assert!(SafeMode::filter(&exit_safe_mode));
+	});
+}
+
+#[test]
+fn pause_and_unpause_pallet_work() {
+	new_test_ext().execute_with(|| {
+		let kill_storage = Call::System(SystemCall::kill_storage(vec![]));
+
+		assert_noop!(SafeMode::pause_pallet(Origin::signed(2), b"System".to_vec()), BadOrigin);
+		assert_ok!(SafeMode::pause_pallet(Origin::signed(1), b"System".to_vec()));
+		assert_eq!(last_event(), TestEvent::safe_mode(RawEvent::PalletPaused(b"System".to_vec())));
+		assert!(SafeMode::is_pallet_paused(b"System"));
+
+		assert!(!SafeMode::filter(&remark()));
+		assert!(!SafeMode::filter(&kill_storage));
+		// Whitelisted calls and the calls of this module are still dispatched.
+		assert!(SafeMode::filter(&Call::System(SystemCall::set_heap_pages(1))));
+		assert_ok!(SafeMode::pause_pallet(Origin::signed(1), b"SafeMode".to_vec()));
+		assert!(SafeMode::filter(&Call::SafeMode(safe_mode::Call::exit_safe_mode())));
+
+		// Calls paused individually remain paused after the pallet is unpaused.
+		assert_ok!(SafeMode::pause(Origin::signed(1), b"System".to_vec(), b"remark".to_vec()));
+		assert_noop!(SafeMode::unpause_pallet(Origin::signed(1), b"System".to_vec()), BadOrigin);
+		assert_ok!(SafeMode::unpause_pallet(Origin::signed(2), b"System".to_vec()));
+		assert_eq!(
+			last_event(),
+			TestEvent::safe_mode(RawEvent::PalletUnpaused(b"System".to_vec())),
+		);
+		assert!(!SafeMode::is_pallet_paused(b"System"));
+		assert!(SafeMode::filter(&kill_storage));
+		assert!(!SafeMode::filter(&remark()));
+	});
+}
+
+#[test]
+fn pause_pallet_fails_for_unknown_or_paused_pallets() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			SafeMode::pause_pallet(Origin::signed(1), b"Unknown".to_vec()),
+			Error::<Test>::UnknownPallet,
+		);
+		assert_ok!(SafeMode::pause_pallet(Origin::signed(1), b"System".to_vec()));
+		assert_noop!(
+			SafeMode::pause_pallet(Origin::signed(1), b"System".to_vec()),
+			Error::<Test>::PalletAlreadyPaused,
+		);
+		assert_noop!(
+			SafeMode::unpause_pallet(Origin::signed(2), b"SafeMode".to_vec()),
+			Error::<Test>::PalletNotPaused,
+		);
+	});
+}
+
+#[test]
+fn safe_mode_filters_all_but_whitelisted_calls() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(SafeMode::enter_safe_mode(Origin::signed(2)), BadOrigin);
+		assert_ok!(SafeMode::enter_safe_mode(Origin::signed(1)));
+		assert_eq!(last_event(), TestEvent::safe_mode(RawEvent::SafeModeEntered(11)));
+		assert!(SafeMode::is_safe_mode_active());
+		assert_noop!(SafeMode::enter_safe_mode(Origin::signed(1)), Error::<Test>::SafeModeActive);
+
+		assert!(!SafeMode::filter(&remark()));
+		assert!(SafeMode::filter(&Call::System(SystemCall::set_heap_pages(1))));
+		assert!(SafeMode::filter(&Call::SafeMode(safe_mode::Call::exit_safe_mode())));
+
+		// Root is not subject to the base call filter.
+		let kill_storage = Call::System(SystemCall::kill_storage(vec![]));
+		assert!(!SafeMode::filter(&kill_storage));
+		assert_ok!(kill_storage.dispatch(Origin::root()));
+
+		assert_noop!(SafeMode::exit_safe_mode(Origin::signed(1)), BadOrigin);
+		assert_ok!(SafeMode::exit_safe_mode(Origin::signed(2)));
+		assert_eq!(last_event(), TestEvent::safe_mode(RawEvent::SafeModeExited));
+		assert!(!SafeMode::is_safe_mode_active());
+		assert!(SafeMode::filter(&remark()));
+		assert_noop!(SafeMode::exit_safe_mode(Origin::signed(2)), Error::<Test>::SafeModeInactive);
+	});
+}
+
+#[test]
+fn safe_mode_expires() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(SafeMode::enter_safe_mode(Origin::signed(1)));
+		assert_eq!(SafeMode::safe_mode_until(), Some(11));
+
+		run_to_block(10);
+		assert!(SafeMode::is_safe_mode_active());
+		assert!(!SafeMode::filter(&remark()));
+
+		run_to_block(11);
+		assert!(!SafeMode::is_safe_mode_active());
+		assert_eq!(SafeMode::safe_mode_until(), None);
+		assert_eq!(last_event(), TestEvent::safe_mode(RawEvent::SafeModeExited));
+		assert!(SafeMode::filter(&remark()));
+	});
+}
+
+#[test]
+fn safe_mode_end_saturates() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(u64::max_value() - 1);
+		assert_ok!(SafeMode::enter_safe_mode(Origin::signed(1)));
+		assert_eq!(SafeMode::safe_mode_until(), Some(u64::max_value()));
+		assert!(SafeMode::is_safe_mode_active());
+	});
+}
@@ -0,0 +1,62 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2020 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Runtime API definition for the scheduler module.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use sp_std::prelude::*;
+use codec::{Encode, Codec, Decode};
+#[cfg(feature = "std")]
+use serde::{Serialize, Deserialize};
+use sp_runtime::RuntimeDebug;
+
+/// A task pending in the scheduler's agenda.
+#[derive(Eq, PartialEq, Encode, Decode, Clone, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct AgendaItem<BlockNumber, PalletsOrigin> {
+	/// The block at which the task is due.
+	pub when: BlockNumber,
+	/// The index of the task in the agenda of that block.
+	pub index: u32,
+	/// The name of the task, if it has one.
+	pub id: Option<Vec<u8>>,
+	/// The priority of the task.
+	pub priority: u8,
+	/// The origin the task is dispatched with.
+	pub origin: PalletsOrigin,
+	/// The period and the number of remaining repetitions, if the task is periodic.
+	pub maybe_periodic: Option<(BlockNumber, u32)>,
+	/// The number of retries left and the period between them, if the task is retried on failure.
+	pub retry: Option<(u8, BlockNumber)>,
+	/// The SCALE encoded call of the task, as a `MaybeHashed` of the call and its hash, since the
+	/// call may be scheduled by hash.
+	pub call: Vec<u8>,
+}
+
+sp_api::decl_runtime_apis! {
+	pub trait SchedulerApi<BlockNumber, PalletsOrigin> where
+		BlockNumber: Codec,
+		PalletsOrigin: Codec,
+	{
+		/// The pending task scheduled under the name `id`, if any.
+		fn agenda_by_name(id: Vec<u8>) -> Option<AgendaItem<BlockNumber, PalletsOrigin>>;
+		/// All pending tasks to be dispatched with `origin`, in the order they are due.
+		fn agenda_by_origin(origin: PalletsOrigin) -> Vec<AgendaItem<BlockNumber, PalletsOrigin>>;
+	}
+}
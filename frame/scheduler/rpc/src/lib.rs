@@ -0,0 +1,149 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2020 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! RPC interface for the scheduler module.
+//!
+//! Origins are passed and returned SCALE encoded, as the origin type depends on the pallets
+//! of the runtime.
+
+use std::sync::Arc;
+use codec::{Codec, Decode, Encode};
+use sp_blockchain::HeaderBackend;
+use jsonrpc_core::{Error as RpcError, ErrorCode, Result};
+use jsonrpc_derive::rpc;
+use sp_runtime::{generic::BlockId, traits::{Block as BlockT, NumberFor}};
+use sp_api::ProvideRuntimeApi;
+use sp_core::Bytes;
+pub use pallet_scheduler_rpc_runtime_api::{AgendaItem, SchedulerApi as SchedulerRuntimeApi};
+pub use self::gen_client::Client as SchedulerClient;
+
+#[rpc]
+pub trait SchedulerApi<BlockHash, BlockNumber> {
+	/// The pending task scheduled under the name `id`, if any.
+	#[rpc(name = "scheduler_agendaByName")]
+	fn agenda_by_name(
+		&self,
+		id: Bytes,
+		at: Option<BlockHash>
+	) -> Result<Option<AgendaItem<BlockNumber, Bytes>>>;
+
+	/// All pending tasks to be dispatched with the given encoded origin, in the order they are
+	/// due.
+	#[rpc(name = "scheduler_agendaByOrigin")]
+	fn agenda_by_origin(
+		&self,
+		encoded_origin: Bytes,
+		at: Option<BlockHash>
+	) -> Result<Vec<AgendaItem<BlockNumber, Bytes>>>;
+}
+
+/// A struct that implements the [`SchedulerApi`].
+pub struct Scheduler<C, B, O> {
+	client: Arc<C>,
+	_marker: std::marker::PhantomData<(B, O)>,
+}
+
+impl<C, B, O> Scheduler<C, B, O> {
+	/// Create new `Scheduler` with the given reference to the client.
+	pub fn new(client: Arc<C>) -> Self {
+		Scheduler { client, _marker: Default::default() }
+	}
+}
+
+/// Error type of this RPC api.
+pub enum Error {
+	/// The origin was not decodable.
+	DecodeError,
+	/// The call to runtime failed.
+	RuntimeError,
+}
+
+impl From<Error> for i64 {
+	fn from(e: Error) -> i64 {
+		match e {
+			Error::RuntimeError => 1,
+			Error::DecodeError => 2,
+		}
+	}
+}
+
+fn runtime_error(message: &str, e: impl std::fmt::Debug) -> RpcError {
+	RpcError {
+		code: ErrorCode::ServerError(Error::RuntimeError.into()),
+		message: message.into(),
+		data: Some(format!("{:?}", e).into()),
+	}
+}
+
+fn encode_origin<BlockNumber, O: Encode>(item: AgendaItem<BlockNumber, O>) -> AgendaItem<BlockNumber, Bytes> {
+	AgendaItem {
+		when: item.when,
+		index: item.index,
+		id: item.id,
+		priority: item.priority,
+		origin: item.origin.encode().into(),
+		maybe_periodic: item.maybe_periodic,
+		retry: item.retry,
+		call: item.call,
+	}
+}
+
+impl<C, Block, PalletsOrigin> SchedulerApi<<Block as BlockT>::Hash, NumberFor<Block>>
+	for Scheduler<C, Block, PalletsOrigin>
+where
+	Block: BlockT,
+	C: Send + Sync + 'static + ProvideRuntimeApi<Block> + HeaderBackend<Block>,
+	C::Api: SchedulerRuntimeApi<Block, NumberFor<Block>, PalletsOrigin>,
+	PalletsOrigin: Codec + Send + Sync + 'static,
+{
+	fn agenda_by_name(
+		&self,
+		id: Bytes,
+		at: Option<<Block as BlockT>::Hash>
+	) -> Result<Option<AgendaItem<NumberFor<Block>, Bytes>>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(||
+			// If the block hash is not supplied assume the best block.
+			self.client.info().best_hash
+		));
+
+		api.agenda_by_name(&at, id.to_vec())
+			.map(|maybe_item| maybe_item.map(encode_origin))
+			.map_err(|e| runtime_error("Unable to query the agenda.", e))
+	}
+
+	fn agenda_by_origin(
+		&self,
+		encoded_origin: Bytes,
+		at: Option<<Block as BlockT>::Hash>
+	) -> Result<Vec<AgendaItem<NumberFor<Block>, Bytes>>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(||
+			// If the block hash is not supplied assume the best block.
+			self.client.info().best_hash
+		));
+
+		let origin = PalletsOrigin::decode(&mut &*encoded_origin).map_err(|e| RpcError {
+			code: ErrorCode::ServerError(Error::DecodeError.into()),
+			message: "Unable to query the agenda: origin has unknown encoding.".into(),
+			data: Some(format!("{:?}", e).into()),
+		})?;
+		api.agenda_by_origin(&at, origin)
+			.map(|items| items.into_iter().map(encode_origin).collect())
+			.map_err(|e| runtime_error("Unable to query the agenda.", e))
+	}
+}
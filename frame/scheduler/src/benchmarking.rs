@@ -44,7 +44,7 @@ fn fill_schedule<T: Trait> (when: T::BlockNumber, n: u32) -> Result<(), &'static
 			// HARD_DEADLINE priority means it gets executed no matter what
 			0,
 			frame_system::RawOrigin::Root.into(),
-			call.clone().into(),
+			CallOrHashOf::<T>::Value(call.clone().into()),
 		)?;
 	}
 	ensure!(Agenda::<T>::get(when).len() == n as usize, "didn't fill schedule");
@@ -60,7 +60,7 @@ benchmarks! {
 		let periodic = Some((T::BlockNumber::one(), 100));
 		let priority = 0;
 		// Essentially a no-op call.
-		let call = Box::new(frame_system::Call::set_storage(vec![]).into());
+		let call = Box::new(CallOrHashOf::<T>::Value(frame_system::Call::set_storage(vec![]).into()));
 
 		fill_schedule::<T>(when, s)?;
 	}: _(RawOrigin::Root, when, periodic, priority, call)
@@ -97,7 +97,7 @@ benchmarks! {
 		let periodic = Some((T::BlockNumber::one(), 100));
 		let priority = 0;
 		// Essentially a no-op call.
-		let call = Box::new(frame_system::Call::set_storage(vec![]).into());
+		let call = Box::new(CallOrHashOf::<T>::Value(frame_system::Call::set_storage(vec![]).into()));
 
 		fill_schedule::<T>(when, s)?;
 	}: _(RawOrigin::Root, id, when, periodic, priority, call)
@@ -126,6 +126,79 @@ benchmarks! {
 		);
 	}
 
+	reschedule_named {
+		let s in 1 .. T::MaxScheduledPerBlock::get();
+		let when: T::BlockNumber = BLOCK_NUMBER.into();
+		let new_when = when + T::BlockNumber::one();
+
+		fill_schedule::<T>(when, s)?;
+		Scheduler::<T>::do_set_retry(None, (when, 0), 10, T::BlockNumber::one())?;
+	}: _(RawOrigin::Root, 0.encode(), new_when)
+	verify {
+		ensure!(
+			Lookup::<T>::get(0.encode()) == Some((new_when, 0)),
+			"didn't reschedule"
+		);
+		ensure!(
+			Retries::<T>::contains_key((new_when, 0)),
+			"didn't move retry configuration"
+		);
+	}
+
+	set_retry {
+		let s in 1 .. T::MaxScheduledPerBlock::get();
+		let when = BLOCK_NUMBER.into();
+
+		fill_schedule::<T>(when, s)?;
+	}: _(RawOrigin::Root, (when, s - 1), 10, T::BlockNumber::one())
+	verify {
+		ensure!(
+			Retries::<T>::contains_key((when, s - 1)),
+			"didn't set retry configuration"
+		);
+	}
+
+	set_retry_named {
+		let s in 1 .. T::MaxScheduledPerBlock::get();
+		let when = BLOCK_NUMBER.into();
+
+		fill_schedule::<T>(when, s)?;
+	}: _(RawOrigin::Root, (s - 1).encode(), 10, T::BlockNumber::one())
+	verify {
+		ensure!(
+			Retries::<T>::contains_key((when, s - 1)),
+			"didn't set retry configuration"
+		);
+	}
+
+	cancel_retry {
+		let s in 1 .. T::MaxScheduledPerBlock::get();
+		let when = BLOCK_NUMBER.into();
+
+		fill_schedule::<T>(when, s)?;
+		Scheduler::<T>::do_set_retry(None, (when, s - 1), 10, T::BlockNumber::one())?;
+	}: _(RawOrigin::Root, (when, s - 1))
+	verify {
+		ensure!(
+			!Retries::<T>::contains_key((when, s - 1)),
+			"didn't remove retry configuration"
+		);
+	}
+
+	cancel_retry_named {
+		let s in 1 .. T::MaxScheduledPerBlock::get();
+		let when = BLOCK_NUMBER.into();
+
+		fill_schedule::<T>(when, s)?;
+		Scheduler::<T>::do_set_retry(None, (when, s - 1), 10, T::BlockNumber::one())?;
+	}: _(RawOrigin::Root, (s - 1).encode())
+	verify {
+		ensure!(
+			!Retries::<T>::contains_key((when, s - 1)),
+			"didn't remove retry configuration"
+		);
+	}
+
 	// TODO [#7141]: Make this more complex and flexible so it can be used in automation.
 	#[extra]
 	on_initialize {
@@ -156,6 +229,11 @@ mod tests {
 			assert_ok!(test_benchmark_cancel::<Test>());
 			assert_ok!(test_benchmark_schedule_named::<Test>());
 			assert_ok!(test_benchmark_cancel_named::<Test>());
+			assert_ok!(test_benchmark_reschedule_named::<Test>());
+			assert_ok!(test_benchmark_set_retry::<Test>());
+			assert_ok!(test_benchmark_set_retry_named::<Test>());
+			assert_ok!(test_benchmark_cancel_retry::<Test>());
+			assert_ok!(test_benchmark_cancel_retry_named::<Test>());
 			assert_ok!(test_benchmark_on_initialize::<Test>());
 		});
 	}
@@ -28,6 +28,18 @@
 //! specified block number or at a specified period. These scheduled dispatches
 //! may be named or anonymous and may be canceled.
 //!
+//! A scheduled task may be given a retry configuration, in which case a failed
+//! dispatch is attempted again after a back-off period, up to a maximum number
+//! of retries. The failure is recorded in `LastFailure` until the retry is dispatched.
+//!
+//! The call of a task may be given by the hash of its encoding instead of in full, in which case
+//! its preimage is requested from `T::PreimageProvider` until the task is due. A due task whose
+//! preimage is not available is postponed by `T::NoPreimagePostponement` blocks, or dropped if
+//! that is `None`.
+//!
+//! The pending agenda can be inspected by name or by origin through the
+//! `SchedulerApi` runtime API, see `pallet-scheduler-rpc-runtime-api`.
+//!
 //! **NOTE:** The scheduled calls will be dispatched with the default filter
 //! for the origin: namely `frame_system::Trait::BaseCallFilter` for all origin
 //! except root which will get no filter. And not the filter contained in origin
@@ -47,6 +59,12 @@
 //! * `schedule_named` - augments the `schedule` interface with an additional
 //!   `Vec<u8>` parameter that can be used for identification.
 //! * `cancel_named` - the named complement to the cancel function.
+//! * `reschedule_named` - move a named task to a different block.
+//! * `set_retry` - set the retry configuration of a scheduled task, specified by
+//!   block number and index.
+//! * `set_retry_named` - the named complement to the `set_retry` function.
+//! * `cancel_retry` - remove the retry configuration of a scheduled task.
+//! * `cancel_retry_named` - the named complement to the `cancel_retry` function.
 
 // Ensure we're `no_std` when compiling for Wasm.
 #![cfg_attr(not(feature = "std"), no_std)]
@@ -58,18 +76,29 @@ use sp_std::{prelude::*, marker::PhantomData, borrow::Borrow};
 use codec::{Encode, Decode, Codec};
 use sp_runtime::{RuntimeDebug, traits::{Zero, One, BadOrigin, Saturating}};
 use frame_support::{
-	decl_module, decl_storage, decl_event, decl_error, IterableStorageMap,
+	decl_module, decl_storage, decl_event, decl_error, ensure, IterableStorageMap,
 	dispatch::{Dispatchable, DispatchError, DispatchResult, Parameter},
-	traits::{Get, schedule::{self, DispatchTime}, OriginTrait, EnsureOrigin, IsType},
+	traits::{
+		Get, schedule::{self, DispatchTime, MaybeHashed, LookupError}, OriginTrait, EnsureOrigin, IsType,
+		PreimageProvider,
+	},
 	weights::{GetDispatchInfo, Weight},
+	type_info::TypeInfo,
 };
 use frame_system::{self as system, ensure_signed};
 
+pub use pallet_scheduler_rpc_runtime_api::AgendaItem;
+
 pub trait WeightInfo {
 	fn schedule(s: u32, ) -> Weight;
 	fn cancel(s: u32, ) -> Weight;
 	fn schedule_named(s: u32, ) -> Weight;
 	fn cancel_named(s: u32, ) -> Weight;
+	fn reschedule_named(s: u32, ) -> Weight;
+	fn set_retry(s: u32, ) -> Weight;
+	fn set_retry_named(s: u32, ) -> Weight;
+	fn cancel_retry(s: u32, ) -> Weight;
+	fn cancel_retry_named(s: u32, ) -> Weight;
 }
 
 /// Our pallet's configuration trait. All our types and constants go in here. If the
@@ -102,6 +131,13 @@ pub trait Trait: system::Trait {
 	/// Not strictly enforced, but used for weight estimation.
 	type MaxScheduledPerBlock: Get<u32>;
 
+	/// The provider of the preimages of calls which are scheduled by their hash.
+	type PreimageProvider: PreimageProvider<Self::Hash>;
+
+	/// The number of blocks by which a task is postponed if the preimage of its call is not
+	/// available when it is due. If `None`, the task is dropped instead.
+	type NoPreimagePostponement: Get<Option<Self::BlockNumber>>;
+
 	/// Weight information for extrinsics in this pallet.
 	type WeightInfo: WeightInfo;
 }
@@ -111,6 +147,9 @@ pub type PeriodicIndex = u32;
 /// The location of a scheduled task that can be used to remove it.
 pub type TaskAddress<BlockNumber> = (BlockNumber, u32);
 
+/// The call of a scheduled task, given in full or by its hash.
+pub type CallOrHashOf<T> = MaybeHashed<<T as Trait>::Call, <T as system::Trait>::Hash>;
+
 #[cfg_attr(any(feature = "std", test), derive(PartialEq, Eq))]
 #[derive(Clone, RuntimeDebug, Encode, Decode)]
 struct ScheduledV1<Call, BlockNumber> {
@@ -120,15 +159,27 @@ struct ScheduledV1<Call, BlockNumber> {
 	maybe_periodic: Option<schedule::Period<BlockNumber>>,
 }
 
-/// Information regarding an item to be executed in the future.
 #[cfg_attr(any(feature = "std", test), derive(PartialEq, Eq))]
 #[derive(Clone, RuntimeDebug, Encode, Decode)]
-pub struct ScheduledV2<Call, BlockNumber, PalletsOrigin, AccountId> {
+struct ScheduledV2<Call, BlockNumber, PalletsOrigin, AccountId> {
+	maybe_id: Option<Vec<u8>>,
+	priority: schedule::Priority,
+	call: Call,
+	maybe_periodic: Option<schedule::Period<BlockNumber>>,
+	origin: PalletsOrigin,
+	_phantom: PhantomData<AccountId>,
+}
+
+/// Information regarding an item to be executed in the future.
+#[cfg_attr(any(feature = "std", test), derive(PartialEq, Eq))]
+#[derive(Clone, RuntimeDebug, Encode, Decode, TypeInfo)]
+#[scale_info(skip_type_params(AccountId))]
+pub struct ScheduledV3<Call, BlockNumber, PalletsOrigin, AccountId> {
 	/// The unique identity for this task, if there is one.
 	maybe_id: Option<Vec<u8>>,
 	/// This task's priority.
 	priority: schedule::Priority,
-	/// The call to be dispatched.
+	/// The call to be dispatched, in full or by its hash.
 	call: Call,
 	/// If the call is periodic, then this points to the information concerning that.
 	maybe_periodic: Option<schedule::Period<BlockNumber>>,
@@ -138,15 +189,46 @@ pub struct ScheduledV2<Call, BlockNumber, PalletsOrigin, AccountId> {
 }
 
 /// The current version of Scheduled struct.
-pub type Scheduled<Call, BlockNumber, PalletsOrigin, AccountId> = ScheduledV2<Call, BlockNumber, PalletsOrigin, AccountId>;
+pub type Scheduled<Call, BlockNumber, PalletsOrigin, AccountId> = ScheduledV3<Call, BlockNumber, PalletsOrigin, AccountId>;
+
+/// A scheduled task of the runtime `T`.
+pub type ScheduledOf<T> = Scheduled<
+	CallOrHashOf<T>,
+	<T as system::Trait>::BlockNumber,
+	<T as Trait>::PalletsOrigin,
+	<T as system::Trait>::AccountId,
+>;
+
+/// How a scheduled task is retried should its dispatch fail.
+#[derive(Clone, Copy, PartialEq, Eq, RuntimeDebug, Encode, Decode, TypeInfo)]
+pub struct RetryConfig<BlockNumber> {
+	/// The number of retries allowed for each scheduled run of the task.
+	pub total_retries: u8,
+	/// The number of retries left for the current run of the task.
+	pub remaining: u8,
+	/// The number of blocks to wait before retrying a failed dispatch.
+	pub period: BlockNumber,
+}
+
+/// Information about the failed dispatch of a scheduled task which is to be retried.
+#[derive(Clone, PartialEq, Eq, RuntimeDebug, Encode, Decode, TypeInfo)]
+pub struct TaskFailure<BlockNumber> {
+	/// The address at which the task was dispatched.
+	pub task: TaskAddress<BlockNumber>,
+	/// The name of the task, if it has one.
+	pub id: Option<Vec<u8>>,
+	/// The error the dispatch returned.
+	pub error: DispatchError,
+}
 
 // A value placed in storage that represents the current version of the Scheduler storage.
 // This value is used by the `on_runtime_upgrade` logic to determine whether we run
 // storage migration logic.
-#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug)]
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo)]
 enum Releases {
 	V1,
 	V2,
+	V3,
 }
 
 impl Default for Releases {
@@ -158,16 +240,24 @@ impl Default for Releases {
 decl_storage! {
 	trait Store for Module<T: Trait> as Scheduler {
 		/// Items to be executed, indexed by the block number that they should be executed on.
-		pub Agenda: map hasher(twox_64_concat) T::BlockNumber
-			=> Vec<Option<Scheduled<<T as Trait>::Call, T::BlockNumber, T::PalletsOrigin, T::AccountId>>>;
+		pub Agenda: map hasher(twox_64_concat) T::BlockNumber => Vec<Option<ScheduledOf<T>>>;
 
 		/// Lookup from identity to the block number and index of the task.
 		Lookup: map hasher(twox_64_concat) Vec<u8> => Option<TaskAddress<T::BlockNumber>>;
 
+		/// Retry configurations of scheduled tasks, indexed by their current address.
+		pub Retries: map hasher(twox_64_concat) TaskAddress<T::BlockNumber>
+			=> Option<RetryConfig<T::BlockNumber>>;
+
+		/// The most recent failed dispatch of scheduled tasks which are to be retried, indexed by
+		/// the address of the retry. Removed once the retry is dispatched or canceled.
+		pub LastFailure get(fn last_failure): map hasher(twox_64_concat) TaskAddress<T::BlockNumber>
+			=> Option<TaskFailure<T::BlockNumber>>;
+
 		/// Storage version of the pallet.
 		///
 		/// New networks start with last version.
-		StorageVersion build(|_| Releases::V2): Releases;
+		StorageVersion build(|_| Releases::V3): Releases;
 	}
 }
 
@@ -179,6 +269,16 @@ decl_event!(
 		Canceled(BlockNumber, u32),
 		/// Dispatched some task. \[task, id, result\]
 		Dispatched(TaskAddress<BlockNumber>, Option<Vec<u8>>, DispatchResult),
+		/// The dispatch of some task failed, and it may have been scheduled to be retried.
+		/// \[task, id, error, retry\]
+		CallFailed(TaskAddress<BlockNumber>, Option<Vec<u8>>, DispatchError, Option<TaskAddress<BlockNumber>>),
+		/// Set the retry configuration of some task. \[task, id, period, retries\]
+		RetrySet(TaskAddress<BlockNumber>, Option<Vec<u8>>, BlockNumber, u8),
+		/// Removed the retry configuration of some task. \[task, id\]
+		RetryCancelled(TaskAddress<BlockNumber>, Option<Vec<u8>>),
+		/// The call of some task could not be looked up from its preimage, and the task was
+		/// dropped. \[task, id, error\]
+		CallLookupFailed(TaskAddress<BlockNumber>, Option<Vec<u8>>, LookupError),
 	}
 );
 
@@ -192,6 +292,8 @@ decl_error! {
 		TargetBlockNumberInPast,
 		/// Reschedule failed because it does not change scheduled time.
 		RescheduleNoChange,
+		/// A retry configuration needs a non-zero number of retries and period.
+		InvalidRetryConfig,
 	}
 }
 
@@ -203,6 +305,9 @@ decl_module! {
 
 		/// Anonymously schedule a task.
 		///
+		/// The call may be given by its hash, in which case its preimage is requested until the
+		/// task is due.
+		///
 		/// # <weight>
 		/// - S = Number of already scheduled calls
 		/// - Base Weight: 22.29 + .126 * S µs
@@ -216,7 +321,7 @@ decl_module! {
 			when: T::BlockNumber,
 			maybe_periodic: Option<schedule::Period<T::BlockNumber>>,
 			priority: schedule::Priority,
-			call: Box<<T as Trait>::Call>,
+			call: Box<CallOrHashOf<T>>,
 		) {
 			T::ScheduleOrigin::ensure_origin(origin.clone())?;
 			let origin = <T as Trait>::Origin::from(origin);
@@ -256,7 +361,7 @@ decl_module! {
 			when: T::BlockNumber,
 			maybe_periodic: Option<schedule::Period<T::BlockNumber>>,
 			priority: schedule::Priority,
-			call: Box<<T as Trait>::Call>,
+			call: Box<CallOrHashOf<T>>,
 		) {
 			T::ScheduleOrigin::ensure_origin(origin.clone())?;
 			let origin = <T as Trait>::Origin::from(origin);
@@ -292,7 +397,7 @@ decl_module! {
 			after: T::BlockNumber,
 			maybe_periodic: Option<schedule::Period<T::BlockNumber>>,
 			priority: schedule::Priority,
-			call: Box<<T as Trait>::Call>,
+			call: Box<CallOrHashOf<T>>,
 		) {
 			T::ScheduleOrigin::ensure_origin(origin.clone())?;
 			let origin = <T as Trait>::Origin::from(origin);
@@ -312,7 +417,7 @@ decl_module! {
 			after: T::BlockNumber,
 			maybe_periodic: Option<schedule::Period<T::BlockNumber>>,
 			priority: schedule::Priority,
-			call: Box<<T as Trait>::Call>,
+			call: Box<CallOrHashOf<T>>,
 		) {
 			T::ScheduleOrigin::ensure_origin(origin.clone())?;
 			let origin = <T as Trait>::Origin::from(origin);
@@ -321,16 +426,110 @@ decl_module! {
 			)?;
 		}
 
+		/// Move a named task to block `when`, keeping its retry configuration.
+		///
+		/// The origin must be the one the task was scheduled with.
+		///
+		/// # <weight>
+		/// - S = Number of already scheduled calls
+		/// - DB Weight:
+		///     - Read: Lookup, Agenda(old), Agenda(new), Retries
+		///     - Write: Lookup, Agenda(old), Agenda(new), Retries
+		/// # </weight>
+		#[weight = T::WeightInfo::reschedule_named(T::MaxScheduledPerBlock::get())]
+		fn reschedule_named(origin, id: Vec<u8>, when: T::BlockNumber) {
+			T::ScheduleOrigin::ensure_origin(origin.clone())?;
+			let origin = <T as Trait>::Origin::from(origin);
+			let address = Lookup::<T>::get(&id).ok_or(Error::<T>::NotFound)?;
+			Self::ensure_task(Some(origin.caller()), address)?;
+			Self::do_reschedule_named(id, DispatchTime::At(when))?;
+		}
+
+		/// Retry a scheduled task up to `retries` times, `period` blocks apart, should its
+		/// dispatch fail. For a periodic task the retries are granted anew for every run.
+		///
+		/// The origin must be the one the task was scheduled with.
+		///
+		/// # <weight>
+		/// - S = Number of already scheduled calls
+		/// - DB Weight:
+		///     - Read: Agenda
+		///     - Write: Retries
+		/// # </weight>
+		#[weight = T::WeightInfo::set_retry(T::MaxScheduledPerBlock::get())]
+		fn set_retry(origin,
+			task: TaskAddress<T::BlockNumber>,
+			retries: u8,
+			period: T::BlockNumber,
+		) {
+			T::ScheduleOrigin::ensure_origin(origin.clone())?;
+			let origin = <T as Trait>::Origin::from(origin);
+			Self::do_set_retry(Some(origin.caller().clone()), task, retries, period)?;
+		}
+
+		/// Same as [`set_retry`], for a named task.
+		///
+		/// # <weight>
+		/// - S = Number of already scheduled calls
+		/// - DB Weight:
+		///     - Read: Lookup, Agenda
+		///     - Write: Retries
+		/// # </weight>
+		#[weight = T::WeightInfo::set_retry_named(T::MaxScheduledPerBlock::get())]
+		fn set_retry_named(origin, id: Vec<u8>, retries: u8, period: T::BlockNumber) {
+			T::ScheduleOrigin::ensure_origin(origin.clone())?;
+			let origin = <T as Trait>::Origin::from(origin);
+			let task = Lookup::<T>::get(&id).ok_or(Error::<T>::NotFound)?;
+			Self::do_set_retry(Some(origin.caller().clone()), task, retries, period)?;
+		}
+
+		/// Remove the retry configuration of a scheduled task.
+		///
+		/// The origin must be the one the task was scheduled with.
+		///
+		/// # <weight>
+		/// - S = Number of already scheduled calls
+		/// - DB Weight:
+		///     - Read: Agenda, Retries
+		///     - Write: Retries
+		/// # </weight>
+		#[weight = T::WeightInfo::cancel_retry(T::MaxScheduledPerBlock::get())]
+		fn cancel_retry(origin, task: TaskAddress<T::BlockNumber>) {
+			T::ScheduleOrigin::ensure_origin(origin.clone())?;
+			let origin = <T as Trait>::Origin::from(origin);
+			Self::do_cancel_retry(Some(origin.caller().clone()), task)?;
+		}
+
+		/// Same as [`cancel_retry`], for a named task.
+		///
+		/// # <weight>
+		/// - S = Number of already scheduled calls
+		/// - DB Weight:
+		///     - Read: Lookup, Agenda, Retries
+		///     - Write: Retries
+		/// # </weight>
+		#[weight = T::WeightInfo::cancel_retry_named(T::MaxScheduledPerBlock::get())]
+		fn cancel_retry_named(origin, id: Vec<u8>) {
+			T::ScheduleOrigin::ensure_origin(origin.clone())?;
+			let origin = <T as Trait>::Origin::from(origin);
+			let task = Lookup::<T>::get(&id).ok_or(Error::<T>::NotFound)?;
+			Self::do_cancel_retry(Some(origin.caller().clone()), task)?;
+		}
+
 		/// Execute the scheduled calls
 		///
 		/// # <weight>
 		/// - S = Number of already scheduled calls
 		/// - N = Named scheduled calls
 		/// - P = Periodic Calls
+		/// - F = Failed calls which are retried
+		/// - H = Calls scheduled by hash
 		/// - Base Weight: 9.243 + 23.45 * S µs
 		/// - DB Weight:
-		///     - Read: Agenda + Lookup * N + Agenda(Future) * P
-		///     - Write: Agenda + Lookup * N  + Agenda(future) * P
+		///     - Read: Agenda + (Retries + LastFailure) * S + Lookup * N + Agenda(Future) * (P + F)
+		///       + Preimage * H
+		///     - Write: Agenda + (Retries + LastFailure) * S + Lookup * N  + Agenda(future) * (P + F)
+		///       + Preimage * H
 		/// # </weight>
 		fn on_initialize(now: T::BlockNumber) -> Weight {
 			let limit = T::MaximumWeight::get();
@@ -347,11 +546,33 @@ decl_module! {
 			queued.sort_by_key(|(_, s)| s.priority);
 			let base_weight: Weight = T::DbWeight::get().reads_writes(1, 2); // Agenda + Agenda(next)
 			let mut total_weight: Weight = 0;
+			let mut failure_weight: Weight = 0;
 			queued.into_iter()
 				.enumerate()
-				.scan(base_weight, |cumulative_weight, (order, (index, s))| {
-					*cumulative_weight = cumulative_weight
-						.saturating_add(s.call.get_dispatch_info().weight);
+				.scan(base_weight, |cumulative_weight, (order, (index, mut s))| {
+					// Look up the call of a task which is scheduled by hash. Once the task keeps
+					// the call in full, its preimage is no longer needed.
+					let maybe_call = match s.call.resolved::<T::PreimageProvider>() {
+						Ok((call, maybe_hash)) => {
+							if let Some(hash) = maybe_hash {
+								T::PreimageProvider::unrequest_preimage(&hash);
+								// Read the preimage, Read/Write its request status
+								*cumulative_weight = cumulative_weight
+									.saturating_add(T::DbWeight::get().reads_writes(2, 2));
+							}
+							*cumulative_weight = cumulative_weight
+								.saturating_add(call.get_dispatch_info().weight);
+							s.call = MaybeHashed::Value(call.clone());
+							Ok(call)
+						},
+						Err((call, error)) => {
+							// Read the preimage
+							*cumulative_weight = cumulative_weight
+								.saturating_add(T::DbWeight::get().reads(1));
+							s.call = call;
+							Err(error)
+						},
+					};
 
 					let origin = <<T as Trait>::Origin as From<T::PalletsOrigin>>::from(
 						s.origin.clone()
@@ -363,6 +584,9 @@ decl_module! {
 							.saturating_add(T::DbWeight::get().reads_writes(1, 1));
 					}
 
+					// Take/Move Retries and LastFailure
+					*cumulative_weight = cumulative_weight.saturating_add(T::DbWeight::get().reads_writes(2, 2));
+
 					if s.maybe_id.is_some() {
 						// Remove/Modify Lookup
 						*cumulative_weight = cumulative_weight.saturating_add(T::DbWeight::get().writes(1));
@@ -372,16 +596,44 @@ decl_module! {
 						*cumulative_weight = cumulative_weight.saturating_add(T::DbWeight::get().reads_writes(1, 1));
 					}
 
-					Some((order, index, *cumulative_weight, s))
+					Some((order, index, *cumulative_weight, s, maybe_call))
 				})
-				.filter_map(|(order, index, cumulative_weight, mut s)| {
+				.filter_map(|(order, index, cumulative_weight, mut s, maybe_call)| {
+					let call = match maybe_call {
+						Ok(call) => call,
+						Err(error) => {
+							// Read/Write Agenda for the postponement block, Write Retries, Lookup,
+							// LastFailure and the request status of the preimage
+							failure_weight = failure_weight
+								.saturating_add(T::DbWeight::get().reads_writes(2, 5));
+							Self::postpone_or_drop(now, index, s, error);
+							return None;
+						},
+					};
 					// We allow a scheduled call if any is true:
 					// - It's priority is `HARD_DEADLINE`
 					// - It does not push the weight past the limit.
 					// - It is the first item in the schedule
 					if s.priority <= schedule::HARD_DEADLINE || cumulative_weight <= limit || order == 0 {
-						let r = s.call.clone().dispatch(s.origin.clone().into());
+						let r = call.dispatch(s.origin.clone().into());
+						let result = r.map(|_| ()).map_err(|e| e.error);
 						let maybe_id = s.maybe_id.clone();
+						let maybe_retry = Retries::<T>::take((now, index));
+						// The failure which led to this dispatch is superseded by its outcome.
+						LastFailure::<T>::remove((now, index));
+						// A failed task with retries left is dispatched again later, once.
+						let retry_task = match (&result, maybe_retry) {
+							(Err(_), Some(config)) if config.remaining > 0 => {
+								let mut task = s.clone();
+								if task.maybe_periodic.is_some() {
+									// The periodic task keeps its name, the retry is anonymous.
+									task.maybe_id = None;
+									task.maybe_periodic = None;
+								}
+								Some((task, RetryConfig { remaining: config.remaining - 1, ..config }))
+							},
+							_ => None,
+						};
 						if let &Some((period, count)) = &s.maybe_periodic {
 							if count > 1 {
 								s.maybe_periodic = Some((period, count - 1));
@@ -389,10 +641,17 @@ decl_module! {
 								s.maybe_periodic = None;
 							}
 							let next = now + period;
+							let next_index = Agenda::<T>::decode_len(next).unwrap_or(0) as u32;
 							// If scheduled is named, place it's information in `Lookup`
 							if let Some(ref id) = s.maybe_id {
-								let next_index = Agenda::<T>::decode_len(now + period).unwrap_or(0);
-								Lookup::<T>::insert(id, (next, next_index as u32));
+								Lookup::<T>::insert(id, (next, next_index));
+							}
+							// The next run of the task gets its retries back.
+							if let Some(config) = maybe_retry {
+								Retries::<T>::insert(
+									(next, next_index),
+									RetryConfig { remaining: config.total_retries, ..config },
+								);
 							}
 							Agenda::<T>::append(next, Some(s));
 						} else {
@@ -400,43 +659,54 @@ decl_module! {
 								Lookup::<T>::remove(id);
 							}
 						}
-						Self::deposit_event(RawEvent::Dispatched(
-							(now, index),
-							maybe_id,
-							r.map(|_| ()).map_err(|e| e.error)
-						));
+						Self::deposit_event(RawEvent::Dispatched((now, index), maybe_id.clone(), result));
+						if let Err(error) = result {
+							let retry = retry_task.map(|(task, config)| {
+								// Read/Write Agenda for the retry block, Write Retries, Lookup and
+								// LastFailure
+								failure_weight = failure_weight
+									.saturating_add(T::DbWeight::get().reads_writes(1, 4));
+								let retry = Self::schedule_retry(now, task, config);
+								LastFailure::<T>::insert(
+									retry,
+									TaskFailure { task: (now, index), id: maybe_id.clone(), error },
+								);
+								retry
+							});
+							Self::deposit_event(RawEvent::CallFailed((now, index), maybe_id, error, retry));
+						}
 						total_weight = cumulative_weight;
 						None
 					} else {
-						Some(Some(s))
+						Some((index, s))
 					}
 				})
-				.for_each(|unused| {
-					let next = now + One::one();
-					Agenda::<T>::append(next, unused);
+				.for_each(|(index, s)| {
+					// Unserved tasks move to the next block, along with their lookup and retries.
+					Self::place_task((now, index), now + One::one(), s);
 				});
 
-			total_weight
+			total_weight.saturating_add(failure_weight)
 		}
 	}
 }
 
 impl<T: Trait> Module<T> {
-	/// Migrate storage format from V1 to V2.
+	/// Migrate storage format from V1 to V3.
 	/// Return true if migration is performed.
-	pub fn migrate_v1_to_t2() -> bool {
+	pub fn migrate_v1_to_v3() -> bool {
 		if StorageVersion::get() == Releases::V1 {
-			StorageVersion::put(Releases::V2);
+			StorageVersion::put(Releases::V3);
 
 			Agenda::<T>::translate::<
 				Vec<Option<ScheduledV1<<T as Trait>::Call, T::BlockNumber>>>, _
 			>(|_, agenda| Some(
 				agenda
 					.into_iter()
-					.map(|schedule| schedule.map(|schedule| ScheduledV2 {
+					.map(|schedule| schedule.map(|schedule| ScheduledV3 {
 						maybe_id: schedule.maybe_id,
 						priority: schedule.priority,
-						call: schedule.call,
+						call: MaybeHashed::Value(schedule.call),
 						maybe_periodic: schedule.maybe_periodic,
 						origin: system::RawOrigin::Root.into(),
 						_phantom: Default::default(),
@@ -450,10 +720,38 @@ impl<T: Trait> Module<T> {
 		}
 	}
 
+	/// Migrate storage format from V2 to V3, where the calls of all tasks are kept in full.
+	/// Return true if migration is performed.
+	pub fn migrate_v2_to_v3() -> bool {
+		if StorageVersion::get() == Releases::V2 {
+			StorageVersion::put(Releases::V3);
+
+			Agenda::<T>::translate::<
+				Vec<Option<ScheduledV2<<T as Trait>::Call, T::BlockNumber, T::PalletsOrigin, T::AccountId>>>, _
+			>(|_, agenda| Some(
+				agenda
+					.into_iter()
+					.map(|schedule| schedule.map(|schedule| ScheduledV3 {
+						maybe_id: schedule.maybe_id,
+						priority: schedule.priority,
+						call: MaybeHashed::Value(schedule.call),
+						maybe_periodic: schedule.maybe_periodic,
+						origin: schedule.origin,
+						_phantom: Default::default(),
+					}))
+					.collect::<Vec<_>>()
+			));
+
+			true
+		} else {
+			false
+		}
+	}
+
 	/// Helper to migrate scheduler when the pallet origin type has changed.
 	pub fn migrate_origin<OldOrigin: Into<T::PalletsOrigin> + codec::Decode>() {
 		Agenda::<T>::translate::<
-			Vec<Option<Scheduled<<T as Trait>::Call, T::BlockNumber, OldOrigin, T::AccountId>>>, _
+			Vec<Option<Scheduled<CallOrHashOf<T>, T::BlockNumber, OldOrigin, T::AccountId>>>, _
 		>(|_, agenda| Some(
 			agenda
 				.into_iter()
@@ -491,9 +789,10 @@ impl<T: Trait> Module<T> {
 		maybe_periodic: Option<schedule::Period<T::BlockNumber>>,
 		priority: schedule::Priority,
 		origin: T::PalletsOrigin,
-		call: <T as Trait>::Call
+		call: CallOrHashOf<T>,
 	) -> Result<TaskAddress<T::BlockNumber>, DispatchError> {
 		let when = Self::resolve_time(when)?;
+		call.ensure_requested::<T::PreimageProvider>();
 
 		// sanitize maybe_periodic
 		let maybe_periodic = maybe_periodic
@@ -535,9 +834,12 @@ impl<T: Trait> Module<T> {
 			},
 		)?;
 		if let Some(s) = scheduled {
+			s.call.ensure_unrequested::<T::PreimageProvider>();
 			if let Some(id) = s.maybe_id {
 				Lookup::<T>::remove(id);
 			}
+			Retries::<T>::remove((when, index));
+			LastFailure::<T>::remove((when, index));
 			Self::deposit_event(RawEvent::Canceled(when, index));
 			Ok(())
 		} else {
@@ -563,6 +865,7 @@ impl<T: Trait> Module<T> {
 		})?;
 
 		let new_index = Agenda::<T>::decode_len(new_time).unwrap_or(1) as u32 - 1;
+		Self::move_retry((when, index), (new_time, new_index));
 		Self::deposit_event(RawEvent::Canceled(when, index));
 		Self::deposit_event(RawEvent::Scheduled(new_time, new_index));
 
@@ -575,7 +878,7 @@ impl<T: Trait> Module<T> {
 		maybe_periodic: Option<schedule::Period<T::BlockNumber>>,
 		priority: schedule::Priority,
 		origin: T::PalletsOrigin,
-		call: <T as Trait>::Call,
+		call: CallOrHashOf<T>,
 	) -> Result<TaskAddress<T::BlockNumber>, DispatchError> {
 		// ensure id it is unique
 		if Lookup::<T>::contains_key(&id) {
@@ -583,6 +886,7 @@ impl<T: Trait> Module<T> {
 		}
 
 		let when = Self::resolve_time(when)?;
+		call.ensure_requested::<T::PreimageProvider>();
 
 		// sanitize maybe_periodic
 		let maybe_periodic = maybe_periodic
@@ -619,10 +923,14 @@ impl<T: Trait> Module<T> {
 								return Err(BadOrigin.into());
 							}
 						}
-						*s = None;
+						if let Some(s) = s.take() {
+							s.call.ensure_unrequested::<T::PreimageProvider>();
+						}
 					}
 					Ok(())
 				})?;
+				Retries::<T>::remove((when, index));
+				LastFailure::<T>::remove((when, index));
 				Self::deposit_event(RawEvent::Canceled(when, index));
 				Ok(())
 			} else {
@@ -653,6 +961,7 @@ impl<T: Trait> Module<T> {
 			})?;
 
 			let new_index = Agenda::<T>::decode_len(new_time).unwrap_or(1) as u32 - 1;
+			Self::move_retry((when, index), (new_time, new_index));
 			Self::deposit_event(RawEvent::Canceled(when, index));
 			Self::deposit_event(RawEvent::Scheduled(new_time, new_index));
 
@@ -661,6 +970,151 @@ impl<T: Trait> Module<T> {
 			Ok((new_time, new_index))
 		})
 	}
+
+	/// Ensure there is a task at `address` and, if `origin` is given, that it is the task's
+	/// origin. Returns the task's name.
+	fn ensure_task(
+		origin: Option<&T::PalletsOrigin>,
+		(when, index): TaskAddress<T::BlockNumber>,
+	) -> Result<Option<Vec<u8>>, DispatchError> {
+		let agenda = Agenda::<T>::get(when);
+		let task = agenda.get(index as usize)
+			.and_then(Option::as_ref)
+			.ok_or(Error::<T>::NotFound)?;
+		if let Some(o) = origin {
+			if *o != task.origin {
+				return Err(BadOrigin.into());
+			}
+		}
+		Ok(task.maybe_id.clone())
+	}
+
+	fn do_set_retry(
+		origin: Option<T::PalletsOrigin>,
+		task: TaskAddress<T::BlockNumber>,
+		retries: u8,
+		period: T::BlockNumber,
+	) -> DispatchResult {
+		ensure!(retries > 0 && !period.is_zero(), Error::<T>::InvalidRetryConfig);
+		let id = Self::ensure_task(origin.as_ref(), task)?;
+		Retries::<T>::insert(task, RetryConfig { total_retries: retries, remaining: retries, period });
+		Self::deposit_event(RawEvent::RetrySet(task, id, period, retries));
+		Ok(())
+	}
+
+	fn do_cancel_retry(origin: Option<T::PalletsOrigin>, task: TaskAddress<T::BlockNumber>) -> DispatchResult {
+		let id = Self::ensure_task(origin.as_ref(), task)?;
+		Retries::<T>::take(task).ok_or(Error::<T>::NotFound)?;
+		Self::deposit_event(RawEvent::RetryCancelled(task, id));
+		Ok(())
+	}
+
+	/// Move the retry configuration and the recorded failure of a task, if any, along with the
+	/// task.
+	fn move_retry(from: TaskAddress<T::BlockNumber>, to: TaskAddress<T::BlockNumber>) {
+		if let Some(config) = Retries::<T>::take(from) {
+			Retries::<T>::insert(to, config);
+		}
+		if let Some(failure) = LastFailure::<T>::take(from) {
+			LastFailure::<T>::insert(to, failure);
+		}
+	}
+
+	/// Place `task`, which was due at `from`, in the agenda of block `when`, along with its lookup,
+	/// retry configuration and recorded failure.
+	fn place_task(
+		from: TaskAddress<T::BlockNumber>,
+		when: T::BlockNumber,
+		task: ScheduledOf<T>,
+	) -> TaskAddress<T::BlockNumber> {
+		let index = Agenda::<T>::decode_len(when).unwrap_or(0) as u32;
+		if let Some(ref id) = task.maybe_id {
+			Lookup::<T>::insert(id, (when, index));
+		}
+		Self::move_retry(from, (when, index));
+		Agenda::<T>::append(when, Some(task));
+		(when, index)
+	}
+
+	/// Postpone the task due at block `now`, whose call could not be looked up because of `error`,
+	/// by `T::NoPreimagePostponement` blocks if its preimage may still be noted, or drop it.
+	fn postpone_or_drop(now: T::BlockNumber, index: u32, task: ScheduledOf<T>, error: LookupError) {
+		match (error, T::NoPreimagePostponement::get()) {
+			(LookupError::Unknown, Some(delay)) => {
+				// The agenda of the current block has already been taken.
+				Self::place_task((now, index), now.saturating_add(delay.max(One::one())), task);
+			},
+			_ => {
+				task.call.ensure_unrequested::<T::PreimageProvider>();
+				if let Some(ref id) = task.maybe_id {
+					Lookup::<T>::remove(id);
+				}
+				Retries::<T>::remove((now, index));
+				LastFailure::<T>::remove((now, index));
+				Self::deposit_event(RawEvent::CallLookupFailed((now, index), task.maybe_id, error));
+			},
+		}
+	}
+
+	/// Schedule `task`, which failed at block `now`, to be retried as per `config`.
+	fn schedule_retry(
+		now: T::BlockNumber,
+		task: ScheduledOf<T>,
+		config: RetryConfig<T::BlockNumber>,
+	) -> TaskAddress<T::BlockNumber> {
+		let when = now.saturating_add(config.period);
+		let index = Agenda::<T>::decode_len(when).unwrap_or(0) as u32;
+		if let Some(ref id) = task.maybe_id {
+			Lookup::<T>::insert(id, (when, index));
+		}
+		Agenda::<T>::append(when, Some(task));
+		Retries::<T>::insert((when, index), config);
+		(when, index)
+	}
+
+	fn agenda_item(
+		(when, index): TaskAddress<T::BlockNumber>,
+		task: &ScheduledOf<T>,
+	) -> AgendaItem<T::BlockNumber, T::PalletsOrigin> {
+		AgendaItem {
+			when,
+			index,
+			id: task.maybe_id.clone(),
+			priority: task.priority,
+			origin: task.origin.clone(),
+			maybe_periodic: task.maybe_periodic,
+			retry: Retries::<T>::get((when, index)).map(|c| (c.remaining, c.period)),
+			call: task.call.encode(),
+		}
+	}
+
+	/// The pending task scheduled under the name `id`, if any.
+	pub fn agenda_by_name(id: Vec<u8>) -> Option<AgendaItem<T::BlockNumber, T::PalletsOrigin>> {
+		let (when, index) = Lookup::<T>::get(id)?;
+		Agenda::<T>::get(when).get(index as usize)
+			.and_then(Option::as_ref)
+			.map(|task| Self::agenda_item((when, index), task))
+	}
+
+	/// The recorded failure of the task scheduled under the name `id`, if it is pending a retry.
+	pub fn last_failure_named(id: Vec<u8>) -> Option<TaskFailure<T::BlockNumber>> {
+		Lookup::<T>::get(id).and_then(LastFailure::<T>::get)
+	}
+
+	/// All pending tasks to be dispatched with `origin`, in the order they are due.
+	pub fn agenda_by_origin(origin: T::PalletsOrigin) -> Vec<AgendaItem<T::BlockNumber, T::PalletsOrigin>> {
+		let mut items = Agenda::<T>::iter()
+			.flat_map(|(when, agenda)| agenda.into_iter()
+				.enumerate()
+				.filter_map(|(index, task)| task.map(|task| (index as u32, task)))
+				.filter(|(_, task)| task.origin == origin)
+				.map(|(index, task)| Self::agenda_item((when, index), &task))
+				.collect::<Vec<_>>()
+			)
+			.collect::<Vec<_>>();
+		items.sort_by_key(|item| (item.when, item.index));
+		items
+	}
 }
 
 impl<T: Trait> schedule::Anon<T::BlockNumber, <T as Trait>::Call, T::PalletsOrigin> for Module<T> {
@@ -673,7 +1127,7 @@ impl<T: Trait> schedule::Anon<T::BlockNumber, <T as Trait>::Call, T::PalletsOrig
 		origin: T::PalletsOrigin,
 		call: <T as Trait>::Call
 	) -> Result<Self::Address, DispatchError> {
-		Self::do_schedule(when, maybe_periodic, priority, origin, call)
+		Self::do_schedule(when, maybe_periodic, priority, origin, call.into())
 	}
 
 	fn cancel((when, index): Self::Address) -> Result<(), ()> {
@@ -703,7 +1157,7 @@ impl<T: Trait> schedule::Named<T::BlockNumber, <T as Trait>::Call, T::PalletsOri
 		origin: T::PalletsOrigin,
 		call: <T as Trait>::Call,
 	) -> Result<Self::Address, ()> {
-		Self::do_schedule_named(id, when, maybe_periodic, priority, origin, call).map_err(|_| ())
+		Self::do_schedule_named(id, when, maybe_periodic, priority, origin, call.into()).map_err(|_| ())
 	}
 
 	fn cancel_named(id: Vec<u8>) -> Result<(), ()> {
@@ -736,7 +1190,7 @@ mod tests {
 	use sp_runtime::{
 		Perbill,
 		testing::Header,
-		traits::{BlakeTwo256, IdentityLookup},
+		traits::{BlakeTwo256, IdentityLookup, Hash},
 	};
 	use frame_system::{EnsureOneOf, EnsureRoot, EnsureSignedBy};
 	use substrate_test_utils::assert_eq_uvec;
@@ -783,6 +1237,15 @@ mod tests {
 						log.borrow_mut().push((origin.caller().clone(), i));
 					})
 				}
+
+				#[weight = *weight]
+				fn log_if_due(origin, i: u32, weight: Weight, due: u32) {
+					ensure!(system::Module::<T>::block_number() >= due.into(), BadOrigin);
+					Self::deposit_event(Event::Logged(i, weight));
+					LOG.with(|log| {
+						log.borrow_mut().push((origin.caller().clone(), i));
+					})
+				}
 			}
 		}
 	}
@@ -852,9 +1315,61 @@ mod tests {
 	impl logger::Trait for Test {
 		type Event = ();
 	}
+	mod preimages {
+		use super::*;
+		use std::{cell::RefCell, collections::BTreeMap};
+
+		thread_local! {
+			static PREIMAGES: RefCell<BTreeMap<H256, Vec<u8>>> = RefCell::new(BTreeMap::new());
+			static REQUESTS: RefCell<BTreeMap<H256, u32>> = RefCell::new(BTreeMap::new());
+		}
+
+		/// A preimage provider which keeps preimages as long as they are requested.
+		pub struct TestPreimages;
+		impl TestPreimages {
+			pub fn note(bytes: Vec<u8>) -> H256 {
+				let hash = BlakeTwo256::hash(&bytes);
+				PREIMAGES.with(|p| p.borrow_mut().insert(hash, bytes));
+				hash
+			}
+			pub fn requests(hash: &H256) -> u32 {
+				REQUESTS.with(|r| r.borrow().get(hash).cloned().unwrap_or(0))
+			}
+		}
+		impl PreimageProvider<H256> for TestPreimages {
+			fn have_preimage(hash: &H256) -> bool {
+				PREIMAGES.with(|p| p.borrow().contains_key(hash))
+			}
+			fn get_preimage(hash: &H256) -> Option<Vec<u8>> {
+				PREIMAGES.with(|p| p.borrow().get(hash).cloned())
+			}
+			fn preimage_requested(hash: &H256) -> bool {
+				Self::requests(hash) > 0
+			}
+			fn request_preimage(hash: &H256) {
+				REQUESTS.with(|r| *r.borrow_mut().entry(*hash).or_insert(0) += 1);
+			}
+			fn unrequest_preimage(hash: &H256) {
+				REQUESTS.with(|r| {
+					let mut requests = r.borrow_mut();
+					let count = requests.get(hash).cloned().unwrap_or(0);
+					assert!(count > 0, "preimage unrequested more often than requested");
+					if count == 1 {
+						requests.remove(hash);
+						PREIMAGES.with(|p| p.borrow_mut().remove(hash));
+					} else {
+						requests.insert(*hash, count - 1);
+					}
+				});
+			}
+		}
+	}
+	use preimages::TestPreimages;
+
 	parameter_types! {
 		pub MaximumSchedulerWeight: Weight = Perbill::from_percent(80) * MaximumBlockWeight::get();
 		pub const MaxScheduledPerBlock: u32 = 10;
+		pub const NoPreimagePostponement: Option<u64> = Some(2);
 	}
 	ord_parameter_types! {
 		pub const One: u64 = 1;
@@ -868,6 +1383,8 @@ mod tests {
 		type MaximumWeight = MaximumSchedulerWeight;
 		type ScheduleOrigin = EnsureOneOf<u64, EnsureRoot<u64>, EnsureSignedBy<One, u64>>;
 		type MaxScheduledPerBlock = MaxScheduledPerBlock;
+		type PreimageProvider = TestPreimages;
+		type NoPreimagePostponement = NoPreimagePostponement;
 		type WeightInfo = ();
 	}
 	type System = system::Module<Test>;
@@ -896,7 +1413,7 @@ mod tests {
 		new_test_ext().execute_with(|| {
 			let call = Call::Logger(logger::Call::log(42, 1000));
 			assert!(!<Test as frame_system::Trait>::BaseCallFilter::filter(&call));
-			assert_ok!(Scheduler::do_schedule(DispatchTime::At(4), None, 127, root(), call));
+			assert_ok!(Scheduler::do_schedule(DispatchTime::At(4), None, 127, root(), call.into()));
 			run_to_block(3);
 			assert!(logger::log().is_empty());
 			run_to_block(4);
@@ -913,7 +1430,7 @@ mod tests {
 			let call = Call::Logger(logger::Call::log(42, 1000));
 			assert!(!<Test as frame_system::Trait>::BaseCallFilter::filter(&call));
 			// This will schedule the call 3 blocks after the next block... so block 3 + 3 = 6
-			assert_ok!(Scheduler::do_schedule(DispatchTime::After(3), None, 127, root(), call));
+			assert_ok!(Scheduler::do_schedule(DispatchTime::After(3), None, 127, root(), call.into()));
 			run_to_block(5);
 			assert!(logger::log().is_empty());
 			run_to_block(6);
@@ -929,7 +1446,7 @@ mod tests {
 			run_to_block(2);
 			let call = Call::Logger(logger::Call::log(42, 1000));
 			assert!(!<Test as frame_system::Trait>::BaseCallFilter::filter(&call));
-			assert_ok!(Scheduler::do_schedule(DispatchTime::After(0), None, 127, root(), call));
+			assert_ok!(Scheduler::do_schedule(DispatchTime::After(0), None, 127, root(), call.into()));
 			// Will trigger on the next block.
 			run_to_block(3);
 			assert_eq!(logger::log(), vec![(root(), 42u32)]);
@@ -943,7 +1460,7 @@ mod tests {
 		new_test_ext().execute_with(|| {
 			// at #4, every 3 blocks, 3 times.
 			assert_ok!(Scheduler::do_schedule(
-				DispatchTime::At(4), Some((3, 3)), 127, root(), Call::Logger(logger::Call::log(42, 1000))
+				DispatchTime::At(4), Some((3, 3)), 127, root(), Call::Logger(logger::Call::log(42, 1000)).into()
 			));
 			run_to_block(3);
 			assert!(logger::log().is_empty());
@@ -967,7 +1484,7 @@ mod tests {
 		new_test_ext().execute_with(|| {
 			let call = Call::Logger(logger::Call::log(42, 1000));
 			assert!(!<Test as frame_system::Trait>::BaseCallFilter::filter(&call));
-			assert_eq!(Scheduler::do_schedule(DispatchTime::At(4), None, 127, root(), call).unwrap(), (4, 0));
+			assert_eq!(Scheduler::do_schedule(DispatchTime::At(4), None, 127, root(), call.into()).unwrap(), (4, 0));
 
 			run_to_block(3);
 			assert!(logger::log().is_empty());
@@ -993,7 +1510,7 @@ mod tests {
 			let call = Call::Logger(logger::Call::log(42, 1000));
 			assert!(!<Test as frame_system::Trait>::BaseCallFilter::filter(&call));
 			assert_eq!(Scheduler::do_schedule_named(
-				1u32.encode(), DispatchTime::At(4), None, 127, root(), call
+				1u32.encode(), DispatchTime::At(4), None, 127, root(), call.into()
 			).unwrap(), (4, 0));
 
 			run_to_block(3);
@@ -1020,7 +1537,7 @@ mod tests {
 			let call = Call::Logger(logger::Call::log(42, 1000));
 			assert!(!<Test as frame_system::Trait>::BaseCallFilter::filter(&call));
 			assert_eq!(Scheduler::do_schedule_named(
-				1u32.encode(), DispatchTime::At(4), Some((3, 3)), 127, root(), call
+				1u32.encode(), DispatchTime::At(4), Some((3, 3)), 127, root(), call.into()
 			).unwrap(), (4, 0));
 
 			run_to_block(3);
@@ -1056,10 +1573,10 @@ mod tests {
 		new_test_ext().execute_with(|| {
 			// at #4.
 			Scheduler::do_schedule_named(
-				1u32.encode(), DispatchTime::At(4), None, 127, root(), Call::Logger(logger::Call::log(69, 1000))
+				1u32.encode(), DispatchTime::At(4), None, 127, root(), Call::Logger(logger::Call::log(69, 1000)).into()
 			).unwrap();
 			let i = Scheduler::do_schedule(
-				DispatchTime::At(4), None, 127, root(), Call::Logger(logger::Call::log(42, 1000))
+				DispatchTime::At(4), None, 127, root(), Call::Logger(logger::Call::log(42, 1000)).into()
 			).unwrap();
 			run_to_block(3);
 			assert!(logger::log().is_empty());
@@ -1080,7 +1597,7 @@ mod tests {
 				Some((3, 3)),
 				127,
 				root(),
-				Call::Logger(logger::Call::log(42, 1000))
+				Call::Logger(logger::Call::log(42, 1000)).into()
 			).unwrap();
 			// same id results in error.
 			assert!(Scheduler::do_schedule_named(
@@ -1089,11 +1606,11 @@ mod tests {
 				None,
 				127,
 				root(),
-				Call::Logger(logger::Call::log(69, 1000))
+				Call::Logger(logger::Call::log(69, 1000)).into()
 			).is_err());
 			// different id is ok.
 			Scheduler::do_schedule_named(
-				2u32.encode(), DispatchTime::At(8), None, 127, root(), Call::Logger(logger::Call::log(69, 1000))
+				2u32.encode(), DispatchTime::At(8), None, 127, root(), Call::Logger(logger::Call::log(69, 1000)).into()
 			).unwrap();
 			run_to_block(3);
 			assert!(logger::log().is_empty());
@@ -1114,13 +1631,13 @@ mod tests {
 				None,
 				127,
 				root(),
-				Call::Logger(logger::Call::log(42, MaximumSchedulerWeight::get() / 2))
+				Call::Logger(logger::Call::log(42, MaximumSchedulerWeight::get() / 2)).into()
 			));
 			assert_ok!(Scheduler::do_schedule(
 				DispatchTime::At(4),
 				None,
 				127,
-				root(), Call::Logger(logger::Call::log(69, MaximumSchedulerWeight::get() / 2))
+				root(), Call::Logger(logger::Call::log(69, MaximumSchedulerWeight::get() / 2)).into()
 			));
 			// 69 and 42 do not fit together
 			run_to_block(4);
@@ -1138,14 +1655,14 @@ mod tests {
 				None,
 				0,
 				root(),
-				Call::Logger(logger::Call::log(42, MaximumSchedulerWeight::get() / 2))
+				Call::Logger(logger::Call::log(42, MaximumSchedulerWeight::get() / 2)).into()
 			));
 			assert_ok!(Scheduler::do_schedule(
 				DispatchTime::At(4),
 				None,
 				0,
 				root(),
-				Call::Logger(logger::Call::log(69, MaximumSchedulerWeight::get() / 2))
+				Call::Logger(logger::Call::log(69, MaximumSchedulerWeight::get() / 2)).into()
 			));
 			// With base weights, 69 and 42 should not fit together, but do because of hard deadlines
 			run_to_block(4);
@@ -1161,14 +1678,14 @@ mod tests {
 				None,
 				1,
 				root(),
-				Call::Logger(logger::Call::log(42, MaximumSchedulerWeight::get() / 2))
+				Call::Logger(logger::Call::log(42, MaximumSchedulerWeight::get() / 2)).into()
 			));
 			assert_ok!(Scheduler::do_schedule(
 				DispatchTime::At(4),
 				None,
 				0,
 				root(),
-				Call::Logger(logger::Call::log(69, MaximumSchedulerWeight::get() / 2))
+				Call::Logger(logger::Call::log(69, MaximumSchedulerWeight::get() / 2)).into()
 			));
 			run_to_block(4);
 			assert_eq!(logger::log(), vec![(root(), 69u32), (root(), 42u32)]);
@@ -1182,19 +1699,19 @@ mod tests {
 				DispatchTime::At(4),
 				None,
 				255,
-				root(), Call::Logger(logger::Call::log(42, MaximumSchedulerWeight::get() / 3))
+				root(), Call::Logger(logger::Call::log(42, MaximumSchedulerWeight::get() / 3)).into()
 			));
 			assert_ok!(Scheduler::do_schedule(
 				DispatchTime::At(4),
 				None,
 				127,
-				root(), Call::Logger(logger::Call::log(69, MaximumSchedulerWeight::get() / 2))
+				root(), Call::Logger(logger::Call::log(69, MaximumSchedulerWeight::get() / 2)).into()
 			));
 			assert_ok!(Scheduler::do_schedule(
 				DispatchTime::At(4),
 				None,
 				126,
-				root(), Call::Logger(logger::Call::log(2600, MaximumSchedulerWeight::get() / 2))
+				root(), Call::Logger(logger::Call::log(2600, MaximumSchedulerWeight::get() / 2)).into()
 			));
 
 			// 2600 does not fit with 69 or 42, but has higher priority, so will go through
@@ -1210,7 +1727,8 @@ mod tests {
 	fn on_initialize_weight_is_correct() {
 		new_test_ext().execute_with(|| {
 			let base_weight: Weight = <Test as frame_system::Trait>::DbWeight::get().reads_writes(1, 2);
-			let base_multiplier = 0;
+			// Take/Move Retries and LastFailure of every dispatched task.
+			let base_multiplier = <Test as frame_system::Trait>::DbWeight::get().reads_writes(2, 2);
 			let named_multiplier = <Test as frame_system::Trait>::DbWeight::get().writes(1);
 			let periodic_multiplier = <Test as frame_system::Trait>::DbWeight::get().reads_writes(1, 1);
 
@@ -1218,7 +1736,7 @@ mod tests {
 			assert_ok!(
 				Scheduler::do_schedule_named(
 					1u32.encode(), DispatchTime::At(1), None, 255, root(),
-					Call::Logger(logger::Call::log(3, MaximumSchedulerWeight::get() / 3))
+					Call::Logger(logger::Call::log(3, MaximumSchedulerWeight::get() / 3)).into()
 				)
 			);
 			// Anon Periodic
@@ -1227,7 +1745,7 @@ mod tests {
 				Some((1000, 3)),
 				128,
 				root(),
-				Call::Logger(logger::Call::log(42, MaximumSchedulerWeight::get() / 3))
+				Call::Logger(logger::Call::log(42, MaximumSchedulerWeight::get() / 3)).into()
 			));
 			// Anon
 			assert_ok!(Scheduler::do_schedule(
@@ -1235,12 +1753,12 @@ mod tests {
 				None,
 				127,
 				root(),
-				Call::Logger(logger::Call::log(69, MaximumSchedulerWeight::get() / 2))
+				Call::Logger(logger::Call::log(69, MaximumSchedulerWeight::get() / 2)).into()
 			));
 			// Named Periodic
 			assert_ok!(Scheduler::do_schedule_named(
 				2u32.encode(), DispatchTime::At(1), Some((1000, 3)), 126, root(),
-				Call::Logger(logger::Call::log(2600, MaximumSchedulerWeight::get() / 2)))
+				Call::Logger(logger::Call::log(2600, MaximumSchedulerWeight::get() / 2)).into())
 			);
 
 			// Will include the named periodic only
@@ -1272,8 +1790,8 @@ mod tests {
 	#[test]
 	fn root_calls_works() {
 		new_test_ext().execute_with(|| {
-			let call = Box::new(Call::Logger(logger::Call::log(69, 1000)));
-			let call2 = Box::new(Call::Logger(logger::Call::log(42, 1000)));
+			let call = Box::new(Call::Logger(logger::Call::log(69, 1000)).into());
+			let call2 = Box::new(Call::Logger(logger::Call::log(42, 1000)).into());
 			assert_ok!(Scheduler::schedule_named(Origin::root(), 1u32.encode(), 4, None, 127, call));
 			assert_ok!(Scheduler::schedule(Origin::root(), 4, None, 127, call2));
 			run_to_block(3);
@@ -1293,8 +1811,8 @@ mod tests {
 		new_test_ext().execute_with(|| {
 			run_to_block(3);
 
-			let call = Box::new(Call::Logger(logger::Call::log(69, 1000)));
-			let call2 = Box::new(Call::Logger(logger::Call::log(42, 1000)));
+			let call = Box::new(Call::Logger(logger::Call::log(69, 1000)).into());
+			let call2 = Box::new(Call::Logger(logger::Call::log(42, 1000)).into());
 
 			assert_err!(
 				Scheduler::schedule_named(Origin::root(), 1u32.encode(), 2, None, 127, call),
@@ -1316,8 +1834,8 @@ mod tests {
 	#[test]
 	fn should_use_orign() {
 		new_test_ext().execute_with(|| {
-			let call = Box::new(Call::Logger(logger::Call::log(69, 1000)));
-			let call2 = Box::new(Call::Logger(logger::Call::log(42, 1000)));
+			let call = Box::new(Call::Logger(logger::Call::log(69, 1000)).into());
+			let call2 = Box::new(Call::Logger(logger::Call::log(42, 1000)).into());
 			assert_ok!(
 				Scheduler::schedule_named(system::RawOrigin::Signed(1).into(), 1u32.encode(), 4, None, 127, call)
 			);
@@ -1337,8 +1855,8 @@ mod tests {
 	#[test]
 	fn should_check_orign() {
 		new_test_ext().execute_with(|| {
-			let call = Box::new(Call::Logger(logger::Call::log(69, 1000)));
-			let call2 = Box::new(Call::Logger(logger::Call::log(42, 1000)));
+			let call = Box::new(Call::Logger(logger::Call::log(69, 1000)).into());
+			let call2 = Box::new(Call::Logger(logger::Call::log(42, 1000)).into());
 			assert_noop!(
 				Scheduler::schedule_named(system::RawOrigin::Signed(2).into(), 1u32.encode(), 4, None, 127, call),
 				BadOrigin
@@ -1350,8 +1868,8 @@ mod tests {
 	#[test]
 	fn should_check_orign_for_cancel() {
 		new_test_ext().execute_with(|| {
-			let call = Box::new(Call::Logger(logger::Call::log_without_filter(69, 1000)));
-			let call2 = Box::new(Call::Logger(logger::Call::log_without_filter(42, 1000)));
+			let call = Box::new(Call::Logger(logger::Call::log_without_filter(69, 1000)).into());
+			let call2 = Box::new(Call::Logger(logger::Call::log_without_filter(42, 1000)).into());
 			assert_ok!(
 				Scheduler::schedule_named(system::RawOrigin::Signed(1).into(), 1u32.encode(), 4, None, 127, call)
 			);
@@ -1373,7 +1891,377 @@ mod tests {
 	}
 
 	#[test]
-	fn migration_to_v2_works() {
+	fn retry_scheduling_works() {
+		new_test_ext().execute_with(|| {
+			// Fails until block #8.
+			let call = Call::Logger(logger::Call::log_if_due(42, 1000, 8));
+			assert_eq!(Scheduler::do_schedule(DispatchTime::At(4), None, 127, root(), call.into()).unwrap(), (4, 0));
+			assert_ok!(Scheduler::set_retry(Origin::root(), (4, 0), 3, 2));
+			assert_eq!(Retries::<Test>::get((4, 0)), Some(RetryConfig { total_retries: 3, remaining: 3, period: 2 }));
+
+			run_to_block(4);
+			assert!(logger::log().is_empty());
+			// The failure is recorded under the address of the retry.
+			assert_eq!(Scheduler::last_failure((6, 0)), Some(TaskFailure {
+				task: (4, 0),
+				id: None,
+				error: DispatchError::BadOrigin,
+			}));
+			assert!(Retries::<Test>::get((4, 0)).is_none());
+			assert_eq!(Retries::<Test>::get((6, 0)), Some(RetryConfig { total_retries: 3, remaining: 2, period: 2 }));
+
+			run_to_block(6);
+			assert!(logger::log().is_empty());
+			assert!(Scheduler::last_failure((6, 0)).is_none());
+			assert_eq!(Scheduler::last_failure((8, 0)).unwrap().task, (6, 0));
+
+			run_to_block(8);
+			assert_eq!(logger::log(), vec![(root(), 42u32)]);
+			assert!(Retries::<Test>::get((8, 0)).is_none());
+			// The failure is cleared once the task succeeds.
+			assert_eq!(LastFailure::<Test>::iter().count(), 0);
+
+			run_to_block(100);
+			assert_eq!(logger::log(), vec![(root(), 42u32)]);
+		});
+	}
+
+	#[test]
+	fn retries_run_out() {
+		new_test_ext().execute_with(|| {
+			let call = Call::Logger(logger::Call::log_if_due(42, 1000, 100));
+			assert_ok!(Scheduler::do_schedule(DispatchTime::At(4), None, 127, root(), call.into()));
+			assert_ok!(Scheduler::do_set_retry(None, (4, 0), 2, 1));
+
+			run_to_block(5);
+			assert_eq!(Scheduler::last_failure((6, 0)).unwrap().task, (5, 0));
+			assert_eq!(Retries::<Test>::get((6, 0)).unwrap().remaining, 0);
+
+			run_to_block(6);
+			// Nothing is left once the retries run out.
+			assert_eq!(LastFailure::<Test>::iter().count(), 0);
+			assert_eq!(Retries::<Test>::iter().count(), 0);
+			assert_eq!(Agenda::<Test>::iter().filter(|(_, a)| a.iter().any(Option::is_some)).count(), 0);
+
+			run_to_block(100);
+			assert!(logger::log().is_empty());
+		});
+	}
+
+	#[test]
+	fn named_retry_works() {
+		new_test_ext().execute_with(|| {
+			let signed: OriginCaller = system::RawOrigin::Signed(1).into();
+			let call = Box::new(Call::Logger(logger::Call::log_if_due(69, 1000, 7)).into());
+			assert_ok!(Scheduler::schedule_named(Origin::signed(1), 1u32.encode(), 4, None, 127, call));
+
+			assert_noop!(Scheduler::set_retry_named(Origin::signed(2), 1u32.encode(), 2, 2), BadOrigin);
+			assert_noop!(Scheduler::set_retry_named(Origin::root(), 1u32.encode(), 2, 2), BadOrigin);
+			assert_noop!(
+				Scheduler::set_retry_named(Origin::signed(1), 2u32.encode(), 2, 2),
+				Error::<Test>::NotFound,
+			);
+			assert_noop!(
+				Scheduler::set_retry_named(Origin::signed(1), 1u32.encode(), 0, 2),
+				Error::<Test>::InvalidRetryConfig,
+			);
+			assert_noop!(
+				Scheduler::set_retry_named(Origin::signed(1), 1u32.encode(), 2, 0),
+				Error::<Test>::InvalidRetryConfig,
+			);
+			assert_ok!(Scheduler::set_retry_named(Origin::signed(1), 1u32.encode(), 2, 2));
+
+			// The retry configuration follows the task.
+			assert_noop!(Scheduler::reschedule_named(Origin::root(), 1u32.encode(), 5), BadOrigin);
+			assert_ok!(Scheduler::reschedule_named(Origin::signed(1), 1u32.encode(), 5));
+			assert!(Retries::<Test>::get((4, 0)).is_none());
+			assert!(Retries::<Test>::get((5, 0)).is_some());
+
+			run_to_block(5);
+			assert!(logger::log().is_empty());
+			// The retry keeps the name of the task.
+			assert_eq!(Lookup::<Test>::get(1u32.encode()), Some((7, 0)));
+			assert_eq!(Scheduler::last_failure_named(1u32.encode()), Some(TaskFailure {
+				task: (5, 0),
+				id: Some(1u32.encode()),
+				error: DispatchError::BadOrigin,
+			}));
+
+			run_to_block(7);
+			assert_eq!(logger::log(), vec![(signed, 69u32)]);
+			assert!(Lookup::<Test>::get(1u32.encode()).is_none());
+			assert_eq!(LastFailure::<Test>::iter().count(), 0);
+		});
+	}
+
+	#[test]
+	fn periodic_retry_works() {
+		new_test_ext().execute_with(|| {
+			// Fails until block #6.
+			let call = Call::Logger(logger::Call::log_if_due(42, 1000, 6));
+			assert_ok!(Scheduler::do_schedule_named(
+				1u32.encode(), DispatchTime::At(4), Some((4, 3)), 127, root(), call.into()
+			));
+			assert_ok!(Scheduler::do_set_retry(None, (4, 0), 3, 1));
+
+			run_to_block(4);
+			assert!(logger::log().is_empty());
+			// The next run of the periodic task keeps its name and gets all its retries.
+			assert_eq!(Lookup::<Test>::get(1u32.encode()), Some((8, 0)));
+			assert_eq!(Retries::<Test>::get((8, 0)), Some(RetryConfig { total_retries: 3, remaining: 3, period: 1 }));
+			// The retry is anonymous and not periodic.
+			assert_eq!(Retries::<Test>::get((5, 0)), Some(RetryConfig { total_retries: 3, remaining: 2, period: 1 }));
+			let retry = Agenda::<Test>::get(5)[0].clone().unwrap();
+			assert_eq!((retry.maybe_id, retry.maybe_periodic), (None, None));
+
+			run_to_block(5);
+			assert!(logger::log().is_empty());
+			assert_eq!(Scheduler::last_failure((6, 0)).unwrap().task, (5, 0));
+
+			run_to_block(6);
+			assert_eq!(logger::log(), vec![(root(), 42u32)]);
+
+			run_to_block(8);
+			assert_eq!(logger::log(), vec![(root(), 42u32), (root(), 42u32)]);
+			assert!(Retries::<Test>::get((12, 0)).is_some());
+
+			run_to_block(100);
+			assert_eq!(logger::log(), vec![(root(), 42u32), (root(), 42u32), (root(), 42u32)]);
+			assert_eq!(Retries::<Test>::iter().count(), 0);
+		});
+	}
+
+	#[test]
+	fn cancel_removes_retry() {
+		new_test_ext().execute_with(|| {
+			let call = Call::Logger(logger::Call::log_if_due(42, 1000, 100));
+			assert_ok!(Scheduler::do_schedule(DispatchTime::At(4), None, 127, root(), call.clone().into()));
+			assert_ok!(Scheduler::do_schedule_named(1u32.encode(), DispatchTime::At(4), None, 127, root(), call.into()));
+			assert_ok!(Scheduler::set_retry(Origin::root(), (4, 0), 1, 1));
+			assert_ok!(Scheduler::set_retry(Origin::root(), (4, 1), 1, 1));
+
+			assert_ok!(Scheduler::cancel_retry(Origin::root(), (4, 0)));
+			assert!(Retries::<Test>::get((4, 0)).is_none());
+			assert_noop!(Scheduler::cancel_retry(Origin::root(), (4, 0)), Error::<Test>::NotFound);
+			assert_noop!(Scheduler::cancel_retry(Origin::root(), (4, 2)), Error::<Test>::NotFound);
+
+			assert_ok!(Scheduler::cancel_named(Origin::root(), 1u32.encode()));
+			assert!(Retries::<Test>::get((4, 1)).is_none());
+			assert_noop!(Scheduler::cancel_retry_named(Origin::root(), 1u32.encode()), Error::<Test>::NotFound);
+
+			run_to_block(100);
+			assert_eq!(LastFailure::<Test>::iter().count(), 0);
+			assert_eq!(Retries::<Test>::iter().count(), 0);
+		});
+	}
+
+	#[test]
+	fn cancel_removes_failure() {
+		new_test_ext().execute_with(|| {
+			let call = Call::Logger(logger::Call::log_if_due(42, 1000, 100));
+			assert_ok!(Scheduler::do_schedule(DispatchTime::At(4), None, 127, root(), call.clone().into()));
+			assert_ok!(Scheduler::do_schedule_named(1u32.encode(), DispatchTime::At(4), None, 127, root(), call.into()));
+			assert_ok!(Scheduler::set_retry(Origin::root(), (4, 0), 1, 2));
+			assert_ok!(Scheduler::set_retry(Origin::root(), (4, 1), 1, 2));
+
+			run_to_block(4);
+			assert!(Scheduler::last_failure((6, 0)).is_some());
+			assert!(Scheduler::last_failure_named(1u32.encode()).is_some());
+
+			assert_ok!(Scheduler::cancel(Origin::root(), 6, 0));
+			assert_ok!(Scheduler::cancel_named(Origin::root(), 1u32.encode()));
+			assert_eq!(LastFailure::<Test>::iter().count(), 0);
+		});
+	}
+
+	#[test]
+	fn deferred_tasks_keep_lookup_and_retry() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(Scheduler::do_schedule(
+				DispatchTime::At(4),
+				None,
+				127,
+				root(),
+				Call::Logger(logger::Call::log(42, MaximumSchedulerWeight::get() / 2)).into()
+			));
+			assert_ok!(Scheduler::do_schedule_named(
+				1u32.encode(),
+				DispatchTime::At(4),
+				None,
+				127,
+				root(),
+				Call::Logger(logger::Call::log(69, MaximumSchedulerWeight::get() / 2)).into()
+			));
+			assert_ok!(Scheduler::do_set_retry(None, (4, 1), 1, 1));
+			// 69 is pushed to the next block.
+			run_to_block(4);
+			assert_eq!(logger::log(), vec![(root(), 42u32)]);
+			assert_eq!(Lookup::<Test>::get(1u32.encode()), Some((5, 0)));
+			assert!(Retries::<Test>::get((5, 0)).is_some());
+			run_to_block(5);
+			assert_eq!(logger::log(), vec![(root(), 42u32), (root(), 69u32)]);
+		});
+	}
+
+	#[test]
+	fn agenda_queries_work() {
+		new_test_ext().execute_with(|| {
+			let signed: OriginCaller = system::RawOrigin::Signed(1).into();
+			let call = Call::Logger(logger::Call::log(42, 1000));
+			assert_ok!(Scheduler::do_schedule_named(
+				1u32.encode(), DispatchTime::At(6), None, 127, root(), call.clone().into()
+			));
+			assert_ok!(Scheduler::do_schedule(DispatchTime::At(5), None, 127, signed.clone(), call.clone().into()));
+			assert_ok!(Scheduler::do_schedule(DispatchTime::At(4), Some((10, 2)), 10, root(), call.clone().into()));
+			assert_ok!(Scheduler::do_set_retry(None, (6, 0), 2, 3));
+
+			let named = AgendaItem {
+				when: 6,
+				index: 0,
+				id: Some(1u32.encode()),
+				priority: 127,
+				origin: root(),
+				maybe_periodic: None,
+				retry: Some((2, 3)),
+				call: MaybeHashed::<_, H256>::Value(call.clone()).encode(),
+			};
+			assert_eq!(Scheduler::agenda_by_name(1u32.encode()), Some(named.clone()));
+			assert_eq!(Scheduler::agenda_by_name(2u32.encode()), None);
+
+			let periodic = AgendaItem {
+				when: 4,
+				index: 0,
+				id: None,
+				priority: 10,
+				origin: root(),
+				maybe_periodic: Some((10, 1)),
+				retry: None,
+				call: MaybeHashed::<_, H256>::Value(call.clone()).encode(),
+			};
+			assert_eq!(Scheduler::agenda_by_origin(root()), vec![periodic, named]);
+			assert_eq!(Scheduler::agenda_by_origin(signed).len(), 1);
+			assert!(Scheduler::agenda_by_origin(system::RawOrigin::Signed(2).into()).is_empty());
+
+			run_to_block(6);
+			assert_eq!(Scheduler::agenda_by_name(1u32.encode()), None);
+			assert_eq!(Scheduler::agenda_by_origin(root()).len(), 1);
+		});
+	}
+
+	#[test]
+	fn scheduling_by_hash_works() {
+		new_test_ext().execute_with(|| {
+			let call = Call::Logger(logger::Call::log(42, 1000));
+			let hash = TestPreimages::note(call.encode());
+			assert_ok!(Scheduler::do_schedule(DispatchTime::At(4), Some((3, 2)), 127, root(), MaybeHashed::Hash(hash)));
+			assert_eq!(TestPreimages::requests(&hash), 1);
+			run_to_block(4);
+			assert_eq!(logger::log(), vec![(root(), 42u32)]);
+			// The task keeps the call in full once it is looked up, so the preimage is released.
+			assert_eq!(TestPreimages::requests(&hash), 0);
+			assert!(!TestPreimages::have_preimage(&hash));
+			assert_eq!(Agenda::<Test>::get(7)[0].as_ref().unwrap().call, MaybeHashed::Value(call));
+			run_to_block(7);
+			assert_eq!(logger::log(), vec![(root(), 42u32), (root(), 42u32)]);
+		});
+	}
+
+	#[test]
+	fn missing_preimage_postpones_task() {
+		new_test_ext().execute_with(|| {
+			let call = Call::Logger(logger::Call::log(42, 1000));
+			let hash = BlakeTwo256::hash_of(&call);
+			assert_ok!(Scheduler::do_schedule_named(
+				1u32.encode(), DispatchTime::At(4), None, 127, root(), MaybeHashed::Hash(hash)
+			));
+			assert_ok!(Scheduler::do_set_retry(None, (4, 0), 1, 10));
+			run_to_block(4);
+			assert!(logger::log().is_empty());
+			// The task, its lookup and its retries are postponed by `NoPreimagePostponement`.
+			assert_eq!(Lookup::<Test>::get(1u32.encode()), Some((6, 0)));
+			assert!(Retries::<Test>::get((6, 0)).is_some());
+			assert_eq!(TestPreimages::requests(&hash), 1);
+
+			TestPreimages::note(call.encode());
+			run_to_block(6);
+			assert_eq!(logger::log(), vec![(root(), 42u32)]);
+			assert_eq!(Lookup::<Test>::get(1u32.encode()), None);
+			assert_eq!(TestPreimages::requests(&hash), 0);
+		});
+	}
+
+	#[test]
+	fn bad_preimage_drops_task() {
+		new_test_ext().execute_with(|| {
+			let hash = TestPreimages::note(vec![255u8; 8]);
+			assert_ok!(Scheduler::do_schedule_named(
+				1u32.encode(), DispatchTime::At(4), None, 127, root(), MaybeHashed::Hash(hash)
+			));
+			run_to_block(4);
+			assert!(logger::log().is_empty());
+			assert_eq!(Lookup::<Test>::get(1u32.encode()), None);
+			assert!(Agenda::<Test>::iter().all(|(_, agenda)| agenda.iter().all(Option::is_none)));
+			assert_eq!(TestPreimages::requests(&hash), 0);
+		});
+	}
+
+	#[test]
+	fn cancel_releases_preimage_request() {
+		new_test_ext().execute_with(|| {
+			let call = Call::Logger(logger::Call::log(42, 1000));
+			let hash = TestPreimages::note(call.encode());
+			let address = Scheduler::do_schedule(DispatchTime::At(4), None, 127, root(), MaybeHashed::Hash(hash))
+				.unwrap();
+			assert_ok!(Scheduler::do_schedule_named(
+				1u32.encode(), DispatchTime::At(4), None, 127, root(), MaybeHashed::Hash(hash)
+			));
+			assert_eq!(TestPreimages::requests(&hash), 2);
+			assert_ok!(Scheduler::do_cancel(None, address));
+			assert_eq!(TestPreimages::requests(&hash), 1);
+			assert_ok!(Scheduler::do_cancel_named(None, 1u32.encode()));
+			assert_eq!(TestPreimages::requests(&hash), 0);
+			run_to_block(4);
+			assert!(logger::log().is_empty());
+		});
+	}
+
+	#[test]
+	fn migration_v2_to_v3_works() {
+		new_test_ext().execute_with(|| {
+			StorageVersion::put(Releases::V2);
+			let old = vec![
+				None,
+				Some(ScheduledV2 {
+					maybe_id: Some(b"test".to_vec()),
+					priority: 123,
+					call: Call::Logger(logger::Call::log(69, 1000)),
+					maybe_periodic: Some((456u64, 10)),
+					origin: root(),
+					_phantom: PhantomData::<u64>::default(),
+				}),
+			];
+			frame_support::migration::put_storage_value(b"Scheduler", b"Agenda", &4u64.twox_64_concat(), old);
+
+			assert!(Scheduler::migrate_v2_to_v3());
+			assert!(!Scheduler::migrate_v2_to_v3());
+
+			assert_eq!(Agenda::<Test>::get(4), vec![
+				None,
+				Some(ScheduledV3 {
+					maybe_id: Some(b"test".to_vec()),
+					priority: 123,
+					call: MaybeHashed::Value(Call::Logger(logger::Call::log(69, 1000))),
+					maybe_periodic: Some((456u64, 10)),
+					origin: root(),
+					_phantom: PhantomData::<u64>::default(),
+				}),
+			]);
+			assert_eq!(StorageVersion::get(), Releases::V3);
+		});
+	}
+
+	#[test]
+	fn migration_to_v3_works() {
 		new_test_ext().execute_with(|| {
 			for i in 0..3u64 {
 				let k = i.twox_64_concat();
@@ -1402,25 +2290,25 @@ mod tests {
 
 			assert_eq!(StorageVersion::get(), Releases::V1);
 
-			assert!(Scheduler::migrate_v1_to_t2());
+			assert!(Scheduler::migrate_v1_to_v3());
 
 			assert_eq_uvec!(Agenda::<Test>::iter().collect::<Vec<_>>(), vec![
 				(
 					0,
 					vec![
-					Some(ScheduledV2 {
+					Some(ScheduledV3 {
 						maybe_id: None,
 						priority: 10,
-						call: Call::Logger(logger::Call::log(96, 100)),
+						call: MaybeHashed::Value(Call::Logger(logger::Call::log(96, 100))),
 						maybe_periodic: None,
 						origin: root(),
 						_phantom: PhantomData::<u64>::default(),
 					}),
 					None,
-					Some(ScheduledV2 {
+					Some(ScheduledV3 {
 						maybe_id: Some(b"test".to_vec()),
 						priority: 123,
-						call: Call::Logger(logger::Call::log(69, 1000)),
+						call: MaybeHashed::Value(Call::Logger(logger::Call::log(69, 1000))),
 						maybe_periodic: Some((456u64, 10)),
 						origin: root(),
 						_phantom: PhantomData::<u64>::default(),
@@ -1429,19 +2317,19 @@ mod tests {
 				(
 					1,
 					vec![
-						Some(ScheduledV2 {
+						Some(ScheduledV3 {
 							maybe_id: None,
 							priority: 11,
-							call: Call::Logger(logger::Call::log(96, 100)),
+							call: MaybeHashed::Value(Call::Logger(logger::Call::log(96, 100))),
 							maybe_periodic: None,
 							origin: root(),
 							_phantom: PhantomData::<u64>::default(),
 						}),
 						None,
-						Some(ScheduledV2 {
+						Some(ScheduledV3 {
 							maybe_id: Some(b"test".to_vec()),
 							priority: 123,
-							call: Call::Logger(logger::Call::log(69, 1000)),
+							call: MaybeHashed::Value(Call::Logger(logger::Call::log(69, 1000))),
 							maybe_periodic: Some((456u64, 10)),
 							origin: root(),
 							_phantom: PhantomData::<u64>::default(),
@@ -1451,19 +2339,19 @@ mod tests {
 				(
 					2,
 					vec![
-						Some(ScheduledV2 {
+						Some(ScheduledV3 {
 							maybe_id: None,
 							priority: 12,
-							call: Call::Logger(logger::Call::log(96, 100)),
+							call: MaybeHashed::Value(Call::Logger(logger::Call::log(96, 100))),
 							maybe_periodic: None,
 							origin: root(),
 							_phantom: PhantomData::<u64>::default(),
 						}),
 						None,
-						Some(ScheduledV2 {
+						Some(ScheduledV3 {
 							maybe_id: Some(b"test".to_vec()),
 							priority: 123,
-							call: Call::Logger(logger::Call::log(69, 1000)),
+							call: MaybeHashed::Value(Call::Logger(logger::Call::log(69, 1000))),
 							maybe_periodic: Some((456u64, 10)),
 							origin: root(),
 							_phantom: PhantomData::<u64>::default(),
@@ -1472,7 +2360,7 @@ mod tests {
 				)
 			]);
 
-			assert_eq!(StorageVersion::get(), Releases::V2);
+			assert_eq!(StorageVersion::get(), Releases::V3);
 		});
 	}
 
@@ -1481,11 +2369,11 @@ mod tests {
 		new_test_ext().execute_with(|| {
 			for i in 0..3u64 {
 				let k = i.twox_64_concat();
-				let old: Vec<Option<Scheduled<_, _, u32, u64>>> = vec![
+				let old: Vec<Option<Scheduled<CallOrHashOf<Test>, _, u32, u64>>> = vec![
 					Some(Scheduled {
 						maybe_id: None,
 						priority: i as u8 + 10,
-						call: Call::Logger(logger::Call::log(96, 100)),
+						call: MaybeHashed::Value(Call::Logger(logger::Call::log(96, 100))),
 						origin: 3u32,
 						maybe_periodic: None,
 						_phantom: Default::default(),
@@ -1495,7 +2383,7 @@ mod tests {
 						maybe_id: Some(b"test".to_vec()),
 						priority: 123,
 						origin: 2u32,
-						call: Call::Logger(logger::Call::log(69, 1000)),
+						call: MaybeHashed::Value(Call::Logger(logger::Call::log(69, 1000))),
 						maybe_periodic: Some((456u64, 10)),
 						_phantom: Default::default(),
 					}),
@@ -1524,19 +2412,19 @@ mod tests {
 				(
 					0,
 					vec![
-					Some(ScheduledV2::<_, _, OriginCaller, u64> {
+					Some(ScheduledV3::<_, _, OriginCaller, u64> {
 						maybe_id: None,
 						priority: 10,
-						call: Call::Logger(logger::Call::log(96, 100)),
+						call: MaybeHashed::Value(Call::Logger(logger::Call::log(96, 100))),
 						maybe_periodic: None,
 						origin: system::RawOrigin::Root.into(),
 						_phantom: PhantomData::<u64>::default(),
 					}),
 					None,
-					Some(ScheduledV2 {
+					Some(ScheduledV3 {
 						maybe_id: Some(b"test".to_vec()),
 						priority: 123,
-						call: Call::Logger(logger::Call::log(69, 1000)),
+						call: MaybeHashed::Value(Call::Logger(logger::Call::log(69, 1000))),
 						maybe_periodic: Some((456u64, 10)),
 						origin: system::RawOrigin::None.into(),
 						_phantom: PhantomData::<u64>::default(),
@@ -1545,19 +2433,19 @@ mod tests {
 				(
 					1,
 					vec![
-						Some(ScheduledV2 {
+						Some(ScheduledV3 {
 							maybe_id: None,
 							priority: 11,
-							call: Call::Logger(logger::Call::log(96, 100)),
+							call: MaybeHashed::Value(Call::Logger(logger::Call::log(96, 100))),
 							maybe_periodic: None,
 							origin: system::RawOrigin::Root.into(),
 							_phantom: PhantomData::<u64>::default(),
 						}),
 						None,
-						Some(ScheduledV2 {
+						Some(ScheduledV3 {
 							maybe_id: Some(b"test".to_vec()),
 							priority: 123,
-							call: Call::Logger(logger::Call::log(69, 1000)),
+							call: MaybeHashed::Value(Call::Logger(logger::Call::log(69, 1000))),
 							maybe_periodic: Some((456u64, 10)),
 							origin: system::RawOrigin::None.into(),
 							_phantom: PhantomData::<u64>::default(),
@@ -1567,19 +2455,19 @@ mod tests {
 				(
 					2,
 					vec![
-						Some(ScheduledV2 {
+						Some(ScheduledV3 {
 							maybe_id: None,
 							priority: 12,
-							call: Call::Logger(logger::Call::log(96, 100)),
+							call: MaybeHashed::Value(Call::Logger(logger::Call::log(96, 100))),
 							maybe_periodic: None,
 							origin: system::RawOrigin::Root.into(),
 							_phantom: PhantomData::<u64>::default(),
 						}),
 						None,
-						Some(ScheduledV2 {
+						Some(ScheduledV3 {
 							maybe_id: Some(b"test".to_vec()),
 							priority: 123,
-							call: Call::Logger(logger::Call::log(69, 1000)),
+							call: MaybeHashed::Value(Call::Logger(logger::Call::log(69, 1000))),
 							maybe_periodic: Some((456u64, 10)),
 							origin: system::RawOrigin::None.into(),
 							_phantom: PhantomData::<u64>::default(),
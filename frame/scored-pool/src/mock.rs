@@ -79,6 +79,8 @@ impl frame_system::Trait for Test {
 
 impl pallet_balances::Trait for Test {
 	type MaxLocks = ();
+	type MaxReserves = ();
+	type ReserveIdentifier = [u8; 8];
 	type Balance = u64;
 	type Event = ();
 	type DustRemoval = ();
@@ -216,6 +216,8 @@ impl frame_system::Trait for Test {
 }
 impl pallet_balances::Trait for Test {
 	type MaxLocks = MaxLocks;
+	type MaxReserves = ();
+	type ReserveIdentifier = [u8; 8];
 	type Balance = Balance;
 	type Event = MetaEvent;
 	type DustRemoval = ();
@@ -294,38 +294,56 @@ fn decl_runtime_metadata<'a>(
 	scrate: &'a TokenStream2,
 	extrinsic: &TypePath,
 ) -> TokenStream2 {
-	let modules_tokens = module_declarations
-		.filter_map(|module_declaration| {
-			module_declaration.find_part("Module").map(|_| {
-				let filtered_names: Vec<_> = module_declaration
-					.module_parts()
-					.into_iter()
-					.filter(|part| part.name() != "Module")
-					.map(|part| part.ident())
-					.collect();
-				(module_declaration, filtered_names)
-			})
-		})
-		.map(|(module_declaration, filtered_names)| {
-			let module = &module_declaration.module;
-			let name = &module_declaration.name;
-			let instance = module_declaration
-				.instance
-				.as_ref()
-				.map(|name| quote!(<#name>))
-				.into_iter();
-
-			let index = module_declaration.index;
+	let modules: Vec<_> = module_declarations
+		.filter(|module_declaration| module_declaration.find_part("Module").is_some())
+		.collect();
+	let modules_tokens = modules.iter().map(|module_declaration| {
+		let filtered_names = module_declaration
+			.module_parts()
+			.into_iter()
+			.filter(|part| part.name() != "Module")
+			.map(|part| part.ident());
+		decl_module_metadata(module_declaration, quote!( #(#filtered_names)* ))
+	});
+	// The type aware metadata needs the generics of the parts, to name the `Event` type.
+	let modules_tokens_v13 = modules.iter().map(|module_declaration| {
+		let filtered_parts = module_declaration
+			.module_parts()
+			.into_iter()
+			.filter(|part| part.name() != "Module")
+			.map(|part| {
+				let ident = part.ident();
+				let generics = &part.generics;
+				quote!( #ident #generics )
+			});
+		decl_module_metadata(module_declaration, quote!( #(#filtered_parts)* ))
+	});
 
-			quote!(
-				#module::Module #(#instance)* as #name { index #index } with #(#filtered_names)*,
-			)
-		});
 	quote!(
 		#scrate::impl_runtime_metadata!{
 			for #runtime with modules where Extrinsic = #extrinsic
 				#(#modules_tokens)*
 		}
+
+		#scrate::impl_runtime_metadata_v13!{
+			for #runtime with modules where Extrinsic = #extrinsic
+				#(#modules_tokens_v13)*
+		}
+	)
+}
+
+fn decl_module_metadata(module_declaration: &Module, parts: TokenStream2) -> TokenStream2 {
+	let module = &module_declaration.module;
+	let name = &module_declaration.name;
+	let instance = module_declaration
+		.instance
+		.as_ref()
+		.map(|name| quote!(<#name>))
+		.into_iter();
+	let index = module_declaration.index;
+
+	quote!(
+		#module::Module #(#instance)* as #name { index #index } with #parts,
 	)
 }
 
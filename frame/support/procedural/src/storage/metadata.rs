@@ -15,7 +15,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-//! Implementation of `storage_metadata` on module structure, used by construct_runtime.
+//! Implementation of `storage_metadata` and `ModuleStorageMetadataV13` on module structure, used
+//! by construct_runtime.
 
 use frame_support_procedural_tools::clean_type_string;
 use proc_macro2::TokenStream;
@@ -66,6 +67,54 @@ fn storage_line_metadata_type(scrate: &TokenStream, line: &StorageLineDefExt) ->
 	}
 }
 
+fn storage_line_metadata_type_v13(
+	scrate: &TokenStream,
+	line: &StorageLineDefExt,
+	type_ref: impl Fn(&syn::Type) -> TokenStream,
+) -> TokenStream {
+	let value = type_ref(&line.value_type);
+	match &line.storage_type {
+		StorageLineTypeDef::Simple(_) => {
+			quote!( #scrate::metadata::StorageEntryTypeV13::Plain(#value) )
+		},
+		StorageLineTypeDef::Map(map) => {
+			let hasher = map.hasher.into_metadata();
+			let key = type_ref(&map.key);
+			quote!{
+				#scrate::metadata::StorageEntryTypeV13::Map {
+					hasher: #scrate::metadata::#hasher,
+					key: #key,
+					value: #value,
+				}
+			}
+		},
+		StorageLineTypeDef::DoubleMap(map) => {
+			let hasher1 = map.hasher1.into_metadata();
+			let hasher2 = map.hasher2.into_metadata();
+			let key1 = type_ref(&map.key1);
+			let key2 = type_ref(&map.key2);
+			quote!{
+				#scrate::metadata::StorageEntryTypeV13::DoubleMap {
+					hasher: #scrate::metadata::#hasher1,
+					key1: #key1,
+					key2: #key2,
+					value: #value,
+					key2_hasher: #scrate::metadata::#hasher2,
+				}
+			}
+		},
+	}
+}
+
+/// The types of a storage line which are referenced by its metadata.
+fn storage_line_types(line: &StorageLineDefExt) -> Vec<&syn::Type> {
+	match &line.storage_type {
+		StorageLineTypeDef::Simple(_) => vec![&line.value_type],
+		StorageLineTypeDef::Map(map) => vec![&map.key, &line.value_type],
+		StorageLineTypeDef::DoubleMap(map) => vec![&map.key1, &map.key2, &line.value_type],
+	}
+}
+
 fn default_byte_getter(
 	scrate: &TokenStream,
 	line: &StorageLineDefExt,
@@ -142,8 +191,15 @@ fn default_byte_getter(
 
 pub fn impl_metadata(scrate: &TokenStream, def: &DeclStorageDefExt) -> TokenStream {
 	let mut entries = TokenStream::new();
+	let mut entries_v13 = TokenStream::new();
+	let mut type_info_bounds = TokenStream::new();
 	let mut default_byte_getter_struct_defs = TokenStream::new();
 
+	let runtime_generic = &def.module_runtime_generic;
+	let lazy_type_info = |ty: &syn::Type| quote!(
+		#scrate::metadata::LazyTypeInfo<#ty, #runtime_generic>
+	);
+
 	for line in def.storage_lines.iter() {
 		let str_name = line.name.to_string();
 
@@ -182,8 +238,37 @@ pub fn impl_metadata(scrate: &TokenStream, def: &DeclStorageDefExt) -> TokenStre
 			},
 		};
 
+		let ty_v13 = storage_line_metadata_type_v13(scrate, line, |ty| {
+			let lazy_type_info = lazy_type_info(ty);
+			quote!(
+				registry.register_type(
+					&<#lazy_type_info as #scrate::metadata::MetaTypeOf>::meta_type()
+				)
+			)
+		});
+		for ty in storage_line_types(line) {
+			let lazy_type_info = lazy_type_info(ty);
+			type_info_bounds.extend(quote!( #lazy_type_info: #scrate::metadata::MetaTypeOf, ));
+		}
+
+		let entry_v13 = quote! {
+			#scrate::metadata::StorageEntryMetadataV13 {
+				name: #str_name.into(),
+				modifier: #modifier,
+				ty: #ty_v13,
+				default: #scrate::metadata::DefaultByte::default_byte(
+					&#default_byte_getter_struct_instance
+				),
+				documentation: {
+					let docs: &[&'static str] = &[ #docs ];
+					docs.iter().map(|doc| (*doc).into()).collect()
+				},
+			},
+		};
+
 		default_byte_getter_struct_defs.extend(default_byte_getter_struct_def);
 		entries.extend(entry);
+		entries_v13.extend(entry_v13);
 	}
 
 	let prefix = if let Some(instance) = &def.module_instance {
@@ -204,6 +289,7 @@ pub fn impl_metadata(scrate: &TokenStream, def: &DeclStorageDefExt) -> TokenStre
 	let module_struct = &def.module_struct;
 	let module_impl = &def.module_impl;
 	let where_clause = &def.where_clause;
+	let where_predicates = where_clause.as_ref().map(|w| &w.predicates);
 
 	quote!(
 		#default_byte_getter_struct_defs
@@ -214,5 +300,18 @@ pub fn impl_metadata(scrate: &TokenStream, def: &DeclStorageDefExt) -> TokenStre
 				#store_metadata
 			}
 		}
+
+		impl#module_impl #scrate::metadata::ModuleStorageMetadataV13 for #module_struct
+		where #type_info_bounds #where_predicates
+		{
+			fn storage_metadata_v13(
+				registry: &mut #scrate::type_info::Registry,
+			) -> #scrate::metadata::StorageMetadataV13 {
+				#scrate::metadata::StorageMetadataV13 {
+					prefix: #prefix.into(),
+					entries: #scrate::sp_std::vec![ #entries_v13 ],
+				}
+			}
+		}
 	)
 }
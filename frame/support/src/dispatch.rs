@@ -298,6 +298,12 @@ impl<T> Parameter for T where T: Codec + EncodeLike + Clone + Eq + fmt::Debug {}
 ///   * `fn on_finalize(n: BlockNumber) -> frame_support::weights::Weight` or
 ///   * `fn on_finalize() -> frame_support::weights::Weight`
 ///
+/// * `on_idle`: Executes after all extrinsics of a block have been applied, with the weight left
+/// unused in the block. Using this function will implement the [`OnIdle`](./traits/trait.OnIdle.html)
+/// trait. It must return the weight it consumed, which must not exceed the given remaining weight.
+/// Function signature must be
+/// `fn on_idle(n: BlockNumber, remaining_weight: Weight) -> frame_support::weights::Weight`.
+///
 /// * `offchain_worker`: Executes at the beginning of a block and produces extrinsics for a future block
 ///   upon completion. Using this function will implement the
 ///   [`OffchainWorker`](./traits/trait.OffchainWorker.html) trait.
@@ -332,6 +338,7 @@ macro_rules! decl_module {
 			{}
 			{}
 			{}
+			{}
 			[]
 			$($t)*
 		);
@@ -367,6 +374,7 @@ macro_rules! decl_module {
 			{}
 			{}
 			{}
+			{}
 			[]
 			$($t)*
 		);
@@ -382,6 +390,7 @@ macro_rules! decl_module {
 		{ $( $on_initialize:tt )* }
 		{ $( $on_runtime_upgrade:tt )* }
 		{ $( $on_finalize:tt )* }
+		{ $( $on_idle:tt )* }
 		{ $( $offchain:tt )* }
 		{ $( $constants:tt )* }
 		{ $( $error_type:tt )* }
@@ -400,6 +409,7 @@ macro_rules! decl_module {
 			{ $( $on_initialize )* }
 			{ $( $on_runtime_upgrade )* }
 			{ $( $on_finalize )* }
+			{ $( $on_idle )* }
 			{ $( $offchain )* }
 			{ $( $constants )* }
 			{ $( $error_type )* }
@@ -417,6 +427,7 @@ macro_rules! decl_module {
 		{ $( $on_initialize:tt )* }
 		{ $( $on_runtime_upgrade:tt )* }
 		{ $( $on_finalize:tt )* }
+		{ $( $on_idle:tt )* }
 		{ $( $offchain:tt )* }
 		{ $( $constants:tt )* }
 		{ $( $error_type:tt )* }
@@ -442,6 +453,7 @@ macro_rules! decl_module {
 		{ $( $on_initialize:tt )* }
 		{ $( $on_runtime_upgrade:tt )* }
 		{ $( $on_finalize:tt )* }
+		{ $( $on_idle:tt )* }
 		{ $( $offchain:tt )* }
 		{ $( $constants:tt )* }
 		{ $( $error_type:tt )* }
@@ -463,6 +475,7 @@ macro_rules! decl_module {
 		{ $( $on_initialize:tt )* }
 		{ $( $on_runtime_upgrade:tt )* }
 		{}
+		{ $( $on_idle:tt )* }
 		{ $( $offchain:tt )* }
 		{ $( $constants:tt )* }
 		{ $( $error_type:tt )* }
@@ -483,6 +496,7 @@ macro_rules! decl_module {
 			{
 				fn on_finalize( $( $param_name : $param ),* ) { $( $impl )* }
 			}
+			{ $( $on_idle )* }
 			{ $( $offchain )* }
 			{ $( $constants )* }
 			{ $( $error_type )* }
@@ -501,6 +515,7 @@ macro_rules! decl_module {
 		{ $( $on_initialize:tt )* }
 		{ $( $on_runtime_upgrade:tt )* }
 		{}
+		{ $( $on_idle:tt )* }
 		{ $( $offchain:tt )* }
 		{ $( $constants:tt )* }
 		{ $( $error_type:tt )* }
@@ -528,6 +543,7 @@ macro_rules! decl_module {
 		{ $( $on_initialize:tt )* }
 		{ $( $on_runtime_upgrade:tt )* }
 		{ $( $on_finalize:tt )+ }
+		{ $( $on_idle:tt )* }
 		{ $( $offchain:tt )* }
 		{ $( $constants:tt )* }
 		{ $( $error_type:tt )* }
@@ -540,6 +556,102 @@ macro_rules! decl_module {
 	) => {
 		compile_error!("`on_finalize` can only be passed once as input.");
 	};
+	// Add on_idle
+	(@normalize
+		$(#[$attr:meta])*
+		pub struct $mod_type:ident<
+			$trait_instance:ident: $trait_name:ident$(<I>, I: $instantiable:path $(= $module_default_instance:path)?)?
+		>
+		for enum $call_type:ident where origin: $origin_type:ty, system = $system:ident
+		{ $( $other_where_bounds:tt )* }
+		{ $( $deposit_event:tt )* }
+		{ $( $on_initialize:tt )* }
+		{ $( $on_runtime_upgrade:tt )* }
+		{ $( $on_finalize:tt )* }
+		{}
+		{ $( $offchain:tt )* }
+		{ $( $constants:tt )* }
+		{ $( $error_type:tt )* }
+		{ $( $integrity_test:tt )* }
+		[ $( $dispatchables:tt )* ]
+		$(#[doc = $doc_attr:tt])*
+		fn on_idle(
+			$param_name1:ident : $param1:ty, $param_name2:ident : $param2:ty $(,)?
+		) -> $return:ty { $( $impl:tt )* }
+		$($rest:tt)*
+	) => {
+		$crate::decl_module!(@normalize
+			$(#[$attr])*
+			pub struct $mod_type<$trait_instance: $trait_name$(<I>, I: $instantiable $(= $module_default_instance)?)?>
+			for enum $call_type where origin: $origin_type, system = $system
+			{ $( $other_where_bounds )* }
+			{ $( $deposit_event )* }
+			{ $( $on_initialize )* }
+			{ $( $on_runtime_upgrade )* }
+			{ $( $on_finalize )* }
+			{
+				fn on_idle( $param_name1: $param1, $param_name2: $param2 ) -> $return { $( $impl )* }
+			}
+			{ $( $offchain )* }
+			{ $( $constants )* }
+			{ $( $error_type )* }
+			{ $( $integrity_test)* }
+			[ $( $dispatchables )* ]
+			$($rest)*
+		);
+	};
+	// compile_error for invalid on_idle function signature in decl_module
+	(@normalize
+		$(#[$attr:meta])*
+		pub struct $mod_type:ident<
+			$trait_instance:ident: $trait_name:ident$(<I>, I: $instantiable:path $(= $module_default_instance:path)?)?
+		>
+		for enum $call_type:ident where origin: $origin_type:ty, system = $system:ident
+		{ $( $other_where_bounds:tt )* }
+		{ $( $deposit_event:tt )* }
+		{ $( $on_initialize:tt )* }
+		{ $( $on_runtime_upgrade:tt )* }
+		{ $( $on_finalize:tt )* }
+		{}
+		{ $( $offchain:tt )* }
+		{ $( $constants:tt )* }
+		{ $( $error_type:tt )* }
+		{ $( $integrity_test:tt )* }
+		[ $( $dispatchables:tt )* ]
+		$(#[doc = $doc_attr:tt])*
+		$(#[weight = $weight:expr])?
+		fn on_idle
+		$($rest:tt)*
+	) => {
+		compile_error!(
+			"`on_idle` must take the block number and the remaining weight as parameters and \
+			return the consumed weight: `fn on_idle(n: T::BlockNumber, remaining_weight: Weight) -> Weight`"
+		);
+	};
+	// Compile error on `on_idle` being added a second time.
+	(@normalize
+		$(#[$attr:meta])*
+		pub struct $mod_type:ident<
+			$trait_instance:ident: $trait_name:ident$(<I>, I: $instantiable:path $(= $module_default_instance:path)?)?
+		>
+		for enum $call_type:ident where origin: $origin_type:ty, system = $system:ident
+		{ $( $other_where_bounds:tt )* }
+		{ $( $deposit_event:tt )* }
+		{ $( $on_initialize:tt )* }
+		{ $( $on_runtime_upgrade:tt )* }
+		{ $( $on_finalize:tt )* }
+		{ $( $on_idle:tt )+ }
+		{ $( $offchain:tt )* }
+		{ $( $constants:tt )* }
+		{ $( $error_type:tt )* }
+		{ $( $integrity_test:tt )* }
+		[ $( $dispatchables:tt )* ]
+		$(#[doc = $doc_attr:tt])*
+		fn on_idle
+		$($rest:tt)*
+	) => {
+		compile_error!("`on_idle` can only be passed once as input.");
+	};
 	// compile_error on_runtime_upgrade, without a given weight removed syntax.
 	(@normalize
 		$(#[$attr:meta])*
@@ -552,6 +664,7 @@ macro_rules! decl_module {
 		{ $( $on_initialize:tt )* }
 		{}
 		{ $( $on_finalize:tt )* }
+		{ $( $on_idle:tt )* }
 		{ $( $offchain:tt )* }
 		{ $( $constants:tt )* }
 		{ $( $error_type:tt )* }
@@ -577,6 +690,7 @@ macro_rules! decl_module {
 		{ $( $on_initialize:tt )* }
 		{}
 		{ $( $on_finalize:tt )* }
+		{ $( $on_idle:tt )* }
 		{ $( $offchain:tt )* }
 		{ $( $constants:tt )* }
 		{ $( $error_type:tt )* }
@@ -604,6 +718,7 @@ macro_rules! decl_module {
 		{ $( $on_initialize:tt )* }
 		{}
 		{ $( $on_finalize:tt )* }
+		{ $( $on_idle:tt )* }
 		{ $( $offchain:tt )* }
 		{ $( $constants:tt )* }
 		{ $( $error_type:tt )* }
@@ -624,6 +739,7 @@ macro_rules! decl_module {
 				fn on_runtime_upgrade( $( $param_name : $param ),* ) -> $return { $( $impl )* }
 			}
 			{ $( $on_finalize )* }
+			{ $( $on_idle )* }
 			{ $( $offchain )* }
 			{ $( $constants )* }
 			{ $( $error_type )* }
@@ -644,6 +760,7 @@ macro_rules! decl_module {
 		{ $( $on_initialize:tt )* }
 		{ $( $on_runtime_upgrade:tt )+ }
 		{ $( $on_finalize:tt )* }
+		{ $( $on_idle:tt )* }
 		{ $( $offchain:tt )* }
 		{ $( $constants:tt )* }
 		{ $( $error_type:tt )* }
@@ -667,6 +784,7 @@ macro_rules! decl_module {
 		{ $( $on_initialize:tt )* }
 		{ $( $on_runtime_upgrade:tt )* }
 		{ $( $on_finalize:tt )* }
+		{ $( $on_idle:tt )* }
 		{ $( $offchain:tt )* }
 		{ $( $constants:tt )* }
 		{ $( $error_type:tt )* }
@@ -685,6 +803,7 @@ macro_rules! decl_module {
 			{ $( $on_initialize )* }
 			{ $( $on_runtime_upgrade )* }
 			{ $( $on_finalize )* }
+			{ $( $on_idle )* }
 			{ $( $offchain )* }
 			{ $( $constants )* }
 			{ $( $error_type )* }
@@ -708,6 +827,7 @@ macro_rules! decl_module {
 		{ $( $on_initialize:tt )* }
 		{ $( $on_runtime_upgrade:tt )* }
 		{ $( $on_finalize:tt )* }
+		{ $( $on_idle:tt )* }
 		{ $( $offchain:tt )* }
 		{ $( $constants:tt )* }
 		{ $( $error_type:tt )* }
@@ -731,6 +851,7 @@ macro_rules! decl_module {
 		{}
 		{ $( $on_runtime_upgrade:tt )* }
 		{ $( $on_finalize:tt )* }
+		{ $( $on_idle:tt )* }
 		{ $( $offchain:tt )* }
 		{ $( $constants:tt )* }
 		{ $( $error_type:tt )* }
@@ -756,6 +877,7 @@ macro_rules! decl_module {
 		{}
 		{ $( $on_runtime_upgrade:tt )* }
 		{ $( $on_finalize:tt )* }
+		{ $( $on_idle:tt )* }
 		{ $( $offchain:tt )* }
 		{ $( $constants:tt )* }
 		{ $( $error_type:tt )* }
@@ -783,6 +905,7 @@ macro_rules! decl_module {
 		{}
 		{ $( $on_runtime_upgrade:tt )* }
 		{ $( $on_finalize:tt )* }
+		{ $( $on_idle:tt )* }
 		{ $( $offchain:tt )* }
 		{ $( $constants:tt )* }
 		{ $( $error_type:tt )* }
@@ -803,6 +926,7 @@ macro_rules! decl_module {
 			}
 			{ $( $on_runtime_upgrade )* }
 			{ $( $on_finalize )* }
+			{ $( $on_idle )* }
 			{ $( $offchain )* }
 			{ $( $constants )* }
 			{ $( $error_type )* }
@@ -823,6 +947,7 @@ macro_rules! decl_module {
 		{ $( $on_initialize:tt )+ }
 		{ $( $on_runtime_upgrade:tt )* }
 		{ $( $on_finalize:tt )* }
+		{ $( $on_idle:tt )* }
 		{ $( $offchain:tt )* }
 		{ $( $constants:tt )* }
 		{ $( $error_type:tt )* }
@@ -846,6 +971,7 @@ macro_rules! decl_module {
 		{ $( $on_initialize:tt )* }
 		{ $( $on_runtime_upgrade:tt )* }
 		{ $( $on_finalize:tt )* }
+		{ $( $on_idle:tt )* }
 		{ }
 		{ $( $constants:tt )* }
 		{ $( $error_type:tt )* }
@@ -866,6 +992,7 @@ macro_rules! decl_module {
 			{ $( $on_initialize )* }
 			{ $( $on_runtime_upgrade )* }
 			{ $( $on_finalize )* }
+			{ $( $on_idle )* }
 			{ fn offchain_worker( $( $param_name : $param ),* ) { $( $impl )* } }
 			{ $( $constants )* }
 			{ $( $error_type )* }
@@ -886,6 +1013,7 @@ macro_rules! decl_module {
 		{ $( $on_initialize:tt )* }
 		{ $( $on_runtime_upgrade:tt )* }
 		{ $( $on_finalize:tt )* }
+		{ $( $on_idle:tt )* }
 		{ $( $offchain:tt )+ }
 		{ $( $constants:tt )* }
 		{ $( $error_type:tt )* }
@@ -910,6 +1038,7 @@ macro_rules! decl_module {
 		{ $( $on_initialize:tt )* }
 		{ $( $on_runtime_upgrade:tt )* }
 		{ $( $on_finalize:tt )* }
+		{ $( $on_idle:tt )* }
 		{ $( $offchain:tt )* }
 		{ $( $constants:tt )* }
 		{ $( $error_type:tt )* }
@@ -931,6 +1060,7 @@ macro_rules! decl_module {
 			{ $( $on_initialize )* }
 			{ $( $on_runtime_upgrade )* }
 			{ $( $on_finalize )* }
+			{ $( $on_idle )* }
 			{ $( $offchain )* }
 			{
 				$( $constants )*
@@ -957,6 +1087,7 @@ macro_rules! decl_module {
 		{ $( $on_initialize:tt )* }
 		{ $( $on_runtime_upgrade:tt )* }
 		{ $( $on_finalize:tt )* }
+		{ $( $on_idle:tt )* }
 		{ $( $offchain:tt )* }
 		{ $( $constants:tt )* }
 		{ }
@@ -977,6 +1108,7 @@ macro_rules! decl_module {
 			{ $( $on_initialize )* }
 			{ $( $on_runtime_upgrade )* }
 			{ $( $on_finalize )* }
+			{ $( $on_idle )* }
 			{ $( $offchain )* }
 			{ $( $constants )* }
 			{ $error_type }
@@ -998,6 +1130,7 @@ macro_rules! decl_module {
 		{ $( $on_initialize:tt )* }
 		{ $( $on_runtime_upgrade:tt )* }
 		{ $( $on_finalize:tt )* }
+		{ $( $on_idle:tt )* }
 		{ $( $offchain:tt )* }
 		{ $( $constants:tt )* }
 		{ }
@@ -1016,6 +1149,7 @@ macro_rules! decl_module {
 			{ $( $on_initialize )* }
 			{ $( $on_runtime_upgrade )* }
 			{ $( $on_finalize )* }
+			{ $( $on_idle )* }
 			{ $( $offchain )* }
 			{ $( $constants )* }
 			{ &'static str }
@@ -1038,6 +1172,7 @@ macro_rules! decl_module {
 		{ $( $on_initialize:tt )* }
 		{ $( $on_runtime_upgrade:tt )* }
 		{ $( $on_finalize:tt )* }
+		{ $( $on_idle:tt )* }
 		{ $( $offchain:tt )* }
 		{ $( $constants:tt )* }
 		{ $error_type:ty }
@@ -1062,6 +1197,7 @@ macro_rules! decl_module {
 			{ $( $on_initialize )* }
 			{ $( $on_runtime_upgrade )* }
 			{ $( $on_finalize )* }
+			{ $( $on_idle )* }
 			{ $( $offchain )* }
 			{ $( $constants )* }
 			{ $error_type }
@@ -1092,6 +1228,7 @@ macro_rules! decl_module {
 		{ $( $on_initialize:tt )* }
 		{ $( $on_runtime_upgrade:tt )* }
 		{ $( $on_finalize:tt )* }
+		{ $( $on_idle:tt )* }
 		{ $( $offchain:tt )* }
 		{ $( $constants:tt )* }
 		{ $( $error_type:tt )* }
@@ -1120,6 +1257,7 @@ macro_rules! decl_module {
 		{ $( $on_initialize:tt )* }
 		{ $( $on_runtime_upgrade:tt )* }
 		{ $( $on_finalize:tt )* }
+		{ $( $on_idle:tt )* }
 		{ $( $offchain:tt )* }
 		{ $( $constants:tt )* }
 		{ $( $error_type:tt )* }
@@ -1148,6 +1286,7 @@ macro_rules! decl_module {
 		{ $( $on_initialize:tt )* }
 		{ $( $on_runtime_upgrade:tt )* }
 		{ $( $on_finalize:tt )* }
+		{ $( $on_idle:tt )* }
 		{ $( $offchain:tt )* }
 		{ $( $constants:tt )* }
 		{ $( $error_type:tt )* }
@@ -1176,6 +1315,7 @@ macro_rules! decl_module {
 		{ $( $on_initialize:tt )* }
 		{ $( $on_runtime_upgrade:tt )* }
 		{ $( $on_finalize:tt )* }
+		{ $( $on_idle:tt )* }
 		{ $( $offchain:tt )* }
 		{ $( $constants:tt )* }
 		{ $( $error_type:tt )* }
@@ -1205,6 +1345,7 @@ macro_rules! decl_module {
 		{ $( $on_initialize:tt )* }
 		{ $( $on_runtime_upgrade:tt )* }
 		{ $( $on_finalize:tt )* }
+		{ $( $on_idle:tt )* }
 		{ $( $offchain:tt )* }
 		{ $( $constants:tt )* }
 		{ $( $error_type:tt )* }
@@ -1222,6 +1363,7 @@ macro_rules! decl_module {
 			{ $( $on_initialize )* }
 			{ $( $on_runtime_upgrade )* }
 			{ $( $on_finalize )* }
+			{ $( $on_idle )* }
 			{ $( $offchain )* }
 			{ $( $constants )* }
 			{ $( $error_type )* }
@@ -1410,6 +1552,36 @@ macro_rules! decl_module {
 		}
 	};
 
+	(@impl_on_idle
+		{ $system:ident }
+		$module:ident<$trait_instance:ident: $trait_name:ident$(<I>, $instance:ident: $instantiable:path)?>;
+		{ $( $other_where_bounds:tt )* }
+		fn on_idle($param1:ident : $param1_ty:ty, $param2:ident : $param2_ty:ty) -> $return:ty {
+			$( $impl:tt )*
+		}
+	) => {
+		impl<$trait_instance: $system::Trait + $trait_name$(<I>, $instance: $instantiable)?>
+			$crate::traits::OnIdle<<$trait_instance as $system::Trait>::BlockNumber>
+			for $module<$trait_instance$(, $instance)?> where $( $other_where_bounds )*
+		{
+			fn on_idle($param1: $param1_ty, $param2: $param2_ty) -> $return {
+				$crate::sp_tracing::enter_span!($crate::sp_tracing::trace_span!("on_idle"));
+				{ $( $impl )* }
+			}
+		}
+	};
+
+	(@impl_on_idle
+		{ $system:ident }
+		$module:ident<$trait_instance:ident: $trait_name:ident$(<I>, $instance:ident: $instantiable:path)?>;
+		{ $( $other_where_bounds:tt )* }
+	) => {
+		impl<$trait_instance: $system::Trait + $trait_name$(<I>, $instance: $instantiable)?>
+			$crate::traits::OnIdle<<$trait_instance as $system::Trait>::BlockNumber>
+			for $module<$trait_instance$(, $instance)?> where $( $other_where_bounds )*
+		{}
+	};
+
 	(@impl_offchain
 		{ $system:ident }
 		$module:ident<$trait_instance:ident: $trait_name:ident$(<I>, $instance:ident: $instantiable:path)?>;
@@ -1626,6 +1798,7 @@ macro_rules! decl_module {
 		{ $( $on_initialize:tt )* }
 		{ $( $on_runtime_upgrade:tt )* }
 		{ $( $on_finalize:tt )* }
+		{ $( $on_idle:tt )* }
 		{ $( $offchain:tt )* }
 		{ $( $constants:tt )* }
 		{ $error_type:ty }
@@ -1665,6 +1838,14 @@ macro_rules! decl_module {
 			$( $on_finalize )*
 		}
 
+		$crate::decl_module! {
+			@impl_on_idle
+			{ $system }
+			$mod_type<$trait_instance: $trait_name $(<I>, $instance: $instantiable)?>;
+			{ $( $other_where_bounds )* }
+			$( $on_idle )*
+		}
+
 		$crate::decl_module! {
 			@impl_offchain
 			{ $system }
@@ -1884,6 +2065,17 @@ macro_rules! decl_module {
 				)*
 			}
 		}
+		$crate::__dispatch_impl_metadata_v13! {
+			$mod_type<$trait_instance: $trait_name $(<I>, $instance: $instantiable)?>
+			{ $( $other_where_bounds )* }
+			$call_type
+			{
+				$(
+					$(#[doc = $doc_attr])*
+					fn $fn_name($( $(#[$codec_attr])* $param_name : $param ),*);
+				)*
+			}
+		}
 		$crate::__impl_module_constants_metadata ! {
 			$mod_type<$trait_instance: $trait_name $(<I>, $instance: $instantiable)?>
 			{ $( $other_where_bounds )* }
@@ -1897,6 +2089,18 @@ macro_rules! decl_module {
 				<$error_type as $crate::dispatch::ModuleErrorMetadata>::metadata()
 			}
 		}
+
+		impl<$trait_instance: $trait_name $(<I>, $instance: $instantiable)?> $mod_type<$trait_instance $(, $instance)?>
+			where $( $other_where_bounds )*
+		{
+			#[doc(hidden)]
+			#[allow(dead_code)]
+			pub fn module_error_type_v13() -> Option<$crate::type_info::MetaType>
+				where $error_type: $crate::metadata::ModuleErrorTypeInfo
+			{
+				<$error_type as $crate::metadata::ModuleErrorTypeInfo>::error_type()
+			}
+		}
 	}
 }
 
@@ -1917,7 +2121,13 @@ macro_rules! impl_outer_dispatch {
 			$crate::codec::Encode,
 			$crate::codec::Decode,
 			$crate::RuntimeDebug,
+			$crate::type_info::TypeInfo,
 		)]
+		// The bounds are higher-ranked so that they are only checked where the type information
+		// is used, see `impl_runtime_metadata_v13!`.
+		#[scale_info(bounds($(
+			for<'a> $crate::dispatch::CallableCallFor<$camelcase, $runtime>: $crate::type_info::TypeInfo,
+		)*))]
 		pub enum $call_type {
 			$(
 				$( #[codec(index = $index)] )?
@@ -2072,6 +2282,143 @@ macro_rules! __dispatch_impl_metadata {
 	}
 }
 
+/// Implement the type aware metadata for dispatch and the type information of the `Call` enum.
+#[macro_export]
+#[doc(hidden)]
+macro_rules! __dispatch_impl_metadata_v13 {
+	(
+		$mod_type:ident<$trait_instance:ident: $trait_name:ident$(<I>, $instance:ident: $instantiable:path)?>
+		{ $( $other_where_bounds:tt )* }
+		$call_type:ident
+		{
+			$(
+				$(#[doc = $doc_attr:tt])*
+				fn $fn_name:ident( $( $(#[$codec_attr:ident])* $param_name:ident : $param:ty ),* );
+			)*
+		}
+	) => {
+		impl<$trait_instance: $trait_name $(<I>, $instance: $instantiable)?> $crate::metadata::ModuleCallMetadataV13
+			for $mod_type<$trait_instance $(, $instance)?>
+		where
+			$( $(
+				$crate::metadata::LazyTypeInfo<
+					$crate::__function_to_metadata_v13!(@arg_type $(#[$codec_attr])* $param),
+					$trait_instance,
+				>: $crate::metadata::MetaTypeOf,
+			)* )*
+			$( $other_where_bounds )*
+		{
+			fn call_functions_v13(
+				registry: &mut $crate::type_info::Registry,
+			) -> $crate::dispatch::Vec<$crate::metadata::FunctionMetadataV13> {
+				$crate::sp_std::vec![
+					$(
+						$crate::metadata::FunctionMetadataV13 {
+							name: stringify!($fn_name).into(),
+							arguments: $crate::sp_std::vec![
+								$(
+									$crate::metadata::FunctionArgumentMetadataV13 {
+										name: stringify!($param_name).into(),
+										ty: registry.register_type(&<
+											$crate::metadata::LazyTypeInfo<
+												$crate::__function_to_metadata_v13!(
+													@arg_type $(#[$codec_attr])* $param
+												),
+												$trait_instance,
+											> as $crate::metadata::MetaTypeOf
+										>::meta_type()),
+									}
+								),*
+							],
+							documentation: $crate::sp_std::vec![ $( $doc_attr.into() ),* ],
+						}
+					),*
+				]
+			}
+		}
+
+		impl<$trait_instance: $trait_name $(<I>, $instance: $instantiable)?> $crate::type_info::TypeInfo
+			for $call_type<$trait_instance $(, $instance)?>
+		where
+			$trait_instance: 'static,
+			$( $instance: 'static, )?
+			$( $(
+				$crate::metadata::LazyTypeInfo<
+					$crate::__function_to_metadata_v13!(@arg_type $(#[$codec_attr])* $param),
+					$trait_instance,
+				>: $crate::metadata::MetaTypeOf,
+			)* )*
+			$( $other_where_bounds )*
+		{
+			type Identity = Self;
+
+			fn type_info() -> $crate::type_info::Type {
+				let variants: &[(&'static str, $crate::dispatch::Vec<$crate::type_info::Field>, &[&'static str])] = &[
+					$(
+						(
+							stringify!($fn_name),
+							$crate::sp_std::vec![
+								$(
+									$crate::type_info::Field {
+										name: Some(stringify!($param_name)),
+										ty: <
+											$crate::metadata::LazyTypeInfo<
+												$crate::__function_to_metadata_v13!(
+													@arg_type $(#[$codec_attr])* $param
+												),
+												$trait_instance,
+											> as $crate::metadata::MetaTypeOf
+										>::meta_type(),
+										type_name: Some(stringify!($param)),
+										docs: $crate::sp_std::vec::Vec::new(),
+									}
+								),*
+							],
+							&[ $( $doc_attr ),* ],
+						)
+					),*
+				];
+
+				$crate::type_info::Type::new(
+					$crate::type_info::Path::new(stringify!($call_type), module_path!()),
+					$crate::sp_std::vec![
+						$crate::type_info::TypeParameter::new(stringify!($trait_instance), None)
+						$(, $crate::type_info::TypeParameter::new(stringify!($instance), None) )?
+					],
+					$crate::type_info::TypeDef::Variant(
+						variants.iter()
+							.enumerate()
+							.map(|(index, (name, fields, docs))| $crate::type_info::Variant::new(
+								*name,
+								fields.clone(),
+								index as u8,
+								docs.to_vec(),
+							))
+							.collect()
+					),
+					$crate::sp_std::vec::Vec::new(),
+				)
+			}
+		}
+	}
+}
+
+/// Resolve the type of a function argument as it is encoded in a call.
+#[macro_export]
+#[doc(hidden)]
+macro_rules! __function_to_metadata_v13 {
+	(@arg_type #[compact] $param:ty) => {
+		$crate::codec::Compact<$param>
+	};
+	(@arg_type $param:ty) => { $param };
+	(@arg_type $(#[$codec_attr:ident])* $param:ty) => {
+		compile_error!(concat!(
+			"Invalid attribute for parameter of type `", stringify!($param),
+			"`, the following attributes are supported: `#[compact]`"
+		));
+	}
+}
+
 /// Implement metadata for module constants.
 #[macro_export]
 #[doc(hidden)]
@@ -2192,6 +2539,36 @@ macro_rules! __impl_module_constants_metadata {
 				]
 			}
 		}
+
+		impl<$trait_instance: 'static + $trait_name $(<I>, $instance: $instantiable)?>
+			$crate::metadata::ModuleConstantsMetadataV13 for $mod_type<$trait_instance $(, $instance)?>
+		where
+			$(
+				$crate::metadata::LazyTypeInfo<$type, $trait_instance>: $crate::metadata::MetaTypeOf,
+			)*
+			$( $other_where_bounds )*
+		{
+			fn module_constants_metadata_v13(
+				registry: &mut $crate::type_info::Registry,
+			) -> $crate::dispatch::Vec<$crate::metadata::ModuleConstantMetadataV13> {
+				$crate::sp_std::vec![
+					$(
+						$crate::metadata::ModuleConstantMetadataV13 {
+							name: stringify!($name).into(),
+							ty: registry.register_type(&<
+								$crate::metadata::LazyTypeInfo<$type, $trait_instance>
+									as $crate::metadata::MetaTypeOf
+							>::meta_type()),
+							value: {
+								let value: $type = $value;
+								$crate::dispatch::Encode::encode(&value)
+							},
+							documentation: $crate::sp_std::vec![ $( $doc_attr.into() ),* ],
+						}
+					),*
+				]
+			}
+		}
 	}
 }
 
@@ -2311,6 +2688,9 @@ macro_rules! __check_reserved_fn_name {
 	(on_finalize $( $rest:ident )*) => {
 		$crate::__check_reserved_fn_name!(@compile_error on_finalize);
 	};
+	(on_idle $( $rest:ident )*) => {
+		$crate::__check_reserved_fn_name!(@compile_error on_idle);
+	};
 	(offchain_worker $( $rest:ident )*) => {
 		$crate::__check_reserved_fn_name!(@compile_error offchain_worker);
 	};
@@ -2352,8 +2732,8 @@ mod tests {
 	use super::*;
 	use crate::weights::{DispatchInfo, DispatchClass, Pays};
 	use crate::traits::{
-		CallMetadata, GetCallMetadata, GetCallName, OnInitialize, OnFinalize, OnRuntimeUpgrade,
-		IntegrityTest,
+		CallMetadata, GetCallMetadata, GetCallName, OnInitialize, OnIdle, OnFinalize,
+		OnRuntimeUpgrade, IntegrityTest,
 	};
 
 	pub trait Trait: system::Trait + Sized where Self::AccountId: From<u32> { }
@@ -2413,6 +2793,10 @@ mod tests {
 			fn operational(_origin) { unreachable!() }
 
 			fn on_initialize(n: T::BlockNumber,) -> Weight { if n.into() == 42 { panic!("on_initialize") } 7 }
+			fn on_idle(n: T::BlockNumber, remaining_weight: Weight) -> Weight {
+				if n.into() == 42 { panic!("on_idle") }
+				remaining_weight.min(5)
+			}
 			fn on_finalize(n: T::BlockNumber,) { if n.into() == 42 { panic!("on_finalize") } }
 			fn on_runtime_upgrade() -> Weight { 10 }
 			fn offchain_worker() {}
@@ -2557,6 +2941,18 @@ mod tests {
 		assert_eq!(<Module<TraitImpl> as OnInitialize<u32>>::on_initialize(10), 7);
 	}
 
+	#[test]
+	#[should_panic(expected = "on_idle")]
+	fn on_idle_should_work_1() {
+		<Module<TraitImpl> as OnIdle<u32>>::on_idle(42, 9);
+	}
+
+	#[test]
+	fn on_idle_should_work_2() {
+		assert_eq!(<Module<TraitImpl> as OnIdle<u32>>::on_idle(10, 9), 5);
+		assert_eq!(<Module<TraitImpl> as OnIdle<u32>>::on_idle(10, 3), 3);
+	}
+
 	#[test]
 	#[should_panic(expected = "on_finalize")]
 	fn on_finalize_should_work() {
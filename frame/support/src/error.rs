@@ -177,6 +177,47 @@ macro_rules! decl_error {
 				]
 			}
 		}
+
+		impl<$generic: $trait + 'static $(, $inst_generic: $instance + 'static)?> $crate::type_info::TypeInfo
+			for $error<$generic $(, $inst_generic)?>
+		$( where $( $where_ty: $where_bound )* )?
+		{
+			type Identity = Self;
+
+			fn type_info() -> $crate::type_info::Type {
+				let variants: &[(&'static str, &[&'static str])] = &[
+					$( (stringify!($name), &[ $( $doc_attr ),* ]) ),*
+				];
+				$crate::type_info::Type::new(
+					$crate::type_info::Path::new(stringify!($error), module_path!()),
+					$crate::sp_std::vec![
+						$crate::type_info::TypeParameter::new(stringify!($generic), None)
+						$(, $crate::type_info::TypeParameter::new(stringify!($inst_generic), None) )?
+					],
+					$crate::type_info::TypeDef::Variant(
+						variants.iter()
+							.enumerate()
+							.map(|(index, (name, docs))| $crate::type_info::Variant::new(
+								*name,
+								$crate::sp_std::vec::Vec::new(),
+								index as u8,
+								docs.to_vec(),
+							))
+							.collect()
+					),
+					$crate::sp_std::vec::Vec::new(),
+				)
+			}
+		}
+
+		impl<$generic: $trait + 'static $(, $inst_generic: $instance + 'static)?> $crate::metadata::ModuleErrorTypeInfo
+			for $error<$generic $(, $inst_generic)?>
+		$( where $( $where_ty: $where_bound )* )?
+		{
+			fn error_type() -> Option<$crate::type_info::MetaType> {
+				Some($crate::type_info::MetaType::new::<Self>())
+			}
+		}
 	};
 	(@GENERATE_AS_U8
 		$self:ident
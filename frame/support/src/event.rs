@@ -145,6 +145,9 @@ macro_rules! decl_event {
 				$crate::__events_to_metadata!(; $( $events )* )
 			}
 		}
+		$crate::__impl_events_metadata_v13!(
+			registry; {} { Event }; {}; {}; {}; $( $events )*
+		);
 	}
 }
 
@@ -293,6 +296,14 @@ macro_rules! __decl_generic_event {
 				$crate::__events_to_metadata!(; $( $events )* )
 			}
 		}
+		$crate::__impl_events_metadata_v13!(
+			registry;
+			{ $( $generic_param ),* $(, $instance)? } { RawEvent };
+			{};
+			{};
+			{};
+			$( $events )*
+		);
 	};
 	(@cannot_parse $ty:ty) => {
 		compile_error!(concat!("The type `", stringify!($ty), "` can't be parsed as an unnamed one, please name it `Name = ", stringify!($ty), "`"));
@@ -329,6 +340,115 @@ macro_rules! __events_to_metadata {
 	}
 }
 
+#[macro_export]
+#[doc(hidden)]
+macro_rules! __impl_events_metadata_v13 {
+	(
+		$registry:ident;
+		{ $( $generics:ident ),* } { $event_name:ident };
+		{ $( $arg_type:path, )* };
+		{ $( $metadata:expr, )* };
+		{ $( $variant:expr, )* };
+		$( #[doc = $doc_attr:tt] )*
+		$event:ident $( ( $( $param:path ),* $(,)? ) )*,
+		$( $rest:tt )*
+	) => {
+		$crate::__impl_events_metadata_v13!(
+			$registry;
+			{ $( $generics ),* } { $event_name };
+			{ $( $arg_type, )* $( $( $param, )* )* };
+			{
+				$( $metadata, )*
+				$crate::metadata::EventMetadataV13 {
+					name: stringify!($event).into(),
+					arguments: $crate::sp_std::vec![
+						$( $(
+							$registry.register_type(&<
+								$crate::metadata::LazyTypeInfo<$param, Context>
+									as $crate::metadata::MetaTypeOf
+							>::meta_type())
+						),* )*
+					],
+					documentation: $crate::sp_std::vec![ $( $doc_attr.into() ),* ],
+				},
+			};
+			{
+				$( $variant, )*
+				(
+					stringify!($event),
+					$crate::sp_std::vec![
+						$( $(
+							$crate::type_info::Field {
+								name: None,
+								ty: $crate::type_info::MetaType::new::<$param>(),
+								type_name: Some(stringify!($param)),
+								docs: $crate::sp_std::vec::Vec::new(),
+							}
+						),* )*
+					],
+					&[ $( $doc_attr ),* ],
+				),
+			};
+			$( $rest )*
+		);
+	};
+	(
+		$registry:ident;
+		{ $( $generics:ident ),* } { $event_name:ident };
+		{ $( $arg_type:path, )* };
+		{ $( $metadata:expr, )* };
+		{ $( $variant:expr, )* };
+	) => {
+		impl<Context $(, $generics )*> $crate::metadata::ModuleEventMetadataV13<Context>
+			for $event_name<$( $generics ),*>
+		where
+			$( $crate::metadata::LazyTypeInfo<$arg_type, Context>: $crate::metadata::MetaTypeOf, )*
+		{
+			fn metadata_v13(
+				$registry: &mut $crate::type_info::Registry,
+			) -> $crate::sp_std::vec::Vec<$crate::metadata::EventMetadataV13> {
+				$crate::sp_std::vec![ $( $metadata ),* ]
+			}
+		}
+
+		// The bounds are higher-ranked so that they are only checked where the type information
+		// is used, and not for every event whose arguments don't implement `TypeInfo`.
+		impl<$( $generics: 'static ),*> $crate::type_info::TypeInfo for $event_name<$( $generics ),*>
+		where
+			$( for<'a> $arg_type: $crate::type_info::TypeInfo, )*
+		{
+			type Identity = Self;
+
+			fn type_info() -> $crate::type_info::Type {
+				let variants: &[(
+					&'static str,
+					$crate::sp_std::vec::Vec<$crate::type_info::Field>,
+					&[&'static str],
+				)] = &[ $( $variant ),* ];
+
+				$crate::type_info::Type::new(
+					$crate::type_info::Path::new(stringify!($event_name), module_path!()),
+					$crate::sp_std::vec![
+						$( $crate::type_info::TypeParameter::new(stringify!($generics), None) ),*
+					],
+					$crate::type_info::TypeDef::Variant(
+						variants.iter()
+							.enumerate()
+							.map(|(index, (name, fields, docs))| $crate::type_info::Variant::new(
+								*name,
+								fields.clone(),
+								index as u8,
+								docs.to_vec(),
+							))
+							.collect()
+					),
+					$crate::sp_std::vec::Vec::new(),
+				)
+			}
+		}
+	};
+}
+
 /// Constructs an Event type for a runtime. This is usually called automatically by the
 /// construct_runtime macro.
 #[macro_export]
@@ -447,7 +567,14 @@ macro_rules! impl_outer_event {
 				$crate::codec::Encode,
 				$crate::codec::Decode,
 				$crate::RuntimeDebug,
+				$crate::type_info::TypeInfo,
 			)]
+			// The bounds are higher-ranked so that they are only checked where the type
+			// information is used, see `impl_runtime_metadata_v13!`.
+			#[scale_info(bounds($(
+				for<'a> $module_name::Event < $( $generic_param )? $(, $module_name::$generic_instance )? >:
+					$crate::type_info::TypeInfo,
+			)*))]
 			$(#[$attr])*
 			#[allow(non_camel_case_types)]
 			pub enum $name {
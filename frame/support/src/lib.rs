@@ -35,6 +35,7 @@ pub use sp_core::Void;
 pub use sp_std;
 #[doc(hidden)]
 pub use codec;
+pub use sp_type_info as type_info;
 #[cfg(feature = "std")]
 #[doc(hidden)]
 pub use once_cell;
@@ -721,6 +722,69 @@ mod tests {
 		});
 	}
 
+	#[test]
+	fn double_map_remove_prefix_with_limit_should_work() {
+		new_test_ext().execute_with(|| {
+			type DoubleMap = DataDM;
+
+			let key1 = 17u32;
+			for key2 in 0..5u32 {
+				DoubleMap::insert(&key1, &key2, &4u64);
+			}
+			DoubleMap::insert(&(key1 + 1), &0u32, &4u64);
+
+			let result = DoubleMap::remove_prefix_with_limit(&key1, 3);
+			assert_eq!(result.removed, 3);
+			assert!(!result.all_removed());
+			assert_eq!(DoubleMap::iter_prefix_values(&key1).count(), 2);
+
+			let cursor = result.maybe_cursor.unwrap();
+			let result = storage::unhashed::kill_prefix_from_cursor(cursor, 3);
+			assert_eq!(result.removed, 2);
+			assert!(result.all_removed());
+			assert_eq!(DoubleMap::iter_prefix_values(&key1).count(), 0);
+
+			assert_eq!(DoubleMap::get(&(key1 + 1), &0u32), 4u64);
+		});
+	}
+
+	#[test]
+	fn kill_prefix_from_cursor_removes_all_remaining_keys() {
+		new_test_ext().execute_with(|| {
+			use storage::unhashed;
+
+			for key in &[b"ab1", b"ab2", b"ab3", b"ab4"] {
+				unhashed::put_raw(&key[..], b"v");
+			}
+			unhashed::put_raw(b"ac", b"v");
+
+			let result = unhashed::kill_prefix_with_limit(b"ab", 0);
+			assert_eq!(result.removed, 0);
+			let cursor = result.maybe_cursor.unwrap();
+
+			let result = unhashed::kill_prefix_from_cursor(cursor, 2);
+			assert_eq!(result.removed, 2);
+			let cursor = result.maybe_cursor.unwrap();
+			assert!(!unhashed::exists(b"ab1"));
+			assert!(!unhashed::exists(b"ab2"));
+
+			// A key inserted in the meantime is removed as well, so that no key remains once the
+			// cursor is gone.
+			unhashed::put_raw(b"ab0", b"v");
+			let result = unhashed::kill_prefix_from_cursor(cursor, 2);
+			assert_eq!(result.removed, 2);
+			let cursor = result.maybe_cursor.unwrap();
+			assert!(!unhashed::exists(b"ab0"));
+			assert!(unhashed::exists(b"ab4"));
+
+			let result = unhashed::kill_prefix_from_cursor(cursor, 2);
+			assert_eq!(result.removed, 1);
+			assert!(result.all_removed());
+			assert!(!unhashed::exists(b"ab4"));
+			assert!(unhashed::exists(b"ac"));
+		});
+	}
+
 	#[test]
 	fn double_map_append_should_work() {
 		new_test_ext().execute_with(|| {
@@ -19,8 +19,70 @@ pub use frame_metadata::{
 	DecodeDifferent, FnEncode, RuntimeMetadata, ModuleMetadata, RuntimeMetadataLastVersion,
 	DefaultByteGetter, RuntimeMetadataPrefixed, StorageEntryMetadata, StorageMetadata,
 	StorageEntryType, StorageEntryModifier, DefaultByte, StorageHasher, ModuleErrorMetadata,
-	ExtrinsicMetadata,
+	ExtrinsicMetadata, RuntimeMetadataV13, ModuleMetadataV13, FunctionMetadataV13,
+	FunctionArgumentMetadataV13, EventMetadataV13, StorageMetadataV13, StorageEntryMetadataV13,
+	StorageEntryTypeV13, ModuleConstantMetadataV13,
 };
+use crate::type_info::{MetaType, Registry, TypeInfo};
+use sp_std::{marker::PhantomData, vec::Vec};
+
+/// Gives access to the type information of `T` in the type aware metadata of a module.
+///
+/// The generated implementations of the `*MetadataV13` traits require
+/// `LazyTypeInfo<T, Context>: MetaTypeOf` instead of `T: TypeInfo`, with a generic parameter
+/// of the implementation, usually the module's runtime, as `Context`. This way the requirement
+/// is only checked where the metadata is used, even if `T` is not generic.
+pub struct LazyTypeInfo<T: ?Sized, Context>(PhantomData<Context>, PhantomData<T>);
+
+/// Resolves the [`MetaType`] of a type, see [`LazyTypeInfo`].
+pub trait MetaTypeOf {
+	/// The reference to the type information of the type.
+	fn meta_type() -> MetaType;
+}
+
+impl<T: TypeInfo + ?Sized, Context> MetaTypeOf for LazyTypeInfo<T, Context> {
+	fn meta_type() -> MetaType {
+		MetaType::new::<T>()
+	}
+}
+
+/// The type information of the errors of a module.
+pub trait ModuleErrorTypeInfo {
+	/// The reference to the type information of the error enum, if the module declares one.
+	fn error_type() -> Option<MetaType>;
+}
+
+impl ModuleErrorTypeInfo for &'static str {
+	fn error_type() -> Option<MetaType> {
+		None
+	}
+}
+
+/// The type aware metadata of the events of a module.
+///
+/// `Context` is the context of the [`LazyTypeInfo`] bounds on the event arguments.
+pub trait ModuleEventMetadataV13<Context> {
+	/// Returns the metadata of all the events, registering their arguments in `registry`.
+	fn metadata_v13(registry: &mut Registry) -> Vec<EventMetadataV13>;
+}
+
+/// The type aware metadata of the calls of a module, implemented by `decl_module!`.
+pub trait ModuleCallMetadataV13 {
+	/// Returns the metadata of all the calls, registering their arguments in `registry`.
+	fn call_functions_v13(registry: &mut Registry) -> Vec<FunctionMetadataV13>;
+}
+
+/// The type aware metadata of the constants of a module, implemented by `decl_module!`.
+pub trait ModuleConstantsMetadataV13 {
+	/// Returns the metadata of all the constants, registering their types in `registry`.
+	fn module_constants_metadata_v13(registry: &mut Registry) -> Vec<ModuleConstantMetadataV13>;
+}
+
+/// The type aware metadata of the storage of a module, implemented by `decl_storage!`.
+pub trait ModuleStorageMetadataV13 {
+	/// Returns the metadata of all the storage entries, registering their types in `registry`.
+	fn storage_metadata_v13(registry: &mut Registry) -> StorageMetadataV13;
+}
 
 /// Implements the metadata support for the given runtime and all its modules.
 ///
@@ -85,6 +147,383 @@ macro_rules! impl_runtime_metadata {
 	}
 }
 
+/// Implements the type aware metadata for the given runtime and all its modules.
+///
+/// This takes the same input as [`impl_runtime_metadata`](macro.impl_runtime_metadata.html),
+/// except that a generic `Event` is written with its generics, like `Event<T>`, and implements
+/// `metadata_v13` on the runtime, which returns the metadata in version 13. In this version all
+/// the calls, events, errors, storage entries and constants refer to the types of a
+/// [`PortableRegistry`](type_info/struct.PortableRegistry.html) stored in the metadata, so every
+/// type they use needs to implement [`TypeInfo`](type_info/trait.TypeInfo.html).
+///
+/// `construct_runtime!` invokes this macro for every runtime. The requirements of
+/// `metadata_v13` are higher-ranked bounds, which are only checked where it is called, so a
+/// runtime whose modules don't provide the type information of all their types still compiles
+/// as long as it doesn't serve the metadata in version 13. The type registry is encoded like a
+/// `scale-info` `PortableRegistry`, see [`type_info`](type_info/index.html).
+///
+/// Example:
+/// ```ignore
+/// frame_support::impl_runtime_metadata_v13! {
+///     for Runtime with modules where Extrinsic = UncheckedExtrinsic
+///         module0::Module as Module0 { index 0 } with Call Event<T>,
+///         module1::Module as Module1 { index 1 } with Storage Event,
+/// };
+/// ```
+#[macro_export]
+macro_rules! impl_runtime_metadata_v13 {
+	(
+		for $runtime:ident with modules where Extrinsic = $ext:ident
+			$( $rest:tt )*
+	) => {
+		$crate::__runtime_modules_to_metadata_v13!(
+			registry; $runtime; $ext; {}; {}; $( $rest )*
+		);
+	}
+}
+
+#[macro_export]
+#[doc(hidden)]
+macro_rules! __runtime_modules_to_metadata_v13 {
+	(
+		$registry:ident; $runtime:ident; $ext:ident;
+		{ $( $bounds:tt )* };
+		{ $( $metadata:expr, )* };
+		$mod:ident::$module:ident $( < $instance:ident > )? as $name:ident
+			{ index $index:tt }
+			with $( $kw:ident $( < $( $kw_generic:ident ),* > )? )*
+		,
+		$( $rest:tt )*
+	) => {
+		$crate::__runtime_modules_to_metadata_v13!(
+			@bounds
+			$registry; $runtime; $ext;
+			{
+				$( $bounds )*
+				for<'a> $mod::$module<$runtime $(, $mod::$instance )?>:
+					$crate::metadata::ModuleConstantsMetadataV13,
+			};
+			{
+				$( $metadata, )*
+				$crate::metadata::ModuleMetadataV13 {
+					name: stringify!($name).into(),
+					index: $index,
+					storage: $crate::__runtime_modules_to_metadata_v13_storage!(
+						$registry; $mod::$module $( <$instance> )?, $runtime;
+						{ $( $kw $( < $( $kw_generic ),* > )? )* }
+					),
+					calls: $crate::__runtime_modules_to_metadata_v13_call!(
+						$registry; $mod::$module $( <$instance> )?, $runtime;
+						{ $( $kw $( < $( $kw_generic ),* > )? )* }
+					),
+					event: $crate::__runtime_modules_to_metadata_v13_event!(
+						$registry; $mod::$module $( <$instance> )?, $runtime;
+						{ $( $kw $( < $( $kw_generic ),* > )? )* }
+					),
+					constants: <
+						$mod::$module<$runtime $(, $mod::$instance )?>
+							as $crate::metadata::ModuleConstantsMetadataV13
+					>::module_constants_metadata_v13(&mut $registry),
+					error: $mod::$module::<$runtime $(, $mod::$instance )?>::module_error_type_v13()
+						.map(|ty| $registry.register_type(&ty)),
+				},
+			};
+			$mod::$module $( <$instance> )?;
+			{ $( $kw $( < $( $kw_generic ),* > )? )* };
+			$( $rest )*
+		);
+	};
+	(
+		$registry:ident; $runtime:ident; $ext:ident;
+		{ $( $bounds:tt )* };
+		{ $( $metadata:expr, )* };
+	) => {
+		impl $runtime {
+			/// Returns the type aware metadata of the runtime, in version 13.
+			pub fn metadata_v13() -> $crate::metadata::RuntimeMetadataPrefixed
+			where
+				$( $bounds )*
+			{
+				let mut $registry = $crate::type_info::Registry::new();
+				let modules = $crate::sp_std::vec![ $( $metadata ),* ];
+				$crate::metadata::RuntimeMetadataV13 {
+					types: $registry.into(),
+					modules,
+					extrinsic: $crate::metadata::ExtrinsicMetadata {
+						version: <$ext as $crate::sp_runtime::traits::ExtrinsicMetadata>::VERSION,
+						signed_extensions: <
+								<
+									$ext as $crate::sp_runtime::traits::ExtrinsicMetadata
+								>::SignedExtensions as $crate::sp_runtime::traits::SignedExtension
+							>::identifier()
+								.into_iter()
+								.map($crate::metadata::DecodeDifferent::Encode)
+								.collect(),
+					},
+				}.into()
+			}
+		}
+	};
+	// Adds the bounds required by the parts of a module.
+	(
+		@bounds
+		$registry:ident; $runtime:ident; $ext:ident;
+		{ $( $bounds:tt )* };
+		{ $( $metadata:expr, )* };
+		$mod:ident::$module:ident $( < $instance:ident > )?;
+		{ Call $( $kws:tt )* };
+		$( $rest:tt )*
+	) => {
+		$crate::__runtime_modules_to_metadata_v13!(
+			@bounds
+			$registry; $runtime; $ext;
+			{
+				$( $bounds )*
+				for<'a> $mod::$module<$runtime $(, $mod::$instance )?>:
+					$crate::metadata::ModuleCallMetadataV13,
+			};
+			{ $( $metadata, )* };
+			$mod::$module $( <$instance> )?;
+			{ $( $kws )* };
+			$( $rest )*
+		);
+	};
+	(
+		@bounds
+		$registry:ident; $runtime:ident; $ext:ident;
+		{ $( $bounds:tt )* };
+		{ $( $metadata:expr, )* };
+		$mod:ident::$module:ident $( < $instance:ident > )?;
+		{ Storage $( $kws:tt )* };
+		$( $rest:tt )*
+	) => {
+		$crate::__runtime_modules_to_metadata_v13!(
+			@bounds
+			$registry; $runtime; $ext;
+			{
+				$( $bounds )*
+				for<'a> $mod::$module<$runtime $(, $mod::$instance )?>:
+					$crate::metadata::ModuleStorageMetadataV13,
+			};
+			{ $( $metadata, )* };
+			$mod::$module $( <$instance> )?;
+			{ $( $kws )* };
+			$( $rest )*
+		);
+	};
+	(
+		@bounds
+		$registry:ident; $runtime:ident; $ext:ident;
+		{ $( $bounds:tt )* };
+		{ $( $metadata:expr, )* };
+		$mod:ident::$module:ident $( < $instance:ident > )?;
+		{ Event < $( $generic:ident ),* > $( $kws:tt )* };
+		$( $rest:tt )*
+	) => {
+		$crate::__runtime_modules_to_metadata_v13!(
+			@bounds
+			$registry; $runtime; $ext;
+			{
+				$( $bounds )*
+				for<'a> $mod::Event<$runtime $(, $mod::$instance )?>:
+					$crate::metadata::ModuleEventMetadataV13<()>,
+			};
+			{ $( $metadata, )* };
+			$mod::$module $( <$instance> )?;
+			{ $( $kws )* };
+			$( $rest )*
+		);
+	};
+	(
+		@bounds
+		$registry:ident; $runtime:ident; $ext:ident;
+		{ $( $bounds:tt )* };
+		{ $( $metadata:expr, )* };
+		$mod:ident::$module:ident $( < $instance:ident > )?;
+		{ Event $( $kws:tt )* };
+		$( $rest:tt )*
+	) => {
+		$crate::__runtime_modules_to_metadata_v13!(
+			@bounds
+			$registry; $runtime; $ext;
+			{
+				$( $bounds )*
+				for<'a> $mod::Event: $crate::metadata::ModuleEventMetadataV13<()>,
+			};
+			{ $( $metadata, )* };
+			$mod::$module $( <$instance> )?;
+			{ $( $kws )* };
+			$( $rest )*
+		);
+	};
+	(
+		@bounds
+		$registry:ident; $runtime:ident; $ext:ident;
+		{ $( $bounds:tt )* };
+		{ $( $metadata:expr, )* };
+		$mod:ident::$module:ident $( < $instance:ident > )?;
+		{ $_:ident < $( $_generic:ident ),* > $( $kws:tt )* };
+		$( $rest:tt )*
+	) => {
+		$crate::__runtime_modules_to_metadata_v13!(
+			@bounds
+			$registry; $runtime; $ext;
+			{ $( $bounds )* };
+			{ $( $metadata, )* };
+			$mod::$module $( <$instance> )?;
+			{ $( $kws )* };
+			$( $rest )*
+		);
+	};
+	(
+		@bounds
+		$registry:ident; $runtime:ident; $ext:ident;
+		{ $( $bounds:tt )* };
+		{ $( $metadata:expr, )* };
+		$mod:ident::$module:ident $( < $instance:ident > )?;
+		{ $_:ident $( $kws:tt )* };
+		$( $rest:tt )*
+	) => {
+		$crate::__runtime_modules_to_metadata_v13!(
+			@bounds
+			$registry; $runtime; $ext;
+			{ $( $bounds )* };
+			{ $( $metadata, )* };
+			$mod::$module $( <$instance> )?;
+			{ $( $kws )* };
+			$( $rest )*
+		);
+	};
+	(
+		@bounds
+		$registry:ident; $runtime:ident; $ext:ident;
+		{ $( $bounds:tt )* };
+		{ $( $metadata:expr, )* };
+		$mod:ident::$module:ident $( < $instance:ident > )?;
+		{};
+		$( $rest:tt )*
+	) => {
+		$crate::__runtime_modules_to_metadata_v13!(
+			$registry; $runtime; $ext;
+			{ $( $bounds )* };
+			{ $( $metadata, )* };
+			$( $rest )*
+		);
+	};
+}
+
+#[macro_export]
+#[doc(hidden)]
+macro_rules! __runtime_modules_to_metadata_v13_call {
+	(
+		$registry:ident; $mod:ident::$module:ident $( <$instance:ident> )?, $runtime:ident;
+		{ Call $( $kws:tt )* }
+	) => {
+		Some(<
+			$mod::$module<$runtime $(, $mod::$instance )?> as $crate::metadata::ModuleCallMetadataV13
+		>::call_functions_v13(&mut $registry))
+	};
+	(
+		$registry:ident; $mod:ident::$module:ident $( <$instance:ident> )?, $runtime:ident;
+		{ $_:ident < $( $_generic:ident ),* > $( $kws:tt )* }
+	) => {
+		$crate::__runtime_modules_to_metadata_v13_call!(
+			$registry; $mod::$module $( <$instance> )?, $runtime; { $( $kws )* }
+		)
+	};
+	(
+		$registry:ident; $mod:ident::$module:ident $( <$instance:ident> )?, $runtime:ident;
+		{ $_:ident $( $kws:tt )* }
+	) => {
+		$crate::__runtime_modules_to_metadata_v13_call!(
+			$registry; $mod::$module $( <$instance> )?, $runtime; { $( $kws )* }
+		)
+	};
+	(
+		$registry:ident; $mod:ident::$module:ident $( <$instance:ident> )?, $runtime:ident;
+		{}
+	) => {
+		None
+	};
+}
+
+#[macro_export]
+#[doc(hidden)]
+macro_rules! __runtime_modules_to_metadata_v13_event {
+	(
+		$registry:ident; $mod:ident::$module:ident $( <$instance:ident> )?, $runtime:ident;
+		{ Event < $( $generic:ident ),* > $( $kws:tt )* }
+	) => {
+		Some(<
+			$mod::Event<$runtime $(, $mod::$instance )?>
+				as $crate::metadata::ModuleEventMetadataV13<()>
+		>::metadata_v13(&mut $registry))
+	};
+	(
+		$registry:ident; $mod:ident::$module:ident $( <$instance:ident> )?, $runtime:ident;
+		{ Event $( $kws:tt )* }
+	) => {
+		Some(<$mod::Event as $crate::metadata::ModuleEventMetadataV13<()>>::metadata_v13(&mut $registry))
+	};
+	(
+		$registry:ident; $mod:ident::$module:ident $( <$instance:ident> )?, $runtime:ident;
+		{ $_:ident < $( $_generic:ident ),* > $( $kws:tt )* }
+	) => {
+		$crate::__runtime_modules_to_metadata_v13_event!(
+			$registry; $mod::$module $( <$instance> )?, $runtime; { $( $kws )* }
+		)
+	};
+	(
+		$registry:ident; $mod:ident::$module:ident $( <$instance:ident> )?, $runtime:ident;
+		{ $_:ident $( $kws:tt )* }
+	) => {
+		$crate::__runtime_modules_to_metadata_v13_event!(
+			$registry; $mod::$module $( <$instance> )?, $runtime; { $( $kws )* }
+		)
+	};
+	(
+		$registry:ident; $mod:ident::$module:ident $( <$instance:ident> )?, $runtime:ident;
+		{}
+	) => {
+		None
+	};
+}
+
+#[macro_export]
+#[doc(hidden)]
+macro_rules! __runtime_modules_to_metadata_v13_storage {
+	(
+		$registry:ident; $mod:ident::$module:ident $( <$instance:ident> )?, $runtime:ident;
+		{ Storage $( $kws:tt )* }
+	) => {
+		Some(<
+			$mod::$module<$runtime $(, $mod::$instance )?> as $crate::metadata::ModuleStorageMetadataV13
+		>::storage_metadata_v13(&mut $registry))
+	};
+	(
+		$registry:ident; $mod:ident::$module:ident $( <$instance:ident> )?, $runtime:ident;
+		{ $_:ident < $( $_generic:ident ),* > $( $kws:tt )* }
+	) => {
+		$crate::__runtime_modules_to_metadata_v13_storage!(
+			$registry; $mod::$module $( <$instance> )?, $runtime; { $( $kws )* }
+		)
+	};
+	(
+		$registry:ident; $mod:ident::$module:ident $( <$instance:ident> )?, $runtime:ident;
+		{ $_:ident $( $kws:tt )* }
+	) => {
+		$crate::__runtime_modules_to_metadata_v13_storage!(
+			$registry; $mod::$module $( <$instance> )?, $runtime; { $( $kws )* }
+		)
+	};
+	(
+		$registry:ident; $mod:ident::$module:ident $( <$instance:ident> )?, $runtime:ident;
+		{}
+	) => {
+		None
+	};
+}
+
 #[macro_export]
 #[doc(hidden)]
 macro_rules! __runtime_modules_to_metadata {
@@ -617,4 +1056,120 @@ mod tests {
 
 		pretty_assertions::assert_eq!(expected_metadata, metadata_decoded.unwrap());
 	}
+
+	impl_runtime_metadata_v13!(
+		for TestRuntime with modules where Extrinsic = TestExtrinsic
+			system::Module as System { index 0 } with Event,
+			event_module::Module as Module { index 1 } with Event<T> Call,
+			event_module2::Module as Module2 { index 2 } with Event<T> Storage Call,
+	);
+
+	#[test]
+	fn runtime_metadata_v13() {
+		use crate::type_info::{TypeDef, TypeDefPrimitive};
+
+		let metadata_encoded = TestRuntime::metadata_v13().encode();
+		let metadata = match RuntimeMetadataPrefixed::decode(&mut &metadata_encoded[..]).unwrap().1 {
+			RuntimeMetadata::V13(metadata) => metadata,
+			_ => panic!("metadata has version 13"),
+		};
+		let types = &metadata.types;
+		let resolve = |id| types.resolve(id).expect("all referenced types are registered");
+
+		// `u32`, `u64` and the error of `event_module`.
+		assert_eq!(types.types().len(), 3);
+		assert_eq!(
+			metadata.extrinsic.signed_extensions,
+			vec![
+				DecodeDifferent::Decoded("testextension".to_string()),
+				DecodeDifferent::Decoded("testextension2".to_string()),
+			],
+		);
+
+		let system = &metadata.modules[0];
+		assert_eq!((system.name.as_str(), system.index), ("System", 0));
+		assert!(system.storage.is_none() && system.calls.is_none() && system.error.is_none());
+		assert_eq!(
+			system.event,
+			Some(vec![EventMetadataV13 {
+				name: "SystemEvent".into(),
+				arguments: vec![],
+				documentation: vec![],
+			}]),
+		);
+		let u32_ty = system.constants[0].ty;
+		assert_eq!(resolve(u32_ty).def, TypeDef::Primitive(TypeDefPrimitive::U32));
+		assert_eq!(
+			system.constants,
+			vec![
+				ModuleConstantMetadataV13 {
+					name: "BlockNumber".into(),
+					ty: u32_ty,
+					value: 100u32.encode(),
+					documentation: vec![" Hi, I am a comment.".into()],
+				},
+				ModuleConstantMetadataV13 {
+					name: "GetType".into(),
+					ty: u32_ty,
+					value: SystemValue::get().encode(),
+					documentation: vec![],
+				},
+				ModuleConstantMetadataV13 {
+					name: "ASSOCIATED_CONST".into(),
+					ty: system.constants[2].ty,
+					value: 500u64.encode(),
+					documentation: vec![],
+				},
+			],
+		);
+		assert_eq!(
+			resolve(system.constants[2].ty).def,
+			TypeDef::Primitive(TypeDefPrimitive::U64),
+		);
+
+		let module = &metadata.modules[1];
+		assert_eq!(
+			module.calls,
+			Some(vec![FunctionMetadataV13 {
+				name: "aux_0".into(),
+				arguments: vec![],
+				documentation: vec![],
+			}]),
+		);
+		assert_eq!(
+			module.event,
+			Some(vec![EventMetadataV13 {
+				name: "TestEvent".into(),
+				arguments: vec![u32_ty],
+				documentation: vec![" Hi, I am a comment.".into()],
+			}]),
+		);
+		let error = resolve(module.error.expect("`event_module` declares an error"));
+		assert_eq!(error.path.segments.last().map(|s| s.as_str()), Some("Error"));
+		match &error.def {
+			TypeDef::Variant(variants) => {
+				let variants = variants.iter()
+					.map(|v| (v.name.as_str(), v.index, v.docs.len()))
+					.collect::<Vec<_>>();
+				assert_eq!(variants, vec![("UserInputError", 0, 1), ("BadThingHappened", 1, 2)]);
+			},
+			def => panic!("unexpected error definition: {:?}", def),
+		}
+
+		let module2 = &metadata.modules[2];
+		assert_eq!(module2.calls, Some(vec![]));
+		assert_eq!(
+			module2.storage,
+			Some(StorageMetadataV13 {
+				prefix: "TestStorage".into(),
+				entries: vec![StorageEntryMetadataV13 {
+					name: "StorageMethod".into(),
+					modifier: StorageEntryModifier::Optional,
+					ty: StorageEntryTypeV13::Plain(u32_ty),
+					default: vec![0],
+					documentation: vec![],
+				}],
+			}),
+		);
+	}
 }
@@ -261,7 +261,23 @@ macro_rules! impl_outer_origin {
 		}
 
 		$crate::paste::item! {
-			#[derive(Clone, PartialEq, Eq, $crate::RuntimeDebug, $crate::codec::Encode, $crate::codec::Decode)]
+			#[derive(
+				Clone, PartialEq, Eq,
+				$crate::RuntimeDebug,
+				$crate::codec::Encode,
+				$crate::codec::Decode,
+				$crate::type_info::TypeInfo,
+			)]
+			// The bounds are higher-ranked so that they are only checked where the type
+			// information is used, see `impl_runtime_metadata_v13!`.
+			#[scale_info(bounds(
+				for<'a> $system::Origin<$runtime>: $crate::type_info::TypeInfo,
+				$(
+					for<'a> $module::Origin < $( $generic, )? $( $module::$generic_instance )? >:
+						$crate::type_info::TypeInfo,
+				)*
+				for<'a> $crate::Void: $crate::type_info::TypeInfo,
+			))]
 			$(#[$attr])*
 			#[allow(non_camel_case_types)]
 			pub enum $caller_name {
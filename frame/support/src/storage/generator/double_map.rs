@@ -18,7 +18,7 @@
 use sp_std::prelude::*;
 use sp_std::borrow::Borrow;
 use codec::{FullCodec, FullEncode, Decode, Encode, EncodeLike};
-use crate::{storage::{self, unhashed, StorageAppend, PrefixIterator}, Never};
+use crate::{storage::{self, unhashed, StorageAppend, PrefixIterator, KeyPrefixIterator}, Never};
 use crate::hash::{StorageHasher, Twox128, ReversibleStorageHasher};
 
 /// Generator for `StorageDoubleMap` used by `decl_storage`.
@@ -209,16 +209,21 @@ impl<K1, K2, V, G> storage::StorageDoubleMap<K1, K2, V> for G where
 		unhashed::kill_prefix(Self::storage_double_map_final_key1(k1).as_ref())
 	}
 
+	fn remove_prefix_with_limit<KArg1>(k1: KArg1, limit: u32) -> storage::PrefixRemovalResult
+		where KArg1: EncodeLike<K1>
+	{
+		unhashed::kill_prefix_with_limit(Self::storage_double_map_final_key1(k1).as_ref(), limit)
+	}
+
 	fn iter_prefix_values<KArg1>(k1: KArg1) -> storage::PrefixIterator<V> where
 		KArg1: ?Sized + EncodeLike<K1>
 	{
 		let prefix = Self::storage_double_map_final_key1(k1);
-		storage::PrefixIterator {
-			prefix: prefix.clone(),
-			previous_key: prefix,
-			drain: false,
-			closure: |_raw_key, mut raw_value| V::decode(&mut raw_value),
-		}
+		storage::PrefixIterator::new(
+			prefix.clone(),
+			prefix,
+			|_raw_key, mut raw_value| V::decode(&mut raw_value),
+		)
 	}
 
 	fn mutate<KArg1, KArg2, R, F>(k1: KArg1, k2: KArg2, f: F) -> R where
@@ -334,46 +339,89 @@ impl<
 {
 	type PrefixIterator = PrefixIterator<(K2, V)>;
 	type Iterator = PrefixIterator<(K1, K2, V)>;
+	type PartialKeyIterator = KeyPrefixIterator<K2>;
+	type FullKeyIterator = KeyPrefixIterator<(K1, K2)>;
 
 	fn iter_prefix(k1: impl EncodeLike<K1>) -> Self::PrefixIterator {
 		let prefix = G::storage_double_map_final_key1(k1);
-		Self::PrefixIterator {
-			prefix: prefix.clone(),
-			previous_key: prefix,
-			drain: false,
-			closure: |raw_key_without_prefix, mut raw_value| {
+		PrefixIterator::new(
+			prefix.clone(),
+			prefix,
+			|raw_key_without_prefix, mut raw_value| {
 				let mut key_material = G::Hasher2::reverse(raw_key_without_prefix);
 				Ok((K2::decode(&mut key_material)?, V::decode(&mut raw_value)?))
 			},
-		}
+		)
+	}
+
+	fn iter_prefix_from(
+		k1: impl EncodeLike<K1>,
+		starting_raw_key: Vec<u8>,
+	) -> Self::PrefixIterator {
+		Self::iter_prefix(k1).with_starting_raw_key(starting_raw_key)
+	}
+
+	fn iter_key_prefix(k1: impl EncodeLike<K1>) -> Self::PartialKeyIterator {
+		let prefix = G::storage_double_map_final_key1(k1);
+		KeyPrefixIterator::new(
+			prefix.clone(),
+			prefix,
+			|raw_key_without_prefix| {
+				let mut key_material = G::Hasher2::reverse(raw_key_without_prefix);
+				K2::decode(&mut key_material)
+			},
+		)
+	}
+
+	fn iter_key_prefix_from(
+		k1: impl EncodeLike<K1>,
+		starting_raw_key: Vec<u8>,
+	) -> Self::PartialKeyIterator {
+		Self::iter_key_prefix(k1).with_starting_raw_key(starting_raw_key)
 	}
 
 	fn drain_prefix(k1: impl EncodeLike<K1>) -> Self::PrefixIterator {
-		let mut iterator = Self::iter_prefix(k1);
-		iterator.drain = true;
-		iterator
+		Self::iter_prefix(k1).drain()
 	}
 
 	fn iter() -> Self::Iterator {
-		let prefix = G::prefix_hash();
-		Self::Iterator {
-			prefix: prefix.clone(),
-			previous_key: prefix,
-			drain: false,
-			closure: |raw_key_without_prefix, mut raw_value| {
+		Self::iter_from(G::prefix_hash())
+	}
+
+	fn iter_from(starting_raw_key: Vec<u8>) -> Self::Iterator {
+		PrefixIterator::new(
+			G::prefix_hash(),
+			starting_raw_key,
+			|raw_key_without_prefix, mut raw_value| {
 				let mut k1_k2_material = G::Hasher1::reverse(raw_key_without_prefix);
 				let k1 = K1::decode(&mut k1_k2_material)?;
 				let mut k2_material = G::Hasher2::reverse(k1_k2_material);
 				let k2 = K2::decode(&mut k2_material)?;
 				Ok((k1, k2, V::decode(&mut raw_value)?))
 			},
-		}
+		)
+	}
+
+	fn iter_keys() -> Self::FullKeyIterator {
+		Self::iter_keys_from(G::prefix_hash())
+	}
+
+	fn iter_keys_from(starting_raw_key: Vec<u8>) -> Self::FullKeyIterator {
+		KeyPrefixIterator::new(
+			G::prefix_hash(),
+			starting_raw_key,
+			|raw_key_without_prefix| {
+				let mut k1_k2_material = G::Hasher1::reverse(raw_key_without_prefix);
+				let k1 = K1::decode(&mut k1_k2_material)?;
+				let mut k2_material = G::Hasher2::reverse(k1_k2_material);
+				let k2 = K2::decode(&mut k2_material)?;
+				Ok((k1, k2))
+			},
+		)
 	}
 
 	fn drain() -> Self::Iterator {
-		let mut iterator = Self::iter();
-		iterator.drain = true;
-		iterator
+		Self::iter().drain()
 	}
 
 	fn translate<O: Decode, F: Fn(K1, K2, O) -> Option<V>>(f: F) {
@@ -557,4 +605,52 @@ mod test_iterators {
 			);
 		})
 	}
+
+	#[test]
+	fn double_map_iteration_can_be_resumed() {
+		sp_io::TestExternalities::default().execute_with(|| {
+			for i in 0..4 {
+				DoubleMap::insert(i as u16, i as u32, i as u64);
+			}
+
+			assert_eq!(
+				DoubleMap::iter_keys().collect::<Vec<_>>(),
+				vec![(3, 3), (0, 0), (2, 2), (1, 1)],
+			);
+
+			let mut iter = DoubleMap::iter();
+			assert_eq!(iter.next(), Some((3, 3, 3)));
+			let cursor = iter.cursor();
+			assert_eq!(
+				DoubleMap::iter_from(cursor.clone().into_raw_key()).collect::<Vec<_>>(),
+				vec![(0, 0, 0), (2, 2, 2), (1, 1, 1)],
+			);
+			assert_eq!(
+				DoubleMap::iter_keys_from(cursor.into_raw_key()).collect::<Vec<_>>(),
+				vec![(0, 0), (2, 2), (1, 1)],
+			);
+
+			// Prefix iterator
+			let k1 = 3 << 8;
+			for i in 0..4 {
+				DoubleMap::insert(k1, i as u32, i as u64);
+			}
+
+			assert_eq!(DoubleMap::iter_key_prefix(k1).collect::<Vec<_>>(), vec![1, 2, 0, 3]);
+
+			let mut iter = DoubleMap::iter_prefix(k1);
+			assert_eq!(iter.next(), Some((1, 1)));
+			assert_eq!(iter.next(), Some((2, 2)));
+			let cursor = iter.cursor();
+			assert_eq!(
+				DoubleMap::iter_prefix_from(k1, cursor.clone().into_raw_key())
+					.collect::<Vec<_>>(),
+				vec![(0, 0), (3, 3)],
+			);
+			assert_eq!(
+				DoubleMap::iter_key_prefix_from(k1, cursor.into_raw_key()).collect::<Vec<_>>(),
+				vec![0, 3],
+			);
+		})
+	}
 }
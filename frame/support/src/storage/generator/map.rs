@@ -20,7 +20,7 @@ use sp_std::prelude::*;
 use sp_std::borrow::Borrow;
 use codec::{FullCodec, FullEncode, Decode, Encode, EncodeLike};
 use crate::{
-	storage::{self, unhashed, StorageAppend, PrefixIterator},
+	storage::{self, unhashed, StorageAppend, PrefixIterator, KeyPrefixIterator},
 	Never, hash::{StorageHasher, Twox128, ReversibleStorageHasher},
 };
 
@@ -140,26 +140,45 @@ impl<
 	G::Hasher: ReversibleStorageHasher
 {
 	type Iterator = PrefixIterator<(K, V)>;
+	type KeyIterator = KeyPrefixIterator<K>;
 
 	/// Enumerate all elements in the map.
 	fn iter() -> Self::Iterator {
-		let prefix = G::prefix_hash();
-		PrefixIterator {
-			prefix: prefix.clone(),
-			previous_key: prefix,
-			drain: false,
-			closure: |raw_key_without_prefix, mut raw_value| {
+		Self::iter_from(G::prefix_hash())
+	}
+
+	/// Enumerate all elements in the map after a given key.
+	fn iter_from(starting_raw_key: Vec<u8>) -> Self::Iterator {
+		PrefixIterator::new(
+			G::prefix_hash(),
+			starting_raw_key,
+			|raw_key_without_prefix, mut raw_value| {
 				let mut key_material = G::Hasher::reverse(raw_key_without_prefix);
 				Ok((K::decode(&mut key_material)?, V::decode(&mut raw_value)?))
 			},
-		}
+		)
+	}
+
+	/// Enumerate all keys in the map.
+	fn iter_keys() -> Self::KeyIterator {
+		Self::iter_keys_from(G::prefix_hash())
+	}
+
+	/// Enumerate all keys in the map after a given key.
+	fn iter_keys_from(starting_raw_key: Vec<u8>) -> Self::KeyIterator {
+		KeyPrefixIterator::new(
+			G::prefix_hash(),
+			starting_raw_key,
+			|raw_key_without_prefix| {
+				let mut key_material = G::Hasher::reverse(raw_key_without_prefix);
+				K::decode(&mut key_material)
+			},
+		)
 	}
 
 	/// Enumerate all elements in the map.
 	fn drain() -> Self::Iterator {
-		let mut iterator = Self::iter();
-		iterator.drain = true;
-		iterator
+		Self::iter().drain()
 	}
 
 	fn translate<O: Decode, F: Fn(K, O) -> Option<V>>(f: F) {
@@ -402,4 +421,42 @@ mod test_iterators {
 			assert_eq!(Map::iter().collect::<Vec<_>>(), vec![(3, 6), (0, 0), (2, 4), (1, 2)]);
 		})
 	}
+
+	#[test]
+	fn map_iteration_can_be_resumed() {
+		sp_io::TestExternalities::default().execute_with(|| {
+			let prefix = Map::prefix_hash();
+
+			unhashed::put(&key_after_prefix(prefix.clone()), &1u64);
+
+			for i in 0..4 {
+				Map::insert(i as u16, i as u64);
+			}
+
+			assert_eq!(Map::iter_keys().collect::<Vec<_>>(), vec![3, 0, 2, 1]);
+
+			let mut iter = Map::iter();
+			assert_eq!(iter.next(), Some((3, 3)));
+			assert_eq!(iter.next(), Some((0, 0)));
+			let cursor = iter.cursor();
+			assert_eq!(cursor.raw_key(), &Map::storage_map_final_key(0u16)[..]);
+
+			assert_eq!(
+				Map::iter_from(cursor.clone().into_raw_key()).collect::<Vec<_>>(),
+				vec![(2, 2), (1, 1)],
+			);
+			assert_eq!(
+				Map::iter_keys_from(cursor.clone().into_raw_key()).collect::<Vec<_>>(),
+				vec![2, 1],
+			);
+
+			// Drain from the cursor on.
+			assert_eq!(
+				Map::iter_from(cursor.into_raw_key()).drain().collect::<Vec<_>>(),
+				vec![(2, 2), (1, 1)],
+			);
+			assert_eq!(Map::iter().collect::<Vec<_>>(), vec![(3, 3), (0, 0)]);
+			assert_eq!(unhashed::get(&key_after_prefix(prefix.clone())), Some(1u64));
+		})
+	}
 }
@@ -21,16 +21,17 @@ use sp_std::prelude::*;
 use codec::{Encode, Decode};
 use crate::{StorageHasher, Twox128};
 use crate::hash::ReversibleStorageHasher;
+use crate::storage::{IterationCursor, PrefixIterator};
 
 /// Utility to iterate through raw items in storage.
+///
+/// Entries whose value fails to decode are skipped; they are left in storage even when draining.
 pub struct StorageIterator<T> {
-	prefix: Vec<u8>,
-	previous_key: Vec<u8>,
+	inner: PrefixIterator<(Vec<u8>, T)>,
 	drain: bool,
-	_phantom: ::sp_std::marker::PhantomData<T>,
 }
 
-impl<T> StorageIterator<T> {
+impl<T: Decode + Sized> StorageIterator<T> {
 	/// Construct iterator to iterate over map items in `module` for the map called `item`.
 	pub fn new(module: &[u8], item: &[u8]) -> Self {
 		Self::with_suffix(module, item, &[][..])
@@ -38,12 +39,15 @@ impl<T> StorageIterator<T> {
 
 	/// Construct iterator to iterate over map items in `module` for the map called `item`.
 	pub fn with_suffix(module: &[u8], item: &[u8], suffix: &[u8]) -> Self {
-		let mut prefix = Vec::new();
-		prefix.extend_from_slice(&Twox128::hash(module));
-		prefix.extend_from_slice(&Twox128::hash(item));
-		prefix.extend_from_slice(suffix);
-		let previous_key = prefix.clone();
-		Self { prefix, previous_key, drain: false, _phantom: Default::default() }
+		let prefix = storage_prefix(module, item, suffix);
+		let inner = PrefixIterator::new(
+			prefix.clone(),
+			prefix,
+			|raw_key_without_prefix, mut raw_value| {
+				Ok((raw_key_without_prefix.to_vec(), T::decode(&mut raw_value)?))
+			},
+		);
+		Self { inner, drain: false }
 	}
 
 	/// Mutate this iterator into a draining iterator; items iterated are removed from storage.
@@ -51,44 +55,46 @@ impl<T> StorageIterator<T> {
 		self.drain = true;
 		self
 	}
+
+	/// Resume the iteration after the given `starting_raw_key`, e.g. one obtained through
+	/// [`StorageIterator::cursor`] in a previous block.
+	pub fn with_starting_raw_key(self, starting_raw_key: Vec<u8>) -> Self {
+		Self { inner: self.inner.with_starting_raw_key(starting_raw_key), drain: self.drain }
+	}
+
+	/// A cursor to resume this iteration after the last item returned.
+	pub fn cursor(&self) -> IterationCursor {
+		self.inner.cursor()
+	}
 }
 
 impl<T: Decode + Sized> Iterator for StorageIterator<T> {
 	type Item = (Vec<u8>, T);
 
 	fn next(&mut self) -> Option<(Vec<u8>, T)> {
-		loop {
-			let maybe_next = sp_io::storage::next_key(&self.previous_key)
-				.filter(|n| n.starts_with(&self.prefix));
-			break match maybe_next {
-				Some(next) => {
-					self.previous_key = next.clone();
-					let maybe_value = frame_support::storage::unhashed::get::<T>(&next);
-					match maybe_value {
-						Some(value) => {
-							if self.drain {
-								frame_support::storage::unhashed::kill(&next);
-							}
-							Some((self.previous_key[self.prefix.len()..].to_vec(), value))
-						}
-						None => continue,
-					}
-				}
-				None => None,
-			}
+		let item = self.inner.next()?;
+		if self.drain {
+			frame_support::storage::unhashed::kill(self.inner.last_raw_key());
 		}
+		Some(item)
 	}
 }
 
 /// Utility to iterate through raw items in storage.
+///
+/// Entries whose key or value fails to decode are skipped; they are left in storage even when
+/// draining.
 pub struct StorageKeyIterator<K, T, H: ReversibleStorageHasher> {
-	prefix: Vec<u8>,
-	previous_key: Vec<u8>,
+	inner: PrefixIterator<(K, T)>,
 	drain: bool,
-	_phantom: ::sp_std::marker::PhantomData<(K, T, H)>,
+	_phantom: ::sp_std::marker::PhantomData<H>,
 }
 
-impl<K, T, H: ReversibleStorageHasher> StorageKeyIterator<K, T, H> {
+impl<
+	K: Decode + Sized,
+	T: Decode + Sized,
+	H: ReversibleStorageHasher,
+> StorageKeyIterator<K, T, H> {
 	/// Construct iterator to iterate over map items in `module` for the map called `item`.
 	pub fn new(module: &[u8], item: &[u8]) -> Self {
 		Self::with_suffix(module, item, &[][..])
@@ -96,12 +102,16 @@ impl<K, T, H: ReversibleStorageHasher> StorageKeyIterator<K, T, H> {
 
 	/// Construct iterator to iterate over map items in `module` for the map called `item`.
 	pub fn with_suffix(module: &[u8], item: &[u8], suffix: &[u8]) -> Self {
-		let mut prefix = Vec::new();
-		prefix.extend_from_slice(&Twox128::hash(module));
-		prefix.extend_from_slice(&Twox128::hash(item));
-		prefix.extend_from_slice(suffix);
-		let previous_key = prefix.clone();
-		Self { prefix, previous_key, drain: false, _phantom: Default::default() }
+		let prefix = storage_prefix(module, item, suffix);
+		let inner = PrefixIterator::new(
+			prefix.clone(),
+			prefix,
+			|raw_key_without_prefix, mut raw_value| {
+				let mut key_material = H::reverse(raw_key_without_prefix);
+				Ok((K::decode(&mut key_material)?, T::decode(&mut raw_value)?))
+			},
+		);
+		Self { inner, drain: false, _phantom: Default::default() }
 	}
 
 	/// Mutate this iterator into a draining iterator; items iterated are removed from storage.
@@ -109,6 +119,21 @@ impl<K, T, H: ReversibleStorageHasher> StorageKeyIterator<K, T, H> {
 		self.drain = true;
 		self
 	}
+
+	/// Resume the iteration after the given `starting_raw_key`, e.g. one obtained through
+	/// [`StorageKeyIterator::cursor`] in a previous block.
+	pub fn with_starting_raw_key(self, starting_raw_key: Vec<u8>) -> Self {
+		Self {
+			inner: self.inner.with_starting_raw_key(starting_raw_key),
+			drain: self.drain,
+			_phantom: Default::default(),
+		}
+	}
+
+	/// A cursor to resume this iteration after the last item returned.
+	pub fn cursor(&self) -> IterationCursor {
+		self.inner.cursor()
+	}
 }
 
 impl<K: Decode + Sized, T: Decode + Sized, H: ReversibleStorageHasher> Iterator
@@ -117,35 +142,24 @@ impl<K: Decode + Sized, T: Decode + Sized, H: ReversibleStorageHasher> Iterator
 	type Item = (K, T);
 
 	fn next(&mut self) -> Option<(K, T)> {
-		loop {
-			let maybe_next = sp_io::storage::next_key(&self.previous_key)
-				.filter(|n| n.starts_with(&self.prefix));
-			break match maybe_next {
-				Some(next) => {
-					self.previous_key = next.clone();
-					let mut key_material = H::reverse(&next[self.prefix.len()..]);
-					match K::decode(&mut key_material) {
-						Ok(key) => {
-							let maybe_value = frame_support::storage::unhashed::get::<T>(&next);
-							match maybe_value {
-								Some(value) => {
-									if self.drain {
-										frame_support::storage::unhashed::kill(&next);
-									}
-									Some((key, value))
-								}
-								None => continue,
-							}
-						}
-						Err(_) => continue,
-					}
-				}
-				None => None,
-			}
+		let item = self.inner.next()?;
+		if self.drain {
+			frame_support::storage::unhashed::kill(self.inner.last_raw_key());
 		}
+		Some(item)
 	}
 }
 
+/// The prefix under which the map called `item` in `module` stores its values, with `suffix`
+/// appended.
+fn storage_prefix(module: &[u8], item: &[u8], suffix: &[u8]) -> Vec<u8> {
+	let mut prefix = Vec::new();
+	prefix.extend_from_slice(&Twox128::hash(module));
+	prefix.extend_from_slice(&Twox128::hash(item));
+	prefix.extend_from_slice(suffix);
+	prefix
+}
+
 /// Get a particular value in storage by the `module`, the map's `item` name and the key `hash`.
 pub fn have_storage_value(module: &[u8], item: &[u8], hash: &[u8]) -> bool {
 	get_storage_value::<()>(module, item, hash).is_some()
@@ -187,6 +201,25 @@ pub fn remove_storage_prefix(module: &[u8], item: &[u8], hash: &[u8]) {
 	frame_support::storage::unhashed::kill_prefix(&key)
 }
 
+/// Remove at most `limit` values in storage under the `module`, the map's `item` name and the
+/// key `hash` prefix.
+///
+/// If values remain, the returned result contains a cursor that can be passed to
+/// [`unhashed::kill_prefix_from_cursor`](frame_support::storage::unhashed::kill_prefix_from_cursor)
+/// to continue the removal in a later block.
+pub fn remove_storage_prefix_with_limit(
+	module: &[u8],
+	item: &[u8],
+	hash: &[u8],
+	limit: u32,
+) -> frame_support::storage::PrefixRemovalResult {
+	let mut key = vec![0u8; 32 + hash.len()];
+	key[0..16].copy_from_slice(&Twox128::hash(module));
+	key[16..32].copy_from_slice(&Twox128::hash(item));
+	key[32..].copy_from_slice(hash);
+	frame_support::storage::unhashed::kill_prefix_with_limit(&key, limit)
+}
+
 /// Get a particular value in storage by the `module`, the map's `item` name and the key `hash`.
 pub fn take_storage_item<K: Encode + Sized, T: Decode + Sized, H: StorageHasher>(
 	module: &[u8],
@@ -195,3 +228,27 @@ pub fn take_storage_item<K: Encode + Sized, T: Decode + Sized, H: StorageHasher>
 ) -> Option<T> {
 	take_storage_value(module, item, key.using_encoded(H::hash).as_ref())
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use sp_io::TestExternalities;
+
+	#[test]
+	fn draining_storage_iterator_keeps_undecodable_entries() {
+		TestExternalities::default().execute_with(|| {
+			put_storage_value(b"MyModule", b"MyMap", &[1u8], 10u64);
+			put_storage_value(b"MyModule", b"MyMap", &[2u8], 20u8);
+			put_storage_value(b"MyModule", b"MyMap", &[3u8], 30u64);
+
+			let drained = StorageIterator::<u64>::new(b"MyModule", b"MyMap")
+				.drain()
+				.collect::<Vec<_>>();
+			assert_eq!(drained, vec![(vec![1u8], 10u64), (vec![3u8], 30u64)]);
+
+			assert!(!have_storage_value(b"MyModule", b"MyMap", &[1u8]));
+			assert_eq!(get_storage_value::<u8>(b"MyModule", b"MyMap", &[2u8]), Some(20u8));
+			assert!(!have_storage_value(b"MyModule", b"MyMap", &[3u8]));
+		});
+	}
+}
@@ -296,10 +296,29 @@ pub trait IterableStorageMap<K: FullEncode, V: FullCodec>: StorageMap<K, V> {
 	/// The type that iterates over all `(key, value)`.
 	type Iterator: Iterator<Item = (K, V)>;
 
+	/// The type that iterates over all `key`s.
+	type KeyIterator: Iterator<Item = K>;
+
 	/// Enumerate all elements in the map in no particular order. If you alter the map while doing
 	/// this, you'll get undefined results.
 	fn iter() -> Self::Iterator;
 
+	/// Enumerate all elements in the map after the given `starting_raw_key`, in no particular
+	/// order. If you alter the map while doing this, you'll get undefined results.
+	///
+	/// The raw key of the last element returned by an iterator is available through
+	/// [`PrefixIterator::cursor`], so the iteration can be resumed later on.
+	fn iter_from(starting_raw_key: Vec<u8>) -> Self::Iterator;
+
+	/// Enumerate all keys in the map in no particular order, without decoding the values. If
+	/// you alter the map while doing this, you'll get undefined results.
+	fn iter_keys() -> Self::KeyIterator;
+
+	/// Enumerate all keys in the map after the given `starting_raw_key`, in no particular order,
+	/// without decoding the values. If you alter the map while doing this, you'll get undefined
+	/// results.
+	fn iter_keys_from(starting_raw_key: Vec<u8>) -> Self::KeyIterator;
+
 	/// Remove all elements from the map and iterate through them in no particular order. If you
 	/// add elements to the map while doing this, you'll get undefined results.
 	fn drain() -> Self::Iterator;
@@ -323,11 +342,39 @@ pub trait IterableStorageDoubleMap<
 	/// The type that iterates over all `(key1, key2, value)`.
 	type Iterator: Iterator<Item = (K1, K2, V)>;
 
+	/// The type that iterates over all `key2`.
+	type PartialKeyIterator: Iterator<Item = K2>;
+
+	/// The type that iterates over all `(key1, key2)`.
+	type FullKeyIterator: Iterator<Item = (K1, K2)>;
+
 	/// Enumerate all elements in the map with first key `k1` in no particular order. If you add or
 	/// remove values whose first key is `k1` to the map while doing this, you'll get undefined
 	/// results.
 	fn iter_prefix(k1: impl EncodeLike<K1>) -> Self::PrefixIterator;
 
+	/// Enumerate all elements in the map with first key `k1` after the given `starting_raw_key`,
+	/// in no particular order. If you add or remove values whose first key is `k1` to the map
+	/// while doing this, you'll get undefined results.
+	fn iter_prefix_from(
+		k1: impl EncodeLike<K1>,
+		starting_raw_key: Vec<u8>,
+	) -> Self::PrefixIterator;
+
+	/// Enumerate all second keys `k2` in the map with first key `k1` in no particular order,
+	/// without decoding the values. If you add or remove values whose first key is `k1` to the
+	/// map while doing this, you'll get undefined results.
+	fn iter_key_prefix(k1: impl EncodeLike<K1>) -> Self::PartialKeyIterator;
+
+	/// Enumerate all second keys `k2` in the map with first key `k1` after the given
+	/// `starting_raw_key`, in no particular order, without decoding the values. If you add or
+	/// remove values whose first key is `k1` to the map while doing this, you'll get undefined
+	/// results.
+	fn iter_key_prefix_from(
+		k1: impl EncodeLike<K1>,
+		starting_raw_key: Vec<u8>,
+	) -> Self::PartialKeyIterator;
+
 	/// Remove all elements from the map with first key `k1` and iterate through them in no
 	/// particular order. If you add elements with first key `k1` to the map while doing this,
 	/// you'll get undefined results.
@@ -337,6 +384,21 @@ pub trait IterableStorageDoubleMap<
 	/// the map while doing this, you'll get undefined results.
 	fn iter() -> Self::Iterator;
 
+	/// Enumerate all elements in the map after the given `starting_raw_key`, in no particular
+	/// order. If you add or remove values to the map while doing this, you'll get undefined
+	/// results.
+	fn iter_from(starting_raw_key: Vec<u8>) -> Self::Iterator;
+
+	/// Enumerate all keys `k1` and `k2` in the map in no particular order, without decoding the
+	/// values. If you add or remove values to the map while doing this, you'll get undefined
+	/// results.
+	fn iter_keys() -> Self::FullKeyIterator;
+
+	/// Enumerate all keys `k1` and `k2` in the map after the given `starting_raw_key`, in no
+	/// particular order, without decoding the values. If you add or remove values to the map
+	/// while doing this, you'll get undefined results.
+	fn iter_keys_from(starting_raw_key: Vec<u8>) -> Self::FullKeyIterator;
+
 	/// Remove all elements from the map and iterate through them in no particular order. If you
 	/// add elements to the map while doing this, you'll get undefined results.
 	fn drain() -> Self::Iterator;
@@ -407,6 +469,13 @@ pub trait StorageDoubleMap<K1: FullEncode, K2: FullEncode, V: FullCodec> {
 	/// Remove all values under the first key.
 	fn remove_prefix<KArg1>(k1: KArg1) where KArg1: ?Sized + EncodeLike<K1>;
 
+	/// Remove at most `limit` values under the first key.
+	///
+	/// If values remain under `k1`, the returned result contains a cursor that can be passed to
+	/// [`unhashed::kill_prefix_from_cursor`] to continue the removal in a later block.
+	fn remove_prefix_with_limit<KArg1>(k1: KArg1, limit: u32) -> PrefixRemovalResult
+		where KArg1: ?Sized + EncodeLike<K1>;
+
 	/// Iterate over values that share the first key.
 	fn iter_prefix_values<KArg1>(k1: KArg1) -> PrefixIterator<V>
 		where KArg1: ?Sized + EncodeLike<K1>;
@@ -492,6 +561,51 @@ pub trait StorageDoubleMap<K1: FullEncode, K2: FullEncode, V: FullCodec> {
 	>(key1: KeyArg1, key2: KeyArg2) -> Option<V>;
 }
 
+/// Opaque position from which a bounded removal of keys under a prefix can be continued.
+///
+/// It can be stored on chain, so the removal can be spread across several blocks.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, crate::RuntimeDebug)]
+pub struct PrefixRemovalCursor {
+	/// The prefix under which keys are removed.
+	pub(crate) prefix: Vec<u8>,
+}
+
+/// The result of removing at most a limited number of keys under a prefix.
+#[derive(Clone, PartialEq, Eq, crate::RuntimeDebug)]
+pub struct PrefixRemovalResult {
+	/// The cursor to continue the removal with, or `None` if no key remains under the prefix.
+	pub maybe_cursor: Option<PrefixRemovalCursor>,
+	/// The number of keys removed.
+	pub removed: u32,
+}
+
+impl PrefixRemovalResult {
+	/// Whether no key remains under the prefix.
+	pub fn all_removed(&self) -> bool {
+		self.maybe_cursor.is_none()
+	}
+}
+
+/// The position reached by an iteration over a storage prefix.
+///
+/// It is the raw key of the last item returned by the iterator. It can be kept in storage
+/// between blocks and handed back to one of the `iter_*from` functions, through
+/// [`IterationCursor::into_raw_key`], to resume the iteration right after that item.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, crate::RuntimeDebug)]
+pub struct IterationCursor(Vec<u8>);
+
+impl IterationCursor {
+	/// The raw key of the last item returned by the iterator.
+	pub fn raw_key(&self) -> &[u8] {
+		&self.0
+	}
+
+	/// Consume the cursor and return the raw key to resume the iteration from.
+	pub fn into_raw_key(self) -> Vec<u8> {
+		self.0
+	}
+}
+
 /// Iterate over a prefix and decode raw_key and raw_value into `T`.
 ///
 /// If any decoding fails it skips it and continues to the next key.
@@ -505,6 +619,41 @@ pub struct PrefixIterator<T> {
 	closure: fn(&[u8], &[u8]) -> Result<T, codec::Error>,
 }
 
+impl<T> PrefixIterator<T> {
+	/// Create an iterator over all items under `prefix`, starting after `previous_key`.
+	///
+	/// `closure` takes `(raw_key_without_prefix, raw_value)` and decodes `T`.
+	pub fn new(
+		prefix: Vec<u8>,
+		previous_key: Vec<u8>,
+		closure: fn(&[u8], &[u8]) -> Result<T, codec::Error>,
+	) -> Self {
+		PrefixIterator { prefix, previous_key, drain: false, closure }
+	}
+
+	/// Mutate this iterator into a draining iterator; items iterated are removed from storage.
+	pub fn drain(mut self) -> Self {
+		self.drain = true;
+		self
+	}
+
+	/// Start the iteration after the given `starting_raw_key` instead.
+	pub fn with_starting_raw_key(mut self, starting_raw_key: Vec<u8>) -> Self {
+		self.previous_key = starting_raw_key;
+		self
+	}
+
+	/// The raw key of the last item returned, or the starting key if none was returned yet.
+	pub fn last_raw_key(&self) -> &[u8] {
+		&self.previous_key
+	}
+
+	/// A cursor to resume this iteration after the last item returned.
+	pub fn cursor(&self) -> IterationCursor {
+		IterationCursor(self.previous_key.clone())
+	}
+}
+
 impl<T> Iterator for PrefixIterator<T> {
 	type Item = T;
 
@@ -548,6 +697,76 @@ impl<T> Iterator for PrefixIterator<T> {
 	}
 }
 
+/// Iterate over a prefix and decode raw_key into `T`, without reading the values.
+///
+/// If any decoding fails it skips it and continues to the next key.
+pub struct KeyPrefixIterator<T> {
+	prefix: Vec<u8>,
+	previous_key: Vec<u8>,
+	/// Function that take `raw_key_without_prefix` and decode `T`.
+	/// `raw_key_without_prefix` is the raw storage key without the prefix iterated on.
+	closure: fn(&[u8]) -> Result<T, codec::Error>,
+}
+
+impl<T> KeyPrefixIterator<T> {
+	/// Create an iterator over all keys under `prefix`, starting after `previous_key`.
+	///
+	/// `closure` takes `raw_key_without_prefix` and decodes `T`.
+	pub fn new(
+		prefix: Vec<u8>,
+		previous_key: Vec<u8>,
+		closure: fn(&[u8]) -> Result<T, codec::Error>,
+	) -> Self {
+		KeyPrefixIterator { prefix, previous_key, closure }
+	}
+
+	/// Start the iteration after the given `starting_raw_key` instead.
+	pub fn with_starting_raw_key(mut self, starting_raw_key: Vec<u8>) -> Self {
+		self.previous_key = starting_raw_key;
+		self
+	}
+
+	/// The raw key of the last item returned, or the starting key if none was returned yet.
+	pub fn last_raw_key(&self) -> &[u8] {
+		&self.previous_key
+	}
+
+	/// A cursor to resume this iteration after the last item returned.
+	pub fn cursor(&self) -> IterationCursor {
+		IterationCursor(self.previous_key.clone())
+	}
+}
+
+impl<T> Iterator for KeyPrefixIterator<T> {
+	type Item = T;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		loop {
+			let maybe_next = sp_io::storage::next_key(&self.previous_key)
+				.filter(|n| n.starts_with(&self.prefix));
+			break match maybe_next {
+				Some(next) => {
+					self.previous_key = next;
+					let raw_key_without_prefix = &self.previous_key[self.prefix.len()..];
+					let item = match (self.closure)(raw_key_without_prefix) {
+						Ok(item) => item,
+						Err(e) => {
+							crate::debug::error!(
+								"key failed to decode at {:?}: {:?}",
+								self.previous_key, e
+							);
+							continue
+						}
+					};
+
+					Some(item)
+				}
+				None => None,
+			}
+		}
+	}
+}
+
 /// Trait for maps that store all its value after a unique prefix.
 ///
 /// By default the final prefix is:
@@ -571,7 +790,15 @@ pub trait StoragePrefixedMap<Value: FullCodec> {
 
 	/// Remove all value of the storage.
 	fn remove_all() {
-		sp_io::storage::clear_prefix(&Self::final_prefix())
+		unhashed::kill_prefix(&Self::final_prefix())
+	}
+
+	/// Remove at most `limit` values of the storage.
+	///
+	/// If values remain, the returned result contains a cursor that can be passed to
+	/// [`unhashed::kill_prefix_from_cursor`] to continue the removal in a later block.
+	fn remove_all_with_limit(limit: u32) -> PrefixRemovalResult {
+		unhashed::kill_prefix_with_limit(&Self::final_prefix(), limit)
 	}
 
 	/// Iter over all value of the storage.
@@ -579,12 +806,18 @@ pub trait StoragePrefixedMap<Value: FullCodec> {
 	/// NOTE: If a value failed to decode becaues storage is corrupted then it is skipped.
 	fn iter_values() -> PrefixIterator<Value> {
 		let prefix = Self::final_prefix();
-		PrefixIterator {
-			prefix: prefix.to_vec(),
-			previous_key: prefix.to_vec(),
-			drain: false,
-			closure: |_raw_key, mut raw_value| Value::decode(&mut raw_value),
-		}
+		Self::iter_values_from(prefix.to_vec())
+	}
+
+	/// Iter over all value of the storage after the given `starting_raw_key`.
+	///
+	/// NOTE: If a value failed to decode becaues storage is corrupted then it is skipped.
+	fn iter_values_from(starting_raw_key: Vec<u8>) -> PrefixIterator<Value> {
+		PrefixIterator::new(
+			Self::final_prefix().to_vec(),
+			starting_raw_key,
+			|_raw_key, mut raw_value| Value::decode(&mut raw_value),
+		)
 	}
 
 	/// Translate the values of all elements by a function `f`, in the map in no particular order.
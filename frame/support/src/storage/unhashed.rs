@@ -19,6 +19,8 @@
 
 use sp_std::prelude::*;
 use codec::{Encode, Decode};
+use sp_io::KillStorageResult;
+use super::{PrefixRemovalCursor, PrefixRemovalResult};
 
 /// Return the value of the item in storage under `key`, or `None` if there is no explicit entry.
 pub fn get<T: Decode + Sized>(key: &[u8]) -> Option<T> {
@@ -93,7 +95,32 @@ pub fn kill(key: &[u8]) {
 
 /// Ensure keys with the given `prefix` have no entries in storage.
 pub fn kill_prefix(prefix: &[u8]) {
-	sp_io::storage::clear_prefix(prefix);
+	let _ = sp_io::storage::clear_prefix(prefix, None);
+}
+
+/// Remove at most `limit` keys with the given `prefix` from storage.
+///
+/// Keys are removed in lexicographic order. If keys remain afterwards, the result holds a
+/// cursor which can be passed to [`kill_prefix_from_cursor`] to continue the removal, e.g. in a
+/// later block.
+pub fn kill_prefix_with_limit(prefix: &[u8], limit: u32) -> PrefixRemovalResult {
+	match sp_io::storage::clear_prefix(prefix, Some(limit)) {
+		KillStorageResult::AllRemoved(removed) => PrefixRemovalResult { maybe_cursor: None, removed },
+		KillStorageResult::SomeRemaining(removed) => PrefixRemovalResult {
+			maybe_cursor: Some(PrefixRemovalCursor { prefix: prefix.to_vec() }),
+			removed,
+		},
+	}
+}
+
+/// Continue a removal started by [`kill_prefix_with_limit`], removing at most `limit` more keys.
+///
+/// Keys which were added under the prefix in the meantime are removed as well.
+pub fn kill_prefix_from_cursor(
+	cursor: PrefixRemovalCursor,
+	limit: u32,
+) -> PrefixRemovalResult {
+	kill_prefix_with_limit(&cursor.prefix, limit)
 }
 
 /// Get a Vec of bytes from storage.
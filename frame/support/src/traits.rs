@@ -33,6 +33,7 @@ use sp_runtime::{
 use crate::dispatch::Parameter;
 use crate::storage::StorageMap;
 use crate::weights::Weight;
+use crate::type_info::TypeInfo;
 use impl_trait_for_tuples::impl_for_tuples;
 
 /// Re-expected for the macro.
@@ -1034,7 +1035,7 @@ pub trait Currency<AccountId> {
 }
 
 /// Status of funds.
-#[derive(PartialEq, Eq, Clone, Copy, Encode, Decode, RuntimeDebug)]
+#[derive(PartialEq, Eq, Clone, Copy, Encode, Decode, RuntimeDebug, TypeInfo)]
 pub enum BalanceStatus {
 	/// Funds are free, as corresponding to `free` item in Balances.
 	Free,
@@ -1042,6 +1043,82 @@ pub enum BalanceStatus {
 	Reserved,
 }
 
+pub mod fungible;
+pub mod fungibles;
+pub mod nonfungibles;
+
+/// One of a number of consequences of withdrawing a fungible from an account.
+#[derive(Copy, Clone, Eq, PartialEq, RuntimeDebug)]
+pub enum WithdrawConsequence<Balance> {
+	/// Withdraw could not happen since the amount to be withdrawn is less than the total funds in
+	/// the account.
+	NoFunds,
+	/// The withdraw would mean the account dying when it needs to exist (usually because other
+	/// modules still hold references to it).
+	WouldDie,
+	/// The asset is unknown. Usually because an `AssetId` has been presented which doesn't exist
+	/// on the system.
+	UnknownAsset,
+	/// There has been an underflow in the system. This is indicative of a corrupt state and
+	/// likely unrecoverable.
+	Underflow,
+	/// Not enough of the funds in the account are available for withdrawal.
+	Frozen,
+	/// Account balance would reduce to zero, potentially destroying it. The parameter is the
+	/// amount of balance which is destroyed.
+	ReducedToZero(Balance),
+	/// Account continued in existence.
+	Success,
+}
+
+impl<Balance: Zero> WithdrawConsequence<Balance> {
+	/// Convert the type into a `Result` with `DispatchError` as the error or the additional
+	/// `Balance` by which the account will be reduced.
+	pub fn into_result(self) -> Result<Balance, DispatchError> {
+		use WithdrawConsequence::*;
+		match self {
+			NoFunds => Err(DispatchError::Other("NoFunds")),
+			WouldDie => Err(DispatchError::Other("WouldDie")),
+			UnknownAsset => Err(DispatchError::Other("UnknownAsset")),
+			Underflow => Err(DispatchError::Other("Underflow")),
+			Frozen => Err(DispatchError::Other("Frozen")),
+			ReducedToZero(result) => Ok(result),
+			Success => Ok(Zero::zero()),
+		}
+	}
+}
+
+/// One of a number of consequences of depositing a fungible into an account.
+#[derive(Copy, Clone, Eq, PartialEq, RuntimeDebug)]
+pub enum DepositConsequence {
+	/// Deposit couldn't happen due to the amount being too low. This is usually because the
+	/// account doesn't yet exist and the deposit wouldn't bring it to at least the minimum needed
+	/// for existence.
+	BelowMinimum,
+	/// The asset is unknown. Usually because an `AssetId` has been presented which doesn't exist
+	/// on the system.
+	UnknownAsset,
+	/// An overflow would occur. This is practically unexpected, but could happen in test systems
+	/// with extremely small balance types or balances that approach the max value of the balance
+	/// type.
+	Overflow,
+	/// Account continued in existence.
+	Success,
+}
+
+impl DepositConsequence {
+	/// Convert the type into a `Result` with `DispatchError` as the error.
+	pub fn into_result(self) -> DispatchResult {
+		use DepositConsequence::*;
+		match self {
+			BelowMinimum => Err(DispatchError::Other("BelowMinimum")),
+			UnknownAsset => Err(DispatchError::Other("UnknownAsset")),
+			Overflow => Err(DispatchError::Other("Overflow")),
+			Success => Ok(()),
+		}
+	}
+}
+
 /// A currency where funds can be reserved from the user.
 pub trait ReservableCurrency<AccountId>: Currency<AccountId> {
 	/// Same result as `reserve(who, value)` (but without the side-effects) assuming there
@@ -1103,6 +1180,138 @@ pub trait ReservableCurrency<AccountId>: Currency<AccountId> {
 	) -> result::Result<Self::Balance, DispatchError>;
 }
 
+/// A currency whose reserved balance can be attributed to individual, named reserves.
+///
+/// Named reserves are a subset of the anonymous reserved balance of `ReservableCurrency`: every
+/// named operation also updates `reserved_balance`, but an operation under one identifier can
+/// never touch the funds held under another. Likewise, the anonymous operations of
+/// `ReservableCurrency` only touch the part of the reserved balance which isn't named. Existing
+/// anonymous reserves stay valid, so pallets can migrate one at a time by attributing the funds
+/// they already hold with `name_reserved`.
+pub trait NamedReservableCurrency<AccountId>: ReservableCurrency<AccountId> {
+	/// An identifier for a reserve. Used for disambiguating different reserves so that
+	/// they can be individually replaced or removed.
+	type ReserveIdentifier;
+
+	/// Deducts up to `value` from the reserved balance of `who` held under `id`. This function
+	/// cannot fail.
+	///
+	/// As much funds up to `value` will be deducted as possible. If the named reserve of `who`
+	/// is less than `value`, then a non-zero second item will be returned.
+	fn slash_reserved_named(
+		id: &Self::ReserveIdentifier,
+		who: &AccountId,
+		value: Self::Balance
+	) -> (Self::NegativeImbalance, Self::Balance);
+
+	/// The amount of the balance of a given account that is reserved under `id`.
+	fn reserved_balance_named(id: &Self::ReserveIdentifier, who: &AccountId) -> Self::Balance;
+
+	/// Moves `value` from balance to reserved balance, attributing it to `id`.
+	///
+	/// If the free balance is lower than `value`, then no funds will be moved and an `Err` will
+	/// be returned to notify of this. This is different behavior than `unreserve_named`.
+	fn reserve_named(
+		id: &Self::ReserveIdentifier,
+		who: &AccountId,
+		value: Self::Balance,
+	) -> DispatchResult;
+
+	/// Moves up to `value` from the reserve held under `id` to free balance. This function
+	/// cannot fail.
+	///
+	/// As much funds up to `value` will be moved as possible. If the named reserve of `who`
+	/// is less than `value`, then the remaining amount will be returned.
+	fn unreserve_named(
+		id: &Self::ReserveIdentifier,
+		who: &AccountId,
+		value: Self::Balance,
+	) -> Self::Balance;
+
+	/// Moves up to `value` from the reserve of account `slashed` held under `id` to balance of
+	/// account `beneficiary`. `beneficiary` must exist for this to succeed. If it does not, `Err`
+	/// will be returned. Funds will be placed in either the `free` balance or the `reserved`
+	/// balance (under the same `id`), depending on the `status`.
+	///
+	/// As much funds up to `value` will be deducted as possible. If this is less than `value`,
+	/// then `Ok(non_zero)` will be returned.
+	fn repatriate_reserved_named(
+		id: &Self::ReserveIdentifier,
+		slashed: &AccountId,
+		beneficiary: &AccountId,
+		value: Self::Balance,
+		status: BalanceStatus,
+	) -> result::Result<Self::Balance, DispatchError>;
+
+	/// Attributes `value` of the already reserved, but not yet named, balance of `who` to `id`.
+	///
+	/// No funds are moved. This is the migration path for pallets that used to reserve through
+	/// `ReservableCurrency`: they can name the deposits they hold without unreserving them first.
+	/// Fails if less than `value` of the reserved balance of `who` is unnamed.
+	fn name_reserved(
+		id: &Self::ReserveIdentifier,
+		who: &AccountId,
+		value: Self::Balance,
+	) -> DispatchResult;
+
+	/// Ensure the reserved balance held under `id` is equal to `value`.
+	///
+	/// This will reserve the extra amount if the current named reserve is less than `value`,
+	/// and unreserve the difference if it is greater.
+	fn ensure_reserved_named(
+		id: &Self::ReserveIdentifier,
+		who: &AccountId,
+		value: Self::Balance,
+	) -> DispatchResult {
+		let current = Self::reserved_balance_named(id, who);
+		if current > value {
+			// we always have enough balance to unreserve here
+			Self::unreserve_named(id, who, current - value);
+			Ok(())
+		} else if value > current {
+			// we checked value > current
+			Self::reserve_named(id, who, value - current)
+		} else { // current == value
+			Ok(())
+		}
+	}
+
+	/// Unreserve all the named reserved balances, returning unreserved amount.
+	///
+	/// Is a no-op if the value to be unreserved is zero.
+	fn unreserve_all_named(id: &Self::ReserveIdentifier, who: &AccountId) -> Self::Balance {
+		let value = Self::reserved_balance_named(id, who);
+		Self::unreserve_named(id, who, value);
+		value
+	}
+
+	/// Slash all the reserved balance held under `id`, returning the negative imbalance created.
+	///
+	/// Is a no-op if the value to be slashed is zero.
+	fn slash_all_reserved_named(
+		id: &Self::ReserveIdentifier,
+		who: &AccountId,
+	) -> Self::NegativeImbalance {
+		let value = Self::reserved_balance_named(id, who);
+		Self::slash_reserved_named(id, who, value).0
+	}
+
+	/// Move all the reserved balance held under `id` of one account into the balance of
+	/// another, according to `status`. If `status` is `Reserved`, the balance will be reserved
+	/// under the same `id` on `beneficiary`.
+	///
+	/// Is a no-op if the value to be moved is zero.
+	fn repatriate_all_reserved_named(
+		id: &Self::ReserveIdentifier,
+		slashed: &AccountId,
+		beneficiary: &AccountId,
+		status: BalanceStatus,
+	) -> DispatchResult {
+		let value = Self::reserved_balance_named(id, slashed);
+		Self::repatriate_reserved_named(id, slashed, beneficiary, value, status).map(|_| ())
+	}
+}
+
 /// An identifier for a lock. Used for disambiguating different locks so that
 /// they can be individually replaced or removed.
 pub type LockIdentifier = [u8; 8];
@@ -1165,7 +1374,7 @@ pub trait VestingSchedule<AccountId> {
 
 	/// Adds a vesting schedule to a given account.
 	///
-	/// If there already exists a vesting schedule for the given account, an `Err` is returned
+	/// If the account already has the maximum number of vesting schedules, an `Err` is returned
 	/// and nothing is updated.
 	///
 	/// Is a no-op if the amount to be vested is zero.
@@ -1178,10 +1387,18 @@ pub trait VestingSchedule<AccountId> {
 		starting_block: Self::Moment,
 	) -> DispatchResult;
 
-	/// Remove a vesting schedule for a given account.
+	/// Checks if `add_vesting_schedule` would work against `who`.
+	fn can_add_vesting_schedule(
+		who: &AccountId,
+		locked: <Self::Currency as Currency<AccountId>>::Balance,
+		per_block: <Self::Currency as Currency<AccountId>>::Balance,
+		starting_block: Self::Moment,
+	) -> DispatchResult;
+
+	/// Remove the vesting schedule at `schedule_index` for a given account.
 	///
 	/// NOTE: This doesn't alter the free balance of the account.
-	fn remove_vesting_schedule(who: &AccountId);
+	fn remove_vesting_schedule(who: &AccountId, schedule_index: u32) -> DispatchResult;
 }
 
 bitmask! {
@@ -1449,6 +1666,46 @@ impl<BlockNumber: Clone> OnInitialize<BlockNumber> for Tuple {
 	}
 }
 
+/// The block idle trait.
+///
+/// Implementing this lets you make use of the weight left unused once all the extrinsics of a
+/// block have been applied, e.g. to make progress on work spread over several blocks such as lazy
+/// migrations or garbage collection.
+pub trait OnIdle<BlockNumber> {
+	/// The block is being finalized and `remaining_weight` is still available.
+	///
+	/// Return the weight consumed, which must not exceed `remaining_weight`.
+	fn on_idle(
+		_n: BlockNumber,
+		_remaining_weight: crate::weights::Weight,
+	) -> crate::weights::Weight {
+		0
+	}
+}
+
+/// Calls the members of the tuple in a rotating order, starting with the member at index
+/// `n % len`, so that no module is always served last when the remaining weight runs out. Each
+/// member is given the weight left unused by the members called before it.
+#[impl_for_tuples(30)]
+impl<BlockNumber: Copy + AtLeast32BitUnsigned> OnIdle<BlockNumber> for Tuple {
+	fn on_idle(n: BlockNumber, remaining_weight: crate::weights::Weight) -> crate::weights::Weight {
+		let on_idle_functions: &[fn(BlockNumber, crate::weights::Weight) -> crate::weights::Weight] =
+			&[for_tuples!( #( Tuple::on_idle ),* )];
+		let len = on_idle_functions.len();
+		if len == 0 {
+			return 0;
+		}
+
+		let start_index: u32 = (n % (len as u32).into()).unique_saturated_into();
+		let mut weight: crate::weights::Weight = 0;
+		for on_idle in on_idle_functions.iter().cycle().skip(start_index as usize).take(len) {
+			let adjusted_remaining_weight = remaining_weight.saturating_sub(weight);
+			weight = weight.saturating_add(on_idle(n, adjusted_remaining_weight));
+		}
+		weight
+	}
+}
+
 /// The runtime upgrade trait.
 ///
 /// Implementing this lets you express what should happen when the runtime upgrades,
@@ -1509,7 +1766,7 @@ pub mod schedule {
 	pub type Priority = u8;
 
 	/// The dispatch time of a scheduled task.
-	#[derive(Encode, Decode, Copy, Clone, PartialEq, Eq, RuntimeDebug)]
+	#[derive(Encode, Decode, Copy, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
 	pub enum DispatchTime<BlockNumber> {
 		/// At specified block.
 		At(BlockNumber),
@@ -1526,6 +1783,80 @@ pub mod schedule {
 	/// The lowest priority. Most stuff should be around here.
 	pub const LOWEST_PRIORITY: Priority = 255;
 
+	/// A value given either in full or by the hash of its encoding, such as the call of a
+	/// scheduled task whose preimage is looked up once it is due.
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+	pub enum MaybeHashed<T, Hash> {
+		/// The value itself.
+		Value(T),
+		/// The hash of the encoded value.
+		Hash(Hash),
+	}
+
+	impl<T, Hash> From<T> for MaybeHashed<T, Hash> {
+		fn from(t: T) -> Self {
+			MaybeHashed::Value(t)
+		}
+	}
+
+	/// The reason the preimage of a `MaybeHashed` value could not be looked up.
+	#[derive(Encode, Decode, Copy, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+	pub enum LookupError {
+		/// The preimage is not available.
+		Unknown,
+		/// The preimage is available but does not decode into the value.
+		BadFormat,
+	}
+
+	impl<T: Decode, Hash> MaybeHashed<T, Hash> {
+		/// The value, if it is given in full.
+		pub fn as_value(&self) -> Option<&T> {
+			match self {
+				MaybeHashed::Value(t) => Some(t),
+				MaybeHashed::Hash(_) => None,
+			}
+		}
+
+		/// The hash of the value, if it is given by hash.
+		pub fn as_hash(&self) -> Option<&Hash> {
+			match self {
+				MaybeHashed::Value(_) => None,
+				MaybeHashed::Hash(h) => Some(h),
+			}
+		}
+
+		/// Request the preimage of the value from `P`, if it is given by hash.
+		pub fn ensure_requested<P: PreimageProvider<Hash>>(&self) {
+			if let MaybeHashed::Hash(hash) = self {
+				P::request_preimage(hash)
+			}
+		}
+
+		/// Clear the request for the preimage of the value from `P`, if it is given by hash.
+		pub fn ensure_unrequested<P: PreimageProvider<Hash>>(&self) {
+			if let MaybeHashed::Hash(hash) = self {
+				P::unrequest_preimage(hash)
+			}
+		}
+
+		/// Look up the value from its preimage in `P`, if it is given by hash.
+		///
+		/// Returns the value in full along with the hash it was given by, if any, or the error
+		/// the lookup failed with.
+		pub fn resolved<P: PreimageProvider<Hash>>(self) -> Result<(T, Option<Hash>), (Self, LookupError)> {
+			match self {
+				MaybeHashed::Value(t) => Ok((t, None)),
+				MaybeHashed::Hash(hash) => match P::get_preimage(&hash) {
+					None => Err((MaybeHashed::Hash(hash), LookupError::Unknown)),
+					Some(data) => match T::decode(&mut &data[..]) {
+						Ok(t) => Ok((t, Some(hash))),
+						Err(_) => Err((MaybeHashed::Hash(hash), LookupError::BadFormat)),
+					},
+				},
+			}
+		}
+	}
+
 	/// A type that can be used as a scheduler.
 	pub trait Anon<BlockNumber, Call, Origin> {
 		/// An address which can be used for removing a scheduled task.
@@ -1611,6 +1942,54 @@ pub mod schedule {
 	}
 }
 
+/// A interface for looking up preimages from their hash on chain.
+pub trait PreimageProvider<Hash> {
+	/// Returns whether a preimage exists for a given hash.
+	///
+	/// A value of `true` implies that `get_preimage` is `Some`.
+	fn have_preimage(hash: &Hash) -> bool;
+
+	/// Returns the preimage for a given hash.
+	fn get_preimage(hash: &Hash) -> Option<Vec<u8>>;
+
+	/// Returns whether a preimage request exists for a given hash.
+	fn preimage_requested(hash: &Hash) -> bool;
+
+	/// Request that someone report a preimage. Providers use this to optimise the economics for
+	/// preimage reporting.
+	fn request_preimage(hash: &Hash);
+
+	/// Cancel a previous preimage request.
+	fn unrequest_preimage(hash: &Hash);
+}
+
+impl<Hash> PreimageProvider<Hash> for () {
+	fn have_preimage(_: &Hash) -> bool { false }
+	fn get_preimage(_: &Hash) -> Option<Vec<u8>> { None }
+	fn preimage_requested(_: &Hash) -> bool { false }
+	fn request_preimage(_: &Hash) {}
+	fn unrequest_preimage(_: &Hash) {}
+}
+
+/// A interface for managing preimages to hashes on chain.
+///
+/// Note that this API does not assume any underlying user is calling, and thus
+/// does not handle any preimage ownership or fees. Other system level logic that
+/// uses this API should implement that on their own side.
+pub trait PreimageRecipient<Hash>: PreimageProvider<Hash> {
+	/// Maximum size of a preimage.
+	type MaxSize: Get<u32>;
+
+	/// Store the bytes of a preimage on chain. Fails if the preimage is larger than `MaxSize` or
+	/// is already noted.
+	fn note_preimage(bytes: Vec<u8>) -> DispatchResult;
+
+	/// Clear a previously noted preimage. This is infallible and should be treated more like a
+	/// hint - if it was not previously noted or if it is now requested, then this will not do
+	/// anything.
+	fn unnote_preimage(hash: &Hash);
+}
+
 /// Some sort of check on the origin is performed by this object.
 pub trait EnsureOrigin<OuterOrigin> {
 	/// A return type.
@@ -1847,4 +2226,58 @@ mod tests {
 		assert_eq!(<(Test, Test)>::on_initialize(0), 20);
 		assert_eq!(<(Test, Test)>::on_runtime_upgrade(), 40);
 	}
+
+	#[test]
+	fn on_idle_round_robin_works() {
+		thread_local! {
+			static ON_IDLE_INVOCATION_ORDER: RefCell<Vec<&'static str>> = RefCell::new(Vec::new());
+		}
+
+		struct Test1;
+		struct Test2;
+		struct Test3;
+		impl OnIdle<u32> for Test1 {
+			fn on_idle(_n: u32, _weight: crate::weights::Weight) -> crate::weights::Weight {
+				ON_IDLE_INVOCATION_ORDER.with(|o| o.borrow_mut().push("Test1"));
+				0
+			}
+		}
+		impl OnIdle<u32> for Test2 {
+			fn on_idle(_n: u32, _weight: crate::weights::Weight) -> crate::weights::Weight {
+				ON_IDLE_INVOCATION_ORDER.with(|o| o.borrow_mut().push("Test2"));
+				0
+			}
+		}
+		impl OnIdle<u32> for Test3 {
+			fn on_idle(_n: u32, _weight: crate::weights::Weight) -> crate::weights::Weight {
+				ON_IDLE_INVOCATION_ORDER.with(|o| o.borrow_mut().push("Test3"));
+				0
+			}
+		}
+
+		let order = |n| {
+			ON_IDLE_INVOCATION_ORDER.with(|o| o.borrow_mut().clear());
+			<(Test1, Test2, Test3)>::on_idle(n, 10);
+			ON_IDLE_INVOCATION_ORDER.with(|o| o.borrow().clone())
+		};
+
+		assert_eq!(order(0), vec!["Test1", "Test2", "Test3"]);
+		assert_eq!(order(1), vec!["Test2", "Test3", "Test1"]);
+		assert_eq!(order(2), vec!["Test3", "Test1", "Test2"]);
+		assert_eq!(order(3), vec!["Test1", "Test2", "Test3"]);
+		assert_eq!(<()>::on_idle(7, 10), 0);
+	}
+
+	#[test]
+	fn on_idle_passes_on_remaining_weight() {
+		struct Greedy;
+		impl OnIdle<u32> for Greedy {
+			fn on_idle(_n: u32, remaining_weight: crate::weights::Weight) -> crate::weights::Weight {
+				remaining_weight.min(30)
+			}
+		}
+
+		assert_eq!(<(Greedy, Greedy, Greedy)>::on_idle(0, 50), 50);
+		assert_eq!(<(Greedy, Greedy, Greedy)>::on_idle(0, 100), 90);
+	}
 }
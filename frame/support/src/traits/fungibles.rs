@@ -0,0 +1,529 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2020 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The traits for sets of fungible tokens and any associated types.
+//!
+//! These mirror the traits of the [`fungible`](../fungible/index.html) module, with every
+//! operation additionally taking the identifier of the asset it applies to.
+
+use sp_std::{fmt::Debug, marker::PhantomData};
+use codec::FullCodec;
+use sp_runtime::{
+	DispatchResult, DispatchError,
+	traits::{AtLeast32BitUnsigned, Zero, CheckedAdd, Saturating},
+};
+use super::{WithdrawConsequence, DepositConsequence, Get, fungible};
+
+/// Trait for providing balance-inspection access to a set of named fungible assets.
+pub trait Inspect<AccountId> {
+	/// Means of identifying one asset class from another.
+	type AssetId: FullCodec + Copy + Eq + PartialEq + Debug;
+
+	/// Scalar type for representing balance of an account.
+	type Balance: AtLeast32BitUnsigned + FullCodec + Copy + Default + Debug;
+
+	/// The total amount of issuance in the system.
+	fn total_issuance(asset: Self::AssetId) -> Self::Balance;
+
+	/// The minimum balance any single account may have.
+	fn minimum_balance(asset: Self::AssetId) -> Self::Balance;
+
+	/// Get the `asset` balance of `who`, not including any funds which are on hold.
+	///
+	/// The funds on hold are given by `InspectHold::balance_on_hold`, the total balance of `who`
+	/// is the sum of both.
+	fn balance(asset: Self::AssetId, who: &AccountId) -> Self::Balance;
+
+	/// Get the maximum amount of `asset` that `who` can withdraw or transfer successfully,
+	/// leaving at least the minimum balance in the account if `keep_alive` is `true`.
+	fn reducible_balance(asset: Self::AssetId, who: &AccountId, keep_alive: bool) -> Self::Balance;
+
+	/// Returns `Success` if the `asset` balance of `who` may be increased by `amount`, otherwise
+	/// the consequence.
+	fn can_deposit(asset: Self::AssetId, who: &AccountId, amount: Self::Balance)
+		-> DepositConsequence;
+
+	/// Returns `Success` if the `asset` balance of `who` may be decreased by `amount`, otherwise
+	/// the consequence.
+	fn can_withdraw(
+		asset: Self::AssetId,
+		who: &AccountId,
+		amount: Self::Balance,
+	) -> WithdrawConsequence<Self::Balance>;
+}
+
+/// Trait for providing a set of named fungible assets which can be minted and burned.
+pub trait Mutate<AccountId>: Inspect<AccountId> {
+	/// Increase the `asset` balance of `who` by exactly `amount`, minting new tokens. If that
+	/// isn't possible then an `Err` is returned and nothing is changed.
+	fn mint_into(asset: Self::AssetId, who: &AccountId, amount: Self::Balance) -> DispatchResult;
+
+	/// Decrease the `asset` balance of `who` by at least `amount`, possibly slightly more in the
+	/// case of minimum balance requirements, burning the tokens. If that isn't possible then an
+	/// `Err` is returned and nothing is changed. If successful, the amount of tokens reduced is
+	/// returned.
+	fn burn_from(asset: Self::AssetId, who: &AccountId, amount: Self::Balance)
+		-> Result<Self::Balance, DispatchError>;
+
+	/// Attempt to reduce the `asset` balance of `who` by as much as possible up to `amount`, and
+	/// possibly slightly more due to minimum balance requirements. If no decrease is possible then
+	/// an `Err` is returned and nothing is changed. If successful, the amount of tokens reduced is
+	/// returned.
+	///
+	/// The default implementation just uses `burn_from` on the reducible balance of `who`.
+	fn slash(asset: Self::AssetId, who: &AccountId, amount: Self::Balance)
+		-> Result<Self::Balance, DispatchError>
+	{
+		let reducible = Self::reducible_balance(asset, who, false);
+		Self::burn_from(asset, who, amount.min(reducible))
+	}
+
+	/// Transfer funds from one account into another. The default implementation uses `mint_into`
+	/// and `burn_from` and may generate unwanted events.
+	fn teleport(
+		asset: Self::AssetId,
+		source: &AccountId,
+		dest: &AccountId,
+		amount: Self::Balance,
+	) -> Result<Self::Balance, DispatchError> {
+		let extra = Self::can_withdraw(asset, &source, amount).into_result()?;
+		Self::can_deposit(asset, &dest, amount.saturating_add(extra)).into_result()?;
+		let actual = Self::burn_from(asset, source, amount)?;
+		debug_assert!(
+			actual == amount.saturating_add(extra),
+			"can_withdraw must agree with withdraw; qed",
+		);
+		match Self::mint_into(asset, dest, actual) {
+			Ok(_) => Ok(actual),
+			Err(err) => {
+				debug_assert!(false, "can_deposit returned true previously; qed");
+				// attempt to return the funds back to source
+				let revert = Self::mint_into(asset, source, actual);
+				debug_assert!(revert.is_ok(), "withdrew funds previously; qed");
+				Err(err)
+			}
+		}
+	}
+}
+
+/// Trait for providing a set of named fungible assets which can only be transferred.
+pub trait Transfer<AccountId>: Inspect<AccountId> {
+	/// Transfer `asset` funds from one account into another. If `keep_alive` is `true`, the
+	/// `source` account must retain at least the minimum balance. Returns the amount transferred.
+	fn transfer(
+		asset: Self::AssetId,
+		source: &AccountId,
+		dest: &AccountId,
+		amount: Self::Balance,
+		keep_alive: bool,
+	) -> Result<Self::Balance, DispatchError>;
+}
+
+/// A set of fungible token classes where the balances can be set arbitrarily.
+///
+/// **WARNING**
+/// Do not use this directly unless you want trouble, since it allows you to alter account balances
+/// without keeping the issuance up to date. It has no safeguards against accidentally creating
+/// token imbalances in your system leading to accidental inflation or deflation. It's really just
+/// for the underlying datatype to implement so the user gets the much safer `Mutate` and
+/// `Transfer` traits.
+pub trait Unbalanced<AccountId>: Inspect<AccountId> {
+	/// Set the `asset` balance of `who` to `amount`. If this cannot be done for some reason (e.g.
+	/// because the account cannot be created or an overflow) then an `Err` is returned.
+	///
+	/// Like `Inspect::balance`, `amount` doesn't include the funds on hold, which are kept.
+	fn set_balance(asset: Self::AssetId, who: &AccountId, amount: Self::Balance) -> DispatchResult;
+
+	/// Set the total issuance of `asset` to `amount`.
+	fn set_total_issuance(asset: Self::AssetId, amount: Self::Balance);
+
+	/// Reduce the `asset` balance of `who` by `amount`. If it cannot be reduced by that amount
+	/// for some reason, return `Err` and don't reduce it at all. If successful, return the amount
+	/// by which the balance was reduced, which may be more than `amount` if the remainder would
+	/// be below the minimum balance.
+	///
+	/// Minimum balance will be respected and the returned imbalance may be up to
+	/// `Self::minimum_balance() - 1` greater than `amount`.
+	fn decrease_balance(asset: Self::AssetId, who: &AccountId, amount: Self::Balance)
+		-> Result<Self::Balance, DispatchError>
+	{
+		let old_balance = Self::balance(asset, who);
+		let (mut new_balance, mut amount) = if old_balance < amount {
+			return Err(DispatchError::Other("NoFunds"));
+		} else {
+			(old_balance - amount, amount)
+		};
+		if new_balance < Self::minimum_balance(asset) {
+			amount = amount.saturating_add(new_balance);
+			new_balance = Zero::zero();
+		}
+		// Defensive only - this should not fail now.
+		Self::set_balance(asset, who, new_balance)?;
+		Ok(amount)
+	}
+
+	/// Reduce the `asset` balance of `who` by the most that is possible, up to `amount`.
+	///
+	/// Minimum balance will be respected and the returned amount may be up to
+	/// `Self::minimum_balance() - 1` greater than `amount`.
+	///
+	/// Return the amount by which the balance was reduced.
+	fn decrease_balance_at_most(asset: Self::AssetId, who: &AccountId, amount: Self::Balance)
+		-> Self::Balance
+	{
+		let old_balance = Self::balance(asset, who);
+		let (mut new_balance, mut amount) = if old_balance < amount {
+			(Zero::zero(), old_balance)
+		} else {
+			(old_balance - amount, amount)
+		};
+		let minimum_balance = Self::minimum_balance(asset);
+		if new_balance < minimum_balance {
+			amount = amount.saturating_add(new_balance);
+			new_balance = Zero::zero();
+		}
+		let mut r = Self::set_balance(asset, who, new_balance);
+		if r.is_err() {
+			// Some error, probably because we tried to destroy an account which cannot be
+			// destroyed.
+			if new_balance.is_zero() && amount >= minimum_balance {
+				new_balance = minimum_balance;
+				amount -= minimum_balance;
+				r = Self::set_balance(asset, who, new_balance);
+			}
+			if r.is_err() {
+				// Still an error. Apparently it's not possible to reduce at all.
+				amount = Zero::zero();
+			}
+		}
+		amount
+	}
+
+	/// Increase the `asset` balance of `who` by `amount`. If it cannot be increased by that
+	/// amount for some reason, return `Err` and don't increase it at all. If successful, return
+	/// `amount`.
+	///
+	/// Minimum balance will be respected and an error will be returned if
+	/// `amount < Self::minimum_balance()` when the account of `who` is zero.
+	fn increase_balance(asset: Self::AssetId, who: &AccountId, amount: Self::Balance)
+		-> Result<Self::Balance, DispatchError>
+	{
+		let old_balance = Self::balance(asset, who);
+		let new_balance = old_balance.checked_add(&amount).ok_or(DispatchError::Other("Overflow"))?;
+		if new_balance < Self::minimum_balance(asset) {
+			DepositConsequence::BelowMinimum.into_result()?;
+		}
+		if old_balance != new_balance {
+			Self::set_balance(asset, who, new_balance)?;
+		}
+		Ok(amount)
+	}
+
+	/// Increase the `asset` balance of `who` by the most that is possible, up to `amount`.
+	///
+	/// Minimum balance will be respected and `Zero::zero()` will be returned if
+	/// `amount < Self::minimum_balance()` when the account of `who` is zero.
+	///
+	/// Return the amount by which the balance was increased.
+	fn increase_balance_at_most(asset: Self::AssetId, who: &AccountId, amount: Self::Balance)
+		-> Self::Balance
+	{
+		let old_balance = Self::balance(asset, who);
+		let mut new_balance = old_balance.saturating_add(amount);
+		let mut amount = new_balance - old_balance;
+		if new_balance < Self::minimum_balance(asset) {
+			new_balance = Zero::zero();
+			amount = Zero::zero();
+		}
+		if old_balance == new_balance || Self::set_balance(asset, who, new_balance).is_ok() {
+			amount
+		} else {
+			Zero::zero()
+		}
+	}
+}
+
+/// Trait for inspecting a set of named fungible assets which can be placed on hold.
+pub trait InspectHold<AccountId>: Inspect<AccountId> {
+	/// Amount of `asset` funds held in reserve by `who`.
+	fn balance_on_hold(asset: Self::AssetId, who: &AccountId) -> Self::Balance;
+
+	/// Check to see if some `amount` of `asset` funds of `who` may be placed on hold.
+	fn can_hold(asset: Self::AssetId, who: &AccountId, amount: Self::Balance) -> bool;
+}
+
+/// Trait for mutating a set of named fungible assets which can be placed on hold.
+pub trait MutateHold<AccountId>: InspectHold<AccountId> + Transfer<AccountId> {
+	/// Hold some `asset` funds in an account.
+	fn hold(asset: Self::AssetId, who: &AccountId, amount: Self::Balance) -> DispatchResult;
+
+	/// Release up to `amount` held `asset` funds in an account.
+	///
+	/// The actual amount released is returned with `Ok`.
+	///
+	/// If `best_effort` is `true`, then the amount actually unreserved and returned as the inner
+	/// value of `Ok` may be smaller than the `amount` passed.
+	fn release(asset: Self::AssetId, who: &AccountId, amount: Self::Balance, best_effort: bool)
+		-> Result<Self::Balance, DispatchError>;
+
+	/// Transfer held `asset` funds into a destination account.
+	///
+	/// If `on_hold` is `true`, then the destination account must already exist and the assets
+	/// transferred will still be on hold in the destination account. If not, then the destination
+	/// account need not already exist, but must be creatable.
+	///
+	/// If `best_effort` is `true`, then an amount less than `amount` may be transferred without
+	/// error.
+	///
+	/// The actual amount transferred is returned, or `Err` in the case of error and nothing is
+	/// changed.
+	fn transfer_held(
+		asset: Self::AssetId,
+		source: &AccountId,
+		dest: &AccountId,
+		amount: Self::Balance,
+		best_effort: bool,
+		on_hold: bool,
+	) -> Result<Self::Balance, DispatchError>;
+}
+
+/// Trait for inspecting a set of named fungible assets whose accounts can be frozen.
+pub trait InspectFreeze<AccountId>: Inspect<AccountId> {
+	/// Returns `true` if no `asset` funds can be withdrawn from `who`.
+	fn is_frozen(asset: Self::AssetId, who: &AccountId) -> bool;
+}
+
+/// Trait for freezing and thawing the accounts of a set of named fungible assets.
+pub trait MutateFreeze<AccountId>: InspectFreeze<AccountId> {
+	/// Prevent any `asset` funds from being withdrawn from `who`.
+	fn freeze(asset: Self::AssetId, who: &AccountId) -> DispatchResult;
+
+	/// Allow `asset` funds to be withdrawn from `who` again.
+	fn thaw(asset: Self::AssetId, who: &AccountId) -> DispatchResult;
+}
+
+/// Presents a single-asset [`fungible`](../fungible/index.html) implementation `F` as the asset
+/// with identifier `A` of a set of fungible assets.
+///
+/// Any other asset identifier refers to an unknown asset: it has no issuance nor balances and
+/// every attempt to alter it fails.
+pub struct SingleAsset<F, A, AssetId>(PhantomData<(F, A, AssetId)>);
+
+impl<F, A: Get<AssetId>, AssetId: PartialEq> SingleAsset<F, A, AssetId> {
+	/// Returns `true` if `asset` is the asset represented by `F`.
+	fn is_known(asset: &AssetId) -> bool {
+		asset == &A::get()
+	}
+
+	/// Ensure that `asset` is the asset represented by `F`.
+	fn ensure_known(asset: &AssetId) -> DispatchResult {
+		if Self::is_known(asset) {
+			Ok(())
+		} else {
+			DepositConsequence::UnknownAsset.into_result()
+		}
+	}
+}
+
+impl<
+	F: fungible::Inspect<AccountId>,
+	A: Get<AssetId>,
+	AssetId: FullCodec + Copy + Eq + PartialEq + Debug,
+	AccountId,
+> Inspect<AccountId> for SingleAsset<F, A, AssetId> {
+	type AssetId = AssetId;
+	type Balance = F::Balance;
+
+	fn total_issuance(asset: AssetId) -> F::Balance {
+		if Self::is_known(&asset) { F::total_issuance() } else { Zero::zero() }
+	}
+
+	fn minimum_balance(asset: AssetId) -> F::Balance {
+		if Self::is_known(&asset) { F::minimum_balance() } else { Zero::zero() }
+	}
+
+	fn balance(asset: AssetId, who: &AccountId) -> F::Balance {
+		if Self::is_known(&asset) { F::balance(who) } else { Zero::zero() }
+	}
+
+	fn reducible_balance(asset: AssetId, who: &AccountId, keep_alive: bool) -> F::Balance {
+		if Self::is_known(&asset) { F::reducible_balance(who, keep_alive) } else { Zero::zero() }
+	}
+
+	fn can_deposit(asset: AssetId, who: &AccountId, amount: F::Balance) -> DepositConsequence {
+		if Self::is_known(&asset) {
+			F::can_deposit(who, amount)
+		} else {
+			DepositConsequence::UnknownAsset
+		}
+	}
+
+	fn can_withdraw(
+		asset: AssetId,
+		who: &AccountId,
+		amount: F::Balance,
+	) -> WithdrawConsequence<F::Balance> {
+		if Self::is_known(&asset) {
+			F::can_withdraw(who, amount)
+		} else {
+			WithdrawConsequence::UnknownAsset
+		}
+	}
+}
+
+impl<
+	F: fungible::Mutate<AccountId>,
+	A: Get<AssetId>,
+	AssetId: FullCodec + Copy + Eq + PartialEq + Debug,
+	AccountId,
+> Mutate<AccountId> for SingleAsset<F, A, AssetId> {
+	fn mint_into(asset: AssetId, who: &AccountId, amount: F::Balance) -> DispatchResult {
+		Self::ensure_known(&asset)?;
+		F::mint_into(who, amount)
+	}
+
+	fn burn_from(asset: AssetId, who: &AccountId, amount: F::Balance)
+		-> Result<F::Balance, DispatchError>
+	{
+		Self::ensure_known(&asset)?;
+		F::burn_from(who, amount)
+	}
+
+	fn slash(asset: AssetId, who: &AccountId, amount: F::Balance)
+		-> Result<F::Balance, DispatchError>
+	{
+		Self::ensure_known(&asset)?;
+		F::slash(who, amount)
+	}
+
+	fn teleport(asset: AssetId, source: &AccountId, dest: &AccountId, amount: F::Balance)
+		-> Result<F::Balance, DispatchError>
+	{
+		Self::ensure_known(&asset)?;
+		F::teleport(source, dest, amount)
+	}
+}
+
+impl<
+	F: fungible::Transfer<AccountId>,
+	A: Get<AssetId>,
+	AssetId: FullCodec + Copy + Eq + PartialEq + Debug,
+	AccountId,
+> Transfer<AccountId> for SingleAsset<F, A, AssetId> {
+	fn transfer(
+		asset: AssetId,
+		source: &AccountId,
+		dest: &AccountId,
+		amount: F::Balance,
+		keep_alive: bool,
+	) -> Result<F::Balance, DispatchError> {
+		Self::ensure_known(&asset)?;
+		F::transfer(source, dest, amount, keep_alive)
+	}
+}
+
+impl<
+	F: fungible::Unbalanced<AccountId>,
+	A: Get<AssetId>,
+	AssetId: FullCodec + Copy + Eq + PartialEq + Debug,
+	AccountId,
+> Unbalanced<AccountId> for SingleAsset<F, A, AssetId> {
+	fn set_balance(asset: AssetId, who: &AccountId, amount: F::Balance) -> DispatchResult {
+		Self::ensure_known(&asset)?;
+		F::set_balance(who, amount)
+	}
+
+	fn set_total_issuance(asset: AssetId, amount: F::Balance) {
+		if Self::is_known(&asset) {
+			F::set_total_issuance(amount)
+		}
+	}
+}
+
+impl<
+	F: fungible::InspectHold<AccountId>,
+	A: Get<AssetId>,
+	AssetId: FullCodec + Copy + Eq + PartialEq + Debug,
+	AccountId,
+> InspectHold<AccountId> for SingleAsset<F, A, AssetId> {
+	fn balance_on_hold(asset: AssetId, who: &AccountId) -> F::Balance {
+		if Self::is_known(&asset) { F::balance_on_hold(who) } else { Zero::zero() }
+	}
+
+	fn can_hold(asset: AssetId, who: &AccountId, amount: F::Balance) -> bool {
+		Self::is_known(&asset) && F::can_hold(who, amount)
+	}
+}
+
+impl<
+	F: fungible::MutateHold<AccountId>,
+	A: Get<AssetId>,
+	AssetId: FullCodec + Copy + Eq + PartialEq + Debug,
+	AccountId,
+> MutateHold<AccountId> for SingleAsset<F, A, AssetId> {
+	fn hold(asset: AssetId, who: &AccountId, amount: F::Balance) -> DispatchResult {
+		Self::ensure_known(&asset)?;
+		F::hold(who, amount)
+	}
+
+	fn release(asset: AssetId, who: &AccountId, amount: F::Balance, best_effort: bool)
+		-> Result<F::Balance, DispatchError>
+	{
+		Self::ensure_known(&asset)?;
+		F::release(who, amount, best_effort)
+	}
+
+	fn transfer_held(
+		asset: AssetId,
+		source: &AccountId,
+		dest: &AccountId,
+		amount: F::Balance,
+		best_effort: bool,
+		on_hold: bool,
+	) -> Result<F::Balance, DispatchError> {
+		Self::ensure_known(&asset)?;
+		F::transfer_held(source, dest, amount, best_effort, on_hold)
+	}
+}
+
+impl<
+	F: fungible::InspectFreeze<AccountId>,
+	A: Get<AssetId>,
+	AssetId: FullCodec + Copy + Eq + PartialEq + Debug,
+	AccountId,
+> InspectFreeze<AccountId> for SingleAsset<F, A, AssetId> {
+	fn is_frozen(asset: AssetId, who: &AccountId) -> bool {
+		Self::is_known(&asset) && F::is_frozen(who)
+	}
+}
+
+impl<
+	F: fungible::MutateFreeze<AccountId>,
+	A: Get<AssetId>,
+	AssetId: FullCodec + Copy + Eq + PartialEq + Debug,
+	AccountId,
+> MutateFreeze<AccountId> for SingleAsset<F, A, AssetId> {
+	fn freeze(asset: AssetId, who: &AccountId) -> DispatchResult {
+		Self::ensure_known(&asset)?;
+		F::freeze(who)
+	}
+
+	fn thaw(asset: AssetId, who: &AccountId) -> DispatchResult {
+		Self::ensure_known(&asset)?;
+		F::thaw(who)
+	}
+}
@@ -16,11 +16,11 @@
 // limitations under the License.
 
 use codec::{Encode, Decode};
-use frame_support::weights::{Weight, DispatchClass};
+use frame_support::{type_info::TypeInfo, weights::{Weight, DispatchClass}};
 use sp_runtime::RuntimeDebug;
 
 /// An object to track the currently used extrinsic weight in a block.
-#[derive(Clone, Eq, PartialEq, Default, RuntimeDebug, Encode, Decode)]
+#[derive(Clone, Eq, PartialEq, Default, RuntimeDebug, Encode, Decode, TypeInfo)]
 pub struct ExtrinsicsWeight {
 	normal: Weight,
 	operational: Weight,
@@ -0,0 +1,282 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2020 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # Asset Transaction Payment Module
+//!
+//! This module allows the signer of a transaction to pay its fee in an asset of the
+//! [Assets module](../pallet_assets/index.html) instead of the native currency.
+//!
+//! It provides the [`ChargeAssetTxPayment`] signed extension, which is meant to replace
+//! `ChargeTransactionPayment` in the `SignedExtra` of the runtime. The fee is computed by the
+//! [Transaction Payment module](../pallet_transaction_payment/index.html) as usual. If the signer
+//! selected an asset:
+//!   - the fee is converted into that asset with [`Trait::BalanceConversion`];
+//!   - it is withdrawn from the signer before the dispatch and the part that is not needed, given
+//!     the actual weight of the call, is refunded after it;
+//!   - the collected assets are handed over to [`Trait::OnAssetTransactionPayment`].
+//!
+//! If no asset was selected, the fee is paid in the native currency, exactly as with
+//! `ChargeTransactionPayment`.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+mod tests;
+
+use codec::{Encode, Decode};
+use frame_support::{
+	decl_module, decl_event,
+	traits::{Currency, OnUnbalanced},
+	weights::{DispatchInfo, PostDispatchInfo},
+	dispatch::DispatchResult,
+};
+use sp_runtime::{
+	FixedPointOperand,
+	transaction_validity::{
+		ValidTransaction, InvalidTransaction, TransactionValidityError, TransactionValidity,
+	},
+	traits::{
+		Zero, Saturating, SignedExtension, Dispatchable, DispatchInfoOf, PostDispatchInfoOf,
+	},
+};
+use pallet_assets::NegativeImbalance;
+use pallet_transaction_payment::ChargeTransactionPayment;
+
+type BalanceOf<T> = <<T as pallet_transaction_payment::Trait>::Currency as Currency<
+	<T as frame_system::Trait>::AccountId,
+>>::Balance;
+type NegativeImbalanceOf<T> = <<T as pallet_transaction_payment::Trait>::Currency as Currency<
+	<T as frame_system::Trait>::AccountId,
+>>::NegativeImbalance;
+type AssetIdOf<T> = <T as pallet_assets::Trait>::AssetId;
+type AssetBalanceOf<T> = <T as pallet_assets::Trait>::Balance;
+
+/// Converts an amount of the native currency into an amount of an asset.
+///
+/// This is the price provider used to determine how many units of an asset a fee costs.
+pub trait BalanceConversion<InBalance, AssetId, OutBalance> {
+	/// The error returned when there is no conversion, for example because the asset has no price.
+	type Error;
+
+	/// Convert `balance` into an amount of the asset `asset_id`.
+	fn to_asset_balance(balance: InBalance, asset_id: AssetId) -> Result<OutBalance, Self::Error>;
+}
+
+pub trait Trait: pallet_transaction_payment::Trait + pallet_assets::Trait {
+	/// The overarching event type.
+	type Event: From<Event<Self>> + Into<<Self as frame_system::Trait>::Event>;
+
+	/// The conversion of fees in the native currency into amounts of an asset.
+	type BalanceConversion: BalanceConversion<BalanceOf<Self>, AssetIdOf<Self>, AssetBalanceOf<Self>>;
+
+	/// Handler for the fees paid in assets, tips included.
+	type OnAssetTransactionPayment: OnUnbalanced<NegativeImbalance<Self>>;
+}
+
+decl_event! {
+	pub enum Event<T> where
+		AccountId = <T as frame_system::Trait>::AccountId,
+		AssetId = AssetIdOf<T>,
+		AssetBalance = AssetBalanceOf<T>,
+	{
+		/// A transaction fee was paid in an asset, tip included. \[who, asset_id, actual_fee\]
+		AssetTxFeePaid(AccountId, AssetId, AssetBalance),
+	}
+}
+
+decl_module! {
+	pub struct Module<T: Trait> for enum Call where origin: T::Origin {
+		fn deposit_event() = default;
+	}
+}
+
+/// The pre-dispatch data of the native `ChargeTransactionPayment` extension.
+type NativePre<T> = (
+	BalanceOf<T>,
+	<T as frame_system::Trait>::AccountId,
+	Option<NegativeImbalanceOf<T>>,
+	BalanceOf<T>,
+);
+
+/// The fee withdrawn before the dispatch of a transaction, to be settled after it.
+pub enum InitialPayment<T: Trait> {
+	/// No fee was paid, e.g. because the transaction is unsigned.
+	Nothing,
+	/// The fee was paid in the native currency.
+	Native(NativePre<T>),
+	/// The fee was paid in an asset by the given account, with the given tip in the native
+	/// currency.
+	Asset(T::AccountId, BalanceOf<T>, NegativeImbalance<T>),
+}
+
+impl<T: Trait> Default for InitialPayment<T> {
+	fn default() -> Self {
+		InitialPayment::Nothing
+	}
+}
+
+/// Require the transactor pay for themselves, either in the native currency or in the given
+/// asset, and maybe include a tip to gain additional priority in the queue.
+///
+/// The tip is always expressed in the native currency and converted along with the fee.
+#[derive(Encode, Decode, Clone, Eq, PartialEq)]
+pub struct ChargeAssetTxPayment<T: Trait + Send + Sync> {
+	#[codec(compact)]
+	tip: BalanceOf<T>,
+	asset_id: Option<AssetIdOf<T>>,
+}
+
+impl<T: Trait + Send + Sync> ChargeAssetTxPayment<T> where
+	T::Call: Dispatchable<Info=DispatchInfo, PostInfo=PostDispatchInfo>,
+	BalanceOf<T>: Send + Sync + FixedPointOperand,
+	AssetIdOf<T>: Send + Sync,
+{
+	/// utility constructor. Used only in client/factory code.
+	pub fn from(tip: BalanceOf<T>, asset_id: Option<AssetIdOf<T>>) -> Self {
+		Self { tip, asset_id }
+	}
+
+	fn withdraw_fee_in_asset(
+		&self,
+		asset_id: AssetIdOf<T>,
+		who: &T::AccountId,
+		info: &DispatchInfoOf<T::Call>,
+		len: usize,
+	) -> Result<(BalanceOf<T>, NegativeImbalance<T>), TransactionValidityError> {
+		let fee = pallet_transaction_payment::Module::<T>::compute_fee(len as u32, info, self.tip);
+
+		// Only mess with assets if fee is not zero.
+		if fee.is_zero() {
+			return Ok((fee, NegativeImbalance::zero(asset_id)));
+		}
+
+		let asset_fee = T::BalanceConversion::to_asset_balance(fee, asset_id)
+			.map_err(|_| TransactionValidityError::from(InvalidTransaction::Payment))?;
+		match pallet_assets::Module::<T>::withdraw(asset_id, who, asset_fee) {
+			Ok(imbalance) => Ok((fee, imbalance)),
+			Err(_) => Err(InvalidTransaction::Payment.into()),
+		}
+	}
+}
+
+impl<T: Trait + Send + Sync> sp_std::fmt::Debug for ChargeAssetTxPayment<T> {
+	#[cfg(feature = "std")]
+	fn fmt(&self, f: &mut sp_std::fmt::Formatter) -> sp_std::fmt::Result {
+		write!(f, "ChargeAssetTxPayment<{:?}, {:?}>", self.tip, self.asset_id)
+	}
+	#[cfg(not(feature = "std"))]
+	fn fmt(&self, _: &mut sp_std::fmt::Formatter) -> sp_std::fmt::Result {
+		Ok(())
+	}
+}
+
+impl<T: Trait + Send + Sync> SignedExtension for ChargeAssetTxPayment<T> where
+	BalanceOf<T>: Send + Sync + From<u64> + FixedPointOperand,
+	AssetIdOf<T>: Send + Sync,
+	T::Call: Dispatchable<Info=DispatchInfo, PostInfo=PostDispatchInfo>,
+{
+	const IDENTIFIER: &'static str = "ChargeAssetTxPayment";
+	type AccountId = T::AccountId;
+	type Call = T::Call;
+	type AdditionalSigned = ();
+	type Pre = InitialPayment<T>;
+	fn additional_signed(&self) -> sp_std::result::Result<(), TransactionValidityError> { Ok(()) }
+
+	fn validate(
+		&self,
+		who: &Self::AccountId,
+		call: &Self::Call,
+		info: &DispatchInfoOf<Self::Call>,
+		len: usize,
+	) -> TransactionValidity {
+		match self.asset_id {
+			None => ChargeTransactionPayment::<T>::from(self.tip).validate(who, call, info, len),
+			Some(asset_id) => {
+				let (fee, _) = self.withdraw_fee_in_asset(asset_id, who, info, len)?;
+				Ok(ValidTransaction {
+					priority: ChargeTransactionPayment::<T>::get_priority(len, info, fee),
+					..Default::default()
+				})
+			},
+		}
+	}
+
+	fn pre_dispatch(
+		self,
+		who: &Self::AccountId,
+		call: &Self::Call,
+		info: &DispatchInfoOf<Self::Call>,
+		len: usize
+	) -> Result<Self::Pre, TransactionValidityError> {
+		match self.asset_id {
+			None => ChargeTransactionPayment::<T>::from(self.tip)
+				.pre_dispatch(who, call, info, len)
+				.map(InitialPayment::Native),
+			Some(asset_id) => {
+				let (_, imbalance) = self.withdraw_fee_in_asset(asset_id, who, info, len)?;
+				Ok(InitialPayment::Asset(who.clone(), self.tip, imbalance))
+			},
+		}
+	}
+
+	fn post_dispatch(
+		pre: Self::Pre,
+		info: &DispatchInfoOf<Self::Call>,
+		post_info: &PostDispatchInfoOf<Self::Call>,
+		len: usize,
+		result: &DispatchResult,
+	) -> Result<(), TransactionValidityError> {
+		let (who, tip, payed) = match pre {
+			InitialPayment::Nothing => return Ok(()),
+			InitialPayment::Native(pre) => return ChargeTransactionPayment::<T>::post_dispatch(
+				pre,
+				info,
+				post_info,
+				len,
+				result,
+			),
+			InitialPayment::Asset(who, tip, payed) => (who, tip, payed),
+		};
+
+		let asset_id = payed.asset_id();
+		let actual_fee = pallet_transaction_payment::Module::<T>::compute_actual_fee(
+			len as u32,
+			info,
+			post_info,
+			tip,
+		);
+		let actual_asset_fee = if actual_fee.is_zero() {
+			Zero::zero()
+		} else {
+			T::BalanceConversion::to_asset_balance(actual_fee, asset_id)
+				.map_err(|_| TransactionValidityError::from(InvalidTransaction::Payment))?
+		};
+
+		// The refund cannot be larger than the up front payment.
+		let refund = payed.peek().saturating_sub(actual_asset_fee);
+		let (refund, actual_payment) = payed.split(refund);
+		// The payer was kept alive by the withdrawal, so the refund can be credited unless the
+		// asset was destroyed in the meantime.
+		pallet_assets::Module::<T>::resolve(&who, refund)
+			.map_err(|_| TransactionValidityError::from(InvalidTransaction::Payment))?;
+
+		if !actual_payment.peek().is_zero() {
+			Module::<T>::deposit_event(RawEvent::AssetTxFeePaid(who, asset_id, actual_payment.peek()));
+		}
+		T::OnAssetTransactionPayment::on_unbalanced(actual_payment);
+		Ok(())
+	}
+}
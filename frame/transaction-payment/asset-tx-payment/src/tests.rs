@@ -0,0 +1,337 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2020 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// Tests for Asset Transaction Payment Module
+
+#![cfg(test)]
+
+use super::*;
+
+use frame_support::{
+	assert_ok, impl_outer_dispatch, impl_outer_event, impl_outer_origin, parameter_types,
+	weights::{DispatchClass, IdentityFee, Pays, Weight},
+};
+use pallet_balances::Call as BalancesCall;
+use sp_core::H256;
+use sp_runtime::{Perbill, traits::{BlakeTwo256, IdentityLookup}, testing::Header};
+use crate as asset_tx_payment;
+
+const CALL: &<Runtime as frame_system::Trait>::Call =
+	&Call::Balances(BalancesCall::transfer(2, 69));
+
+/// The account receiving the fees paid in assets.
+const FEE_RECEIVER: u64 = 99;
+
+impl_outer_origin! {
+	pub enum Origin for Runtime where system = frame_system {}
+}
+impl_outer_event! {
+	pub enum TestEvent for Runtime {
+		frame_system<T>,
+		pallet_balances<T>,
+		pallet_assets<T>,
+		asset_tx_payment<T>,
+	}
+}
+impl_outer_dispatch! {
+	pub enum Call for Runtime where origin: Origin {
+		frame_system::System,
+		pallet_balances::Balances,
+	}
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Runtime;
+parameter_types! {
+	pub const BlockHashCount: u64 = 250;
+	pub const MaximumBlockWeight: Weight = 1024;
+	pub const MaximumBlockLength: u32 = 2 * 1024;
+	pub const AvailableBlockRatio: Perbill = Perbill::one();
+	pub const ExtrinsicBaseWeight: Weight = 5;
+}
+impl frame_system::Trait for Runtime {
+	type BaseCallFilter = ();
+	type Origin = Origin;
+	type Index = u64;
+	type BlockNumber = u64;
+	type Call = Call;
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type AccountId = u64;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type Header = Header;
+	type Event = TestEvent;
+	type BlockHashCount = BlockHashCount;
+	type MaximumBlockWeight = MaximumBlockWeight;
+	type DbWeight = ();
+	type BlockExecutionWeight = ();
+	type ExtrinsicBaseWeight = ExtrinsicBaseWeight;
+	type MaximumExtrinsicWeight = MaximumBlockWeight;
+	type MaximumBlockLength = MaximumBlockLength;
+	type AvailableBlockRatio = AvailableBlockRatio;
+	type Version = ();
+	type PalletInfo = ();
+	type AccountData = pallet_balances::AccountData<u64>;
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+}
+parameter_types! {
+	pub const ExistentialDeposit: u64 = 1;
+}
+impl pallet_balances::Trait for Runtime {
+	type Balance = u64;
+	type Event = TestEvent;
+	type DustRemoval = ();
+	type ExistentialDeposit = ExistentialDeposit;
+	type AccountStore = System;
+	type MaxLocks = ();
+	type MaxReserves = ();
+	type ReserveIdentifier = [u8; 8];
+	type WeightInfo = ();
+}
+parameter_types! {
+	pub const TransactionByteFee: u64 = 1;
+}
+impl pallet_transaction_payment::Trait for Runtime {
+	type Currency = Balances;
+	type OnTransactionPayment = ();
+	type TransactionByteFee = TransactionByteFee;
+	type WeightToFee = IdentityFee<u64>;
+	type FeeMultiplierUpdate = ();
+}
+parameter_types! {
+	pub const AssetDeposit: u64 = 1;
+	pub const MetadataDepositBase: u64 = 1;
+	pub const MetadataDepositPerByte: u64 = 1;
+	pub const ApprovalDeposit: u64 = 1;
+	pub const StringLimit: u32 = 50;
+}
+impl pallet_assets::Trait for Runtime {
+	type Event = TestEvent;
+	type Balance = u64;
+	type AssetId = u32;
+	type Currency = Balances;
+	type ForceOrigin = frame_system::EnsureRoot<u64>;
+	type AssetDeposit = AssetDeposit;
+	type MetadataDepositBase = MetadataDepositBase;
+	type MetadataDepositPerByte = MetadataDepositPerByte;
+	type ApprovalDeposit = ApprovalDeposit;
+	type StringLimit = StringLimit;
+	type WeightInfo = ();
+}
+
+/// Asset `0` is worth half a unit of the native currency, the other assets have no price.
+pub struct TestConversion;
+impl BalanceConversion<u64, u32, u64> for TestConversion {
+	type Error = ();
+
+	fn to_asset_balance(balance: u64, asset_id: u32) -> Result<u64, ()> {
+		match asset_id {
+			0 => Ok(balance * 2),
+			_ => Err(()),
+		}
+	}
+}
+
+pub struct DealWithAssetFees;
+impl OnUnbalanced<NegativeImbalance<Runtime>> for DealWithAssetFees {
+	fn on_nonzero_unbalanced(amount: NegativeImbalance<Runtime>) {
+		// The fee receiver is credited at least the minimum balance in these tests.
+		let _ = Assets::resolve(&FEE_RECEIVER, amount);
+	}
+}
+
+impl Trait for Runtime {
+	type Event = TestEvent;
+	type BalanceConversion = TestConversion;
+	type OnAssetTransactionPayment = DealWithAssetFees;
+}
+
+type System = frame_system::Module<Runtime>;
+type Balances = pallet_balances::Module<Runtime>;
+type Assets = pallet_assets::Module<Runtime>;
+
+fn new_test_ext() -> sp_io::TestExternalities {
+	let mut t = frame_system::GenesisConfig::default().build_storage::<Runtime>().unwrap();
+	pallet_balances::GenesisConfig::<Runtime> {
+		balances: vec![(1, 100), (2, 200)],
+	}.assimilate_storage(&mut t).unwrap();
+	let mut ext = sp_io::TestExternalities::from(t);
+	ext.execute_with(|| {
+		System::set_block_number(1);
+		// Asset `0` has a price, asset `1` has none.
+		assert_ok!(Assets::force_create(Origin::root(), 0, 1, 1));
+		assert_ok!(Assets::force_create(Origin::root(), 1, 1, 1));
+		assert_ok!(Assets::mint(Origin::signed(1), 0, 1, 1000));
+		assert_ok!(Assets::mint(Origin::signed(1), 1, 1, 1000));
+	});
+	ext
+}
+
+fn info_from_weight(w: Weight) -> DispatchInfo {
+	// pays_fee: Pays::Yes -- class: DispatchClass::Normal
+	DispatchInfo { weight: w, ..Default::default() }
+}
+
+fn post_info_from_weight(w: Weight) -> PostDispatchInfo {
+	PostDispatchInfo {
+		actual_weight: Some(w),
+		pays_fee: Default::default(),
+	}
+}
+
+fn last_event() -> TestEvent {
+	System::events().pop().map(|e| e.event).expect("Event expected")
+}
+
+#[test]
+fn paying_fee_in_native_currency_works() {
+	new_test_ext().execute_with(|| {
+		let len = 10;
+		let pre = ChargeAssetTxPayment::<Runtime>::from(0, None)
+			.pre_dispatch(&1, CALL, &info_from_weight(5), len)
+			.unwrap();
+		// 5 base fee, 10 byte fee, 5 weight fee
+		assert_eq!(Balances::free_balance(1), 100 - 5 - 10 - 5);
+
+		assert_ok!(ChargeAssetTxPayment::<Runtime>::post_dispatch(
+			pre,
+			&info_from_weight(5),
+			&post_info_from_weight(2),
+			len,
+			&Ok(()),
+		));
+		assert_eq!(Balances::free_balance(1), 100 - 5 - 10 - 2);
+		assert_eq!(Assets::balance(0, 1), 1000);
+	});
+}
+
+#[test]
+fn paying_fee_in_asset_works() {
+	new_test_ext().execute_with(|| {
+		let len = 10;
+		let pre = ChargeAssetTxPayment::<Runtime>::from(0, Some(0))
+			.pre_dispatch(&1, CALL, &info_from_weight(5), len)
+			.unwrap();
+		// The fee of 5 + 10 + 5 is paid twice over in asset `0`.
+		assert_eq!(Assets::balance(0, 1), 1000 - 40);
+		assert_eq!(Balances::free_balance(1), 100);
+
+		assert_ok!(ChargeAssetTxPayment::<Runtime>::post_dispatch(
+			pre,
+			&info_from_weight(5),
+			&post_info_from_weight(2),
+			len,
+			&Ok(()),
+		));
+		// The unused weight of 3 is refunded.
+		assert_eq!(Assets::balance(0, 1), 1000 - 34);
+		assert_eq!(Assets::balance(0, FEE_RECEIVER), 34);
+		assert_eq!(Assets::total_supply(0), 1000);
+		assert_eq!(
+			last_event(),
+			TestEvent::asset_tx_payment(RawEvent::AssetTxFeePaid(1, 0, 34)),
+		);
+	});
+}
+
+#[test]
+fn tip_is_converted_with_the_fee() {
+	new_test_ext().execute_with(|| {
+		let len = 10;
+		let pre = ChargeAssetTxPayment::<Runtime>::from(5 /* tipped */, Some(0))
+			.pre_dispatch(&1, CALL, &info_from_weight(5), len)
+			.unwrap();
+		assert_eq!(Assets::balance(0, 1), 1000 - 50);
+
+		assert_ok!(ChargeAssetTxPayment::<Runtime>::post_dispatch(
+			pre,
+			&info_from_weight(5),
+			&post_info_from_weight(5),
+			len,
+			&Ok(()),
+		));
+		assert_eq!(Assets::balance(0, 1), 1000 - 50);
+		assert_eq!(Assets::balance(0, FEE_RECEIVER), 50);
+	});
+}
+
+#[test]
+fn paying_fee_in_asset_without_price_or_balance_fails() {
+	new_test_ext().execute_with(|| {
+		let len = 10;
+		// Asset `1` has no price.
+		assert_eq!(
+			ChargeAssetTxPayment::<Runtime>::from(0, Some(1))
+				.validate(&1, CALL, &info_from_weight(5), len),
+			Err(InvalidTransaction::Payment.into()),
+		);
+		// Account `2` has no units of asset `0`.
+		assert_eq!(
+			ChargeAssetTxPayment::<Runtime>::from(0, Some(0))
+				.validate(&2, CALL, &info_from_weight(5), len),
+			Err(InvalidTransaction::Payment.into()),
+		);
+		// Asset `2` does not exist.
+		assert!(
+			ChargeAssetTxPayment::<Runtime>::from(0, Some(2))
+				.pre_dispatch(&1, CALL, &info_from_weight(5), len)
+				.is_err()
+		);
+		assert_eq!(Assets::balance(0, 1), 1000);
+	});
+}
+
+#[test]
+fn free_transactions_do_not_withdraw_assets() {
+	new_test_ext().execute_with(|| {
+		let len = 10;
+		let free_transaction = DispatchInfo {
+			weight: 0,
+			class: DispatchClass::Operational,
+			pays_fee: Pays::No,
+		};
+		// Asset `1` has no price, but no conversion is needed.
+		let pre = ChargeAssetTxPayment::<Runtime>::from(0, Some(1))
+			.pre_dispatch(&2, CALL, &free_transaction, len)
+			.unwrap();
+		assert_ok!(ChargeAssetTxPayment::<Runtime>::post_dispatch(
+			pre,
+			&free_transaction,
+			&post_info_from_weight(0),
+			len,
+			&Ok(()),
+		));
+		assert_eq!(Assets::balance(1, 2), 0);
+		assert_eq!(Assets::balance(1, FEE_RECEIVER), 0);
+	});
+}
+
+#[test]
+fn validate_gives_priority_like_native_payment() {
+	new_test_ext().execute_with(|| {
+		let len = 10;
+		let native = ChargeAssetTxPayment::<Runtime>::from(5, None)
+			.validate(&1, CALL, &info_from_weight(5), len)
+			.unwrap();
+		let asset = ChargeAssetTxPayment::<Runtime>::from(5, Some(0))
+			.validate(&1, CALL, &info_from_weight(5), len)
+			.unwrap();
+		assert_eq!(native.priority, asset.priority);
+	});
+}
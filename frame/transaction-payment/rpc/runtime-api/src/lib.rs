@@ -24,7 +24,10 @@ use frame_support::weights::{Weight, DispatchClass};
 use codec::{Encode, Codec, Decode};
 #[cfg(feature = "std")]
 use serde::{Serialize, Deserialize, Serializer, Deserializer};
-use sp_runtime::traits::{MaybeDisplay, MaybeFromStr};
+use sp_runtime::{
+	FixedU128, Perbill,
+	traits::{AtLeast32BitUnsigned, MaybeDisplay, MaybeFromStr, Zero},
+};
 
 /// Information related to a dispatchable's class, weight, and fee that can be queried from the runtime.
 #[derive(Eq, PartialEq, Encode, Decode, Default)]
@@ -44,6 +47,85 @@ pub struct RuntimeDispatchInfo<Balance> {
 	pub partial_fee: Balance,
 }
 
+/// The base fee and adjusted weight and length fees constitute the _inclusion fee_, which is the
+/// minimum fee for a transaction to be included in a block.
+///
+/// ```ignore
+/// inclusion_fee = base_fee + len_fee + [targeted_fee_adjustment * weight_fee];
+/// ```
+#[derive(Eq, PartialEq, Encode, Decode, Default, Clone)]
+#[cfg_attr(feature = "std", derive(Debug, Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+#[cfg_attr(feature = "std", serde(bound(serialize = "Balance: std::fmt::Display")))]
+#[cfg_attr(feature = "std", serde(bound(deserialize = "Balance: std::str::FromStr")))]
+pub struct InclusionFee<Balance> {
+	/// The minimum fee for a transaction to be included in a block, i.e. the fee of the
+	/// extrinsic base weight.
+	#[cfg_attr(feature = "std", serde(serialize_with = "serialize_as_string"))]
+	#[cfg_attr(feature = "std", serde(deserialize_with = "deserialize_from_string"))]
+	pub base_fee: Balance,
+	/// The length fee, the amount paid for the encoded length (in bytes) of the transaction.
+	#[cfg_attr(feature = "std", serde(serialize_with = "serialize_as_string"))]
+	#[cfg_attr(feature = "std", serde(deserialize_with = "deserialize_from_string"))]
+	pub len_fee: Balance,
+	/// - `targeted_fee_adjustment`: This is a multiplier that can tune the final fee based on the
+	///   congestion of the network.
+	/// - `weight_fee`: This amount is computed based on the weight of the transaction. Weight
+	///   accounts for the execution time of a transaction.
+	///
+	/// adjusted_weight_fee = targeted_fee_adjustment * weight_fee
+	#[cfg_attr(feature = "std", serde(serialize_with = "serialize_as_string"))]
+	#[cfg_attr(feature = "std", serde(deserialize_with = "deserialize_from_string"))]
+	pub adjusted_weight_fee: Balance,
+}
+
+impl<Balance: AtLeast32BitUnsigned + Copy> InclusionFee<Balance> {
+	/// Returns the total of the inclusion fee.
+	///
+	/// ```ignore
+	/// inclusion_fee = base_fee + len_fee + adjusted_weight_fee
+	/// ```
+	pub fn inclusion_fee(&self) -> Balance {
+		self.base_fee
+			.saturating_add(self.len_fee)
+			.saturating_add(self.adjusted_weight_fee)
+	}
+}
+
+/// The `FeeDetails` is composed of:
+///   - (Optional) `inclusion_fee`: Only the `Pays::Yes` transaction can have the inclusion fee.
+///   - `tip`: If included in the transaction, the tip will be added on top. Only signed
+///     transactions can have a tip.
+#[derive(Eq, PartialEq, Encode, Decode, Default, Clone)]
+#[cfg_attr(feature = "std", derive(Debug, Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+#[cfg_attr(feature = "std", serde(bound(serialize = "Balance: std::fmt::Display")))]
+#[cfg_attr(feature = "std", serde(bound(deserialize = "Balance: std::str::FromStr")))]
+pub struct FeeDetails<Balance> {
+	/// The inclusion fee, if the transaction pays any fee.
+	pub inclusion_fee: Option<InclusionFee<Balance>>,
+	/// The tip, which is always zero when queried through the runtime API, as it depends on the
+	/// signed extensions of the transaction.
+	#[cfg_attr(feature = "std", serde(serialize_with = "serialize_as_string"))]
+	#[cfg_attr(feature = "std", serde(deserialize_with = "deserialize_from_string"))]
+	pub tip: Balance,
+}
+
+impl<Balance: AtLeast32BitUnsigned + Copy> FeeDetails<Balance> {
+	/// Returns the final fee.
+	///
+	/// ```ignore
+	/// final_fee = inclusion_fee + tip;
+	/// ```
+	pub fn final_fee(&self) -> Balance {
+		self.inclusion_fee
+			.as_ref()
+			.map(|i| i.inclusion_fee())
+			.unwrap_or_else(|| Zero::zero())
+			.saturating_add(self.tip)
+	}
+}
+
 #[cfg(feature = "std")]
 fn serialize_as_string<S: Serializer, T: std::fmt::Display>(t: &T, serializer: S) -> Result<S::Ok, S::Error> {
 	serializer.serialize_str(&t.to_string())
@@ -56,10 +138,19 @@ fn deserialize_from_string<'de, D: Deserializer<'de>, T: std::str::FromStr>(dese
 }
 
 sp_api::decl_runtime_apis! {
+	#[api_version(2)]
 	pub trait TransactionPaymentApi<Balance> where
 		Balance: Codec + MaybeDisplay + MaybeFromStr,
 	{
 		fn query_info(uxt: Block::Extrinsic, len: u32) -> RuntimeDispatchInfo<Balance>;
+		/// Query the components of the fee of the given extrinsic.
+		fn query_fee_details(uxt: Block::Extrinsic, len: u32) -> FeeDetails<Balance>;
+		/// Project the fee multiplier over the next `blocks` blocks, assuming that each of them
+		/// is filled to `fullness` of its normal dispatch capacity.
+		///
+		/// The first value is the multiplier of the next block, followed by `blocks` projected
+		/// values.
+		fn query_fee_multiplier_projection(fullness: Perbill, blocks: u32) -> Vec<FixedU128>;
 	}
 }
 
@@ -100,4 +191,29 @@ mod tests {
 		// should not panic
 		serde_json::to_value(&info).unwrap();
 	}
+
+	#[test]
+	fn fee_details_should_serialize_and_deserialize_properly_with_string() {
+		let details = FeeDetails {
+			inclusion_fee: Some(InclusionFee {
+				base_fee: 1_u64,
+				len_fee: 2,
+				adjusted_weight_fee: 3,
+			}),
+			tip: 4,
+		};
+
+		let json_str = r#"{"inclusionFee":{"baseFee":"1","lenFee":"2","adjustedWeightFee":"3"},"tip":"4"}"#;
+
+		assert_eq!(serde_json::to_string(&details).unwrap(), json_str);
+		assert_eq!(serde_json::from_str::<FeeDetails<u64>>(json_str).unwrap(), details);
+		assert_eq!(details.final_fee(), 10);
+
+		let details = FeeDetails { inclusion_fee: None, tip: 4_u64 };
+		let json_str = r#"{"inclusionFee":null,"tip":"4"}"#;
+
+		assert_eq!(serde_json::to_string(&details).unwrap(), json_str);
+		assert_eq!(serde_json::from_str::<FeeDetails<u64>>(json_str).unwrap(), details);
+		assert_eq!(details.final_fee(), 4);
+	}
 }
@@ -22,21 +22,46 @@ use codec::{Codec, Decode};
 use sp_blockchain::HeaderBackend;
 use jsonrpc_core::{Error as RpcError, ErrorCode, Result};
 use jsonrpc_derive::rpc;
-use sp_runtime::{generic::BlockId, traits::{Block as BlockT, MaybeDisplay, MaybeFromStr}};
+use sp_runtime::{
+	FixedU128, Perbill, generic::BlockId, traits::{Block as BlockT, MaybeDisplay, MaybeFromStr},
+};
 use sp_api::ProvideRuntimeApi;
 use sp_core::Bytes;
-use pallet_transaction_payment_rpc_runtime_api::RuntimeDispatchInfo;
+use pallet_transaction_payment_rpc_runtime_api::{FeeDetails, RuntimeDispatchInfo};
 pub use pallet_transaction_payment_rpc_runtime_api::TransactionPaymentApi as TransactionPaymentRuntimeApi;
 pub use self::gen_client::Client as TransactionPaymentClient;
 
+/// The maximum number of blocks over which the fee multiplier can be projected in one call.
+pub const MAX_PROJECTED_BLOCKS: u32 = 1024;
+
 #[rpc]
-pub trait TransactionPaymentApi<BlockHash, ResponseType> {
+pub trait TransactionPaymentApi<BlockHash, Balance> {
 	#[rpc(name = "payment_queryInfo")]
 	fn query_info(
 		&self,
 		encoded_xt: Bytes,
 		at: Option<BlockHash>
-	) -> Result<ResponseType>;
+	) -> Result<RuntimeDispatchInfo<Balance>>;
+
+	/// Query the components of the fee of the given encoded extrinsic.
+	#[rpc(name = "payment_queryFeeDetails")]
+	fn query_fee_details(
+		&self,
+		encoded_xt: Bytes,
+		at: Option<BlockHash>
+	) -> Result<FeeDetails<Balance>>;
+
+	/// Project the fee multiplier over the next `blocks` blocks, assuming that each of them is
+	/// filled to `fullness` of its normal dispatch capacity.
+	///
+	/// At most [`MAX_PROJECTED_BLOCKS`] blocks can be projected.
+	#[rpc(name = "payment_queryFeeMultiplierProjection")]
+	fn query_fee_multiplier_projection(
+		&self,
+		fullness: Perbill,
+		blocks: u32,
+		at: Option<BlockHash>
+	) -> Result<Vec<FixedU128>>;
 }
 
 /// A struct that implements the [`TransactionPaymentApi`].
@@ -69,7 +94,35 @@ impl From<Error> for i64 {
 	}
 }
 
-impl<C, Block, Balance> TransactionPaymentApi<<Block as BlockT>::Hash, RuntimeDispatchInfo<Balance>>
+impl<C, Block> TransactionPayment<C, Block> where
+	Block: BlockT,
+	C: HeaderBackend<Block>,
+{
+	fn block_id(&self, at: Option<<Block as BlockT>::Hash>) -> BlockId<Block> {
+		BlockId::hash(at.unwrap_or_else(||
+			// If the block hash is not supplied assume the best block.
+			self.client.info().best_hash
+		))
+	}
+}
+
+fn decode_extrinsic<Block: BlockT>(encoded_xt: &Bytes, message: &str) -> Result<Block::Extrinsic> {
+	Decode::decode(&mut &**encoded_xt).map_err(|e| RpcError {
+		code: ErrorCode::ServerError(Error::DecodeError.into()),
+		message: message.into(),
+		data: Some(format!("{:?}", e).into()),
+	})
+}
+
+fn runtime_error(message: &str, e: impl std::fmt::Debug) -> RpcError {
+	RpcError {
+		code: ErrorCode::ServerError(Error::RuntimeError.into()),
+		message: message.into(),
+		data: Some(format!("{:?}", e).into()),
+	}
+}
+
+impl<C, Block, Balance> TransactionPaymentApi<<Block as BlockT>::Hash, Balance>
 	for TransactionPayment<C, Block>
 where
 	Block: BlockT,
@@ -83,22 +136,47 @@ where
 		at: Option<<Block as BlockT>::Hash>
 	) -> Result<RuntimeDispatchInfo<Balance>> {
 		let api = self.client.runtime_api();
-		let at = BlockId::hash(at.unwrap_or_else(||
-			// If the block hash is not supplied assume the best block.
-			self.client.info().best_hash
-		));
+		let at = self.block_id(at);
 
 		let encoded_len = encoded_xt.len() as u32;
 
-		let uxt: Block::Extrinsic = Decode::decode(&mut &*encoded_xt).map_err(|e| RpcError {
-			code: ErrorCode::ServerError(Error::DecodeError.into()),
-			message: "Unable to query dispatch info.".into(),
-			data: Some(format!("{:?}", e).into()),
-		})?;
-		api.query_info(&at, uxt, encoded_len).map_err(|e| RpcError {
-			code: ErrorCode::ServerError(Error::RuntimeError.into()),
-			message: "Unable to query dispatch info.".into(),
-			data: Some(format!("{:?}", e).into()),
-		})
+		let uxt = decode_extrinsic::<Block>(&encoded_xt, "Unable to query dispatch info.")?;
+		api.query_info(&at, uxt, encoded_len)
+			.map_err(|e| runtime_error("Unable to query dispatch info.", e))
+	}
+
+	fn query_fee_details(
+		&self,
+		encoded_xt: Bytes,
+		at: Option<<Block as BlockT>::Hash>
+	) -> Result<FeeDetails<Balance>> {
+		let api = self.client.runtime_api();
+		let at = self.block_id(at);
+
+		let encoded_len = encoded_xt.len() as u32;
+
+		let uxt = decode_extrinsic::<Block>(&encoded_xt, "Unable to query fee details.")?;
+		api.query_fee_details(&at, uxt, encoded_len)
+			.map_err(|e| runtime_error("Unable to query fee details.", e))
+	}
+
+	fn query_fee_multiplier_projection(
+		&self,
+		fullness: Perbill,
+		blocks: u32,
+		at: Option<<Block as BlockT>::Hash>
+	) -> Result<Vec<FixedU128>> {
+		if blocks > MAX_PROJECTED_BLOCKS {
+			return Err(RpcError::invalid_params(format!(
+				"Cannot project the fee multiplier over more than {} blocks.",
+				MAX_PROJECTED_BLOCKS,
+			)));
+		}
+
+		let api = self.client.runtime_api();
+		let at = self.block_id(at);
+
+		api.query_fee_multiplier_projection(&at, fullness, blocks)
+			.map_err(|e| runtime_error("Unable to project the fee multiplier.", e))
 	}
 }
@@ -0,0 +1,520 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2020 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # Sponsored Transaction Payment Module
+//!
+//! - [`sponsored_tx_payment::Trait`](./trait.Trait.html)
+//! - [`Call`](./enum.Call.html)
+//!
+//! ## Overview
+//!
+//! This module allows a sponsor to pay the transaction fees of another account, for example so
+//! that a dApp can onboard new users who hold no balance yet.
+//!
+//! It provides the [`ChargeSponsoredTxPayment`] signed extension, which is meant to replace
+//! `ChargeTransactionPayment` in the `SignedExtra` of the runtime. The extension optionally
+//! carries a [`Sponsorship`]: the sponsor account, the maximum fee the sponsor is willing to pay
+//! and the signature of the sponsor over the signer, a nonce, the chain, the last block at which
+//! the sponsorship is valid, the hash of the call and this spending limit (see
+//! [`Module::sponsorship_payload`]). When present, the fee is computed and refunded exactly as with
+//! `ChargeTransactionPayment`, but it is withdrawn from the sponsor instead of the signer.
+//!
+//! The nonce is kept for each pair of sponsor and signer and incremented by every sponsored
+//! transaction, so that a signature of a sponsor can only be used once.
+//!
+//! Sponsors only pay for transactions under a registered [`SponsorshipPolicy`], which limits the
+//! calls that are sponsored, the total fees sponsored for each user and the block until which the
+//! policy holds. The policy is checked when the transaction is validated, and the fees are charged
+//! against the quota of the user before the dispatch and partially refunded to it after the
+//! dispatch.
+//!
+//! ## Interface
+//!
+//! ### Dispatchable Functions
+//!
+//! * `set_policy` - Register or replace the sponsorship policy of the caller.
+//! * `remove_policy` - Remove the sponsorship policy of the caller.
+//!
+//! [`Call`]: ./enum.Call.html
+//! [`Trait`]: ./trait.Trait.html
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+mod tests;
+
+use sp_std::prelude::*;
+use codec::{Encode, Decode};
+use frame_support::{
+	decl_module, decl_event, decl_storage, decl_error, ensure,
+	traits::{Currency, Get, GetCallMetadata},
+	weights::{DispatchInfo, PostDispatchInfo, Weight},
+	dispatch::DispatchResult,
+};
+use sp_runtime::{
+	FixedPointOperand, RuntimeDebug,
+	transaction_validity::{
+		InvalidTransaction, TransactionValidityError, TransactionValidity, ValidTransaction,
+	},
+	traits::{
+		Hash, IdentifyAccount, Member, One, SaturatedConversion, Saturating, SignedExtension,
+		Dispatchable, DispatchInfoOf, PostDispatchInfoOf, Verify, Zero,
+	},
+};
+use frame_system::ensure_signed;
+use pallet_transaction_payment::ChargeTransactionPayment;
+
+type BalanceOf<T> = <<T as pallet_transaction_payment::Trait>::Currency as Currency<
+	<T as frame_system::Trait>::AccountId,
+>>::Balance;
+type NegativeImbalanceOf<T> = <<T as pallet_transaction_payment::Trait>::Currency as Currency<
+	<T as frame_system::Trait>::AccountId,
+>>::NegativeImbalance;
+
+pub trait Trait: pallet_transaction_payment::Trait {
+	/// The overarching event type.
+	type Event: From<Event<Self>> + Into<<Self as frame_system::Trait>::Event>;
+
+	/// The signature of a sponsor over a sponsorship payload.
+	type SponsorSignature: Member + Encode + Decode + Verify<Signer = Self::SponsorSigner>;
+
+	/// The signer of a `SponsorSignature`, identifying the sponsor account.
+	type SponsorSigner: IdentifyAccount<AccountId = Self::AccountId>;
+
+	/// The maximum number of call selectors in a sponsorship policy.
+	type MaxAllowedCalls: Get<u32>;
+}
+
+/// A selection of calls, identified by the names returned by `GetCallMetadata`.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug)]
+pub enum CallSelector {
+	/// Any call of the pallet with the given name.
+	Pallet(Vec<u8>),
+	/// The call with the given name of the pallet with the given name.
+	Call(Vec<u8>, Vec<u8>),
+}
+
+impl CallSelector {
+	/// Whether the call with the given pallet and call name is selected.
+	pub fn matches(&self, pallet_name: &[u8], call_name: &[u8]) -> bool {
+		match self {
+			CallSelector::Pallet(pallet) => &pallet[..] == pallet_name,
+			CallSelector::Call(pallet, call) => &pallet[..] == pallet_name && &call[..] == call_name,
+		}
+	}
+}
+
+/// The conditions under which a sponsor pays the fees of a transaction.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug)]
+pub struct SponsorshipPolicy<Balance, BlockNumber> {
+	/// The calls that are sponsored. No call is sponsored if this is empty.
+	pub allowed_calls: Vec<CallSelector>,
+	/// The total fees that are sponsored for each user, as long as the policy is not replaced.
+	pub user_quota: Balance,
+	/// The block from which on the policy no longer holds, if any.
+	pub expiry: Option<BlockNumber>,
+}
+
+/// The sponsor of a transaction and its approval.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug)]
+pub struct Sponsorship<AccountId, Balance, Index, BlockNumber, Signature> {
+	/// The account paying the fees.
+	pub sponsor: AccountId,
+	/// The maximum fee, tip included, the sponsor pays.
+	#[codec(compact)]
+	pub limit: Balance,
+	/// The nonce of the sponsorship, which must match the next nonce of the sponsor for the
+	/// signer.
+	#[codec(compact)]
+	pub nonce: Index,
+	/// The last block at which the sponsorship is valid.
+	pub valid_until: BlockNumber,
+	/// The signature of the sponsor over the payload returned by
+	/// [`Module::sponsorship_payload`].
+	pub signature: Signature,
+}
+
+type SponsorshipOf<T> = Sponsorship<
+	<T as frame_system::Trait>::AccountId,
+	BalanceOf<T>,
+	<T as frame_system::Trait>::Index,
+	<T as frame_system::Trait>::BlockNumber,
+	<T as Trait>::SponsorSignature,
+>;
+
+decl_storage! {
+	trait Store for Module<T: Trait> as SponsoredTxPayment {
+		/// The sponsorship policies, keyed by sponsor.
+		pub Policies get(fn policy):
+			map hasher(blake2_128_concat) T::AccountId
+			=> Option<SponsorshipPolicy<BalanceOf<T>, T::BlockNumber>>;
+
+		/// The fees sponsored for a user under the current policy, keyed by sponsor and user.
+		pub SponsoredFees get(fn sponsored_fees):
+			double_map hasher(blake2_128_concat) T::AccountId, hasher(blake2_128_concat) T::AccountId
+			=> BalanceOf<T>;
+
+		/// The number of users with sponsored fees under the current policy of a sponsor.
+		pub SponsoredUsers get(fn sponsored_users): map hasher(blake2_128_concat) T::AccountId => u32;
+
+		/// The nonce of the next sponsorship, keyed by sponsor and user.
+		pub SponsorshipNonces get(fn sponsorship_nonce):
+			double_map hasher(blake2_128_concat) T::AccountId, hasher(blake2_128_concat) T::AccountId
+			=> T::Index;
+	}
+}
+
+decl_event! {
+	pub enum Event<T> where
+		AccountId = <T as frame_system::Trait>::AccountId,
+		Balance = BalanceOf<T>,
+	{
+		/// A sponsorship policy was set. \[sponsor\]
+		PolicySet(AccountId),
+		/// A sponsorship policy was removed. \[sponsor\]
+		PolicyRemoved(AccountId),
+		/// The fee of a transaction was paid by a sponsor, tip included. \[sponsor, who, actual_fee\]
+		TransactionSponsored(AccountId, AccountId, Balance),
+	}
+}
+
+decl_error! {
+	/// Error for the sponsored transaction payment module.
+	pub enum Error for Module<T: Trait> {
+		/// The policy has more call selectors than allowed.
+		TooManyCalls,
+		/// The sponsor has no policy.
+		NoPolicy,
+		/// The fee of the transaction is above the spending limit signed by the sponsor.
+		LimitExceeded,
+		/// The call is not sponsored by the policy of the sponsor.
+		CallNotSponsored,
+		/// The policy of the sponsor has expired.
+		PolicyExpired,
+		/// The fee of the transaction exceeds the remaining quota of the user.
+		QuotaExceeded,
+		/// The given number of sponsored users is below the actual number.
+		BadWitness,
+	}
+}
+
+decl_module! {
+	/// Sponsored transaction payment module declaration.
+	pub struct Module<T: Trait> for enum Call where origin: T::Origin {
+		type Error = Error<T>;
+
+		/// The maximum number of call selectors in a sponsorship policy.
+		const MaxAllowedCalls: u32 = T::MaxAllowedCalls::get();
+
+		fn deposit_event() = default;
+
+		/// Register or replace the sponsorship policy of the caller.
+		///
+		/// Replacing a policy resets the quotas of all users.
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		///
+		/// - `policy`: The new policy.
+		/// - `sponsored_users`: The number of users with sponsored fees under the current policy,
+		///   see `sponsored_users`. Used for the weight of resetting their quotas.
+		///
+		/// # <weight>
+		/// - O(C + U) where C is the number of call selectors in the policy and U the number of
+		///   sponsored users.
+		/// - U + 3 storage writes.
+		/// - One event.
+		/// # </weight>
+		#[weight = 20_000_000
+			+ 500_000 * policy.allowed_calls.len() as Weight
+			+ T::DbWeight::get().reads_writes(1, 2)
+			+ T::DbWeight::get().writes(*sponsored_users as Weight)]
+		fn set_policy(
+			origin,
+			policy: SponsorshipPolicy<BalanceOf<T>, T::BlockNumber>,
+			#[compact] sponsored_users: u32,
+		) {
+			let sponsor = ensure_signed(origin)?;
+			ensure!(
+				policy.allowed_calls.len() <= T::MaxAllowedCalls::get() as usize,
+				Error::<T>::TooManyCalls,
+			);
+			ensure!(
+				SponsoredUsers::<T>::get(&sponsor) <= sponsored_users,
+				Error::<T>::BadWitness,
+			);
+
+			Policies::<T>::insert(&sponsor, policy);
+			Self::clear_sponsored_fees(&sponsor);
+			Self::deposit_event(RawEvent::PolicySet(sponsor));
+		}
+
+		/// Remove the sponsorship policy of the caller.
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		///
+		/// - `sponsored_users`: The number of users with sponsored fees under the current policy,
+		///   see `sponsored_users`. Used for the weight of removing their quotas.
+		///
+		/// # <weight>
+		/// - O(U) where U is the number of sponsored users.
+		/// - One storage read, U + 2 storage writes.
+		/// - One event.
+		/// # </weight>
+		#[weight = 20_000_000
+			+ T::DbWeight::get().reads_writes(2, 2)
+			+ T::DbWeight::get().writes(*sponsored_users as Weight)]
+		fn remove_policy(origin, #[compact] sponsored_users: u32) {
+			let sponsor = ensure_signed(origin)?;
+			ensure!(Policies::<T>::contains_key(&sponsor), Error::<T>::NoPolicy);
+			ensure!(
+				SponsoredUsers::<T>::get(&sponsor) <= sponsored_users,
+				Error::<T>::BadWitness,
+			);
+
+			Policies::<T>::remove(&sponsor);
+			Self::clear_sponsored_fees(&sponsor);
+			Self::deposit_event(RawEvent::PolicyRemoved(sponsor));
+		}
+	}
+}
+
+impl<T: Trait> Module<T> {
+	/// The payload a sponsor signs to pay for `call` signed by `who`, for at most `limit`.
+	///
+	/// `nonce` must be the next sponsorship nonce of `who`, see `sponsorship_nonce`, and the
+	/// sponsorship is valid up to and including the block `valid_until`.
+	pub fn sponsorship_payload<C: Encode>(
+		who: &T::AccountId,
+		nonce: T::Index,
+		valid_until: T::BlockNumber,
+		call: &C,
+		limit: BalanceOf<T>,
+	) -> Vec<u8> {
+		let genesis_hash = frame_system::Module::<T>::block_hash(T::BlockNumber::zero());
+		(who, nonce, genesis_hash, valid_until, T::Hashing::hash_of(call), limit).encode()
+	}
+
+	/// Update the fees sponsored for `who` by `sponsor` under the current policy of `sponsor`.
+	fn mutate_sponsored_fees(
+		sponsor: &T::AccountId,
+		who: &T::AccountId,
+		f: impl FnOnce(BalanceOf<T>) -> BalanceOf<T>,
+	) {
+		if !SponsoredFees::<T>::contains_key(sponsor, who) {
+			SponsoredUsers::<T>::mutate(sponsor, |users| *users = users.saturating_add(1));
+		}
+		SponsoredFees::<T>::mutate(sponsor, who, |fees| *fees = f(*fees));
+	}
+
+	/// Remove the fees sponsored by `sponsor` for all users.
+	fn clear_sponsored_fees(sponsor: &T::AccountId) {
+		SponsoredFees::<T>::remove_prefix(sponsor);
+		SponsoredUsers::<T>::remove(sponsor);
+	}
+}
+
+/// Convert an error of this module into a custom invalid transaction error.
+fn invalid<T: Trait>(error: Error<T>) -> TransactionValidityError {
+	InvalidTransaction::Custom(error.as_u8()).into()
+}
+
+/// The pre-dispatch data of the native `ChargeTransactionPayment` extension.
+type NativePre<T> = (
+	BalanceOf<T>,
+	<T as frame_system::Trait>::AccountId,
+	Option<NegativeImbalanceOf<T>>,
+	BalanceOf<T>,
+);
+
+/// The parties of a sponsored transaction, to be settled after its dispatch.
+pub struct SponsoredPayment<AccountId> {
+	/// The account that paid the fee.
+	sponsor: AccountId,
+	/// The signer of the transaction.
+	who: AccountId,
+}
+
+/// Require the transactor, or a sponsor approving the call, pay for the transaction and maybe
+/// include a tip to gain additional priority in the queue.
+#[derive(Encode, Decode, Clone, Eq, PartialEq)]
+pub struct ChargeSponsoredTxPayment<T: Trait + Send + Sync> {
+	#[codec(compact)]
+	tip: BalanceOf<T>,
+	sponsorship: Option<SponsorshipOf<T>>,
+}
+
+impl<T: Trait + Send + Sync> ChargeSponsoredTxPayment<T> where
+	T::Call: Dispatchable<Info=DispatchInfo, PostInfo=PostDispatchInfo> + GetCallMetadata + Encode,
+	BalanceOf<T>: Send + Sync + FixedPointOperand,
+{
+	/// utility constructor. Used only in client/factory code.
+	pub fn from(tip: BalanceOf<T>, sponsorship: Option<SponsorshipOf<T>>) -> Self {
+		Self { tip, sponsorship }
+	}
+
+	/// Check the sponsorship of the transaction, returning the fee to be charged against the
+	/// quota of `who`.
+	fn check_sponsorship(
+		&self,
+		sponsorship: &SponsorshipOf<T>,
+		who: &T::AccountId,
+		call: &T::Call,
+		info: &DispatchInfoOf<T::Call>,
+		len: usize,
+	) -> Result<BalanceOf<T>, TransactionValidityError> {
+		let Sponsorship { sponsor, limit, nonce, valid_until, signature } = sponsorship;
+		if frame_system::Module::<T>::block_number() > *valid_until {
+			return Err(InvalidTransaction::Stale.into());
+		}
+		let expected_nonce = SponsorshipNonces::<T>::get(sponsor, who);
+		if *nonce < expected_nonce {
+			return Err(InvalidTransaction::Stale.into());
+		}
+		if *nonce > expected_nonce {
+			return Err(InvalidTransaction::Future.into());
+		}
+		let payload = Module::<T>::sponsorship_payload(who, *nonce, *valid_until, call, *limit);
+		if !signature.verify(&payload[..], sponsor) {
+			return Err(InvalidTransaction::BadProof.into());
+		}
+
+		let fee = pallet_transaction_payment::Module::<T>::compute_fee(len as u32, info, self.tip);
+		if fee > *limit {
+			return Err(invalid(Error::<T>::LimitExceeded));
+		}
+
+		let policy = match Policies::<T>::get(sponsor) {
+			Some(policy) => policy,
+			None => return Err(invalid(Error::<T>::NoPolicy)),
+		};
+		if policy.expiry.map_or(false, |expiry| frame_system::Module::<T>::block_number() >= expiry) {
+			return Err(invalid(Error::<T>::PolicyExpired));
+		}
+		let metadata = call.get_call_metadata();
+		let is_allowed = policy.allowed_calls.iter().any(|selector| selector.matches(
+			metadata.pallet_name.as_bytes(),
+			metadata.function_name.as_bytes(),
+		));
+		if !is_allowed {
+			return Err(invalid(Error::<T>::CallNotSponsored));
+		}
+		if Module::<T>::sponsored_fees(sponsor, who).saturating_add(fee) > policy.user_quota {
+			return Err(invalid(Error::<T>::QuotaExceeded));
+		}
+
+		Ok(fee)
+	}
+}
+
+impl<T: Trait + Send + Sync> sp_std::fmt::Debug for ChargeSponsoredTxPayment<T> {
+	#[cfg(feature = "std")]
+	fn fmt(&self, f: &mut sp_std::fmt::Formatter) -> sp_std::fmt::Result {
+		write!(
+			f,
+			"ChargeSponsoredTxPayment<{:?}, {:?}>",
+			self.tip,
+			self.sponsorship.as_ref().map(|s| &s.sponsor),
+		)
+	}
+	#[cfg(not(feature = "std"))]
+	fn fmt(&self, _: &mut sp_std::fmt::Formatter) -> sp_std::fmt::Result {
+		Ok(())
+	}
+}
+
+impl<T: Trait + Send + Sync> SignedExtension for ChargeSponsoredTxPayment<T> where
+	BalanceOf<T>: Send + Sync + From<u64> + FixedPointOperand,
+	T::Call: Dispatchable<Info=DispatchInfo, PostInfo=PostDispatchInfo> + GetCallMetadata + Encode,
+{
+	const IDENTIFIER: &'static str = "ChargeSponsoredTxPayment";
+	type AccountId = T::AccountId;
+	type Call = T::Call;
+	type AdditionalSigned = ();
+	type Pre = (NativePre<T>, Option<SponsoredPayment<T::AccountId>>);
+	fn additional_signed(&self) -> sp_std::result::Result<(), TransactionValidityError> { Ok(()) }
+
+	fn validate(
+		&self,
+		who: &Self::AccountId,
+		call: &Self::Call,
+		info: &DispatchInfoOf<Self::Call>,
+		len: usize,
+	) -> TransactionValidity {
+		let native = ChargeTransactionPayment::<T>::from(self.tip);
+		match &self.sponsorship {
+			None => native.validate(who, call, info, len),
+			Some(sponsorship) => {
+				self.check_sponsorship(sponsorship, who, call, info, len)?;
+				let now = frame_system::Module::<T>::block_number();
+				let remaining: u64 = sponsorship.valid_until.saturating_sub(now)
+					.saturating_add(One::one())
+					.saturated_into();
+				let native_validity = native.validate(&sponsorship.sponsor, call, info, len)?;
+				ValidTransaction::with_tag_prefix("SponsoredTxPayment")
+					.and_provides((&sponsorship.sponsor, who, sponsorship.nonce))
+					.longevity(remaining)
+					.combine_with(native_validity)
+					.build()
+			},
+		}
+	}
+
+	fn pre_dispatch(
+		self,
+		who: &Self::AccountId,
+		call: &Self::Call,
+		info: &DispatchInfoOf<Self::Call>,
+		len: usize
+	) -> Result<Self::Pre, TransactionValidityError> {
+		let native = ChargeTransactionPayment::<T>::from(self.tip);
+		let sponsorship = match &self.sponsorship {
+			None => return Ok((native.pre_dispatch(who, call, info, len)?, None)),
+			Some(sponsorship) => sponsorship,
+		};
+
+		let fee = self.check_sponsorship(sponsorship, who, call, info, len)?;
+		let sponsor = sponsorship.sponsor.clone();
+		let pre = native.pre_dispatch(&sponsor, call, info, len)?;
+		SponsorshipNonces::<T>::mutate(&sponsor, who, |nonce| *nonce += One::one());
+		Module::<T>::mutate_sponsored_fees(&sponsor, who, |fees| fees.saturating_add(fee));
+
+		Ok((pre, Some(SponsoredPayment { sponsor, who: who.clone() })))
+	}
+
+	fn post_dispatch(
+		pre: Self::Pre,
+		info: &DispatchInfoOf<Self::Call>,
+		post_info: &PostDispatchInfoOf<Self::Call>,
+		len: usize,
+		result: &DispatchResult,
+	) -> Result<(), TransactionValidityError> {
+		let (pre, sponsored) = pre;
+		if let Some(SponsoredPayment { sponsor, who }) = sponsored {
+			let (tip, _, _, fee) = &pre;
+			let actual_fee = pallet_transaction_payment::Module::<T>::compute_actual_fee(
+				len as u32,
+				info,
+				post_info,
+				*tip,
+			);
+			let refund = fee.saturating_sub(actual_fee);
+			Module::<T>::mutate_sponsored_fees(&sponsor, &who, |fees| fees.saturating_sub(refund));
+			Module::<T>::deposit_event(RawEvent::TransactionSponsored(sponsor, who, actual_fee));
+		}
+
+		ChargeTransactionPayment::<T>::post_dispatch(pre, info, post_info, len, result)
+	}
+}
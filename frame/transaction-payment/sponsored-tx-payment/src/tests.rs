@@ -0,0 +1,466 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2020 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// Tests for Sponsored Transaction Payment Module
+
+#![cfg(test)]
+
+use super::*;
+
+use frame_support::{
+	assert_ok, assert_noop, impl_outer_dispatch, impl_outer_event, impl_outer_origin,
+	parameter_types, weights::IdentityFee,
+};
+use pallet_balances::Call as BalancesCall;
+use sp_core::H256;
+use sp_runtime::{
+	Perbill,
+	traits::{BlakeTwo256, IdentityLookup},
+	testing::{Header, TestSignature, UintAuthorityId},
+};
+use crate as sponsored_tx_payment;
+
+const CALL: &<Runtime as frame_system::Trait>::Call =
+	&Call::Balances(BalancesCall::transfer(2, 69));
+
+impl_outer_origin! {
+	pub enum Origin for Runtime where system = frame_system {}
+}
+impl_outer_event! {
+	pub enum TestEvent for Runtime {
+		frame_system<T>,
+		pallet_balances<T>,
+		sponsored_tx_payment<T>,
+	}
+}
+impl_outer_dispatch! {
+	pub enum Call for Runtime where origin: Origin {
+		frame_system::System,
+		pallet_balances::Balances,
+	}
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Runtime;
+parameter_types! {
+	pub const BlockHashCount: u64 = 250;
+	pub const MaximumBlockWeight: Weight = 1024;
+	pub const MaximumBlockLength: u32 = 2 * 1024;
+	pub const AvailableBlockRatio: Perbill = Perbill::one();
+	pub const ExtrinsicBaseWeight: Weight = 5;
+}
+impl frame_system::Trait for Runtime {
+	type BaseCallFilter = ();
+	type Origin = Origin;
+	type Index = u64;
+	type BlockNumber = u64;
+	type Call = Call;
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type AccountId = u64;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type Header = Header;
+	type Event = TestEvent;
+	type BlockHashCount = BlockHashCount;
+	type MaximumBlockWeight = MaximumBlockWeight;
+	type DbWeight = ();
+	type BlockExecutionWeight = ();
+	type ExtrinsicBaseWeight = ExtrinsicBaseWeight;
+	type MaximumExtrinsicWeight = MaximumBlockWeight;
+	type MaximumBlockLength = MaximumBlockLength;
+	type AvailableBlockRatio = AvailableBlockRatio;
+	type Version = ();
+	type PalletInfo = ();
+	type AccountData = pallet_balances::AccountData<u64>;
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+}
+parameter_types! {
+	pub const ExistentialDeposit: u64 = 1;
+}
+impl pallet_balances::Trait for Runtime {
+	type Balance = u64;
+	type Event = TestEvent;
+	type DustRemoval = ();
+	type ExistentialDeposit = ExistentialDeposit;
+	type AccountStore = System;
+	type MaxLocks = ();
+	type MaxReserves = ();
+	type ReserveIdentifier = [u8; 8];
+	type WeightInfo = ();
+}
+parameter_types! {
+	pub const TransactionByteFee: u64 = 1;
+}
+impl pallet_transaction_payment::Trait for Runtime {
+	type Currency = Balances;
+	type OnTransactionPayment = ();
+	type TransactionByteFee = TransactionByteFee;
+	type WeightToFee = IdentityFee<u64>;
+	type FeeMultiplierUpdate = ();
+}
+parameter_types! {
+	pub const MaxAllowedCalls: u32 = 2;
+}
+impl Trait for Runtime {
+	type Event = TestEvent;
+	type SponsorSignature = TestSignature;
+	type SponsorSigner = UintAuthorityId;
+	type MaxAllowedCalls = MaxAllowedCalls;
+}
+
+type System = frame_system::Module<Runtime>;
+type Balances = pallet_balances::Module<Runtime>;
+type SponsoredTxPayment = Module<Runtime>;
+
+fn new_test_ext() -> sp_io::TestExternalities {
+	let mut t = frame_system::GenesisConfig::default().build_storage::<Runtime>().unwrap();
+	// Account `1` is a new user without any balance.
+	pallet_balances::GenesisConfig::<Runtime> {
+		balances: vec![(2, 1000), (3, 1000)],
+	}.assimilate_storage(&mut t).unwrap();
+	let mut ext = sp_io::TestExternalities::from(t);
+	ext.execute_with(|| System::set_block_number(1));
+	ext
+}
+
+fn info_from_weight(w: Weight) -> DispatchInfo {
+	// pays_fee: Pays::Yes -- class: DispatchClass::Normal
+	DispatchInfo { weight: w, ..Default::default() }
+}
+
+fn post_info_from_weight(w: Weight) -> PostDispatchInfo {
+	PostDispatchInfo {
+		actual_weight: Some(w),
+		pays_fee: Default::default(),
+	}
+}
+
+fn last_event() -> TestEvent {
+	System::events().pop().map(|e| e.event).expect("Event expected")
+}
+
+/// A sponsorship by `sponsor` of `call` signed by `who`, valid until block 10 and signed by
+/// `signer`.
+fn sponsorship(
+	signer: u64,
+	sponsor: u64,
+	who: u64,
+	call: &Call,
+	limit: u64,
+) -> Option<Sponsorship<u64, u64, u64, u64, TestSignature>> {
+	let nonce = SponsoredTxPayment::sponsorship_nonce(sponsor, who);
+	let valid_until = 10;
+	let payload = SponsoredTxPayment::sponsorship_payload(&who, nonce, valid_until, call, limit);
+	Some(Sponsorship { sponsor, limit, nonce, valid_until, signature: TestSignature(signer, payload) })
+}
+
+fn policy(allowed_calls: Vec<CallSelector>, user_quota: u64) -> SponsorshipPolicy<u64, u64> {
+	SponsorshipPolicy { allowed_calls, user_quota, expiry: None }
+}
+
+fn transfer_selector() -> CallSelector {
+	CallSelector::Call(b"Balances".to_vec(), b"transfer".to_vec())
+}
+
+/// Pay for `CALL` with weight 5, of which 2 is actually used, returning the dispatch result.
+fn sponsored_transfer(limit: u64) -> Result<(), TransactionValidityError> {
+	let len = 10;
+	let pre = ChargeSponsoredTxPayment::<Runtime>::from(0, sponsorship(2, 2, 1, CALL, limit))
+		.pre_dispatch(&1, CALL, &info_from_weight(5), len)?;
+	ChargeSponsoredTxPayment::<Runtime>::post_dispatch(
+		pre,
+		&info_from_weight(5),
+		&post_info_from_weight(2),
+		len,
+		&Ok(()),
+	)
+}
+
+#[test]
+fn unsponsored_transaction_is_paid_by_signer() {
+	new_test_ext().execute_with(|| {
+		let len = 10;
+		let pre = ChargeSponsoredTxPayment::<Runtime>::from(0, None)
+			.pre_dispatch(&3, CALL, &info_from_weight(5), len)
+			.unwrap();
+		// 5 base fee, 10 byte fee, 5 weight fee
+		assert_eq!(Balances::free_balance(3), 1000 - 5 - 10 - 5);
+
+		assert_ok!(ChargeSponsoredTxPayment::<Runtime>::post_dispatch(
+			pre,
+			&info_from_weight(5),
+			&post_info_from_weight(2),
+			len,
+			&Ok(()),
+		));
+		assert_eq!(Balances::free_balance(3), 1000 - 5 - 10 - 2);
+		assert!(
+			ChargeSponsoredTxPayment::<Runtime>::from(0, None)
+				.validate(&1, CALL, &info_from_weight(5), len)
+				.is_err()
+		);
+	});
+}
+
+#[test]
+fn sponsored_transaction_is_paid_by_sponsor() {
+	new_test_ext().execute_with(|| {
+		let len = 10;
+		assert_ok!(SponsoredTxPayment::set_policy(Origin::signed(2), policy(vec![transfer_selector()], 100), 0));
+		let ext = ChargeSponsoredTxPayment::<Runtime>::from(0, sponsorship(2, 2, 1, CALL, 20));
+		assert_ok!(ext.validate(&1, CALL, &info_from_weight(5), len));
+
+		let pre = ext.pre_dispatch(&1, CALL, &info_from_weight(5), len).unwrap();
+		assert_eq!(Balances::free_balance(2), 1000 - 20);
+		assert_eq!(Balances::free_balance(1), 0);
+
+		assert_ok!(ChargeSponsoredTxPayment::<Runtime>::post_dispatch(
+			pre,
+			&info_from_weight(5),
+			&post_info_from_weight(2),
+			len,
+			&Ok(()),
+		));
+		assert_eq!(Balances::free_balance(2), 1000 - 17);
+		assert_eq!(
+			last_event(),
+			TestEvent::sponsored_tx_payment(RawEvent::TransactionSponsored(2, 1, 17)),
+		);
+	});
+}
+
+#[test]
+fn sponsorship_must_be_signed_by_sponsor() {
+	new_test_ext().execute_with(|| {
+		let len = 10;
+		let other_call = Call::System(frame_system::Call::remark(vec![]));
+		let bad_sponsorships = vec![
+			// Signed by someone else.
+			sponsorship(3, 2, 1, CALL, 20),
+			// Signed for another call.
+			sponsorship(2, 2, 1, &other_call, 20),
+			// Signed for another signer.
+			sponsorship(2, 2, 3, CALL, 20),
+		];
+		for bad_sponsorship in bad_sponsorships {
+			assert_eq!(
+				ChargeSponsoredTxPayment::<Runtime>::from(0, bad_sponsorship)
+					.validate(&1, CALL, &info_from_weight(5), len),
+				Err(InvalidTransaction::BadProof.into()),
+			);
+		}
+
+		// Signed for another limit.
+		let mut raised_limit = sponsorship(2, 2, 1, CALL, 20).unwrap();
+		raised_limit.limit = 100;
+		assert_eq!(
+			ChargeSponsoredTxPayment::<Runtime>::from(0, Some(raised_limit))
+				.pre_dispatch(&1, CALL, &info_from_weight(5), len)
+				.map(|_| ()),
+			Err(InvalidTransaction::BadProof.into()),
+		);
+		assert_eq!(Balances::free_balance(2), 1000);
+	});
+}
+
+#[test]
+fn spending_limit_is_enforced() {
+	new_test_ext().execute_with(|| {
+		let len = 10;
+		assert_ok!(SponsoredTxPayment::set_policy(Origin::signed(2), policy(vec![transfer_selector()], 100), 0));
+		// The fee of 20 plus a tip of 1 exceeds the limit.
+		assert_eq!(
+			ChargeSponsoredTxPayment::<Runtime>::from(1, sponsorship(2, 2, 1, CALL, 20))
+				.validate(&1, CALL, &info_from_weight(5), len),
+			Err(invalid(Error::<Runtime>::LimitExceeded)),
+		);
+		assert_eq!(sponsored_transfer(19), Err(invalid(Error::<Runtime>::LimitExceeded)));
+		assert_ok!(sponsored_transfer(20));
+	});
+}
+
+#[test]
+fn set_and_remove_policy_work() {
+	new_test_ext().execute_with(|| {
+		let too_many_calls = vec![
+			transfer_selector(),
+			CallSelector::Pallet(b"System".to_vec()),
+			CallSelector::Pallet(b"Balances".to_vec()),
+		];
+		assert_noop!(
+			SponsoredTxPayment::set_policy(Origin::signed(2), policy(too_many_calls, 100), 0),
+			Error::<Runtime>::TooManyCalls,
+		);
+		assert_noop!(SponsoredTxPayment::remove_policy(Origin::signed(2), 0), Error::<Runtime>::NoPolicy);
+
+		assert_ok!(SponsoredTxPayment::set_policy(Origin::signed(2), policy(vec![transfer_selector()], 100), 0));
+		assert_eq!(last_event(), TestEvent::sponsored_tx_payment(RawEvent::PolicySet(2)));
+		assert_eq!(SponsoredTxPayment::policy(2), Some(policy(vec![transfer_selector()], 100)));
+
+		assert_ok!(SponsoredTxPayment::remove_policy(Origin::signed(2), 0));
+		assert_eq!(last_event(), TestEvent::sponsored_tx_payment(RawEvent::PolicyRemoved(2)));
+		assert_eq!(SponsoredTxPayment::policy(2), None);
+	});
+}
+
+#[test]
+fn policy_restricts_sponsored_calls() {
+	new_test_ext().execute_with(|| {
+		let len = 10;
+		let remark = Call::System(frame_system::Call::remark(vec![]));
+		assert_ok!(SponsoredTxPayment::set_policy(Origin::signed(2), policy(vec![transfer_selector()], 100), 0));
+
+		assert_ok!(
+			ChargeSponsoredTxPayment::<Runtime>::from(0, sponsorship(2, 2, 1, CALL, 20))
+				.validate(&1, CALL, &info_from_weight(5), len)
+		);
+		assert_eq!(
+			ChargeSponsoredTxPayment::<Runtime>::from(0, sponsorship(2, 2, 1, &remark, 20))
+				.validate(&1, &remark, &info_from_weight(5), len),
+			Err(invalid(Error::<Runtime>::CallNotSponsored)),
+		);
+
+		// Whole pallets can be sponsored.
+		let system_selector = CallSelector::Pallet(b"System".to_vec());
+		assert_ok!(SponsoredTxPayment::set_policy(Origin::signed(2), policy(vec![system_selector], 100), 0));
+		assert_ok!(
+			ChargeSponsoredTxPayment::<Runtime>::from(0, sponsorship(2, 2, 1, &remark, 20))
+				.validate(&1, &remark, &info_from_weight(5), len)
+		);
+	});
+}
+
+#[test]
+fn policy_expires() {
+	new_test_ext().execute_with(|| {
+		let mut expiring = policy(vec![transfer_selector()], 100);
+		expiring.expiry = Some(3);
+		assert_ok!(SponsoredTxPayment::set_policy(Origin::signed(2), expiring, 0));
+
+		System::set_block_number(2);
+		assert_ok!(sponsored_transfer(20));
+
+		System::set_block_number(3);
+		assert_eq!(sponsored_transfer(20), Err(invalid(Error::<Runtime>::PolicyExpired)));
+	});
+}
+
+#[test]
+fn user_quota_is_charged_and_refunded() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(SponsoredTxPayment::set_policy(Origin::signed(2), policy(vec![transfer_selector()], 40), 0));
+
+		// The fee of 20 is charged up front and 17 remain after the refund.
+		assert_ok!(sponsored_transfer(20));
+		assert_eq!(SponsoredTxPayment::sponsored_fees(&2, &1), 17);
+		assert_ok!(sponsored_transfer(20));
+		assert_eq!(SponsoredTxPayment::sponsored_fees(&2, &1), 34);
+		assert_eq!(sponsored_transfer(20), Err(invalid(Error::<Runtime>::QuotaExceeded)));
+		assert_eq!(Balances::free_balance(2), 1000 - 34);
+
+		// Quotas are per user.
+		let len = 10;
+		assert_ok!(
+			ChargeSponsoredTxPayment::<Runtime>::from(0, sponsorship(2, 2, 3, CALL, 20))
+				.validate(&3, CALL, &info_from_weight(5), len)
+		);
+
+		// Replacing the policy resets the quotas.
+		assert_eq!(SponsoredTxPayment::sponsored_users(2), 1);
+		assert_noop!(
+			SponsoredTxPayment::set_policy(Origin::signed(2), policy(vec![transfer_selector()], 40), 0),
+			Error::<Runtime>::BadWitness,
+		);
+		assert_ok!(SponsoredTxPayment::set_policy(Origin::signed(2), policy(vec![transfer_selector()], 40), 1));
+		assert_eq!(SponsoredTxPayment::sponsored_fees(&2, &1), 0);
+		assert_eq!(SponsoredTxPayment::sponsored_users(2), 0);
+		assert_ok!(sponsored_transfer(20));
+		assert_eq!(SponsoredTxPayment::sponsored_fees(&2, &1), 17);
+	});
+}
+
+#[test]
+fn sponsorship_requires_policy() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(sponsored_transfer(20), Err(invalid(Error::<Runtime>::NoPolicy)));
+		assert_eq!(Balances::free_balance(2), 1000);
+	});
+}
+
+#[test]
+fn sponsorship_cannot_be_replayed() {
+	new_test_ext().execute_with(|| {
+		let len = 10;
+		assert_ok!(SponsoredTxPayment::set_policy(Origin::signed(2), policy(vec![transfer_selector()], 100), 0));
+		let first = sponsorship(2, 2, 1, CALL, 20);
+		assert_ok!(sponsored_transfer(20));
+		assert_eq!(SponsoredTxPayment::sponsorship_nonce(2, 1), 1);
+
+		// The same sponsorship can't be used again.
+		assert_eq!(
+			ChargeSponsoredTxPayment::<Runtime>::from(0, first)
+				.validate(&1, CALL, &info_from_weight(5), len),
+			Err(InvalidTransaction::Stale.into()),
+		);
+
+		// A sponsorship for a later nonce is not valid yet.
+		let mut future = sponsorship(2, 2, 1, CALL, 20).unwrap();
+		future.nonce = 2;
+		assert_eq!(
+			ChargeSponsoredTxPayment::<Runtime>::from(0, Some(future))
+				.validate(&1, CALL, &info_from_weight(5), len),
+			Err(InvalidTransaction::Future.into()),
+		);
+
+		assert_ok!(sponsored_transfer(20));
+		assert_eq!(SponsoredTxPayment::sponsorship_nonce(2, 1), 2);
+		assert_eq!(Balances::free_balance(2), 1000 - 34);
+	});
+}
+
+#[test]
+fn sponsorship_expires() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(SponsoredTxPayment::set_policy(Origin::signed(2), policy(vec![transfer_selector()], 100), 0));
+
+		// The sponsorship is valid up to and including block 10.
+		System::set_block_number(10);
+		assert_ok!(sponsored_transfer(20));
+
+		System::set_block_number(11);
+		assert_eq!(sponsored_transfer(20), Err(InvalidTransaction::Stale.into()));
+	});
+}
+
+#[test]
+fn removing_policy_clears_sponsored_fees() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(SponsoredTxPayment::set_policy(Origin::signed(2), policy(vec![transfer_selector()], 100), 0));
+		assert_ok!(sponsored_transfer(20));
+		assert_eq!(SponsoredTxPayment::sponsored_fees(&2, &1), 17);
+
+		assert_noop!(SponsoredTxPayment::remove_policy(Origin::signed(2), 0), Error::<Runtime>::BadWitness);
+		assert_ok!(SponsoredTxPayment::remove_policy(Origin::signed(2), 1));
+		assert!(!SponsoredFees::<Runtime>::contains_key(&2, &1));
+		assert_eq!(SponsoredTxPayment::sponsored_users(2), 0);
+
+		// The nonce is kept, so that earlier sponsorships can't be replayed under a new policy.
+		assert_eq!(SponsoredTxPayment::sponsorship_nonce(2, 1), 1);
+	});
+}
@@ -38,13 +38,16 @@ use frame_support::{
 	decl_storage, decl_module,
 	traits::{Currency, Get, OnUnbalanced, ExistenceRequirement, WithdrawReason, Imbalance},
 	weights::{
-		Weight, DispatchInfo, PostDispatchInfo, GetDispatchInfo, Pays, WeightToFeePolynomial,
-		WeightToFeeCoefficient,
+		Weight, DispatchClass, DispatchInfo, PostDispatchInfo, GetDispatchInfo, Pays,
+		WeightToFeePolynomial, WeightToFeeCoefficient,
 	},
 	dispatch::DispatchResult,
+	storage::with_transaction,
+	type_info::TypeInfo,
 };
 use sp_runtime::{
-	FixedU128, FixedPointNumber, FixedPointOperand, Perquintill, RuntimeDebug,
+	FixedU128, FixedPointNumber, FixedPointOperand, Perbill, Perquintill, RuntimeDebug,
+	TransactionOutcome,
 	transaction_validity::{
 		TransactionPriority, ValidTransaction, InvalidTransaction, TransactionValidityError,
 		TransactionValidity,
@@ -54,7 +57,7 @@ use sp_runtime::{
 		DispatchInfoOf, PostDispatchInfoOf,
 	},
 };
-use pallet_transaction_payment_rpc_runtime_api::RuntimeDispatchInfo;
+use pallet_transaction_payment_rpc_runtime_api::{FeeDetails, InclusionFee, RuntimeDispatchInfo};
 
 /// Fee multiplier.
 pub type Multiplier = FixedU128;
@@ -198,7 +201,7 @@ impl<T, S, V, M> Convert<Multiplier, Multiplier> for TargetedFeeAdjustment<T, S,
 }
 
 /// Storage releases of the module.
-#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug)]
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo)]
 enum Releases {
 	/// Original version of the module.
 	V1Ancient,
@@ -328,6 +331,47 @@ impl<T: Trait> Module<T> where
 		RuntimeDispatchInfo { weight, class, partial_fee }
 	}
 
+	/// Query the detailed fee of a given `call`.
+	///
+	/// Like [`query_info`](#method.query_info), this does not account for the tip or anything else
+	/// that depends on the signed extensions of the extrinsic.
+	pub fn query_fee_details<Extrinsic: GetDispatchInfo>(
+		unchecked_extrinsic: Extrinsic,
+		len: u32,
+	) -> FeeDetails<BalanceOf<T>>
+	where
+		T::Call: Dispatchable<Info=DispatchInfo>,
+	{
+		let info = <Extrinsic as GetDispatchInfo>::get_dispatch_info(&unchecked_extrinsic);
+		Self::compute_fee_details(len, &info, 0u32.into())
+	}
+
+	/// Project the fee multiplier over the next `blocks` blocks, assuming that each of them is
+	/// filled to `fullness` of its normal dispatch capacity.
+	///
+	/// The first value is the current `NextFeeMultiplier`, followed by the `blocks` values it
+	/// would take at the end of each of the next blocks according to `T::FeeMultiplierUpdate`.
+	/// The storage is left untouched.
+	pub fn project_fee_multiplier(fullness: Perbill, blocks: u32) -> Vec<Multiplier> {
+		let normal_max_weight =
+			<T as frame_system::Trait>::AvailableBlockRatio::get() *
+			<T as frame_system::Trait>::MaximumBlockWeight::get();
+		let block_weight = fullness * normal_max_weight;
+
+		with_transaction(|| {
+			// The multiplier update reads the weight of the block being finalized.
+			frame_system::Module::<T>::set_block_weight_unchecked(block_weight, DispatchClass::Normal);
+
+			let mut multiplier = Self::next_fee_multiplier();
+			let mut projection = vec![multiplier];
+			for _ in 0..blocks {
+				multiplier = T::FeeMultiplierUpdate::convert(multiplier);
+				projection.push(multiplier);
+			}
+			TransactionOutcome::Rollback(projection)
+		})
+	}
+
 	/// Compute the final fee value for a particular transaction.
 	///
 	/// The final fee is composed of:
@@ -355,6 +399,19 @@ impl<T: Trait> Module<T> where
 		tip: BalanceOf<T>,
 	) -> BalanceOf<T> where
 		T::Call: Dispatchable<Info=DispatchInfo>,
+	{
+		Self::compute_fee_details(len, info, tip).final_fee()
+	}
+
+	/// Compute the fee details for a particular transaction.
+	///
+	/// The components are the ones described in [`compute_fee`](#method.compute_fee).
+	pub fn compute_fee_details(
+		len: u32,
+		info: &DispatchInfoOf<T::Call>,
+		tip: BalanceOf<T>,
+	) -> FeeDetails<BalanceOf<T>> where
+		T::Call: Dispatchable<Info=DispatchInfo>,
 	{
 		Self::compute_fee_raw(len, info.weight, tip, info.pays_fee)
 	}
@@ -372,6 +429,7 @@ impl<T: Trait> Module<T> where
 		T::Call: Dispatchable<Info=DispatchInfo,PostInfo=PostDispatchInfo>,
 	{
 		Self::compute_fee_raw(len, post_info.calc_actual_weight(info), tip, post_info.pays_fee(info))
+			.final_fee()
 	}
 
 	fn compute_fee_raw(
@@ -379,7 +437,7 @@ impl<T: Trait> Module<T> where
 		weight: Weight,
 		tip: BalanceOf<T>,
 		pays_fee: Pays,
-	) -> BalanceOf<T> {
+	) -> FeeDetails<BalanceOf<T>> {
 		if pays_fee == Pays::Yes {
 			let len = <BalanceOf<T>>::from(len);
 			let per_byte = T::TransactionByteFee::get();
@@ -394,12 +452,19 @@ impl<T: Trait> Module<T> where
 			let adjusted_weight_fee = multiplier.saturating_mul_int(unadjusted_weight_fee);
 
 			let base_fee = Self::weight_to_fee(T::ExtrinsicBaseWeight::get());
-			base_fee
-				.saturating_add(fixed_len_fee)
-				.saturating_add(adjusted_weight_fee)
-				.saturating_add(tip)
+			FeeDetails {
+				inclusion_fee: Some(InclusionFee {
+					base_fee,
+					len_fee: fixed_len_fee,
+					adjusted_weight_fee,
+				}),
+				tip,
+			}
 		} else {
-			tip
+			FeeDetails {
+				inclusion_fee: None,
+				tip,
+			}
 		}
 	}
 
@@ -478,7 +543,7 @@ impl<T: Trait + Send + Sync> ChargeTransactionPayment<T> where
 	/// and the entire block weight `(1/1)`, its priority is `fee * min(1, 4) = fee * 1`. This means
 	///  that the transaction which consumes more resources (either length or weight) with the same
 	/// `fee` ends up having lower priority.
-	fn get_priority(len: usize, info: &DispatchInfoOf<T::Call>, final_fee: BalanceOf<T>) -> TransactionPriority {
+	pub fn get_priority(len: usize, info: &DispatchInfoOf<T::Call>, final_fee: BalanceOf<T>) -> TransactionPriority {
 		let weight_saturation = T::MaximumBlockWeight::get() / info.weight.max(1);
 		let len_saturation = T::MaximumBlockLength::get() as u64 / (len as u64).max(1);
 		let coefficient: BalanceOf<T> = weight_saturation.min(len_saturation).saturated_into::<BalanceOf<T>>();
@@ -672,6 +737,8 @@ mod tests {
 		type ExistentialDeposit = ExistentialDeposit;
 		type AccountStore = System;
 		type MaxLocks = ();
+		type MaxReserves = ();
+		type ReserveIdentifier = [u8; 8];
 		type WeightInfo = ();
 	}
 	thread_local! {
@@ -982,6 +1049,105 @@ mod tests {
 		});
 	}
 
+	#[test]
+	fn query_fee_details_works() {
+		let call = Call::Balances(BalancesCall::transfer(2, 69));
+		let origin = 111111;
+		let extra = ();
+		let xt = TestXt::new(call, Some((origin, extra)));
+		let info  = xt.get_dispatch_info();
+		let ext = xt.encode();
+		let len = ext.len() as u32;
+		ExtBuilder::default()
+			.base_weight(5)
+			.weight_fee(2)
+			.build()
+			.execute_with(||
+		{
+			// all fees should be x1.5
+			NextFeeMultiplier::put(Multiplier::saturating_from_rational(3, 2));
+
+			let details = TransactionPayment::query_fee_details(xt.clone(), len);
+			assert_eq!(
+				details,
+				FeeDetails {
+					inclusion_fee: Some(InclusionFee {
+						base_fee: 5 * 2,
+						len_fee: len as u64,
+						adjusted_weight_fee: info.weight.min(MaximumBlockWeight::get()) as u64 * 2 * 3 / 2,
+					}),
+					tip: 0,
+				},
+			);
+			assert_eq!(details.final_fee(), TransactionPayment::query_info(xt, len).partial_fee);
+		});
+	}
+
+	#[test]
+	fn compute_fee_details_works() {
+		ExtBuilder::default()
+			.base_weight(100)
+			.byte_fee(10)
+			.balance_factor(0)
+			.build()
+			.execute_with(||
+		{
+			let operational = DispatchInfo {
+				weight: 0,
+				class: DispatchClass::Operational,
+				pays_fee: Pays::No,
+			};
+			assert_eq!(
+				Module::<Runtime>::compute_fee_details(0, &operational, 10),
+				FeeDetails { inclusion_fee: None, tip: 10 },
+			);
+
+			let normal = DispatchInfo {
+				weight: 2000,
+				class: DispatchClass::Normal,
+				pays_fee: Pays::Yes,
+			};
+			NextFeeMultiplier::put(Multiplier::saturating_from_rational(1, 2));
+			let details = Module::<Runtime>::compute_fee_details(10, &normal, 10);
+			assert_eq!(
+				details,
+				FeeDetails {
+					inclusion_fee: Some(InclusionFee {
+						base_fee: 100,
+						len_fee: 100,
+						// the weight is capped at the maximum block weight.
+						adjusted_weight_fee: 1024 / 2,
+					}),
+					tip: 10,
+				},
+			);
+			assert_eq!(details.final_fee(), Module::<Runtime>::compute_fee(10, &normal, 10));
+		});
+	}
+
+	#[test]
+	fn project_fee_multiplier_works() {
+		ExtBuilder::default().build().execute_with(|| {
+			let multiplier = Multiplier::saturating_from_rational(3, 2);
+			NextFeeMultiplier::put(multiplier);
+			System::register_extra_weight_unchecked(100, DispatchClass::Normal);
+
+			// The multiplier of the test runtime does not change.
+			assert_eq!(
+				TransactionPayment::project_fee_multiplier(Perbill::from_percent(75), 3),
+				vec![multiplier; 4],
+			);
+			assert_eq!(
+				TransactionPayment::project_fee_multiplier(Perbill::one(), 0),
+				vec![multiplier],
+			);
+
+			// The storage is left untouched.
+			assert_eq!(NextFeeMultiplier::get(), multiplier);
+			assert_eq!(System::block_weight().get(DispatchClass::Normal), 100);
+		});
+	}
+
 	#[test]
 	fn compute_fee_works_without_multiplier() {
 		ExtBuilder::default()
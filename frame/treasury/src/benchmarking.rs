@@ -109,62 +109,38 @@ fn create_approved_proposals<T: Trait<I>, I: Instance>(n: u32) -> Result<(), &'s
 	Ok(())
 }
 
-// Create bounties that are approved for use in `on_initialize`.
-fn create_approved_bounties<T: Trait<I>, I: Instance>(n: u32) -> Result<(), &'static str> {
-	for i in 0 .. n {
-		let (caller, _curator, _fee, value, reason) = setup_bounty::<T, I>(i, MAX_BYTES);
-		Treasury::<T, I>::propose_bounty(RawOrigin::Signed(caller).into(), value, reason)?;
-		let bounty_id = BountyCount::<I>::get() - 1;
-		Treasury::<T, I>::approve_bounty(RawOrigin::Root.into(), bounty_id)?;
-	}
-	ensure!(BountyApprovals::<I>::get().len() == n as usize, "Not all bounty approved");
-	Ok(())
-}
-
-// Create the pre-requisite information needed to create a treasury `propose_bounty`.
-fn setup_bounty<T: Trait<I>, I: Instance>(u: u32, d: u32) -> (
-	T::AccountId,
-	T::AccountId,
-	BalanceOf<T, I>,
-	BalanceOf<T, I>,
-	Vec<u8>,
-) {
-	let caller = account("caller", u, SEED);
-	let value: BalanceOf<T, I> = T::BountyValueMinimum::get().saturating_mul(100.into());
-	let fee = value / 2.into();
-	let deposit = T::BountyDepositBase::get() + T::DataDepositPerByte::get() * MAX_BYTES.into();
-	let _ = T::Currency::make_free_balance_be(&caller, deposit);
-	let curator = account("curator", u, SEED);
-	let _ = T::Currency::make_free_balance_be(&curator, fee / 2.into());
-	let reason = vec![0; d as usize];
-	(caller, curator, fee, value, reason)
-}
-
-fn create_bounty<T: Trait<I>, I: Instance>() -> Result<(
-	<T::Lookup as StaticLookup>::Source,
-	BountyIndex,
-), &'static str> {
-	let (caller, curator, fee, value, reason) = setup_bounty::<T, I>(0, MAX_BYTES);
-	let curator_lookup = T::Lookup::unlookup(curator.clone());
-	Treasury::<T, I>::propose_bounty(RawOrigin::Signed(caller).into(), value, reason)?;
-	let bounty_id = BountyCount::<I>::get() - 1;
-	Treasury::<T, I>::approve_bounty(RawOrigin::Root.into(), bounty_id)?;
-	Treasury::<T, I>::on_initialize(T::BlockNumber::zero());
-	Treasury::<T, I>::propose_curator(RawOrigin::Root.into(), bounty_id, curator_lookup.clone(), fee)?;
-	Treasury::<T, I>::accept_curator(RawOrigin::Signed(curator).into(), bounty_id)?;
-	Ok((curator_lookup, bounty_id))
-}
-
 fn setup_pod_account<T: Trait<I>, I: Instance>() {
 	let pot_account = Treasury::<T, I>::account_id();
 	let value = T::Currency::minimum_balance().saturating_mul(1_000_000_000.into());
 	let _ = T::Currency::make_free_balance_be(&pot_account, value);
 }
 
+// Approve a spend of the default asset which is payable at once, and fund the treasury account to
+// pay it out.
+fn setup_asset_spend<T: Trait<I>, I: Instance>() -> Result<SpendIndex, &'static str> where
+	T::Assets: fungibles::Mutate<T::AccountId>,
+	AssetIdOf<T, I>: Default,
+{
+	let asset_id = AssetIdOf::<T, I>::default();
+	let minimum_balance = T::Assets::minimum_balance(asset_id);
+	let amount = minimum_balance.saturating_mul(100u32.into());
+	T::Assets::mint_into(asset_id, &Treasury::<T, I>::account_id(), amount + minimum_balance)?;
+	let beneficiary_lookup = T::Lookup::unlookup(account("beneficiary", 0, SEED));
+	let schedule = PayoutSchedule {
+		valid_from: Zero::zero(),
+		instalments: 1,
+		period: Zero::zero(),
+	};
+	Treasury::<T, I>::spend(RawOrigin::Root.into(), asset_id, amount, beneficiary_lookup, schedule)?;
+	Ok(Treasury::<T, I>::spend_count() - 1)
+}
+
 const MAX_BYTES: u32 = 16384;
 const MAX_TIPPERS: u32 = 100;
 
 benchmarks_instance! {
+	where_clause { where T::Assets: fungibles::Mutate<T::AccountId>, AssetIdOf<T, I>: Default }
+
 	_ { }
 
 	propose_spend {
@@ -275,96 +251,27 @@ benchmarks_instance! {
 		frame_benchmarking::benchmarking::add_to_whitelist(caller_key.into());
 	}: _(RawOrigin::Signed(caller), hash)
 
-	propose_bounty {
-		let d in 0 .. MAX_BYTES;
-
-		let (caller, curator, fee, value, description) = setup_bounty::<T, _>(0, d);
-	}: _(RawOrigin::Signed(caller), value, description)
-
-	approve_bounty {
-		let (caller, curator, fee, value, reason) = setup_bounty::<T, _>(0, MAX_BYTES);
-		Treasury::<T, _>::propose_bounty(RawOrigin::Signed(caller).into(), value, reason)?;
-		let bounty_id = BountyCount::<I>::get() - 1;
-	}: _(RawOrigin::Root, bounty_id)
-
-	propose_curator {
-		setup_pod_account::<T, _>();
-		let (caller, curator, fee, value, reason) = setup_bounty::<T, _>(0, MAX_BYTES);
-		let curator_lookup = T::Lookup::unlookup(curator.clone());
-		Treasury::<T, _>::propose_bounty(RawOrigin::Signed(caller).into(), value, reason)?;
-		let bounty_id = BountyCount::<I>::get() - 1;
-		Treasury::<T, _>::approve_bounty(RawOrigin::Root.into(), bounty_id)?;
-		Treasury::<T, _>::on_initialize(T::BlockNumber::zero());
-	}: _(RawOrigin::Root, bounty_id, curator_lookup, fee)
-
-	// Worst case when curator is inactive and any sender unassigns the curator.
-	unassign_curator {
-		setup_pod_account::<T, _>();
-		let (curator_lookup, bounty_id) = create_bounty::<T, _>()?;
-		Treasury::<T, _>::on_initialize(T::BlockNumber::zero());
-		let bounty_id = BountyCount::<I>::get() - 1;
-		frame_system::Module::<T>::set_block_number(T::BountyUpdatePeriod::get() + 1.into());
-		let caller = whitelisted_caller();
-	}: _(RawOrigin::Signed(caller), bounty_id)
-
-	accept_curator {
-		setup_pod_account::<T, _>();
-		let (caller, curator, fee, value, reason) = setup_bounty::<T, _>(0, MAX_BYTES);
-		let curator_lookup = T::Lookup::unlookup(curator.clone());
-		Treasury::<T, _>::propose_bounty(RawOrigin::Signed(caller).into(), value, reason)?;
-		let bounty_id = BountyCount::<I>::get() - 1;
-		Treasury::<T, _>::approve_bounty(RawOrigin::Root.into(), bounty_id)?;
-		Treasury::<T, _>::on_initialize(T::BlockNumber::zero());
-		Treasury::<T, _>::propose_curator(RawOrigin::Root.into(), bounty_id, curator_lookup, fee)?;
-	}: _(RawOrigin::Signed(curator), bounty_id)
-
-	award_bounty {
-		setup_pod_account::<T, _>();
-		let (curator_lookup, bounty_id) = create_bounty::<T, _>()?;
-		Treasury::<T, _>::on_initialize(T::BlockNumber::zero());
-
-		let bounty_id = BountyCount::<I>::get() - 1;
-		let curator = T::Lookup::lookup(curator_lookup)?;
-		let beneficiary = T::Lookup::unlookup(account("beneficiary", 0, SEED));
-	}: _(RawOrigin::Signed(curator), bounty_id, beneficiary)
-
-	claim_bounty {
-		setup_pod_account::<T, _>();
-		let (curator_lookup, bounty_id) = create_bounty::<T, _>()?;
-		Treasury::<T, _>::on_initialize(T::BlockNumber::zero());
-
-		let bounty_id = BountyCount::<I>::get() - 1;
-		let curator = T::Lookup::lookup(curator_lookup)?;
-
-		let beneficiary = T::Lookup::unlookup(account("beneficiary", 0, SEED));
-		Treasury::<T, _>::award_bounty(RawOrigin::Signed(curator.clone()).into(), bounty_id, beneficiary)?;
-
-		frame_system::Module::<T>::set_block_number(T::BountyDepositPayoutDelay::get());
-
-	}: _(RawOrigin::Signed(curator), bounty_id)
-
-	close_bounty_proposed {
-		setup_pod_account::<T, _>();
-		let (caller, curator, fee, value, reason) = setup_bounty::<T, _>(0, 0);
-		Treasury::<T, _>::propose_bounty(RawOrigin::Signed(caller).into(), value, reason)?;
-		let bounty_id = BountyCount::<I>::get() - 1;
-	}: close_bounty(RawOrigin::Root, bounty_id)
-
-	close_bounty_active {
-		setup_pod_account::<T, _>();
-		let (curator_lookup, bounty_id) = create_bounty::<T, _>()?;
-		Treasury::<T, _>::on_initialize(T::BlockNumber::zero());
-		let bounty_id = BountyCount::<I>::get() - 1;
-	}: close_bounty(RawOrigin::Root, bounty_id)
-
-	extend_bounty_expiry {
-		setup_pod_account::<T, _>();
-		let (curator_lookup, bounty_id) = create_bounty::<T, _>()?;
-		Treasury::<T, _>::on_initialize(T::BlockNumber::zero());
-
-		let bounty_id = BountyCount::<I>::get() - 1;
-		let curator = T::Lookup::lookup(curator_lookup)?;
-	}: _(RawOrigin::Signed(curator), bounty_id, Vec::new())
+	spend {
+		let asset_id = AssetIdOf::<T, I>::default();
+		let minimum_balance = T::Assets::minimum_balance(asset_id);
+		let amount = minimum_balance.saturating_mul(100u32.into());
+		T::Assets::mint_into(asset_id, &Treasury::<T, I>::account_id(), amount + minimum_balance)?;
+		let beneficiary_lookup = T::Lookup::unlookup(account("beneficiary", 0, SEED));
+		let schedule = PayoutSchedule {
+			valid_from: Zero::zero(),
+			instalments: T::MaxInstalments::get(),
+			period: 1u32.into(),
+		};
+	}: _(RawOrigin::Root, asset_id, amount, beneficiary_lookup, schedule)
+
+	payout {
+		let index = setup_asset_spend::<T, _>()?;
+		let caller: T::AccountId = whitelisted_caller();
+	}: _(RawOrigin::Signed(caller), index)
+
+	void_spend {
+		let index = setup_asset_spend::<T, _>()?;
+	}: _(RawOrigin::Root, index)
 
 	on_initialize_proposals {
 		let p in 0 .. 100;
@@ -373,14 +280,6 @@ benchmarks_instance! {
 	}: {
 		Treasury::<T, _>::on_initialize(T::BlockNumber::zero());
 	}
-
-	on_initialize_bounties {
-		let b in 0 .. 100;
-		setup_pod_account::<T, _>();
-		create_approved_bounties::<T, _>(b)?;
-	}: {
-		Treasury::<T, _>::on_initialize(T::BlockNumber::zero());
-	}
 }
 
 #[cfg(test)]
@@ -400,18 +299,10 @@ mod tests {
 			assert_ok!(test_benchmark_tip_new::<Test>());
 			assert_ok!(test_benchmark_tip::<Test>());
 			assert_ok!(test_benchmark_close_tip::<Test>());
-			assert_ok!(test_benchmark_propose_bounty::<Test>());
-			assert_ok!(test_benchmark_approve_bounty::<Test>());
-			assert_ok!(test_benchmark_propose_curator::<Test>());
-			assert_ok!(test_benchmark_unassign_curator::<Test>());
-			assert_ok!(test_benchmark_accept_curator::<Test>());
-			assert_ok!(test_benchmark_award_bounty::<Test>());
-			assert_ok!(test_benchmark_claim_bounty::<Test>());
-			assert_ok!(test_benchmark_close_bounty_proposed::<Test>());
-			assert_ok!(test_benchmark_close_bounty_active::<Test>());
-			assert_ok!(test_benchmark_extend_bounty_expiry::<Test>());
+			assert_ok!(test_benchmark_spend::<Test>());
+			assert_ok!(test_benchmark_payout::<Test>());
+			assert_ok!(test_benchmark_void_spend::<Test>());
 			assert_ok!(test_benchmark_on_initialize_proposals::<Test>());
-			assert_ok!(test_benchmark_on_initialize_bounties::<Test>());
 		});
 	}
 }
@@ -16,6 +16,10 @@
 // limitations under the License.
 
 //! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 2.0.0-rc6
+//!
+//! The weights after the `Estimates` comment were written by hand and not generated by the
+//! benchmark CLI. They are estimated from the generated weights of similar calls until the
+//! benchmarks are run again.
 
 #![allow(unused_parens)]
 #![allow(unused_imports)]
@@ -68,57 +72,6 @@ impl crate::WeightInfo for () {
 			.saturating_add(DbWeight::get().reads(3 as Weight))
 			.saturating_add(DbWeight::get().writes(3 as Weight))
 	}
-	fn propose_bounty(d: u32, ) -> Weight {
-		(86198000 as Weight)
-			.saturating_add((1000 as Weight).saturating_mul(d as Weight))
-			.saturating_add(DbWeight::get().reads(2 as Weight))
-			.saturating_add(DbWeight::get().writes(4 as Weight))
-	}
-	fn approve_bounty() -> Weight {
-		(23063000 as Weight)
-			.saturating_add(DbWeight::get().reads(2 as Weight))
-			.saturating_add(DbWeight::get().writes(2 as Weight))
-	}
-	fn propose_curator() -> Weight {
-		(18890000 as Weight)
-			.saturating_add(DbWeight::get().reads(1 as Weight))
-			.saturating_add(DbWeight::get().writes(1 as Weight))
-	}
-	fn unassign_curator() -> Weight {
-		(66768000 as Weight)
-			.saturating_add(DbWeight::get().reads(2 as Weight))
-			.saturating_add(DbWeight::get().writes(2 as Weight))
-	}
-	fn accept_curator() -> Weight {
-		(69131000 as Weight)
-			.saturating_add(DbWeight::get().reads(2 as Weight))
-			.saturating_add(DbWeight::get().writes(2 as Weight))
-	}
-	fn award_bounty() -> Weight {
-		(48184000 as Weight)
-			.saturating_add(DbWeight::get().reads(1 as Weight))
-			.saturating_add(DbWeight::get().writes(1 as Weight))
-	}
-	fn claim_bounty() -> Weight {
-		(243104000 as Weight)
-			.saturating_add(DbWeight::get().reads(4 as Weight))
-			.saturating_add(DbWeight::get().writes(5 as Weight))
-	}
-	fn close_bounty_proposed() -> Weight {
-		(65917000 as Weight)
-			.saturating_add(DbWeight::get().reads(2 as Weight))
-			.saturating_add(DbWeight::get().writes(3 as Weight))
-	}
-	fn close_bounty_active() -> Weight {
-		(157232000 as Weight)
-			.saturating_add(DbWeight::get().reads(3 as Weight))
-			.saturating_add(DbWeight::get().writes(4 as Weight))
-	}
-	fn extend_bounty_expiry() -> Weight {
-		(46216000 as Weight)
-			.saturating_add(DbWeight::get().reads(1 as Weight))
-			.saturating_add(DbWeight::get().writes(1 as Weight))
-	}
 	fn on_initialize_proposals(p: u32, ) -> Weight {
 		(119765000 as Weight)
 			.saturating_add((108368000 as Weight).saturating_mul(p as Weight))
@@ -127,12 +80,20 @@ impl crate::WeightInfo for () {
 			.saturating_add(DbWeight::get().writes(2 as Weight))
 			.saturating_add(DbWeight::get().writes((3 as Weight).saturating_mul(p as Weight)))
 	}
-	fn on_initialize_bounties(b: u32, ) -> Weight {
-		(112536000 as Weight)
-			.saturating_add((107132000 as Weight).saturating_mul(b as Weight))
+	// Estimates, not generated by the benchmark CLI.
+	fn spend() -> Weight {
+		(31542000 as Weight)
+			.saturating_add(DbWeight::get().reads(5 as Weight))
+			.saturating_add(DbWeight::get().writes(3 as Weight))
+	}
+	fn payout() -> Weight {
+		(82307000 as Weight)
+			.saturating_add(DbWeight::get().reads(4 as Weight))
+			.saturating_add(DbWeight::get().writes(4 as Weight))
+	}
+	fn void_spend() -> Weight {
+		(24871000 as Weight)
 			.saturating_add(DbWeight::get().reads(2 as Weight))
-			.saturating_add(DbWeight::get().reads((3 as Weight).saturating_mul(b as Weight)))
 			.saturating_add(DbWeight::get().writes(2 as Weight))
-			.saturating_add(DbWeight::get().writes((3 as Weight).saturating_mul(b as Weight)))
 	}
 }
@@ -44,22 +44,28 @@
 //! countdown period, the median of all declared tips is paid to the reported beneficiary, along
 //! with any finders fee, in case of a public (and bonded) original report.
 //!
-//! ### Bounty
+//! ### Spending hooks
 //!
-//! A Bounty Spending is a reward for a specified body of work - or specified set of objectives - that
-//! needs to be executed for a predefined Treasury amount to be paid out. A curator is assigned after
-//! the bounty is approved and funded by Council, to be delegated
-//! with the responsibility of assigning a payout address once the specified set of objectives is completed.
+//! Other modules may spend from the pot at the end of each spend period through the
+//! [`SpendFunds`](./trait.SpendFunds.html) hook, before any surplus is burnt. This is how
+//! bounties are funded by the bounties module.
 //!
-//! After the Council has activated a bounty, it delegates the work that requires expertise to a curator
-//! in exchange of a deposit. Once the curator accepts the bounty, they
-//! get to close the Active bounty. Closing the Active bounty enacts a delayed payout to the payout
-//! address, the curator fee and the return of the curator deposit. The
-//! delay allows for intervention through regular democracy. The Council gets to unassign the curator,
-//! resulting in a new curator election. The Council also gets to cancel
-//! the bounty if deemed necessary before assigning a curator or once the bounty is active or payout
-//! is pending, resulting in the slash of the curator's deposit.
+//! ### Asset spends
 //!
+//! Besides the pot of the native currency, the treasury account may hold balances of a set of
+//! fungible assets, such as those of the Assets module. The `ApproveOrigin` may approve a spend of
+//! any of these assets to a beneficiary directly, up to a maximum value in the native currency as
+//! given by the [`ConversionFromAssetBalance`](./trait.ConversionFromAssetBalance.html) of the
+//! runtime. The spend is paid out by the treasury account according to its
+//! [`PayoutSchedule`](./struct.PayoutSchedule.html): either at once, or in equal instalments which
+//! become claimable one period after another, much like a vesting schedule. Anyone may claim the
+//! instalments which are due on behalf of the beneficiary, and the remainder of a spend may be
+//! voided by the `RejectOrigin`.
+//!
+//! A spend may only be approved while the treasury account holds its amount on top of all
+//! spends of the asset which are approved and not yet paid out or voided. Asset spends bypass the
+//! proposal bonds, the budget of each spend period and the burn, so `Assets` should not give access
+//! to the native currency of the pot.
 //!
 //! ### Terminology
 //!
@@ -71,6 +77,13 @@
 //! respectively.
 //! - **Pot:** Unspent funds accumulated by the treasury module.
 //!
+//! Asset spend protocol:
+//! - **Asset spend:** An approved amount of an asset to be paid from the treasury account to a
+//!   beneficiary.
+//! - **Payout schedule:** The block from which a spend can be claimed, and the number of equal
+//!   instalments it is paid in along with the number of blocks between them.
+//! - **Instalment:** A share of an asset spend which becomes claimable at a given block.
+//!
 //! Tipping protocol:
 //! - **Tipping:** The process of gathering declarations of amounts to tip and taking the median
 //!   amount to be transferred from the treasury to a beneficiary account.
@@ -81,22 +94,6 @@
 //! - **Finders Fee:** Some proportion of the tip amount that is paid to the reporter of the tip,
 //!   rather than the main beneficiary.
 //!
-//! Bounty:
-//! - **Bounty spending proposal:** A proposal to reward a predefined body of work upon completion by
-//! the Treasury.
-//! - **Proposer:** An account proposing a bounty spending.
-//! - **Curator:** An account managing the bounty and assigning a payout address receiving the reward
-//! for the completion of work.
-//! - **Deposit:** The amount held on deposit for placing a bounty proposal plus the amount held on
-//! deposit per byte within the bounty description.
-//! - **Curator deposit:** The payment from a candidate willing to curate an approved bounty. The deposit
-//! is returned when/if the bounty is completed.
-//! - **Bounty value:** The total amount that should be paid to the Payout Address if the bounty is
-//! rewarded.
-//! - **Payout address:** The account to which the total or part of the bounty is assigned to.
-//! - **Payout Delay:** The delay period for which a bounty beneficiary needs to wait before claiming.
-//! - **Curator fee:** The reserved upfront payment for a curator for work related to the bounty.
-//!
 //! ## Interface
 //!
 //! ### Dispatchable Functions
@@ -108,6 +105,11 @@
 //! - `reject_proposal` - Reject a proposal, slashing the deposit.
 //! - `approve_proposal` - Accept the proposal, returning the deposit.
 //!
+//! Asset spend protocol:
+//! - `spend` - Approve a spend of an asset, paid out according to a payout schedule.
+//! - `payout` - Claim the instalments of an asset spend which are due.
+//! - `void_spend` - Void the unpaid remainder of an asset spend.
+//!
 //! Tipping protocol:
 //! - `report_awesome` - Report something worthy of a tip and register for a finders fee.
 //! - `retract_tip` - Retract a previous (finders fee registered) report.
@@ -115,19 +117,6 @@
 //! - `tip` - Declare or redeclare an amount to tip for a particular reason.
 //! - `close_tip` - Close and pay out a tip.
 //!
-//! Bounty protocol:
-//! - `propose_bounty` - Propose a specific treasury amount to be earmarked for a predefined set of
-//! tasks and stake the required deposit.
-//! - `approve_bounty` - Accept a specific treasury amount to be earmarked for a predefined body of work.
-//! - `propose_curator` - Assign an account to a bounty as candidate curator.
-//! - `accept_curator` - Accept a bounty assignment from the Council, setting a curator deposit.
-//! - `extend_bounty_expiry` - Extend the expiry block number of the bounty and stay active.
-//! - `award_bounty` - Close and pay out the specified amount for the completed work.
-//! - `claim_bounty` - Claim a specific bounty amount from the Payout Address.
-//! - `unassign_curator` - Unassign an accepted curator from a specific earmark.
-//! - `close_bounty` - Cancel the earmark for a specific treasury amount and close the bounty.
-//!
-//!
 //! ## GenesisConfig
 //!
 //! The Treasury module depends on the [`GenesisConfig`](./struct.GenesisConfig.html).
@@ -139,15 +128,15 @@ use serde::{Serialize, Deserialize};
 use sp_std::prelude::*;
 use frame_support::{decl_module, decl_storage, decl_event, ensure, print, decl_error, Parameter};
 use frame_support::traits::{
-	Currency, Get, Imbalance, OnUnbalanced, ExistenceRequirement::{KeepAlive, AllowDeath},
-	ReservableCurrency, WithdrawReason
+	Currency, Get, Imbalance, OnUnbalanced, ExistenceRequirement::KeepAlive,
+	ReservableCurrency, WithdrawReason, fungibles,
 };
-use sp_runtime::{Permill, ModuleId, Percent, RuntimeDebug, DispatchResult, traits::{
-	Zero, StaticLookup, AccountIdConversion, Saturating, Hash, BadOrigin
+use sp_runtime::{Permill, ModuleId, Percent, RuntimeDebug, SaturatedConversion, traits::{
+	Zero, StaticLookup, AccountIdConversion, Saturating, Hash, BadOrigin, CheckedAdd
 }};
-use frame_support::dispatch::DispatchResultWithPostInfo;
 use frame_support::weights::{Weight, DispatchClass};
 use frame_support::traits::{Contains, ContainsLengthBound, EnsureOrigin};
+use frame_support::type_info::TypeInfo;
 use codec::{Encode, Decode};
 use frame_system::{self as system, ensure_signed};
 
@@ -155,12 +144,64 @@ mod tests;
 mod benchmarking;
 mod default_weights;
 
-type BalanceOf<T, I> =
+pub type BalanceOf<T, I = DefaultInstance> =
 	<<T as Trait<I>>::Currency as Currency<<T as frame_system::Trait>::AccountId>>::Balance;
-type PositiveImbalanceOf<T, I> =
+pub type PositiveImbalanceOf<T, I = DefaultInstance> =
 	<<T as Trait<I>>::Currency as Currency<<T as frame_system::Trait>::AccountId>>::PositiveImbalance;
-type NegativeImbalanceOf<T, I> =
+pub type NegativeImbalanceOf<T, I = DefaultInstance> =
 	<<T as Trait<I>>::Currency as Currency<<T as frame_system::Trait>::AccountId>>::NegativeImbalance;
+pub type AssetIdOf<T, I = DefaultInstance> =
+	<<T as Trait<I>>::Assets as fungibles::Inspect<<T as frame_system::Trait>::AccountId>>::AssetId;
+pub type AssetBalanceOf<T, I = DefaultInstance> =
+	<<T as Trait<I>>::Assets as fungibles::Inspect<<T as frame_system::Trait>::AccountId>>::Balance;
+
+/// A trait to allow the Treasury module to spend its funds for other purposes.
+/// There is an expectation that the implementer of this trait will correctly manage
+/// the mutable variables passed to it:
+/// * `budget_remaining`: How much available funds that can be spent by the treasury.
+///   As funds are spent, you must correctly deduct from this value.
+/// * `imbalance`: Any imbalances that you create should be subsumed in here to
+///   maximize efficiency of updating the total issuance. (i.e. `deposit_creating`)
+/// * `total_weight`: Track any weight that your `spend_funds` implementation uses by
+///   updating this value.
+/// * `missed_any`: If there were items that you want to spend on, but there were
+///   not enough funds, mark this value as `true`. This will prevent the treasury
+///   from burning the excess funds.
+#[impl_trait_for_tuples::impl_for_tuples(30)]
+pub trait SpendFunds<T: Trait<I>, I=DefaultInstance> {
+	fn spend_funds(
+		budget_remaining: &mut BalanceOf<T, I>,
+		imbalance: &mut PositiveImbalanceOf<T, I>,
+		total_weight: &mut Weight,
+		missed_any: &mut bool,
+	);
+}
+
+/// Converts an amount of an asset into an amount of the native currency.
+///
+/// This is the price provider used to value asset spends against the native currency.
+pub trait ConversionFromAssetBalance<AssetBalance, AssetId, OutBalance> {
+	/// The error returned when there is no conversion, for example because the asset has no price.
+	type Error;
+
+	/// Convert `balance` of the asset `asset_id` into an amount of the native currency.
+	fn from_asset_balance(balance: AssetBalance, asset_id: AssetId) -> Result<OutBalance, Self::Error>;
+}
+
+/// A conversion which values every asset one-to-one with the native currency, for runtimes whose
+/// assets are all denominated in it.
+pub struct UnityAssetBalanceConversion;
+impl<AssetBalance, AssetId, OutBalance> ConversionFromAssetBalance<AssetBalance, AssetId, OutBalance>
+	for UnityAssetBalanceConversion
+where
+	AssetBalance: Into<OutBalance>,
+{
+	type Error = ();
+
+	fn from_asset_balance(balance: AssetBalance, _: AssetId) -> Result<OutBalance, ()> {
+		Ok(balance.into())
+	}
+}
 
 pub trait WeightInfo {
 	fn propose_spend() -> Weight;
@@ -171,18 +212,10 @@ pub trait WeightInfo {
 	fn tip_new(r: u32, t: u32, ) -> Weight;
 	fn tip(t: u32, ) -> Weight;
 	fn close_tip(t: u32, ) -> Weight;
-	fn propose_bounty(r: u32, ) -> Weight;
-	fn approve_bounty() -> Weight;
-	fn propose_curator() -> Weight;
-	fn unassign_curator() -> Weight;
-	fn accept_curator() -> Weight;
-	fn award_bounty() -> Weight;
-	fn claim_bounty() -> Weight;
-	fn close_bounty_proposed() -> Weight;
-	fn close_bounty_active() -> Weight;
-	fn extend_bounty_expiry() -> Weight;
 	fn on_initialize_proposals(p: u32, ) -> Weight;
-	fn on_initialize_bounties(b: u32, ) -> Weight;
+	fn spend() -> Weight;
+	fn payout() -> Weight;
+	fn void_spend() -> Weight;
 }
 
 pub trait Trait<I=DefaultInstance>: frame_system::Trait {
@@ -218,7 +251,8 @@ pub trait Trait<I=DefaultInstance>: frame_system::Trait {
 	/// The overarching event type.
 	type Event: From<Event<Self, I>> + Into<<Self as frame_system::Trait>::Event>;
 
-	/// Handler for the unbalanced decrease when slashing for a rejected proposal or bounty.
+	/// Handler for the unbalanced decrease when slashing for a rejected proposal, or for a
+	/// rejected proposal of a module building on the treasury.
 	type OnSlash: OnUnbalanced<NegativeImbalanceOf<Self, I>>;
 
 	/// Fraction of a proposal's value that should be bonded in order to place the proposal.
@@ -234,26 +268,33 @@ pub trait Trait<I=DefaultInstance>: frame_system::Trait {
 	/// Percentage of spare funds (if any) that are burnt per spend period.
 	type Burn: Get<Permill>;
 
-	/// The amount held on deposit for placing a bounty proposal.
-	type BountyDepositBase: Get<BalanceOf<Self, I>>;
+	/// Maximum acceptable reason length.
+	type MaximumReasonLength: Get<u32>;
 
-	/// The delay period for which a bounty beneficiary need to wait before claim the payout.
-	type BountyDepositPayoutDelay: Get<Self::BlockNumber>;
+	/// Handler for the unbalanced decrease when treasury funds are burned.
+	type BurnDestination: OnUnbalanced<NegativeImbalanceOf<Self, I>>;
 
-	/// Bounty duration in blocks.
-	type BountyUpdatePeriod: Get<Self::BlockNumber>;
+	/// Runtime hooks to external modules which may spend from the pot each spend period,
+	/// e.g. to fund approved bounties.
+	type SpendFunds: SpendFunds<Self, I>;
 
-	/// Percentage of the curator fee that will be reserved upfront as deposit for bounty curator.
-	type BountyCuratorDeposit: Get<Permill>;
+	/// The set of fungible assets, such as those of the Assets module, which may be spent from
+	/// the treasury account. This should not include the native currency, as asset spends are not
+	/// accounted against the pot.
+	type Assets: fungibles::Transfer<Self::AccountId>;
 
-	/// Minimum value for a bounty.
-	type BountyValueMinimum: Get<BalanceOf<Self, I>>;
+	/// The conversion of amounts of an asset into the native currency, used to value asset spends.
+	type BalanceConverter: ConversionFromAssetBalance<
+		AssetBalanceOf<Self, I>,
+		AssetIdOf<Self, I>,
+		BalanceOf<Self, I>,
+	>;
 
-	/// Maximum acceptable reason length.
-	type MaximumReasonLength: Get<u32>;
+	/// The maximum value, in the native currency, of a single asset spend.
+	type AssetSpendMaximum: Get<BalanceOf<Self, I>>;
 
-	/// Handler for the unbalanced decrease when treasury funds are burned.
-	type BurnDestination: OnUnbalanced<NegativeImbalanceOf<Self, I>>;
+	/// The maximum number of instalments an asset spend may be paid out in.
+	type MaxInstalments: Get<u32>;
 
 	/// Weight information for extrinsics in this pallet.
 	type WeightInfo: WeightInfo;
@@ -264,7 +305,7 @@ pub type ProposalIndex = u32;
 
 /// A spending proposal.
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
-#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug)]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
 pub struct Proposal<AccountId, Balance> {
 	/// The account proposing it.
 	proposer: AccountId,
@@ -278,7 +319,7 @@ pub struct Proposal<AccountId, Balance> {
 
 /// An open tipping "motion". Retains all details of a tip including information on the finder
 /// and the members who have voted.
-#[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug)]
+#[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo)]
 pub struct OpenTip<
 	AccountId: Parameter,
 	Balance: Parameter,
@@ -303,56 +344,51 @@ pub struct OpenTip<
 	finders_fee: bool,
 }
 
-/// An index of a bounty. Just a `u32`.
-pub type BountyIndex = u32;
+/// An index of an asset spend. Just a `u32`.
+pub type SpendIndex = u32;
+
+/// The schedule by which an asset spend is paid out.
+///
+/// The amount of the spend is split into `instalments` equal shares, any remainder being paid
+/// with the last one. The first instalment can be claimed from `valid_from`, and each following
+/// one `period` blocks after the previous one.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct PayoutSchedule<BlockNumber> {
+	/// The block from which the first instalment can be claimed.
+	pub valid_from: BlockNumber,
+	/// The number of instalments the spend is paid out in. At least one.
+	pub instalments: u32,
+	/// The number of blocks between two successive instalments.
+	pub period: BlockNumber,
+}
 
-/// A bounty proposal.
-#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug)]
-pub struct Bounty<AccountId, Balance, BlockNumber> {
-	/// The account proposing it.
-	proposer: AccountId,
-	/// The (total) amount that should be paid if the bounty is rewarded.
-	value: Balance,
-	/// The curator fee. Included in value.
-	fee: Balance,
-	/// The deposit of curator.
-	curator_deposit: Balance,
-	/// The amount held on deposit (reserved) for making this proposal.
-	bond: Balance,
-	/// The status of this bounty.
-	status: BountyStatus<AccountId, BlockNumber>,
+/// An approved spend of an asset from the treasury account.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct AssetSpend<AssetId, AssetBalance, AccountId, BlockNumber> {
+	/// The asset to be paid out.
+	asset_id: AssetId,
+	/// The (total) amount of the asset to be paid out.
+	amount: AssetBalance,
+	/// The account to whom the payments should be made.
+	beneficiary: AccountId,
+	/// The schedule by which the amount is paid out.
+	schedule: PayoutSchedule<BlockNumber>,
+	/// The amount that has been paid out so far.
+	paid: AssetBalance,
 }
 
-/// The status of a bounty proposal.
-#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug)]
-pub enum BountyStatus<AccountId, BlockNumber> {
-	/// The bounty is proposed and waiting for approval.
-	Proposed,
-	/// The bounty is approved and waiting to become active at next spend period.
-	Approved,
-	/// The bounty is funded and waiting for curator assignment.
-	Funded,
-	/// A curator has been proposed by the `ApproveOrigin`. Waiting for acceptance from the curator.
-	CuratorProposed {
-		/// The assigned curator of this bounty.
-		curator: AccountId,
-	},
-	/// The bounty is active and waiting to be awarded.
-	Active {
-		/// The curator of this bounty.
-		curator: AccountId,
-		/// An update from the curator is due by this block, else they are considered inactive.
-		update_due: BlockNumber,
-	},
-	/// The bounty is awarded and waiting to released after a delay.
-	PendingPayout {
-		/// The curator of this bounty.
-		curator: AccountId,
-		/// The beneficiary of the bounty.
-		beneficiary: AccountId,
-		/// When the bounty can be claimed.
-		unlock_at: BlockNumber,
-	},
+/// The payout status of an asset spend, as given by
+/// [`Module::spend_status`](./struct.Module.html#method.spend_status).
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct SpendStatus<AssetBalance, BlockNumber> {
+	/// The amount that has been paid out so far.
+	pub paid: AssetBalance,
+	/// The amount of the instalments which are due but not yet paid out.
+	pub claimable: AssetBalance,
+	/// The amount that is yet to be paid out, the claimable amount included.
+	pub remaining: AssetBalance,
+	/// The block from which the next instalment that is not yet due can be claimed, if any.
+	pub next_instalment: Option<BlockNumber>,
 }
 
 decl_storage! {
@@ -379,19 +415,17 @@ decl_storage! {
 		/// insecure enumerable hash since the key is guaranteed to be the result of a secure hash.
 		pub Reasons get(fn reasons): map hasher(identity) T::Hash => Option<Vec<u8>>;
 
-		/// Number of bounty proposals that have been made.
-		pub BountyCount get(fn bounty_count): BountyIndex;
+		/// Number of asset spends that have been approved.
+		SpendCount get(fn spend_count): SpendIndex;
 
-		/// Bounties that have been made.
-		pub Bounties get(fn bounties):
-			map hasher(twox_64_concat) BountyIndex
-			=> Option<Bounty<T::AccountId, BalanceOf<T, I>, T::BlockNumber>>;
+		/// Asset spends that have been approved and are not yet fully paid out.
+		pub Spends get(fn spends):
+			map hasher(twox_64_concat) SpendIndex
+			=> Option<AssetSpend<AssetIdOf<T, I>, AssetBalanceOf<T, I>, T::AccountId, T::BlockNumber>>;
 
-		/// The description of each bounty.
-		pub BountyDescriptions get(fn bounty_descriptions): map hasher(twox_64_concat) BountyIndex => Option<Vec<u8>>;
-
-		/// Bounty indices that have been approved but not yet funded.
-		pub BountyApprovals get(fn bounty_approvals): Vec<BountyIndex>;
+		/// The amount of each asset that is approved to be spent and not yet paid out or voided.
+		pub Committed get(fn committed):
+			map hasher(twox_64_concat) AssetIdOf<T, I> => AssetBalanceOf<T, I>;
 	}
 	add_extra_genesis {
 		build(|_config| {
@@ -414,6 +448,8 @@ decl_event!(
 		Balance = BalanceOf<T, I>,
 		<T as frame_system::Trait>::AccountId,
 		<T as frame_system::Trait>::Hash,
+		AssetId = AssetIdOf<T, I>,
+		AssetBalance = AssetBalanceOf<T, I>,
 	{
 		/// New proposal. \[proposal_index\]
 		Proposed(ProposalIndex),
@@ -438,20 +474,13 @@ decl_event!(
 		TipClosed(Hash, AccountId, Balance),
 		/// A tip suggestion has been retracted. \[tip_hash\]
 		TipRetracted(Hash),
-		/// New bounty proposal. [index]
-		BountyProposed(BountyIndex),
-		/// A bounty proposal was rejected; funds were slashed. [index, bond]
-		BountyRejected(BountyIndex, Balance),
-		/// A bounty proposal is funded and became active. [index]
-		BountyBecameActive(BountyIndex),
-		/// A bounty is awarded to a beneficiary. [index, beneficiary]
-		BountyAwarded(BountyIndex, AccountId),
-		/// A bounty is claimed by beneficiary. [index, payout, beneficiary]
-		BountyClaimed(BountyIndex, Balance, AccountId),
-		/// A bounty is cancelled. [index]
-		BountyCanceled(BountyIndex),
-		/// A bounty expiry is extended. [index]
-		BountyExtended(BountyIndex),
+		/// A spend of an asset was approved, worth the given value in the native currency.
+		/// \[spend_index, asset_id, amount, beneficiary, value\]
+		AssetSpendApproved(SpendIndex, AssetId, AssetBalance, AccountId, Balance),
+		/// Some instalments of an asset spend have been paid out. \[spend_index, payout\]
+		AssetPaid(SpendIndex, AssetBalance),
+		/// An asset spend was voided before being fully paid out. \[spend_index, unpaid\]
+		AssetSpendVoided(SpendIndex, AssetBalance),
 	}
 );
 
@@ -460,7 +489,7 @@ decl_error! {
 	pub enum Error for Module<T: Trait<I>, I: Instance> {
 		/// Proposer's balance is too low.
 		InsufficientProposersBalance,
-		/// No proposal or bounty at that index.
+		/// No proposal or spend at that index.
 		InvalidIndex,
 		/// The reason given is just too big.
 		ReasonTooBig,
@@ -474,17 +503,18 @@ decl_error! {
 		StillOpen,
 		/// The tip cannot be claimed/closed because it's still in the countdown period.
 		Premature,
-		/// The bounty status is unexpected.
-		UnexpectedStatus,
-		/// Require bounty curator.
-		RequireCurator,
-		/// Invalid bounty value.
-		InvalidValue,
-		/// Invalid bounty fee.
-		InvalidFee,
-		/// A bounty payout is pending.
-		/// To cancel the bounty, you must unassign and slash the curator.
-		PendingPayout,
+		/// The payout schedule has no instalments, too many of them, or several instalments
+		/// without a period between them.
+		InvalidSchedule,
+		/// The asset of the spend could not be valued in the native currency.
+		FailedToConvertBalance,
+		/// The value of the spend in the native currency exceeds the maximum.
+		SpendTooLarge,
+		/// No instalment of the spend is due for payout.
+		NothingToClaim,
+		/// The treasury account does not hold enough of the asset to pay out the spend on top of
+		/// those already approved.
+		InsufficientFunds,
 	}
 }
 
@@ -521,20 +551,15 @@ decl_module! {
 		/// The treasury's module id, used for deriving its sovereign account ID.
 		const ModuleId: ModuleId = T::ModuleId::get();
 
-		/// The amount held on deposit for placing a bounty proposal.
-		const BountyDepositBase: BalanceOf<T, I> = T::BountyDepositBase::get();
-
-		/// The delay period for which a bounty beneficiary need to wait before claim the payout.
-		const BountyDepositPayoutDelay: T::BlockNumber = T::BountyDepositPayoutDelay::get();
-
-		/// Percentage of the curator fee that will be reserved upfront as deposit for bounty curator.
-		const BountyCuratorDeposit: Permill = T::BountyCuratorDeposit::get();
-
-		const BountyValueMinimum: BalanceOf<T, I> = T::BountyValueMinimum::get();
-
 		/// Maximum acceptable reason length.
 		const MaximumReasonLength: u32 = T::MaximumReasonLength::get();
 
+		/// The maximum value, in the native currency, of a single asset spend.
+		const AssetSpendMaximum: BalanceOf<T, I> = T::AssetSpendMaximum::get();
+
+		/// The maximum number of instalments an asset spend may be paid out in.
+		const MaxInstalments: u32 = T::MaxInstalments::get();
+
 		type Error = Error<T, I>;
 
 		fn deposit_event() = default;
@@ -800,369 +825,123 @@ decl_module! {
 			Self::payout_tip(hash, tip);
 		}
 
-		/// Propose a new bounty.
-		///
-		/// The dispatch origin for this call must be _Signed_.
-		///
-		/// Payment: `TipReportDepositBase` will be reserved from the origin account, as well as
-		/// `DataDepositPerByte` for each byte in `reason`. It will be unreserved upon approval,
-		/// or slashed when rejected.
+		/// Approve a spend of `amount` of the asset `asset_id` from the treasury account to
+		/// `beneficiary`, paid out according to `schedule`.
 		///
-		/// - `curator`: The curator account whom will manage this bounty.
-		/// - `fee`: The curator fee.
-		/// - `value`: The total payment amount of this bounty, curator fee included.
-		/// - `description`: The description of this bounty.
-		#[weight = T::WeightInfo::propose_bounty(description.len() as u32)]
-		fn propose_bounty(
-			origin,
-			#[compact] value: BalanceOf<T, I>,
-			description: Vec<u8>,
-		) {
-			let proposer = ensure_signed(origin)?;
-			Self::create_bounty(proposer, description, value)?;
-		}
-
-		/// Approve a bounty proposal. At a later time, the bounty will be funded and become active
-		/// and the original deposit will be returned.
-		///
-		/// May only be called from `T::ApproveOrigin`.
-		///
-		/// # <weight>
-		/// - O(1).
-		/// - Limited storage reads.
-		/// - One DB change.
-		/// # </weight>
-		#[weight = T::WeightInfo::approve_bounty()]
-		fn approve_bounty(origin, #[compact] bounty_id: ProposalIndex) {
-			T::ApproveOrigin::ensure_origin(origin)?;
-
-			Bounties::<T, I>::try_mutate_exists(bounty_id, |maybe_bounty| -> DispatchResult {
-				let mut bounty = maybe_bounty.as_mut().ok_or(Error::<T, I>::InvalidIndex)?;
-				ensure!(bounty.status == BountyStatus::Proposed, Error::<T, I>::UnexpectedStatus);
-
-				bounty.status = BountyStatus::Approved;
-
-				BountyApprovals::<I>::append(bounty_id);
-
-				Ok(())
-			})?;
-		}
-
-		/// Assign a curator to a funded bounty.
+		/// May only be called from `T::ApproveOrigin`. The value of the spend in the native
+		/// currency, as given by `T::BalanceConverter`, may not exceed `T::AssetSpendMaximum`, and
+		/// the treasury account must hold the amount on top of all spends of the asset which are
+		/// approved and not yet paid out.
 		///
-		/// May only be called from `T::ApproveOrigin`.
+		/// - `asset_id`: The asset to be paid out.
+		/// - `amount`: The total amount of the asset to be paid out.
+		/// - `beneficiary`: The account to whom the payments should be made.
+		/// - `schedule`: The block from which the spend can be claimed, and the number of
+		///   instalments it is paid out in, along with the period between them.
 		///
 		/// # <weight>
-		/// - O(1).
-		/// - Limited storage reads.
-		/// - One DB change.
+		/// - Complexity: O(1)
+		/// - DbReads: `SpendCount`, `Committed`, `treasury asset account`
+		/// - DbWrites: `SpendCount`, `Spends`, `Committed`
 		/// # </weight>
-		#[weight = T::WeightInfo::propose_curator()]
-		fn propose_curator(
+		#[weight = (T::WeightInfo::spend(), DispatchClass::Operational)]
+		fn spend(
 			origin,
-			#[compact] bounty_id: ProposalIndex,
-			curator: <T::Lookup as StaticLookup>::Source,
-			#[compact] fee: BalanceOf<T, I>,
+			asset_id: AssetIdOf<T, I>,
+			#[compact] amount: AssetBalanceOf<T, I>,
+			beneficiary: <T::Lookup as StaticLookup>::Source,
+			schedule: PayoutSchedule<T::BlockNumber>,
 		) {
 			T::ApproveOrigin::ensure_origin(origin)?;
+			let beneficiary = T::Lookup::lookup(beneficiary)?;
 
-			let curator = T::Lookup::lookup(curator)?;
-			Bounties::<T, I>::try_mutate_exists(bounty_id, |maybe_bounty| -> DispatchResult {
-				let mut bounty = maybe_bounty.as_mut().ok_or(Error::<T, I>::InvalidIndex)?;
-				match bounty.status {
-					BountyStatus::Funded | BountyStatus::CuratorProposed { .. } => {},
-					_ => return Err(Error::<T, I>::UnexpectedStatus.into()),
-				};
-
-				ensure!(fee < bounty.value, Error::<T, I>::InvalidFee);
-
-				bounty.status = BountyStatus::CuratorProposed { curator };
-				bounty.fee = fee;
+			ensure!(
+				schedule.instalments > 0 &&
+					schedule.instalments <= T::MaxInstalments::get() &&
+					(schedule.instalments == 1 || !schedule.period.is_zero()),
+				Error::<T, I>::InvalidSchedule,
+			);
+			let value = T::BalanceConverter::from_asset_balance(amount, asset_id)
+				.map_err(|_| Error::<T, I>::FailedToConvertBalance)?;
+			ensure!(value <= T::AssetSpendMaximum::get(), Error::<T, I>::SpendTooLarge);
+
+			let committed = Self::committed(asset_id).checked_add(&amount)
+				.ok_or(Error::<T, I>::InsufficientFunds)?;
+			let available = T::Assets::reducible_balance(asset_id, &Self::account_id(), true);
+			ensure!(committed <= available, Error::<T, I>::InsufficientFunds);
+
+			<Committed<T, I>>::insert(asset_id, committed);
+			let index = Self::spend_count();
+			<SpendCount<I>>::put(index + 1);
+			<Spends<T, I>>::insert(index, AssetSpend {
+				asset_id,
+				amount,
+				beneficiary: beneficiary.clone(),
+				schedule,
+				paid: Zero::zero(),
+			});
 
-				Ok(())
-			})?;
+			Self::deposit_event(RawEvent::AssetSpendApproved(index, asset_id, amount, beneficiary, value));
 		}
 
-		/// Unassign curator from a bounty.
+		/// Pay out the instalments of an asset spend which are due and not yet paid out. The spend
+		/// is removed once it is fully paid out.
 		///
-		/// This function can only be called by the `RejectOrigin` a signed origin.
+		/// The dispatch origin for this call must be _Signed_; anyone may claim a payout on behalf
+		/// of the beneficiary.
 		///
-		/// If this function is called by the `RejectOrigin`, we assume that the curator is malicious
-		/// or inactive. As a result, we will slash the curator when possible.
-		///
-		/// If the origin is the curator, we take this as a sign they are unable to do their job and
-		/// they willingly give up. We could slash them, but for now we allow them to recover their
-		/// deposit and exit without issue. (We may want to change this if it is abused.)
-		///
-		/// Finally, the origin can be anyone if and only if the curator is "inactive". This allows
-		/// anyone in the community to call out that a curator is not doing their due diligence, and
-		/// we should pick a new curator. In this case the curator should also be slashed.
-		///
-		/// # <weight>
-		/// - O(1).
-		/// - Limited storage reads.
-		/// - One DB change.
-		/// # </weight>
-		#[weight = T::WeightInfo::unassign_curator()]
-		fn unassign_curator(
-			origin,
-			#[compact] bounty_id: ProposalIndex,
-		) {
-			let maybe_sender = ensure_signed(origin.clone())
-				.map(Some)
-				.or_else(|_| T::RejectOrigin::ensure_origin(origin).map(|_| None))?;
-
-			Bounties::<T, I>::try_mutate_exists(bounty_id, |maybe_bounty| -> DispatchResult {
-				let mut bounty = maybe_bounty.as_mut().ok_or(Error::<T, I>::InvalidIndex)?;
-
-				let slash_curator = |curator: &T::AccountId, curator_deposit: &mut BalanceOf<T, I>| {
-					let imbalance = T::Currency::slash_reserved(curator, *curator_deposit).0;
-					T::OnSlash::on_unbalanced(imbalance);
-					*curator_deposit = Zero::zero();
-				};
-
-				match bounty.status {
-					BountyStatus::Proposed | BountyStatus::Approved | BountyStatus::Funded => {
-						// No curator to unassign at this point.
-						return Err(Error::<T, I>::UnexpectedStatus.into())
-					}
-					BountyStatus::CuratorProposed { ref curator } => {
-						// A curator has been proposed, but not accepted yet.
-						// Either `RejectOrigin` or the proposed curator can unassign the curator.
-						ensure!(maybe_sender.map_or(true, |sender| sender == *curator), BadOrigin);
-					},
-					BountyStatus::Active { ref curator, ref update_due } => {
-						// The bounty is active.
-						match maybe_sender {
-							// If the `RejectOrigin` is calling this function, slash the curator.
-							None => {
-								slash_curator(curator, &mut bounty.curator_deposit);
-								// Continue to change bounty status below...
-							},
-							Some(sender) => {
-								// If the sender is not the curator, and the curator is inactive,
-								// slash the curator.
-								if sender != *curator {
-									let block_number = system::Module::<T>::block_number();
-									if *update_due < block_number {
-										slash_curator(curator, &mut bounty.curator_deposit);
-										// Continue to change bounty status below...
-									} else {
-										// Curator has more time to give an update.
-										return Err(Error::<T, I>::Premature.into())
-									}
-								} else {
-									// Else this is the curator, willingly giving up their role.
-									// Give back their deposit.
-									let _ = T::Currency::unreserve(&curator, bounty.curator_deposit);
-									// Continue to change bounty status below...
-								}
-							},
-						}
-					},
-					BountyStatus::PendingPayout { ref curator, .. } => {
-						// The bounty is pending payout, so only council can unassign a curator.
-						// By doing so, they are claiming the curator is acting maliciously, so
-						// we slash the curator.
-						ensure!(maybe_sender.is_none(), BadOrigin);
-						slash_curator(curator, &mut bounty.curator_deposit);
-						// Continue to change bounty status below...
-					}
-				};
-
-				bounty.status = BountyStatus::Funded;
-				Ok(())
-			})?;
-		}
-
-		/// Accept the curator role for a bounty.
-		/// A deposit will be reserved from curator and refund upon successful payout.
+		/// The treasury account is kept alive, so it must hold the minimum balance of the asset on
+		/// top of the payout.
 		///
-		/// May only be called from the curator.
+		/// - `index`: The index of the spend.
 		///
 		/// # <weight>
-		/// - O(1).
-		/// - Limited storage reads.
-		/// - One DB change.
+		/// - Complexity: O(1)
+		/// - DbReads: `Spends`, `Committed`, `treasury asset account`, `beneficiary asset account`
+		/// - DbWrites: `Spends`, `Committed`, `treasury asset account`, `beneficiary asset account`
 		/// # </weight>
-		#[weight = T::WeightInfo::accept_curator()]
-		fn accept_curator(origin, #[compact] bounty_id: ProposalIndex) {
-			let signer = ensure_signed(origin)?;
-
-			Bounties::<T, I>::try_mutate_exists(bounty_id, |maybe_bounty| -> DispatchResult {
-				let mut bounty = maybe_bounty.as_mut().ok_or(Error::<T, I>::InvalidIndex)?;
-
-				match bounty.status {
-					BountyStatus::CuratorProposed { ref curator } => {
-						ensure!(signer == *curator, Error::<T, I>::RequireCurator);
-
-						let deposit = T::BountyCuratorDeposit::get() * bounty.fee;
-						T::Currency::reserve(curator, deposit)?;
-						bounty.curator_deposit = deposit;
-
-						let update_due = system::Module::<T>::block_number() + T::BountyUpdatePeriod::get();
-						bounty.status = BountyStatus::Active { curator: curator.clone(), update_due };
-
-						Ok(())
-					},
-					_ => Err(Error::<T, I>::UnexpectedStatus.into()),
-				}
-			})?;
-		}
+		#[weight = T::WeightInfo::payout()]
+		fn payout(origin, #[compact] index: SpendIndex) {
+			ensure_signed(origin)?;
 
-		/// Award bounty to a beneficiary account. The beneficiary will be able to claim the funds after a delay.
-		///
-		/// The dispatch origin for this call must be the curator of this bounty.
-		///
-		/// - `bounty_id`: Bounty ID to award.
-		/// - `beneficiary`: The beneficiary account whom will receive the payout.
-		#[weight = T::WeightInfo::award_bounty()]
-		fn award_bounty(origin, #[compact] bounty_id: ProposalIndex, beneficiary: <T::Lookup as StaticLookup>::Source) {
-			let signer = ensure_signed(origin)?;
-			let beneficiary = T::Lookup::lookup(beneficiary)?;
+			let mut spend = Self::spends(index).ok_or(Error::<T, I>::InvalidIndex)?;
+			let (vested, _) = Self::vested(&spend, system::Module::<T>::block_number());
+			let claimable = vested.saturating_sub(spend.paid);
+			ensure!(!claimable.is_zero(), Error::<T, I>::NothingToClaim);
 
-			Bounties::<T, I>::try_mutate_exists(bounty_id, |maybe_bounty| -> DispatchResult {
-				let mut bounty = maybe_bounty.as_mut().ok_or(Error::<T, I>::InvalidIndex)?;
-				match &bounty.status {
-					BountyStatus::Active {
-						curator,
-						..
-					} => {
-						ensure!(signer == *curator, Error::<T, I>::RequireCurator);
-					},
-					_ => return Err(Error::<T, I>::UnexpectedStatus.into()),
-				}
-				bounty.status = BountyStatus::PendingPayout {
-					curator: signer,
-					beneficiary: beneficiary.clone(),
-					unlock_at: system::Module::<T>::block_number() + T::BountyDepositPayoutDelay::get(),
-				};
+			T::Assets::transfer(spend.asset_id, &Self::account_id(), &spend.beneficiary, claimable, true)?;
 
-				Ok(())
-			})?;
+			<Committed<T, I>>::mutate(spend.asset_id, |c| *c = c.saturating_sub(claimable));
+			spend.paid = spend.paid.saturating_add(claimable);
+			if spend.paid >= spend.amount {
+				<Spends<T, I>>::remove(index);
+			} else {
+				<Spends<T, I>>::insert(index, spend);
+			}
 
-			Self::deposit_event(Event::<T, I>::BountyAwarded(bounty_id, beneficiary));
+			Self::deposit_event(RawEvent::AssetPaid(index, claimable));
 		}
 
-		/// Claim the payout from an awarded bounty after payout delay.
-		///
-		/// The dispatch origin for this call must be the beneficiary of this bounty.
+		/// Void an asset spend, so that the part of it which is not yet paid out never is.
 		///
-		/// - `bounty_id`: Bounty ID to claim.
-		#[weight = T::WeightInfo::claim_bounty()]
-		fn claim_bounty(origin, #[compact] bounty_id: BountyIndex) {
-			let _ = ensure_signed(origin)?; // anyone can trigger claim
-
-			Bounties::<T, I>::try_mutate_exists(bounty_id, |maybe_bounty| -> DispatchResult {
-				let bounty = maybe_bounty.take().ok_or(Error::<T, I>::InvalidIndex)?;
-				if let BountyStatus::PendingPayout { curator, beneficiary, unlock_at } = bounty.status {
-					ensure!(system::Module::<T>::block_number() >= unlock_at, Error::<T, I>::Premature);
-					let bounty_account = Self::bounty_account_id(bounty_id);
-					let balance = T::Currency::free_balance(&bounty_account);
-					let fee = bounty.fee.min(balance); // just to be safe
-					let payout = balance.saturating_sub(fee);
-					let _ = T::Currency::unreserve(&curator, bounty.curator_deposit);
-					let _ = T::Currency::transfer(&bounty_account, &curator, fee, AllowDeath); // should not fail
-					let _ = T::Currency::transfer(&bounty_account, &beneficiary, payout, AllowDeath); // should not fail
-					*maybe_bounty = None;
-
-					BountyDescriptions::<I>::remove(bounty_id);
-
-					Self::deposit_event(Event::<T, I>::BountyClaimed(bounty_id, payout, beneficiary));
-					Ok(())
-				} else {
-					Err(Error::<T, I>::UnexpectedStatus.into())
-				}
-			})?;
-		}
-
-		/// Cancel a proposed or active bounty. All the funds will be sent to treasury and
-		/// the curator deposit will be unreserved if possible.
+		/// May only be called from `T::RejectOrigin`.
 		///
-		/// Only `T::RejectOrigin` is able to cancel a bounty.
+		/// - `index`: The index of the spend.
 		///
-		/// - `bounty_id`: Bounty ID to cancel.
-		#[weight = T::WeightInfo::close_bounty_proposed().max(T::WeightInfo::close_bounty_active())]
-		fn close_bounty(origin, #[compact] bounty_id: BountyIndex) -> DispatchResultWithPostInfo {
+		/// # <weight>
+		/// - Complexity: O(1)
+		/// - DbReads: `Spends`, `Committed`
+		/// - DbWrites: `Spends`, `Committed`
+		/// # </weight>
+		#[weight = (T::WeightInfo::void_spend(), DispatchClass::Operational)]
+		fn void_spend(origin, #[compact] index: SpendIndex) {
 			T::RejectOrigin::ensure_origin(origin)?;
 
-			Bounties::<T, I>::try_mutate_exists(bounty_id, |maybe_bounty| -> DispatchResultWithPostInfo {
-				let bounty = maybe_bounty.as_ref().ok_or(Error::<T, I>::InvalidIndex)?;
-
-				match &bounty.status {
-					BountyStatus::Proposed => {
-						// The reject origin would like to cancel a proposed bounty.
-						BountyDescriptions::<I>::remove(bounty_id);
-						let value = bounty.bond;
-						let imbalance = T::Currency::slash_reserved(&bounty.proposer, value).0;
-						T::OnSlash::on_unbalanced(imbalance);
-						*maybe_bounty = None;
-
-						Self::deposit_event(Event::<T, I>::BountyRejected(bounty_id, value));
-						// Return early, nothing else to do.
-						return Ok(Some(T::WeightInfo::close_bounty_proposed()).into())
-					},
-					BountyStatus::Approved => {
-						// For weight reasons, we don't allow a council to cancel in this phase.
-						// We ask for them to wait until it is funded before they can cancel.
-						return Err(Error::<T, I>::UnexpectedStatus.into())
-					},
-					BountyStatus::Funded |
-					BountyStatus::CuratorProposed { .. } => {
-						// Nothing extra to do besides the removal of the bounty below.
-					},
-					BountyStatus::Active { curator, .. } => {
-						// Cancelled by council, refund deposit of the working curator.
-						let _ = T::Currency::unreserve(&curator, bounty.curator_deposit);
-						// Then execute removal of the bounty below.
-					},
-					BountyStatus::PendingPayout { .. } => {
-						// Bounty is already pending payout. If council wants to cancel
-						// this bounty, it should mean the curator was acting maliciously.
-						// So the council should first unassign the curator, slashing their
-						// deposit.
-						return Err(Error::<T, I>::PendingPayout.into())
-					}
-				}
-
-				let bounty_account = Self::bounty_account_id(bounty_id);
-
-				BountyDescriptions::<I>::remove(bounty_id);
-
-				let balance = T::Currency::free_balance(&bounty_account);
-				let _ = T::Currency::transfer(&bounty_account, &Self::account_id(), balance, AllowDeath); // should not fail
-				*maybe_bounty = None;
+			let spend = <Spends<T, I>>::take(index).ok_or(Error::<T, I>::InvalidIndex)?;
+			let unpaid = spend.amount.saturating_sub(spend.paid);
+			<Committed<T, I>>::mutate(spend.asset_id, |c| *c = c.saturating_sub(unpaid));
 
-				Self::deposit_event(Event::<T, I>::BountyCanceled(bounty_id));
-				Ok(Some(T::WeightInfo::close_bounty_active()).into())
-			})
-		}
-
-		/// Extend the expiry time of an active bounty.
-		///
-		/// The dispatch origin for this call must be the curator of this bounty.
-		///
-		/// - `bounty_id`: Bounty ID to extend.
-		/// - `remark`: additional information.
-		#[weight = T::WeightInfo::extend_bounty_expiry()]
-		fn extend_bounty_expiry(origin, #[compact] bounty_id: BountyIndex, _remark: Vec<u8>) {
-			let signer = ensure_signed(origin)?;
-
-			Bounties::<T, I>::try_mutate_exists(bounty_id, |maybe_bounty| -> DispatchResult {
-				let bounty = maybe_bounty.as_mut().ok_or(Error::<T, I>::InvalidIndex)?;
-
-				match bounty.status {
-					BountyStatus::Active { ref curator, ref mut update_due } => {
-						ensure!(*curator == signer, Error::<T, I>::RequireCurator);
-						*update_due = (system::Module::<T>::block_number() + T::BountyUpdatePeriod::get()).max(*update_due);
-					},
-					_ => return Err(Error::<T, I>::UnexpectedStatus.into()),
-				}
-
-				Ok(())
-			})?;
-
-			Self::deposit_event(Event::<T, I>::BountyExtended(bounty_id));
+			Self::deposit_event(RawEvent::AssetSpendVoided(index, unpaid));
 		}
 
 		/// # <weight>
@@ -1194,11 +973,42 @@ impl<T: Trait<I>, I: Instance> Module<T, I> {
 		T::ModuleId::get().into_account()
 	}
 
-	/// The account ID of a bounty account
-	pub fn bounty_account_id(id: BountyIndex) -> T::AccountId {
-		// only use two byte prefix to support 16 byte account id (used by test)
-		// "modl" ++ "py/trsry" ++ "bt" is 14 bytes, and two bytes remaining for bounty index
-		T::ModuleId::get().into_sub_account(("bt", id))
+	/// The payout status of the asset spend `index`, if it is neither fully paid out nor voided.
+	pub fn spend_status(index: SpendIndex) -> Option<SpendStatus<AssetBalanceOf<T, I>, T::BlockNumber>> {
+		let spend = Self::spends(index)?;
+		let (vested, next_instalment) = Self::vested(&spend, system::Module::<T>::block_number());
+		Some(SpendStatus {
+			paid: spend.paid,
+			claimable: vested.saturating_sub(spend.paid),
+			remaining: spend.amount.saturating_sub(spend.paid),
+			next_instalment,
+		})
+	}
+
+	/// The amount of an asset spend which is due by block `now`, whether paid out or not, and the
+	/// block from which the next instalment is due, if any is left.
+	fn vested(
+		spend: &AssetSpend<AssetIdOf<T, I>, AssetBalanceOf<T, I>, T::AccountId, T::BlockNumber>,
+		now: T::BlockNumber,
+	) -> (AssetBalanceOf<T, I>, Option<T::BlockNumber>) {
+		let schedule = &spend.schedule;
+		let due = if now < schedule.valid_from {
+			0
+		} else if schedule.period.is_zero() {
+			schedule.instalments
+		} else {
+			let elapsed = (now - schedule.valid_from) / schedule.period;
+			elapsed.saturated_into::<u32>().saturating_add(1).min(schedule.instalments)
+		};
+
+		if due >= schedule.instalments {
+			(spend.amount, None)
+		} else {
+			// Any remainder of the division is paid with the last instalment.
+			let vested = spend.amount / schedule.instalments.into() * due.into();
+			let next = schedule.valid_from.saturating_add(schedule.period.saturating_mul(due.into()));
+			(vested, Some(next))
+		}
 	}
 
 	/// The needed bond for a proposal whose spend is `value`.
@@ -1319,38 +1129,8 @@ impl<T: Trait<I>, I: Instance> Module<T, I> {
 
 		total_weight += T::WeightInfo::on_initialize_proposals(proposals_len);
 
-		let bounties_len = BountyApprovals::<I>::mutate(|v| {
-			let bounties_approval_len = v.len() as u32;
-			v.retain(|&index| {
-				Bounties::<T, I>::mutate(index, |bounty| {
-					// Should always be true, but shouldn't panic if false or we're screwed.
-					if let Some(bounty) = bounty {
-						if bounty.value <= budget_remaining {
-							budget_remaining -= bounty.value;
-
-							bounty.status = BountyStatus::Funded;
-
-							// return their deposit.
-							let _ = T::Currency::unreserve(&bounty.proposer, bounty.bond);
-
-							// fund the bounty account
-							imbalance.subsume(T::Currency::deposit_creating(&Self::bounty_account_id(index), bounty.value));
-
-							Self::deposit_event(RawEvent::BountyBecameActive(index));
-							false
-						} else {
-							missed_any = true;
-							true
-						}
-					} else {
-						false
-					}
-				})
-			});
-			bounties_approval_len
-		});
-
-		total_weight += T::WeightInfo::on_initialize_bounties(bounties_len);
+		// Call Runtime hooks to external modules to spend some funds.
+		T::SpendFunds::spend_funds(&mut budget_remaining, &mut imbalance, &mut total_weight, &mut missed_any);
 
 		if !missed_any {
 			// burn some proportion of the remaining budget if we run a surplus.
@@ -1385,42 +1165,12 @@ impl<T: Trait<I>, I: Instance> Module<T, I> {
 
 	/// Return the amount of money in the pot.
 	// The existential deposit is not part of the pot so treasury account never gets deleted.
-	fn pot() -> BalanceOf<T, I> {
+	pub fn pot() -> BalanceOf<T, I> {
 		T::Currency::free_balance(&Self::account_id())
 			// Must never be less than 0 but better be safe.
 			.saturating_sub(T::Currency::minimum_balance())
 	}
 
-	fn create_bounty(
-		proposer: T::AccountId,
-		description: Vec<u8>,
-		value: BalanceOf<T, I>,
-	) -> DispatchResult {
-		ensure!(description.len() <= T::MaximumReasonLength::get() as usize, Error::<T, I>::ReasonTooBig);
-		ensure!(value >= T::BountyValueMinimum::get(), Error::<T, I>::InvalidValue);
-
-		let index = Self::bounty_count();
-
-		// reserve deposit for new bounty
-		let bond = T::BountyDepositBase::get()
-			+ T::DataDepositPerByte::get() * (description.len() as u32).into();
-		T::Currency::reserve(&proposer, bond)
-			.map_err(|_| Error::<T, I>::InsufficientProposersBalance)?;
-
-		BountyCount::<I>::put(index + 1);
-
-		let bounty = Bounty {
-			proposer, value, fee: 0.into(), curator_deposit: 0.into(), bond, status: BountyStatus::Proposed,
-		};
-
-		Bounties::<T, I>::insert(index, &bounty);
-		BountyDescriptions::<I>::insert(index, description);
-
-		Self::deposit_event(RawEvent::BountyProposed(index));
-
-		Ok(())
-	}
-
 	pub fn migrate_retract_tip_for_tip_new() {
 		/// An open tipping "motion". Retains all details of a tip including information on the finder
 		/// and the members who have voted.
@@ -46,6 +46,7 @@ impl_outer_event! {
 	pub enum Event for Test {
 		system<T>,
 		pallet_balances<T>,
+		pallet_assets<T>,
 		treasury<T>,
 	}
 }
@@ -91,6 +92,8 @@ parameter_types! {
 }
 impl pallet_balances::Trait for Test {
 	type MaxLocks = ();
+	type MaxReserves = ();
+	type ReserveIdentifier = [u8; 8];
 	type Balance = u64;
 	type Event = Event;
 	type DustRemoval = ();
@@ -98,6 +101,40 @@ impl pallet_balances::Trait for Test {
 	type AccountStore = System;
 	type WeightInfo = ();
 }
+parameter_types! {
+	pub const AssetDeposit: u64 = 1;
+	pub const MetadataDepositBase: u64 = 1;
+	pub const MetadataDepositPerByte: u64 = 1;
+	pub const ApprovalDeposit: u64 = 1;
+	pub const StringLimit: u32 = 50;
+}
+impl pallet_assets::Trait for Test {
+	type Event = Event;
+	type Balance = u64;
+	type AssetId = u32;
+	type Currency = Balances;
+	type ForceOrigin = frame_system::EnsureRoot<u128>;
+	type AssetDeposit = AssetDeposit;
+	type MetadataDepositBase = MetadataDepositBase;
+	type MetadataDepositPerByte = MetadataDepositPerByte;
+	type ApprovalDeposit = ApprovalDeposit;
+	type StringLimit = StringLimit;
+	type WeightInfo = ();
+}
+/// Asset `0` is worth one unit of the native currency and asset `1` two, the other assets have
+/// no price.
+pub struct TestConversion;
+impl ConversionFromAssetBalance<u64, u32, u64> for TestConversion {
+	type Error = ();
+
+	fn from_asset_balance(balance: u64, asset_id: u32) -> Result<u64, ()> {
+		match asset_id {
+			0 => Ok(balance),
+			1 => Ok(balance * 2),
+			_ => Err(()),
+		}
+	}
+}
 thread_local! {
 	static TEN_TO_FOURTEEN: RefCell<Vec<u128>> = RefCell::new(vec![10,11,12,13,14]);
 }
@@ -132,13 +169,10 @@ parameter_types! {
 	pub const TipFindersFee: Percent = Percent::from_percent(20);
 	pub const TipReportDepositBase: u64 = 1;
 	pub const DataDepositPerByte: u64 = 1;
-	pub const BountyDepositBase: u64 = 80;
-	pub const BountyDepositPayoutDelay: u64 = 3;
 	pub const TreasuryModuleId: ModuleId = ModuleId(*b"py/trsry");
-	pub const BountyUpdatePeriod: u32 = 20;
 	pub const MaximumReasonLength: u32 = 16384;
-	pub const BountyCuratorDeposit: Permill = Permill::from_percent(50);
-	pub const BountyValueMinimum: u64 = 1;
+	pub const AssetSpendMaximum: u64 = 1000;
+	pub const MaxInstalments: u32 = 4;
 }
 impl Trait for Test {
 	type ModuleId = TreasuryModuleId;
@@ -156,17 +190,18 @@ impl Trait for Test {
 	type ProposalBondMinimum = ProposalBondMinimum;
 	type SpendPeriod = SpendPeriod;
 	type Burn = Burn;
-	type BountyDepositBase = BountyDepositBase;
-	type BountyDepositPayoutDelay = BountyDepositPayoutDelay;
-	type BountyUpdatePeriod = BountyUpdatePeriod;
-	type BountyCuratorDeposit = BountyCuratorDeposit;
-	type BountyValueMinimum = BountyValueMinimum;
 	type MaximumReasonLength = MaximumReasonLength;
 	type BurnDestination = ();  // Just gets burned.
+	type SpendFunds = ();
+	type Assets = Assets;
+	type BalanceConverter = TestConversion;
+	type AssetSpendMaximum = AssetSpendMaximum;
+	type MaxInstalments = MaxInstalments;
 	type WeightInfo = ();
 }
 type System = frame_system::Module<Test>;
 type Balances = pallet_balances::Module<Test>;
+type Assets = pallet_assets::Module<Test>;
 type Treasury = Module<Test>;
 
 pub fn new_test_ext() -> sp_io::TestExternalities {
@@ -176,10 +211,18 @@ pub fn new_test_ext() -> sp_io::TestExternalities {
 		balances: vec![(0, 100), (1, 98), (2, 1)],
 	}.assimilate_storage(&mut t).unwrap();
 	GenesisConfig::default().assimilate_storage::<Test, _>(&mut t).unwrap();
-	t.into()
+	let mut ext: sp_io::TestExternalities = t.into();
+	ext.execute_with(|| {
+		// Assets `0` and `1` exist, with 1000 of each held by the treasury account.
+		for id in 0..2 {
+			assert_ok!(Assets::force_create(Origin::root(), id, 0, 1));
+			assert_ok!(Assets::mint(Origin::signed(0), id, Treasury::account_id(), 1000));
+		}
+	});
+	ext
 }
 
-fn last_event() -> RawEvent<u64, u128, H256, DefaultInstance> {
+fn last_event() -> RawEvent<u64, u128, H256, u32, u64, DefaultInstance> {
 	System::events().into_iter().map(|r| r.event)
 		.filter_map(|e| {
 			if let Event::treasury(inner) = e { Some(inner) } else { None }
@@ -559,502 +602,6 @@ fn inexistent_account_works() {
 	});
 }
 
-#[test]
-fn propose_bounty_works() {
-	new_test_ext().execute_with(|| {
-		System::set_block_number(1);
-
-		Balances::make_free_balance_be(&Treasury::account_id(), 101);
-		assert_eq!(Treasury::pot(), 100);
-
-		assert_ok!(Treasury::propose_bounty(Origin::signed(0), 10, b"1234567890".to_vec()));
-
-		assert_eq!(last_event(), RawEvent::BountyProposed(0));
-
-		let deposit: u64 = 85 + 5;
-		assert_eq!(Balances::reserved_balance(0), deposit);
-		assert_eq!(Balances::free_balance(0), 100 - deposit);
-
-		assert_eq!(Treasury::bounties(0).unwrap(), Bounty {
-			proposer: 0,
-			fee: 0,
-			curator_deposit: 0,
-			value: 10,
-			bond: deposit,
-			status: BountyStatus::Proposed,
-		});
-
-		assert_eq!(Treasury::bounty_descriptions(0).unwrap(), b"1234567890".to_vec());
-
-		assert_eq!(Treasury::bounty_count(), 1);
-	});
-}
-
-#[test]
-fn propose_bounty_validation_works() {
-	new_test_ext().execute_with(|| {
-		System::set_block_number(1);
-
-		Balances::make_free_balance_be(&Treasury::account_id(), 101);
-		assert_eq!(Treasury::pot(), 100);
-
-		assert_noop!(
-			Treasury::propose_bounty(Origin::signed(1), 0, [0; 17_000].to_vec()),
-			Error::<Test, _>::ReasonTooBig
-		);
-
-		assert_noop!(
-			Treasury::propose_bounty(Origin::signed(1), 10, b"12345678901234567890".to_vec()),
-			Error::<Test, _>::InsufficientProposersBalance
-		);
-
-		assert_noop!(
-			Treasury::propose_bounty(Origin::signed(1), 0, b"12345678901234567890".to_vec()),
-			Error::<Test, _>::InvalidValue
-		);
-	});
-}
-
-#[test]
-fn close_bounty_works() {
-	new_test_ext().execute_with(|| {
-		System::set_block_number(1);
-		Balances::make_free_balance_be(&Treasury::account_id(), 101);
-		assert_noop!(Treasury::close_bounty(Origin::root(), 0), Error::<Test, _>::InvalidIndex);
-
-		assert_ok!(Treasury::propose_bounty(Origin::signed(0), 10, b"12345".to_vec()));
-
-		assert_ok!(Treasury::close_bounty(Origin::root(), 0));
-
-		let deposit: u64 = 80 + 5;
-
-		assert_eq!(last_event(), RawEvent::BountyRejected(0, deposit));
-
-		assert_eq!(Balances::reserved_balance(0), 0);
-		assert_eq!(Balances::free_balance(0), 100 - deposit);
-
-		assert_eq!(Treasury::bounties(0), None);
-		assert!(!Bounties::<Test>::contains_key(0));
-		assert_eq!(Treasury::bounty_descriptions(0), None);
-	});
-}
-
-#[test]
-fn approve_bounty_works() {
-	new_test_ext().execute_with(|| {
-		System::set_block_number(1);
-		Balances::make_free_balance_be(&Treasury::account_id(), 101);
-		assert_noop!(Treasury::approve_bounty(Origin::root(), 0), Error::<Test, _>::InvalidIndex);
-
-		assert_ok!(Treasury::propose_bounty(Origin::signed(0), 50, b"12345".to_vec()));
-
-		assert_ok!(Treasury::approve_bounty(Origin::root(), 0));
-
-		let deposit: u64 = 80 + 5;
-
-		assert_eq!(Treasury::bounties(0).unwrap(), Bounty {
-			proposer: 0,
-			fee: 0,
-			value: 50,
-			curator_deposit: 0,
-			bond: deposit,
-			status: BountyStatus::Approved,
-		});
-		assert_eq!(Treasury::bounty_approvals(), vec![0]);
-
-		assert_noop!(Treasury::close_bounty(Origin::root(), 0), Error::<Test, _>::UnexpectedStatus);
-
-		// deposit not returned yet
-		assert_eq!(Balances::reserved_balance(0), deposit);
-		assert_eq!(Balances::free_balance(0), 100 - deposit);
-
-		<Treasury as OnInitialize<u64>>::on_initialize(2);
-
-		// return deposit
-		assert_eq!(Balances::reserved_balance(0), 0);
-		assert_eq!(Balances::free_balance(0), 100);
-
-		assert_eq!(Treasury::bounties(0).unwrap(), Bounty {
-			proposer: 0,
-			fee: 0,
-			curator_deposit: 0,
-			value: 50,
-			bond: deposit,
-			status: BountyStatus::Funded,
-		});
-		assert_eq!(Treasury::pot(), 100 - 50 - 25); // burn 25
-		assert_eq!(Balances::free_balance(Treasury::bounty_account_id(0)), 50);
-	});
-}
-
-#[test]
-fn assign_curator_works() {
-	new_test_ext().execute_with(|| {
-		System::set_block_number(1);
-		Balances::make_free_balance_be(&Treasury::account_id(), 101);
-
-		assert_noop!(Treasury::propose_curator(Origin::root(), 0, 4, 4), Error::<Test, _>::InvalidIndex);
-
-		assert_ok!(Treasury::propose_bounty(Origin::signed(0), 50, b"12345".to_vec()));
-
-		assert_ok!(Treasury::approve_bounty(Origin::root(), 0));
-
-		System::set_block_number(2);
-		<Treasury as OnInitialize<u64>>::on_initialize(2);
-
-		assert_noop!(Treasury::propose_curator(Origin::root(), 0, 4, 50), Error::<Test, _>::InvalidFee);
-
-		assert_ok!(Treasury::propose_curator(Origin::root(), 0, 4, 4));
-
-		assert_eq!(Treasury::bounties(0).unwrap(), Bounty {
-			proposer: 0,
-			fee: 4,
-			curator_deposit: 0,
-			value: 50,
-			bond: 85,
-			status: BountyStatus::CuratorProposed {
-				curator: 4,
-			},
-		});
-
-		assert_noop!(Treasury::accept_curator(Origin::signed(1), 0), Error::<Test, _>::RequireCurator);
-		assert_noop!(Treasury::accept_curator(Origin::signed(4), 0), pallet_balances::Error::<Test, _>::InsufficientBalance);
-
-		Balances::make_free_balance_be(&4, 10);
-
-		assert_ok!(Treasury::accept_curator(Origin::signed(4), 0));
-
-		assert_eq!(Treasury::bounties(0).unwrap(), Bounty {
-			proposer: 0,
-			fee: 4,
-			curator_deposit: 2,
-			value: 50,
-			bond: 85,
-			status: BountyStatus::Active {
-				curator: 4,
-				update_due: 22,
-			},
-		});
-
-		assert_eq!(Balances::free_balance(&4), 8);
-		assert_eq!(Balances::reserved_balance(&4), 2);
-	});
-}
-
-#[test]
-fn unassign_curator_works() {
-	new_test_ext().execute_with(|| {
-		System::set_block_number(1);
-		Balances::make_free_balance_be(&Treasury::account_id(), 101);
-		assert_ok!(Treasury::propose_bounty(Origin::signed(0), 50, b"12345".to_vec()));
-
-		assert_ok!(Treasury::approve_bounty(Origin::root(), 0));
-
-		System::set_block_number(2);
-		<Treasury as OnInitialize<u64>>::on_initialize(2);
-
-		assert_ok!(Treasury::propose_curator(Origin::root(), 0, 4, 4));
-
-		assert_noop!(Treasury::unassign_curator(Origin::signed(1), 0), BadOrigin);
-
-		assert_ok!(Treasury::unassign_curator(Origin::signed(4), 0));
-
-		assert_eq!(Treasury::bounties(0).unwrap(), Bounty {
-			proposer: 0,
-			fee: 4,
-			curator_deposit: 0,
-			value: 50,
-			bond: 85,
-			status: BountyStatus::Funded,
-		});
-
-		assert_ok!(Treasury::propose_curator(Origin::root(), 0, 4, 4));
-
-		Balances::make_free_balance_be(&4, 10);
-
-		assert_ok!(Treasury::accept_curator(Origin::signed(4), 0));
-
-		assert_ok!(Treasury::unassign_curator(Origin::root(), 0));
-
-		assert_eq!(Treasury::bounties(0).unwrap(), Bounty {
-			proposer: 0,
-			fee: 4,
-			curator_deposit: 0,
-			value: 50,
-			bond: 85,
-			status: BountyStatus::Funded,
-		});
-
-		assert_eq!(Balances::free_balance(&4), 8);
-		assert_eq!(Balances::reserved_balance(&4), 0); // slashed 2
-	});
-}
-
-#[test]
-fn award_and_claim_bounty_works() {
-	new_test_ext().execute_with(|| {
-		System::set_block_number(1);
-		Balances::make_free_balance_be(&Treasury::account_id(), 101);
-		Balances::make_free_balance_be(&4, 10);
-		assert_ok!(Treasury::propose_bounty(Origin::signed(0), 50, b"12345".to_vec()));
-
-		assert_ok!(Treasury::approve_bounty(Origin::root(), 0));
-
-		System::set_block_number(2);
-		<Treasury as OnInitialize<u64>>::on_initialize(2);
-
-		assert_ok!(Treasury::propose_curator(Origin::root(), 0, 4, 4));
-		assert_ok!(Treasury::accept_curator(Origin::signed(4), 0));
-
-		assert_eq!(Balances::free_balance(4), 8); // inital 10 - 2 deposit
-
-		assert_noop!(Treasury::award_bounty(Origin::signed(1), 0, 3), Error::<Test, _>::RequireCurator);
-
-		assert_ok!(Treasury::award_bounty(Origin::signed(4), 0, 3));
-
-		assert_eq!(Treasury::bounties(0).unwrap(), Bounty {
-			proposer: 0,
-			fee: 4,
-			curator_deposit: 2,
-			value: 50,
-			bond: 85,
-			status: BountyStatus::PendingPayout {
-				curator: 4,
-				beneficiary: 3,
-				unlock_at: 5
-			},
-		});
-
-		assert_noop!(Treasury::claim_bounty(Origin::signed(1), 0), Error::<Test, _>::Premature);
-
-		System::set_block_number(5);
-		<Treasury as OnInitialize<u64>>::on_initialize(5);
-
-		assert_ok!(Balances::transfer(Origin::signed(0), Treasury::bounty_account_id(0), 10));
-
-		assert_ok!(Treasury::claim_bounty(Origin::signed(1), 0));
-
-		assert_eq!(last_event(), RawEvent::BountyClaimed(0, 56, 3));
-
-		assert_eq!(Balances::free_balance(4), 14); // initial 10 + fee 4
-		assert_eq!(Balances::free_balance(3), 56);
-		assert_eq!(Balances::free_balance(Treasury::bounty_account_id(0)), 0);
-
-		assert_eq!(Treasury::bounties(0), None);
-		assert_eq!(Treasury::bounty_descriptions(0), None);
-	});
-}
-
-#[test]
-fn claim_handles_high_fee() {
-	new_test_ext().execute_with(|| {
-		System::set_block_number(1);
-		Balances::make_free_balance_be(&Treasury::account_id(), 101);
-		Balances::make_free_balance_be(&4, 30);
-		assert_ok!(Treasury::propose_bounty(Origin::signed(0), 50, b"12345".to_vec()));
-
-		assert_ok!(Treasury::approve_bounty(Origin::root(), 0));
-
-		System::set_block_number(2);
-		<Treasury as OnInitialize<u64>>::on_initialize(2);
-
-		assert_ok!(Treasury::propose_curator(Origin::root(), 0, 4, 49));
-		assert_ok!(Treasury::accept_curator(Origin::signed(4), 0));
-
-		assert_ok!(Treasury::award_bounty(Origin::signed(4), 0, 3));
-
-		System::set_block_number(5);
-		<Treasury as OnInitialize<u64>>::on_initialize(5);
-
-		// make fee > balance
-		let _ = Balances::slash(&Treasury::bounty_account_id(0), 10);
-
-		assert_ok!(Treasury::claim_bounty(Origin::signed(1), 0));
-
-		assert_eq!(last_event(), RawEvent::BountyClaimed(0, 0, 3));
-
-		assert_eq!(Balances::free_balance(4), 70); // 30 + 50 - 10
-		assert_eq!(Balances::free_balance(3), 0);
-		assert_eq!(Balances::free_balance(Treasury::bounty_account_id(0)), 0);
-
-		assert_eq!(Treasury::bounties(0), None);
-		assert_eq!(Treasury::bounty_descriptions(0), None);
-	});
-}
-
-#[test]
-fn cancel_and_refund() {
-	new_test_ext().execute_with(|| {
-		System::set_block_number(1);
-		Balances::make_free_balance_be(&Treasury::account_id(), 101);
-		assert_ok!(Treasury::propose_bounty(Origin::signed(0), 50, b"12345".to_vec()));
-
-		assert_ok!(Treasury::approve_bounty(Origin::root(), 0));
-
-		System::set_block_number(2);
-		<Treasury as OnInitialize<u64>>::on_initialize(2);
-
-		assert_ok!(Balances::transfer(Origin::signed(0), Treasury::bounty_account_id(0), 10));
-
-		assert_eq!(Treasury::bounties(0).unwrap(), Bounty {
-			proposer: 0,
-			fee: 0,
-			curator_deposit: 0,
-			value: 50,
-			bond: 85,
-			status: BountyStatus::Funded,
-		});
-
-		assert_eq!(Balances::free_balance(Treasury::bounty_account_id(0)), 60);
-
-		assert_noop!(Treasury::close_bounty(Origin::signed(0), 0), BadOrigin);
-
-		assert_ok!(Treasury::close_bounty(Origin::root(), 0));
-
-		assert_eq!(Treasury::pot(), 85); // - 25 + 10
-	});
-}
-
-#[test]
-fn award_and_cancel() {
-	new_test_ext().execute_with(|| {
-		System::set_block_number(1);
-		Balances::make_free_balance_be(&Treasury::account_id(), 101);
-		assert_ok!(Treasury::propose_bounty(Origin::signed(0), 50, b"12345".to_vec()));
-
-		assert_ok!(Treasury::approve_bounty(Origin::root(), 0));
-
-		System::set_block_number(2);
-		<Treasury as OnInitialize<u64>>::on_initialize(2);
-
-		assert_ok!(Treasury::propose_curator(Origin::root(), 0, 0, 10));
-		assert_ok!(Treasury::accept_curator(Origin::signed(0), 0));
-
-		assert_eq!(Balances::free_balance(0), 95);
-		assert_eq!(Balances::reserved_balance(0), 5);
-
-		assert_ok!(Treasury::award_bounty(Origin::signed(0), 0, 3));
-
-		// Cannot close bounty directly when payout is happening...
-		assert_noop!(Treasury::close_bounty(Origin::root(), 0), Error::<Test, _>::PendingPayout);
-
-		// Instead unassign the curator to slash them and then close.
-		assert_ok!(Treasury::unassign_curator(Origin::root(), 0));
-		assert_ok!(Treasury::close_bounty(Origin::root(), 0));
-
-		assert_eq!(last_event(), RawEvent::BountyCanceled(0));
-
-		assert_eq!(Balances::free_balance(Treasury::bounty_account_id(0)), 0);
-		// Slashed.
-		assert_eq!(Balances::free_balance(0), 95);
-		assert_eq!(Balances::reserved_balance(0), 0);
-
-		assert_eq!(Treasury::bounties(0), None);
-		assert_eq!(Treasury::bounty_descriptions(0), None);
-	});
-}
-
-#[test]
-fn expire_and_unassign() {
-	new_test_ext().execute_with(|| {
-		System::set_block_number(1);
-		Balances::make_free_balance_be(&Treasury::account_id(), 101);
-		assert_ok!(Treasury::propose_bounty(Origin::signed(0), 50, b"12345".to_vec()));
-
-		assert_ok!(Treasury::approve_bounty(Origin::root(), 0));
-
-		System::set_block_number(2);
-		<Treasury as OnInitialize<u64>>::on_initialize(2);
-
-		assert_ok!(Treasury::propose_curator(Origin::root(), 0, 1, 10));
-		assert_ok!(Treasury::accept_curator(Origin::signed(1), 0));
-
-		assert_eq!(Balances::free_balance(1), 93);
-		assert_eq!(Balances::reserved_balance(1), 5);
-
-		System::set_block_number(22);
-		<Treasury as OnInitialize<u64>>::on_initialize(22);
-
-		assert_noop!(Treasury::unassign_curator(Origin::signed(0), 0), Error::<Test, _>::Premature);
-
-		System::set_block_number(23);
-		<Treasury as OnInitialize<u64>>::on_initialize(23);
-
-		assert_ok!(Treasury::unassign_curator(Origin::signed(0), 0));
-
-		assert_eq!(Treasury::bounties(0).unwrap(), Bounty {
-			proposer: 0,
-			fee: 10,
-			curator_deposit: 0,
-			value: 50,
-			bond: 85,
-			status: BountyStatus::Funded,
-		});
-
-		assert_eq!(Balances::free_balance(1), 93);
-		assert_eq!(Balances::reserved_balance(1), 0); // slashed
-
-	});
-}
-
-#[test]
-fn extend_expiry() {
-	new_test_ext().execute_with(|| {
-		System::set_block_number(1);
-		Balances::make_free_balance_be(&Treasury::account_id(), 101);
-		Balances::make_free_balance_be(&4, 10);
-		assert_ok!(Treasury::propose_bounty(Origin::signed(0), 50, b"12345".to_vec()));
-
-		assert_ok!(Treasury::approve_bounty(Origin::root(), 0));
-
-		assert_noop!(Treasury::extend_bounty_expiry(Origin::signed(1), 0, Vec::new()), Error::<Test, _>::UnexpectedStatus);
-
-		System::set_block_number(2);
-		<Treasury as OnInitialize<u64>>::on_initialize(2);
-
-		assert_ok!(Treasury::propose_curator(Origin::root(), 0, 4, 10));
-		assert_ok!(Treasury::accept_curator(Origin::signed(4), 0));
-
-		assert_eq!(Balances::free_balance(4), 5);
-		assert_eq!(Balances::reserved_balance(4), 5);
-
-		System::set_block_number(10);
-		<Treasury as OnInitialize<u64>>::on_initialize(10);
-
-		assert_noop!(Treasury::extend_bounty_expiry(Origin::signed(0), 0, Vec::new()), Error::<Test, _>::RequireCurator);
-		assert_ok!(Treasury::extend_bounty_expiry(Origin::signed(4), 0, Vec::new()));
-
-		assert_eq!(Treasury::bounties(0).unwrap(), Bounty {
-			proposer: 0,
-			fee: 10,
-			curator_deposit: 5,
-			value: 50,
-			bond: 85,
-			status: BountyStatus::Active { curator: 4, update_due: 30 },
-		});
-
-		assert_ok!(Treasury::extend_bounty_expiry(Origin::signed(4), 0, Vec::new()));
-
-		assert_eq!(Treasury::bounties(0).unwrap(), Bounty {
-			proposer: 0,
-			fee: 10,
-			curator_deposit: 5,
-			value: 50,
-			bond: 85,
-			status: BountyStatus::Active { curator: 4, update_due: 30 }, // still the same
-		});
-
-		System::set_block_number(25);
-		<Treasury as OnInitialize<u64>>::on_initialize(25);
-
-		assert_noop!(Treasury::unassign_curator(Origin::signed(0), 0), Error::<Test, _>::Premature);
-		assert_ok!(Treasury::unassign_curator(Origin::signed(4), 0));
-
-		assert_eq!(Balances::free_balance(4), 10); // not slashed
-		assert_eq!(Balances::reserved_balance(4), 0);
-	});
-}
-
 #[test]
 fn test_last_reward_migration() {
 	use sp_storage::Storage;
@@ -1165,3 +712,148 @@ fn genesis_funding_works() {
 		assert_eq!(Treasury::pot(), initial_funding - Balances::minimum_balance());
 	});
 }
+
+fn schedule(valid_from: u64, instalments: u32, period: u64) -> PayoutSchedule<u64> {
+	PayoutSchedule { valid_from, instalments, period }
+}
+
+#[test]
+fn spend_checks_origin_schedule_and_value() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_noop!(Treasury::spend(Origin::signed(0), 0, 100, 3, schedule(1, 1, 0)), BadOrigin);
+		assert_noop!(
+			Treasury::spend(Origin::root(), 0, 100, 3, schedule(1, 0, 0)),
+			Error::<Test, _>::InvalidSchedule,
+		);
+		assert_noop!(
+			Treasury::spend(Origin::root(), 0, 100, 3, schedule(1, 5, 1)),
+			Error::<Test, _>::InvalidSchedule,
+		);
+		assert_noop!(
+			Treasury::spend(Origin::root(), 0, 100, 3, schedule(1, 2, 0)),
+			Error::<Test, _>::InvalidSchedule,
+		);
+		assert_noop!(
+			Treasury::spend(Origin::root(), 2, 100, 3, schedule(1, 1, 0)),
+			Error::<Test, _>::FailedToConvertBalance,
+		);
+		assert_noop!(
+			Treasury::spend(Origin::root(), 1, 501, 3, schedule(1, 1, 0)),
+			Error::<Test, _>::SpendTooLarge,
+		);
+
+		assert_ok!(Treasury::spend(Origin::root(), 1, 500, 3, schedule(1, 1, 0)));
+		assert_eq!(last_event(), RawEvent::AssetSpendApproved(0, 1, 500, 3, 1000));
+		assert_eq!(Treasury::spend_count(), 1);
+	});
+}
+
+#[test]
+fn spend_is_paid_out_from_valid_from() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(Treasury::spend(Origin::root(), 0, 100, 3, schedule(5, 1, 0)));
+		assert_noop!(Treasury::payout(Origin::signed(10), 0), Error::<Test, _>::NothingToClaim);
+
+		System::set_block_number(5);
+		assert_ok!(Treasury::payout(Origin::signed(10), 0));
+		assert_eq!(last_event(), RawEvent::AssetPaid(0, 100));
+		assert_eq!(Assets::balance(0, 3), 100);
+		assert_eq!(Assets::balance(0, Treasury::account_id()), 900);
+
+		// A fully paid out spend is removed.
+		assert_eq!(Treasury::spends(0), None);
+		assert_eq!(Treasury::spend_status(0), None);
+		assert_noop!(Treasury::payout(Origin::signed(10), 0), Error::<Test, _>::InvalidIndex);
+	});
+}
+
+#[test]
+fn spend_is_paid_out_in_instalments() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(Treasury::spend(Origin::root(), 0, 100, 3, schedule(10, 3, 10)));
+		assert_eq!(
+			Treasury::spend_status(0),
+			Some(SpendStatus { paid: 0, claimable: 0, remaining: 100, next_instalment: Some(10) }),
+		);
+
+		System::set_block_number(10);
+		assert_eq!(
+			Treasury::spend_status(0),
+			Some(SpendStatus { paid: 0, claimable: 33, remaining: 100, next_instalment: Some(20) }),
+		);
+		assert_ok!(Treasury::payout(Origin::signed(3), 0));
+		assert_eq!(Assets::balance(0, 3), 33);
+		assert_noop!(Treasury::payout(Origin::signed(3), 0), Error::<Test, _>::NothingToClaim);
+
+		System::set_block_number(25);
+		assert_eq!(
+			Treasury::spend_status(0),
+			Some(SpendStatus { paid: 33, claimable: 33, remaining: 67, next_instalment: Some(30) }),
+		);
+
+		// Instalments which are due accumulate until they are claimed, and the remainder of the
+		// division is paid with the last one.
+		System::set_block_number(40);
+		assert_eq!(
+			Treasury::spend_status(0),
+			Some(SpendStatus { paid: 33, claimable: 67, remaining: 67, next_instalment: None }),
+		);
+		assert_ok!(Treasury::payout(Origin::signed(3), 0));
+		assert_eq!(last_event(), RawEvent::AssetPaid(0, 67));
+		assert_eq!(Assets::balance(0, 3), 100);
+		assert_eq!(Treasury::spend_status(0), None);
+	});
+}
+
+#[test]
+fn void_spend_stops_remaining_payouts() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(Treasury::spend(Origin::root(), 0, 100, 3, schedule(0, 2, 10)));
+		assert_ok!(Treasury::payout(Origin::signed(3), 0));
+		assert_eq!(Assets::balance(0, 3), 50);
+
+		assert_noop!(Treasury::void_spend(Origin::signed(3), 0), BadOrigin);
+		assert_ok!(Treasury::void_spend(Origin::root(), 0));
+		assert_eq!(last_event(), RawEvent::AssetSpendVoided(0, 50));
+
+		System::set_block_number(10);
+		assert_noop!(Treasury::payout(Origin::signed(3), 0), Error::<Test, _>::InvalidIndex);
+		assert_noop!(Treasury::void_spend(Origin::root(), 0), Error::<Test, _>::InvalidIndex);
+		assert_eq!(Assets::balance(0, 3), 50);
+	});
+}
+
+#[test]
+fn spend_is_limited_to_uncommitted_funds() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		// The treasury account holds 1000 of asset `0` and is kept alive with 1 of it.
+		assert_ok!(Treasury::spend(Origin::root(), 0, 500, 3, schedule(1, 1, 0)));
+		assert_ok!(Treasury::spend(Origin::root(), 0, 499, 3, schedule(1, 1, 0)));
+		assert_eq!(Treasury::committed(0), 999);
+		assert_noop!(
+			Treasury::spend(Origin::root(), 0, 1, 3, schedule(1, 1, 0)),
+			Error::<Test, _>::InsufficientFunds,
+		);
+		// Other assets are committed separately.
+		assert_ok!(Treasury::spend(Origin::root(), 1, 1, 3, schedule(1, 1, 0)));
+
+		// Paying out a spend releases its commitment along with the funds.
+		assert_ok!(Treasury::payout(Origin::signed(3), 0));
+		assert_eq!(Treasury::committed(0), 499);
+		assert_noop!(
+			Treasury::spend(Origin::root(), 0, 1, 3, schedule(1, 1, 0)),
+			Error::<Test, _>::InsufficientFunds,
+		);
+
+		// Voiding a spend releases the commitment of its unpaid remainder.
+		assert_ok!(Treasury::void_spend(Origin::root(), 1));
+		assert_eq!(Treasury::committed(0), 0);
+		assert_ok!(Treasury::spend(Origin::root(), 0, 499, 3, schedule(1, 1, 0)));
+	});
+}
@@ -0,0 +1,118 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2020 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Implementations of the `nonfungibles` traits for the Uniques module.
+
+use super::*;
+use frame_support::traits::nonfungibles;
+
+impl<T: Trait> nonfungibles::Inspect<T::AccountId> for Module<T> {
+	type ClassId = T::ClassId;
+	type InstanceId = T::InstanceId;
+
+	fn owner(class: &T::ClassId, instance: &T::InstanceId) -> Option<T::AccountId> {
+		Self::owner(*class, *instance)
+	}
+
+	fn class_owner(class: &T::ClassId) -> Option<T::AccountId> {
+		Self::class_owner(*class)
+	}
+
+	fn attribute(class: &T::ClassId, instance: &T::InstanceId, key: &[u8]) -> Option<Vec<u8>> {
+		Attribute::<T>::get(class, (Some(*instance), key.to_vec())).map(|(value, _)| value)
+	}
+
+	fn class_attribute(class: &T::ClassId, key: &[u8]) -> Option<Vec<u8>> {
+		Attribute::<T>::get(class, (None::<T::InstanceId>, key.to_vec())).map(|(value, _)| value)
+	}
+
+	fn can_transfer(class: &T::ClassId, instance: &T::InstanceId) -> bool {
+		match (Class::<T>::get(class), Asset::<T>::get(class, instance)) {
+			(Some(class_details), Some(details)) => !class_details.is_frozen && !details.is_frozen,
+			_ => false,
+		}
+	}
+}
+
+impl<T: Trait> nonfungibles::InspectEnumerable<T::AccountId> for Module<T> {
+	fn classes() -> Box<dyn Iterator<Item = T::ClassId>> {
+		Box::new(Class::<T>::iter_keys())
+	}
+
+	fn instances(class: &T::ClassId) -> Box<dyn Iterator<Item = T::InstanceId>> {
+		Box::new(Asset::<T>::iter_key_prefix(class))
+	}
+
+	fn owned(who: &T::AccountId) -> Box<dyn Iterator<Item = (T::ClassId, T::InstanceId)>> {
+		Box::new(Account::<T>::iter_key_prefix(who))
+	}
+
+	fn owned_in_class(
+		class: &T::ClassId,
+		who: &T::AccountId,
+	) -> Box<dyn Iterator<Item = T::InstanceId>> {
+		let class = *class;
+		Box::new(
+			Account::<T>::iter_key_prefix(who)
+				.filter(move |(c, _)| *c == class)
+				.map(|(_, instance)| instance)
+		)
+	}
+}
+
+impl<T: Trait> nonfungibles::Create<T::AccountId> for Module<T> {
+	/// Create a `class` of nonfungible assets to be owned by `who` and managed by `admin`,
+	/// reserving the `ClassDeposit` from `who`.
+	fn create_class(class: &T::ClassId, who: &T::AccountId, admin: &T::AccountId) -> DispatchResult {
+		Self::do_create_class(*class, who.clone(), admin.clone(), T::ClassDeposit::get(), false)?;
+		Self::deposit_event(RawEvent::Created(*class, who.clone(), admin.clone()));
+		Ok(())
+	}
+}
+
+impl<T: Trait> nonfungibles::Mutate<T::AccountId> for Module<T> {
+	fn mint_into(class: &T::ClassId, instance: &T::InstanceId, who: &T::AccountId) -> DispatchResult {
+		Self::do_mint(*class, *instance, who.clone(), |_| Ok(()))
+	}
+
+	fn burn_from(class: &T::ClassId, instance: &T::InstanceId) -> DispatchResult {
+		Self::do_burn(*class, *instance, |_, _| Ok(()))
+	}
+
+	fn set_attribute(
+		class: &T::ClassId,
+		instance: &T::InstanceId,
+		key: &[u8],
+		value: &[u8],
+	) -> DispatchResult {
+		Self::do_set_attribute(*class, Some(*instance), key.to_vec(), value.to_vec(), None)
+	}
+
+	fn set_class_attribute(class: &T::ClassId, key: &[u8], value: &[u8]) -> DispatchResult {
+		Self::do_set_attribute(*class, None, key.to_vec(), value.to_vec(), None)
+	}
+}
+
+impl<T: Trait> nonfungibles::Transfer<T::AccountId> for Module<T> {
+	fn transfer(
+		class: &T::ClassId,
+		instance: &T::InstanceId,
+		destination: &T::AccountId,
+	) -> DispatchResult {
+		Self::do_transfer(*class, *instance, destination.clone(), |_, _| Ok(()))
+	}
+}
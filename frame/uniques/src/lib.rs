@@ -0,0 +1,1628 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2020 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # Unique (Assets) Module
+//!
+//! A simple, secure module for dealing with non-fungible assets.
+//!
+//! ## Overview
+//!
+//! The Uniques module provides functionality for management of classes of non-fungible assets,
+//! including:
+//!
+//! * Asset Class Creation
+//! * Asset Instance Minting and Burning
+//! * Asset Instance Transfer, directly or through an approved delegate
+//! * Asset Class and Instance Freezing and Thawing
+//! * Asset Class and Instance Metadata and Attributes
+//! * Asset Class Destruction
+//!
+//! To use it in your runtime, you need to implement the uniques [`Trait`](./trait.Trait.html).
+//!
+//! The supported dispatchable functions are documented in the [`Call`](./enum.Call.html) enum.
+//!
+//! ### Terminology
+//!
+//! * **Asset class**: A collection of asset instances which share the same team and deposits.
+//! * **Asset instance**: A single non-fungible asset of an asset class, identified by the pair of
+//!   its class and instance identifiers.
+//! * **Owner**: An account ID uniquely privileged to be able to destroy an asset class, to set its
+//!   team and to set the metadata and attributes of the class and its instances. All deposits of
+//!   an asset class are reserved from its Owner.
+//! * **Issuer**: An account ID uniquely privileged to be able to mint instances of an asset class.
+//! * **Admin**: An account ID uniquely privileged to be able to thaw instances of an asset class,
+//!   as well as to transfer and burn any of them.
+//! * **Freezer**: An account ID uniquely privileged to be able to freeze an asset class or any of
+//!   its instances.
+//! * **Freezing**: Removing the possibility of a transfer of an instance, or of any instance of an
+//!   asset class.
+//! * **Approval**: The permission given by the owner of an instance to some delegate account to
+//!   transfer that instance once.
+//! * **Attribute**: An arbitrary key-value pair stored on-chain for an asset class or instance.
+//! * **Metadata**: Arbitrary data describing an asset class or instance, which can be frozen so
+//!   that it can no longer be altered by the Owner.
+//! * **Deposit**: An amount of the native currency reserved from the Owner of an asset class for
+//!   the class itself, each of its instances, its metadata and its attributes, and returned when
+//!   the corresponding item is removed. A class may be made "free holding" by the forcing origin,
+//!   in which case no deposits are taken for its instances, metadata and attributes.
+//!
+//! ### Goals
+//!
+//! The uniques system in Substrate is designed to make the following possible:
+//!
+//! * Create a new asset class and let its team mint, transfer, freeze and burn its instances.
+//! * Let owners transfer their instances, directly or through an approved delegate.
+//! * Attach metadata and arbitrary attributes to asset classes and instances.
+//! * Let a privileged origin, e.g. governance, create, destroy and reconfigure any asset class.
+//! * Let other modules deal with any asset instance through the
+//!   [`nonfungibles`](../frame_support/traits/nonfungibles/index.html) traits.
+//!
+//! ## Interface
+//!
+//! ### Permissionless Functions
+//!
+//! * `create`: Create a new asset class by placing a deposit.
+//!
+//! ### Permissioned Functions
+//!
+//! * `force_create`: Create a new asset class without a deposit.
+//! * `force_asset_status`: Alter the underlying characteristics of an asset class.
+//!
+//! ### Privileged Functions
+//!
+//! * `destroy`: Destroy an asset class; called by the asset class's Owner, or by the forcing
+//!   origin.
+//! * `mint`: Mint a new asset instance within an asset class; called by the Issuer.
+//! * `burn`: Burn an asset instance; called by the Admin or the instance's owner.
+//! * `transfer`: Transfer an asset instance to a new owner; called by the instance's owner, its
+//!   approved delegate or the Admin.
+//! * `freeze`: Prevent an individual asset instance from being transferred; called by the
+//!   Freezer.
+//! * `thaw`: Revert the effects of a previous `freeze`; called by the Admin.
+//! * `freeze_class`: Prevent all asset instances within a class from being transferred; called
+//!   by the Freezer.
+//! * `thaw_class`: Revert the effects of a previous `freeze_class`; called by the Admin.
+//! * `transfer_ownership`: Alter the Owner of an asset class, moving all associated deposits;
+//!   called by the Owner.
+//! * `set_team`: Alter the Issuer, Admin and Freezer of an asset class; called by the Owner.
+//! * `approve_transfer`: Allow a delegate to transfer an instance once; called by the instance's
+//!   owner or the Admin.
+//! * `cancel_approval`: Revert the effects of a previous `approve_transfer`; called by the
+//!   instance's owner or the Admin.
+//! * `set_attribute`: Set an attribute of an asset class or instance; called by the Owner.
+//! * `clear_attribute`: Remove an attribute of an asset class or instance; called by the Owner.
+//! * `set_metadata`: Set the metadata of an asset instance; called by the Owner.
+//! * `clear_metadata`: Remove the metadata of an asset instance; called by the Owner.
+//! * `set_class_metadata`: Set the metadata of an asset class; called by the Owner.
+//! * `clear_class_metadata`: Remove the metadata of an asset class; called by the Owner.
+//!
+//! The forcing origin may also call any of the functions dealing with approvals, metadata and
+//! attributes, in which case no deposits are taken and frozen metadata may be altered.
+//!
+//! Please refer to the [`Call`](./enum.Call.html) enum and its associated variants for
+//! documentation on each function.
+//!
+//! ### Public Functions
+//!
+//! * `owner` - Get the owner of an asset instance, if it exists.
+//! * `class_owner` - Get the Owner of an asset class, if it exists.
+//!
+//! Please refer to the [`Module`](./struct.Module.html) struct for details on publicly available
+//! functions. The module also implements the
+//! [`nonfungibles`](../frame_support/traits/nonfungibles/index.html) traits.
+//!
+//! ## Assumptions
+//!
+//! Below are assumptions that must be held when using this module.  If any of
+//! them are violated, the behavior of this module is undefined.
+//!
+//! * The number of instances, instance metadata and attributes of an asset class must be
+//!   bounded, since destroying the asset class removes all of them in a single call.
+//!
+//! ## Related Modules
+//!
+//! * [`System`](../frame_system/index.html)
+//! * [`Support`](../frame_support/index.html)
+//! * [`Assets`](../pallet_assets/index.html)
+
+// Ensure we're `no_std` when compiling for Wasm.
+#![cfg_attr(not(feature = "std"), no_std)]
+
+mod tests;
+mod benchmarking;
+mod default_weight;
+mod impl_nonfungibles;
+
+use sp_std::prelude::*;
+use codec::{Encode, Decode, HasCompact};
+use frame_support::{
+	Parameter, decl_module, decl_event, decl_storage, decl_error, ensure, RuntimeDebug,
+	dispatch::{DispatchError, DispatchResult, DispatchResultWithPostInfo},
+	traits::{Currency, ReservableCurrency, EnsureOrigin, Get, BalanceStatus},
+	weights::Weight,
+	type_info::TypeInfo,
+};
+use sp_runtime::traits::{Member, Zero, StaticLookup, Saturating};
+use frame_system::ensure_signed;
+
+type DepositBalanceOf<T> =
+	<<T as Trait>::Currency as Currency<<T as frame_system::Trait>::AccountId>>::Balance;
+type ClassDetailsFor<T> =
+	ClassDetails<<T as frame_system::Trait>::AccountId, DepositBalanceOf<T>>;
+type InstanceDetailsFor<T> =
+	InstanceDetails<<T as frame_system::Trait>::AccountId, DepositBalanceOf<T>>;
+
+pub trait WeightInfo {
+	fn create() -> Weight;
+	fn force_create() -> Weight;
+	fn destroy(n: u32, m: u32, a: u32, ) -> Weight;
+	fn mint() -> Weight;
+	fn burn() -> Weight;
+	fn transfer() -> Weight;
+	fn freeze() -> Weight;
+	fn thaw() -> Weight;
+	fn freeze_class() -> Weight;
+	fn thaw_class() -> Weight;
+	fn transfer_ownership() -> Weight;
+	fn set_team() -> Weight;
+	fn force_asset_status() -> Weight;
+	fn set_attribute() -> Weight;
+	fn clear_attribute() -> Weight;
+	fn set_metadata() -> Weight;
+	fn clear_metadata() -> Weight;
+	fn set_class_metadata() -> Weight;
+	fn clear_class_metadata() -> Weight;
+	fn approve_transfer() -> Weight;
+	fn cancel_approval() -> Weight;
+}
+
+/// The module configuration trait.
+pub trait Trait: frame_system::Trait {
+	/// The overarching event type.
+	type Event: From<Event<Self>> + Into<<Self as frame_system::Trait>::Event>;
+
+	/// Identifier for the class of asset.
+	type ClassId: Member + Parameter + Default + Copy + HasCompact;
+
+	/// The type used to identify a unique asset within an asset class.
+	type InstanceId: Member + Parameter + Default + Copy + HasCompact;
+
+	/// The currency mechanism, used for paying for reserves.
+	type Currency: ReservableCurrency<Self::AccountId>;
+
+	/// The origin which may forcibly create or destroy an asset class or otherwise alter
+	/// privileged attributes.
+	type ForceOrigin: EnsureOrigin<Self::Origin>;
+
+	/// The basic amount of funds that must be reserved for an asset class.
+	type ClassDeposit: Get<DepositBalanceOf<Self>>;
+
+	/// The basic amount of funds that must be reserved for an asset instance.
+	type InstanceDeposit: Get<DepositBalanceOf<Self>>;
+
+	/// The basic amount of funds that must be reserved when adding metadata to an asset class or
+	/// instance.
+	type MetadataDepositBase: Get<DepositBalanceOf<Self>>;
+
+	/// The basic amount of funds that must be reserved when adding an attribute to an asset
+	/// class or instance.
+	type AttributeDepositBase: Get<DepositBalanceOf<Self>>;
+
+	/// The additional funds that must be reserved for the number of bytes stored in metadata or
+	/// in an attribute, either "normal" metadata or attribute metadata.
+	type DepositPerByte: Get<DepositBalanceOf<Self>>;
+
+	/// The maximum length of data stored on-chain as metadata.
+	type StringLimit: Get<u32>;
+
+	/// The maximum length of an attribute key.
+	type KeyLimit: Get<u32>;
+
+	/// The maximum length of an attribute value.
+	type ValueLimit: Get<u32>;
+
+	/// Weight information for extrinsics in this pallet.
+	type WeightInfo: WeightInfo;
+}
+
+/// The details of an asset class.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct ClassDetails<AccountId, DepositBalance> {
+	/// Can change `owner`, `issuer`, `freezer` and `admin` accounts.
+	owner: AccountId,
+	/// Can mint tokens.
+	issuer: AccountId,
+	/// Can thaw tokens, force transfers and burn tokens from any account.
+	admin: AccountId,
+	/// Can freeze tokens.
+	freezer: AccountId,
+	/// The total balance deposited for all the storage associated with this asset class. Used
+	/// by `destroy`.
+	total_deposit: DepositBalance,
+	/// If `true`, then no deposit is needed to hold instances of this class.
+	free_holding: bool,
+	/// The total number of outstanding instances of this asset class.
+	instances: u32,
+	/// The total number of outstanding instance metadata of this asset class.
+	instance_metadatas: u32,
+	/// The total number of attributes for this asset class.
+	attributes: u32,
+	/// Whether the asset is frozen for non-admin transfers.
+	is_frozen: bool,
+}
+
+impl<AccountId, DepositBalance> ClassDetails<AccountId, DepositBalance> {
+	/// The witness data needed to destroy this asset class.
+	pub fn destroy_witness(&self) -> DestroyWitness {
+		DestroyWitness {
+			instances: self.instances,
+			instance_metadatas: self.instance_metadatas,
+			attributes: self.attributes,
+		}
+	}
+}
+
+/// Witness data for the destruction of an asset class, used to bound the weight of `destroy`.
+#[derive(Copy, Clone, Encode, Decode, Eq, PartialEq, Default, RuntimeDebug, TypeInfo)]
+pub struct DestroyWitness {
+	/// The total number of outstanding instances of this asset class.
+	#[codec(compact)]
+	pub instances: u32,
+	/// The total number of outstanding instance metadata of this asset class.
+	#[codec(compact)]
+	pub instance_metadatas: u32,
+	/// The total number of attributes for this asset class.
+	#[codec(compact)]
+	pub attributes: u32,
+}
+
+/// Information concerning the ownership of a single unique asset.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct InstanceDetails<AccountId, DepositBalance> {
+	/// The owner of this asset.
+	owner: AccountId,
+	/// The approved transferrer of this asset, if one is set.
+	approved: Option<AccountId>,
+	/// Whether the asset can be transferred or not.
+	is_frozen: bool,
+	/// The amount held in the pallet's default account for this asset. Free-hold assets will have
+	/// this as zero.
+	deposit: DepositBalance,
+}
+
+/// The metadata of an asset class.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, Default, RuntimeDebug, TypeInfo)]
+pub struct ClassMetadata<DepositBalance> {
+	/// The balance deposited for this metadata.
+	///
+	/// This pays for the data stored in this struct.
+	deposit: DepositBalance,
+	/// General information concerning this asset class. Limited in length by `StringLimit`. This
+	/// will generally be either a JSON dump or the hash of some JSON which can be found on a
+	/// hash-addressable global publication system such as IPFS.
+	data: Vec<u8>,
+	/// Whether the asset metadata may be changed by a non Force origin.
+	is_frozen: bool,
+}
+
+/// The metadata of an asset instance.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, Default, RuntimeDebug, TypeInfo)]
+pub struct InstanceMetadata<DepositBalance> {
+	/// The balance deposited for this metadata.
+	///
+	/// This pays for the data stored in this struct.
+	deposit: DepositBalance,
+	/// General information concerning this asset instance. Limited in length by `StringLimit`.
+	/// This will generally be either a JSON dump or the hash of some JSON which can be found on a
+	/// hash-addressable global publication system such as IPFS.
+	data: Vec<u8>,
+	/// Whether the asset metadata may be changed by a non Force origin.
+	is_frozen: bool,
+}
+
+decl_storage! {
+	trait Store for Module<T: Trait> as Uniques {
+		/// Details of an asset class.
+		Class get(fn class): map hasher(blake2_128_concat) T::ClassId
+			=> Option<ClassDetailsFor<T>>;
+
+		/// The assets held by any given account; set out this way so that assets owned by a
+		/// single account can be enumerated.
+		Account: double_map
+			hasher(blake2_128_concat) T::AccountId,
+			hasher(blake2_128_concat) (T::ClassId, T::InstanceId)
+			=> Option<()>;
+
+		/// The assets in existence and their ownership details.
+		Asset get(fn asset): double_map
+			hasher(blake2_128_concat) T::ClassId,
+			hasher(blake2_128_concat) T::InstanceId
+			=> Option<InstanceDetailsFor<T>>;
+
+		/// Metadata of an asset class.
+		ClassMetadataOf get(fn class_metadata): map hasher(blake2_128_concat) T::ClassId
+			=> Option<ClassMetadata<DepositBalanceOf<T>>>;
+
+		/// Metadata of an asset instance.
+		InstanceMetadataOf get(fn instance_metadata): double_map
+			hasher(blake2_128_concat) T::ClassId,
+			hasher(blake2_128_concat) T::InstanceId
+			=> Option<InstanceMetadata<DepositBalanceOf<T>>>;
+
+		/// Attributes of an asset class or instance, together with the deposit reserved for them.
+		Attribute: double_map
+			hasher(blake2_128_concat) T::ClassId,
+			hasher(blake2_128_concat) (Option<T::InstanceId>, Vec<u8>)
+			=> Option<(Vec<u8>, DepositBalanceOf<T>)>;
+	}
+}
+
+decl_event! {
+	pub enum Event<T> where
+		<T as frame_system::Trait>::AccountId,
+		<T as Trait>::ClassId,
+		<T as Trait>::InstanceId,
+	{
+		/// An asset class was created. \[class, creator, owner\]
+		Created(ClassId, AccountId, AccountId),
+		/// An asset class was force-created. \[class, owner\]
+		ForceCreated(ClassId, AccountId),
+		/// An asset `class` was destroyed. \[class\]
+		Destroyed(ClassId),
+		/// An asset `instance` was issued. \[class, instance, owner\]
+		Issued(ClassId, InstanceId, AccountId),
+		/// An asset `instance` was transferred. \[class, instance, from, to\]
+		Transferred(ClassId, InstanceId, AccountId, AccountId),
+		/// An asset `instance` was destroyed. \[class, instance, owner\]
+		Burned(ClassId, InstanceId, AccountId),
+		/// Some asset `instance` was frozen. \[class, instance\]
+		Frozen(ClassId, InstanceId),
+		/// Some asset `instance` was thawed. \[class, instance\]
+		Thawed(ClassId, InstanceId),
+		/// Some asset `class` was frozen. \[class\]
+		ClassFrozen(ClassId),
+		/// Some asset `class` was thawed. \[class\]
+		ClassThawed(ClassId),
+		/// The owner changed. \[class, new_owner\]
+		OwnerChanged(ClassId, AccountId),
+		/// The management team changed. \[class, issuer, admin, freezer\]
+		TeamChanged(ClassId, AccountId, AccountId, AccountId),
+		/// An `instance` of an asset `class` has been approved by the `owner` for transfer by a
+		/// `delegate`.
+		/// \[class, instance, owner, delegate\]
+		ApprovedTransfer(ClassId, InstanceId, AccountId, AccountId),
+		/// An approval for a `delegate` account to transfer the `instance` of an asset `class`
+		/// was cancelled by its `owner`.
+		/// \[class, instance, owner, delegate\]
+		ApprovalCancelled(ClassId, InstanceId, AccountId, AccountId),
+		/// An asset `class` has had its attributes changed by the `Force` origin.
+		/// \[class\]
+		AssetStatusChanged(ClassId),
+		/// New metadata has been set for an asset class. \[class, data, is_frozen\]
+		ClassMetadataSet(ClassId, Vec<u8>, bool),
+		/// Metadata has been cleared for an asset class. \[class\]
+		ClassMetadataCleared(ClassId),
+		/// New metadata has been set for an asset instance.
+		/// \[class, instance, data, is_frozen\]
+		MetadataSet(ClassId, InstanceId, Vec<u8>, bool),
+		/// Metadata has been cleared for an asset instance. \[class, instance\]
+		MetadataCleared(ClassId, InstanceId),
+		/// New attribute metadata has been set for an asset class or instance.
+		/// \[class, maybe_instance, key, value\]
+		AttributeSet(ClassId, Option<InstanceId>, Vec<u8>, Vec<u8>),
+		/// Attribute metadata has been cleared for an asset class or instance.
+		/// \[class, maybe_instance, key\]
+		AttributeCleared(ClassId, Option<InstanceId>, Vec<u8>),
+	}
+}
+
+decl_error! {
+	pub enum Error for Module<T: Trait> {
+		/// The signing account has no permission to do the operation.
+		NoPermission,
+		/// The given asset ID is unknown.
+		Unknown,
+		/// The asset instance ID has already been used for an asset.
+		AlreadyExists,
+		/// The owner turned out to be different to what was expected.
+		WrongOwner,
+		/// Invalid witness data given.
+		BadWitness,
+		/// The asset ID is already taken.
+		InUse,
+		/// The asset instance or class is frozen.
+		Frozen,
+		/// The delegate turned out to be different to what was expected.
+		WrongDelegate,
+		/// There is no delegate approved.
+		NoDelegate,
+		/// Invalid metadata given.
+		BadMetadata,
+		/// The attribute key or value is too long.
+		BadAttribute,
+	}
+}
+
+decl_module! {
+	pub struct Module<T: Trait> for enum Call where origin: T::Origin {
+		type Error = Error<T>;
+
+		/// The basic amount of funds that must be reserved for an asset class.
+		const ClassDeposit: DepositBalanceOf<T> = T::ClassDeposit::get();
+
+		/// The basic amount of funds that must be reserved for an asset instance.
+		const InstanceDeposit: DepositBalanceOf<T> = T::InstanceDeposit::get();
+
+		/// The basic amount of funds that must be reserved when adding metadata to an asset
+		/// class or instance.
+		const MetadataDepositBase: DepositBalanceOf<T> = T::MetadataDepositBase::get();
+
+		/// The basic amount of funds that must be reserved when adding an attribute to an asset
+		/// class or instance.
+		const AttributeDepositBase: DepositBalanceOf<T> = T::AttributeDepositBase::get();
+
+		/// The additional funds that must be reserved for the number of bytes stored in metadata
+		/// or in an attribute.
+		const DepositPerByte: DepositBalanceOf<T> = T::DepositPerByte::get();
+
+		/// The maximum length of data stored on-chain as metadata.
+		const StringLimit: u32 = T::StringLimit::get();
+
+		/// The maximum length of an attribute key.
+		const KeyLimit: u32 = T::KeyLimit::get();
+
+		/// The maximum length of an attribute value.
+		const ValueLimit: u32 = T::ValueLimit::get();
+
+		fn deposit_event() = default;
+
+		/// Issue a new class of non-fungible assets from a public origin.
+		///
+		/// This new asset class has no assets initially and its owner is the origin.
+		///
+		/// The origin must be Signed and the sender must have sufficient funds free.
+		///
+		/// `ClassDeposit` funds of sender are reserved.
+		///
+		/// Parameters:
+		/// - `class`: The identifier of the new asset class. This must not be currently in use.
+		/// - `admin`: The admin of this class of assets. The admin is the initial address of each
+		/// member of the asset class's admin team.
+		///
+		/// Emits `Created` event when successful.
+		///
+		/// # <weight>
+		/// - `O(1)`
+		/// - 1 storage read and write.
+		/// - 1 reserve.
+		/// - 1 event.
+		/// # </weight>
+		#[weight = T::WeightInfo::create()]
+		fn create(origin,
+			#[compact] class: T::ClassId,
+			admin: <T::Lookup as StaticLookup>::Source,
+		) {
+			let owner = ensure_signed(origin)?;
+			let admin = T::Lookup::lookup(admin)?;
+
+			let deposit = T::ClassDeposit::get();
+			Self::do_create_class(class, owner.clone(), admin.clone(), deposit, false)?;
+			Self::deposit_event(RawEvent::Created(class, owner, admin));
+		}
+
+		/// Issue a new class of non-fungible assets from a privileged origin.
+		///
+		/// This new asset class has no assets initially.
+		///
+		/// The origin must conform to `ForceOrigin`.
+		///
+		/// Unlike `create`, no funds are reserved.
+		///
+		/// - `class`: The identifier of the new asset. This must not be currently in use.
+		/// - `owner`: The owner of this class of assets. The owner has full superuser permissions
+		/// over this asset, but may later change and configure the permissions using
+		/// `transfer_ownership` and `set_team`.
+		/// - `free_holding`: Whether instances of this class, and their metadata and attributes,
+		/// can be held without any deposit.
+		///
+		/// Emits `ForceCreated` event when successful.
+		///
+		/// # <weight>
+		/// - `O(1)`
+		/// - 1 storage read and write.
+		/// - 1 event.
+		/// # </weight>
+		#[weight = T::WeightInfo::force_create()]
+		fn force_create(origin,
+			#[compact] class: T::ClassId,
+			owner: <T::Lookup as StaticLookup>::Source,
+			free_holding: bool,
+		) {
+			T::ForceOrigin::ensure_origin(origin)?;
+			let owner = T::Lookup::lookup(owner)?;
+
+			Self::do_create_class(class, owner.clone(), owner.clone(), Zero::zero(), free_holding)?;
+			Self::deposit_event(RawEvent::ForceCreated(class, owner));
+		}
+
+		/// Destroy a class of non-fungible assets.
+		///
+		/// The origin must conform to `ForceOrigin` or must be `Signed` and the sender must be the
+		/// owner of the asset `class`.
+		///
+		/// - `class`: The identifier of the asset class to be destroyed.
+		/// - `witness`: Information on the instances minted in the asset class. This must be
+		/// correct.
+		///
+		/// Emits `Destroyed` event when successful.
+		///
+		/// # <weight>
+		/// - `O(n + m + a)` where `n` is the number of instances, `m` the number of instance
+		///   metadata and `a` the number of attributes of the asset class.
+		/// - `2 * n + m + a + 2` storage deletions.
+		/// - 1 unreserve.
+		/// - 1 event.
+		/// # </weight>
+		#[weight = T::WeightInfo::destroy(
+			witness.instances,
+			witness.instance_metadatas,
+			witness.attributes,
+		)]
+		fn destroy(origin,
+			#[compact] class: T::ClassId,
+			witness: DestroyWitness,
+		) -> DispatchResultWithPostInfo {
+			let maybe_check_owner = match T::ForceOrigin::try_origin(origin) {
+				Ok(_) => None,
+				Err(origin) => Some(ensure_signed(origin)?),
+			};
+
+			let class_details = Class::<T>::get(class).ok_or(Error::<T>::Unknown)?;
+			if let Some(check_owner) = maybe_check_owner {
+				ensure!(class_details.owner == check_owner, Error::<T>::NoPermission);
+			}
+			ensure!(class_details.instances <= witness.instances, Error::<T>::BadWitness);
+			ensure!(
+				class_details.instance_metadatas <= witness.instance_metadatas,
+				Error::<T>::BadWitness,
+			);
+			ensure!(class_details.attributes <= witness.attributes, Error::<T>::BadWitness);
+
+			for (instance, details) in Asset::<T>::drain_prefix(&class) {
+				Account::<T>::remove(&details.owner, &(class, instance));
+			}
+			InstanceMetadataOf::<T>::remove_prefix(&class);
+			ClassMetadataOf::<T>::remove(&class);
+			Attribute::<T>::remove_prefix(&class);
+			Class::<T>::remove(&class);
+			T::Currency::unreserve(&class_details.owner, class_details.total_deposit);
+
+			Self::deposit_event(RawEvent::Destroyed(class));
+			Ok(Some(T::WeightInfo::destroy(
+				class_details.instances,
+				class_details.instance_metadatas,
+				class_details.attributes,
+			)).into())
+		}
+
+		/// Mint an asset instance of a particular class.
+		///
+		/// The origin must be Signed and the sender must be the Issuer of the asset `class`.
+		///
+		/// Unless the class is free holding, `InstanceDeposit` funds of the Owner of the class are
+		/// reserved.
+		///
+		/// - `class`: The class of the asset to be minted.
+		/// - `instance`: The instance value of the asset to be minted.
+		/// - `owner`: The initial owner of the minted asset.
+		///
+		/// Emits `Issued` event when successful.
+		///
+		/// # <weight>
+		/// - `O(1)`
+		/// - 3 storage mutations.
+		/// - 1 reserve.
+		/// - 1 event.
+		/// # </weight>
+		#[weight = T::WeightInfo::mint()]
+		fn mint(origin,
+			#[compact] class: T::ClassId,
+			#[compact] instance: T::InstanceId,
+			owner: <T::Lookup as StaticLookup>::Source,
+		) {
+			let origin = ensure_signed(origin)?;
+			let owner = T::Lookup::lookup(owner)?;
+
+			Self::do_mint(class, instance, owner, |class_details| {
+				ensure!(class_details.issuer == origin, Error::<T>::NoPermission);
+				Ok(())
+			})?;
+		}
+
+		/// Destroy a single asset instance.
+		///
+		/// Origin must be Signed and the sender should be the Admin of the asset `class` or the
+		/// owner of the instance.
+		///
+		/// Any deposit of the instance and of its metadata is returned to the Owner of the class.
+		/// Attributes of the instance are left in place until they are cleared or the class is
+		/// destroyed.
+		///
+		/// - `class`: The class of the asset to be burned.
+		/// - `instance`: The instance of the asset to be burned.
+		/// - `check_owner`: If `Some` then the operation will fail with `WrongOwner` unless the
+		///   asset is owned by this value.
+		///
+		/// Emits `Burned` with the actual amount burned.
+		///
+		/// # <weight>
+		/// - `O(1)`
+		/// - 4 storage mutations.
+		/// - 1 unreserve.
+		/// - 1 event.
+		/// # </weight>
+		#[weight = T::WeightInfo::burn()]
+		fn burn(origin,
+			#[compact] class: T::ClassId,
+			#[compact] instance: T::InstanceId,
+			check_owner: Option<<T::Lookup as StaticLookup>::Source>,
+		) {
+			let origin = ensure_signed(origin)?;
+			let check_owner = check_owner.map(T::Lookup::lookup).transpose()?;
+
+			Self::do_burn(class, instance, |class_details, details| {
+				let is_permitted = class_details.admin == origin || details.owner == origin;
+				ensure!(is_permitted, Error::<T>::NoPermission);
+				ensure!(
+					check_owner.map_or(true, |o| o == details.owner),
+					Error::<T>::WrongOwner,
+				);
+				Ok(())
+			})?;
+		}
+
+		/// Move an asset from the sender account to another.
+		///
+		/// Origin must be Signed and the signing account must be either:
+		/// - the Admin of the asset `class`;
+		/// - the Owner of the asset `instance`;
+		/// - the approved delegate for the asset `instance` (in this case, the approval is reset).
+		///
+		/// Arguments:
+		/// - `class`: The class of the asset to be transferred.
+		/// - `instance`: The instance of the asset to be transferred.
+		/// - `dest`: The account to receive ownership of the asset.
+		///
+		/// Emits `Transferred`.
+		///
+		/// # <weight>
+		/// - `O(1)`
+		/// - 3 storage mutations.
+		/// - 1 event.
+		/// # </weight>
+		#[weight = T::WeightInfo::transfer()]
+		fn transfer(origin,
+			#[compact] class: T::ClassId,
+			#[compact] instance: T::InstanceId,
+			dest: <T::Lookup as StaticLookup>::Source,
+		) {
+			let origin = ensure_signed(origin)?;
+			let dest = T::Lookup::lookup(dest)?;
+
+			Self::do_transfer(class, instance, dest, |class_details, details| {
+				if details.owner != origin && class_details.admin != origin {
+					let approved = details.approved.as_ref() == Some(&origin);
+					ensure!(approved, Error::<T>::NoPermission);
+				}
+				Ok(())
+			})?;
+		}
+
+		/// Disallow further unprivileged transfer of an asset instance.
+		///
+		/// Origin must be Signed and the sender should be the Freezer of the asset `class`.
+		///
+		/// - `class`: The class of the asset to be frozen.
+		/// - `instance`: The instance of the asset to be frozen.
+		///
+		/// Emits `Frozen`.
+		///
+		/// # <weight>
+		/// - `O(1)`
+		/// - 1 storage mutation.
+		/// - 1 event.
+		/// # </weight>
+		#[weight = T::WeightInfo::freeze()]
+		fn freeze(origin,
+			#[compact] class: T::ClassId,
+			#[compact] instance: T::InstanceId,
+		) {
+			let origin = ensure_signed(origin)?;
+
+			let mut details = Asset::<T>::get(&class, &instance).ok_or(Error::<T>::Unknown)?;
+			let class_details = Class::<T>::get(&class).ok_or(Error::<T>::Unknown)?;
+			ensure!(class_details.freezer == origin, Error::<T>::NoPermission);
+
+			details.is_frozen = true;
+			Asset::<T>::insert(&class, &instance, &details);
+
+			Self::deposit_event(RawEvent::Frozen(class, instance));
+		}
+
+		/// Re-allow unprivileged transfer of an asset instance.
+		///
+		/// Origin must be Signed and the sender should be the Admin of the asset `class`.
+		///
+		/// - `class`: The class of the asset to be thawed.
+		/// - `instance`: The instance of the asset to be thawed.
+		///
+		/// Emits `Thawed`.
+		///
+		/// # <weight>
+		/// - `O(1)`
+		/// - 1 storage mutation.
+		/// - 1 event.
+		/// # </weight>
+		#[weight = T::WeightInfo::thaw()]
+		fn thaw(origin,
+			#[compact] class: T::ClassId,
+			#[compact] instance: T::InstanceId,
+		) {
+			let origin = ensure_signed(origin)?;
+
+			let mut details = Asset::<T>::get(&class, &instance).ok_or(Error::<T>::Unknown)?;
+			let class_details = Class::<T>::get(&class).ok_or(Error::<T>::Unknown)?;
+			ensure!(class_details.admin == origin, Error::<T>::NoPermission);
+
+			details.is_frozen = false;
+			Asset::<T>::insert(&class, &instance, &details);
+
+			Self::deposit_event(RawEvent::Thawed(class, instance));
+		}
+
+		/// Disallow further unprivileged transfers for a whole asset class.
+		///
+		/// Origin must be Signed and the sender should be the Freezer of the asset `class`.
+		///
+		/// - `class`: The asset class to be frozen.
+		///
+		/// Emits `ClassFrozen`.
+		///
+		/// # <weight>
+		/// - `O(1)`
+		/// - 1 storage mutation.
+		/// - 1 event.
+		/// # </weight>
+		#[weight = T::WeightInfo::freeze_class()]
+		fn freeze_class(origin, #[compact] class: T::ClassId) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+
+			Class::<T>::try_mutate(class, |maybe_details| {
+				let details = maybe_details.as_mut().ok_or(Error::<T>::Unknown)?;
+				ensure!(&origin == &details.freezer, Error::<T>::NoPermission);
+
+				details.is_frozen = true;
+
+				Self::deposit_event(RawEvent::ClassFrozen(class));
+				Ok(())
+			})
+		}
+
+		/// Re-allow unprivileged transfers for a whole asset class.
+		///
+		/// Origin must be Signed and the sender should be the Admin of the asset `class`.
+		///
+		/// - `class`: The class to be thawed.
+		///
+		/// Emits `ClassThawed`.
+		///
+		/// # <weight>
+		/// - `O(1)`
+		/// - 1 storage mutation.
+		/// - 1 event.
+		/// # </weight>
+		#[weight = T::WeightInfo::thaw_class()]
+		fn thaw_class(origin, #[compact] class: T::ClassId) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+
+			Class::<T>::try_mutate(class, |maybe_details| {
+				let details = maybe_details.as_mut().ok_or(Error::<T>::Unknown)?;
+				ensure!(&origin == &details.admin, Error::<T>::NoPermission);
+
+				details.is_frozen = false;
+
+				Self::deposit_event(RawEvent::ClassThawed(class));
+				Ok(())
+			})
+		}
+
+		/// Change the Owner of an asset class.
+		///
+		/// Origin must be Signed and the sender should be the Owner of the asset `class`.
+		///
+		/// All the deposits of the class are moved to the new owner.
+		///
+		/// - `class`: The asset class whose owner should be changed.
+		/// - `owner`: The new Owner of this asset class.
+		///
+		/// Emits `OwnerChanged`.
+		///
+		/// # <weight>
+		/// - `O(1)`
+		/// - 1 storage mutation.
+		/// - 1 repatriation of reserved funds.
+		/// - 1 event.
+		/// # </weight>
+		#[weight = T::WeightInfo::transfer_ownership()]
+		fn transfer_ownership(origin,
+			#[compact] class: T::ClassId,
+			owner: <T::Lookup as StaticLookup>::Source,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			let owner = T::Lookup::lookup(owner)?;
+
+			Class::<T>::try_mutate(class, |maybe_details| {
+				let details = maybe_details.as_mut().ok_or(Error::<T>::Unknown)?;
+				ensure!(&origin == &details.owner, Error::<T>::NoPermission);
+				if details.owner == owner { return Ok(()) }
+
+				// Move the deposit to the new owner.
+				T::Currency::repatriate_reserved(
+					&details.owner,
+					&owner,
+					details.total_deposit,
+					BalanceStatus::Reserved,
+				)?;
+				details.owner = owner.clone();
+
+				Self::deposit_event(RawEvent::OwnerChanged(class, owner));
+				Ok(())
+			})
+		}
+
+		/// Change the Issuer, Admin and Freezer of an asset class.
+		///
+		/// Origin must be Signed and the sender should be the Owner of the asset `class`.
+		///
+		/// - `class`: The asset class whose team should be changed.
+		/// - `issuer`: The new Issuer of this asset class.
+		/// - `admin`: The new Admin of this asset class.
+		/// - `freezer`: The new Freezer of this asset class.
+		///
+		/// Emits `TeamChanged`.
+		///
+		/// # <weight>
+		/// - `O(1)`
+		/// - 1 storage mutation.
+		/// - 1 event.
+		/// # </weight>
+		#[weight = T::WeightInfo::set_team()]
+		fn set_team(origin,
+			#[compact] class: T::ClassId,
+			issuer: <T::Lookup as StaticLookup>::Source,
+			admin: <T::Lookup as StaticLookup>::Source,
+			freezer: <T::Lookup as StaticLookup>::Source,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			let issuer = T::Lookup::lookup(issuer)?;
+			let admin = T::Lookup::lookup(admin)?;
+			let freezer = T::Lookup::lookup(freezer)?;
+
+			Class::<T>::try_mutate(class, |maybe_details| {
+				let details = maybe_details.as_mut().ok_or(Error::<T>::Unknown)?;
+				ensure!(&origin == &details.owner, Error::<T>::NoPermission);
+
+				details.issuer = issuer.clone();
+				details.admin = admin.clone();
+				details.freezer = freezer.clone();
+
+				Self::deposit_event(RawEvent::TeamChanged(class, issuer, admin, freezer));
+				Ok(())
+			})
+		}
+
+		/// Approve an instance to be transferred by a delegated third-party account.
+		///
+		/// Origin must conform to `ForceOrigin` or must be Signed and the sender must be either
+		/// the owner of the asset `instance` or the Admin of the asset `class`.
+		///
+		/// Any previous approval of the instance is replaced.
+		///
+		/// - `class`: The class of the asset to be approved for delegated transfer.
+		/// - `instance`: The instance of the asset to be approved for delegated transfer.
+		/// - `delegate`: The account to delegate permission to transfer the asset.
+		///
+		/// Emits `ApprovedTransfer` on success.
+		///
+		/// # <weight>
+		/// - `O(1)`
+		/// - 1 storage mutation.
+		/// - 1 event.
+		/// # </weight>
+		#[weight = T::WeightInfo::approve_transfer()]
+		fn approve_transfer(origin,
+			#[compact] class: T::ClassId,
+			#[compact] instance: T::InstanceId,
+			delegate: <T::Lookup as StaticLookup>::Source,
+		) {
+			let maybe_check = match T::ForceOrigin::try_origin(origin) {
+				Ok(_) => None,
+				Err(origin) => Some(ensure_signed(origin)?),
+			};
+			let delegate = T::Lookup::lookup(delegate)?;
+
+			let class_details = Class::<T>::get(&class).ok_or(Error::<T>::Unknown)?;
+			let mut details = Asset::<T>::get(&class, &instance).ok_or(Error::<T>::Unknown)?;
+			if let Some(check) = maybe_check {
+				let permitted = check == class_details.admin || check == details.owner;
+				ensure!(permitted, Error::<T>::NoPermission);
+			}
+
+			details.approved = Some(delegate.clone());
+			Asset::<T>::insert(&class, &instance, &details);
+
+			let owner = details.owner;
+			Self::deposit_event(RawEvent::ApprovedTransfer(class, instance, owner, delegate));
+		}
+
+		/// Cancel the prior approval for the transfer of an asset by a delegate.
+		///
+		/// Origin must conform to `ForceOrigin` or must be Signed and the sender must be either
+		/// the owner of the asset `instance` or the Admin of the asset `class`.
+		///
+		/// Arguments:
+		/// - `class`: The class of the asset of whose approval will be cancelled.
+		/// - `instance`: The instance of the asset of whose approval will be cancelled.
+		/// - `maybe_check_delegate`: If `Some` will ensure that the given account is the one to
+		///   which permission of transfer is delegated.
+		///
+		/// Emits `ApprovalCancelled` on success.
+		///
+		/// # <weight>
+		/// - `O(1)`
+		/// - 1 storage mutation.
+		/// - 1 event.
+		/// # </weight>
+		#[weight = T::WeightInfo::cancel_approval()]
+		fn cancel_approval(origin,
+			#[compact] class: T::ClassId,
+			#[compact] instance: T::InstanceId,
+			maybe_check_delegate: Option<<T::Lookup as StaticLookup>::Source>,
+		) {
+			let maybe_check = match T::ForceOrigin::try_origin(origin) {
+				Ok(_) => None,
+				Err(origin) => Some(ensure_signed(origin)?),
+			};
+			let maybe_check_delegate = maybe_check_delegate.map(T::Lookup::lookup).transpose()?;
+
+			let class_details = Class::<T>::get(&class).ok_or(Error::<T>::Unknown)?;
+			let mut details = Asset::<T>::get(&class, &instance).ok_or(Error::<T>::Unknown)?;
+			if let Some(check) = maybe_check {
+				let permitted = check == class_details.admin || check == details.owner;
+				ensure!(permitted, Error::<T>::NoPermission);
+			}
+
+			let old = details.approved.take().ok_or(Error::<T>::NoDelegate)?;
+			if let Some(check_delegate) = maybe_check_delegate {
+				ensure!(check_delegate == old, Error::<T>::WrongDelegate);
+			}
+			Asset::<T>::insert(&class, &instance, &details);
+
+			Self::deposit_event(RawEvent::ApprovalCancelled(class, instance, details.owner, old));
+		}
+
+		/// Alter the attributes of a given asset class.
+		///
+		/// Origin must be `ForceOrigin`.
+		///
+		/// If the owner changes, the class deposit is moved to the new owner.
+		///
+		/// - `class`: The identifier of the asset class.
+		/// - `owner`: The new Owner of this asset class.
+		/// - `issuer`: The new Issuer of this asset class.
+		/// - `admin`: The new Admin of this asset class.
+		/// - `freezer`: The new Freezer of this asset class.
+		/// - `free_holding`: Whether a deposit is taken for holding an instance of this asset
+		///   class.
+		/// - `is_frozen`: Whether this asset class is frozen except for permissioned/admin
+		/// instructions.
+		///
+		/// Emits `AssetStatusChanged` with the identity of the asset class.
+		///
+		/// # <weight>
+		/// - `O(1)`
+		/// - 1 storage mutation.
+		/// - 1 event.
+		/// # </weight>
+		#[weight = T::WeightInfo::force_asset_status()]
+		fn force_asset_status(origin,
+			#[compact] class: T::ClassId,
+			owner: <T::Lookup as StaticLookup>::Source,
+			issuer: <T::Lookup as StaticLookup>::Source,
+			admin: <T::Lookup as StaticLookup>::Source,
+			freezer: <T::Lookup as StaticLookup>::Source,
+			free_holding: bool,
+			is_frozen: bool,
+		) -> DispatchResult {
+			T::ForceOrigin::ensure_origin(origin)?;
+			let owner = T::Lookup::lookup(owner)?;
+			let issuer = T::Lookup::lookup(issuer)?;
+			let admin = T::Lookup::lookup(admin)?;
+			let freezer = T::Lookup::lookup(freezer)?;
+
+			Class::<T>::try_mutate(class, |maybe_details| {
+				let details = maybe_details.as_mut().ok_or(Error::<T>::Unknown)?;
+				if details.owner != owner {
+					// Move the deposit to the new owner.
+					T::Currency::repatriate_reserved(
+						&details.owner,
+						&owner,
+						details.total_deposit,
+						BalanceStatus::Reserved,
+					)?;
+					details.owner = owner;
+				}
+				details.issuer = issuer;
+				details.admin = admin;
+				details.freezer = freezer;
+				details.free_holding = free_holding;
+				details.is_frozen = is_frozen;
+
+				Self::deposit_event(RawEvent::AssetStatusChanged(class));
+				Ok(())
+			})
+		}
+
+		/// Set an attribute for an asset class or instance.
+		///
+		/// Origin must be either `ForceOrigin` or Signed and the sender should be the Owner of
+		/// the asset `class`.
+		///
+		/// If the origin is Signed, then funds of the Owner are reserved according to the
+		/// formula: `AttributeDepositBase + DepositPerByte * (key.len + value.len)` taking into
+		/// account any already reserved funds.
+		///
+		/// The attribute cannot be set by a Signed origin if the metadata of the class or
+		/// instance it belongs to is frozen.
+		///
+		/// - `class`: The identifier of the asset class whose instance's metadata to set.
+		/// - `maybe_instance`: The identifier of the asset instance whose metadata to set, or
+		///   `None` to set an attribute of the class itself.
+		/// - `key`: The key of the attribute. Limited in length by `KeyLimit`.
+		/// - `value`: The value to which to set the attribute. Limited in length by `ValueLimit`.
+		///
+		/// Emits `AttributeSet`.
+		///
+		/// # <weight>
+		/// - `O(K + V)` where K and V are the length of the key and value respectively.
+		/// - 2 storage reads.
+		/// - 2 storage mutations.
+		/// - 1 reserve or unreserve.
+		/// - 1 event.
+		/// # </weight>
+		#[weight = T::WeightInfo::set_attribute()]
+		fn set_attribute(origin,
+			#[compact] class: T::ClassId,
+			maybe_instance: Option<T::InstanceId>,
+			key: Vec<u8>,
+			value: Vec<u8>,
+		) {
+			let maybe_check_owner = match T::ForceOrigin::try_origin(origin) {
+				Ok(_) => None,
+				Err(origin) => Some(ensure_signed(origin)?),
+			};
+
+			Self::do_set_attribute(class, maybe_instance, key, value, maybe_check_owner)?;
+		}
+
+		/// Clear an attribute for an asset class or instance.
+		///
+		/// Origin must be either `ForceOrigin` or Signed and the sender should be the Owner of
+		/// the asset `class`.
+		///
+		/// Any deposit is freed for the asset class Owner.
+		///
+		/// - `class`: The identifier of the asset class whose instance's metadata to clear.
+		/// - `maybe_instance`: The identifier of the asset instance whose metadata to clear, or
+		///   `None` to clear an attribute of the class itself.
+		/// - `key`: The key of the attribute.
+		///
+		/// Emits `AttributeCleared`.
+		///
+		/// # <weight>
+		/// - `O(1)`
+		/// - 2 storage reads.
+		/// - 2 storage mutations.
+		/// - 1 unreserve.
+		/// - 1 event.
+		/// # </weight>
+		#[weight = T::WeightInfo::clear_attribute()]
+		fn clear_attribute(origin,
+			#[compact] class: T::ClassId,
+			maybe_instance: Option<T::InstanceId>,
+			key: Vec<u8>,
+		) {
+			let maybe_check_owner = match T::ForceOrigin::try_origin(origin) {
+				Ok(_) => None,
+				Err(origin) => Some(ensure_signed(origin)?),
+			};
+
+			let mut class_details = Class::<T>::get(&class).ok_or(Error::<T>::Unknown)?;
+			if let Some(check_owner) = &maybe_check_owner {
+				ensure!(check_owner == &class_details.owner, Error::<T>::NoPermission);
+			}
+			if maybe_check_owner.is_some() {
+				ensure!(!Self::is_metadata_frozen(class, maybe_instance), Error::<T>::Frozen);
+			}
+
+			let attribute_key = (maybe_instance, key.clone());
+			if let Some((_, deposit)) = Attribute::<T>::take(&class, &attribute_key) {
+				class_details.attributes = class_details.attributes.saturating_sub(1);
+				class_details.total_deposit = class_details.total_deposit.saturating_sub(deposit);
+				T::Currency::unreserve(&class_details.owner, deposit);
+				Class::<T>::insert(&class, &class_details);
+
+				Self::deposit_event(RawEvent::AttributeCleared(class, maybe_instance, key));
+			}
+		}
+
+		/// Set the metadata for an asset instance.
+		///
+		/// Origin must be either `ForceOrigin` or Signed and the sender should be the Owner of
+		/// the asset `class`.
+		///
+		/// If the origin is Signed, then funds of the Owner are reserved according to the
+		/// formula: `MetadataDepositBase + DepositPerByte * data.len` taking into account any
+		/// already reserved funds.
+		///
+		/// - `class`: The identifier of the asset class whose instance's metadata to set.
+		/// - `instance`: The identifier of the asset instance whose metadata to set.
+		/// - `data`: The general information of this asset. Limited in length by `StringLimit`.
+		/// - `is_frozen`: Whether the metadata should be frozen against further changes by a
+		///   Signed origin.
+		///
+		/// Emits `MetadataSet`.
+		///
+		/// # <weight>
+		/// - `O(D)` where D is the length of the data.
+		/// - 2 storage reads.
+		/// - 2 storage mutations.
+		/// - 1 reserve or unreserve.
+		/// - 1 event.
+		/// # </weight>
+		#[weight = T::WeightInfo::set_metadata()]
+		fn set_metadata(origin,
+			#[compact] class: T::ClassId,
+			#[compact] instance: T::InstanceId,
+			data: Vec<u8>,
+			is_frozen: bool,
+		) {
+			let maybe_check_owner = match T::ForceOrigin::try_origin(origin) {
+				Ok(_) => None,
+				Err(origin) => Some(ensure_signed(origin)?),
+			};
+			ensure!(data.len() <= T::StringLimit::get() as usize, Error::<T>::BadMetadata);
+
+			let mut class_details = Class::<T>::get(&class).ok_or(Error::<T>::Unknown)?;
+			if let Some(check_owner) = &maybe_check_owner {
+				ensure!(check_owner == &class_details.owner, Error::<T>::NoPermission);
+			}
+
+			InstanceMetadataOf::<T>::try_mutate_exists(class, instance, |metadata| -> DispatchResult {
+				let was_frozen = metadata.as_ref().map_or(false, |m| m.is_frozen);
+				ensure!(maybe_check_owner.is_none() || !was_frozen, Error::<T>::Frozen);
+
+				if metadata.is_none() {
+					class_details.instance_metadatas =
+						class_details.instance_metadatas.saturating_add(1);
+				}
+				let old_deposit = metadata.take().map_or(Zero::zero(), |m| m.deposit);
+				let deposit =
+					Self::metadata_deposit(&class_details, &maybe_check_owner, data.len());
+				Self::update_deposit(&mut class_details, old_deposit, deposit)?;
+
+				*metadata = Some(InstanceMetadata { deposit, data: data.clone(), is_frozen });
+				Ok(())
+			})?;
+			Class::<T>::insert(&class, &class_details);
+
+			Self::deposit_event(RawEvent::MetadataSet(class, instance, data, is_frozen));
+		}
+
+		/// Clear the metadata for an asset instance.
+		///
+		/// Origin must be either `ForceOrigin` or Signed and the sender should be the Owner of
+		/// the asset `class`.
+		///
+		/// Any deposit is freed for the asset class Owner.
+		///
+		/// - `class`: The identifier of the asset class whose instance's metadata to clear.
+		/// - `instance`: The identifier of the asset instance whose metadata to clear.
+		///
+		/// Emits `MetadataCleared`.
+		///
+		/// # <weight>
+		/// - `O(1)`
+		/// - 2 storage reads.
+		/// - 2 storage mutations.
+		/// - 1 unreserve.
+		/// - 1 event.
+		/// # </weight>
+		#[weight = T::WeightInfo::clear_metadata()]
+		fn clear_metadata(origin,
+			#[compact] class: T::ClassId,
+			#[compact] instance: T::InstanceId,
+		) {
+			let maybe_check_owner = match T::ForceOrigin::try_origin(origin) {
+				Ok(_) => None,
+				Err(origin) => Some(ensure_signed(origin)?),
+			};
+
+			let mut class_details = Class::<T>::get(&class).ok_or(Error::<T>::Unknown)?;
+			if let Some(check_owner) = &maybe_check_owner {
+				ensure!(check_owner == &class_details.owner, Error::<T>::NoPermission);
+			}
+
+			InstanceMetadataOf::<T>::try_mutate_exists(class, instance, |metadata| -> DispatchResult {
+				let was_frozen = metadata.as_ref().map_or(false, |m| m.is_frozen);
+				ensure!(maybe_check_owner.is_none() || !was_frozen, Error::<T>::Frozen);
+
+				let deposit = metadata.take().ok_or(Error::<T>::Unknown)?.deposit;
+				class_details.instance_metadatas =
+					class_details.instance_metadatas.saturating_sub(1);
+				Self::update_deposit(&mut class_details, deposit, Zero::zero())
+			})?;
+			Class::<T>::insert(&class, &class_details);
+
+			Self::deposit_event(RawEvent::MetadataCleared(class, instance));
+		}
+
+		/// Set the metadata for an asset class.
+		///
+		/// Origin must be either `ForceOrigin` or `Signed` and the sender should be the Owner of
+		/// the asset `class`.
+		///
+		/// If the origin is `Signed`, then funds of the Owner are reserved according to the
+		/// formula: `MetadataDepositBase + DepositPerByte * data.len` taking into account any
+		/// already reserved funds.
+		///
+		/// - `class`: The identifier of the asset whose metadata to update.
+		/// - `data`: The general information of this asset. Limited in length by `StringLimit`.
+		/// - `is_frozen`: Whether the metadata should be frozen against further changes by a
+		///   Signed origin.
+		///
+		/// Emits `ClassMetadataSet`.
+		///
+		/// # <weight>
+		/// - `O(D)` where D is the length of the data.
+		/// - 2 storage reads.
+		/// - 2 storage mutations.
+		/// - 1 reserve or unreserve.
+		/// - 1 event.
+		/// # </weight>
+		#[weight = T::WeightInfo::set_class_metadata()]
+		fn set_class_metadata(origin,
+			#[compact] class: T::ClassId,
+			data: Vec<u8>,
+			is_frozen: bool,
+		) {
+			let maybe_check_owner = match T::ForceOrigin::try_origin(origin) {
+				Ok(_) => None,
+				Err(origin) => Some(ensure_signed(origin)?),
+			};
+			ensure!(data.len() <= T::StringLimit::get() as usize, Error::<T>::BadMetadata);
+
+			let mut class_details = Class::<T>::get(&class).ok_or(Error::<T>::Unknown)?;
+			if let Some(check_owner) = &maybe_check_owner {
+				ensure!(check_owner == &class_details.owner, Error::<T>::NoPermission);
+			}
+
+			ClassMetadataOf::<T>::try_mutate_exists(class, |metadata| -> DispatchResult {
+				let was_frozen = metadata.as_ref().map_or(false, |m| m.is_frozen);
+				ensure!(maybe_check_owner.is_none() || !was_frozen, Error::<T>::Frozen);
+
+				let old_deposit = metadata.take().map_or(Zero::zero(), |m| m.deposit);
+				let deposit =
+					Self::metadata_deposit(&class_details, &maybe_check_owner, data.len());
+				Self::update_deposit(&mut class_details, old_deposit, deposit)?;
+
+				*metadata = Some(ClassMetadata { deposit, data: data.clone(), is_frozen });
+				Ok(())
+			})?;
+			Class::<T>::insert(&class, &class_details);
+
+			Self::deposit_event(RawEvent::ClassMetadataSet(class, data, is_frozen));
+		}
+
+		/// Clear the metadata for an asset class.
+		///
+		/// Origin must be either `ForceOrigin` or `Signed` and the sender should be the Owner of
+		/// the asset `class`.
+		///
+		/// Any deposit is freed for the asset class Owner.
+		///
+		/// - `class`: The identifier of the asset class whose metadata to clear.
+		///
+		/// Emits `ClassMetadataCleared`.
+		///
+		/// # <weight>
+		/// - `O(1)`
+		/// - 2 storage reads.
+		/// - 2 storage mutations.
+		/// - 1 unreserve.
+		/// - 1 event.
+		/// # </weight>
+		#[weight = T::WeightInfo::clear_class_metadata()]
+		fn clear_class_metadata(origin, #[compact] class: T::ClassId) {
+			let maybe_check_owner = match T::ForceOrigin::try_origin(origin) {
+				Ok(_) => None,
+				Err(origin) => Some(ensure_signed(origin)?),
+			};
+
+			let mut class_details = Class::<T>::get(&class).ok_or(Error::<T>::Unknown)?;
+			if let Some(check_owner) = &maybe_check_owner {
+				ensure!(check_owner == &class_details.owner, Error::<T>::NoPermission);
+			}
+
+			ClassMetadataOf::<T>::try_mutate_exists(class, |metadata| -> DispatchResult {
+				let was_frozen = metadata.as_ref().map_or(false, |m| m.is_frozen);
+				ensure!(maybe_check_owner.is_none() || !was_frozen, Error::<T>::Frozen);
+
+				let deposit = metadata.take().ok_or(Error::<T>::Unknown)?.deposit;
+				Self::update_deposit(&mut class_details, deposit, Zero::zero())
+			})?;
+			Class::<T>::insert(&class, &class_details);
+
+			Self::deposit_event(RawEvent::ClassMetadataCleared(class));
+		}
+	}
+}
+
+impl<T: Trait> Module<T> {
+	// Public immutables
+
+	/// Get the owner of the asset `instance` of `class`, if the asset exists.
+	pub fn owner(class: T::ClassId, instance: T::InstanceId) -> Option<T::AccountId> {
+		Asset::<T>::get(class, instance).map(|i| i.owner)
+	}
+
+	/// Get the Owner of the asset `class`, if it exists.
+	pub fn class_owner(class: T::ClassId) -> Option<T::AccountId> {
+		Class::<T>::get(class).map(|i| i.owner)
+	}
+
+	// Internal functions
+
+	/// Create the asset `class` owned by `owner` and managed by `admin`, reserving `deposit`
+	/// from `owner`.
+	pub(crate) fn do_create_class(
+		class: T::ClassId,
+		owner: T::AccountId,
+		admin: T::AccountId,
+		deposit: DepositBalanceOf<T>,
+		free_holding: bool,
+	) -> DispatchResult {
+		ensure!(!Class::<T>::contains_key(class), Error::<T>::InUse);
+
+		T::Currency::reserve(&owner, deposit)?;
+
+		Class::<T>::insert(class, ClassDetails {
+			owner,
+			issuer: admin.clone(),
+			admin: admin.clone(),
+			freezer: admin,
+			total_deposit: deposit,
+			free_holding,
+			instances: 0,
+			instance_metadatas: 0,
+			attributes: 0,
+			is_frozen: false,
+		});
+		Ok(())
+	}
+
+	/// Mint the asset `instance` of `class` into the account of `owner`, once `with_details`
+	/// accepts the details of the class.
+	pub(crate) fn do_mint(
+		class: T::ClassId,
+		instance: T::InstanceId,
+		owner: T::AccountId,
+		with_details: impl FnOnce(&ClassDetailsFor<T>) -> DispatchResult,
+	) -> DispatchResult {
+		ensure!(!Asset::<T>::contains_key(class, instance), Error::<T>::AlreadyExists);
+
+		Class::<T>::try_mutate(&class, |maybe_class_details| -> DispatchResult {
+			let class_details = maybe_class_details.as_mut().ok_or(Error::<T>::Unknown)?;
+
+			with_details(&class_details)?;
+
+			let deposit = match class_details.free_holding {
+				true => Zero::zero(),
+				false => T::InstanceDeposit::get(),
+			};
+			T::Currency::reserve(&class_details.owner, deposit)?;
+			class_details.total_deposit = class_details.total_deposit.saturating_add(deposit);
+			class_details.instances = class_details.instances.saturating_add(1);
+
+			Account::<T>::insert(&owner, &(class, instance), ());
+			let details = InstanceDetails {
+				owner: owner.clone(),
+				approved: None,
+				is_frozen: false,
+				deposit,
+			};
+			Asset::<T>::insert(&class, &instance, details);
+			Ok(())
+		})?;
+
+		Self::deposit_event(RawEvent::Issued(class, instance, owner));
+		Ok(())
+	}
+
+	/// Burn the asset `instance` of `class`, once `with_details` accepts the details of the
+	/// class and the instance.
+	pub(crate) fn do_burn(
+		class: T::ClassId,
+		instance: T::InstanceId,
+		with_details: impl FnOnce(&ClassDetailsFor<T>, &InstanceDetailsFor<T>) -> DispatchResult,
+	) -> DispatchResult {
+		let owner = Class::<T>::try_mutate(
+			&class,
+			|maybe_class_details| -> Result<T::AccountId, DispatchError> {
+				let class_details = maybe_class_details.as_mut().ok_or(Error::<T>::Unknown)?;
+				let details = Asset::<T>::get(&class, &instance).ok_or(Error::<T>::Unknown)?;
+				with_details(&class_details, &details)?;
+
+				// Return the deposits of the instance and of its metadata.
+				let metadata_deposit = match InstanceMetadataOf::<T>::take(&class, &instance) {
+					Some(metadata) => {
+						class_details.instance_metadatas =
+							class_details.instance_metadatas.saturating_sub(1);
+						metadata.deposit
+					},
+					None => Zero::zero(),
+				};
+				let deposit = details.deposit.saturating_add(metadata_deposit);
+				T::Currency::unreserve(&class_details.owner, deposit);
+				class_details.total_deposit = class_details.total_deposit.saturating_sub(deposit);
+				class_details.instances = class_details.instances.saturating_sub(1);
+				Ok(details.owner)
+			},
+		)?;
+
+		Asset::<T>::remove(&class, &instance);
+		Account::<T>::remove(&owner, &(class, instance));
+
+		Self::deposit_event(RawEvent::Burned(class, instance, owner));
+		Ok(())
+	}
+
+	/// Transfer the asset `instance` of `class` to `dest`, once `with_details` accepts the
+	/// details of the class and the instance. Any approval of the instance is reset.
+	pub(crate) fn do_transfer(
+		class: T::ClassId,
+		instance: T::InstanceId,
+		dest: T::AccountId,
+		with_details: impl FnOnce(&ClassDetailsFor<T>, &InstanceDetailsFor<T>) -> DispatchResult,
+	) -> DispatchResult {
+		let class_details = Class::<T>::get(&class).ok_or(Error::<T>::Unknown)?;
+		ensure!(!class_details.is_frozen, Error::<T>::Frozen);
+
+		let mut details = Asset::<T>::get(&class, &instance).ok_or(Error::<T>::Unknown)?;
+		ensure!(!details.is_frozen, Error::<T>::Frozen);
+		with_details(&class_details, &details)?;
+
+		Account::<T>::remove(&details.owner, &(class, instance));
+		Account::<T>::insert(&dest, &(class, instance), ());
+		let origin = sp_std::mem::replace(&mut details.owner, dest.clone());
+
+		// The approved delegate only holds the permission for the previous owner.
+		details.approved = None;
+		Asset::<T>::insert(&class, &instance, &details);
+
+		Self::deposit_event(RawEvent::Transferred(class, instance, origin, dest));
+		Ok(())
+	}
+
+	/// Set the attribute `key` of `class`, or of its instance `maybe_instance`, to `value`.
+	///
+	/// If `maybe_check_owner` is given, it must be the Owner of the class, the metadata that the
+	/// attribute belongs to must not be frozen and a deposit is taken unless the class is free
+	/// holding.
+	pub(crate) fn do_set_attribute(
+		class: T::ClassId,
+		maybe_instance: Option<T::InstanceId>,
+		key: Vec<u8>,
+		value: Vec<u8>,
+		maybe_check_owner: Option<T::AccountId>,
+	) -> DispatchResult {
+		ensure!(key.len() <= T::KeyLimit::get() as usize, Error::<T>::BadAttribute);
+		ensure!(value.len() <= T::ValueLimit::get() as usize, Error::<T>::BadAttribute);
+
+		let mut class_details = Class::<T>::get(&class).ok_or(Error::<T>::Unknown)?;
+		if let Some(check_owner) = &maybe_check_owner {
+			ensure!(check_owner == &class_details.owner, Error::<T>::NoPermission);
+			ensure!(!Self::is_metadata_frozen(class, maybe_instance), Error::<T>::Frozen);
+		}
+
+		let attribute_key = (maybe_instance, key.clone());
+		let old_deposit = match Attribute::<T>::get(&class, &attribute_key) {
+			Some((_, deposit)) => deposit,
+			None => {
+				class_details.attributes = class_details.attributes.saturating_add(1);
+				Zero::zero()
+			},
+		};
+		let deposit = if !class_details.free_holding && maybe_check_owner.is_some() {
+			T::DepositPerByte::get()
+				.saturating_mul(((key.len() + value.len()) as u32).into())
+				.saturating_add(T::AttributeDepositBase::get())
+		} else {
+			Zero::zero()
+		};
+		Self::update_deposit(&mut class_details, old_deposit, deposit)?;
+
+		Attribute::<T>::insert(&class, &attribute_key, (value.clone(), deposit));
+		Class::<T>::insert(&class, &class_details);
+
+		Self::deposit_event(RawEvent::AttributeSet(class, maybe_instance, key, value));
+		Ok(())
+	}
+
+	/// Whether the metadata of `class`, or of its instance `maybe_instance`, is frozen.
+	fn is_metadata_frozen(class: T::ClassId, maybe_instance: Option<T::InstanceId>) -> bool {
+		match maybe_instance {
+			None => ClassMetadataOf::<T>::get(class).map_or(false, |m| m.is_frozen),
+			Some(instance) =>
+				InstanceMetadataOf::<T>::get(class, instance).map_or(false, |m| m.is_frozen),
+		}
+	}
+
+	/// The deposit needed for `len` bytes of metadata of the class with `class_details`.
+	///
+	/// No deposit is needed when the metadata is set by the forcing origin, i.e. without
+	/// `maybe_check_owner`, or for a free holding class.
+	fn metadata_deposit(
+		class_details: &ClassDetailsFor<T>,
+		maybe_check_owner: &Option<T::AccountId>,
+		len: usize,
+	) -> DepositBalanceOf<T> {
+		if class_details.free_holding || maybe_check_owner.is_none() {
+			return Zero::zero()
+		}
+		T::DepositPerByte::get()
+			.saturating_mul((len as u32).into())
+			.saturating_add(T::MetadataDepositBase::get())
+	}
+
+	/// Replace the `old` deposit held by the Owner of a class for some item with a `new` one,
+	/// reserving or unreserving the difference.
+	fn update_deposit(
+		class_details: &mut ClassDetailsFor<T>,
+		old: DepositBalanceOf<T>,
+		new: DepositBalanceOf<T>,
+	) -> DispatchResult {
+		if new > old {
+			T::Currency::reserve(&class_details.owner, new - old)?;
+		} else if new < old {
+			T::Currency::unreserve(&class_details.owner, old - new);
+		}
+		class_details.total_deposit =
+			class_details.total_deposit.saturating_add(new).saturating_sub(old);
+		Ok(())
+	}
+}
@@ -0,0 +1,762 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2020 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// Tests for Uniques Pallet
+
+#![cfg(test)]
+
+use super::*;
+
+use frame_support::{
+	assert_ok, assert_noop, impl_outer_origin, impl_outer_event, parameter_types, weights::Weight,
+	traits::nonfungibles::{self, Inspect as _, InspectEnumerable as _},
+};
+use frame_system::EnsureRoot;
+use sp_core::H256;
+use sp_runtime::{Perbill, traits::{BadOrigin, BlakeTwo256, IdentityLookup}, testing::Header};
+use pallet_balances::Error as BalancesError;
+use crate as uniques;
+
+impl_outer_origin! {
+	pub enum Origin for Test where system = frame_system {}
+}
+impl_outer_event! {
+	pub enum TestEvent for Test {
+		frame_system<T>,
+		pallet_balances<T>,
+		uniques<T>,
+	}
+}
+
+#[derive(Clone, Eq, PartialEq)]
+pub struct Test;
+parameter_types! {
+	pub const BlockHashCount: u64 = 250;
+	pub const MaximumBlockWeight: Weight = 1024;
+	pub const MaximumBlockLength: u32 = 2 * 1024;
+	pub const AvailableBlockRatio: Perbill = Perbill::one();
+}
+impl frame_system::Trait for Test {
+	type BaseCallFilter = ();
+	type Origin = Origin;
+	type Index = u64;
+	type Call = ();
+	type BlockNumber = u64;
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type AccountId = u64;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type Header = Header;
+	type Event = TestEvent;
+	type BlockHashCount = BlockHashCount;
+	type MaximumBlockWeight = MaximumBlockWeight;
+	type DbWeight = ();
+	type BlockExecutionWeight = ();
+	type ExtrinsicBaseWeight = ();
+	type MaximumExtrinsicWeight = MaximumBlockWeight;
+	type AvailableBlockRatio = AvailableBlockRatio;
+	type MaximumBlockLength = MaximumBlockLength;
+	type Version = ();
+	type PalletInfo = ();
+	type AccountData = pallet_balances::AccountData<u64>;
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+}
+parameter_types! {
+	pub const ExistentialDeposit: u64 = 1;
+}
+impl pallet_balances::Trait for Test {
+	type Balance = u64;
+	type Event = TestEvent;
+	type DustRemoval = ();
+	type ExistentialDeposit = ExistentialDeposit;
+	type AccountStore = System;
+	type MaxLocks = ();
+	type MaxReserves = ();
+	type ReserveIdentifier = [u8; 8];
+	type WeightInfo = ();
+}
+parameter_types! {
+	pub const ClassDeposit: u64 = 2;
+	pub const InstanceDeposit: u64 = 1;
+	pub const KeyLimit: u32 = 50;
+	pub const ValueLimit: u32 = 50;
+	pub const StringLimit: u32 = 50;
+	pub const MetadataDepositBase: u64 = 1;
+	pub const AttributeDepositBase: u64 = 1;
+	pub const DepositPerByte: u64 = 1;
+}
+impl Trait for Test {
+	type Event = TestEvent;
+	type ClassId = u32;
+	type InstanceId = u32;
+	type Currency = Balances;
+	type ForceOrigin = EnsureRoot<u64>;
+	type ClassDeposit = ClassDeposit;
+	type InstanceDeposit = InstanceDeposit;
+	type MetadataDepositBase = MetadataDepositBase;
+	type AttributeDepositBase = AttributeDepositBase;
+	type DepositPerByte = DepositPerByte;
+	type StringLimit = StringLimit;
+	type KeyLimit = KeyLimit;
+	type ValueLimit = ValueLimit;
+	type WeightInfo = ();
+}
+type System = frame_system::Module<Test>;
+type Balances = pallet_balances::Module<Test>;
+type Uniques = Module<Test>;
+
+pub(crate) fn new_test_ext() -> sp_io::TestExternalities {
+	let t = frame_system::GenesisConfig::default().build_storage::<Test>().unwrap();
+	let mut ext = sp_io::TestExternalities::new(t);
+	ext.execute_with(|| System::set_block_number(1));
+	ext
+}
+
+fn last_event() -> TestEvent {
+	System::events().pop().expect("Event expected").event
+}
+
+fn assets() -> Vec<(u64, u32, u32)> {
+	let mut r: Vec<_> = Account::<Test>::iter()
+		.map(|(who, (class, instance), _)| (who, class, instance))
+		.collect();
+	r.sort();
+	let mut s: Vec<_> = Asset::<Test>::iter()
+		.map(|(class, instance, details)| (details.owner, class, instance))
+		.collect();
+	s.sort();
+	assert_eq!(r, s);
+	let mut classes: Vec<_> = Asset::<Test>::iter().map(|x| x.0).collect();
+	classes.sort();
+	classes.dedup();
+	for class in classes {
+		let details = Class::<Test>::get(class).unwrap();
+		let instances = Asset::<Test>::iter_prefix(class).count() as u32;
+		assert_eq!(details.instances, instances);
+	}
+	r
+}
+
+fn witness(class: u32) -> DestroyWitness {
+	Uniques::class(class).unwrap().destroy_witness()
+}
+
+#[test]
+fn basic_setup_works() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(assets(), vec![]);
+	});
+}
+
+#[test]
+fn basic_minting_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Uniques::force_create(Origin::root(), 0, 1, true));
+		assert_eq!(last_event(), TestEvent::uniques(RawEvent::ForceCreated(0, 1)));
+		assert_ok!(Uniques::mint(Origin::signed(1), 0, 42, 1));
+		assert_eq!(last_event(), TestEvent::uniques(RawEvent::Issued(0, 42, 1)));
+		assert_eq!(assets(), vec![(1, 0, 42)]);
+
+		assert_ok!(Uniques::force_create(Origin::root(), 1, 2, true));
+		assert_ok!(Uniques::mint(Origin::signed(2), 1, 69, 1));
+		assert_eq!(assets(), vec![(1, 0, 42), (1, 1, 69)]);
+		assert_eq!(Uniques::owner(1, 69), Some(1));
+		assert_eq!(Uniques::class_owner(1), Some(2));
+	});
+}
+
+#[test]
+fn lifecycle_should_work() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&1, 100);
+		assert_ok!(Uniques::create(Origin::signed(1), 0, 1));
+		assert_eq!(Balances::reserved_balance(&1), 2);
+
+		assert_ok!(Uniques::set_class_metadata(Origin::signed(1), 0, vec![0, 0], false));
+		assert_eq!(Balances::reserved_balance(&1), 5);
+		assert!(ClassMetadataOf::<Test>::contains_key(0));
+
+		assert_ok!(Uniques::mint(Origin::signed(1), 0, 42, 10));
+		assert_eq!(Balances::reserved_balance(&1), 6);
+		assert_ok!(Uniques::mint(Origin::signed(1), 0, 69, 20));
+		assert_eq!(Balances::reserved_balance(&1), 7);
+		assert_eq!(assets(), vec![(10, 0, 42), (20, 0, 69)]);
+		assert_eq!(Class::<Test>::get(0).unwrap().instances, 2);
+		assert_eq!(Class::<Test>::get(0).unwrap().instance_metadatas, 0);
+
+		assert_ok!(Uniques::set_metadata(Origin::signed(1), 0, 42, vec![42, 42], false));
+		assert_eq!(Balances::reserved_balance(&1), 10);
+		assert!(InstanceMetadataOf::<Test>::contains_key(0, 42));
+		assert_ok!(Uniques::set_metadata(Origin::signed(1), 0, 69, vec![69, 69], false));
+		assert_eq!(Balances::reserved_balance(&1), 13);
+		assert!(InstanceMetadataOf::<Test>::contains_key(0, 69));
+
+		let w = Class::<Test>::get(0).unwrap().destroy_witness();
+		assert_eq!(w.instances, 2);
+		assert_eq!(w.instance_metadatas, 2);
+		assert_ok!(Uniques::destroy(Origin::signed(1), 0, w));
+		assert_eq!(Balances::reserved_balance(&1), 0);
+
+		assert!(!Class::<Test>::contains_key(0));
+		assert!(!Asset::<Test>::contains_key(0, 42));
+		assert!(!Asset::<Test>::contains_key(0, 69));
+		assert!(!ClassMetadataOf::<Test>::contains_key(0));
+		assert!(!InstanceMetadataOf::<Test>::contains_key(0, 42));
+		assert!(!InstanceMetadataOf::<Test>::contains_key(0, 69));
+		assert_eq!(assets(), vec![]);
+	});
+}
+
+#[test]
+fn destroy_with_bad_witness_should_not_work() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&1, 100);
+		assert_ok!(Uniques::create(Origin::signed(1), 0, 1));
+
+		let w = Class::<Test>::get(0).unwrap().destroy_witness();
+		assert_ok!(Uniques::mint(Origin::signed(1), 0, 42, 1));
+		assert_noop!(Uniques::destroy(Origin::signed(1), 0, w), Error::<Test>::BadWitness);
+	});
+}
+
+#[test]
+fn destroy_should_respect_permissions() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&1, 100);
+		assert_ok!(Uniques::create(Origin::signed(1), 0, 2));
+		assert_noop!(Uniques::destroy(Origin::signed(2), 0, witness(0)), Error::<Test>::NoPermission);
+		assert_noop!(Uniques::destroy(Origin::signed(1), 1, witness(0)), Error::<Test>::Unknown);
+		assert_ok!(Uniques::destroy(Origin::root(), 0, witness(0)));
+		assert_eq!(Balances::reserved_balance(&1), 0);
+		assert_eq!(last_event(), TestEvent::uniques(RawEvent::Destroyed(0)));
+	});
+}
+
+#[test]
+fn mint_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Uniques::force_create(Origin::root(), 0, 1, true));
+		assert_ok!(Uniques::mint(Origin::signed(1), 0, 42, 1));
+		assert_eq!(Uniques::owner(0, 42).unwrap(), 1);
+		assert_eq!(assets(), vec![(1, 0, 42)]);
+	});
+}
+
+#[test]
+fn mint_should_respect_permissions_and_uniqueness() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Uniques::force_create(Origin::root(), 0, 1, true));
+		assert_noop!(Uniques::mint(Origin::signed(2), 0, 42, 1), Error::<Test>::NoPermission);
+		assert_noop!(Uniques::mint(Origin::signed(1), 1, 42, 1), Error::<Test>::Unknown);
+		assert_ok!(Uniques::mint(Origin::signed(1), 0, 42, 1));
+		assert_noop!(Uniques::mint(Origin::signed(1), 0, 42, 2), Error::<Test>::AlreadyExists);
+	});
+}
+
+#[test]
+fn mint_should_reserve_instance_deposit_unless_free_holding() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&1, 100);
+		assert_ok!(Uniques::force_create(Origin::root(), 0, 1, false));
+		assert_ok!(Uniques::mint(Origin::signed(1), 0, 42, 2));
+		assert_eq!(Balances::reserved_balance(&1), 1);
+		assert_eq!(Class::<Test>::get(0).unwrap().total_deposit, 1);
+
+		assert_ok!(Uniques::force_create(Origin::root(), 1, 1, true));
+		assert_ok!(Uniques::mint(Origin::signed(1), 1, 42, 2));
+		assert_eq!(Balances::reserved_balance(&1), 1);
+
+		assert_ok!(Uniques::force_create(Origin::root(), 2, 3, false));
+		assert_noop!(
+			Uniques::mint(Origin::signed(3), 2, 42, 2),
+			BalancesError::<Test, _>::InsufficientBalance,
+		);
+	});
+}
+
+#[test]
+fn burn_should_work() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&1, 100);
+		assert_ok!(Uniques::force_create(Origin::root(), 0, 1, false));
+		assert_ok!(Uniques::set_team(Origin::signed(1), 0, 2, 3, 4));
+		assert_ok!(Uniques::mint(Origin::signed(2), 0, 42, 5));
+		assert_ok!(Uniques::mint(Origin::signed(2), 0, 69, 5));
+		assert_ok!(Uniques::set_metadata(Origin::signed(1), 0, 42, vec![42], false));
+		assert_eq!(Balances::reserved_balance(&1), 4);
+
+		assert_noop!(Uniques::burn(Origin::signed(0), 0, 42, None), Error::<Test>::NoPermission);
+		assert_noop!(Uniques::burn(Origin::signed(5), 0, 42, Some(6)), Error::<Test>::WrongOwner);
+
+		assert_ok!(Uniques::burn(Origin::signed(5), 0, 42, Some(5)));
+		assert_eq!(last_event(), TestEvent::uniques(RawEvent::Burned(0, 42, 5)));
+		assert!(!InstanceMetadataOf::<Test>::contains_key(0, 42));
+		assert_eq!(Class::<Test>::get(0).unwrap().instance_metadatas, 0);
+		assert_ok!(Uniques::burn(Origin::signed(3), 0, 69, Some(5)));
+		assert_eq!(Balances::reserved_balance(&1), 0);
+		assert_eq!(Class::<Test>::get(0).unwrap().total_deposit, 0);
+		assert_eq!(assets(), vec![]);
+	});
+}
+
+#[test]
+fn transfer_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Uniques::force_create(Origin::root(), 0, 1, true));
+		assert_ok!(Uniques::mint(Origin::signed(1), 0, 42, 2));
+
+		assert_ok!(Uniques::transfer(Origin::signed(2), 0, 42, 3));
+		assert_eq!(last_event(), TestEvent::uniques(RawEvent::Transferred(0, 42, 2, 3)));
+		assert_eq!(assets(), vec![(3, 0, 42)]);
+		assert_noop!(Uniques::transfer(Origin::signed(2), 0, 42, 4), Error::<Test>::NoPermission);
+
+		assert_ok!(Uniques::approve_transfer(Origin::signed(3), 0, 42, 2));
+		assert_ok!(Uniques::transfer(Origin::signed(2), 0, 42, 4));
+		assert_eq!(assets(), vec![(4, 0, 42)]);
+		assert_eq!(Asset::<Test>::get(0, 42).unwrap().approved, None);
+
+		// The admin may transfer any instance.
+		assert_ok!(Uniques::transfer(Origin::signed(1), 0, 42, 5));
+		assert_eq!(assets(), vec![(5, 0, 42)]);
+	});
+}
+
+#[test]
+fn freezing_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Uniques::force_create(Origin::root(), 0, 1, true));
+		assert_ok!(Uniques::mint(Origin::signed(1), 0, 42, 1));
+		assert_ok!(Uniques::freeze(Origin::signed(1), 0, 42));
+		assert_noop!(Uniques::transfer(Origin::signed(1), 0, 42, 2), Error::<Test>::Frozen);
+		assert!(!<Uniques as nonfungibles::Inspect<_>>::can_transfer(&0, &42));
+
+		assert_ok!(Uniques::thaw(Origin::signed(1), 0, 42));
+		assert_ok!(Uniques::freeze_class(Origin::signed(1), 0));
+		assert_noop!(Uniques::transfer(Origin::signed(1), 0, 42, 2), Error::<Test>::Frozen);
+
+		assert_ok!(Uniques::thaw_class(Origin::signed(1), 0));
+		assert!(<Uniques as nonfungibles::Inspect<_>>::can_transfer(&0, &42));
+		assert_ok!(Uniques::transfer(Origin::signed(1), 0, 42, 2));
+	});
+}
+
+#[test]
+fn origin_guards_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Uniques::force_create(Origin::root(), 0, 1, true));
+		assert_ok!(Uniques::mint(Origin::signed(1), 0, 42, 1));
+		assert_noop!(Uniques::transfer_ownership(Origin::signed(2), 0, 2), Error::<Test>::NoPermission);
+		assert_noop!(Uniques::set_team(Origin::signed(2), 0, 2, 2, 2), Error::<Test>::NoPermission);
+		assert_noop!(Uniques::freeze(Origin::signed(2), 0, 42), Error::<Test>::NoPermission);
+		assert_noop!(Uniques::thaw(Origin::signed(2), 0, 42), Error::<Test>::NoPermission);
+		assert_noop!(Uniques::freeze_class(Origin::signed(2), 0), Error::<Test>::NoPermission);
+		assert_noop!(Uniques::thaw_class(Origin::signed(2), 0), Error::<Test>::NoPermission);
+		assert_noop!(Uniques::mint(Origin::signed(2), 0, 69, 2), Error::<Test>::NoPermission);
+		assert_noop!(Uniques::burn(Origin::signed(2), 0, 42, None), Error::<Test>::NoPermission);
+		assert_noop!(Uniques::destroy(Origin::signed(2), 0, witness(0)), Error::<Test>::NoPermission);
+		assert_noop!(
+			Uniques::set_attribute(Origin::signed(2), 0, None, vec![0], vec![0]),
+			Error::<Test>::NoPermission,
+		);
+		assert_noop!(
+			Uniques::set_metadata(Origin::signed(2), 0, 42, vec![0], false),
+			Error::<Test>::NoPermission,
+		);
+	});
+}
+
+#[test]
+fn transfer_owner_should_work() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&1, 100);
+		Balances::make_free_balance_be(&2, 100);
+		Balances::make_free_balance_be(&3, 100);
+		assert_ok!(Uniques::create(Origin::signed(1), 0, 1));
+		assert_ok!(Uniques::transfer_ownership(Origin::signed(1), 0, 2));
+		assert_eq!(Balances::total_balance(&1), 98);
+		assert_eq!(Balances::total_balance(&2), 102);
+		assert_eq!(Balances::reserved_balance(&1), 0);
+		assert_eq!(Balances::reserved_balance(&2), 2);
+
+		assert_noop!(Uniques::transfer_ownership(Origin::signed(1), 0, 1), Error::<Test>::NoPermission);
+
+		// Mint and set metadata now and make sure that deposit gets transferred back.
+		assert_ok!(Uniques::set_class_metadata(Origin::signed(2), 0, vec![0u8; 20], false));
+		assert_ok!(Uniques::mint(Origin::signed(1), 0, 42, 1));
+		assert_ok!(Uniques::set_metadata(Origin::signed(2), 0, 42, vec![0u8; 20], false));
+		assert_ok!(Uniques::transfer_ownership(Origin::signed(2), 0, 3));
+		assert_eq!(Balances::total_balance(&2), 57);
+		assert_eq!(Balances::total_balance(&3), 145);
+		assert_eq!(Balances::reserved_balance(&2), 0);
+		assert_eq!(Balances::reserved_balance(&3), 45);
+		assert_eq!(last_event(), TestEvent::uniques(RawEvent::OwnerChanged(0, 3)));
+	});
+}
+
+#[test]
+fn set_team_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Uniques::force_create(Origin::root(), 0, 1, true));
+		assert_ok!(Uniques::set_team(Origin::signed(1), 0, 2, 3, 4));
+		assert_eq!(last_event(), TestEvent::uniques(RawEvent::TeamChanged(0, 2, 3, 4)));
+
+		assert_ok!(Uniques::mint(Origin::signed(2), 0, 42, 2));
+		assert_ok!(Uniques::freeze(Origin::signed(4), 0, 42));
+		assert_ok!(Uniques::thaw(Origin::signed(3), 0, 42));
+		assert_ok!(Uniques::transfer(Origin::signed(3), 0, 42, 3));
+		assert_ok!(Uniques::burn(Origin::signed(3), 0, 42, None));
+	});
+}
+
+#[test]
+fn set_class_metadata_should_work() {
+	new_test_ext().execute_with(|| {
+		// Cannot add metadata to unknown asset
+		assert_noop!(
+			Uniques::set_class_metadata(Origin::signed(1), 0, vec![0u8; 20], false),
+			Error::<Test>::Unknown,
+		);
+		assert_ok!(Uniques::force_create(Origin::root(), 0, 1, false));
+		// Cannot add metadata to unowned asset
+		assert_noop!(
+			Uniques::set_class_metadata(Origin::signed(2), 0, vec![0u8; 20], false),
+			Error::<Test>::NoPermission,
+		);
+
+		// Successfully add metadata and take deposit
+		Balances::make_free_balance_be(&1, 30);
+		assert_ok!(Uniques::set_class_metadata(Origin::signed(1), 0, vec![0u8; 20], false));
+		assert_eq!(Balances::free_balance(&1), 9);
+		assert!(ClassMetadataOf::<Test>::contains_key(0));
+
+		// Force origin works, too.
+		assert_ok!(Uniques::set_class_metadata(Origin::root(), 0, vec![0u8; 18], false));
+
+		// Update deposit
+		assert_ok!(Uniques::set_class_metadata(Origin::signed(1), 0, vec![0u8; 15], false));
+		assert_eq!(Balances::free_balance(&1), 14);
+		assert_ok!(Uniques::set_class_metadata(Origin::signed(1), 0, vec![0u8; 25], false));
+		assert_eq!(Balances::free_balance(&1), 4);
+
+		// Cannot over-reserve
+		assert_noop!(
+			Uniques::set_class_metadata(Origin::signed(1), 0, vec![0u8; 40], false),
+			BalancesError::<Test, _>::InsufficientBalance,
+		);
+
+		// Can't set or clear metadata once frozen
+		assert_ok!(Uniques::set_class_metadata(Origin::signed(1), 0, vec![0u8; 15], true));
+		assert_noop!(
+			Uniques::set_class_metadata(Origin::signed(1), 0, vec![0u8; 15], false),
+			Error::<Test>::Frozen,
+		);
+		assert_noop!(Uniques::clear_class_metadata(Origin::signed(1), 0), Error::<Test>::Frozen);
+
+		// Clear Metadata
+		assert_ok!(Uniques::set_class_metadata(Origin::root(), 0, vec![0u8; 15], false));
+		assert_noop!(Uniques::clear_class_metadata(Origin::signed(2), 0), Error::<Test>::NoPermission);
+		assert_noop!(Uniques::clear_class_metadata(Origin::signed(1), 1), Error::<Test>::Unknown);
+		assert_ok!(Uniques::clear_class_metadata(Origin::signed(1), 0));
+		assert!(!ClassMetadataOf::<Test>::contains_key(0));
+		assert_eq!(Balances::free_balance(&1), 30);
+	});
+}
+
+#[test]
+fn set_instance_metadata_should_work() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&1, 30);
+
+		// Cannot add metadata to unknown asset
+		assert_noop!(
+			Uniques::set_metadata(Origin::signed(1), 0, 42, vec![0u8; 20], false),
+			Error::<Test>::Unknown,
+		);
+		assert_ok!(Uniques::force_create(Origin::root(), 0, 1, false));
+		assert_ok!(Uniques::mint(Origin::signed(1), 0, 42, 1));
+		// Cannot add metadata to unowned asset
+		assert_noop!(
+			Uniques::set_metadata(Origin::signed(2), 0, 42, vec![0u8; 20], false),
+			Error::<Test>::NoPermission,
+		);
+		// Cannot add oversized metadata
+		assert_noop!(
+			Uniques::set_metadata(Origin::signed(1), 0, 42, vec![0u8; 51], false),
+			Error::<Test>::BadMetadata,
+		);
+
+		// Successfully add metadata and take deposit
+		assert_ok!(Uniques::set_metadata(Origin::signed(1), 0, 42, vec![0u8; 20], false));
+		assert_eq!(Balances::free_balance(&1), 8);
+		assert!(InstanceMetadataOf::<Test>::contains_key(0, 42));
+
+		// Update deposit
+		assert_ok!(Uniques::set_metadata(Origin::signed(1), 0, 42, vec![0u8; 15], false));
+		assert_eq!(Balances::free_balance(&1), 13);
+		assert_ok!(Uniques::set_metadata(Origin::signed(1), 0, 42, vec![0u8; 25], false));
+		assert_eq!(Balances::free_balance(&1), 3);
+
+		// Cannot over-reserve
+		assert_noop!(
+			Uniques::set_metadata(Origin::signed(1), 0, 42, vec![0u8; 40], false),
+			BalancesError::<Test, _>::InsufficientBalance,
+		);
+
+		// Can't set or clear metadata once frozen
+		assert_ok!(Uniques::set_metadata(Origin::signed(1), 0, 42, vec![0u8; 15], true));
+		assert_noop!(
+			Uniques::set_metadata(Origin::signed(1), 0, 42, vec![0u8; 15], false),
+			Error::<Test>::Frozen,
+		);
+		assert_noop!(Uniques::clear_metadata(Origin::signed(1), 0, 42), Error::<Test>::Frozen);
+
+		// Clear Metadata
+		assert_ok!(Uniques::set_metadata(Origin::root(), 0, 42, vec![0u8; 15], false));
+		assert_noop!(Uniques::clear_metadata(Origin::signed(2), 0, 42), Error::<Test>::NoPermission);
+		assert_noop!(Uniques::clear_metadata(Origin::signed(1), 1, 42), Error::<Test>::Unknown);
+		assert_ok!(Uniques::clear_metadata(Origin::signed(1), 0, 42));
+		assert!(!InstanceMetadataOf::<Test>::contains_key(0, 42));
+		assert_eq!(Balances::free_balance(&1), 29);
+	});
+}
+
+#[test]
+fn set_attribute_should_work() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&1, 100);
+
+		assert_ok!(Uniques::force_create(Origin::root(), 0, 1, false));
+
+		assert_ok!(Uniques::set_attribute(Origin::signed(1), 0, None, vec![0], vec![0]));
+		assert_ok!(Uniques::set_attribute(Origin::signed(1), 0, Some(0), vec![0], vec![0]));
+		assert_ok!(Uniques::set_attribute(Origin::signed(1), 0, Some(0), vec![1], vec![0]));
+		assert_eq!(Attribute::<Test>::iter_prefix(0).count(), 3);
+		assert_eq!(Class::<Test>::get(0).unwrap().attributes, 3);
+		assert_eq!(Class::<Test>::get(0).unwrap().total_deposit, 9);
+		assert_eq!(Balances::reserved_balance(1), 9);
+
+		assert_ok!(Uniques::set_attribute(Origin::signed(1), 0, None, vec![0], vec![0; 10]));
+		assert_eq!(Attribute::<Test>::get(0, (None::<u32>, vec![0])).unwrap(), (vec![0; 10], 12));
+		assert_eq!(Class::<Test>::get(0).unwrap().total_deposit, 18);
+		assert_eq!(Balances::reserved_balance(1), 18);
+
+		assert_ok!(Uniques::clear_attribute(Origin::signed(1), 0, Some(0), vec![1]));
+		assert_eq!(Attribute::<Test>::iter_prefix(0).count(), 2);
+		assert_eq!(Class::<Test>::get(0).unwrap().attributes, 2);
+		assert_eq!(Class::<Test>::get(0).unwrap().total_deposit, 15);
+		assert_eq!(Balances::reserved_balance(1), 15);
+		assert_eq!(last_event(), TestEvent::uniques(RawEvent::AttributeCleared(0, Some(0), vec![1])));
+
+		assert_noop!(
+			Uniques::set_attribute(Origin::signed(1), 0, None, vec![0; 51], vec![0]),
+			Error::<Test>::BadAttribute,
+		);
+		assert_noop!(
+			Uniques::set_attribute(Origin::signed(1), 0, None, vec![0], vec![0; 51]),
+			Error::<Test>::BadAttribute,
+		);
+
+		let w = Class::<Test>::get(0).unwrap().destroy_witness();
+		assert_ok!(Uniques::destroy(Origin::signed(1), 0, w));
+		assert_eq!(Attribute::<Test>::iter_prefix(0).count(), 0);
+		assert_eq!(Balances::reserved_balance(1), 0);
+	});
+}
+
+#[test]
+fn set_attribute_should_respect_freeze() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&1, 100);
+
+		assert_ok!(Uniques::force_create(Origin::root(), 0, 1, false));
+
+		assert_ok!(Uniques::set_attribute(Origin::signed(1), 0, None, vec![0], vec![0]));
+		assert_ok!(Uniques::set_attribute(Origin::signed(1), 0, Some(0), vec![0], vec![0]));
+		assert_ok!(Uniques::set_attribute(Origin::signed(1), 0, Some(1), vec![0], vec![0]));
+		assert_eq!(Attribute::<Test>::iter_prefix(0).count(), 3);
+		assert_eq!(Class::<Test>::get(0).unwrap().attributes, 3);
+		assert_eq!(Class::<Test>::get(0).unwrap().total_deposit, 9);
+		assert_eq!(Balances::reserved_balance(1), 9);
+
+		assert_ok!(Uniques::set_class_metadata(Origin::signed(1), 0, vec![], true));
+		assert_noop!(
+			Uniques::set_attribute(Origin::signed(1), 0, None, vec![0], vec![0]),
+			Error::<Test>::Frozen,
+		);
+		assert_noop!(
+			Uniques::clear_attribute(Origin::signed(1), 0, None, vec![0]),
+			Error::<Test>::Frozen,
+		);
+		assert_ok!(Uniques::set_attribute(Origin::signed(1), 0, Some(0), vec![0], vec![1]));
+
+		assert_ok!(Uniques::mint(Origin::signed(1), 0, 0, 1));
+		assert_ok!(Uniques::set_metadata(Origin::signed(1), 0, 0, vec![], true));
+		assert_noop!(
+			Uniques::set_attribute(Origin::signed(1), 0, Some(0), vec![0], vec![1]),
+			Error::<Test>::Frozen,
+		);
+		assert_ok!(Uniques::set_attribute(Origin::signed(1), 0, Some(1), vec![0], vec![1]));
+
+		// The forcing origin is not bound by frozen metadata and takes no deposit.
+		assert_ok!(Uniques::set_attribute(Origin::root(), 0, Some(0), vec![0], vec![2]));
+		assert_eq!(Attribute::<Test>::get(0, (Some(0), vec![0])).unwrap(), (vec![2], 0));
+	});
+}
+
+#[test]
+fn force_asset_status_should_work() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&1, 100);
+
+		assert_ok!(Uniques::force_create(Origin::root(), 0, 1, false));
+		assert_ok!(Uniques::mint(Origin::signed(1), 0, 42, 1));
+		assert_ok!(Uniques::mint(Origin::signed(1), 0, 69, 2));
+		assert_ok!(Uniques::set_class_metadata(Origin::signed(1), 0, vec![0; 20], false));
+		assert_ok!(Uniques::set_metadata(Origin::signed(1), 0, 42, vec![0; 20], false));
+		assert_ok!(Uniques::set_metadata(Origin::signed(1), 0, 69, vec![0; 20], false));
+		assert_eq!(Balances::reserved_balance(1), 65);
+
+		// force free holding and freeze the class.
+		assert_noop!(
+			Uniques::force_asset_status(Origin::signed(1), 0, 1, 1, 1, 1, true, false),
+			BadOrigin,
+		);
+		assert_ok!(Uniques::force_asset_status(Origin::root(), 0, 1, 1, 1, 1, true, true));
+		assert_eq!(last_event(), TestEvent::uniques(RawEvent::AssetStatusChanged(0)));
+		assert_noop!(Uniques::transfer(Origin::signed(1), 0, 42, 2), Error::<Test>::Frozen);
+
+		// Newly minted instances and metadata take no deposit.
+		assert_ok!(Uniques::mint(Origin::signed(1), 0, 142, 1));
+		assert_ok!(Uniques::mint(Origin::signed(1), 0, 169, 2));
+		assert_ok!(Uniques::set_metadata(Origin::signed(1), 0, 142, vec![0; 20], false));
+		assert_ok!(Uniques::set_metadata(Origin::signed(1), 0, 169, vec![0; 20], false));
+		assert_eq!(Balances::reserved_balance(1), 65);
+
+		// Existing deposits are only returned once their item is removed.
+		assert_ok!(Uniques::set_metadata(Origin::signed(1), 0, 42, vec![0; 20], false));
+		assert_eq!(Balances::reserved_balance(1), 44);
+		assert_ok!(Uniques::clear_class_metadata(Origin::signed(1), 0));
+		assert_eq!(Balances::reserved_balance(1), 23);
+	});
+}
+
+#[test]
+fn force_asset_status_moves_deposit_to_new_owner() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&1, 100);
+		Balances::make_free_balance_be(&2, 100);
+		assert_ok!(Uniques::create(Origin::signed(1), 0, 1));
+		assert_ok!(Uniques::set_class_metadata(Origin::signed(1), 0, vec![0; 20], false));
+		assert_eq!(Balances::reserved_balance(1), 23);
+
+		assert_ok!(Uniques::force_asset_status(Origin::root(), 0, 2, 1, 1, 1, false, false));
+		assert_eq!(Balances::total_balance(&1), 77);
+		assert_eq!(Balances::reserved_balance(&1), 0);
+		assert_eq!(Balances::reserved_balance(&2), 23);
+
+		// The new owner gets the deposit back when clearing the metadata.
+		assert_ok!(Uniques::clear_class_metadata(Origin::signed(2), 0));
+		assert_eq!(Balances::reserved_balance(&2), 2);
+		assert_eq!(Balances::free_balance(&2), 121);
+	});
+}
+
+#[test]
+fn approval_lifecycle_works() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Uniques::force_create(Origin::root(), 0, 1, true));
+		assert_ok!(Uniques::mint(Origin::signed(1), 0, 42, 2));
+		assert_ok!(Uniques::approve_transfer(Origin::signed(2), 0, 42, 3));
+		assert_eq!(last_event(), TestEvent::uniques(RawEvent::ApprovedTransfer(0, 42, 2, 3)));
+		assert_ok!(Uniques::transfer(Origin::signed(3), 0, 42, 4));
+		assert_noop!(Uniques::transfer(Origin::signed(3), 0, 42, 3), Error::<Test>::NoPermission);
+		assert!(Asset::<Test>::get(0, 42).unwrap().approved.is_none());
+
+		assert_ok!(Uniques::approve_transfer(Origin::signed(4), 0, 42, 2));
+		assert_ok!(Uniques::transfer(Origin::signed(2), 0, 42, 2));
+		assert_eq!(assets(), vec![(2, 0, 42)]);
+	});
+}
+
+#[test]
+fn cancel_approval_works() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Uniques::force_create(Origin::root(), 0, 1, true));
+		assert_ok!(Uniques::mint(Origin::signed(1), 0, 42, 2));
+
+		assert_noop!(Uniques::cancel_approval(Origin::signed(2), 0, 42, None), Error::<Test>::NoDelegate);
+
+		assert_ok!(Uniques::approve_transfer(Origin::signed(2), 0, 42, 3));
+		assert_noop!(Uniques::cancel_approval(Origin::signed(2), 1, 42, None), Error::<Test>::Unknown);
+		assert_noop!(Uniques::cancel_approval(Origin::signed(2), 0, 43, None), Error::<Test>::Unknown);
+		assert_noop!(Uniques::cancel_approval(Origin::signed(3), 0, 42, None), Error::<Test>::NoPermission);
+		assert_noop!(
+			Uniques::cancel_approval(Origin::signed(2), 0, 42, Some(4)),
+			Error::<Test>::WrongDelegate,
+		);
+
+		assert_ok!(Uniques::cancel_approval(Origin::signed(2), 0, 42, Some(3)));
+		assert_eq!(last_event(), TestEvent::uniques(RawEvent::ApprovalCancelled(0, 42, 2, 3)));
+		assert_noop!(Uniques::cancel_approval(Origin::signed(2), 0, 42, None), Error::<Test>::NoDelegate);
+
+		// The admin and the forcing origin may cancel approvals as well.
+		assert_ok!(Uniques::approve_transfer(Origin::signed(2), 0, 42, 3));
+		assert_ok!(Uniques::cancel_approval(Origin::signed(1), 0, 42, None));
+		assert_ok!(Uniques::approve_transfer(Origin::root(), 0, 42, 3));
+		assert_ok!(Uniques::cancel_approval(Origin::root(), 0, 42, None));
+	});
+}
+
+#[test]
+fn nonfungibles_traits_work() {
+	new_test_ext().execute_with(|| {
+		use nonfungibles::{Create, Mutate, Transfer};
+
+		Balances::make_free_balance_be(&1, 100);
+		assert_ok!(<Uniques as Create<_>>::create_class(&0, &1, &1));
+		assert_eq!(Balances::reserved_balance(1), 2);
+		assert_eq!(<Uniques as nonfungibles::Inspect<_>>::class_owner(&0), Some(1));
+
+		assert_ok!(<Uniques as Mutate<_>>::mint_into(&0, &42, &2));
+		assert_ok!(<Uniques as Mutate<_>>::mint_into(&0, &69, &2));
+		assert_noop!(<Uniques as Mutate<_>>::mint_into(&0, &42, &3), Error::<Test>::AlreadyExists);
+		assert_eq!(<Uniques as nonfungibles::Inspect<_>>::owner(&0, &42), Some(2));
+
+		assert_ok!(<Uniques as Mutate<_>>::set_typed_attribute(&0, &42, &b"level", &7u32));
+		assert_ok!(<Uniques as Mutate<_>>::set_class_attribute(&0, b"name", b"kitties"));
+		assert_eq!(Uniques::typed_attribute::<_, u32>(&0, &42, &b"level"), Some(7));
+		assert_eq!(Uniques::class_attribute(&0, b"name"), Some(b"kitties".to_vec()));
+		assert_eq!(Uniques::attribute(&0, &69, b"level"), None);
+
+		assert_ok!(<Uniques as Transfer<_>>::transfer(&0, &42, &3));
+		assert_eq!(assets(), vec![(2, 0, 69), (3, 0, 42)]);
+
+		let mut instances: Vec<_> = Uniques::instances(&0).collect();
+		instances.sort();
+		assert_eq!(instances, vec![42, 69]);
+		assert_eq!(Uniques::classes().collect::<Vec<_>>(), vec![0]);
+		assert_eq!(Uniques::owned(&3).collect::<Vec<_>>(), vec![(0, 42)]);
+		assert_eq!(Uniques::owned_in_class(&0, &2).collect::<Vec<_>>(), vec![69]);
+		assert_eq!(Uniques::owned_in_class(&1, &2).count(), 0);
+
+		assert_ok!(<Uniques as Mutate<_>>::burn_from(&0, &42));
+		assert_eq!(assets(), vec![(2, 0, 69)]);
+		assert_eq!(Balances::reserved_balance(1), 3);
+	});
+}
@@ -23,11 +23,13 @@
 //!
 //! ## Overview
 //!
-//! This module contains two basic pieces of functionality:
+//! This module contains three basic pieces of functionality:
 //! - Batch dispatch: A stateless operation, allowing any origin to execute multiple calls in a
 //!   single dispatch. This can be useful to amalgamate proposals, combining `set_code` with
 //!   corresponding `set_storage`s, for efficient multiple payouts with just a single signature
-//!   verify, or in combination with one of the other two dispatch functionality.
+//!   verify, or in combination with one of the other two dispatch functionality. The calls may
+//!   either be dispatched one after another until the first failure (`batch`), or atomically,
+//!   such that all of them are reverted should any of them fail (`batch_all`).
 //! - Pseudonymal dispatch: A stateless operation, allowing a signed origin to execute a call from
 //!   an alternative signed origin. Each account has 2 * 2**16 possible "pseudonyms" (alternative
 //!   account IDs) and these can be stacked. This can be useful as a key management tool, where you
@@ -35,6 +37,9 @@
 //!   it's perfectly fine to have each of them controlled by the same underlying keypair.
 //!   Derivative accounts are, for the purposes of proxy filtering considered exactly the same as
 //!   the oigin and are thus hampered with the origin's filters.
+//! - Dispatch as origin: A privileged operation, allowing the root origin to execute a call from
+//!   any other origin of the runtime. This can be useful for governance to act on behalf of a
+//!   pallet or account.
 //!
 //! Since proxy filters are respected in all dispatches of this module, it should never need to be
 //! filtered by any proxy.
@@ -45,10 +50,15 @@
 //!
 //! #### For batch dispatch
 //! * `batch` - Dispatch multiple calls from the sender's origin.
+//! * `batch_all` - Dispatch multiple calls from the sender's origin, reverting all of them if any
+//!   fails.
 //!
 //! #### For pseudonymal dispatch
 //! * `as_derivative` - Dispatch a call from a derivative signed origin.
 //!
+//! #### For dispatch as origin
+//! * `dispatch_as` - Dispatch a call from a given origin. Root only.
+//!
 //! [`Call`]: ./enum.Call.html
 //! [`Trait`]: ./trait.Trait.html
 
@@ -59,10 +69,11 @@ use sp_std::prelude::*;
 use codec::{Encode, Decode};
 use sp_core::TypeId;
 use sp_io::hashing::blake2_256;
-use frame_support::{decl_module, decl_event, decl_storage, Parameter};
+use frame_support::{decl_module, decl_event, decl_storage, Parameter, transactional};
 use frame_support::{
 	traits::{OriginTrait, UnfilteredDispatchable, Get},
-	weights::{Weight, GetDispatchInfo, DispatchClass}, dispatch::PostDispatchInfo,
+	weights::{Weight, GetDispatchInfo, DispatchClass, extract_actual_weight},
+	dispatch::{PostDispatchInfo, DispatchResultWithPostInfo},
 };
 use frame_system::{ensure_signed, ensure_root};
 use sp_runtime::{DispatchError, DispatchResult, traits::Dispatchable};
@@ -74,6 +85,8 @@ mod default_weights;
 pub trait WeightInfo {
 	fn batch(c: u32, ) -> Weight;
 	fn as_derivative() -> Weight;
+	fn batch_all(c: u32, ) -> Weight;
+	fn dispatch_as() -> Weight;
 }
 
 /// Configuration trait.
@@ -86,6 +99,10 @@ pub trait Trait: frame_system::Trait {
 		+ GetDispatchInfo + From<frame_system::Call<Self>>
 		+ UnfilteredDispatchable<Origin=Self::Origin>;
 
+	/// The caller origin, overarching type of all pallets origins.
+	type PalletsOrigin: Parameter + Into<<Self as frame_system::Trait>::Origin>
+		+ From<frame_system::RawOrigin<Self::AccountId>>;
+
 	/// Weight information for extrinsics in this pallet.
 	type WeightInfo: WeightInfo;
 }
@@ -102,6 +119,8 @@ decl_event! {
 		BatchInterrupted(u32, DispatchError),
 		/// Batch of dispatches completed fully with no error.
 		BatchCompleted,
+		/// A call was dispatched from a given origin. \[result\]
+		DispatchedAs(DispatchResult),
 	}
 }
 
@@ -197,6 +216,91 @@ decl_module! {
 			origin.set_caller_from(frame_system::RawOrigin::Signed(pseudonym));
 			call.dispatch(origin).map(|_| ()).map_err(|e| e.error)
 		}
+
+		/// Send a batch of dispatch calls and atomically execute them.
+		/// The whole transaction will rollback and fail if any of the calls failed.
+		///
+		/// May be called from any origin.
+		///
+		/// - `calls`: The calls to be dispatched from the same origin.
+		///
+		/// If origin is root then call are dispatch without checking origin filter. (This includes
+		/// bypassing `frame_system::Trait::BaseCallFilter`).
+		///
+		/// # <weight>
+		/// - Base weight: 14.39 + .987 * c µs
+		/// - Plus the sum of the weights of the `calls`.
+		/// - Plus one additional event. (repeat read/write)
+		/// # </weight>
+		///
+		/// The actual weight of the dispatched calls is refunded, and on failure only the weight of
+		/// the calls made up to and including the failing one is charged.
+		#[weight = (
+			calls.iter()
+				.map(|call| call.get_dispatch_info().weight)
+				.fold(0, |total: Weight, weight: Weight| total.saturating_add(weight))
+				.saturating_add(T::WeightInfo::batch_all(calls.len() as u32)),
+			{
+				let all_operational = calls.iter()
+					.map(|call| call.get_dispatch_info().class)
+					.all(|class| class == DispatchClass::Operational);
+				if all_operational {
+					DispatchClass::Operational
+				} else {
+					DispatchClass::Normal
+				}
+			},
+		)]
+		#[transactional]
+		fn batch_all(origin, calls: Vec<<T as Trait>::Call>) -> DispatchResultWithPostInfo {
+			let is_root = ensure_root(origin.clone()).is_ok();
+			let calls_len = calls.len();
+			// Track the actual weight of each of the batch calls.
+			let mut weight: Weight = 0;
+			for (index, call) in calls.into_iter().enumerate() {
+				let info = call.get_dispatch_info();
+				let result = if is_root {
+					call.dispatch_bypass_filter(origin.clone())
+				} else {
+					call.dispatch(origin.clone())
+				};
+				weight = weight.saturating_add(extract_actual_weight(&result, &info));
+				result.map_err(|mut err| {
+					// Take the weight of this function itself into account.
+					let base_weight = T::WeightInfo::batch_all(index.saturating_add(1) as u32);
+					// Return the actual used weight + base_weight of this call.
+					err.post_info = Some(base_weight.saturating_add(weight)).into();
+					err
+				})?;
+			}
+			Self::deposit_event(Event::BatchCompleted);
+			let base_weight = T::WeightInfo::batch_all(calls_len as u32);
+			Ok(Some(base_weight.saturating_add(weight)).into())
+		}
+
+		/// Dispatches a function call with a provided origin.
+		///
+		/// The call is dispatched without checking the origin filter. (This includes bypassing
+		/// `frame_system::Trait::BaseCallFilter`).
+		///
+		/// The dispatch origin for this call must be _Root_.
+		///
+		/// This will return `Ok` if the origin is root. The result of the call is deposited in the
+		/// `DispatchedAs` event.
+		///
+		/// # <weight>
+		/// - O(1).
+		/// - Plus the weight of the `call`.
+		/// # </weight>
+		#[weight = (
+			T::WeightInfo::dispatch_as().saturating_add(call.get_dispatch_info().weight),
+			call.get_dispatch_info().class,
+		)]
+		fn dispatch_as(origin, as_origin: Box<T::PalletsOrigin>, call: Box<<T as Trait>::Call>) {
+			ensure_root(origin)?;
+			let res = call.dispatch_bypass_filter((*as_origin).into());
+			Self::deposit_event(Event::DispatchedAs(res.map(|_| ()).map_err(|e| e.error)));
+		}
 	}
 }
 
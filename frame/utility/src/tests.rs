@@ -23,7 +23,8 @@ use super::*;
 
 use frame_support::{
 	assert_ok, assert_noop, impl_outer_origin, parameter_types, impl_outer_dispatch,
-	weights::Weight, impl_outer_event, dispatch::DispatchError, traits::Filter, storage,
+	weights::{Weight, Pays}, impl_outer_event, dispatch::{DispatchError, DispatchErrorWithPostInfo},
+	traits::Filter, storage,
 };
 use sp_core::H256;
 use sp_runtime::{Perbill, traits::{BlakeTwo256, IdentityLookup}, testing::Header};
@@ -90,6 +91,8 @@ parameter_types! {
 }
 impl pallet_balances::Trait for Test {
 	type MaxLocks = ();
+	type MaxReserves = ();
+	type ReserveIdentifier = [u8; 8];
 	type Balance = u64;
 	type DustRemoval = ();
 	type Event = TestEvent;
@@ -116,6 +119,7 @@ impl Filter<Call> for TestBaseCallFilter {
 impl Trait for Test {
 	type Event = TestEvent;
 	type Call = Call;
+	type PalletsOrigin = OriginCaller;
 	type WeightInfo = ();
 }
 type System = frame_system::Module<Test>;
@@ -255,3 +259,123 @@ fn batch_weight_calculation_doesnt_overflow() {
 		assert_eq!(batch_call.get_dispatch_info().weight, Weight::max_value());
 	});
 }
+
+#[test]
+fn batch_all_works() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(Balances::free_balance(1), 10);
+		assert_eq!(Balances::free_balance(2), 10);
+		assert_ok!(
+			Utility::batch_all(Origin::signed(1), vec![
+				Call::Balances(BalancesCall::transfer(2, 5)),
+				Call::Balances(BalancesCall::transfer(2, 5))
+			]),
+		);
+		assert_eq!(Balances::free_balance(1), 0);
+		assert_eq!(Balances::free_balance(2), 20);
+		expect_event(Event::BatchCompleted);
+	});
+}
+
+#[test]
+fn batch_all_revert() {
+	new_test_ext().execute_with(|| {
+		let call = Call::Balances(BalancesCall::transfer(2, 5));
+		let info = call.get_dispatch_info();
+
+		assert_eq!(Balances::free_balance(1), 10);
+		assert_eq!(Balances::free_balance(2), 10);
+		assert_noop!(
+			Utility::batch_all(Origin::signed(1), vec![
+				Call::Balances(BalancesCall::transfer(2, 5)),
+				Call::Balances(BalancesCall::transfer(2, 10)),
+				Call::Balances(BalancesCall::transfer(2, 5)),
+			]),
+			DispatchErrorWithPostInfo {
+				post_info: PostDispatchInfo {
+					actual_weight: Some(<Test as Trait>::WeightInfo::batch_all(2) + info.weight * 2),
+					pays_fee: Pays::Yes,
+				},
+				error: BalancesError::<Test, _>::InsufficientBalance.into(),
+			}
+		);
+		assert_eq!(Balances::free_balance(1), 10);
+		assert_eq!(Balances::free_balance(2), 10);
+	});
+}
+
+#[test]
+fn batch_all_with_signed_filters() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			Utility::batch_all(Origin::signed(1), vec![
+				Call::Balances(BalancesCall::transfer(2, 5)),
+				Call::System(frame_system::Call::suicide()),
+			]).map_err(|e| e.error),
+			DispatchError::BadOrigin
+		);
+	});
+}
+
+#[test]
+fn batch_all_with_root_bypasses_filters() {
+	new_test_ext().execute_with(|| {
+		let k = b"a".to_vec();
+		let call = Call::System(frame_system::Call::set_storage(vec![(k.clone(), k.clone())]));
+		assert!(!TestBaseCallFilter::filter(&call));
+		assert_ok!(Utility::batch_all(Origin::root(), vec![
+			Call::Balances(BalancesCall::force_transfer(1, 2, 5)),
+			call,
+		]));
+		assert_eq!(Balances::free_balance(2), 15);
+		assert_eq!(storage::unhashed::get_raw(&k), Some(k));
+	});
+}
+
+#[test]
+fn batch_all_weight_calculation_doesnt_overflow() {
+	new_test_ext().execute_with(|| {
+		let big_call = Call::System(SystemCall::fill_block(Perbill::from_percent(50)));
+		let batch_all_call = Call::Utility(crate::Call::batch_all(vec![
+			big_call.clone(),
+			big_call.clone(),
+			big_call.clone(),
+		]));
+		assert_eq!(batch_all_call.get_dispatch_info().weight, Weight::max_value());
+	});
+}
+
+#[test]
+fn dispatch_as_works() {
+	new_test_ext().execute_with(|| {
+		let call = Box::new(Call::Balances(BalancesCall::transfer(2, 5)));
+		let as_origin: Box<OriginCaller> = Box::new(frame_system::RawOrigin::Signed(1).into());
+		assert_noop!(
+			Utility::dispatch_as(Origin::signed(1), as_origin.clone(), call.clone()),
+			DispatchError::BadOrigin
+		);
+		assert_ok!(Utility::dispatch_as(Origin::root(), as_origin.clone(), call.clone()));
+		expect_event(Event::DispatchedAs(Ok(())));
+		assert_eq!(Balances::free_balance(1), 5);
+		assert_eq!(Balances::free_balance(2), 15);
+
+		// the result of the call is reported through the event.
+		let call = Box::new(Call::Balances(BalancesCall::transfer(2, 10)));
+		assert_ok!(Utility::dispatch_as(Origin::root(), as_origin, call));
+		let error = DispatchError::from(BalancesError::<Test, _>::InsufficientBalance).stripped();
+		expect_event(Event::DispatchedAs(Err(error)));
+	});
+}
+
+#[test]
+fn dispatch_as_bypasses_filters() {
+	new_test_ext().execute_with(|| {
+		let k = b"a".to_vec();
+		let call = Call::System(frame_system::Call::set_storage(vec![(k.clone(), k.clone())]));
+		assert!(!TestBaseCallFilter::filter(&call));
+		let as_origin: Box<OriginCaller> = Box::new(frame_system::RawOrigin::Root.into());
+		assert_ok!(Utility::dispatch_as(Origin::root(), as_origin, Box::new(call)));
+		expect_event(Event::DispatchedAs(Ok(())));
+		assert_eq!(storage::unhashed::get_raw(&k), Some(k));
+	});
+}
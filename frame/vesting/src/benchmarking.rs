@@ -40,21 +40,25 @@ fn add_locks<T: Trait>(who: &T::AccountId, n: u8) {
 	}
 }
 
-fn add_vesting_schedule<T: Trait>(who: &T::AccountId) -> Result<(), &'static str> {
-	let locked = 100;
-	let per_block = 10;
-	let starting_block = 1;
+fn add_vesting_schedules<T: Trait>(who: &T::AccountId, n: u32) -> Result<BalanceOf<T>, &'static str> {
+	let locked = 100u32;
+	let per_block = 10u32;
+	let starting_block = 1u32;
 
 	System::<T>::set_block_number(0.into());
 
-	// Add schedule to avoid `NotVesting` error.
-	Vesting::<T>::add_vesting_schedule(
-		&who,
-		locked.into(),
-		per_block.into(),
-		starting_block.into(),
-	)?;
-	Ok(())
+	let mut total_locked: BalanceOf<T> = Zero::zero();
+	for _ in 0 .. n {
+		// Add schedule to avoid `NotVesting` error.
+		Vesting::<T>::add_vesting_schedule(
+			&who,
+			locked.into(),
+			per_block.into(),
+			starting_block.into(),
+		)?;
+		total_locked += locked.into();
+	}
+	Ok(total_locked)
 }
 
 benchmarks! {
@@ -62,16 +66,17 @@ benchmarks! {
 
 	vest_locked {
 		let l in 0 .. MaxLocksOf::<T>::get();
+		let s in 1 .. T::MaxVestingSchedules::get();
 
 		let caller = whitelisted_caller();
 		T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value());
 		add_locks::<T>(&caller, l as u8);
-		add_vesting_schedule::<T>(&caller)?;
+		let expected_balance = add_vesting_schedules::<T>(&caller, s)?;
 		// At block zero, everything is vested.
 		System::<T>::set_block_number(T::BlockNumber::zero());
 		assert_eq!(
 			Vesting::<T>::vesting_balance(&caller),
-			Some(100.into()),
+			Some(expected_balance),
 			"Vesting schedule not added",
 		);
 	}: vest(RawOrigin::Signed(caller.clone()))
@@ -79,18 +84,19 @@ benchmarks! {
 		// Nothing happened since everything is still vested.
 		assert_eq!(
 			Vesting::<T>::vesting_balance(&caller),
-			Some(100.into()),
+			Some(expected_balance),
 			"Vesting schedule was removed",
 		);
 	}
 
 	vest_unlocked {
 		let l in 0 .. MaxLocksOf::<T>::get();
+		let s in 1 .. T::MaxVestingSchedules::get();
 
 		let caller = whitelisted_caller();
 		T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value());
 		add_locks::<T>(&caller, l as u8);
-		add_vesting_schedule::<T>(&caller)?;
+		add_vesting_schedules::<T>(&caller, s)?;
 		// At block 20, everything is unvested.
 		System::<T>::set_block_number(20.into());
 		assert_eq!(
@@ -110,17 +116,18 @@ benchmarks! {
 
 	vest_other_locked {
 		let l in 0 .. MaxLocksOf::<T>::get();
+		let s in 1 .. T::MaxVestingSchedules::get();
 
 		let other: T::AccountId = account("other", 0, SEED);
 		let other_lookup: <T::Lookup as StaticLookup>::Source = T::Lookup::unlookup(other.clone());
 		T::Currency::make_free_balance_be(&other, BalanceOf::<T>::max_value());
 		add_locks::<T>(&other, l as u8);
-		add_vesting_schedule::<T>(&other)?;
+		let expected_balance = add_vesting_schedules::<T>(&other, s)?;
 		// At block zero, everything is vested.
 		System::<T>::set_block_number(T::BlockNumber::zero());
 		assert_eq!(
 			Vesting::<T>::vesting_balance(&other),
-			Some(100.into()),
+			Some(expected_balance),
 			"Vesting schedule not added",
 		);
 
@@ -130,19 +137,20 @@ benchmarks! {
 		// Nothing happened since everything is still vested.
 		assert_eq!(
 			Vesting::<T>::vesting_balance(&other),
-			Some(100.into()),
+			Some(expected_balance),
 			"Vesting schedule was removed",
 		);
 	}
 
 	vest_other_unlocked {
 		let l in 0 .. MaxLocksOf::<T>::get();
+		let s in 1 .. T::MaxVestingSchedules::get();
 
 		let other: T::AccountId = account("other", 0, SEED);
 		let other_lookup: <T::Lookup as StaticLookup>::Source = T::Lookup::unlookup(other.clone());
 		T::Currency::make_free_balance_be(&other, BalanceOf::<T>::max_value());
 		add_locks::<T>(&other, l as u8);
-		add_vesting_schedule::<T>(&other)?;
+		add_vesting_schedules::<T>(&other, s)?;
 		// At block 20, everything is unvested.
 		System::<T>::set_block_number(20.into());
 		assert_eq!(
@@ -164,13 +172,15 @@ benchmarks! {
 
 	vested_transfer {
 		let l in 0 .. MaxLocksOf::<T>::get();
+		let s in 0 .. T::MaxVestingSchedules::get() - 1;
 
 		let caller: T::AccountId = whitelisted_caller();
 		T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value());
 		let target: T::AccountId = account("target", 0, SEED);
 		let target_lookup: <T::Lookup as StaticLookup>::Source = T::Lookup::unlookup(target.clone());
-		// Give target existing locks
+		// Give target existing locks and schedules
 		add_locks::<T>(&target, l as u8);
+		add_vesting_schedules::<T>(&target, s)?;
 
 		let transfer_amount = T::MinVestedTransfer::get();
 
@@ -191,18 +201,25 @@ benchmarks! {
 			Some(T::MinVestedTransfer::get()),
 			"Lock not created",
 		);
+		assert_eq!(
+			Vesting::<T>::vesting(&target).map(|schedules| schedules.len() as u32),
+			Some(s + 1),
+			"Schedule not added",
+		);
 	}
 
 	force_vested_transfer {
 		let l in 0 .. MaxLocksOf::<T>::get();
+		let s in 0 .. T::MaxVestingSchedules::get() - 1;
 
 		let source: T::AccountId = account("source", 0, SEED);
 		let source_lookup: <T::Lookup as StaticLookup>::Source = T::Lookup::unlookup(source.clone());
 		T::Currency::make_free_balance_be(&source, BalanceOf::<T>::max_value());
 		let target: T::AccountId = account("target", 0, SEED);
 		let target_lookup: <T::Lookup as StaticLookup>::Source = T::Lookup::unlookup(target.clone());
-		// Give target existing locks
+		// Give target existing locks and schedules
 		add_locks::<T>(&target, l as u8);
+		add_vesting_schedules::<T>(&target, s)?;
 
 		let transfer_amount = T::MinVestedTransfer::get();
 
@@ -223,6 +240,65 @@ benchmarks! {
 			Some(T::MinVestedTransfer::get()),
 			"Lock not created",
 		);
+		assert_eq!(
+			Vesting::<T>::vesting(&target).map(|schedules| schedules.len() as u32),
+			Some(s + 1),
+			"Schedule not added",
+		);
+	}
+
+	not_unlocking_merge_schedules {
+		let l in 0 .. MaxLocksOf::<T>::get();
+		let s in 2 .. T::MaxVestingSchedules::get();
+
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value());
+		add_locks::<T>(&caller, l as u8);
+		let expected_balance = add_vesting_schedules::<T>(&caller, s)?;
+		// At block zero, nothing has vested yet.
+		System::<T>::set_block_number(T::BlockNumber::zero());
+	}: merge_schedules(RawOrigin::Signed(caller.clone()), 0, s - 1)
+	verify {
+		let merged = VestingInfo {
+			locked: 200u32.into(),
+			per_block: 20u32.into(),
+			starting_block: 1u32.into(),
+		};
+		let schedules = Vesting::<T>::vesting(&caller).ok_or("Schedules were removed")?;
+		assert_eq!(schedules.len() as u32, s - 1, "Schedules were not merged");
+		assert_eq!(schedules.last(), Some(&merged), "Merged schedule is wrong");
+		assert_eq!(
+			Vesting::<T>::vesting_balance(&caller),
+			Some(expected_balance),
+			"Vesting balance changed",
+		);
+	}
+
+	unlocking_merge_schedules {
+		let l in 0 .. MaxLocksOf::<T>::get();
+		let s in 2 .. T::MaxVestingSchedules::get();
+
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value());
+		add_locks::<T>(&caller, l as u8);
+		add_vesting_schedules::<T>(&caller, s)?;
+		// At block 6, half of every schedule has vested.
+		System::<T>::set_block_number(6.into());
+	}: merge_schedules(RawOrigin::Signed(caller.clone()), 0, s - 1)
+	verify {
+		let merged = VestingInfo {
+			locked: 100u32.into(),
+			per_block: 20u32.into(),
+			starting_block: 6u32.into(),
+		};
+		let schedules = Vesting::<T>::vesting(&caller).ok_or("Schedules were removed")?;
+		assert_eq!(schedules.len() as u32, s - 1, "Schedules were not merged");
+		assert_eq!(schedules.last(), Some(&merged), "Merged schedule is wrong");
+		assert_eq!(
+			Vesting::<T>::vesting_balance(&caller),
+			Some((50 * s).into()),
+			"Vesting balance is wrong",
+		);
 	}
 }
 
@@ -241,6 +317,8 @@ mod tests {
 			assert_ok!(test_benchmark_vest_other_unlocked::<Test>());
 			assert_ok!(test_benchmark_vested_transfer::<Test>());
 			assert_ok!(test_benchmark_force_vested_transfer::<Test>());
+			assert_ok!(test_benchmark_not_unlocking_merge_schedules::<Test>());
+			assert_ok!(test_benchmark_unlocking_merge_schedules::<Test>());
 		});
 	}
 }
@@ -16,6 +16,10 @@
 // limitations under the License.
 
 //! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 2.0.0-rc6
+//!
+//! The weights after the `Estimates` comment were written by hand and not generated by the
+//! benchmark CLI. They are estimated from the generated weights of similar calls until the
+//! benchmarks are run again.
 
 #![allow(unused_parens)]
 #![allow(unused_imports)]
@@ -23,40 +27,61 @@
 use frame_support::weights::{Weight, constants::RocksDbWeight as DbWeight};
 
 impl crate::WeightInfo for () {
-	fn vest_locked(l: u32, ) -> Weight {
+	// Estimates, not generated by the benchmark CLI.
+	fn vest_locked(l: u32, s: u32, ) -> Weight {
 		(82109000 as Weight)
 			.saturating_add((332000 as Weight).saturating_mul(l as Weight))
+			.saturating_add((171000 as Weight).saturating_mul(s as Weight))
 			.saturating_add(DbWeight::get().reads(2 as Weight))
 			.saturating_add(DbWeight::get().writes(1 as Weight))
 	}
-	fn vest_unlocked(l: u32, ) -> Weight {
+	fn vest_unlocked(l: u32, s: u32, ) -> Weight {
 		(88419000 as Weight)
 			.saturating_add((3000 as Weight).saturating_mul(l as Weight))
+			.saturating_add((203000 as Weight).saturating_mul(s as Weight))
 			.saturating_add(DbWeight::get().reads(2 as Weight))
 			.saturating_add(DbWeight::get().writes(2 as Weight))
 	}
-	fn vest_other_locked(l: u32, ) -> Weight {
+	fn vest_other_locked(l: u32, s: u32, ) -> Weight {
 		(81277000 as Weight)
 			.saturating_add((321000 as Weight).saturating_mul(l as Weight))
+			.saturating_add((174000 as Weight).saturating_mul(s as Weight))
 			.saturating_add(DbWeight::get().reads(3 as Weight))
 			.saturating_add(DbWeight::get().writes(2 as Weight))
 	}
-	fn vest_other_unlocked(l: u32, ) -> Weight {
+	fn vest_other_unlocked(l: u32, s: u32, ) -> Weight {
 		(87584000 as Weight)
 			.saturating_add((19000 as Weight).saturating_mul(l as Weight))
+			.saturating_add((209000 as Weight).saturating_mul(s as Weight))
 			.saturating_add(DbWeight::get().reads(3 as Weight))
 			.saturating_add(DbWeight::get().writes(3 as Weight))
 	}
-	fn vested_transfer(l: u32, ) -> Weight {
+	fn vested_transfer(l: u32, s: u32, ) -> Weight {
 		(185916000 as Weight)
 			.saturating_add((625000 as Weight).saturating_mul(l as Weight))
+			.saturating_add((196000 as Weight).saturating_mul(s as Weight))
 			.saturating_add(DbWeight::get().reads(3 as Weight))
 			.saturating_add(DbWeight::get().writes(3 as Weight))
 	}
-	fn force_vested_transfer(l: u32, ) -> Weight {
+	fn force_vested_transfer(l: u32, s: u32, ) -> Weight {
 		(185916000 as Weight)
 			.saturating_add((625000 as Weight).saturating_mul(l as Weight))
+			.saturating_add((196000 as Weight).saturating_mul(s as Weight))
 			.saturating_add(DbWeight::get().reads(4 as Weight))
 			.saturating_add(DbWeight::get().writes(4 as Weight))
 	}
+	fn not_unlocking_merge_schedules(l: u32, s: u32, ) -> Weight {
+		(99813000 as Weight)
+			.saturating_add((298000 as Weight).saturating_mul(l as Weight))
+			.saturating_add((371000 as Weight).saturating_mul(s as Weight))
+			.saturating_add(DbWeight::get().reads(2 as Weight))
+			.saturating_add(DbWeight::get().writes(2 as Weight))
+	}
+	fn unlocking_merge_schedules(l: u32, s: u32, ) -> Weight {
+		(100641000 as Weight)
+			.saturating_add((307000 as Weight).saturating_mul(l as Weight))
+			.saturating_add((383000 as Weight).saturating_mul(s as Weight))
+			.saturating_add(DbWeight::get().reads(2 as Weight))
+			.saturating_add(DbWeight::get().writes(2 as Weight))
+	}
 }
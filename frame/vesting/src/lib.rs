@@ -26,6 +26,9 @@
 //! module ensures that there is a lock in place preventing the balance to drop below the *unvested*
 //! amount for any reason other than transaction fee payment.
 //!
+//! An account may have up to `MaxVestingSchedules` vesting schedules at once. The amounts still
+//! unvested under each of them are summed into a single lock.
+//!
 //! As the amount vested increases over time, the amount unvested reduces. However, locks remain in
 //! place and explicit action is needed on behalf of the user to ensure that the amount locked is
 //! equivalent to the amount remaining to be vested. This is done through a dispatchable function,
@@ -41,6 +44,11 @@
 //! - `vest` - Update the lock, reducing it in line with the amount "vested" so far.
 //! - `vest_other` - Update the lock of another account, reducing it in line with the amount
 //!   "vested" so far.
+//! - `vested_transfer` - Transfer funds to an account, vesting them under a new schedule.
+//! - `force_vested_transfer` - Transfer funds between any two accounts, vesting them under a new
+//!   schedule on the target.
+//! - `merge_schedules` - Merge two of the sender's vesting schedules into one, freeing up a
+//!   schedule slot.
 //!
 //! [`Call`]: ./enum.Call.html
 //! [`Trait`]: ./trait.Trait.html
@@ -48,12 +56,14 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
 use sp_std::prelude::*;
-use sp_std::fmt::Debug;
+use sp_std::{fmt::Debug, cell::Cell};
 use codec::{Encode, Decode};
-use sp_runtime::{DispatchResult, RuntimeDebug, traits::{
-	StaticLookup, Zero, AtLeast32BitUnsigned, MaybeSerializeDeserialize, Convert
+use sp_runtime::{DispatchResult, DispatchError, RuntimeDebug, traits::{
+	StaticLookup, Zero, One, AtLeast32BitUnsigned, MaybeSerializeDeserialize, Convert, Saturating,
+	Bounded,
 }};
 use frame_support::{decl_module, decl_event, decl_storage, decl_error, ensure, weights::Weight};
+use frame_support::type_info::TypeInfo;
 use frame_support::traits::{
 	Currency, LockableCurrency, VestingSchedule, WithdrawReason, LockIdentifier,
 	ExistenceRequirement, Get,
@@ -65,14 +75,17 @@ mod default_weights;
 
 type BalanceOf<T> = <<T as Trait>::Currency as Currency<<T as frame_system::Trait>::AccountId>>::Balance;
 type MaxLocksOf<T> = <<T as Trait>::Currency as LockableCurrency<<T as frame_system::Trait>::AccountId>>::MaxLocks;
+type VestingInfoOf<T> = VestingInfo<BalanceOf<T>, <T as frame_system::Trait>::BlockNumber>;
 
 pub trait WeightInfo {
-	fn vest_locked(l: u32, ) -> Weight;
-	fn vest_unlocked(l: u32, ) -> Weight;
-	fn vest_other_locked(l: u32, ) -> Weight;
-	fn vest_other_unlocked(l: u32, ) -> Weight;
-	fn vested_transfer(l: u32, ) -> Weight;
-	fn force_vested_transfer(l: u32, ) -> Weight;
+	fn vest_locked(l: u32, s: u32, ) -> Weight;
+	fn vest_unlocked(l: u32, s: u32, ) -> Weight;
+	fn vest_other_locked(l: u32, s: u32, ) -> Weight;
+	fn vest_other_unlocked(l: u32, s: u32, ) -> Weight;
+	fn vested_transfer(l: u32, s: u32, ) -> Weight;
+	fn force_vested_transfer(l: u32, s: u32, ) -> Weight;
+	fn not_unlocking_merge_schedules(l: u32, s: u32, ) -> Weight;
+	fn unlocking_merge_schedules(l: u32, s: u32, ) -> Weight;
 }
 
 pub trait Trait: frame_system::Trait {
@@ -88,14 +101,54 @@ pub trait Trait: frame_system::Trait {
 	/// The minimum amount transferred to call `vested_transfer`.
 	type MinVestedTransfer: Get<BalanceOf<Self>>;
 
+	/// The maximum number of vesting schedules an account may have at a given moment.
+	type MaxVestingSchedules: Get<u32>;
+
 	/// Weight information for extrinsics in this pallet.
 	type WeightInfo: WeightInfo;
 }
 
 const VESTING_ID: LockIdentifier = *b"vesting ";
 
+// A value placed in storage that represents the current version of the Vesting storage.
+// This value is used by the `on_runtime_upgrade` logic to determine whether we run
+// storage migration logic.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+enum Releases {
+	V0,
+	V1,
+}
+
+impl Default for Releases {
+	fn default() -> Self {
+		Releases::V0
+	}
+}
+
+/// Actions to take against a user's `Vesting` storage entry.
+#[derive(Clone, Copy)]
+enum VestingAction {
+	/// Do not actively remove any schedules.
+	Passive,
+	/// Remove the schedule specified by the index.
+	Remove(usize),
+	/// Remove the two schedules, specified by index, so they can be merged.
+	Merge(usize, usize),
+}
+
+impl VestingAction {
+	/// Whether or not the action says the schedule at `index` should be removed.
+	fn should_remove(&self, index: usize) -> bool {
+		match self {
+			Self::Passive => false,
+			Self::Remove(index1) => *index1 == index,
+			Self::Merge(index1, index2) => *index1 == index || *index2 == index,
+		}
+	}
+}
+
 /// Struct to encode the vesting schedule of an individual account.
-#[derive(Encode, Decode, Copy, Clone, PartialEq, Eq, RuntimeDebug)]
+#[derive(Encode, Decode, Copy, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
 pub struct VestingInfo<Balance, BlockNumber> {
 	/// Locked amount at genesis.
 	pub locked: Balance,
@@ -109,6 +162,12 @@ impl<
 	Balance: AtLeast32BitUnsigned + Copy,
 	BlockNumber: AtLeast32BitUnsigned + Copy,
 > VestingInfo<Balance, BlockNumber> {
+	/// Whether the schedule would ever lock and unlock anything. Note that this does not check
+	/// against `MinVestedTransfer`.
+	pub fn is_valid(&self) -> bool {
+		!self.locked.is_zero() && !self.per_block.is_zero()
+	}
+
 	/// Amount locked at block `n`.
 	pub fn locked_at<
 		BlockNumberToBalance: Convert<BlockNumber, Balance>
@@ -125,6 +184,29 @@ impl<
 			Zero::zero()
 		}
 	}
+
+	/// Block number at which the schedule has fully vested, as a `Balance`.
+	pub fn ending_block_as_balance<
+		BlockNumberToBalance: Convert<BlockNumber, Balance>
+	>(&self) -> Balance {
+		if self.per_block.is_zero() { return Bounded::max_value() }
+
+		let starting_block = BlockNumberToBalance::convert(self.starting_block);
+		let duration = if self.per_block >= self.locked {
+			// Everything unlocks in the first block.
+			One::one()
+		} else {
+			let remainder = if (self.locked % self.per_block).is_zero() {
+				Zero::zero()
+			} else {
+				// `per_block` does not divide `locked`, so one more block is needed to unlock
+				// the remainder.
+				One::one()
+			};
+			(self.locked / self.per_block).saturating_add(remainder)
+		};
+		starting_block.saturating_add(duration)
+	}
 }
 
 decl_storage! {
@@ -132,12 +214,16 @@ decl_storage! {
 		/// Information regarding the vesting of a given account.
 		pub Vesting get(fn vesting):
 			map hasher(blake2_128_concat) T::AccountId
-			=> Option<VestingInfo<BalanceOf<T>, T::BlockNumber>>;
+			=> Option<Vec<VestingInfo<BalanceOf<T>, T::BlockNumber>>>;
+
+		/// Storage version of the pallet.
+		///
+		/// New networks start with last version.
+		StorageVersion build(|_: &GenesisConfig<T>| Releases::V1): Releases;
 	}
 	add_extra_genesis {
 		config(vesting): Vec<(T::AccountId, T::BlockNumber, T::BlockNumber, BalanceOf<T>)>;
 		build(|config: &GenesisConfig<T>| {
-			use sp_runtime::traits::Saturating;
 			// Generate initial vesting configuration
 			// * who - Account which we are generating vesting configuration for
 			// * begin - Block when the account will start to vest
@@ -149,15 +235,28 @@ decl_storage! {
 				// Total genesis `balance` minus `liquid` equals funds locked for vesting
 				let locked = balance.saturating_sub(liquid);
 				let length_as_balance = T::BlockNumberToBalance::convert(length);
-				let per_block = locked / length_as_balance.max(sp_runtime::traits::One::one());
+				let per_block = locked / length_as_balance.max(One::one());
 
-				Vesting::<T>::insert(who, VestingInfo {
+				let vesting_info = VestingInfo {
 					locked: locked,
 					per_block: per_block,
 					starting_block: begin
+				};
+				assert!(vesting_info.is_valid(), "Invalid VestingInfo params at genesis");
+
+				let total_locked = Vesting::<T>::mutate(who, |maybe_schedules| {
+					let schedules = maybe_schedules.get_or_insert_with(Vec::new);
+					assert!(
+						(schedules.len() as u32) < T::MaxVestingSchedules::get(),
+						"Too many vesting schedules at genesis",
+					);
+					schedules.push(vesting_info);
+					schedules.iter().fold(Zero::zero(), |total: BalanceOf<T>, schedule| {
+						total.saturating_add(schedule.locked)
+					})
 				});
 				let reasons = WithdrawReason::Transfer | WithdrawReason::Reserve;
-				T::Currency::set_lock(VESTING_ID, who, locked, reasons);
+				T::Currency::set_lock(VESTING_ID, who, total_locked, reasons);
 			}
 		})
 	}
@@ -179,10 +278,15 @@ decl_error! {
 	pub enum Error for Module<T: Trait> {
 		/// The account given is not vesting.
 		NotVesting,
-		/// An existing vesting schedule already exists for this account that cannot be clobbered.
-		ExistingVestingSchedule,
+		/// The account already has `MaxVestingSchedules` vesting schedules and thus cannot add
+		/// another one. Consider merging existing schedules in order to add another.
+		AtMaxVestingSchedules,
 		/// Amount being transferred is too low to create a vesting schedule.
 		AmountLow,
+		/// An index was out of bounds of the vesting schedules.
+		ScheduleIndexOutOfBounds,
+		/// Failed to create a new schedule because some parameter was invalid.
+		InvalidScheduleParams,
 	}
 }
 
@@ -194,8 +298,15 @@ decl_module! {
 		/// The minimum amount to be transferred to create a new vesting schedule.
 		const MinVestedTransfer: BalanceOf<T> = T::MinVestedTransfer::get();
 
+		/// The maximum number of vesting schedules an account may have.
+		const MaxVestingSchedules: u32 = T::MaxVestingSchedules::get();
+
 		fn deposit_event() = default;
 
+		fn on_runtime_upgrade() -> Weight {
+			Self::migrate_v0_to_v1()
+		}
+
 		/// Unlock any vested funds of the sender account.
 		///
 		/// The dispatch origin for this call must be _Signed_ and the sender must have funds still
@@ -204,17 +315,17 @@ decl_module! {
 		/// Emits either `VestingCompleted` or `VestingUpdated`.
 		///
 		/// # <weight>
-		/// - `O(1)`.
+		/// - `O(S)` where `S` is the number of vesting schedules of the sender.
 		/// - DbWeight: 2 Reads, 2 Writes
 		///     - Reads: Vesting Storage, Balances Locks, [Sender Account]
 		///     - Writes: Vesting Storage, Balances Locks, [Sender Account]
 		/// # </weight>
-		#[weight = T::WeightInfo::vest_locked(MaxLocksOf::<T>::get())
-			.max(T::WeightInfo::vest_unlocked(MaxLocksOf::<T>::get()))
+		#[weight = T::WeightInfo::vest_locked(MaxLocksOf::<T>::get(), T::MaxVestingSchedules::get())
+			.max(T::WeightInfo::vest_unlocked(MaxLocksOf::<T>::get(), T::MaxVestingSchedules::get()))
 		]
 		fn vest(origin) -> DispatchResult {
 			let who = ensure_signed(origin)?;
-			Self::update_lock(who)
+			Self::do_vest(who)
 		}
 
 		/// Unlock any vested funds of a `target` account.
@@ -227,17 +338,17 @@ decl_module! {
 		/// Emits either `VestingCompleted` or `VestingUpdated`.
 		///
 		/// # <weight>
-		/// - `O(1)`.
+		/// - `O(S)` where `S` is the number of vesting schedules of `target`.
 		/// - DbWeight: 3 Reads, 3 Writes
 		///     - Reads: Vesting Storage, Balances Locks, Target Account
 		///     - Writes: Vesting Storage, Balances Locks, Target Account
 		/// # </weight>
-		#[weight = T::WeightInfo::vest_other_locked(MaxLocksOf::<T>::get())
-			.max(T::WeightInfo::vest_other_unlocked(MaxLocksOf::<T>::get()))
+		#[weight = T::WeightInfo::vest_other_locked(MaxLocksOf::<T>::get(), T::MaxVestingSchedules::get())
+			.max(T::WeightInfo::vest_other_unlocked(MaxLocksOf::<T>::get(), T::MaxVestingSchedules::get()))
 		]
 		fn vest_other(origin, target: <T::Lookup as StaticLookup>::Source) -> DispatchResult {
 			ensure_signed(origin)?;
-			Self::update_lock(T::Lookup::lookup(target)?)
+			Self::do_vest(T::Lookup::lookup(target)?)
 		}
 
 		/// Create a vested transfer.
@@ -245,35 +356,29 @@ decl_module! {
 		/// The dispatch origin for this call must be _Signed_.
 		///
 		/// - `target`: The account that should be transferred the vested funds.
-		/// - `amount`: The amount of funds to transfer and will be vested.
-		/// - `schedule`: The vesting schedule attached to the transfer.
+		/// - `schedule`: The vesting schedule attached to the transfer. The amount transferred is
+		///   `schedule.locked`.
+		///
+		/// The schedule is added to any existing schedules of `target`, which must have fewer than
+		/// `MaxVestingSchedules` of them.
 		///
-		/// Emits `VestingCreated`.
+		/// Emits `VestingUpdated`.
 		///
 		/// # <weight>
-		/// - `O(1)`.
+		/// - `O(S)` where `S` is the number of vesting schedules of `target`.
 		/// - DbWeight: 3 Reads, 3 Writes
 		///     - Reads: Vesting Storage, Balances Locks, Target Account, [Sender Account]
 		///     - Writes: Vesting Storage, Balances Locks, Target Account, [Sender Account]
 		/// # </weight>
-		#[weight = T::WeightInfo::vested_transfer(MaxLocksOf::<T>::get())]
+		#[weight = T::WeightInfo::vested_transfer(MaxLocksOf::<T>::get(), T::MaxVestingSchedules::get())]
 		pub fn vested_transfer(
 			origin,
 			target: <T::Lookup as StaticLookup>::Source,
 			schedule: VestingInfo<BalanceOf<T>, T::BlockNumber>,
 		) -> DispatchResult {
 			let transactor = ensure_signed(origin)?;
-			ensure!(schedule.locked >= T::MinVestedTransfer::get(), Error::<T>::AmountLow);
-
-			let who = T::Lookup::lookup(target)?;
-			ensure!(!Vesting::<T>::contains_key(&who), Error::<T>::ExistingVestingSchedule);
-
-			T::Currency::transfer(&transactor, &who, schedule.locked, ExistenceRequirement::AllowDeath)?;
-
-			Self::add_vesting_schedule(&who, schedule.locked, schedule.per_block, schedule.starting_block)
-				.expect("user does not have an existing vesting schedule; q.e.d.");
-
-			Ok(())
+			let target = T::Lookup::lookup(target)?;
+			Self::do_vested_transfer(transactor, target, schedule)
 		}
 
 		/// Force a vested transfer.
@@ -282,18 +387,21 @@ decl_module! {
 		///
 		/// - `source`: The account whose funds should be transferred.
 		/// - `target`: The account that should be transferred the vested funds.
-		/// - `amount`: The amount of funds to transfer and will be vested.
-		/// - `schedule`: The vesting schedule attached to the transfer.
+		/// - `schedule`: The vesting schedule attached to the transfer. The amount transferred is
+		///   `schedule.locked`.
+		///
+		/// The schedule is added to any existing schedules of `target`, which must have fewer than
+		/// `MaxVestingSchedules` of them.
 		///
-		/// Emits `VestingCreated`.
+		/// Emits `VestingUpdated`.
 		///
 		/// # <weight>
-		/// - `O(1)`.
+		/// - `O(S)` where `S` is the number of vesting schedules of `target`.
 		/// - DbWeight: 4 Reads, 4 Writes
 		///     - Reads: Vesting Storage, Balances Locks, Target Account, Source Account
 		///     - Writes: Vesting Storage, Balances Locks, Target Account, Source Account
 		/// # </weight>
-		#[weight = T::WeightInfo::force_vested_transfer(MaxLocksOf::<T>::get())]
+		#[weight = T::WeightInfo::force_vested_transfer(MaxLocksOf::<T>::get(), T::MaxVestingSchedules::get())]
 		pub fn force_vested_transfer(
 			origin,
 			source: <T::Lookup as StaticLookup>::Source,
@@ -301,40 +409,217 @@ decl_module! {
 			schedule: VestingInfo<BalanceOf<T>, T::BlockNumber>,
 		) -> DispatchResult {
 			ensure_root(origin)?;
-			ensure!(schedule.locked >= T::MinVestedTransfer::get(), Error::<T>::AmountLow);
-
 			let target = T::Lookup::lookup(target)?;
 			let source = T::Lookup::lookup(source)?;
-			ensure!(!Vesting::<T>::contains_key(&target), Error::<T>::ExistingVestingSchedule);
+			Self::do_vested_transfer(source, target, schedule)
+		}
 
-			T::Currency::transfer(&source, &target, schedule.locked, ExistenceRequirement::AllowDeath)?;
+		/// Merge two vesting schedules of the sender into one.
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		///
+		/// - `schedule1_index`: The index of the first schedule to merge.
+		/// - `schedule2_index`: The index of the second schedule to merge.
+		///
+		/// Funds vested so far are unlocked first. The merged schedule then:
+		/// - starts at the latest of the two starting blocks and the current block;
+		/// - ends at the latest of the two ending blocks;
+		/// - locks the sum of what both schedules still lock at the current block.
+		///
+		/// If one schedule has already ended, the other is kept unmodified, and if both have ended
+		/// no new schedule is created. Is a no-op if both indices are equal.
+		///
+		/// Emits either `VestingCompleted` or `VestingUpdated`.
+		///
+		/// # <weight>
+		/// - `O(S)` where `S` is the number of vesting schedules of the sender.
+		/// - DbWeight: 2 Reads, 2 Writes
+		///     - Reads: Vesting Storage, Balances Locks, [Sender Account]
+		///     - Writes: Vesting Storage, Balances Locks, [Sender Account]
+		/// # </weight>
+		#[weight = T::WeightInfo::not_unlocking_merge_schedules(
+				MaxLocksOf::<T>::get(), T::MaxVestingSchedules::get()
+			).max(T::WeightInfo::unlocking_merge_schedules(
+				MaxLocksOf::<T>::get(), T::MaxVestingSchedules::get()
+			))
+		]
+		fn merge_schedules(origin, schedule1_index: u32, schedule2_index: u32) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			if schedule1_index == schedule2_index { return Ok(()) }
 
-			Self::add_vesting_schedule(&target, schedule.locked, schedule.per_block, schedule.starting_block)
-				.expect("user does not have an existing vesting schedule; q.e.d.");
+			let schedules = Self::vesting(&who).ok_or(Error::<T>::NotVesting)?;
+			let action = VestingAction::Merge(schedule1_index as usize, schedule2_index as usize);
+			let (schedules, locked_now) = Self::exec_action(schedules, action)?;
 
+			Self::write_vesting(&who, schedules);
+			Self::write_lock(&who, locked_now);
 			Ok(())
 		}
 	}
 }
 
 impl<T: Trait> Module<T> {
-	/// (Re)set or remove the module's currency lock on `who`'s account in accordance with their
-	/// current unvested amount.
-	fn update_lock(who: T::AccountId) -> DispatchResult {
-		let vesting = Self::vesting(&who).ok_or(Error::<T>::NotVesting)?;
+	/// Migrate storage format from a single schedule per account (V0) to a list of schedules (V1).
+	/// Return the weight consumed.
+	pub fn migrate_v0_to_v1() -> Weight {
+		if StorageVersion::get() == Releases::V0 {
+			StorageVersion::put(Releases::V1);
+
+			let translated = Cell::new(0u64);
+			Vesting::<T>::translate::<VestingInfoOf<T>, _>(|_, schedule| {
+				translated.set(translated.get() + 1);
+				Some(vec![schedule])
+			});
+
+			T::DbWeight::get().reads_writes(translated.get() + 1, translated.get() + 1)
+		} else {
+			T::DbWeight::get().reads(1)
+		}
+	}
+
+	/// Unlock any vested funds of `who`, dropping the schedules which have fully vested.
+	fn do_vest(who: T::AccountId) -> DispatchResult {
+		let schedules = Self::vesting(&who).ok_or(Error::<T>::NotVesting)?;
+		let (schedules, locked_now) = Self::exec_action(schedules, VestingAction::Passive)?;
+
+		Self::write_vesting(&who, schedules);
+		Self::write_lock(&who, locked_now);
+		Ok(())
+	}
+
+	/// Transfer `schedule.locked` from `source` to `target` and vest it under `schedule`.
+	fn do_vested_transfer(
+		source: T::AccountId,
+		target: T::AccountId,
+		schedule: VestingInfoOf<T>,
+	) -> DispatchResult {
+		ensure!(schedule.locked >= T::MinVestedTransfer::get(), Error::<T>::AmountLow);
+		Self::can_add_vesting_schedule(&target, schedule.locked, schedule.per_block, schedule.starting_block)?;
+
+		T::Currency::transfer(&source, &target, schedule.locked, ExistenceRequirement::AllowDeath)?;
+
+		Self::add_vesting_schedule(&target, schedule.locked, schedule.per_block, schedule.starting_block)
+			.expect("schedule was checked to be addable before the transfer; qed");
+
+		Ok(())
+	}
+
+	/// Create a new schedule out of `schedule1` and `schedule2`, which are both assumed to have
+	/// unlocked their funds up to `now`.
+	///
+	/// Returns `None` if both schedules have ended, and the other schedule unmodified if one of
+	/// them has ended.
+	fn merge_vesting_info(
+		now: T::BlockNumber,
+		schedule1: VestingInfoOf<T>,
+		schedule2: VestingInfoOf<T>,
+	) -> Option<VestingInfoOf<T>> {
+		let schedule1_ending_block = schedule1.ending_block_as_balance::<T::BlockNumberToBalance>();
+		let schedule2_ending_block = schedule2.ending_block_as_balance::<T::BlockNumberToBalance>();
+		let now_as_balance = T::BlockNumberToBalance::convert(now);
+
+		match (schedule1_ending_block <= now_as_balance, schedule2_ending_block <= now_as_balance) {
+			(true, true) => return None,
+			(true, false) => return Some(schedule2),
+			(false, true) => return Some(schedule1),
+			_ => {},
+		}
+
+		let locked = schedule1.locked_at::<T::BlockNumberToBalance>(now)
+			.saturating_add(schedule2.locked_at::<T::BlockNumberToBalance>(now));
+		let ending_block = schedule1_ending_block.max(schedule2_ending_block);
+		let starting_block = now.max(schedule1.starting_block).max(schedule2.starting_block);
+		let per_block = {
+			let duration = ending_block
+				.saturating_sub(T::BlockNumberToBalance::convert(starting_block))
+				.max(One::one());
+			(locked / duration).max(One::one())
+		};
+
+		let schedule = VestingInfo { locked, per_block, starting_block };
+		debug_assert!(schedule.is_valid(), "merged schedules always lock something; qed");
+		Some(schedule)
+	}
+
+	/// Drop the schedules of `schedules` which have fully vested or are removed by `action`.
+	///
+	/// Returns the remaining schedules and the amount they lock at the current block.
+	fn report_schedule_updates(
+		schedules: Vec<VestingInfoOf<T>>,
+		action: VestingAction,
+	) -> (Vec<VestingInfoOf<T>>, BalanceOf<T>) {
 		let now = <frame_system::Module<T>>::block_number();
-		let locked_now = vesting.locked_at::<T::BlockNumberToBalance>(now);
 
-		if locked_now.is_zero() {
-			T::Currency::remove_lock(VESTING_ID, &who);
-			Vesting::<T>::remove(&who);
-			Self::deposit_event(RawEvent::VestingCompleted(who));
+		let mut total_locked_now: BalanceOf<T> = Zero::zero();
+		let schedules = schedules.into_iter()
+			.enumerate()
+			.filter_map(|(index, schedule)| {
+				let locked_now = schedule.locked_at::<T::BlockNumberToBalance>(now);
+				if locked_now.is_zero() || action.should_remove(index) {
+					None
+				} else {
+					total_locked_now = total_locked_now.saturating_add(locked_now);
+					Some(schedule)
+				}
+			})
+			.collect::<Vec<_>>();
+
+		(schedules, total_locked_now)
+	}
+
+	/// Apply `action` to `schedules`, returning the updated schedules and the amount they lock at
+	/// the current block.
+	fn exec_action(
+		schedules: Vec<VestingInfoOf<T>>,
+		action: VestingAction,
+	) -> Result<(Vec<VestingInfoOf<T>>, BalanceOf<T>), DispatchError> {
+		match action {
+			VestingAction::Passive => Ok(Self::report_schedule_updates(schedules, action)),
+			VestingAction::Remove(index) => {
+				ensure!(index < schedules.len(), Error::<T>::ScheduleIndexOutOfBounds);
+				Ok(Self::report_schedule_updates(schedules, action))
+			},
+			VestingAction::Merge(index1, index2) => {
+				// Indices refer to the schedules before any fully vested ones are dropped.
+				let schedule1 = *schedules.get(index1).ok_or(Error::<T>::ScheduleIndexOutOfBounds)?;
+				let schedule2 = *schedules.get(index2).ok_or(Error::<T>::ScheduleIndexOutOfBounds)?;
+
+				// Two schedules are dropped here, so there is always room for the merged one.
+				let (mut schedules, mut locked_now) = Self::report_schedule_updates(schedules, action);
+
+				let now = <frame_system::Module<T>>::block_number();
+				if let Some(merged) = Self::merge_vesting_info(now, schedule1, schedule2) {
+					schedules.push(merged);
+					// A schedule merged from two unfinished ones starts at `now` or later and so
+					// locks all of `locked`, but when the other schedule had already fully vested
+					// the remaining one is kept as it is and may have started in the past.
+					locked_now = locked_now.saturating_add(merged.locked_at::<T::BlockNumberToBalance>(now));
+				}
+
+				Ok((schedules, locked_now))
+			},
+		}
+	}
+
+	/// (Re)set or remove the module's currency lock on `who`'s account to `total_locked_now`.
+	fn write_lock(who: &T::AccountId, total_locked_now: BalanceOf<T>) {
+		if total_locked_now.is_zero() {
+			T::Currency::remove_lock(VESTING_ID, who);
+			Self::deposit_event(RawEvent::VestingCompleted(who.clone()));
 		} else {
 			let reasons = WithdrawReason::Transfer | WithdrawReason::Reserve;
-			T::Currency::set_lock(VESTING_ID, &who, locked_now, reasons);
-			Self::deposit_event(RawEvent::VestingUpdated(who, locked_now));
+			T::Currency::set_lock(VESTING_ID, who, total_locked_now, reasons);
+			Self::deposit_event(RawEvent::VestingUpdated(who.clone(), total_locked_now));
+		}
+	}
+
+	/// Store the vesting schedules of `who`, removing the entry if there are none left.
+	fn write_vesting(who: &T::AccountId, schedules: Vec<VestingInfoOf<T>>) {
+		if schedules.is_empty() {
+			Vesting::<T>::remove(who);
+		} else {
+			Vesting::<T>::insert(who, schedules);
 		}
-		Ok(())
 	}
 }
 
@@ -346,9 +631,11 @@ impl<T: Trait> VestingSchedule<T::AccountId> for Module<T> where
 
 	/// Get the amount that is currently being vested and cannot be transferred out of this account.
 	fn vesting_balance(who: &T::AccountId) -> Option<BalanceOf<T>> {
-		if let Some(v) = Self::vesting(who) {
+		if let Some(schedules) = Self::vesting(who) {
 			let now = <frame_system::Module<T>>::block_number();
-			let locked_now = v.locked_at::<T::BlockNumberToBalance>(now);
+			let locked_now = schedules.iter().fold(Zero::zero(), |total: BalanceOf<T>, schedule| {
+				total.saturating_add(schedule.locked_at::<T::BlockNumberToBalance>(now))
+			});
 			Some(T::Currency::free_balance(who).min(locked_now))
 		} else {
 			None
@@ -357,8 +644,8 @@ impl<T: Trait> VestingSchedule<T::AccountId> for Module<T> where
 
 	/// Adds a vesting schedule to a given account.
 	///
-	/// If there already exists a vesting schedule for the given account, an `Err` is returned
-	/// and nothing is updated.
+	/// If the account already has `MaxVestingSchedules` schedules, or the schedule would never
+	/// unlock anything, an `Err` is returned and nothing is updated.
 	///
 	/// On success, a linearly reducing amount of funds will be locked. In order to realise any
 	/// reduction of the lock over time as it diminishes, the account owner must use `vest` or
@@ -372,25 +659,44 @@ impl<T: Trait> VestingSchedule<T::AccountId> for Module<T> where
 		starting_block: T::BlockNumber
 	) -> DispatchResult {
 		if locked.is_zero() { return Ok(()) }
-		if Vesting::<T>::contains_key(who) {
-			Err(Error::<T>::ExistingVestingSchedule)?
-		}
-		let vesting_schedule = VestingInfo {
-			locked,
-			per_block,
-			starting_block
-		};
-		Vesting::<T>::insert(who, vesting_schedule);
-		// it can't fail, but even if somehow it did, we don't really care.
-		let _ = Self::update_lock(who.clone());
+		Self::can_add_vesting_schedule(who, locked, per_block, starting_block)?;
+
+		let mut schedules = Self::vesting(who).unwrap_or_default();
+		schedules.push(VestingInfo { locked, per_block, starting_block });
+		let (schedules, locked_now) = Self::exec_action(schedules, VestingAction::Passive)?;
+
+		Self::write_vesting(who, schedules);
+		Self::write_lock(who, locked_now);
+		Ok(())
+	}
+
+	/// Checks whether `add_vesting_schedule` would succeed for the given parameters.
+	fn can_add_vesting_schedule(
+		who: &T::AccountId,
+		locked: BalanceOf<T>,
+		per_block: BalanceOf<T>,
+		starting_block: T::BlockNumber
+	) -> DispatchResult {
+		ensure!(
+			VestingInfo { locked, per_block, starting_block }.is_valid(),
+			Error::<T>::InvalidScheduleParams
+		);
+		ensure!(
+			(Vesting::<T>::decode_len(who).unwrap_or(0) as u32) < T::MaxVestingSchedules::get(),
+			Error::<T>::AtMaxVestingSchedules
+		);
 		Ok(())
 	}
 
-	/// Remove a vesting schedule for a given account.
-	fn remove_vesting_schedule(who: &T::AccountId) {
-		Vesting::<T>::remove(who);
-		// it can't fail, but even if somehow it did, we don't really care.
-		let _ = Self::update_lock(who.clone());
+	/// Remove the vesting schedule at `schedule_index` for a given account.
+	fn remove_vesting_schedule(who: &T::AccountId, schedule_index: u32) -> DispatchResult {
+		let schedules = Self::vesting(who).ok_or(Error::<T>::NotVesting)?;
+		let action = VestingAction::Remove(schedule_index as usize);
+		let (schedules, locked_now) = Self::exec_action(schedules, action)?;
+
+		Self::write_vesting(who, schedules);
+		Self::write_lock(who, locked_now);
+		Ok(())
 	}
 }
 
@@ -401,7 +707,7 @@ mod tests {
 	use std::cell::RefCell;
 	use frame_support::{
 		assert_ok, assert_noop, impl_outer_origin, parameter_types, weights::Weight,
-		traits::Get
+		traits::Get, Blake2_128Concat, StorageHasher,
 	};
 	use sp_core::H256;
 	use sp_runtime::{
@@ -460,16 +766,20 @@ mod tests {
 		type ExistentialDeposit = ExistentialDeposit;
 		type AccountStore = System;
 		type MaxLocks = MaxLocks;
+		type MaxReserves = ();
+		type ReserveIdentifier = [u8; 8];
 		type WeightInfo = ();
 	}
 	parameter_types! {
 		pub const MinVestedTransfer: u64 = 256 * 2;
+		pub const MaxVestingSchedules: u32 = 3;
 	}
 	impl Trait for Test {
 		type Event = ();
 		type Currency = Balances;
 		type BlockNumberToBalance = Identity;
 		type MinVestedTransfer = MinVestedTransfer;
+		type MaxVestingSchedules = MaxVestingSchedules;
 		type WeightInfo = ();
 	}
 	type System = frame_system::Module<Test>;
@@ -551,9 +861,9 @@ mod tests {
 					per_block: 64, // Vesting over 20 blocks
 					starting_block: 10,
 				};
-				assert_eq!(Vesting::vesting(&1), Some(user1_vesting_schedule)); // Account 1 has a vesting schedule
-				assert_eq!(Vesting::vesting(&2), Some(user2_vesting_schedule)); // Account 2 has a vesting schedule
-				assert_eq!(Vesting::vesting(&12), Some(user12_vesting_schedule)); // Account 12 has a vesting schedule
+				assert_eq!(Vesting::vesting(&1), Some(vec![user1_vesting_schedule])); // Account 1 has a vesting schedule
+				assert_eq!(Vesting::vesting(&2), Some(vec![user2_vesting_schedule])); // Account 2 has a vesting schedule
+				assert_eq!(Vesting::vesting(&12), Some(vec![user12_vesting_schedule])); // Account 12 has a vesting schedule
 
 				// Account 1 has only 128 units vested from their illiquid 256 * 5 units at block 1
 				assert_eq!(Vesting::vesting_balance(&1), Some(128 * 9));
@@ -674,7 +984,7 @@ mod tests {
 					per_block: 64, // Vesting over 20 blocks
 					starting_block: 10,
 				};
-				assert_eq!(Vesting::vesting(&12), Some(user12_vesting_schedule));
+				assert_eq!(Vesting::vesting(&12), Some(vec![user12_vesting_schedule]));
 
 				// Account 12 can still send liquid funds
 				assert_ok!(Balances::transfer(Some(12).into(), 3, 256 * 5));
@@ -701,7 +1011,7 @@ mod tests {
 				};
 				assert_ok!(Vesting::vested_transfer(Some(3).into(), 4, new_vesting_schedule));
 				// Now account 4 should have vesting.
-				assert_eq!(Vesting::vesting(&4), Some(new_vesting_schedule));
+				assert_eq!(Vesting::vesting(&4), Some(vec![new_vesting_schedule]));
 				// Ensure the transfer happened correctly.
 				let user3_free_balance_updated = Balances::free_balance(&3);
 				assert_eq!(user3_free_balance_updated, 256 * 25);
@@ -740,17 +1050,17 @@ mod tests {
 					per_block: 256, // Vesting over 20 blocks
 					starting_block: 10,
 				};
-				assert_eq!(Vesting::vesting(&2), Some(user2_vesting_schedule));
+				assert_eq!(Vesting::vesting(&2), Some(vec![user2_vesting_schedule]));
 
-				// The vesting schedule we will try to create, fails due to pre-existence of schedule.
+				// The vesting schedule we will try to create, fails as it would never unlock anything.
 				let new_vesting_schedule = VestingInfo {
 					locked: 256 * 5,
-					per_block: 64, // Vesting over 20 blocks
+					per_block: 0,
 					starting_block: 10,
 				};
 				assert_noop!(
 					Vesting::vested_transfer(Some(4).into(), 2, new_vesting_schedule),
-					Error::<Test>::ExistingVestingSchedule,
+					Error::<Test>::InvalidScheduleParams,
 				);
 
 				// Fails due to too low transfer amount.
@@ -791,7 +1101,7 @@ mod tests {
 				assert_noop!(Vesting::force_vested_transfer(Some(4).into(), 3, 4, new_vesting_schedule), BadOrigin);
 				assert_ok!(Vesting::force_vested_transfer(RawOrigin::Root.into(), 3, 4, new_vesting_schedule));
 				// Now account 4 should have vesting.
-				assert_eq!(Vesting::vesting(&4), Some(new_vesting_schedule));
+				assert_eq!(Vesting::vesting(&4), Some(vec![new_vesting_schedule]));
 				// Ensure the transfer happened correctly.
 				let user3_free_balance_updated = Balances::free_balance(&3);
 				assert_eq!(user3_free_balance_updated, 256 * 25);
@@ -830,17 +1140,17 @@ mod tests {
 					per_block: 256, // Vesting over 20 blocks
 					starting_block: 10,
 				};
-				assert_eq!(Vesting::vesting(&2), Some(user2_vesting_schedule));
+				assert_eq!(Vesting::vesting(&2), Some(vec![user2_vesting_schedule]));
 
-				// The vesting schedule we will try to create, fails due to pre-existence of schedule.
+				// The vesting schedule we will try to create, fails as it would never unlock anything.
 				let new_vesting_schedule = VestingInfo {
 					locked: 256 * 5,
-					per_block: 64, // Vesting over 20 blocks
+					per_block: 0,
 					starting_block: 10,
 				};
 				assert_noop!(
 					Vesting::force_vested_transfer(RawOrigin::Root.into(), 4, 2, new_vesting_schedule),
-					Error::<Test>::ExistingVestingSchedule,
+					Error::<Test>::InvalidScheduleParams,
 				);
 
 				// Fails due to too low transfer amount.
@@ -859,4 +1169,183 @@ mod tests {
 				assert_eq!(user4_free_balance, 256 * 40);
 			});
 	}
+
+	#[test]
+	fn multiple_schedules_are_summed_into_one_lock() {
+		ExtBuilder::default()
+			.existential_deposit(256)
+			.build()
+			.execute_with(|| {
+				let user2_vesting_schedule = VestingInfo {
+					locked: 256 * 20,
+					per_block: 256, // Vesting over 20 blocks
+					starting_block: 10,
+				};
+				// A second schedule can be granted to an account which is already vesting.
+				let new_vesting_schedule = VestingInfo {
+					locked: 256 * 5,
+					per_block: 64, // Vesting over 20 blocks
+					starting_block: 10,
+				};
+				assert_ok!(Vesting::vested_transfer(Some(4).into(), 2, new_vesting_schedule));
+				assert_eq!(Vesting::vesting(&2), Some(vec![user2_vesting_schedule, new_vesting_schedule]));
+				assert_eq!(Balances::free_balance(&2), 256 * 25);
+				assert_eq!(Vesting::vesting_balance(&2), Some(256 * 25));
+				assert_eq!(Balances::locks(&2)[0].amount, 256 * 25);
+
+				System::set_block_number(20);
+				assert_ok!(Vesting::vest(Some(2).into()));
+				// 10 blocks of 256 and 10 blocks of 64 are vested.
+				assert_eq!(Vesting::vesting_balance(&2), Some(256 * 25 - 10 * 256 - 10 * 64));
+				assert_eq!(Balances::locks(&2)[0].amount, 256 * 25 - 10 * 256 - 10 * 64);
+
+				System::set_block_number(30);
+				assert_ok!(Vesting::vest(Some(2).into()));
+				assert_eq!(Vesting::vesting(&2), None);
+				assert!(Balances::locks(&2).is_empty());
+			});
+	}
+
+	#[test]
+	fn vested_transfer_respects_max_vesting_schedules() {
+		ExtBuilder::default()
+			.existential_deposit(256)
+			.build()
+			.execute_with(|| {
+				let new_vesting_schedule = VestingInfo {
+					locked: 256 * 2,
+					per_block: 64,
+					starting_block: 10,
+				};
+				// Account 2 already has one schedule.
+				for _ in 1..MaxVestingSchedules::get() {
+					assert_ok!(Vesting::vested_transfer(Some(4).into(), 2, new_vesting_schedule));
+				}
+				assert_eq!(Vesting::vesting(&2).unwrap().len() as u32, MaxVestingSchedules::get());
+				assert_noop!(
+					Vesting::vested_transfer(Some(4).into(), 2, new_vesting_schedule),
+					Error::<Test>::AtMaxVestingSchedules,
+				);
+				assert_noop!(
+					Vesting::force_vested_transfer(RawOrigin::Root.into(), 4, 2, new_vesting_schedule),
+					Error::<Test>::AtMaxVestingSchedules,
+				);
+
+				// Merging two schedules frees up a slot.
+				assert_ok!(Vesting::merge_schedules(Some(2).into(), 1, 2));
+				assert_ok!(Vesting::vested_transfer(Some(4).into(), 2, new_vesting_schedule));
+			});
+	}
+
+	#[test]
+	fn merge_schedules_works() {
+		ExtBuilder::default()
+			.existential_deposit(256)
+			.build()
+			.execute_with(|| {
+				// Account 2 vests 256 * 20 over blocks 10 to 30.
+				let user2_vesting_schedule = VestingInfo {
+					locked: 256 * 20,
+					per_block: 256,
+					starting_block: 10,
+				};
+				// Ends at block 20.
+				let new_vesting_schedule = VestingInfo {
+					locked: 256 * 10,
+					per_block: 256,
+					starting_block: 10,
+				};
+				assert_ok!(Vesting::vested_transfer(Some(4).into(), 2, new_vesting_schedule));
+
+				assert_noop!(Vesting::merge_schedules(Some(3).into(), 0, 1), Error::<Test>::NotVesting);
+				assert_noop!(
+					Vesting::merge_schedules(Some(2).into(), 0, 2),
+					Error::<Test>::ScheduleIndexOutOfBounds,
+				);
+				// Merging a schedule with itself does nothing.
+				assert_ok!(Vesting::merge_schedules(Some(2).into(), 1, 1));
+				assert_eq!(Vesting::vesting(&2).unwrap().len(), 2);
+
+				System::set_block_number(15);
+				assert_ok!(Vesting::merge_schedules(Some(2).into(), 0, 1));
+				// Both schedules have unlocked 5 * 256 so far; the merged schedule locks the rest
+				// from now until the later ending block.
+				let merged_schedule = VestingInfo {
+					locked: 256 * 15 + 256 * 5,
+					per_block: 256 * 20 / 15,
+					starting_block: 15,
+				};
+				assert_eq!(Vesting::vesting(&2), Some(vec![merged_schedule]));
+				assert_eq!(Vesting::vesting_balance(&2), Some(256 * 20));
+				assert_eq!(Balances::locks(&2)[0].amount, 256 * 20);
+			});
+	}
+
+	#[test]
+	fn merge_schedules_with_ended_schedule_keeps_the_other() {
+		ExtBuilder::default()
+			.existential_deposit(256)
+			.build()
+			.execute_with(|| {
+				let user2_vesting_schedule = VestingInfo {
+					locked: 256 * 20,
+					per_block: 256,
+					starting_block: 10,
+				};
+				// Ends at block 5.
+				let new_vesting_schedule = VestingInfo {
+					locked: 256 * 4,
+					per_block: 256,
+					starting_block: 1,
+				};
+				assert_ok!(Vesting::vested_transfer(Some(4).into(), 2, new_vesting_schedule));
+
+				System::set_block_number(5);
+				assert_ok!(Vesting::merge_schedules(Some(2).into(), 0, 1));
+				assert_eq!(Vesting::vesting(&2), Some(vec![user2_vesting_schedule]));
+				assert_eq!(Balances::locks(&2)[0].amount, 256 * 20);
+			});
+	}
+
+	#[test]
+	fn remove_vesting_schedule_works() {
+		ExtBuilder::default()
+			.existential_deposit(256)
+			.build()
+			.execute_with(|| {
+				assert_noop!(Vesting::remove_vesting_schedule(&3, 0), Error::<Test>::NotVesting);
+				assert_noop!(Vesting::remove_vesting_schedule(&2, 1), Error::<Test>::ScheduleIndexOutOfBounds);
+
+				assert_ok!(Vesting::remove_vesting_schedule(&2, 0));
+				assert_eq!(Vesting::vesting(&2), None);
+				assert!(Balances::locks(&2).is_empty());
+			});
+	}
+
+	#[test]
+	fn migrate_v0_to_v1_works() {
+		ExtBuilder::default()
+			.existential_deposit(256)
+			.build()
+			.execute_with(|| {
+				let schedule = VestingInfo {
+					locked: 256 * 5,
+					per_block: 64,
+					starting_block: 10,
+				};
+				// Account 3 is not vesting yet, so write a schedule in the old format for it.
+				StorageVersion::put(Releases::V0);
+				frame_support::migration::put_storage_value(
+					b"Vesting",
+					b"Vesting",
+					&Blake2_128Concat::hash(&3u64.encode()),
+					schedule,
+				);
+
+				Vesting::migrate_v0_to_v1();
+
+				assert_eq!(StorageVersion::get(), Releases::V1);
+				assert_eq!(Vesting::vesting(&3), Some(vec![schedule]));
+			});
+	}
 }
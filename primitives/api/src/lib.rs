@@ -67,7 +67,7 @@ pub use sp_version::{ApiId, RuntimeVersion, ApisVec, create_apis_vec};
 #[doc(hidden)]
 pub use sp_std::{slice, mem};
 #[cfg(feature = "std")]
-use sp_std::result;
+use sp_std::{result, vec::Vec};
 #[doc(hidden)]
 pub use codec::{Encode, Decode, DecodeLimit};
 use sp_core::OpaqueMetadata;
@@ -589,8 +589,14 @@ decl_runtime_apis! {
 	}
 
 	/// The `Metadata` api trait that returns metadata for the runtime.
+	#[api_version(2)]
 	pub trait Metadata {
 		/// Returns the metadata of a runtime.
 		fn metadata() -> OpaqueMetadata;
+		/// Returns the metadata of a runtime in the given version, or `None` if the runtime
+		/// doesn't support that version.
+		fn metadata_at_version(version: u32) -> Option<OpaqueMetadata>;
+		/// Returns the versions of the metadata supported by `metadata_at_version`.
+		fn metadata_versions() -> Vec<u32>;
 	}
 }
@@ -31,6 +31,8 @@ pub use sp_core::crypto::{KeyTypeId, CryptoTypeId, key_types};
 #[doc(hidden)]
 pub use codec;
 #[doc(hidden)]
+pub use sp_type_info;
+#[doc(hidden)]
 #[cfg(feature = "std")]
 pub use serde;
 #[doc(hidden)]
@@ -331,6 +333,8 @@ macro_rules! app_crypto_public_common {
 				<$public>::try_from(data).map(Into::into)
 			}
 		}
+
+		$crate::app_crypto_type_info!(Public, $public);
 	}
 }
 
@@ -478,6 +482,35 @@ macro_rules! app_crypto_signature_common {
 				Ok(<$sig>::try_from(data.as_slice())?.into())
 			}
 		}
+
+		$crate::app_crypto_type_info!(Signature, $sig);
+	}
+}
+
+/// Implements `TypeInfo` for the application-specific wrapper `$outer` of `$inner`, which is
+/// encoded like `$inner`.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! app_crypto_type_info {
+	($outer:ident, $inner:ty) => {
+		impl $crate::sp_type_info::TypeInfo for $outer {
+			type Identity = Self;
+
+			fn type_info() -> $crate::sp_type_info::Type {
+				$crate::sp_type_info::Type::new(
+					$crate::sp_type_info::Path::new(stringify!($outer), module_path!()),
+					$crate::Vec::new(),
+					$crate::sp_type_info::TypeDef::Composite($crate::sp_type_info::sp_std::vec![
+						$crate::sp_type_info::Field::new::<$inner>(
+							None,
+							Some(stringify!($inner)),
+							$crate::Vec::new(),
+						),
+					]),
+					$crate::Vec::new(),
+				)
+			}
+		}
 	}
 }
 
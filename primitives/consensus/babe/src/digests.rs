@@ -22,6 +22,7 @@ use super::{
 	BabeEpochConfiguration, SlotNumber, BABE_ENGINE_ID,
 };
 use codec::{Codec, Decode, Encode};
+use sp_type_info::TypeInfo;
 use sp_std::vec::Vec;
 use sp_runtime::{generic::OpaqueDigestItemId, DigestItem, RuntimeDebug};
 
@@ -134,7 +135,7 @@ pub struct NextEpochDescriptor {
 
 /// Information about the next epoch config, if changed. This is broadcast in the first
 /// block of the epoch, and applies using the same rules as `NextEpochDescriptor`.
-#[derive(Decode, Encode, PartialEq, Eq, Clone, RuntimeDebug)]
+#[derive(Decode, Encode, PartialEq, Eq, Clone, RuntimeDebug, TypeInfo)]
 pub enum NextConfigDescriptor {
 	/// Version 1.
 	#[codec(index = "1")]
@@ -140,7 +140,10 @@ pub trait Externalities: ExtensionStore {
 	fn kill_child_storage(&mut self, child_info: &ChildInfo);
 
 	/// Clear storage entries which keys are start with the given prefix.
-	fn clear_prefix(&mut self, prefix: &[u8]);
+	///
+	/// If `limit` is `Some(n)`, at most `n` keys are removed. Returns whether no key with the
+	/// given prefix remains in storage, together with the number of keys that were removed.
+	fn clear_prefix(&mut self, prefix: &[u8], limit: Option<u32>) -> (bool, u32);
 
 	/// Clear child storage entries which keys are start with the given prefix.
 	fn clear_child_prefix(
@@ -28,7 +28,8 @@ use serde::Serialize;
 use codec::{Encode, Decode, Input, Codec};
 use sp_runtime::{ConsensusEngineId, RuntimeDebug, traits::NumberFor};
 use sp_std::borrow::Cow;
-use sp_std::vec::Vec;
+use sp_std::{marker::PhantomData, prelude::*};
+use sp_type_info::{Field, MetaType, Path, Type, TypeDef, TypeInfo, TypeParameter, Variant};
 #[cfg(feature = "std")]
 use sp_keystore::{SyncCryptoStorePtr, SyncCryptoStore};
 
@@ -78,7 +79,7 @@ pub type AuthorityList = Vec<(AuthorityId, AuthorityWeight)>;
 
 /// A scheduled change of authority set.
 #[cfg_attr(feature = "std", derive(Serialize))]
-#[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug)]
+#[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo)]
 pub struct ScheduledChange<N> {
 	/// The new authorities after the change, along with their respective weights.
 	pub next_authorities: AuthorityList,
@@ -88,7 +89,7 @@ pub struct ScheduledChange<N> {
 
 /// An consensus log item for GRANDPA.
 #[cfg_attr(feature = "std", derive(Serialize))]
-#[derive(Decode, Encode, PartialEq, Eq, Clone, RuntimeDebug)]
+#[derive(Decode, Encode, PartialEq, Eq, Clone, RuntimeDebug, TypeInfo)]
 pub enum ConsensusLog<N: Codec> {
 	/// Schedule an authority set change.
 	///
@@ -171,7 +172,7 @@ impl<N: Codec> ConsensusLog<N> {
 /// GRANDPA happens when a voter votes on the same round (either at prevote or
 /// precommit stage) for different blocks. Proving is achieved by collecting the
 /// signed messages of conflicting votes.
-#[derive(Clone, Debug, Decode, Encode, PartialEq)]
+#[derive(Clone, Debug, Decode, Encode, PartialEq, TypeInfo)]
 pub struct EquivocationProof<H, N> {
 	set_id: SetId,
 	equivocation: Equivocation<H, N>,
@@ -254,6 +255,109 @@ impl<H, N> Equivocation<H, N> {
 	}
 }
 
+impl<H: TypeInfo + 'static, N: TypeInfo + 'static> TypeInfo for Equivocation<H, N> {
+	type Identity = Self;
+
+	fn type_info() -> Type {
+		Type::new(
+			Path::new("Equivocation", module_path!()),
+			vec![
+				TypeParameter::new("H", Some(MetaType::new::<H>())),
+				TypeParameter::new("N", Some(MetaType::new::<N>())),
+			],
+			TypeDef::Variant(vec![
+				Variant::new(
+					"Prevote",
+					vec![Field::new::<GrandpaEquivocation<Prevote<H, N>>>(
+						None,
+						Some("Equivocation<AuthorityId, Prevote<H, N>, AuthoritySignature>"),
+						Vec::new(),
+					)],
+					0,
+					Vec::new(),
+				),
+				Variant::new(
+					"Precommit",
+					vec![Field::new::<GrandpaEquivocation<Precommit<H, N>>>(
+						None,
+						Some("Equivocation<AuthorityId, Precommit<H, N>, AuthoritySignature>"),
+						Vec::new(),
+					)],
+					1,
+					Vec::new(),
+				),
+			]),
+			Vec::new(),
+		)
+	}
+}
+
+/// Describes a `grandpa::Equivocation` of votes of type `V`.
+///
+/// The types of `finality-grandpa` can't implement `TypeInfo` outside of that crate, so the
+/// description of an [`Equivocation`] is built from these stand-ins, which follow their encoding.
+struct GrandpaEquivocation<V>(PhantomData<V>);
+
+/// Describes a `grandpa::Prevote`.
+struct Prevote<H, N>(PhantomData<(H, N)>);
+
+/// Describes a `grandpa::Precommit`.
+struct Precommit<H, N>(PhantomData<(H, N)>);
+
+impl<V: TypeInfo + 'static> TypeInfo for GrandpaEquivocation<V> {
+	type Identity = Self;
+
+	fn type_info() -> Type {
+		Type::new(
+			Path::new("Equivocation", "finality_grandpa"),
+			vec![
+				TypeParameter::new("Id", Some(MetaType::new::<AuthorityId>())),
+				TypeParameter::new("V", Some(MetaType::new::<V>())),
+				TypeParameter::new("S", Some(MetaType::new::<AuthoritySignature>())),
+			],
+			TypeDef::Composite(vec![
+				Field::new::<RoundNumber>(Some("round_number"), Some("u64"), Vec::new()),
+				Field::new::<AuthorityId>(Some("identity"), Some("Id"), Vec::new()),
+				Field::new::<(V, AuthoritySignature)>(Some("first"), Some("(V, S)"), Vec::new()),
+				Field::new::<(V, AuthoritySignature)>(Some("second"), Some("(V, S)"), Vec::new()),
+			]),
+			Vec::new(),
+		)
+	}
+}
+
+/// Describes a vote of `finality-grandpa` with the given name, which is a target block.
+fn vote_type_info<H: TypeInfo + 'static, N: TypeInfo + 'static>(name: &'static str) -> Type {
+	Type::new(
+		Path::new(name, "finality_grandpa"),
+		vec![
+			TypeParameter::new("H", Some(MetaType::new::<H>())),
+			TypeParameter::new("N", Some(MetaType::new::<N>())),
+		],
+		TypeDef::Composite(vec![
+			Field::new::<H>(Some("target_hash"), Some("H"), Vec::new()),
+			Field::new::<N>(Some("target_number"), Some("N"), Vec::new()),
+		]),
+		Vec::new(),
+	)
+}
+
+impl<H: TypeInfo + 'static, N: TypeInfo + 'static> TypeInfo for Prevote<H, N> {
+	type Identity = Self;
+
+	fn type_info() -> Type {
+		vote_type_info::<H, N>("Prevote")
+	}
+}
+
+impl<H: TypeInfo + 'static, N: TypeInfo + 'static> TypeInfo for Precommit<H, N> {
+	type Identity = Self;
+
+	fn type_info() -> Type {
+		vote_type_info::<H, N>("Precommit")
+	}
+}
+
 /// Verifies the equivocation proof by making sure that both votes target
 /// different blocks and that its signatures are valid.
 pub fn check_equivocation_proof<H, N>(report: EquivocationProof<H, N>) -> bool
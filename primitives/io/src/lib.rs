@@ -47,7 +47,7 @@ use sp_core::{
 use sp_keystore::{KeystoreExt, SyncCryptoStore};
 
 use sp_core::{
-	OpaquePeerId, crypto::KeyTypeId, ed25519, sr25519, ecdsa, H256, LogLevel,
+	OpaquePeerId, crypto::KeyTypeId, ed25519, sr25519, ecdsa, H256, LogLevel, RuntimeDebug,
 	offchain::{
 		Timestamp, HttpRequestId, HttpRequestStatus, HttpError, StorageKind, OpaqueNetworkState,
 	},
@@ -57,7 +57,7 @@ use sp_core::{
 use sp_trie::{TrieConfiguration, trie_types::Layout};
 
 use sp_runtime_interface::{runtime_interface, Pointer};
-use sp_runtime_interface::pass_by::PassBy;
+use sp_runtime_interface::pass_by::{PassBy, PassByCodec};
 
 use codec::{Encode, Decode};
 
@@ -81,6 +81,16 @@ pub enum EcdsaVerifyError {
 	BadSignature,
 }
 
+/// The outcome of calling `storage::clear_prefix` with a `limit`.
+#[derive(PassByCodec, Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug)]
+pub enum KillStorageResult {
+	/// No key remains in storage for the given prefix. Contains the number of removed keys.
+	AllRemoved(u32),
+	/// At least one key still remains in storage for the given prefix. Contains the number
+	/// of removed keys.
+	SomeRemaining(u32),
+}
+
 /// Interface for accessing the storage from within the runtime.
 #[runtime_interface]
 pub trait Storage {
@@ -121,7 +131,27 @@ pub trait Storage {
 
 	/// Clear the storage of each key-value pair where the key starts with the given `prefix`.
 	fn clear_prefix(&mut self, prefix: &[u8]) {
-		Externalities::clear_prefix(*self, prefix)
+		let _ = Externalities::clear_prefix(*self, prefix, None);
+	}
+
+	/// Clear the storage of each key-value pair where the key starts with the given `prefix`.
+	///
+	/// # Limit
+	///
+	/// Deletes at most `limit` keys in lexicographic order if it is set to `Some`. No limit
+	/// is applied when `limit` is set to `None`.
+	///
+	/// The limit can be used to partially delete a prefix storage in case it is too large
+	/// to delete in one go (block). Keys which were already removed earlier in the same
+	/// block are not counted against the limit, so calling this function repeatedly with
+	/// the same `prefix` always makes progress.
+	#[version(2)]
+	fn clear_prefix(&mut self, prefix: &[u8], limit: Option<u32>) -> KillStorageResult {
+		let (all_removed, num_removed) = Externalities::clear_prefix(*self, prefix, limit);
+		match all_removed {
+			true => KillStorageResult::AllRemoved(num_removed),
+			false => KillStorageResult::SomeRemaining(num_removed),
+		}
 	}
 
 	/// Append the encoded `value` to the storage item at `key`.
@@ -1370,7 +1400,7 @@ mod tests {
 		});
 
 		t.execute_with(|| {
-			storage::clear_prefix(b":abc");
+			assert_eq!(storage::clear_prefix(b":abc", None), KillStorageResult::AllRemoved(2));
 
 			assert!(storage::get(b":a").is_some());
 			assert!(storage::get(b":abdd").is_some());
@@ -1379,6 +1409,30 @@ mod tests {
 		});
 	}
 
+	#[test]
+	fn clear_prefix_with_limit_works() {
+		let mut t = BasicExternalities::new(Storage {
+			top: map![
+				b":a".to_vec() => b"\x0b\0\0\0Hello world".to_vec(),
+				b":abcd".to_vec() => b"\x0b\0\0\0Hello world".to_vec(),
+				b":abc".to_vec() => b"\x0b\0\0\0Hello world".to_vec(),
+				b":abdd".to_vec() => b"\x0b\0\0\0Hello world".to_vec()
+			],
+			children_default: map![],
+		});
+
+		t.execute_with(|| {
+			assert_eq!(storage::clear_prefix(b":ab", Some(2)), KillStorageResult::SomeRemaining(2));
+			assert!(storage::get(b":abc").is_none());
+			assert!(storage::get(b":abcd").is_none());
+			assert!(storage::get(b":abdd").is_some());
+
+			assert_eq!(storage::clear_prefix(b":ab", Some(2)), KillStorageResult::AllRemoved(1));
+			assert!(storage::get(b":abdd").is_none());
+			assert!(storage::get(b":a").is_some());
+		});
+	}
+
 	#[test]
 	fn batch_verify_start_finish_works() {
 		let mut ext = BasicExternalities::default();
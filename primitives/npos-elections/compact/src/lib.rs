@@ -25,6 +25,7 @@ use syn::{parse::{Parse, ParseStream, Result}};
 
 mod assignment;
 mod codec;
+mod type_info;
 
 // prefix used for struct fields in compact.
 const PREFIX: &'static str = "votes";
@@ -63,7 +64,8 @@ pub(crate) fn syn_err(message: &'static str) -> syn::Error {
 ///
 /// The generated struct is by default deriving both `Encode` and `Decode`. This is okay but could
 /// lead to many 0s in the solution. If prefixed with `#[compact]`, then a custom compact encoding
-/// for numbers will be used, similar to how `parity-scale-codec`'s `Compact` works.
+/// for numbers will be used, similar to how `parity-scale-codec`'s `Compact` works. Either way,
+/// the generated `TypeInfo` describes the encoding in use.
 ///
 /// ```ignore
 /// generate_solution_type!(
@@ -95,6 +97,15 @@ pub fn generate_solution_type(item: TokenStream) -> TokenStream {
 		compact_encoding,
 	).unwrap_or_else(|e| e.to_compile_error());
 
+	let type_info_impl = type_info::type_info_impl(
+		ident.clone(),
+		voter_type.clone(),
+		target_type.clone(),
+		weight_type.clone(),
+		count,
+		compact_encoding,
+	);
+
 	let assignment_impls = assignment::assignment(
 		ident.clone(),
 		voter_type.clone(),
@@ -106,6 +117,7 @@ pub fn generate_solution_type(item: TokenStream) -> TokenStream {
 	quote!(
 		#imports
 		#solution_struct
+		#type_info_impl
 		#assignment_impls
 	).into()
 }
@@ -110,6 +110,8 @@ pub use balancing::*;
 pub use codec;
 #[doc(hidden)]
 pub use sp_arithmetic;
+#[doc(hidden)]
+pub use sp_type_info;
 
 /// Simple Extension trait to easily convert `None` from index closures to `Err`.
 ///
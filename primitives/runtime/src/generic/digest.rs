@@ -25,9 +25,10 @@ use sp_std::prelude::*;
 use crate::ConsensusEngineId;
 use crate::codec::{Decode, Encode, Input, Error};
 use sp_core::{ChangesTrieConfiguration, RuntimeDebug};
+use sp_type_info::{Field, MetaType, Path, Type, TypeDef, TypeInfo, TypeParameter, Variant};
 
 /// Generic header digest.
-#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug)]
+#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, TypeInfo)]
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize, parity_util_mem::MallocSizeOf))]
 pub struct Digest<Hash> {
 	/// A list of logs in the digest.
@@ -118,7 +119,7 @@ pub enum DigestItem<Hash> {
 }
 
 /// Available changes trie signals.
-#[derive(PartialEq, Eq, Clone, Encode, Decode)]
+#[derive(PartialEq, Eq, Clone, Encode, Decode, TypeInfo)]
 #[cfg_attr(feature = "std", derive(Debug, parity_util_mem::MallocSizeOf))]
 pub enum ChangesTrieSignal {
 	/// New changes trie configuration is enacted, starting from **next block**.
@@ -307,6 +308,52 @@ impl<Hash: Decode> Decode for DigestItem<Hash> {
 	}
 }
 
+impl<Hash: TypeInfo + 'static> TypeInfo for DigestItem<Hash> {
+	type Identity = Self;
+
+	fn type_info() -> Type {
+		let variant = |name, item_type: DigestItemType, fields| {
+			Variant::new(name, fields, item_type as u8, Vec::new())
+		};
+		let engine_message = || vec![
+			Field::new::<ConsensusEngineId>(None, Some("ConsensusEngineId"), Vec::new()),
+			Field::new::<Vec<u8>>(None, Some("Vec<u8>"), Vec::new()),
+		];
+
+		Type::new(
+			Path::new("DigestItem", module_path!()),
+			vec![TypeParameter::new("Hash", Some(MetaType::new::<Hash>()))],
+			TypeDef::Variant(vec![
+				variant(
+					"ChangesTrieRoot",
+					DigestItemType::ChangesTrieRoot,
+					vec![Field::new::<Hash>(None, Some("Hash"), Vec::new())],
+				),
+				variant("PreRuntime", DigestItemType::PreRuntime, engine_message()),
+				variant("Consensus", DigestItemType::Consensus, engine_message()),
+				variant("Seal", DigestItemType::Seal, engine_message()),
+				variant(
+					"ChangesTrieSignal",
+					DigestItemType::ChangesTrieSignal,
+					vec![
+						Field::new::<ChangesTrieSignal>(
+							None,
+							Some("ChangesTrieSignal"),
+							Vec::new(),
+						),
+					],
+				),
+				variant(
+					"Other",
+					DigestItemType::Other,
+					vec![Field::new::<Vec<u8>>(None, Some("Vec<u8>"), Vec::new())],
+				),
+			]),
+			Vec::new(),
+		)
+	}
+}
+
 impl<'a, Hash> DigestItemRef<'a, Hash> {
 	/// Cast this digest item into `ChangesTrieRoot`.
 	pub fn as_changes_trie_root(&self) -> Option<&'a Hash> {
@@ -27,9 +27,11 @@ use crate::traits::{
 };
 use crate::generic::Digest;
 use sp_core::U256;
+use sp_type_info::{Field, MetaType, Path, Type, TypeDef, TypeInfo, TypeParameter};
 use sp_std::{
 	convert::TryFrom,
 	fmt::Debug,
+	prelude::*,
 };
 
 /// Abstraction over a block header for a substrate chain.
@@ -121,6 +123,40 @@ impl<Number, Hash> codec::EncodeLike for Header<Number, Hash> where
 	Hash::Output: Encode,
 {}
 
+impl<Number, Hash> TypeInfo for Header<Number, Hash> where
+	Number: HasCompact + Copy + Into<U256> + TryFrom<U256> + TypeInfo + 'static,
+	Hash: HashT + 'static,
+	Hash::Output: TypeInfo,
+{
+	type Identity = Self;
+
+	fn type_info() -> Type {
+		Type::new(
+			Path::new("Header", module_path!()),
+			vec![
+				TypeParameter::new("Number", Some(MetaType::new::<Number>())),
+				TypeParameter::new("Hash", None),
+			],
+			TypeDef::Composite(vec![
+				Field::new::<Hash::Output>(Some("parent_hash"), Some("Hash::Output"), Vec::new()),
+				Field::new::<codec::Compact<Number>>(Some("number"), Some("Number"), Vec::new()),
+				Field::new::<Hash::Output>(Some("state_root"), Some("Hash::Output"), Vec::new()),
+				Field::new::<Hash::Output>(
+					Some("extrinsics_root"),
+					Some("Hash::Output"),
+					Vec::new(),
+				),
+				Field::new::<Digest<Hash::Output>>(
+					Some("digest"),
+					Some("Digest<Hash::Output>"),
+					Vec::new(),
+				),
+			]),
+			Vec::new(),
+		)
+	}
+}
+
 impl<Number, Hash> traits::Header for Header<Number, Hash> where
 	Number: Member + MaybeSerializeDeserialize + Debug + sp_std::hash::Hash + MaybeDisplay +
 		AtLeast32BitUnsigned + Codec + Copy + Into<U256> + TryFrom<U256> + sp_std::str::FromStr +
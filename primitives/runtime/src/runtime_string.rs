@@ -18,6 +18,7 @@
 use codec::{Encode, Decode};
 use sp_core::RuntimeDebug;
 use sp_std::vec::Vec;
+use sp_type_info::{Type, TypeInfo};
 
 /// A string that wraps a `&'static str` in the runtime and `String`/`Vec<u8>` on decode.
 #[derive(Eq, RuntimeDebug, Clone)]
@@ -84,6 +85,14 @@ impl Decode for RuntimeString {
 	}
 }
 
+impl TypeInfo for RuntimeString {
+	type Identity = str;
+
+	fn type_info() -> Type {
+		str::type_info()
+	}
+}
+
 #[cfg(feature = "std")]
 impl std::fmt::Display for RuntimeString {
 	fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
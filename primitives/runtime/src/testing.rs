@@ -28,6 +28,7 @@ use crate::traits::ValidateUnsigned;
 use crate::{generic, KeyTypeId, CryptoTypeId, ApplyExtrinsicResultWithInfo};
 pub use sp_core::{H256, sr25519};
 use sp_core::{crypto::{CryptoType, Dummy, key_types, Public}, U256};
+use sp_type_info::TypeInfo;
 use crate::transaction_validity::{TransactionValidity, TransactionValidityError, TransactionSource};
 
 /// A dummy type which can be used instead of regular cryptographic primitives.
@@ -36,7 +37,10 @@ use crate::transaction_validity::{TransactionValidity, TransactionValidityError,
 /// 2. Can be converted to any `Public` key.
 /// 3. Implements `RuntimeAppPublic` so it can be used instead of regular application-specific
 ///    crypto.
-#[derive(Default, PartialEq, Eq, Clone, Encode, Decode, Debug, Hash, Serialize, Deserialize, PartialOrd, Ord)]
+#[derive(
+	Default, PartialEq, Eq, Clone, Encode, Decode, Debug, Hash, Serialize, Deserialize,
+	PartialOrd, Ord, TypeInfo,
+)]
 pub struct UintAuthorityId(pub u64);
 
 impl From<u64> for UintAuthorityId {
@@ -1130,7 +1130,8 @@ macro_rules! count {
 /// Implement `OpaqueKeys` for a described struct.
 ///
 /// Every field type must implement [`BoundToRuntimeAppPublic`](crate::BoundToRuntimeAppPublic).
-/// `KeyTypeIdProviders` is set to the types given as fields.
+/// `KeyTypeIdProviders` is set to the types given as fields. The struct also implements
+/// `TypeInfo`, so the public keys of the fields must implement it as well.
 ///
 /// ```rust
 /// use sp_runtime::{
@@ -1256,6 +1257,29 @@ macro_rules! impl_opaque_keys {
 				}
 			}
 		}
+
+		impl $crate::sp_type_info::TypeInfo for $name {
+			type Identity = Self;
+
+			fn type_info() -> $crate::sp_type_info::Type {
+				$crate::sp_type_info::Type::new(
+					$crate::sp_type_info::Path::new(stringify!($name), module_path!()),
+					$crate::sp_std::vec::Vec::new(),
+					$crate::sp_type_info::TypeDef::Composite($crate::sp_std::vec![
+						$(
+							$crate::sp_type_info::Field::new::<
+								<$type as $crate::BoundToRuntimeAppPublic>::Public
+							>(
+								Some(stringify!($field)),
+								Some(stringify!(<$type as BoundToRuntimeAppPublic>::Public)),
+								$crate::sp_std::vec::Vec::new(),
+							),
+						)*
+					]),
+					$crate::sp_std::vec::Vec::new(),
+				)
+			}
+		}
 	};
 }
 
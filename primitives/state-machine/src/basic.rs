@@ -214,13 +214,15 @@ impl Externalities for BasicExternalities {
 		self.inner.children_default.remove(child_info.storage_key());
 	}
 
-	fn clear_prefix(&mut self, prefix: &[u8]) {
+	fn clear_prefix(&mut self, prefix: &[u8], limit: Option<u32>) -> (bool, u32) {
 		if is_child_storage_key(prefix) {
 			warn!(
 				target: "trie",
 				"Refuse to clear prefix that is part of child storage key via main storage"
 			);
-			return;
+			// Child storage can never be removed through the main storage, so report that nothing
+			// is left to remove rather than making callers retry forever.
+			return (true, 0);
 		}
 
 		let to_remove = self.inner.top.range::<[u8], _>((Bound::Included(prefix), Bound::Unbounded))
@@ -229,9 +231,15 @@ impl Externalities for BasicExternalities {
 			.cloned()
 			.collect::<Vec<_>>();
 
+		let mut num_removed = 0;
 		for key in to_remove {
+			if limit.map_or(false, |limit| num_removed >= limit) {
+				return (false, num_removed);
+			}
 			self.inner.top.remove(&key);
+			num_removed += 1;
 		}
+		(true, num_removed)
 	}
 
 	fn clear_child_prefix(
@@ -425,22 +425,60 @@ where
 		});
 	}
 
-	fn clear_prefix(&mut self, prefix: &[u8]) {
-		trace!(target: "state", "{:04x}: ClearPrefix {}",
+	fn clear_prefix(&mut self, prefix: &[u8], limit: Option<u32>) -> (bool, u32) {
+		trace!(target: "state", "{:04x}: ClearPrefix {} limit={:?}",
 			self.id,
 			HexDisplay::from(&prefix),
+			limit,
 		);
 		let _guard = guard();
 		if is_child_storage_key(prefix) {
 			warn!(target: "trie", "Refuse to directly clear prefix that is part of child storage key");
-			return;
+			// Child storage can never be removed through the main storage, so report that nothing
+			// is left to remove rather than making callers retry forever.
+			return (true, 0);
 		}
 
 		self.mark_dirty();
-		self.overlay.clear_prefix(prefix);
-		self.backend.for_keys_with_prefix(prefix, |key| {
-			self.overlay.set_storage(key.to_vec(), None);
-		});
+
+		let limit = match limit {
+			Some(limit) => limit,
+			None => {
+				// Without a limit the backend and the overlay are cleared in bulk. Keys which were
+				// already removed earlier in this block are not counted.
+				let backend = self.backend;
+				let mut num_removed = self.overlay.changes()
+					.filter(|(key, value)| key.starts_with(prefix) && value.value().is_some())
+					.filter(|(key, _)| !backend.exists_storage(key).unwrap_or(false))
+					.count() as u32;
+				self.backend.for_keys_with_prefix(prefix, |key| {
+					if self.overlay.storage(key) != Some(None) {
+						num_removed += 1;
+					}
+					self.overlay.set_storage(key.to_vec(), None);
+				});
+				self.overlay.clear_prefix(prefix);
+				return (true, num_removed);
+			},
+		};
+
+		// Walk the merged view of overlay and backend, so that keys which were already removed
+		// earlier in this block are neither counted nor held against the limit again.
+		let mut num_removed = 0;
+		let mut next_key = if self.exists_storage(prefix) {
+			Some(prefix.to_vec())
+		} else {
+			self.next_storage_key(prefix)
+		};
+		while let Some(key) = next_key.take().filter(|k| k.starts_with(prefix)) {
+			if num_removed == limit {
+				return (false, num_removed);
+			}
+			self.overlay.set_storage(key.clone(), None);
+			num_removed += 1;
+			next_key = self.next_storage_key(&key);
+		}
+		(true, num_removed)
 	}
 
 	fn clear_child_prefix(
@@ -1099,6 +1099,49 @@ mod tests {
 		assert_eq!(remote_result, local_result);
 	}
 
+	#[test]
+	fn clear_prefix_with_limit_in_ext_works() {
+		let initial: BTreeMap<_, _> = map![
+			b"aaa".to_vec() => b"0".to_vec(),
+			b"abb".to_vec() => b"1".to_vec(),
+			b"abc".to_vec() => b"2".to_vec(),
+			b"bbb".to_vec() => b"3".to_vec()
+		];
+		let mut state = InMemoryBackend::<BlakeTwo256>::from(initial);
+		let backend = state.as_trie_backend().unwrap();
+
+		let mut overlay = OverlayedChanges::default();
+		overlay.set_storage(b"aba".to_vec(), Some(b"1312".to_vec()));
+		overlay.set_storage(b"abd".to_vec(), Some(b"69".to_vec()));
+
+		let mut offchain_overlay = Default::default();
+		let mut cache = StorageTransactionCache::default();
+		let mut ext = Ext::new(
+			&mut overlay,
+			&mut offchain_overlay,
+			&mut cache,
+			backend,
+			changes_trie::disabled_state::<_, u64>(),
+			None,
+		);
+
+		assert_eq!(ext.clear_prefix(b"ab", Some(3)), (false, 3));
+		assert_eq!(ext.storage(b"aba"), None);
+		assert_eq!(ext.storage(b"abb"), None);
+		assert_eq!(ext.storage(b"abc"), None);
+		assert_eq!(ext.storage(b"abd"), Some(b"69".to_vec()));
+
+		// Keys removed by the first call are not counted again.
+		assert_eq!(ext.clear_prefix(b"ab", Some(3)), (true, 1));
+		assert_eq!(ext.storage(b"abd"), None);
+		assert_eq!(ext.clear_prefix(b"ab", Some(3)), (true, 0));
+
+		assert_eq!(ext.storage(b"aaa"), Some(b"0".to_vec()));
+		assert_eq!(ext.storage(b"bbb"), Some(b"3".to_vec()));
+		// Child storage is never removed through the main storage, nor reported as remaining.
+		assert_eq!(ext.clear_prefix(b":child_storage:default:", Some(3)), (true, 0));
+	}
+
 	#[test]
 	fn clear_prefix_in_ext_works() {
 		let initial: BTreeMap<_, _> = map![
@@ -1128,7 +1171,7 @@ mod tests {
 				changes_trie::disabled_state::<_, u64>(),
 				None,
 			);
-			ext.clear_prefix(b"ab");
+			assert_eq!(ext.clear_prefix(b"ab", None), (true, 4));
 		}
 		overlay.commit_transaction().unwrap();
 
@@ -0,0 +1,288 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2020 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Derive macro for the `TypeInfo` trait of `sp-type-info`.
+
+use proc_macro::TokenStream;
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use proc_macro_crate::crate_name;
+use quote::quote;
+use syn::{
+	parenthesized, parse::ParseStream, parse_macro_input, parse_quote, punctuated::Punctuated,
+	spanned::Spanned, token, Attribute, Data, DeriveInput, Error, Fields, Ident, Lit, Meta,
+	NestedMeta, Result, Variant, WherePredicate,
+};
+
+/// Derives `TypeInfo` for a struct or an enum.
+///
+/// The `codec` attributes `skip`, `compact` and `index` are taken into account, so that the
+/// generated description matches the SCALE encoding derived by `parity-scale-codec`.
+///
+/// Every generic type parameter is required to implement `TypeInfo`, unless it is named in
+/// `#[scale_info(skip_type_params(..))]`. This is meant for parameters that are not part of the
+/// encoding, like the `T: Trait` of a pallet. Skipped parameters are still listed in the
+/// description, but without a type.
+///
+/// `#[scale_info(bounds(..))]` replaces the generated bounds with the given where predicates.
+#[proc_macro_derive(TypeInfo, attributes(scale_info, codec))]
+pub fn derive_type_info(input: TokenStream) -> TokenStream {
+	let input = parse_macro_input!(input as DeriveInput);
+	generate(input).unwrap_or_else(|e| e.to_compile_error()).into()
+}
+
+fn generate(input: DeriveInput) -> Result<TokenStream2> {
+	let crate_path = crate_access();
+	let attrs = ScaleInfoAttrs::from_attrs(&input.attrs)?;
+	let skipped_params = attrs.skip_type_params;
+
+	let ident = &input.ident;
+	let mut generics = input.generics.clone();
+	let params = generics.type_params().map(|p| p.ident.clone()).collect::<Vec<_>>();
+	if let Some(bounds) = attrs.bounds {
+		generics.make_where_clause().predicates.extend(bounds);
+	} else if !params.is_empty() {
+		let where_clause = generics.make_where_clause();
+		for param in &params {
+			if skipped_params.contains(param) {
+				where_clause.predicates.push(parse_quote!(#param: 'static));
+			} else {
+				where_clause.predicates.push(parse_quote!(#param: #crate_path::TypeInfo + 'static));
+			}
+		}
+	}
+	let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+	let type_params = params.iter()
+		.map(|p| {
+			let name = p.to_string();
+			if skipped_params.contains(p) {
+				quote!( #crate_path::TypeParameter::new(#name, None) )
+			} else {
+				quote!( #crate_path::TypeParameter::new(#name, Some(#crate_path::MetaType::new::<#p>())) )
+			}
+		});
+	let docs = docs(&input.attrs);
+	let def = match &input.data {
+		Data::Struct(data) => {
+			let fields = fields(&data.fields, &crate_path);
+			quote!( #crate_path::TypeDef::Composite(#fields) )
+		},
+		Data::Enum(data) => {
+			let variants = data.variants.iter()
+				.filter(|v| !has_codec_flag(&v.attrs, "skip"))
+				.enumerate()
+				.map(|(i, v)| variant(v, i, &crate_path))
+				.collect::<Result<Vec<_>>>()?;
+			quote!( #crate_path::TypeDef::Variant(#crate_path::sp_std::vec![ #( #variants ),* ]) )
+		},
+		Data::Union(_) => return Err(Error::new(input.span(), "Unions are not supported")),
+	};
+
+	Ok(quote! {
+		const _: () = {
+			impl #impl_generics #crate_path::TypeInfo for #ident #ty_generics #where_clause {
+				type Identity = Self;
+
+				fn type_info() -> #crate_path::Type {
+					#crate_path::Type::new(
+						#crate_path::Path::new(stringify!(#ident), module_path!()),
+						#crate_path::sp_std::vec![ #( #type_params ),* ],
+						#def,
+						#crate_path::sp_std::vec![ #( #docs ),* ],
+					)
+				}
+			}
+		};
+	})
+}
+
+/// Generates the path to the `sp-type-info` crate, either directly or through `frame-support`.
+fn crate_access() -> TokenStream2 {
+	if let Ok(name) = crate_name("sp-type-info") {
+		let name = Ident::new(&name.replace('-', "_"), Span::call_site());
+		quote!( ::#name )
+	} else if let Ok(name) = crate_name("frame-support") {
+		let name = Ident::new(&name.replace('-', "_"), Span::call_site());
+		quote!( ::#name::type_info )
+	} else {
+		quote!( ::sp_type_info )
+	}
+}
+
+fn variant(variant: &Variant, position: usize, crate_path: &TokenStream2) -> Result<TokenStream2> {
+	let name = variant.ident.to_string();
+	let fields = fields(&variant.fields, crate_path);
+	let docs = docs(&variant.attrs);
+	let index = match codec_index(&variant.attrs)? {
+		Some(index) => quote!( #index ),
+		None => match &variant.discriminant {
+			Some((_, expr)) => quote!( (#expr) as u8 ),
+			None => {
+				let position = position as u8;
+				quote!( #position )
+			},
+		},
+	};
+
+	Ok(quote! {
+		#crate_path::Variant::new(
+			#name,
+			#fields,
+			#index,
+			#crate_path::sp_std::vec![ #( #docs ),* ],
+		)
+	})
+}
+
+fn fields(fields: &Fields, crate_path: &TokenStream2) -> TokenStream2 {
+	let fields = fields.iter()
+		.filter(|f| !has_codec_flag(&f.attrs, "skip"))
+		.map(|f| {
+			let ty = &f.ty;
+			let name = match &f.ident {
+				Some(ident) => {
+					let name = ident.to_string().trim_start_matches("r#").to_string();
+					quote!( Some(#name) )
+				},
+				None => quote!( None ),
+			};
+			let type_name = type_name(quote!(#ty).to_string());
+			let docs = docs(&f.attrs);
+			let ty = if has_codec_flag(&f.attrs, "compact") {
+				quote!( #crate_path::codec::Compact<#ty> )
+			} else {
+				quote!( #ty )
+			};
+
+			quote! {
+				#crate_path::Field::new::<#ty>(
+					#name,
+					Some(#type_name),
+					#crate_path::sp_std::vec![ #( #docs ),* ],
+				)
+			}
+		});
+
+	quote!( #crate_path::sp_std::vec![ #( #fields ),* ] )
+}
+
+/// Turns the stringified tokens of a type into the way it is usually written.
+fn type_name(tokens: String) -> String {
+	tokens
+		.replace(" :: ", "::")
+		.replace(":: ", "::")
+		.replace(" < ", "<")
+		.replace("< ", "<")
+		.replace(" >", ">")
+		.replace(" ,", ",")
+		.replace("& ", "&")
+		.replace("[ ", "[")
+		.replace(" ]", "]")
+		.replace(" ;", ";")
+		.replace("( ", "(")
+		.replace(" )", ")")
+}
+
+fn docs(attrs: &[Attribute]) -> Vec<String> {
+	attrs.iter()
+		.filter_map(|attr| match attr.parse_meta() {
+			Ok(Meta::NameValue(meta)) if meta.path.is_ident("doc") => match meta.lit {
+				Lit::Str(doc) => Some(doc.value()),
+				_ => None,
+			},
+			_ => None,
+		})
+		.collect()
+}
+
+fn codec_items(attrs: &[Attribute]) -> Vec<NestedMeta> {
+	attrs.iter()
+		.filter(|attr| attr.path.is_ident("codec"))
+		.filter_map(|attr| match attr.parse_meta() {
+			Ok(Meta::List(list)) => Some(list.nested.into_iter()),
+			_ => None,
+		})
+		.flatten()
+		.collect()
+}
+
+fn has_codec_flag(attrs: &[Attribute], flag: &str) -> bool {
+	codec_items(attrs).iter().any(|item| match item {
+		NestedMeta::Meta(Meta::Path(path)) => path.is_ident(flag),
+		_ => false,
+	})
+}
+
+fn codec_index(attrs: &[Attribute]) -> Result<Option<u8>> {
+	for item in codec_items(attrs) {
+		if let NestedMeta::Meta(Meta::NameValue(meta)) = item {
+			if meta.path.is_ident("index") {
+				let index = match &meta.lit {
+					Lit::Str(s) => s.value().parse::<u8>().ok(),
+					Lit::Int(i) => i.base10_parse::<u8>().ok(),
+					_ => None,
+				};
+				return index
+					.map(Some)
+					.ok_or_else(|| Error::new(meta.lit.span(), "Numeric index expected"));
+			}
+		}
+	}
+
+	Ok(None)
+}
+
+/// The arguments of the `scale_info` attributes of a type.
+#[derive(Default)]
+struct ScaleInfoAttrs {
+	skip_type_params: Vec<Ident>,
+	bounds: Option<Punctuated<WherePredicate, token::Comma>>,
+}
+
+impl ScaleInfoAttrs {
+	fn from_attrs(attrs: &[Attribute]) -> Result<Self> {
+		let mut parsed = Self::default();
+		for attr in attrs.iter().filter(|attr| attr.path.is_ident("scale_info")) {
+			attr.parse_args_with(|input: ParseStream| {
+				loop {
+					let name: Ident = input.parse()?;
+					let content;
+					parenthesized!(content in input);
+					if name == "skip_type_params" {
+						let params = Punctuated::<Ident, token::Comma>::parse_terminated(&content)?;
+						parsed.skip_type_params.extend(params);
+					} else if name == "bounds" {
+						let bounds = Punctuated::<WherePredicate, token::Comma>::parse_terminated(&content)?;
+						parsed.bounds.get_or_insert_with(Punctuated::new).extend(bounds);
+					} else {
+						return Err(Error::new(
+							name.span(),
+							"Unknown attribute, expected `skip_type_params(..)` or `bounds(..)`",
+						))
+					}
+
+					if input.is_empty() {
+						return Ok(())
+					}
+					input.parse::<token::Comma>()?;
+				}
+			})?;
+		}
+
+		Ok(parsed)
+	}
+}
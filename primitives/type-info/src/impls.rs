@@ -0,0 +1,263 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2020 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Implementations of [`TypeInfo`] for core, alloc and other common types.
+
+use codec::Compact;
+use primitive_types::{H160, H256, H512, U256};
+use sp_std::{
+	collections::{btree_map::BTreeMap, btree_set::BTreeSet},
+	marker::PhantomData,
+	prelude::*,
+};
+
+use crate::{Field, MetaType, Path, Type, TypeDef, TypeDefPrimitive, TypeInfo, TypeParameter, Variant};
+
+macro_rules! impl_primitives {
+	( $( $ty:ty => $primitive:ident, )* ) => {
+		$(
+			impl TypeInfo for $ty {
+				type Identity = Self;
+
+				fn type_info() -> Type {
+					Type::anonymous(TypeDef::Primitive(TypeDefPrimitive::$primitive))
+				}
+			}
+		)*
+	}
+}
+
+impl_primitives! {
+	bool => Bool,
+	char => Char,
+	str => Str,
+	u8 => U8,
+	u16 => U16,
+	u32 => U32,
+	u64 => U64,
+	u128 => U128,
+	i8 => I8,
+	i16 => I16,
+	i32 => I32,
+	i64 => I64,
+	i128 => I128,
+}
+
+#[cfg(feature = "std")]
+impl TypeInfo for String {
+	type Identity = str;
+
+	fn type_info() -> Type {
+		str::type_info()
+	}
+}
+
+macro_rules! impl_arrays {
+	( $( $n:expr, )* ) => {
+		$(
+			impl<T: TypeInfo + 'static> TypeInfo for [T; $n] {
+				type Identity = Self;
+
+				fn type_info() -> Type {
+					Type::anonymous(TypeDef::Array($n, MetaType::new::<T>()))
+				}
+			}
+		)*
+	}
+}
+
+impl_arrays! {
+	0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16,
+	17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32,
+	33, 40, 48, 56, 64, 65, 72, 96, 128, 160, 192, 224, 256,
+	384, 512, 768, 1024, 2048, 4096, 8192, 16384,
+}
+
+macro_rules! impl_tuples {
+	( $one:ident, ) => {
+		impl_tuple!($one,);
+	};
+	( $first:ident, $( $rest:ident, )+ ) => {
+		impl_tuple!($first, $( $rest, )+);
+		impl_tuples!($( $rest, )+);
+	};
+}
+
+macro_rules! impl_tuple {
+	( $( $ty:ident, )* ) => {
+		impl<$( $ty: TypeInfo + 'static, )*> TypeInfo for ($( $ty, )*) {
+			type Identity = Self;
+
+			fn type_info() -> Type {
+				Type::anonymous(TypeDef::Tuple(vec![ $( MetaType::new::<$ty>(), )* ]))
+			}
+		}
+	}
+}
+
+impl_tuples!(A, B, C, D, E, F, G, H, I, J, K, L, M, N, O, P,);
+
+impl TypeInfo for () {
+	type Identity = Self;
+
+	fn type_info() -> Type {
+		Type::anonymous(TypeDef::Tuple(Vec::new()))
+	}
+}
+
+impl<T: TypeInfo + 'static> TypeInfo for [T] {
+	type Identity = Self;
+
+	fn type_info() -> Type {
+		Type::anonymous(TypeDef::Sequence(MetaType::new::<T>()))
+	}
+}
+
+impl<T: TypeInfo + 'static> TypeInfo for Vec<T> {
+	type Identity = [T];
+
+	fn type_info() -> Type {
+		<[T]>::type_info()
+	}
+}
+
+impl<T: TypeInfo + 'static> TypeInfo for BTreeSet<T> {
+	type Identity = Self;
+
+	fn type_info() -> Type {
+		Type::new(
+			Path::from_segments(vec!["BTreeSet"]),
+			vec![TypeParameter::new("T", Some(MetaType::new::<T>()))],
+			TypeDef::Composite(vec![Field::unnamed::<[T]>()]),
+			Vec::new(),
+		)
+	}
+}
+
+impl<K: TypeInfo + 'static, V: TypeInfo + 'static> TypeInfo for BTreeMap<K, V> {
+	type Identity = Self;
+
+	fn type_info() -> Type {
+		Type::new(
+			Path::from_segments(vec!["BTreeMap"]),
+			vec![
+				TypeParameter::new("K", Some(MetaType::new::<K>())),
+				TypeParameter::new("V", Some(MetaType::new::<V>())),
+			],
+			TypeDef::Composite(vec![Field::unnamed::<[(K, V)]>()]),
+			Vec::new(),
+		)
+	}
+}
+
+impl<T: TypeInfo + 'static> TypeInfo for Option<T> {
+	type Identity = Self;
+
+	fn type_info() -> Type {
+		Type::new(
+			Path::from_segments(vec!["Option"]),
+			vec![TypeParameter::new("T", Some(MetaType::new::<T>()))],
+			TypeDef::Variant(vec![
+				Variant::new("None", Vec::new(), 0, Vec::new()),
+				Variant::new("Some", vec![Field::unnamed::<T>()], 1, Vec::new()),
+			]),
+			Vec::new(),
+		)
+	}
+}
+
+impl<T: TypeInfo + 'static, E: TypeInfo + 'static> TypeInfo for Result<T, E> {
+	type Identity = Self;
+
+	fn type_info() -> Type {
+		Type::new(
+			Path::from_segments(vec!["Result"]),
+			vec![
+				TypeParameter::new("T", Some(MetaType::new::<T>())),
+				TypeParameter::new("E", Some(MetaType::new::<E>())),
+			],
+			TypeDef::Variant(vec![
+				Variant::new("Ok", vec![Field::unnamed::<T>()], 0, Vec::new()),
+				Variant::new("Err", vec![Field::unnamed::<E>()], 1, Vec::new()),
+			]),
+			Vec::new(),
+		)
+	}
+}
+
+impl<T: TypeInfo + ?Sized + 'static> TypeInfo for Box<T> {
+	type Identity = T::Identity;
+
+	fn type_info() -> Type {
+		<T::Identity as TypeInfo>::type_info()
+	}
+}
+
+impl<T: TypeInfo + ?Sized + 'static> TypeInfo for &T {
+	type Identity = T::Identity;
+
+	fn type_info() -> Type {
+		<T::Identity as TypeInfo>::type_info()
+	}
+}
+
+impl<T: ?Sized> TypeInfo for PhantomData<T> {
+	type Identity = PhantomData<()>;
+
+	fn type_info() -> Type {
+		Type::new(
+			Path::from_segments(vec!["PhantomData"]),
+			Vec::new(),
+			TypeDef::Composite(Vec::new()),
+			Vec::new(),
+		)
+	}
+}
+
+impl<T: TypeInfo + 'static> TypeInfo for Compact<T> {
+	type Identity = Self;
+
+	fn type_info() -> Type {
+		Type::anonymous(TypeDef::Compact(MetaType::new::<T>()))
+	}
+}
+
+macro_rules! impl_primitive_types {
+	( $( $ty:ident => $inner:ty, $inner_name:expr, )* ) => {
+		$(
+			impl TypeInfo for $ty {
+				type Identity = Self;
+
+				fn type_info() -> Type {
+					Type::new(
+						Path::from_segments(vec!["primitive_types", stringify!($ty)]),
+						Vec::new(),
+						TypeDef::Composite(vec![Field::new::<$inner>(None, Some($inner_name), Vec::new())]),
+						Vec::new(),
+					)
+				}
+			}
+		)*
+	}
+}
+
+impl_primitive_types! {
+	H160 => [u8; 20], "[u8; 20]",
+	H256 => [u8; 32], "[u8; 32]",
+	H512 => [u8; 64], "[u8; 64]",
+	U256 => [u64; 4], "[u64; 4]",
+}
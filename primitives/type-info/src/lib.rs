@@ -0,0 +1,322 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2020 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Portable type information for runtime types.
+//!
+//! A type implementing [`TypeInfo`] describes the shape of its SCALE encoding: its path, its
+//! generic parameters and its definition (a composite, a set of variants, a sequence, ...).
+//! References to other types inside a definition are expressed as [`MetaType`]s, which are
+//! resolved lazily.
+//!
+//! Type information is collected into a [`Registry`]. Registering a type assigns it a compact
+//! [`TypeRef`] and registers every type it refers to, each type being stored only once.
+//! Once everything is registered the registry is turned into a [`PortableRegistry`], which is
+//! self-contained and can be encoded, for example as part of the runtime metadata.
+//!
+//! `TypeInfo` can be derived with `#[derive(TypeInfo)]`. The derive honours the `codec`
+//! attributes `skip`, `compact` and `index` so that the description matches the encoding.
+//! Generic parameters that are not encoded (e.g. the runtime `T: Trait` parameter) can be
+//! excluded from the bounds with `#[scale_info(skip_type_params(T))]`, and the bounds can be
+//! replaced altogether with `#[scale_info(bounds(..))]`.
+//!
+//! The API, the derive attributes and the encoding of a [`PortableRegistry`] follow those of
+//! the `scale-info` crate (version 1), so that tools built on `scale-info` can decode the
+//! registries produced here. This crate only exists because `scale-info` requires version 2 of
+//! `parity-scale-codec`, and implementing its `TypeInfo` for `H256` and friends requires the
+//! `scale-info` support of `primitive-types`, which also depends on version 2; both crates are
+//! still on version 1 in this tree. Once they are upgraded, this crate is to be replaced by a
+//! re-export of `scale-info`.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![warn(missing_docs)]
+
+// Allows the derive macro to refer to this crate as `::sp_type_info` from within the crate.
+extern crate self as sp_type_info;
+
+mod impls;
+mod registry;
+mod ty;
+
+pub use registry::{PortableRegistry, PortableType, Registry, TypeRef};
+pub use ty::{
+	Field, Form, IntoPortable, MetaForm, Path, PortableForm, Type, TypeDef, TypeDefPrimitive,
+	TypeParameter, Variant,
+};
+pub use sp_type_info_derive::TypeInfo;
+
+#[doc(hidden)]
+pub use codec;
+#[doc(hidden)]
+pub use sp_std;
+
+use sp_std::{any::TypeId, cmp::Ordering, fmt};
+
+/// A type which can describe the shape of its SCALE encoding.
+pub trait TypeInfo {
+	/// The type that is used to identify this type in a [`Registry`].
+	///
+	/// This is `Self` for almost all types. Wrappers which are encoded transparently, like
+	/// `Box<T>` or `&T`, use the identity of the wrapped type so that they share its entry.
+	type Identity: ?Sized + TypeInfo + 'static;
+
+	/// Returns the description of this type.
+	fn type_info() -> Type;
+}
+
+/// A lazily resolved reference to the type information of a type.
+#[derive(Clone, Copy)]
+pub struct MetaType {
+	fn_type_info: fn() -> Type,
+	type_id: TypeId,
+}
+
+impl MetaType {
+	/// Creates a new reference to the type information of `T`.
+	pub fn new<T: TypeInfo + ?Sized>() -> Self {
+		MetaType {
+			fn_type_info: <T::Identity as TypeInfo>::type_info,
+			type_id: TypeId::of::<T::Identity>(),
+		}
+	}
+
+	/// Returns the type information of the referenced type.
+	pub fn type_info(&self) -> Type {
+		(self.fn_type_info)()
+	}
+
+	/// Returns the identifier of the referenced type.
+	pub fn type_id(&self) -> TypeId {
+		self.type_id
+	}
+}
+
+impl PartialEq for MetaType {
+	fn eq(&self, other: &Self) -> bool {
+		self.type_id == other.type_id
+	}
+}
+
+impl Eq for MetaType {}
+
+impl PartialOrd for MetaType {
+	fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+		Some(self.cmp(other))
+	}
+}
+
+impl Ord for MetaType {
+	fn cmp(&self, other: &Self) -> Ordering {
+		self.type_id.cmp(&other.type_id)
+	}
+}
+
+impl fmt::Debug for MetaType {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		self.type_id.fmt(f)
+	}
+}
+
+/// Returns a [`MetaType`] referring to `T`.
+pub fn meta_type<T: TypeInfo + ?Sized>() -> MetaType {
+	MetaType::new::<T>()
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use codec::{Compact, Encode};
+
+	#[derive(TypeInfo)]
+	struct Unit;
+
+	#[allow(dead_code)]
+	#[derive(TypeInfo, Encode)]
+	struct Named<T> {
+		/// The first field.
+		a: T,
+		#[codec(compact)]
+		b: u64,
+		#[codec(skip)]
+		c: bool,
+	}
+
+	#[allow(dead_code)]
+	#[derive(TypeInfo, Encode)]
+	enum Recursive {
+		Leaf(u32),
+		#[codec(index = "5")]
+		Node(Box<Recursive>, Box<Recursive>),
+	}
+
+	#[allow(dead_code)]
+	#[derive(TypeInfo)]
+	#[scale_info(skip_type_params(T))]
+	struct Skipped<T: 'static> {
+		value: u8,
+		_phantom: sp_std::marker::PhantomData<T>,
+	}
+
+	struct NoTypeInfo;
+
+	#[allow(dead_code)]
+	#[derive(TypeInfo)]
+	#[scale_info(bounds(T: Copy + TypeInfo + 'static))]
+	struct Bounded<T> {
+		value: T,
+	}
+
+	// Higher-ranked bounds are only checked where the type information is used.
+	#[allow(dead_code)]
+	#[derive(TypeInfo)]
+	#[scale_info(bounds(for<'a> NoTypeInfo: TypeInfo))]
+	struct Deferred(NoTypeInfo);
+
+	fn resolve(registry: &PortableRegistry, id: TypeRef) -> &Type<PortableForm> {
+		registry.resolve(id).expect("type is registered")
+	}
+
+	#[test]
+	fn types_are_registered_once() {
+		let mut registry = Registry::new();
+		let a = registry.register_type(&meta_type::<u32>());
+		let b = registry.register_type(&meta_type::<u32>());
+		let c = registry.register_type(&meta_type::<Box<u32>>());
+		assert_eq!(a, b);
+		assert_eq!(a, c);
+
+		let portable = PortableRegistry::from(registry);
+		assert_eq!(portable.types().len(), 1);
+		assert_eq!(resolve(&portable, a).def, TypeDef::Primitive(TypeDefPrimitive::U32));
+	}
+
+	#[test]
+	fn derive_follows_codec_attributes() {
+		let mut registry = Registry::new();
+		let id = registry.register_type(&meta_type::<Named<bool>>());
+		let portable = PortableRegistry::from(registry);
+
+		let ty = resolve(&portable, id);
+		assert_eq!(ty.path.segments.last().map(|s| s.as_str()), Some("Named"));
+		assert_eq!(ty.type_params.len(), 1);
+		assert_eq!(ty.type_params[0].name, "T");
+		assert_eq!(
+			resolve(&portable, ty.type_params[0].ty.expect("`T` is not skipped")).def,
+			TypeDef::Primitive(TypeDefPrimitive::Bool),
+		);
+		assert_eq!(ty.docs, Vec::<String>::new());
+
+		let fields = match &ty.def {
+			TypeDef::Composite(fields) => fields,
+			def => panic!("unexpected definition: {:?}", def),
+		};
+		assert_eq!(fields.len(), 2);
+		assert_eq!(fields[0].name.as_deref(), Some("a"));
+		assert_eq!(fields[0].type_name.as_deref(), Some("T"));
+		assert_eq!(fields[0].docs, vec![" The first field.".to_string()]);
+		assert_eq!(resolve(&portable, fields[0].ty).def, TypeDef::Primitive(TypeDefPrimitive::Bool));
+
+		let compact = resolve(&portable, fields[1].ty);
+		match &compact.def {
+			TypeDef::Compact(inner) => assert_eq!(
+				resolve(&portable, *inner).def,
+				TypeDef::Primitive(TypeDefPrimitive::U64),
+			),
+			def => panic!("unexpected definition: {:?}", def),
+		}
+
+		let value = Named { a: true, b: 1, c: false };
+		assert_eq!(value.encode(), (true, Compact(1u64)).encode());
+	}
+
+	#[test]
+	fn recursive_types_are_supported() {
+		let mut registry = Registry::new();
+		let id = registry.register_type(&meta_type::<Recursive>());
+		let portable = PortableRegistry::from(registry);
+
+		let variants = match &resolve(&portable, id).def {
+			TypeDef::Variant(variants) => variants.clone(),
+			def => panic!("unexpected definition: {:?}", def),
+		};
+		assert_eq!(variants.len(), 2);
+		assert_eq!((variants[0].name.as_str(), variants[0].index), ("Leaf", 0));
+		assert_eq!((variants[1].name.as_str(), variants[1].index), ("Node", 5));
+		assert_eq!(variants[1].fields[0].ty, id);
+		assert_eq!(variants[1].fields[1].ty, id);
+	}
+
+	#[test]
+	fn skipped_type_params_need_no_type_info() {
+		let mut registry = Registry::new();
+		let id = registry.register_type(&meta_type::<Skipped<NoTypeInfo>>());
+		let portable = PortableRegistry::from(registry);
+
+		let ty = resolve(&portable, id);
+		assert_eq!(ty.type_params, vec![TypeParameter::<PortableForm> { name: "T".to_string(), ty: None }]);
+		match &ty.def {
+			TypeDef::Composite(fields) => assert_eq!(fields.len(), 2),
+			def => panic!("unexpected definition: {:?}", def),
+		}
+	}
+
+	#[test]
+	fn bounds_can_be_replaced() {
+		let mut registry = Registry::new();
+		let id = registry.register_type(&meta_type::<Bounded<u16>>());
+		let portable = PortableRegistry::from(registry);
+
+		match &resolve(&portable, id).def {
+			TypeDef::Composite(fields) => assert_eq!(
+				resolve(&portable, fields[0].ty).def,
+				TypeDef::Primitive(TypeDefPrimitive::U16),
+			),
+			def => panic!("unexpected definition: {:?}", def),
+		}
+	}
+
+	#[test]
+	fn portable_registry_is_encoded_like_in_scale_info() {
+		let mut registry = Registry::new();
+		registry.register_type(&meta_type::<u32>());
+		let portable = PortableRegistry::from(registry);
+
+		assert_eq!(
+			portable.encode(),
+			vec![
+				4, // one type
+				0, // with id 0,
+				0, // an empty path,
+				0, // no type parameters,
+				5, 5, // the `U32` primitive definition
+				0, // and no documentation.
+			],
+		);
+	}
+
+	#[test]
+	fn portable_registry_encodes_and_decodes() {
+		use codec::Decode;
+
+		let mut registry = Registry::new();
+		registry.register_type(&meta_type::<(Option<Vec<u8>>, Result<[u8; 4], ()>)>());
+		registry.register_type(&meta_type::<Unit>());
+		let portable = PortableRegistry::from(registry);
+
+		let encoded = portable.encode();
+		assert_eq!(PortableRegistry::decode(&mut &encoded[..]).unwrap(), portable);
+	}
+}
@@ -0,0 +1,137 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2020 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Registries of type definitions.
+
+use codec::Encode;
+#[cfg(feature = "std")]
+use codec::Decode;
+#[cfg(feature = "std")]
+use serde::Serialize;
+use sp_debug_derive::RuntimeDebug;
+use sp_std::{any::TypeId, collections::btree_map::BTreeMap, prelude::*};
+
+use crate::{IntoPortable, MetaType, PortableForm, Type};
+
+/// A reference to a type of a [`PortableRegistry`], i.e. its index in the registry.
+#[derive(PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Encode, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(Decode, Serialize))]
+pub struct TypeRef(#[codec(compact)] u32);
+
+impl TypeRef {
+	/// Returns the index of the referenced type in its registry.
+	pub fn index(&self) -> u32 {
+		self.0
+	}
+}
+
+/// Collects type definitions, storing each type only once.
+#[derive(Default)]
+pub struct Registry {
+	type_table: BTreeMap<TypeId, TypeRef>,
+	types: Vec<Option<Type<PortableForm>>>,
+}
+
+impl Registry {
+	/// Creates an empty registry.
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Registers the given type and all the types it refers to.
+	///
+	/// Returns the reference to the type, which is the same for every registration of a type.
+	pub fn register_type(&mut self, ty: &MetaType) -> TypeRef {
+		if let Some(id) = self.type_table.get(&ty.type_id()) {
+			return *id;
+		}
+
+		// Reserve the entry first, so that recursive types resolve to it while the definition
+		// is being converted.
+		let id = TypeRef(self.types.len() as u32);
+		self.types.push(None);
+		self.type_table.insert(ty.type_id(), id);
+
+		let portable = ty.type_info().into_portable(self);
+		self.types[id.0 as usize] = Some(portable);
+		id
+	}
+
+	/// Registers all the given types, returning their references in the same order.
+	pub fn register_types<I>(&mut self, types: I) -> Vec<TypeRef>
+	where
+		I: IntoIterator<Item = MetaType>,
+	{
+		types.into_iter().map(|ty| self.register_type(&ty)).collect()
+	}
+}
+
+/// The self-contained set of the types of a [`Registry`], indexed by [`TypeRef`].
+#[derive(PartialEq, Eq, Clone, Encode, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(Decode, Serialize))]
+pub struct PortableRegistry {
+	types: Vec<PortableType>,
+}
+
+impl PortableRegistry {
+	/// Returns the type referred to by `id`, if any.
+	pub fn resolve(&self, id: TypeRef) -> Option<&Type<PortableForm>> {
+		self.types.get(id.0 as usize).map(|ty| &ty.ty)
+	}
+
+	/// Returns all the types of the registry, ordered by their index.
+	pub fn types(&self) -> &[PortableType] {
+		&self.types
+	}
+}
+
+impl From<Registry> for PortableRegistry {
+	fn from(registry: Registry) -> Self {
+		PortableRegistry {
+			types: registry.types
+				.into_iter()
+				.enumerate()
+				.map(|(id, ty)| PortableType {
+					id: id as u32,
+					ty: ty.expect("Entries are only reserved while the type is being registered; qed"),
+				})
+				.collect(),
+		}
+	}
+}
+
+/// A type of a [`PortableRegistry`] along with its index.
+#[derive(PartialEq, Eq, Clone, Encode, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(Decode, Serialize))]
+pub struct PortableType {
+	#[codec(compact)]
+	id: u32,
+	#[cfg_attr(feature = "std", serde(rename = "type"))]
+	ty: Type<PortableForm>,
+}
+
+impl PortableType {
+	/// Returns the index of the type in its registry.
+	pub fn id(&self) -> u32 {
+		self.id
+	}
+
+	/// Returns the description of the type.
+	pub fn ty(&self) -> &Type<PortableForm> {
+		&self.ty
+	}
+}
@@ -0,0 +1,356 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2020 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Definitions of types.
+//!
+//! All definitions are generic over a [`Form`], which determines how references to other types
+//! and strings are represented. [`MetaForm`] is used while describing types, [`PortableForm`]
+//! once they are registered in a [`Registry`](crate::Registry).
+
+use codec::Encode;
+#[cfg(feature = "std")]
+use codec::Decode;
+#[cfg(feature = "std")]
+use serde::Serialize;
+use sp_debug_derive::RuntimeDebug;
+use sp_std::{fmt::Debug, prelude::*};
+
+use crate::{MetaType, Registry, TypeRef};
+
+/// Determines the representation of type references and strings in type definitions.
+pub trait Form {
+	/// The representation of a reference to another type.
+	type Type: PartialEq + Eq + Clone + Debug;
+	/// The representation of a string.
+	type String: PartialEq + Eq + Clone + Debug;
+}
+
+/// The form used to describe types, referring to other types through [`MetaType`].
+#[derive(PartialEq, Eq, Clone, Copy, RuntimeDebug)]
+pub enum MetaForm {}
+
+impl Form for MetaForm {
+	type Type = MetaType;
+	type String = &'static str;
+}
+
+/// The self-contained form of registered types, referring to other types by [`TypeRef`].
+#[derive(PartialEq, Eq, Clone, Copy, RuntimeDebug)]
+pub enum PortableForm {}
+
+impl Form for PortableForm {
+	type Type = TypeRef;
+	#[cfg(feature = "std")]
+	type String = String;
+	#[cfg(not(feature = "std"))]
+	type String = &'static str;
+}
+
+/// Converts a definition in [`MetaForm`] into its [`PortableForm`] counterpart.
+pub trait IntoPortable {
+	/// The portable counterpart of `Self`.
+	type Output;
+
+	/// Converts `self` into its portable form, registering all referenced types in `registry`.
+	fn into_portable(self, registry: &mut Registry) -> Self::Output;
+}
+
+/// Converts a static string into the string representation of [`PortableForm`].
+pub(crate) fn portable_string(s: &'static str) -> <PortableForm as Form>::String {
+	s.into()
+}
+
+fn portable_strings(strings: Vec<&'static str>) -> Vec<<PortableForm as Form>::String> {
+	strings.into_iter().map(portable_string).collect()
+}
+
+/// The description of a type.
+#[derive(PartialEq, Eq, Clone, Encode, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(Decode, Serialize))]
+#[cfg_attr(feature = "std", serde(bound(serialize = "T::Type: Serialize, T::String: Serialize")))]
+pub struct Type<T: Form = MetaForm> {
+	/// The path of the type, empty for types without a name such as tuples or primitives.
+	pub path: Path<T>,
+	/// The generic type parameters of the type.
+	#[cfg_attr(feature = "std", serde(rename = "params"))]
+	pub type_params: Vec<TypeParameter<T>>,
+	/// The definition of the type.
+	pub def: TypeDef<T>,
+	/// The documentation of the type.
+	pub docs: Vec<T::String>,
+}
+
+impl Type {
+	/// Creates a new type description.
+	pub fn new(
+		path: Path,
+		type_params: Vec<TypeParameter>,
+		def: TypeDef,
+		docs: Vec<&'static str>,
+	) -> Self {
+		Type { path, type_params, def, docs }
+	}
+
+	/// Creates a new description of a type without path, type parameters and documentation.
+	pub fn anonymous(def: TypeDef) -> Self {
+		Type { path: Path::default(), type_params: Vec::new(), def, docs: Vec::new() }
+	}
+}
+
+impl IntoPortable for Type {
+	type Output = Type<PortableForm>;
+
+	fn into_portable(self, registry: &mut Registry) -> Self::Output {
+		Type {
+			path: self.path.into_portable(registry),
+			type_params: self.type_params.into_iter().map(|p| p.into_portable(registry)).collect(),
+			def: self.def.into_portable(registry),
+			docs: portable_strings(self.docs),
+		}
+	}
+}
+
+/// A generic type parameter of a type.
+#[derive(PartialEq, Eq, Clone, Encode, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(Decode, Serialize))]
+#[cfg_attr(feature = "std", serde(bound(serialize = "T::Type: Serialize, T::String: Serialize")))]
+pub struct TypeParameter<T: Form = MetaForm> {
+	/// The name of the parameter, e.g. `T`.
+	pub name: T::String,
+	/// The type the parameter is instantiated with, `None` if the parameter is skipped.
+	#[cfg_attr(feature = "std", serde(rename = "type"))]
+	pub ty: Option<T::Type>,
+}
+
+impl TypeParameter {
+	/// Creates a new type parameter.
+	pub fn new(name: &'static str, ty: Option<MetaType>) -> Self {
+		TypeParameter { name, ty }
+	}
+}
+
+impl IntoPortable for TypeParameter {
+	type Output = TypeParameter<PortableForm>;
+
+	fn into_portable(self, registry: &mut Registry) -> Self::Output {
+		TypeParameter {
+			name: portable_string(self.name),
+			ty: self.ty.map(|ty| registry.register_type(&ty)),
+		}
+	}
+}
+
+/// The path of a named type, made of the module path segments followed by the type name.
+#[derive(PartialEq, Eq, Clone, Encode, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(Decode, Serialize))]
+#[cfg_attr(feature = "std", serde(transparent, bound(serialize = "T::String: Serialize")))]
+pub struct Path<T: Form = MetaForm> {
+	/// The segments of the path.
+	pub segments: Vec<T::String>,
+}
+
+impl<T: Form> Default for Path<T> {
+	fn default() -> Self {
+		Path { segments: Vec::new() }
+	}
+}
+
+impl Path {
+	/// Creates the path of the type `ident` declared in `module_path`.
+	///
+	/// `module_path` is expected to be the output of `module_path!()`.
+	pub fn new(ident: &'static str, module_path: &'static str) -> Self {
+		let mut segments = module_path.split("::").collect::<Vec<_>>();
+		segments.push(ident);
+		Path { segments }
+	}
+
+	/// Creates a path from the given segments.
+	pub fn from_segments(segments: Vec<&'static str>) -> Self {
+		Path { segments }
+	}
+}
+
+impl IntoPortable for Path {
+	type Output = Path<PortableForm>;
+
+	fn into_portable(self, _: &mut Registry) -> Self::Output {
+		Path { segments: portable_strings(self.segments) }
+	}
+}
+
+/// The definition of a type.
+#[derive(PartialEq, Eq, Clone, Encode, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(Decode, Serialize))]
+#[cfg_attr(feature = "std", serde(bound(serialize = "T::Type: Serialize, T::String: Serialize")))]
+pub enum TypeDef<T: Form = MetaForm> {
+	/// A struct with named or unnamed fields, encoded as the concatenation of its fields.
+	Composite(Vec<Field<T>>),
+	/// An enum, encoded as the index of the variant followed by the fields of the variant.
+	Variant(Vec<Variant<T>>),
+	/// A variable length sequence, encoded with a compact length prefix.
+	Sequence(T::Type),
+	/// A fixed length array, encoded without a length prefix.
+	Array(u32, T::Type),
+	/// A tuple, encoded as the concatenation of its elements.
+	Tuple(Vec<T::Type>),
+	/// A primitive type.
+	Primitive(TypeDefPrimitive),
+	/// The compact encoding of the given type.
+	Compact(T::Type),
+}
+
+impl IntoPortable for TypeDef {
+	type Output = TypeDef<PortableForm>;
+
+	fn into_portable(self, registry: &mut Registry) -> Self::Output {
+		match self {
+			TypeDef::Composite(fields) => TypeDef::Composite(
+				fields.into_iter().map(|f| f.into_portable(registry)).collect(),
+			),
+			TypeDef::Variant(variants) => TypeDef::Variant(
+				variants.into_iter().map(|v| v.into_portable(registry)).collect(),
+			),
+			TypeDef::Sequence(ty) => TypeDef::Sequence(registry.register_type(&ty)),
+			TypeDef::Array(len, ty) => TypeDef::Array(len, registry.register_type(&ty)),
+			TypeDef::Tuple(types) => TypeDef::Tuple(registry.register_types(types)),
+			TypeDef::Primitive(primitive) => TypeDef::Primitive(primitive),
+			TypeDef::Compact(ty) => TypeDef::Compact(registry.register_type(&ty)),
+		}
+	}
+}
+
+/// A primitive type.
+#[derive(PartialEq, Eq, Clone, Copy, Encode, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(Decode, Serialize))]
+pub enum TypeDefPrimitive {
+	/// `bool`
+	Bool,
+	/// `char`
+	Char,
+	/// `str`, encoded like a sequence of bytes.
+	Str,
+	/// `u8`
+	U8,
+	/// `u16`
+	U16,
+	/// `u32`
+	U32,
+	/// `u64`
+	U64,
+	/// `u128`
+	U128,
+	/// A 256 bit unsigned integer.
+	U256,
+	/// `i8`
+	I8,
+	/// `i16`
+	I16,
+	/// `i32`
+	I32,
+	/// `i64`
+	I64,
+	/// `i128`
+	I128,
+	/// A 256 bit signed integer.
+	I256,
+}
+
+/// A field of a composite type or of an enum variant.
+#[derive(PartialEq, Eq, Clone, Encode, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(Decode, Serialize))]
+#[cfg_attr(feature = "std", serde(bound(serialize = "T::Type: Serialize, T::String: Serialize")))]
+pub struct Field<T: Form = MetaForm> {
+	/// The name of the field, `None` for unnamed fields.
+	pub name: Option<T::String>,
+	/// The type of the field.
+	#[cfg_attr(feature = "std", serde(rename = "type"))]
+	pub ty: T::Type,
+	/// The name of the type of the field as written in the source code.
+	pub type_name: Option<T::String>,
+	/// The documentation of the field.
+	pub docs: Vec<T::String>,
+}
+
+impl Field {
+	/// Creates a new field of type `T`.
+	pub fn new<T: crate::TypeInfo + ?Sized>(
+		name: Option<&'static str>,
+		type_name: Option<&'static str>,
+		docs: Vec<&'static str>,
+	) -> Self {
+		Field { name, ty: MetaType::new::<T>(), type_name, docs }
+	}
+
+	/// Creates a new unnamed field of type `T`.
+	pub fn unnamed<T: crate::TypeInfo + ?Sized>() -> Self {
+		Self::new::<T>(None, None, Vec::new())
+	}
+}
+
+impl IntoPortable for Field {
+	type Output = Field<PortableForm>;
+
+	fn into_portable(self, registry: &mut Registry) -> Self::Output {
+		Field {
+			name: self.name.map(portable_string),
+			ty: registry.register_type(&self.ty),
+			type_name: self.type_name.map(portable_string),
+			docs: portable_strings(self.docs),
+		}
+	}
+}
+
+/// A variant of an enum.
+#[derive(PartialEq, Eq, Clone, Encode, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(Decode, Serialize))]
+#[cfg_attr(feature = "std", serde(bound(serialize = "T::Type: Serialize, T::String: Serialize")))]
+pub struct Variant<T: Form = MetaForm> {
+	/// The name of the variant.
+	pub name: T::String,
+	/// The fields of the variant.
+	pub fields: Vec<Field<T>>,
+	/// The index used to encode the variant.
+	pub index: u8,
+	/// The documentation of the variant.
+	pub docs: Vec<T::String>,
+}
+
+impl Variant {
+	/// Creates a new variant.
+	pub fn new(
+		name: &'static str,
+		fields: Vec<Field>,
+		index: u8,
+		docs: Vec<&'static str>,
+	) -> Self {
+		Variant { name, fields, index, docs }
+	}
+}
+
+impl IntoPortable for Variant {
+	type Output = Variant<PortableForm>;
+
+	fn into_portable(self, registry: &mut Registry) -> Self::Output {
+		Variant {
+			name: portable_string(self.name),
+			fields: self.fields.into_iter().map(|f| f.into_portable(registry)).collect(),
+			index: self.index,
+			docs: portable_strings(self.docs),
+		}
+	}
+}
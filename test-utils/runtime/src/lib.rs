@@ -570,6 +570,14 @@ cfg_if! {
 				fn metadata() -> OpaqueMetadata {
 					unimplemented!()
 				}
+
+				fn metadata_at_version(_: u32) -> Option<OpaqueMetadata> {
+					unimplemented!()
+				}
+
+				fn metadata_versions() -> Vec<u32> {
+					unimplemented!()
+				}
 			}
 
 			impl sp_transaction_pool::runtime_api::TaggedTransactionQueue<Block> for Runtime {
@@ -812,6 +820,14 @@ cfg_if! {
 				fn metadata() -> OpaqueMetadata {
 					unimplemented!()
 				}
+
+				fn metadata_at_version(_: u32) -> Option<OpaqueMetadata> {
+					unimplemented!()
+				}
+
+				fn metadata_versions() -> Vec<u32> {
+					unimplemented!()
+				}
 			}
 
 			impl sp_transaction_pool::runtime_api::TaggedTransactionQueue<Block> for Runtime {